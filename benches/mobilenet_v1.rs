@@ -1,9 +1,9 @@
-use berrylite::kernel::micro_operator::i8::avg_pool2d_i8::OpAvgPool2DInt8;
-use berrylite::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8;
-use berrylite::kernel::micro_operator::i8::depthwise_conv2d_i8::OpDepthWiseConv2DInt8;
-use berrylite::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8;
-use berrylite::kernel::micro_operator::i8::reshape_i8::OpReshapeInt8;
-use berrylite::kernel::micro_operator::i8::softmax_i8::OpSoftMaxInt8;
+use berrylite::kernel::micro_operator::u8::avg_pool2d_u8::OpAvgPool2DUInt8;
+use berrylite::kernel::micro_operator::u8::conv2d_u8::OpConv2DUInt8;
+use berrylite::kernel::micro_operator::u8::depthwise_conv2d_u8::OpDepthWiseConv2DUInt8;
+use berrylite::kernel::micro_operator::u8::fully_connected_u8::OpFullyConnectedUInt8;
+use berrylite::kernel::micro_operator::u8::reshape_u8::OpReshapeUInt8;
+use berrylite::kernel::micro_operator::u8::softmax_u8::OpSoftMaxUInt8;
 use berrylite::micro_allocator::BumpArenaAllocator;
 use berrylite::micro_interpreter::BLiteInterpreter;
 use berrylite::micro_op_resolver::BLiteOpResolver;
@@ -32,25 +32,25 @@ const MOBILENET_V1_0_50_128_QUANTIZED: &[u8; 1364512] =
 pub fn benchmark_mobilenet_v1_0_50_128_quantized_1_default_1(c: &mut Criterion) {
     let base_model = tflite::root_as_model(MOBILENET_V1_0_50_128_QUANTIZED).unwrap();
     let mut base_allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
-    let mut base_op_resolver = BLiteOpResolver::<7, i8, _>::new();
-    base_op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8());
-    base_op_resolver.add_op(OpReshapeInt8::reshape_int8());
-    base_op_resolver.add_op(OpConv2DInt8::conv2d_int8());
-    base_op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8());
-    base_op_resolver.add_op(OpSoftMaxInt8::softmax_int8());
-    base_op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8());
+    let mut base_op_resolver = BLiteOpResolver::<7, u8, _>::new();
+    base_op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8());
+    base_op_resolver.add_op(OpReshapeUInt8::reshape_uint8());
+    base_op_resolver.add_op(OpConv2DUInt8::conv2d_uint8());
+    base_op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8());
+    base_op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8());
+    base_op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8());
     let base_interpreter =
         BLiteInterpreter::new(&mut base_allocator, &base_op_resolver, &base_model).unwrap();
 
     let model = tflite::root_as_model(MOBILENET_V1_0_50_128_QUANTIZED).unwrap();
     let mut allocator = unsafe { BumpArenaAllocator::new(&mut BASE_ARENA) };
-    let mut op_resolver = BLiteOpResolver::<7, i8, _>::new();
-    op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8());
-    op_resolver.add_op(OpReshapeInt8::reshape_int8());
-    op_resolver.add_op(OpConv2DInt8::conv2d_int8());
-    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8());
-    op_resolver.add_op(OpSoftMaxInt8::softmax_int8());
-    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8());
+    let mut op_resolver = BLiteOpResolver::<7, u8, _>::new();
+    op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8());
+    op_resolver.add_op(OpReshapeUInt8::reshape_uint8());
+    op_resolver.add_op(OpConv2DUInt8::conv2d_uint8());
+    op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8());
+    op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8());
+    op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8());
     let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model).unwrap();
 
     let mut group = c.benchmark_group("mobilenet_v1_0_50_128_quantized");
@@ -75,25 +75,25 @@ const MOBILENET_V1_0_50_160_QUANTIZED: &[u8; 1364512] =
 pub fn benchmark_mobilenet_v1_0_50_160_quantized_1_default_1(c: &mut Criterion) {
     let base_model = tflite::root_as_model(MOBILENET_V1_0_50_160_QUANTIZED).unwrap();
     let mut base_allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
-    let mut base_op_resolver = BLiteOpResolver::<7, i8, _>::new();
-    base_op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8());
-    base_op_resolver.add_op(OpReshapeInt8::reshape_int8());
-    base_op_resolver.add_op(OpConv2DInt8::conv2d_int8());
-    base_op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8());
-    base_op_resolver.add_op(OpSoftMaxInt8::softmax_int8());
-    base_op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8());
+    let mut base_op_resolver = BLiteOpResolver::<7, u8, _>::new();
+    base_op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8());
+    base_op_resolver.add_op(OpReshapeUInt8::reshape_uint8());
+    base_op_resolver.add_op(OpConv2DUInt8::conv2d_uint8());
+    base_op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8());
+    base_op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8());
+    base_op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8());
     let base_interpreter =
         BLiteInterpreter::new(&mut base_allocator, &base_op_resolver, &base_model).unwrap();
 
     let model = tflite::root_as_model(MOBILENET_V1_0_50_160_QUANTIZED).unwrap();
     let mut allocator = unsafe { BumpArenaAllocator::new(&mut BASE_ARENA) };
-    let mut op_resolver = BLiteOpResolver::<7, i8, _>::new();
-    op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8());
-    op_resolver.add_op(OpReshapeInt8::reshape_int8());
-    op_resolver.add_op(OpConv2DInt8::conv2d_int8());
-    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8());
-    op_resolver.add_op(OpSoftMaxInt8::softmax_int8());
-    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8());
+    let mut op_resolver = BLiteOpResolver::<7, u8, _>::new();
+    op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8());
+    op_resolver.add_op(OpReshapeUInt8::reshape_uint8());
+    op_resolver.add_op(OpConv2DUInt8::conv2d_uint8());
+    op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8());
+    op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8());
+    op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8());
     let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model).unwrap();
 
     let mut group = c.benchmark_group("mobilenet_v1_0_50_160_quantized");
@@ -118,25 +118,25 @@ const MOBILENET_V1_0_50_192_QUANTIZED: &[u8; 1364512] =
 pub fn benchmark_mobilenet_v1_0_50_192_quantized_1_default_1(c: &mut Criterion) {
     let base_model = tflite::root_as_model(MOBILENET_V1_0_50_192_QUANTIZED).unwrap();
     let mut base_allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
-    let mut base_op_resolver = BLiteOpResolver::<7, i8, _>::new();
-    base_op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8());
-    base_op_resolver.add_op(OpReshapeInt8::reshape_int8());
-    base_op_resolver.add_op(OpConv2DInt8::conv2d_int8());
-    base_op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8());
-    base_op_resolver.add_op(OpSoftMaxInt8::softmax_int8());
-    base_op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8());
+    let mut base_op_resolver = BLiteOpResolver::<7, u8, _>::new();
+    base_op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8());
+    base_op_resolver.add_op(OpReshapeUInt8::reshape_uint8());
+    base_op_resolver.add_op(OpConv2DUInt8::conv2d_uint8());
+    base_op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8());
+    base_op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8());
+    base_op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8());
     let base_interpreter =
         BLiteInterpreter::new(&mut base_allocator, &base_op_resolver, &base_model).unwrap();
 
     let model = tflite::root_as_model(MOBILENET_V1_0_50_192_QUANTIZED).unwrap();
     let mut allocator = unsafe { BumpArenaAllocator::new(&mut BASE_ARENA) };
-    let mut op_resolver = BLiteOpResolver::<7, i8, _>::new();
-    op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8());
-    op_resolver.add_op(OpReshapeInt8::reshape_int8());
-    op_resolver.add_op(OpConv2DInt8::conv2d_int8());
-    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8());
-    op_resolver.add_op(OpSoftMaxInt8::softmax_int8());
-    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8());
+    let mut op_resolver = BLiteOpResolver::<7, u8, _>::new();
+    op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8());
+    op_resolver.add_op(OpReshapeUInt8::reshape_uint8());
+    op_resolver.add_op(OpConv2DUInt8::conv2d_uint8());
+    op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8());
+    op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8());
+    op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8());
     let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model).unwrap();
 
     let mut group = c.benchmark_group("mobilenet_v1_0_50_192_quantized");
@@ -161,25 +161,25 @@ const MOBILENET_V1_0_50_224_QUANTIZED: &[u8; 1364512] =
 pub fn benchmark_mobilenet_v1_0_50_224_quantized_1_default_1(c: &mut Criterion) {
     let base_model = tflite::root_as_model(MOBILENET_V1_0_50_224_QUANTIZED).unwrap();
     let mut base_allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
-    let mut base_op_resolver = BLiteOpResolver::<7, i8, _>::new();
-    base_op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8());
-    base_op_resolver.add_op(OpReshapeInt8::reshape_int8());
-    base_op_resolver.add_op(OpConv2DInt8::conv2d_int8());
-    base_op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8());
-    base_op_resolver.add_op(OpSoftMaxInt8::softmax_int8());
-    base_op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8());
+    let mut base_op_resolver = BLiteOpResolver::<7, u8, _>::new();
+    base_op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8());
+    base_op_resolver.add_op(OpReshapeUInt8::reshape_uint8());
+    base_op_resolver.add_op(OpConv2DUInt8::conv2d_uint8());
+    base_op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8());
+    base_op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8());
+    base_op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8());
     let base_interpreter =
         BLiteInterpreter::new(&mut base_allocator, &base_op_resolver, &base_model).unwrap();
 
     let model = tflite::root_as_model(MOBILENET_V1_0_50_224_QUANTIZED).unwrap();
     let mut allocator = unsafe { BumpArenaAllocator::new(&mut BASE_ARENA) };
-    let mut op_resolver = BLiteOpResolver::<7, i8, _>::new();
-    op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8());
-    op_resolver.add_op(OpReshapeInt8::reshape_int8());
-    op_resolver.add_op(OpConv2DInt8::conv2d_int8());
-    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8());
-    op_resolver.add_op(OpSoftMaxInt8::softmax_int8());
-    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8());
+    let mut op_resolver = BLiteOpResolver::<7, u8, _>::new();
+    op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8());
+    op_resolver.add_op(OpReshapeUInt8::reshape_uint8());
+    op_resolver.add_op(OpConv2DUInt8::conv2d_uint8());
+    op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8());
+    op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8());
+    op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8());
     let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model).unwrap();
 
     let mut group = c.benchmark_group("mobilenet_v1_0_50_224_quantized");
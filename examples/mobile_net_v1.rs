@@ -1,9 +1,9 @@
-use berrylite::kernel::micro_operator::i8::avg_pool2d_i8::OpAvgPool2DInt8;
-use berrylite::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8;
-use berrylite::kernel::micro_operator::i8::depthwise_conv2d_i8::OpDepthWiseConv2DInt8;
-use berrylite::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8;
-use berrylite::kernel::micro_operator::i8::reshape_i8::OpReshapeInt8;
-use berrylite::kernel::micro_operator::i8::softmax_i8::OpSoftMaxInt8;
+use berrylite::kernel::micro_operator::u8::avg_pool2d_u8::OpAvgPool2DUInt8;
+use berrylite::kernel::micro_operator::u8::conv2d_u8::OpConv2DUInt8;
+use berrylite::kernel::micro_operator::u8::depthwise_conv2d_u8::OpDepthWiseConv2DUInt8;
+use berrylite::kernel::micro_operator::u8::fully_connected_u8::OpFullyConnectedUInt8;
+use berrylite::kernel::micro_operator::u8::reshape_u8::OpReshapeUInt8;
+use berrylite::kernel::micro_operator::u8::softmax_u8::OpSoftMaxUInt8;
 use berrylite::micro_allocator::{ArenaAllocator, BumpArenaAllocator};
 use berrylite::micro_errors::Result;
 use berrylite::micro_interpreter::BLiteInterpreter;
@@ -17,14 +17,14 @@ const ARENA_SIZE: usize = 1024 * 1024 * 1024;
 static mut ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
 
 fn set_input(
-    interpreter: &mut BLiteInterpreter<'_, i8>,
+    interpreter: &mut BLiteInterpreter<'_, u8>,
     input_h: usize,
     input_w: usize,
     _input_zero_point: i32,
 ) {
     for h in 0..input_h {
         for w in 0..input_w {
-            interpreter.input.data[h * input_w + w] = 0 as i8;
+            interpreter.input.data[h * input_w + w] = 0 as u8;
         }
     }
 }
@@ -35,12 +35,12 @@ fn predict() -> Result<usize> {
     let mut allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
 
     let mut op_resolver = BLiteOpResolver::<7, _, _>::new();
-    op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8())?;
-    op_resolver.add_op(OpReshapeInt8::reshape_int8())?;
-    op_resolver.add_op(OpConv2DInt8::conv2d_int8())?;
-    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8())?;
-    op_resolver.add_op(OpSoftMaxInt8::softmax_int8())?;
-    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8())?;
+    op_resolver.add_op(OpFullyConnectedUInt8::fully_connected_uint8())?;
+    op_resolver.add_op(OpReshapeUInt8::reshape_uint8())?;
+    op_resolver.add_op(OpConv2DUInt8::conv2d_uint8())?;
+    op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8())?;
+    op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8())?;
+    op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8())?;
 
     let mut interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
     let (_input_scale, input_zero_point) = interpreter.get_input_quantization_params().unwrap();
@@ -0,0 +1,26 @@
+use berrylite::codegen::generate_module;
+use std::path::Path;
+use std::process::exit;
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} <model.tflite> <output.rs>", args[0]);
+        exit(2);
+    }
+    let buffer = std::fs::read(&args[1]).unwrap_or_else(|e| {
+        eprintln!("failed to read {}: {e}", args[1]);
+        exit(1);
+    });
+    let model_name = Path::new(&args[1])
+        .file_name()
+        .map_or(args[1].clone(), |name| name.to_string_lossy().into_owned());
+    let module = generate_module(&buffer, &model_name).unwrap_or_else(|e| {
+        eprintln!("failed to generate {}: {e:?}", args[1]);
+        exit(1);
+    });
+    std::fs::write(&args[2], module).unwrap_or_else(|e| {
+        eprintln!("failed to write {}: {e}", args[2]);
+        exit(1);
+    });
+}
@@ -0,0 +1,1016 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::f32::{
+    conv2d::OpConv2D, depthwise_conv2d::OpDepthWiseConv2D, fully_connected::OpFullyConnected,
+    max_pool2d::OpMaxPool2D, reshape::OpReshape, softmax::OpSoftMax,
+};
+use crate::kernel::micro_operator::i8::{
+    avg_pool2d_i8::OpAvgPool2DInt8, conv2d_i8::OpConv2DInt8,
+    depthwise_conv2d_i8::OpDepthWiseConv2DInt8, fully_connected_i8::OpFullyConnectedInt8,
+    max_pool2d_i8::OpMaxPool2DInt8, reshape_i8::OpReshapeInt8, softmax_i8::OpSoftMaxInt8,
+};
+use crate::kernel::utils::types::flat_skip_dims;
+use crate::micro_allocator::BumpArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_errors::{BLiteError::*, Result};
+use crate::micro_interpreter::BLiteInterpreter;
+use crate::micro_node::BLiteNode;
+use crate::micro_op_resolver::BLiteOpResolver;
+use crate::micro_tensor::BLiteTensor::{self, *};
+use crate::tflite_schema_generated::tflite::{self, BuiltinOperator, Model, TensorType};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::mem::{align_of, size_of};
+use std::string::String;
+use std::vec::Vec;
+
+// the arena of the interpreter on the host, which grows until the model fits
+const HOST_ARENA_SIZE: usize = 1 << 20;
+const MAX_HOST_ARENA_SIZE: usize = 1 << 30;
+const VALUES_PER_LINE: usize = 16;
+
+/*-----------------------------------------------------------------------------*/
+/* Trait for the elements written into the generated module                    */
+/*-----------------------------------------------------------------------------*/
+pub trait CodegenElem: Copy {
+    const TYPE_NAME: &'static str;
+
+    /// Writes the value as a Rust literal, which is read back as the same bits
+    fn literal(self) -> String;
+}
+
+impl CodegenElem for i8 {
+    const TYPE_NAME: &'static str = "i8";
+    fn literal(self) -> String {
+        self.to_string()
+    }
+}
+
+impl CodegenElem for u8 {
+    const TYPE_NAME: &'static str = "u8";
+    fn literal(self) -> String {
+        self.to_string()
+    }
+}
+
+impl CodegenElem for i32 {
+    const TYPE_NAME: &'static str = "i32";
+    fn literal(self) -> String {
+        self.to_string()
+    }
+}
+
+impl CodegenElem for f32 {
+    const TYPE_NAME: &'static str = "f32";
+    fn literal(self) -> String {
+        if self.is_finite() {
+            // Debug prints the shortest representation which is parsed as the same value
+            format!("{self:?}")
+        } else if self.is_nan() {
+            "f32::NAN".into()
+        } else if self > 0. {
+            "f32::INFINITY".into()
+        } else {
+            "f32::NEG_INFINITY".into()
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------*/
+/* Code generator                                                              */
+/*-----------------------------------------------------------------------------*/
+/// Generates the Rust module evaluating the model without the interpreter.
+/// The module has the weights as statics, `Arena` laid out by the `GreedyMemoryPlanner`,
+/// and `invoke` calling the kernels of the operators in order.
+/// It is included by `include!` into a module of the crate depending on `berrylite`.
+///
+/// The int8 and float32 models of CONV_2D, DEPTHWISE_CONV_2D, FULLY_CONNECTED,
+/// AVERAGE_POOL_2D, MAX_POOL_2D, RESHAPE and SOFTMAX are supported,
+/// and the other operators and the sparse weights are `NotSupportedCodegen`.
+/// The float operators call the scalar kernels, whose rounding can differ from the `simd` ones.
+pub fn generate_module(buffer: &[u8], model_name: &str) -> Result<String> {
+    let model = tflite::root_as_model(buffer).map_err(|_| FailedToCreateGraph)?;
+    let Some(subgraphs) = model.subgraphs() else {
+        return Err(NotFoundSubgraphs);
+    };
+    let subgraph = subgraphs.get(0);
+    let (Some(tensors), Some(inputs)) = (subgraph.tensors(), subgraph.inputs()) else {
+        return Err(NotFoundTensor);
+    };
+    match tensors.get(inputs.get(0) as usize).type_() {
+        TensorType::INT8 => {
+            let mut op_resolver = BLiteOpResolver::<7, i8, BumpArenaAllocator>::new();
+            op_resolver.add_op(OpConv2DInt8::conv2d_int8())?;
+            op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8())?;
+            op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8())?;
+            op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8())?;
+            op_resolver.add_op(OpMaxPool2DInt8::max_pool2d_int8())?;
+            op_resolver.add_op(OpReshapeInt8::reshape_int8())?;
+            op_resolver.add_op(OpSoftMaxInt8::softmax_int8())?;
+            generate(&model, &op_resolver, model_name)
+        }
+        TensorType::FLOAT32 => {
+            let mut op_resolver = BLiteOpResolver::<6, f32, BumpArenaAllocator>::new();
+            op_resolver.add_op(OpConv2D::conv2d())?;
+            op_resolver.add_op(OpDepthWiseConv2D::depthwise_conv2d())?;
+            op_resolver.add_op(OpFullyConnected::fully_connected())?;
+            op_resolver.add_op(OpMaxPool2D::max_pool2d())?;
+            op_resolver.add_op(OpReshape::reshape())?;
+            op_resolver.add_op(OpSoftMax::softmax())?;
+            generate(&model, &op_resolver, model_name)
+        }
+        _ => Err(InCompatibleType),
+    }
+}
+
+fn generate<'a, const N: usize, T: ArrayElem<T> + CodegenElem>(
+    model: &'a Model<'a>,
+    op_resolver: &'a BLiteOpResolver<'a, N, T, BumpArenaAllocator>,
+    model_name: &str,
+) -> Result<String> {
+    let mut arena_size = HOST_ARENA_SIZE;
+    loop {
+        // the tensors of the interpreter borrow the arena for the rest of the program,
+        // which is leaked as the generator runs once on the host
+        let arena = Box::leak(vec![0u8; arena_size].into_boxed_slice());
+        let mut allocator = unsafe { BumpArenaAllocator::new(arena) };
+        match BLiteInterpreter::new(&mut allocator, op_resolver, model) {
+            Ok(interpreter) => return ModuleWriter::new(model, &interpreter, model_name)?.write(),
+            Err(FailedToAllocateMemory) if arena_size < MAX_HOST_ARENA_SIZE => arena_size *= 2,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------*/
+/* Struct for a writer of the generated module                                 */
+/*-----------------------------------------------------------------------------*/
+struct ModuleWriter<'m, 'a, T: ArrayElem<T> + CodegenElem> {
+    model_name: &'m str,
+    interpreter: &'m BLiteInterpreter<'a, T>,
+    input_idx: usize,
+    output_idx: usize,
+    // byte offsets of the activation tensors in the arena
+    offsets: BTreeMap<usize, usize>,
+    scratch_offset: usize,
+    arena_size: usize,
+    imports: BTreeSet<&'static str>,
+    statics: String,
+    emitted_tensors: BTreeSet<usize>,
+    invoke: String,
+    uses_cells: bool,
+}
+
+impl<'m, 'a, T: ArrayElem<T> + CodegenElem> ModuleWriter<'m, 'a, T> {
+    /// Lays out the arena, whose intermediate tensors are placed at the offsets
+    /// planned by the `GreedyMemoryPlanner` followed by the input, the output and the scratch
+    fn new(
+        model: &Model<'a>,
+        interpreter: &'m BLiteInterpreter<'a, T>,
+        model_name: &'m str,
+    ) -> Result<Self> {
+        let subgraph = model.subgraphs().ok_or(NotFoundSubgraphs)?.get(0);
+        let buffers = model.buffers().ok_or(NotFoundBuffers)?;
+        let tflite_tensors = subgraph.tensors().ok_or(NotFoundTensor)?;
+        let input_idx = subgraph.inputs().ok_or(NotFoundTensor)?.get(0) as usize;
+        let output_idx = subgraph.outputs().ok_or(NotFoundTensor)?.get(0) as usize;
+
+        let blite_subgraph = interpreter.graph.subgraphs[0].borrow();
+        let tensors = &blite_subgraph.tensors;
+        let memory_plan = interpreter.memory_plan();
+        let is_activation = |idx: usize| {
+            let buffer = buffers.get(tflite_tensors.get(idx).buffer() as usize);
+            buffer.data().is_none_or(|data| data.is_empty())
+        };
+
+        // the planned offsets are relative to the first intermediate tensor
+        let mut offsets = BTreeMap::new();
+        let mut scratch_len = 0;
+        for (node, registration) in blite_subgraph.node_and_registrations.iter() {
+            for &idx in node.inputs.iter().chain(node.outputs.iter()) {
+                let idx = idx as usize;
+                if idx == input_idx || idx == output_idx || !is_activation(idx) {
+                    continue;
+                }
+                // the activations which are not planned, such as the other inputs of the graph
+                let Ok(offset) = usize::try_from(memory_plan[idx]) else {
+                    return Err(NotSupportedCodegen(registration.op_code));
+                };
+                offsets.insert(idx, offset);
+            }
+            if let Conv2DOptions { im2col_buffer, .. }
+            | QuantizedConv2DOptions { im2col_buffer, .. } = registration.builtin_option
+            {
+                scratch_len = scratch_len.max(im2col_buffer.len());
+            }
+        }
+
+        let mut arena_size = interpreter.planned_size();
+        for idx in [input_idx, output_idx] {
+            if offsets.contains_key(&idx) {
+                continue;
+            }
+            let offset = arena_size.next_multiple_of(align_of::<T>());
+            arena_size = offset + tensors[idx].size() * size_of::<T>();
+            offsets.insert(idx, offset);
+        }
+        let scratch_offset = arena_size.next_multiple_of(align_of::<T>());
+        arena_size = scratch_offset + scratch_len * size_of::<T>();
+
+        Ok(Self {
+            model_name,
+            interpreter,
+            input_idx,
+            output_idx,
+            offsets,
+            scratch_offset,
+            arena_size,
+            imports: BTreeSet::new(),
+            statics: String::new(),
+            emitted_tensors: BTreeSet::new(),
+            invoke: String::new(),
+            uses_cells: false,
+        })
+    }
+
+    fn write(mut self) -> Result<String> {
+        let blite_subgraph = self.interpreter.graph.subgraphs[0].borrow();
+        for (i, (node, registration)) in blite_subgraph.node_and_registrations.iter().enumerate() {
+            let name = BuiltinOperator(registration.op_code)
+                .variant_name()
+                .unwrap_or("UNKNOWN");
+            writeln!(self.invoke, "    // {i}: {name}").unwrap();
+            self.write_op(i, node, registration.op_code, registration.builtin_option)?;
+        }
+        self.imports.insert("berrylite::micro_errors::Result");
+        if self.uses_cells {
+            self.imports.insert("core::cell::Cell");
+        }
+        Ok(self.module())
+    }
+
+    fn module(&self) -> String {
+        let tensors = &self.interpreter.graph.subgraphs[0].borrow().tensors;
+        let ty = T::TYPE_NAME;
+        let mut out = String::new();
+        writeln!(
+            out,
+            "// @generated by berrylite-codegen from {}, do not edit",
+            self.model_name
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        for import in self.imports.iter() {
+            writeln!(out, "use {import};").unwrap();
+        }
+        writeln!(out).unwrap();
+
+        writeln!(out, "pub const ARENA_SIZE: usize = {};", self.arena_size).unwrap();
+        for (prefix, idx) in [("INPUT", self.input_idx), ("OUTPUT", self.output_idx)] {
+            writeln!(
+                out,
+                "pub const {prefix}_OFFSET: usize = {};",
+                self.offsets[&idx]
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "pub const {prefix}_LEN: usize = {};",
+                tensors[idx].size()
+            )
+            .unwrap();
+            if let Some(quant_params) = tensors[idx].quant_params() {
+                writeln!(
+                    out,
+                    "pub const {prefix}_SCALE: f32 = {};",
+                    quant_params.scale[0].literal()
+                )
+                .unwrap();
+                writeln!(
+                    out,
+                    "pub const {prefix}_ZERO_POINT: i32 = {};",
+                    quant_params.zero_point[0]
+                )
+                .unwrap();
+            }
+        }
+        writeln!(out).unwrap();
+
+        writeln!(
+            out,
+            "/// The memory of the activations, which is laid out by the greedy memory planner"
+        )
+        .unwrap();
+        writeln!(out, "#[repr(C, align(16))]").unwrap();
+        writeln!(out, "pub struct Arena(pub [u8; ARENA_SIZE]);").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "impl Arena {{").unwrap();
+        writeln!(out, "    pub const fn new() -> Self {{").unwrap();
+        writeln!(out, "        Self([0; ARENA_SIZE])").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "    pub fn input(&mut self) -> &mut [{ty}] {{").unwrap();
+        writeln!(
+            out,
+            "        unsafe {{ slice_mut(self.0.as_mut_ptr(), INPUT_OFFSET, INPUT_LEN) }}"
+        )
+        .unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "    pub fn output(&self) -> &[{ty}] {{").unwrap();
+        writeln!(
+            out,
+            "        unsafe {{ slice(self.0.as_ptr() as *mut u8, OUTPUT_OFFSET, OUTPUT_LEN) }}"
+        )
+        .unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "impl Default for Arena {{").unwrap();
+        writeln!(out, "    fn default() -> Self {{").unwrap();
+        writeln!(out, "        Self::new()").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(
+            out,
+            "/// Returns the `len` elements of `E` at the byte `offset` of the arena."
+        )
+        .unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(out, "/// # Safety").unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(out, "/// The elements must be in the arena and aligned for `E`, as the offsets of the memory plan are.").unwrap();
+        writeln!(out, "/// The slice must not overlap a slice returned by `slice_mut` while both of them are alive,").unwrap();
+        writeln!(out, "/// which holds for the tensors of an operator, as the memory plan keeps its inputs and outputs apart.").unwrap();
+        writeln!(
+            out,
+            "unsafe fn slice<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a [E] {{"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    core::slice::from_raw_parts(arena.add(offset) as *const E, len)"
+        )
+        .unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "/// Returns the `len` mutable elements of `E` at the byte `offset` of the arena."
+        )
+        .unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(out, "/// # Safety").unwrap();
+        writeln!(out, "///").unwrap();
+        writeln!(out, "/// As `slice`, and the slice must not overlap any other slice of the arena while it is alive.").unwrap();
+        writeln!(
+            out,
+            "unsafe fn slice_mut<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a mut [E] {{"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "    core::slice::from_raw_parts_mut(arena.add(offset) as *mut E, len)"
+        )
+        .unwrap();
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+        if self.uses_cells {
+            writeln!(out, "/// Returns the `len` elements of `E` at the byte `offset` of the arena as the scratch of a kernel.").unwrap();
+            writeln!(out, "///").unwrap();
+            writeln!(out, "/// # Safety").unwrap();
+            writeln!(out, "///").unwrap();
+            writeln!(out, "/// As `slice`, where the scratch follows all the tensors in the arena and overlaps none of them.").unwrap();
+            writeln!(
+                out,
+                "unsafe fn cells<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a [Cell<E>] {{"
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "    core::slice::from_raw_parts(arena.add(offset) as *const Cell<E>, len)"
+            )
+            .unwrap();
+            writeln!(out, "}}").unwrap();
+            writeln!(out).unwrap();
+        }
+
+        out.push_str(&self.statics);
+
+        writeln!(out, "pub fn invoke(arena: &mut Arena) -> Result<()> {{").unwrap();
+        writeln!(out, "    let arena = arena.0.as_mut_ptr();").unwrap();
+        out.push_str(&self.invoke);
+        writeln!(out, "    Ok(())").unwrap();
+        writeln!(out, "}}").unwrap();
+        out
+    }
+
+    fn write_static<E: CodegenElem>(&mut self, name: &str, values: &[E]) {
+        writeln!(
+            self.statics,
+            "static {name}: [{}; {}] = [",
+            E::TYPE_NAME,
+            values.len()
+        )
+        .unwrap();
+        for line in values.chunks(VALUES_PER_LINE) {
+            let line: Vec<String> = line.iter().map(|v| v.literal()).collect();
+            writeln!(self.statics, "    {},", line.join(", ")).unwrap();
+        }
+        writeln!(self.statics, "];").unwrap();
+        writeln!(self.statics).unwrap();
+    }
+
+    /// Writes the constant tensor as a static once, and returns the reference to it
+    fn constant(&mut self, idx: i32) -> Result<String> {
+        let idx = idx as usize;
+        let name = format!("TENSOR_{idx}");
+        if self.emitted_tensors.insert(idx) {
+            let tensors = &self.interpreter.graph.subgraphs[0].borrow().tensors;
+            match &tensors[idx] {
+                BTensor(x) => self.write_static(&name, x.borrow().data),
+                I32Tensor(x) => self.write_static(&name, x.borrow().data),
+                I8Tensor(x) => self.write_static(&name, x.borrow().data),
+                I4Tensor(x) => self.write_static(&name, x.borrow().data),
+                I64Tensor(_) => return Err(InCompatibleType),
+            }
+        }
+        Ok(format!("&{name}"))
+    }
+
+    /// Writes the filter of the int8 kernels, whose packed int4 values are kept packed
+    fn filter_data(&mut self, idx: i32) -> Result<String> {
+        self.imports
+            .insert("berrylite::kernel::utils::int4::FilterData");
+        let tensors = &self.interpreter.graph.subgraphs[0].borrow().tensors;
+        let variant = match tensors[idx as usize] {
+            I4Tensor(_) => "PackedInt4",
+            _ => "Dense",
+        };
+        Ok(format!("FilterData::{variant}({})", self.constant(idx)?))
+    }
+
+    fn derived<E: CodegenElem>(&mut self, op: usize, name: &str, values: &[E]) -> String {
+        let name = format!("OP{op}_{name}");
+        self.write_static(&name, values);
+        format!("&{name}")
+    }
+
+    fn dims(&self, idx: i32) -> &'a [i32] {
+        self.interpreter.graph.subgraphs[0].borrow().tensors[idx as usize].dims()
+    }
+
+    fn tensor(&self, idx: i32) -> &BLiteTensor<'a, T> {
+        let tensors = &self.interpreter.graph.subgraphs[0].borrow().tensors;
+        // the tensors are not moved while the interpreter is borrowed
+        unsafe { &*(&tensors[idx as usize] as *const BLiteTensor<'a, T>) }
+    }
+
+    /// Binds `input` and `output` of the node to the slices of the arena
+    fn bind_input_output(&mut self, node: &BLiteNode<'_>, scratch_len: usize) -> Result<()> {
+        let ty = T::TYPE_NAME;
+        let (input, output) = (node.inputs[0], node.outputs[0]);
+        let input_offset = self.offsets[&(input as usize)];
+        let output_offset = self.offsets[&(output as usize)];
+        let input_len = self.tensor(input).size();
+        let output_len = self.tensor(output).size();
+        if scratch_len == 0 {
+            writeln!(
+                self.invoke,
+                "    let (input, output) = unsafe {{ (slice::<{ty}>(arena, {input_offset}, {input_len}), slice_mut::<{ty}>(arena, {output_offset}, {output_len})) }};"
+            )
+            .unwrap();
+        } else {
+            self.uses_cells = true;
+            writeln!(
+                self.invoke,
+                "    let (input, output, scratch) = unsafe {{ (slice::<{ty}>(arena, {input_offset}, {input_len}), slice_mut::<{ty}>(arena, {output_offset}, {output_len}), cells::<{ty}>(arena, {}, {scratch_len})) }};",
+                self.scratch_offset
+            )
+            .unwrap();
+        }
+        Ok(())
+    }
+
+    fn call(&mut self, import: &'static str, function: &str, args: &[String]) {
+        self.imports.insert(import);
+        let op = import.rsplit("::").next().unwrap();
+        writeln!(self.invoke, "    {op}::{function}(").unwrap();
+        for arg in args {
+            writeln!(self.invoke, "        {arg},").unwrap();
+        }
+        writeln!(self.invoke, "    )?;").unwrap();
+    }
+
+    fn write_op(
+        &mut self,
+        i: usize,
+        node: &BLiteNode<'_>,
+        op_code: i32,
+        builtin_option: BLiteBuiltinOption<'_, T>,
+    ) -> Result<()> {
+        let s = |v: i32| v.to_string();
+        let input_dims = self.dims(node.inputs[0]);
+        let output_dims = self.dims(node.outputs[0]);
+        match builtin_option {
+            QuantizedConv2DOptions {
+                fused_activation_min,
+                fused_activation_max,
+                padding_w,
+                padding_h,
+                stride_w,
+                stride_h,
+                dilation_w_factor,
+                dilation_h_factor,
+                input_offset,
+                filter_offset,
+                output_offset,
+                per_channel_multiplier,
+                per_channel_shift,
+                filter_sparsity: None,
+                im2col_buffer,
+                effective_bias,
+                ..
+            } => {
+                let filter_dims = self.dims(node.inputs[1]);
+                let groups = input_dims[3] / filter_dims[3];
+                let filters_per_group = output_dims[3] / groups;
+                let is_dense = !matches!(self.tensor(node.inputs[1]), I4Tensor(_));
+                let use_gemm = !effective_bias.is_empty() && is_dense;
+                self.bind_input_output(node, if use_gemm { im2col_buffer.len() } else { 0 })?;
+                let multiplier = self.derived(i, "MULTIPLIER", per_channel_multiplier);
+                let shift = self.derived(i, "SHIFT", per_channel_shift);
+                let geometry = [
+                    s(stride_w),
+                    s(stride_h),
+                    s(dilation_w_factor),
+                    s(dilation_h_factor),
+                    s(padding_w),
+                    s(padding_h),
+                ];
+                let quantization = [
+                    s(input_offset),
+                    s(filter_offset),
+                    s(output_offset),
+                    multiplier,
+                    shift,
+                    input_dims[0].to_string(),
+                    s(fused_activation_min),
+                    s(fused_activation_max),
+                ];
+                let mut args;
+                if use_gemm {
+                    let scratch = if im2col_buffer.is_empty() {
+                        "&[]".into()
+                    } else {
+                        "scratch".into()
+                    };
+                    args = vec![
+                        "input".into(),
+                        self.constant(node.inputs[1])?,
+                        self.derived(i, "EFFECTIVE_BIAS", effective_bias),
+                        "output".into(),
+                        scratch,
+                        s(input_dims[1]),
+                        s(input_dims[2]),
+                        s(input_dims[3]),
+                        s(filter_dims[1]),
+                        s(filter_dims[2]),
+                        s(output_dims[1]),
+                        s(output_dims[2]),
+                        s(output_dims[3]),
+                    ];
+                    args.extend(geometry);
+                    args.extend(quantization);
+                    self.call(
+                        "berrylite::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8",
+                        "gemm_kernel",
+                        &args,
+                    );
+                } else {
+                    args = vec![
+                        "input".into(),
+                        self.filter_data(node.inputs[1])?,
+                        self.constant(node.inputs[2])?,
+                        "output".into(),
+                        s(input_dims[1]),
+                        s(input_dims[2]),
+                        s(input_dims[3]),
+                        s(filter_dims[1]),
+                        s(filter_dims[2]),
+                        s(filter_dims[3]),
+                        s(output_dims[1]),
+                        s(output_dims[2]),
+                        s(output_dims[3]),
+                    ];
+                    args.extend(geometry);
+                    args.push(s(filters_per_group));
+                    args.extend(quantization);
+                    self.call(
+                        "berrylite::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8",
+                        "kernel",
+                        &args,
+                    );
+                }
+            }
+            QuantizedDepthWiseConv2DOptions {
+                fused_activation_min,
+                fused_activation_max,
+                padding_w,
+                padding_h,
+                stride_w,
+                stride_h,
+                depth_multiplier,
+                dilation_w_factor,
+                dilation_h_factor,
+                input_offset,
+                filter_offset,
+                output_offset,
+                per_channel_multiplier,
+                per_channel_shift,
+                effective_bias,
+                is_3x3_kernel,
+                ..
+            } => {
+                let filter_dims = self.dims(node.inputs[1]);
+                self.bind_input_output(node, 0)?;
+                let multiplier = self.derived(i, "MULTIPLIER", per_channel_multiplier);
+                let shift = self.derived(i, "SHIFT", per_channel_shift);
+                let effective_bias = self.derived(i, "EFFECTIVE_BIAS", effective_bias);
+                let quantization = [
+                    s(input_offset),
+                    s(filter_offset),
+                    s(output_offset),
+                    multiplier,
+                    shift,
+                    input_dims[0].to_string(),
+                    s(fused_activation_min),
+                    s(fused_activation_max),
+                ];
+                let mut args;
+                if is_3x3_kernel && !matches!(self.tensor(node.inputs[1]), I4Tensor(_)) {
+                    args = vec![
+                        "input".into(),
+                        self.constant(node.inputs[1])?,
+                        effective_bias,
+                        "output".into(),
+                        s(input_dims[1]),
+                        s(input_dims[2]),
+                        s(input_dims[3]),
+                        s(output_dims[1]),
+                        s(output_dims[2]),
+                        s(stride_w),
+                        s(padding_w),
+                        s(padding_h),
+                    ];
+                    args.extend(quantization);
+                    self.call(
+                        "berrylite::kernel::micro_operator::i8::depthwise_conv2d_i8::OpDepthWiseConv2DInt8",
+                        "kernel_3x3",
+                        &args,
+                    );
+                } else {
+                    args = vec![
+                        "input".into(),
+                        self.filter_data(node.inputs[1])?,
+                        effective_bias,
+                        "output".into(),
+                        s(input_dims[1]),
+                        s(input_dims[2]),
+                        s(input_dims[3]),
+                        s(filter_dims[1]),
+                        s(filter_dims[2]),
+                        s(filter_dims[3]),
+                        s(output_dims[1]),
+                        s(output_dims[2]),
+                        s(output_dims[3]),
+                        s(stride_w),
+                        s(stride_h),
+                        s(dilation_w_factor),
+                        s(dilation_h_factor),
+                        s(padding_w),
+                        s(padding_h),
+                        s(depth_multiplier),
+                    ];
+                    args.extend(quantization);
+                    self.call(
+                        "berrylite::kernel::micro_operator::i8::depthwise_conv2d_i8::OpDepthWiseConv2DInt8",
+                        "kernel",
+                        &args,
+                    );
+                }
+            }
+            QuantizedFullyConnectedOptions {
+                fused_activation_min,
+                fused_activation_max,
+                filter_offset,
+                output_offset,
+                per_channel_multiplier,
+                per_channel_shift,
+                filter_sparsity: None,
+                effective_bias,
+                ..
+            } => {
+                let filter_dims = self.dims(node.inputs[1]);
+                self.bind_input_output(node, 0)?;
+                let args = [
+                    "input".into(),
+                    self.filter_data(node.inputs[1])?,
+                    self.derived(i, "EFFECTIVE_BIAS", effective_bias),
+                    "output".into(),
+                    s(filter_offset),
+                    s(output_offset),
+                    filter_dims[filter_dims.len() - 2].to_string(),
+                    self.derived(i, "MULTIPLIER", per_channel_multiplier),
+                    self.derived(i, "SHIFT", per_channel_shift),
+                    filter_dims[filter_dims.len() - 1].to_string(),
+                    "1".into(),
+                    s(fused_activation_min),
+                    s(fused_activation_max),
+                ];
+                self.call(
+                    "berrylite::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8",
+                    "kernel",
+                    &args,
+                );
+            }
+            QuantizedMaxPool2DOptions {
+                fused_activation_min,
+                fused_activation_max,
+                padding_w,
+                padding_h,
+                stride_w,
+                stride_h,
+                filter_w,
+                filter_h,
+                ..
+            }
+            | QuantizedAvgPool2DOptions {
+                fused_activation_min,
+                fused_activation_max,
+                padding_w,
+                padding_h,
+                stride_w,
+                stride_h,
+                filter_w,
+                filter_h,
+                ..
+            } => {
+                self.bind_input_output(node, 0)?;
+                let args = [
+                    "input".into(),
+                    "output".into(),
+                    s(input_dims[1]),
+                    s(input_dims[2]),
+                    s(input_dims[3]),
+                    s(output_dims[1]),
+                    s(output_dims[2]),
+                    s(output_dims[3]),
+                    s(stride_w),
+                    s(stride_h),
+                    s(filter_w),
+                    s(filter_h),
+                    s(padding_w),
+                    s(padding_h),
+                    input_dims[0].to_string(),
+                    s(fused_activation_min),
+                    s(fused_activation_max),
+                ];
+                let import = if matches!(builtin_option, QuantizedMaxPool2DOptions { .. }) {
+                    "berrylite::kernel::micro_operator::i8::max_pool2d_i8::OpMaxPool2DInt8"
+                } else {
+                    "berrylite::kernel::micro_operator::i8::avg_pool2d_i8::OpAvgPool2DInt8"
+                };
+                self.call(import, "kernel", &args);
+            }
+            QuantizedSoftMaxOptions { exp_table } => {
+                self.bind_input_output(node, 0)?;
+                let args = [
+                    "input".into(),
+                    "output".into(),
+                    input_dims[input_dims.len() - 1].to_string(),
+                    self.derived(i, "EXP_TABLE", exp_table),
+                ];
+                self.call(
+                    "berrylite::kernel::micro_operator::i8::softmax_i8::OpSoftMaxInt8",
+                    "kernel",
+                    &args,
+                );
+            }
+            QuantizedReshapeOptions {} | ReshapeOptions {} => {
+                let input_elems: i32 = input_dims.iter().product();
+                let output_elems: i32 = output_dims.iter().product();
+                if input_elems != output_elems {
+                    return Err(InCompatibleShape(input_elems, output_elems));
+                }
+                let (input, output) = (node.inputs[0] as usize, node.outputs[0] as usize);
+                if self.offsets[&input] != self.offsets[&output] {
+                    self.bind_input_output(node, 0)?;
+                    writeln!(self.invoke, "    output.copy_from_slice(input);").unwrap();
+                } else {
+                    writeln!(
+                        self.invoke,
+                        "    // the output shares the buffer of the input"
+                    )
+                    .unwrap();
+                }
+            }
+            Conv2DOptions {
+                fused_activation_min,
+                fused_activation_max,
+                padding_w,
+                padding_h,
+                stride_w,
+                stride_h,
+                dilation_w_factor,
+                dilation_h_factor,
+                filter_sparsity: None,
+                im2col_buffer,
+                ..
+            } => {
+                let filter_dims = self.dims(node.inputs[1]);
+                let groups = input_dims[3] / filter_dims[3];
+                let filters_per_group = output_dims[3] / groups;
+                let use_gemm = groups == 1
+                    && (!im2col_buffer.is_empty()
+                        || (filter_dims[1] == 1
+                            && filter_dims[2] == 1
+                            && stride_w == 1
+                            && stride_h == 1));
+                self.bind_input_output(node, if use_gemm { im2col_buffer.len() } else { 0 })?;
+                let mut args = vec![
+                    "input".into(),
+                    self.constant(node.inputs[1])?,
+                    self.constant(node.inputs[2])?,
+                    "output".into(),
+                ];
+                if use_gemm {
+                    args.push(if im2col_buffer.is_empty() {
+                        "&[]".into()
+                    } else {
+                        "scratch".into()
+                    });
+                }
+                args.extend([s(input_dims[1]), s(input_dims[2]), s(input_dims[3])]);
+                args.extend([s(filter_dims[1]), s(filter_dims[2])]);
+                if !use_gemm {
+                    args.push(s(filter_dims[3]));
+                }
+                args.extend([s(output_dims[1]), s(output_dims[2]), s(output_dims[3])]);
+                args.extend([
+                    s(stride_w),
+                    s(stride_h),
+                    s(dilation_w_factor),
+                    s(dilation_h_factor),
+                    s(padding_w),
+                    s(padding_h),
+                ]);
+                if !use_gemm {
+                    args.push(s(filters_per_group));
+                }
+                args.extend([
+                    s(input_dims[0]),
+                    fused_activation_min.literal(),
+                    fused_activation_max.literal(),
+                ]);
+                let function = if use_gemm { "gemm_kernel" } else { "kernel" };
+                self.call(
+                    "berrylite::kernel::micro_operator::f32::conv2d::OpConv2D",
+                    function,
+                    &args,
+                );
+            }
+            DepthWiseConv2DOptions {
+                fused_activation_min,
+                fused_activation_max,
+                padding_w,
+                padding_h,
+                stride_w,
+                stride_h,
+                depth_multiplier,
+                dilation_w_factor,
+                dilation_h_factor,
+                ..
+            } => {
+                let filter_dims = self.dims(node.inputs[1]);
+                self.bind_input_output(node, 0)?;
+                let args = [
+                    "input".into(),
+                    self.constant(node.inputs[1])?,
+                    self.constant(node.inputs[2])?,
+                    "output".into(),
+                    s(input_dims[1]),
+                    s(input_dims[2]),
+                    s(input_dims[3]),
+                    s(filter_dims[1]),
+                    s(filter_dims[2]),
+                    s(filter_dims[3]),
+                    s(output_dims[1]),
+                    s(output_dims[2]),
+                    s(output_dims[3]),
+                    s(stride_w),
+                    s(stride_h),
+                    s(dilation_w_factor),
+                    s(dilation_h_factor),
+                    s(padding_w),
+                    s(padding_h),
+                    s(depth_multiplier),
+                    s(input_dims[0]),
+                    fused_activation_min.literal(),
+                    fused_activation_max.literal(),
+                ];
+                self.call(
+                    "berrylite::kernel::micro_operator::f32::depthwise_conv2d::OpDepthWiseConv2D",
+                    "kernel",
+                    &args,
+                );
+            }
+            FullyConnectedOptions {
+                fused_activation_min,
+                fused_activation_max,
+                filter_sparsity: None,
+                ..
+            } => {
+                let filter_dims = self.dims(node.inputs[1]);
+                self.bind_input_output(node, 0)?;
+                let bias = match node.inputs.get(2) {
+                    Some(&idx) if idx >= 0 => format!("Some({})", self.constant(idx)?),
+                    _ => "None".into(),
+                };
+                let args = [
+                    "input".into(),
+                    bias,
+                    self.constant(node.inputs[1])?,
+                    "output".into(),
+                    flat_skip_dims(output_dims, output_dims.len() - 1).to_string(),
+                    filter_dims[filter_dims.len() - 2].to_string(),
+                    filter_dims[filter_dims.len() - 1].to_string(),
+                    fused_activation_min.literal(),
+                    fused_activation_max.literal(),
+                ];
+                self.call(
+                    "berrylite::kernel::micro_operator::f32::fully_connected::OpFullyConnected",
+                    "kernel",
+                    &args,
+                );
+            }
+            MaxPool2DOptions {
+                fused_activation_min,
+                fused_activation_max,
+                padding_w,
+                padding_h,
+                stride_w,
+                stride_h,
+                filter_w,
+                filter_h,
+                ..
+            } => {
+                self.bind_input_output(node, 0)?;
+                let args = [
+                    "input".into(),
+                    "output".into(),
+                    s(input_dims[1]),
+                    s(input_dims[2]),
+                    s(input_dims[3]),
+                    s(output_dims[1]),
+                    s(output_dims[2]),
+                    s(output_dims[3]),
+                    s(stride_w),
+                    s(stride_h),
+                    s(filter_w),
+                    s(filter_h),
+                    s(padding_w),
+                    s(padding_h),
+                    s(input_dims[0]),
+                    fused_activation_min.literal(),
+                    fused_activation_max.literal(),
+                ];
+                self.call(
+                    "berrylite::kernel::micro_operator::f32::max_pool2d::OpMaxPool2D",
+                    "kernel",
+                    &args,
+                );
+            }
+            SoftMaxOptions { beta } => {
+                self.bind_input_output(node, 0)?;
+                let depth = input_dims[input_dims.len() - 1];
+                let outer_size: i32 = input_dims[..input_dims.len() - 1].iter().product();
+                let args = [
+                    "input".into(),
+                    "output".into(),
+                    s(outer_size),
+                    s(depth),
+                    beta.literal(),
+                ];
+                self.call(
+                    "berrylite::kernel::micro_operator::f32::softmax::OpSoftMax",
+                    "kernel",
+                    &args,
+                );
+            }
+            _ => return Err(NotSupportedCodegen(op_code)),
+        }
+        Ok(())
+    }
+}
@@ -21,8 +21,16 @@ pub fn calculate_fused_activation_range<T: ArrayElem<T>>(
     op: i32,
 ) -> Result<(T /* activtion_min */, T /* activation_max */)> {
     match op {
-        0 => Ok((T::MIN, T::MAX)),
-        1 => Ok((FromPrimitive::from_f32(0.).unwrap(), T::MAX)),
+        0 /* None */ => Ok((T::MIN, T::MAX)),
+        1 /* Relu */ => Ok((FromPrimitive::from_f32(0.).unwrap(), T::MAX)),
+        2 /* ReluN1To1 */ => Ok((
+            FromPrimitive::from_f32(-1.).unwrap(),
+            FromPrimitive::from_f32(1.).unwrap(),
+        )),
+        3 /* Relu6 */ => Ok((
+            FromPrimitive::from_f32(0.).unwrap(),
+            FromPrimitive::from_f32(6.).unwrap(),
+        )),
         _ => Err(BLiteError::NotFoundFusedActivation(op)),
     }
 }
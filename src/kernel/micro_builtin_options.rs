@@ -1,6 +1,8 @@
+use core::cell::Cell;
 use core::fmt::Debug;
 
 use crate::micro_array::ArrayElem;
+use crate::micro_sparsity::BLiteSparsity;
 
 #[derive(Debug, Clone, Copy)]
 pub enum BLiteBuiltinOption<'a, T: Debug + ArrayElem<T>> {
@@ -8,6 +10,8 @@ pub enum BLiteBuiltinOption<'a, T: Debug + ArrayElem<T>> {
         op_code: i32,
         fused_activation_min: T,
         fused_activation_max: T,
+        // the compressed filter taken for the sparse kernel
+        filter_sparsity: Option<BLiteSparsity<'a>>,
     },
     Conv2DOptions {
         op_code: i32, // activation operator code
@@ -22,6 +26,10 @@ pub enum BLiteBuiltinOption<'a, T: Debug + ArrayElem<T>> {
         stride_h: i32,
         dilation_w_factor: i32,
         dilation_h_factor: i32,
+        // the compressed filter taken for the sparse kernel
+        filter_sparsity: Option<BLiteSparsity<'a>>,
+        // the scratch of im2col, which is empty if the GEMM kernel does not need it
+        im2col_buffer: &'a [Cell<T>],
     },
     DepthWiseConv2DOptions {
         op_code: i32,
@@ -56,6 +64,157 @@ pub enum BLiteBuiltinOption<'a, T: Debug + ArrayElem<T>> {
     SoftMaxOptions {
         beta: f32,
     },
+    ReluOptions {
+        fused_activation_min: T,
+        fused_activation_max: T,
+    },
+    LeakyReluOptions {
+        alpha: f32,
+    },
+    PReluOptions {},
+    HardSwishOptions {},
+    LogisticOptions {},
+    TanhOptions {},
+    TransposeOptions {},
+    StridedSliceOptions {
+        begin_mask: i32,
+        end_mask: i32,
+        ellipsis_mask: i32,
+        new_axis_mask: i32,
+        shrink_axis_mask: i32,
+        offset: bool, // end is relative to begin
+    },
+    SliceOptions {},
+    GatherOptions {
+        axis: i32,
+        batch_dims: i32,
+    },
+    GatherNdOptions {},
+    PackOptions {
+        axis: i32,
+    },
+    UnpackOptions {
+        axis: i32,
+    },
+    SqueezeOptions {},
+    ExpandDimsOptions {},
+    /// constant_value is the zero point of the output for the quantized tensors
+    PadOptions {
+        constant_value: T,
+    },
+    ResizeBilinearOptions {
+        align_corners: bool,
+        half_pixel_centers: bool,
+        output_height: i32,
+        output_width: i32,
+    },
+    ResizeNearestNeighborOptions {
+        align_corners: bool,
+        half_pixel_centers: bool,
+        output_height: i32,
+        output_width: i32,
+    },
+    TransposeConvOptions {
+        op_code: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+        padding: usize, // 0: same, 1: valid
+        padding_w: i32,
+        padding_h: i32,
+        padding_w_offset: i32,
+        padding_h_offset: i32,
+        stride_w: i32,
+        stride_h: i32,
+    },
+    AddOptions {
+        op_code: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+    },
+    DetectionPostprocessOptions {
+        max_detections: i32,
+        max_classes_per_detection: i32,
+        detections_per_class: i32,
+        num_classes: i32,
+        use_regular_nms: bool,
+        nms_score_threshold: f32,
+        nms_iou_threshold: f32,
+        y_scale: f32,
+        x_scale: f32,
+        h_scale: f32,
+        w_scale: f32,
+        // scratch buffers of the decoded boxes and the non-maximum suppression,
+        // which are allocated in the arena
+        decoded_boxes: &'a [Cell<f32>],
+        candidate_scores: &'a [Cell<f32>],
+        class_indices: &'a [Cell<i32>],
+        selected: &'a [Cell<i32>],
+        top_scores: &'a [Cell<f32>],
+        top_indices: &'a [Cell<i32>],
+    },
+    /// Hybrid options are for int8 weights with float activations,
+    /// whose inputs are quantized on the fly into the scratch buffers allocated in the arena
+    HybridFullyConnectedOptions {
+        op_code: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+        asymmetric_quantize_inputs: bool,
+        filter_scales: &'a [f32],
+        quantized_input: &'a [Cell<i8>],
+        scaling_factors: &'a [Cell<f32>],
+        input_offsets: &'a [Cell<i32>],
+    },
+    HybridConv2DOptions {
+        op_code: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+        padding: usize, // 0: same, 1: valid
+        padding_w: i32,
+        padding_h: i32,
+        padding_w_offset: i32,
+        padding_h_offset: i32,
+        stride_w: i32,
+        stride_h: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        filter_scales: &'a [f32],
+        quantized_input: &'a [Cell<i8>],
+        scaling_factors: &'a [Cell<f32>],
+        input_offsets: &'a [Cell<i32>],
+    },
+    HybridDepthWiseConv2DOptions {
+        op_code: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+        padding: usize, // 0: same, 1: valid
+        padding_w: i32,
+        padding_h: i32,
+        padding_w_offset: i32,
+        padding_h_offset: i32,
+        stride_w: i32,
+        stride_h: i32,
+        depth_multiplier: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        filter_scales: &'a [f32],
+        quantized_input: &'a [Cell<i8>],
+        scaling_factors: &'a [Cell<f32>],
+        input_offsets: &'a [Cell<i32>],
+    },
+    /// gates holds the input, forget, cell and output gates of a batch in this order,
+    /// and hidden holds the output of a batch before the projection
+    HybridUnidirectionalSequenceLSTMOptions {
+        op_code: i32,
+        cell_clip: f32,
+        proj_clip: f32,
+        time_major: bool,
+        asymmetric_quantize_inputs: bool,
+        quantized_input: &'a [Cell<i8>],
+        quantized_output_state: &'a [Cell<i8>],
+        quantized_hidden: &'a [Cell<i8>],
+        gates: &'a [Cell<f32>],
+        hidden: &'a [Cell<f32>],
+    },
     /// input/filter/output_offset are negative values of input/filter/output_zero_point
     QuantizedFullyConnectedOptions {
         op_code: i32,
@@ -65,10 +224,12 @@ pub enum BLiteBuiltinOption<'a, T: Debug + ArrayElem<T>> {
         input_offset: i32,
         filter_offset: i32,
         output_offset: i32,
-        output_multiplier: i32,
-        output_shift: i32,
-        // output_activation_min: i32,
-        // outpu_activation_max: i32,
+        per_channel_multiplier: &'a [i32],
+        per_channel_shift: &'a [i32],
+        // the compressed filter taken for the sparse kernel
+        filter_sparsity: Option<BLiteSparsity<'a>>,
+        // the bias into which the input offset times the filter sums are folded
+        effective_bias: &'a [i32],
     },
     QuantizedConv2DOptions {
         op_code: i32, // activation operator code
@@ -89,6 +250,12 @@ pub enum BLiteBuiltinOption<'a, T: Debug + ArrayElem<T>> {
         output_offset: i32,
         per_channel_multiplier: &'a [i32],
         per_channel_shift: &'a [i32],
+        // the compressed filter taken for the sparse kernel
+        filter_sparsity: Option<BLiteSparsity<'a>>,
+        // the scratch of im2col, which is empty if the GEMM kernel does not need it
+        im2col_buffer: &'a [Cell<T>],
+        // the bias into which the input offset times the filter sums are folded
+        effective_bias: &'a [i32],
     },
     QuantizedDepthWiseConv2DOptions {
         op_code: i32,
@@ -110,6 +277,10 @@ pub enum BLiteBuiltinOption<'a, T: Debug + ArrayElem<T>> {
         output_offset: i32,
         per_channel_multiplier: &'a [i32],
         per_channel_shift: &'a [i32],
+        // the bias into which the input offset times the filter sums are folded
+        effective_bias: &'a [i32],
+        // the 3x3 filter with the stride 1 or 2 and the depth multiplier 1 is evaluated by the specialised kernel
+        is_3x3_kernel: bool,
     },
     QuantizedMaxPool2DOptions {
         op_code: i32,
@@ -140,12 +311,106 @@ pub enum BLiteBuiltinOption<'a, T: Debug + ArrayElem<T>> {
         filter_h: i32,
     },
     QuantizedReshapeOptions {},
+    /// exp_table[-diff] holds exp(beta * input_scale * diff) as a Q0.31 fixed-point number
+    /// for every difference diff between an input and the maximum of its row
     QuantizedSoftMaxOptions {
-        beta: f32,
-        input_scale: f32,
+        exp_table: &'a [i32],
+    },
+    /// input_offset/output_offset are input/output zero points, not negated
+    QuantizedReluOptions {
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+        input_offset: i32,
+        output_offset: i32,
+        output_multiplier: i32,
+        output_shift: i32,
+    },
+    QuantizedLeakyReluOptions {
+        input_offset: i32,
+        output_offset: i32,
+        output_multiplier_alpha: i32,
+        output_shift_alpha: i32,
+        output_multiplier_identity: i32,
+        output_shift_identity: i32,
+    },
+    /// input_offset/alpha_offset are negative values of input/alpha_zero_point
+    QuantizedPReluOptions {
+        input_offset: i32,
+        alpha_offset: i32,
+        output_offset: i32,
+        output_multiplier_1: i32,
+        output_shift_1: i32,
+        output_multiplier_2: i32,
+        output_shift_2: i32,
+    },
+    QuantizedHardSwishOptions {
         input_zero_point: i32,
-        output_scale: f32,
         output_zero_point: i32,
+        reluish_multiplier_fixedpoint_int16: i16,
+        reluish_multiplier_exponent: i32,
+        output_multiplier_fixedpoint_int16: i16,
+        output_multiplier_exponent: i32,
+    },
+    /// table holds the output for every input value, indexed by input - T::MIN
+    QuantizedLogisticOptions {
+        table: &'a [T],
+    },
+    QuantizedTanhOptions {
+        table: &'a [T],
+    },
+    QuantizedTransposeConvOptions {
+        op_code: i32,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+        padding: usize, // 0: same, 1: valid
+        padding_w: i32,
+        padding_h: i32,
+        padding_w_offset: i32,
+        padding_h_offset: i32,
+        stride_w: i32,
+        stride_h: i32,
+        // for quantization parameters
+        input_offset: i32,
+        output_offset: i32,
+        per_channel_multiplier: &'a [i32],
+        per_channel_shift: &'a [i32],
+        // int32 accumulators of the output, which are allocated in the arena
+        scratch_buffer: &'a [Cell<i32>],
+    },
+    /// input1/input2_offset are negative values of input1/input2_zero_point,
+    /// and the inputs are shifted left by left_shift before rescaling
+    QuantizedAddOptions {
+        op_code: i32,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+        left_shift: i32,
+        input1_offset: i32,
+        input2_offset: i32,
+        output_offset: i32,
+        input1_multiplier: i32,
+        input1_shift: i32,
+        input2_multiplier: i32,
+        input2_shift: i32,
+        output_multiplier: i32,
+        output_shift: i32,
+    },
+    /// exp_lut and one_over_one_plus_x_lut are the int16 lookup tables of
+    /// exp(x) on [-10, 0] and 1 / (1 + x) on [0, 1]
+    QuantizedSoftMaxInt16Options {
+        input_multiplier: i32,
+        input_left_shift: i32,
+        exp_lut: &'a [i16],
+        one_over_one_plus_x_lut: &'a [i16],
+    },
+    /// input_multiplier is 0 when the input scale is a power of two
+    QuantizedLogisticInt16Options {
+        input_multiplier: i32,
+        input_left_shift: i32,
+        sigmoid_table: &'a [u16],
+    },
+    /// the raw custom_options of a custom operator
+    CustomOptions {
+        custom_options: &'a [u8],
     },
     NotInitialize,
 }
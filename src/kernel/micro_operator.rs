@@ -1,16 +1,27 @@
 pub mod f32;
+pub mod generic;
+pub mod i16;
 pub mod i8;
+pub mod u8;
 
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::ArrayElem;
 use crate::micro_errors::Result;
-use crate::micro_registration::BLiteRegistration;
+use crate::micro_registration::{BLiteEval, BLiteInPlace, BLiteRegistration};
+use crate::micro_slice::alloc_array_mut;
 use crate::micro_tensor::BLiteTensor;
 use crate::tflite_schema_generated::tflite::Operator;
 use core::fmt::Debug;
 
 use super::micro_builtin_options::BLiteBuiltinOption;
 
+/// The signature of the parser of operators, which builds the options from the model
+pub type BLiteParser<'a, T, S> = fn(
+    allocator: &mut S,
+    op: Operator,
+    tensors: &mut [BLiteTensor<'a, T>],
+) -> Result<BLiteBuiltinOption<'a, T>>;
+
 #[derive(Clone, Copy)]
 pub struct BLiteOperator<'a, T, S>
 where
@@ -18,11 +29,10 @@ where
     S: ArenaAllocator,
 {
     registration: BLiteRegistration<'a, T>,
-    parser: fn(
-        allocator: &mut S,
-        op: Operator,
-        tensors: &mut [BLiteTensor<'a, T>],
-    ) -> Result<BLiteBuiltinOption<'a, T>>,
+    parser: BLiteParser<'a, T, S>,
+    // the range of `OperatorCode.version` which the kernel supports
+    min_version: i32,
+    max_version: i32,
 }
 
 impl<'a, T, S> BLiteOperator<'a, T, S>
@@ -30,21 +40,81 @@ where
     T: ArrayElem<T>,
     S: ArenaAllocator,
 {
+    pub fn new(
+        registration: BLiteRegistration<'a, T>,
+        parser: BLiteParser<'a, T, S>,
+        min_version: i32,
+        max_version: i32,
+    ) -> Self {
+        Self {
+            registration,
+            parser,
+            min_version,
+            max_version,
+        }
+    }
+
+    /// Creates an operator resolved by `OperatorCode.custom_code` of the model,
+    /// which accepts any versions unless `with_versions` restricts them. Its eval receives the raw `custom_options` bytes as `CustomOptions`,
+    /// which are usually a flexbuffer (see `kernel::utils::flexbuffer`).
+    pub fn custom(custom_code: &'static str, eval: BLiteEval<'a, T>) -> Self {
+        Self {
+            registration: BLiteRegistration::new_custom(
+                custom_code,
+                eval,
+                BLiteBuiltinOption::NotInitialize,
+            ),
+            parser: Self::custom_options_parser,
+            min_version: 1,
+            max_version: i32::MAX,
+        }
+    }
+
+    pub fn with_versions(self, min_version: i32, max_version: i32) -> Self {
+        Self {
+            min_version,
+            max_version,
+            ..self
+        }
+    }
+
+    /// Declares how the output may share the buffer of the inputs, e.g. for the elementwise custom operators
+    pub fn with_in_place(self, in_place: BLiteInPlace) -> Self {
+        Self {
+            registration: self.registration.with_in_place(in_place),
+            ..self
+        }
+    }
+
+    pub fn supports_version(&self, version: i32) -> bool {
+        (self.min_version..=self.max_version).contains(&version)
+    }
+
+    /// Copies the raw `custom_options` into the arena, which is empty when the operator has none
+    pub fn custom_options_parser(
+        allocator: &mut S,
+        op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let bytes = op.custom_options().map(|v| v.bytes()).unwrap_or(&[]);
+        let custom_options = unsafe { alloc_array_mut::<u8>(allocator, bytes.len()) }?;
+        custom_options.copy_from_slice(bytes);
+        Ok(BLiteBuiltinOption::CustomOptions { custom_options })
+    }
+
     pub fn get_op_code(&self) -> i32 {
         self.registration.op_code
     }
 
+    pub fn get_custom_code(&self) -> Option<&'static str> {
+        self.registration.custom_code
+    }
+
     pub fn get_registration(&self) -> BLiteRegistration<'a, T> {
         self.registration
     }
 
-    pub fn get_parser(
-        &self,
-    ) -> fn(
-        allocator: &mut S,
-        op: Operator,
-        tensors: &mut [BLiteTensor<'a, T>],
-    ) -> Result<BLiteBuiltinOption<'a, T>> {
+    pub fn get_parser(&self) -> BLiteParser<'a, T, S> {
         self.parser
     }
 }
@@ -53,8 +123,8 @@ impl<'a, T: ArrayElem<T>, S: ArenaAllocator> Debug for BLiteOperator<'a, T, S> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "Operator {{ registration: {:?}, parse:...}}",
-            self.registration,
+            "Operator {{ registration: {:?}, parse:..., versions: {}..={} }}",
+            self.registration, self.min_version, self.max_version,
         )?;
         Ok(())
     }
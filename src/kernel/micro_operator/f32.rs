@@ -1,9 +1,23 @@
+pub mod add;
 pub mod conv2d;
+pub mod conv2d_hybrid;
 pub mod depthwise_conv2d;
+pub mod depthwise_conv2d_hybrid;
+pub mod detection_postprocess;
 pub mod fully_connected;
+pub mod fully_connected_hybrid;
+pub mod hard_swish;
+pub mod leaky_relu;
+pub mod logistic;
 pub mod max_pool2d;
+pub mod prelu;
+pub mod relu;
 pub mod reshape;
+pub mod resize_bilinear;
 pub mod softmax;
+pub mod tanh;
+pub mod transpose_conv;
+pub mod unidirectional_sequence_lstm_hybrid;
 
 #[cfg(test)]
 mod fully_connected_test;
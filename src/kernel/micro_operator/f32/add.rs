@@ -0,0 +1,238 @@
+use crate::kernel::micro_activation::{activation_with_min_max, calculate_fused_activation_range};
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::broadcast::broadcast_index;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpAdd {}
+
+impl OpAdd {
+    const OPCODE: i32 = 0;
+
+    pub fn add<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_add_options();
+        let mut op_code = -1;
+        if let Some(builtin_option) = builtin_option {
+            op_code = builtin_option.fused_activation_function().0 as i32;
+        }
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range(op_code)?;
+        Ok(BLiteBuiltinOption::AddOptions {
+            op_code,
+            fused_activation_min,
+            fused_activation_max,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input1 = node.inputs[0] as usize;
+        let idx_input2 = node.inputs[1] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let AddOptions {
+            op_code: _,
+            fused_activation_min,
+            fused_activation_max,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites one of the inputs if the memory planner gives them the same buffer
+        for (idx_input, idx_other) in [(idx_input1, idx_input2), (idx_input2, idx_input1)] {
+            if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+                let other = tensors[idx_other]._t()?.borrow();
+                let mut output = tensors[idx_output]._t()?.borrow_mut();
+                let output_dims = output.dims;
+                return Self::kernel_in_place(
+                    output.data,
+                    output_dims,
+                    other.data,
+                    other.dims,
+                    fused_activation_min,
+                    fused_activation_max,
+                );
+            }
+        }
+        let input1 = tensors[idx_input1]._t()?.borrow();
+        let input2 = tensors[idx_input2]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+        let output_dims = output.dims;
+
+        Self::kernel(
+            input1.data,
+            input1.dims,
+            input2.data,
+            input2.dims,
+            output.data,
+            output_dims,
+            fused_activation_min,
+            fused_activation_max,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input1_data: &[T],
+        input1_dims: &[i32],
+        input2_data: &[T],
+        input2_dims: &[i32],
+        output_data: &mut [T],
+        output_dims: &[i32],
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        for (i, output_v) in output_data.iter_mut().enumerate() {
+            let input1_v = input1_data[broadcast_index(i, output_dims, input1_dims)];
+            let input2_v = input2_data[broadcast_index(i, output_dims, input2_dims)];
+            *output_v = activation_with_min_max(
+                input1_v + input2_v,
+                fused_activation_min,
+                fused_activation_max,
+            );
+        }
+        Ok(())
+    }
+
+    /// `data` holds the input which is not broadcast, and is overwritten by the output
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(
+        data: &mut [T],
+        output_dims: &[i32],
+        other_data: &[T],
+        other_dims: &[i32],
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        for (i, v) in data.iter_mut().enumerate() {
+            let other_v = other_data[broadcast_index(i, output_dims, other_dims)];
+            *v = activation_with_min_max(*v + other_v, fused_activation_min, fused_activation_max);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpAdd;
+    use crate::kernel::micro_activation::calculate_fused_activation_range;
+
+    #[test]
+    fn test_add_broadcast() {
+        let input1 = [-3.5f32, -0.25, 0., 1.5, 2.75, 8.];
+        let input2 = [-1.25f32, 4.5];
+
+        // (fused activation, expected range)
+        for (op_code, (min, max)) in [
+            (0, (f32::MIN, f32::MAX)),
+            (1, (0., f32::MAX)),
+            (2, (-1., 1.)),
+            (3, (0., 6.)),
+        ] {
+            let (fused_activation_min, fused_activation_max) =
+                calculate_fused_activation_range::<f32>(op_code).unwrap();
+            let mut output = [0f32; 6];
+            OpAdd::kernel(
+                &input1,
+                &[3, 2],
+                &input2,
+                &[2],
+                &mut output,
+                &[3, 2],
+                fused_activation_min,
+                fused_activation_max,
+            )
+            .unwrap();
+
+            for (i, &output_v) in output.iter().enumerate() {
+                let expected = (input1[i] + input2[i % 2]).clamp(min, max);
+                assert_eq!(expected, output_v, "op_code {op_code}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_in_place() {
+        let input1 = [-3.5f32, -0.25, 0., 1.5, 2.75, 8.];
+        let input2 = [-1.25f32, 4.5, 0.5, -2., 3.25, -7.75];
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range::<f32>(3).unwrap();
+
+        let mut expected = [0f32; 6];
+        OpAdd::kernel(
+            &input1,
+            &[6],
+            &input2,
+            &[6],
+            &mut expected,
+            &[6],
+            fused_activation_min,
+            fused_activation_max,
+        )
+        .unwrap();
+
+        // the output overwrites either of the inputs
+        for (data, other) in [(input1, input2), (input2, input1)] {
+            let mut data = data;
+            OpAdd::kernel_in_place(
+                &mut data,
+                &[6],
+                &other,
+                &[6],
+                fused_activation_min,
+                fused_activation_max,
+            )
+            .unwrap();
+            assert_eq!(data, expected);
+        }
+
+        // the broadcast input is read as the other one
+        let mut data = input1;
+        OpAdd::kernel_in_place(
+            &mut data,
+            &[3, 2],
+            &input2[..2],
+            &[2],
+            fused_activation_min,
+            fused_activation_max,
+        )
+        .unwrap();
+        for (i, &v) in data.iter().enumerate() {
+            assert_eq!((input1[i] + input2[i % 2]).clamp(0., 6.), v);
+        }
+    }
+}
@@ -1,16 +1,21 @@
 use crate::kernel::micro_activation::{activation_with_min_max, calculate_fused_activation_range};
 use crate::kernel::micro_builtin_options::BLiteBuiltinOption::Conv2DOptions;
 use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
-use crate::kernel::utils::padding::compute_padding_height_width;
+use crate::kernel::utils::gemm::{
+    alloc_im2col_buffer, gemm_float, im2col, is_pointwise, Im2ColGeometry, IM2COL_TILE_ROWS,
+};
+use crate::kernel::utils::padding::{compute_padding_height_width, for_each_output_rows};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::ArrayElem;
-use crate::micro_context::BLiteContext;
+use crate::micro_context::{BLiteContext, ParallelElem};
 use crate::micro_errors::BLiteError::*;
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
 use crate::micro_registration::BLiteRegistration;
+use crate::micro_sparsity::BLiteSparsity;
 use crate::micro_tensor::BLiteTensor;
 use crate::tflite_schema_generated::tflite::Operator;
+use core::cell::Cell;
 use core::fmt::Debug;
 
 use crate::kernel::micro_operator::BLiteOperator;
@@ -21,15 +26,18 @@ pub struct OpConv2D {}
 impl OpConv2D {
     const OPCODE: i32 = 3;
 
-    pub fn conv2d<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+    pub fn conv2d<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>() -> BLiteOperator<'a, T, S>
+    {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
         }
     }
 
     pub fn parser<'a, T: ArrayElem<T>>(
-        _allocator: &mut impl ArenaAllocator,
+        allocator: &mut impl ArenaAllocator,
         op: Operator,
         tensors: &mut [BLiteTensor<'a, T>],
     ) -> Result<BLiteBuiltinOption<'a, T>> {
@@ -72,6 +80,32 @@ impl OpConv2D {
                 output_h,
                 output_w,
             );
+
+        // the sparse weights are kept compressed and evaluated by the sparse kernel
+        let filter_sparsity = tensors[filter_idx]
+            ._t()?
+            .borrow_mut()
+            .sparsity
+            .take()
+            .copied();
+
+        // the GEMM kernel unfolds the input by im2col except for the 1x1 convolutions,
+        // and the grouped or sparse filters are evaluated by the reference kernels
+        let input_depth = tensors[input_idx]._t()?.borrow().dims[3];
+        let filter_depth = tensors[filter_idx]._t()?.borrow().dims[3];
+        let im2col_buffer = if filter_sparsity.is_none()
+            && input_depth == filter_depth
+            && !is_pointwise(filter_h, filter_w, stride_w, stride_h)
+        {
+            alloc_im2col_buffer(
+                allocator,
+                (output_h * output_w) as usize,
+                (filter_h * filter_w * filter_depth) as usize,
+            )?
+        } else {
+            &[]
+        };
+
         Ok(BLiteBuiltinOption::Conv2DOptions {
             op_code,
             fused_activation_min,
@@ -85,15 +119,17 @@ impl OpConv2D {
             stride_h,
             dilation_w_factor,
             dilation_h_factor,
+            filter_sparsity,
+            im2col_buffer,
         })
     }
 
-    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
         BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
     }
 
-    pub fn eval<'a, T: ArrayElem<T>>(
-        _context: &BLiteContext,
+    pub fn eval<'a, T: ArrayElem<T> + ParallelElem>(
+        context: &BLiteContext,
         tensors: &'a mut [BLiteTensor<'a, T>],
         node: &BLiteNode<'a>,
         builtin_option: BLiteBuiltinOption<T>,
@@ -119,8 +155,6 @@ impl OpConv2D {
         let output_width = output.dims[2];
         let output_depth = output.dims[3];
 
-        // TODO: What is this?
-        let batches = input.dims[0]; // TODO: min(input.dims[0], output.dims[0])
         let groups = input_depth / filter_input_depth;
         let filters_per_group = output_depth / groups;
 
@@ -137,35 +171,122 @@ impl OpConv2D {
             padding_h,
             padding_w_offset: _,
             padding_h_offset: _,
+            filter_sparsity,
+            im2col_buffer,
         } = builtin_option
         else {
             return Err(NotCompatibleOption);
         };
 
-        Self::kernel(
-            input.data,
-            filter.data,
-            bias.data,
+        let input_data = &*input.data;
+        let filter_data = &*filter.data;
+        let bias_data = &*bias.data;
+        let use_gemm = groups == 1
+            && (!im2col_buffer.is_empty()
+                || is_pointwise(filter_height, filter_width, stride_w, stride_h));
+
+        // the output rows are split across the worker pool, and each part is evaluated as a single batch
+        let output_row_len = (output_width * output_depth) as usize;
+        context.parallel_for(
             output.data,
-            input_height,
-            input_width,
-            input_depth,
-            filter_height,
-            filter_width,
-            filter_input_depth,
-            output_height,
-            output_width,
-            output_depth,
-            stride_w,
-            stride_h,
-            dilation_w_factor,
-            dilation_h_factor,
-            padding_w,
-            padding_h,
-            filters_per_group,
-            batches,
-            fused_activation_min,
-            fused_activation_max,
+            output_row_len,
+            im2col_buffer,
+            |units, output_chunk, im2col_buffer| {
+                for_each_output_rows(
+                    input_data,
+                    output_chunk,
+                    units,
+                    input_height,
+                    (input_width * input_depth) as usize,
+                    output_height,
+                    output_row_len,
+                    stride_h,
+                    padding_h,
+                    |input_data, output_data, rows| {
+                        if let Some(sparsity) = &filter_sparsity {
+                            return Self::sparse_kernel(
+                                input_data,
+                                filter_data,
+                                sparsity,
+                                bias_data,
+                                output_data,
+                                rows.input_height,
+                                input_width,
+                                input_depth,
+                                filter_height,
+                                filter_width,
+                                filter_input_depth,
+                                rows.output_height,
+                                output_width,
+                                output_depth,
+                                stride_w,
+                                stride_h,
+                                dilation_w_factor,
+                                dilation_h_factor,
+                                padding_w,
+                                rows.padding_h,
+                                filters_per_group,
+                                1,
+                                fused_activation_min,
+                                fused_activation_max,
+                            );
+                        }
+
+                        if use_gemm {
+                            return Self::gemm_kernel(
+                                input_data,
+                                filter_data,
+                                bias_data,
+                                output_data,
+                                im2col_buffer,
+                                rows.input_height,
+                                input_width,
+                                input_depth,
+                                filter_height,
+                                filter_width,
+                                rows.output_height,
+                                output_width,
+                                output_depth,
+                                stride_w,
+                                stride_h,
+                                dilation_w_factor,
+                                dilation_h_factor,
+                                padding_w,
+                                rows.padding_h,
+                                1,
+                                fused_activation_min,
+                                fused_activation_max,
+                            );
+                        }
+
+                        Self::kernel(
+                            input_data,
+                            filter_data,
+                            bias_data,
+                            output_data,
+                            rows.input_height,
+                            input_width,
+                            input_depth,
+                            filter_height,
+                            filter_width,
+                            filter_input_depth,
+                            rows.output_height,
+                            output_width,
+                            output_depth,
+                            stride_w,
+                            stride_h,
+                            dilation_w_factor,
+                            dilation_h_factor,
+                            padding_w,
+                            rows.padding_h,
+                            filters_per_group,
+                            1,
+                            fused_activation_min,
+                            fused_activation_max,
+                        )
+                    },
+                )
+            },
         )
     }
 
@@ -268,8 +389,352 @@ impl OpConv2D {
         Ok(())
     }
 
+    /// This computations is corresponded to the im2col and the GEMM of the optimized kernels.
+    /// The 1x1 convolutions read the input as the rows of the GEMM without im2col.
+    pub fn gemm_kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: &[T],
+        bias_data: &[T],
+        output_data: &mut [T],
+        im2col_buffer: &[Cell<T>],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        output_height: i32,
+        output_width: i32,
+        output_depth: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        padding_w: i32,
+        padding_h: i32,
+        //
+        batches: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        let batches = batches as usize;
+        let pixels = (output_height * output_width) as usize;
+        let output_depth = output_depth as usize;
+        let depth = (filter_height * filter_width * input_depth) as usize;
+        let mut store = |first_pixel: usize, row: usize, out_channel: usize, acc: T| {
+            output_data[(first_pixel + row) * output_depth + out_channel] = activation_with_min_max(
+                acc + bias_data[out_channel],
+                fused_activation_min,
+                fused_activation_max,
+            );
+        };
+
+        if is_pointwise(filter_height, filter_width, stride_w, stride_h) {
+            gemm_float(
+                input_data,
+                filter_data,
+                batches * pixels,
+                output_depth,
+                depth,
+                |row, out_channel, acc| store(0, row, out_channel, acc),
+            );
+            return Ok(());
+        }
+
+        let tile_rows = IM2COL_TILE_ROWS.min(im2col_buffer.len() / depth);
+        let geometry = Im2ColGeometry {
+            input_height,
+            input_width,
+            input_depth,
+            filter_height,
+            filter_width,
+            output_width,
+            stride_w,
+            stride_h,
+            dilation_w_factor,
+            dilation_h_factor,
+            padding_w,
+            padding_h,
+        };
+        for batch in 0..batches {
+            let mut first_pixel = 0;
+            while first_pixel < pixels {
+                let rows = tile_rows.min(pixels - first_pixel);
+                im2col(
+                    input_data,
+                    im2col_buffer,
+                    &geometry,
+                    batch,
+                    first_pixel,
+                    rows,
+                    Default::default(),
+                );
+                gemm_float(
+                    im2col_buffer,
+                    filter_data,
+                    rows,
+                    output_depth,
+                    depth,
+                    |row, out_channel, acc| {
+                        store(batch * pixels + first_pixel, row, out_channel, acc)
+                    },
+                );
+                first_pixel += rows;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The filter holds only the nonzero blocks along the input channels of each
+    /// (out_channel, filter_y, filter_x), and the zero blocks are skipped
+    pub fn sparse_kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: &[T],
+        filter_sparsity: &BLiteSparsity,
+        bias_data: &[T],
+        output_data: &mut [T],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        filter_input_depth: i32,
+        output_height: i32,
+        output_width: i32,
+        output_depth: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        padding_w: i32,
+        padding_h: i32,
+        filters_per_group: i32,
+        //
+        batches: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        let block_size = filter_sparsity.block_size;
+        for batch in 0..batches {
+            for out_y in 0..output_height {
+                let in_y_origin = (out_y * stride_h) - padding_h;
+                for out_x in 0..output_width {
+                    let in_x_origin = (out_x * stride_w) - padding_w;
+                    for out_channel in 0..output_depth {
+                        let group = out_channel / filters_per_group;
+                        let mut total: T = Default::default();
+                        for filter_y in 0..filter_height {
+                            let in_y = in_y_origin + dilation_h_factor * filter_y;
+                            for filter_x in 0..filter_width {
+                                let in_x = in_x_origin + dilation_w_factor * filter_x;
+                                let is_point_inside_image = (in_x >= 0)
+                                    && (in_x < input_width)
+                                    && (in_y >= 0)
+                                    && (in_y < input_height);
+                                if !is_point_inside_image {
+                                    continue;
+                                }
+
+                                let input_v_idx = Self::offset(
+                                    input_height,
+                                    input_width,
+                                    input_depth,
+                                    batch,
+                                    in_y,
+                                    in_x,
+                                    group * filter_input_depth,
+                                ) as usize;
+                                let row = (out_channel * filter_height + filter_y) * filter_width
+                                    + filter_x;
+                                for (in_channel, offset) in filter_sparsity.row_blocks(row as usize)
+                                {
+                                    for i in 0..block_size {
+                                        total += input_data[input_v_idx + in_channel + i]
+                                            * filter_data[offset + i];
+                                    }
+                                }
+                            }
+                        }
+                        let bias_v = bias_data[out_channel as usize];
+                        let output_v_idx = Self::offset(
+                            output_height,
+                            output_width,
+                            output_depth,
+                            batch,
+                            out_y,
+                            out_x,
+                            out_channel,
+                        );
+
+                        total += bias_v;
+                        total = activation_with_min_max(
+                            total,
+                            fused_activation_min,
+                            fused_activation_max,
+                        );
+                        output_data[output_v_idx as usize] = total;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[inline(always)]
     fn offset(h: i32, w: i32, d: i32, i0: i32, i1: i32, i2: i32, i3: i32) -> i32 {
         ((i0 * h + i1) * w + i2) * d + i3
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OpConv2D;
+    use crate::kernel::utils::gemm::IM2COL_TILE_ROWS;
+    use crate::micro_sparsity::BLiteSparsity;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_gemm_conv2d_matches_reference() {
+        // input 1x5x4x2, filter 3x3x3x2 with the stride 2 and the padding 1, output 1x3x2x3
+        let input: [f32; 40] = core::array::from_fn(|i| (i % 7) as f32 * 0.5 - 1.5);
+        let filter: [f32; 54] = core::array::from_fn(|i| (i % 5) as f32 * 0.25 - 0.5);
+        let bias = [0.1f32, -0.2, 0.3];
+
+        let mut expected = [0f32; 18];
+        OpConv2D::kernel(
+            &input,
+            &filter,
+            &bias,
+            &mut expected,
+            5,
+            4,
+            2,
+            3,
+            3,
+            2,
+            3,
+            2,
+            3,
+            2,
+            2,
+            1,
+            1,
+            1,
+            1,
+            3,
+            1,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+
+        let mut im2col_buffer = [0f32; IM2COL_TILE_ROWS * 18];
+        let im2col_buffer = Cell::from_mut(&mut im2col_buffer[..]).as_slice_of_cells();
+        let mut output = [0f32; 18];
+        OpConv2D::gemm_kernel(
+            &input,
+            &filter,
+            &bias,
+            &mut output,
+            im2col_buffer,
+            5,
+            4,
+            2,
+            3,
+            3,
+            3,
+            2,
+            3,
+            2,
+            2,
+            1,
+            1,
+            1,
+            1,
+            1,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_sparse_conv2d_matches_dense() {
+        // input 1x3x3x2, filter 2x2x2x2, output 1x2x2x2
+        let input: [f32; 18] = core::array::from_fn(|i| i as f32 * 0.25 - 2.);
+        // the rows are (out_channel, filter_y, filter_x) and the columns are the input channels
+        let sparsity = BLiteSparsity {
+            segments: &[0, 1, 1, 3, 3, 3, 4, 4, 5],
+            indices: &[1, 0, 1, 0, 1],
+            block_size: 1,
+        };
+        let values = [1.5f32, -2., 0.5, 3., -1.];
+        let mut filter = [0f32; 16];
+        sparsity.densify(&values, &mut filter, 2);
+        let bias = [0.1f32, -0.2];
+
+        let mut expected = [0f32; 8];
+        OpConv2D::kernel(
+            &input,
+            &filter,
+            &bias,
+            &mut expected,
+            3,
+            3,
+            2,
+            2,
+            2,
+            2,
+            2,
+            2,
+            2,
+            1,
+            1,
+            1,
+            1,
+            0,
+            0,
+            2,
+            1,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+
+        let mut output = [0f32; 8];
+        OpConv2D::sparse_kernel(
+            &input,
+            &values,
+            &sparsity,
+            &bias,
+            &mut output,
+            3,
+            3,
+            2,
+            2,
+            2,
+            2,
+            2,
+            2,
+            2,
+            1,
+            1,
+            1,
+            1,
+            0,
+            0,
+            2,
+            1,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+        assert_eq!(expected, output);
+    }
+}
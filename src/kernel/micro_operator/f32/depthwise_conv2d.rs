@@ -1,9 +1,11 @@
 use crate::kernel::micro_activation::{activation_with_min_max, calculate_fused_activation_range};
 use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
-use crate::kernel::utils::padding::compute_padding_height_width;
+use crate::kernel::utils::padding::{compute_padding_height_width, for_each_output_rows};
+#[cfg(feature = "simd")]
+use crate::kernel::utils::simd::{cast_slice, cast_slice_mut, cast_value, mac_f32, CHANNEL_BLOCK};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::ArrayElem;
-use crate::micro_context::BLiteContext;
+use crate::micro_context::{BLiteContext, ParallelElem};
 use crate::micro_errors::BLiteError::*;
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
@@ -20,10 +22,13 @@ pub struct OpDepthWiseConv2D {}
 impl OpDepthWiseConv2D {
     const OPCODE: i32 = 4;
 
-    pub fn depthwise_conv2d<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+    pub fn depthwise_conv2d<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
         }
     }
 
@@ -89,12 +94,12 @@ impl OpDepthWiseConv2D {
         })
     }
 
-    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
         BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
     }
 
-    pub fn eval<'a, T: ArrayElem<T>>(
-        _context: &BLiteContext,
+    pub fn eval<'a, T: ArrayElem<T> + ParallelElem>(
+        context: &BLiteContext,
         tensors: &'a mut [BLiteTensor<'a, T>],
         node: &BLiteNode<'a>,
         builtin_option: BLiteBuiltinOption<T>,
@@ -120,9 +125,6 @@ impl OpDepthWiseConv2D {
         let output_width = output.dims[2];
         let output_depth = output.dims[3];
 
-        // TODO: What is this?
-        let batches = input.dims[0]; // TODO: min(input.dims[0], output.dims[0])
-
         let DepthWiseConv2DOptions {
             op_code: _,
             fused_activation_min,
@@ -141,30 +143,93 @@ impl OpDepthWiseConv2D {
         else {
             return Err(NotCompatibleOption);
         };
-        Self::kernel(
-            input.data,
-            filter.data,
-            bias.data,
+        let input_data = &*input.data;
+        let filter_data = &*filter.data;
+        let bias_data = &*bias.data;
+
+        // the output rows are split across the worker pool, and each part is evaluated as a single batch
+        let output_row_len = (output_width * output_depth) as usize;
+        context.parallel_for(
             output.data,
-            input_height,
-            input_width,
-            input_depth,
-            filter_height,
-            filter_width,
-            filter_input_depth,
-            output_height,
-            output_width,
-            output_depth,
-            stride_w,
-            stride_h,
-            dilation_w_factor,
-            dilation_h_factor,
-            padding_w,
-            padding_h,
-            depth_multiplier,
-            batches,
-            fused_activation_min,
-            fused_activation_max,
+            output_row_len,
+            &[],
+            |units, output_chunk, _| {
+                for_each_output_rows(
+                    input_data,
+                    output_chunk,
+                    units,
+                    input_height,
+                    (input_width * input_depth) as usize,
+                    output_height,
+                    output_row_len,
+                    stride_h,
+                    padding_h,
+                    |input_data, output_data, rows| {
+                        #[cfg(feature = "simd")]
+                        if let (
+                            Some(input_data),
+                            Some(filter_data),
+                            Some(bias_data),
+                            Some(output_data),
+                            1,
+                        ) = (
+                            cast_slice::<T, f32>(input_data),
+                            cast_slice::<T, f32>(filter_data),
+                            cast_slice::<T, f32>(bias_data),
+                            cast_slice_mut::<T, f32>(output_data),
+                            depth_multiplier,
+                        ) {
+                            return Self::simd_kernel(
+                                input_data,
+                                filter_data,
+                                bias_data,
+                                output_data,
+                                rows.input_height,
+                                input_width,
+                                input_depth,
+                                filter_height,
+                                filter_width,
+                                rows.output_height,
+                                output_width,
+                                stride_w,
+                                stride_h,
+                                dilation_w_factor,
+                                dilation_h_factor,
+                                padding_w,
+                                rows.padding_h,
+                                1,
+                                cast_value(fused_activation_min).unwrap(),
+                                cast_value(fused_activation_max).unwrap(),
+                            );
+                        }
+                        Self::kernel(
+                            input_data,
+                            filter_data,
+                            bias_data,
+                            output_data,
+                            rows.input_height,
+                            input_width,
+                            input_depth,
+                            filter_height,
+                            filter_width,
+                            filter_input_depth,
+                            rows.output_height,
+                            output_width,
+                            output_depth,
+                            stride_w,
+                            stride_h,
+                            dilation_w_factor,
+                            dilation_h_factor,
+                            padding_w,
+                            rows.padding_h,
+                            depth_multiplier,
+                            1,
+                            fused_activation_min,
+                            fused_activation_max,
+                        )
+                    },
+                )
+            },
         )
     }
 
@@ -265,7 +330,171 @@ impl OpDepthWiseConv2D {
         Ok(())
     }
 
+    /// The channels of each output pixel are accumulated by the vectorised multiply-add,
+    /// which needs the depth multiplier of 1
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    pub fn simd_kernel(
+        input_data: &[f32],
+        filter_data: &[f32],
+        bias_data: &[f32],
+        output_data: &mut [f32],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        output_height: i32,
+        output_width: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        padding_w: i32,
+        padding_h: i32,
+        batches: i32,
+        fused_activation_min: f32,
+        fused_activation_max: f32,
+    ) -> Result<()> {
+        let depth = input_depth as usize;
+        let mut acc = [0f32; CHANNEL_BLOCK];
+        for batch in 0..batches {
+            for out_y in 0..output_height {
+                for out_x in 0..output_width {
+                    let in_x_origin = (out_x * stride_w) - padding_w;
+                    let in_y_origin = (out_y * stride_h) - padding_h;
+                    let output_idx = Self::offset(
+                        output_height,
+                        output_width,
+                        input_depth,
+                        batch,
+                        out_y,
+                        out_x,
+                        0,
+                    ) as usize;
+                    let mut first_channel = 0;
+                    while first_channel < depth {
+                        let block = CHANNEL_BLOCK.min(depth - first_channel);
+                        let channels = first_channel..first_channel + block;
+                        let acc = &mut acc[..block];
+                        acc.fill(0.);
+                        for filter_y in 0..filter_height {
+                            for filter_x in 0..filter_width {
+                                let in_x = in_x_origin + dilation_w_factor * filter_x;
+                                let in_y = in_y_origin + dilation_h_factor * filter_y;
+                                let is_point_inside_image = (in_x >= 0)
+                                    && (in_x < input_width)
+                                    && (in_y >= 0)
+                                    && (in_y < input_height);
+                                if is_point_inside_image {
+                                    let input_idx = Self::offset(
+                                        input_height,
+                                        input_width,
+                                        input_depth,
+                                        batch,
+                                        in_y,
+                                        in_x,
+                                        0,
+                                    ) as usize;
+                                    let filter_idx =
+                                        (filter_y * filter_width + filter_x) as usize * depth;
+                                    mac_f32(
+                                        acc,
+                                        &input_data[input_idx..][channels.clone()],
+                                        &filter_data[filter_idx..][channels.clone()],
+                                    );
+                                }
+                            }
+                        }
+                        for (channel, &total) in channels.zip(acc.iter()) {
+                            output_data[output_idx + channel] = activation_with_min_max(
+                                total + bias_data[channel],
+                                fused_activation_min,
+                                fused_activation_max,
+                            );
+                        }
+                        first_channel += block;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn offset(h: i32, w: i32, d: i32, i0: i32, i1: i32, i2: i32, i3: i32) -> i32 {
         ((i0 * h + i1) * w + i2) * d + i3
     }
 }
+
+#[cfg(all(test, feature = "simd"))]
+mod tests {
+    use super::OpDepthWiseConv2D;
+
+    #[test]
+    fn test_simd_depthwise_conv2d_matches_reference() {
+        // input 1x5x6x37, filter 1x3x3x37 with the stride 2 and the padding 1, output 1x3x3x37
+        const DEPTH: usize = 37;
+        let input: [f32; 5 * 6 * DEPTH] = core::array::from_fn(|i| (i % 11) as f32 * 0.25 - 1.);
+        let filter: [f32; 9 * DEPTH] = core::array::from_fn(|i| (i % 7) as f32 * 0.5 - 1.5);
+        let bias: [f32; DEPTH] = core::array::from_fn(|i| i as f32 * 0.1 - 2.);
+
+        let mut expected = [0f32; 9 * DEPTH];
+        OpDepthWiseConv2D::kernel(
+            &input,
+            &filter,
+            &bias,
+            &mut expected,
+            5,
+            6,
+            DEPTH as i32,
+            3,
+            3,
+            DEPTH as i32,
+            3,
+            3,
+            DEPTH as i32,
+            2,
+            2,
+            1,
+            1,
+            1,
+            1,
+            1,
+            1,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+
+        let mut output = [0f32; 9 * DEPTH];
+        OpDepthWiseConv2D::simd_kernel(
+            &input,
+            &filter,
+            &bias,
+            &mut output,
+            5,
+            6,
+            DEPTH as i32,
+            3,
+            3,
+            3,
+            3,
+            2,
+            2,
+            1,
+            1,
+            1,
+            1,
+            1,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+        for (o, e) in output.iter().zip(expected.iter()) {
+            assert!((o - e).abs() < 1e-4, "{o} vs {e}");
+        }
+    }
+}
@@ -0,0 +1,357 @@
+use crate::kernel::micro_activation::{activation_with_min_max, calculate_fused_activation_range};
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::f32::depthwise_conv2d::OpDepthWiseConv2D;
+use crate::kernel::micro_operator::f32::fully_connected_hybrid::OpFullyConnectedHybrid;
+use crate::kernel::micro_operator::BLiteOperator;
+use crate::kernel::utils::padding::compute_padding_height_width;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::{BLiteContext, ParallelElem};
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cell::Cell;
+use num_traits::{AsPrimitive, FromPrimitive};
+
+/// DEPTHWISE_CONV_2D of the hybrid (dynamic range) quantization,
+/// whose input and output are float, filter is int8 and bias is float.
+/// Models whose filter is float are evaluated by OpDepthWiseConv2D.
+#[derive(Debug, Clone, Copy)]
+pub struct OpDepthWiseConv2DHybrid {}
+
+impl OpDepthWiseConv2DHybrid {
+    const OPCODE: i32 = 4;
+
+    pub fn depthwise_conv2d_hybrid<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 6,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        // the activations of the hybrid quantization are float
+        let half: T = FromPrimitive::from_f32(0.5).ok_or(InCompatibleCasting)?;
+        if AsPrimitive::<f32>::as_(half) != 0.5 {
+            return Err(InCompatibleType);
+        }
+
+        let filter_idx = op.inputs().unwrap().get(1) as usize;
+        let BLiteTensor::I8Tensor(filter) = &tensors[filter_idx] else {
+            return OpDepthWiseConv2D::parser(allocator, op, tensors);
+        };
+        let filter_scales = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = filter.borrow().quant_params
+            else {
+                return Err(NotFoundQuantParams);
+            };
+            // the weights of the hybrid quantization are symmetric
+            if let Some(&zp) = zero_point.iter().find(|&&zp| zp != 0) {
+                return Err(NotMatchZeroPoint(zp as i32));
+            }
+            scale
+        };
+        let filter_h = filter.borrow().dims[1];
+        let filter_w = filter.borrow().dims[2];
+
+        let builtin_option = op.builtin_options_as_depthwise_conv_2_doptions();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        let op_code = builtin_option.fused_activation_function().0 as i32;
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range(op_code)?;
+        let padding = builtin_option.padding().0 as usize;
+        let stride_w = builtin_option.stride_w();
+        let stride_h = builtin_option.stride_h();
+        let depth_multiplier = builtin_option.depth_multiplier();
+        let dilation_w_factor = builtin_option.dilation_w_factor();
+        let dilation_h_factor = builtin_option.dilation_h_factor();
+
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let input_h = tensors[input_idx]._t()?.borrow().dims[1];
+        let input_w = tensors[input_idx]._t()?.borrow().dims[2];
+        let input_size = tensors[input_idx]._t()?.borrow().size();
+        let batches = tensors[input_idx]._t()?.borrow().dims[0] as usize;
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let output_h = tensors[output_idx]._t()?.borrow().dims[1];
+        let output_w = tensors[output_idx]._t()?.borrow().dims[2];
+
+        let (padding_w, padding_w_offset, padding_h, padding_h_offset) =
+            compute_padding_height_width(
+                padding,
+                stride_h,
+                stride_w,
+                dilation_h_factor,
+                dilation_w_factor,
+                input_h,
+                input_w,
+                filter_h,
+                filter_w,
+                output_h,
+                output_w,
+            );
+
+        let quantized_input = Self::alloc_cells::<i8>(allocator, input_size)?;
+        let scaling_factors = Self::alloc_cells::<f32>(allocator, batches)?;
+        let input_offsets = Self::alloc_cells::<i32>(allocator, batches)?;
+
+        Ok(BLiteBuiltinOption::HybridDepthWiseConv2DOptions {
+            op_code,
+            fused_activation_min,
+            fused_activation_max,
+            padding,
+            padding_w,
+            padding_h,
+            padding_w_offset,
+            padding_h_offset,
+            stride_w,
+            stride_h,
+            depth_multiplier,
+            dilation_w_factor,
+            dilation_h_factor,
+            filter_scales,
+            quantized_input,
+            scaling_factors,
+            input_offsets,
+        })
+    }
+
+    fn alloc_cells<'a, E>(
+        allocator: &mut impl ArenaAllocator,
+        size: usize,
+    ) -> Result<&'a [Cell<E>]> {
+        let buffer = unsafe { alloc_array_mut::<E>(allocator, size) }?;
+        Ok(Cell::from_mut(buffer).as_slice_of_cells())
+    }
+
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T> + ParallelElem>(
+        context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let HybridDepthWiseConv2DOptions {
+            op_code: _,
+            fused_activation_min,
+            fused_activation_max,
+            padding: _,
+            stride_w,
+            stride_h,
+            dilation_w_factor,
+            dilation_h_factor,
+            padding_w,
+            padding_h,
+            padding_w_offset: _,
+            padding_h_offset: _,
+            depth_multiplier,
+            filter_scales,
+            quantized_input,
+            scaling_factors,
+            input_offsets,
+        } = builtin_option
+        else {
+            return OpDepthWiseConv2D::eval(context, tensors, node, builtin_option);
+        };
+
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+        let batches = input.dims[0];
+        let input_height = input.dims[1];
+        let input_width = input.dims[2];
+        let input_depth = input.dims[3];
+
+        let idx_filter = node.inputs[1] as usize;
+        let filter = tensors[idx_filter]._i8()?.borrow();
+        let filter_height = filter.dims[1];
+        let filter_width = filter.dims[2];
+        let filter_input_depth = filter.dims[3];
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+        let output_height = output.dims[1];
+        let output_width = output.dims[2];
+        let output_depth = output.dims[3];
+
+        // This computations is corresponded to the quantization of the inputs in EvalHybridPerChannel
+        OpFullyConnectedHybrid::quantize_inputs(
+            input.data,
+            quantized_input,
+            scaling_factors,
+            input_offsets,
+            batches,
+            input_height * input_width * input_depth,
+            true,
+        );
+
+        let idx_bias = node.inputs[2];
+        let bias = if idx_bias >= 0 {
+            Some(tensors[idx_bias as usize]._t()?.borrow())
+        } else {
+            None
+        };
+        Self::kernel(
+            quantized_input,
+            input_offsets,
+            scaling_factors,
+            filter.data,
+            filter_scales,
+            bias.as_ref().map(|bias| &bias.data[..]),
+            output.data,
+            input_height,
+            input_width,
+            input_depth,
+            filter_height,
+            filter_width,
+            filter_input_depth,
+            output_height,
+            output_width,
+            output_depth,
+            stride_w,
+            stride_h,
+            dilation_w_factor,
+            dilation_h_factor,
+            padding_w,
+            padding_h,
+            depth_multiplier,
+            batches,
+            fused_activation_min,
+            fused_activation_max,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        quantized_input: &[Cell<i8>],
+        input_offsets: &[Cell<i32>],
+        scaling_factors: &[Cell<f32>],
+        filter_data: &[i8],
+        filter_scales: &[f32],
+        bias_data: Option<&[T]>,
+        output_data: &mut [T],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        filter_input_depth: i32,
+        output_height: i32,
+        output_width: i32,
+        output_depth: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        padding_w: i32,
+        padding_h: i32,
+        depth_multiplier: i32,
+        batches: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        for batch in 0..batches {
+            let input_offset = input_offsets[batch as usize].get();
+            let scaling_factor = scaling_factors[batch as usize].get();
+            for out_y in 0..output_height {
+                for out_x in 0..output_width {
+                    for in_channel in 0..input_depth {
+                        for m in 0..depth_multiplier {
+                            let out_channel = m + in_channel * depth_multiplier;
+                            let in_x_origin = (out_x * stride_w) - padding_w;
+                            let in_y_origin = (out_y * stride_h) - padding_h;
+                            let mut acc: i32 = 0;
+                            for filter_y in 0..filter_height {
+                                for filter_x in 0..filter_width {
+                                    let in_x = in_x_origin + dilation_w_factor * filter_x;
+                                    let in_y = in_y_origin + dilation_h_factor * filter_y;
+                                    let is_point_inside_image = (in_x >= 0)
+                                        && (in_x < input_width)
+                                        && (in_y >= 0)
+                                        && (in_y < input_height);
+                                    if is_point_inside_image {
+                                        let input_v_idx = Self::offset(
+                                            input_height,
+                                            input_width,
+                                            input_depth,
+                                            batch,
+                                            in_y,
+                                            in_x,
+                                            in_channel,
+                                        );
+                                        let input_v = quantized_input[input_v_idx as usize].get();
+                                        let filter_v_idx = Self::offset(
+                                            filter_height,
+                                            filter_width,
+                                            filter_input_depth,
+                                            0,
+                                            filter_y,
+                                            filter_x,
+                                            out_channel,
+                                        );
+                                        let filter_v = filter_data[filter_v_idx as usize];
+                                        acc += (input_v as i32 - input_offset) * filter_v as i32;
+                                    }
+                                }
+                            }
+
+                            // a per-tensor quantized filter has only one scale
+                            let filter_scale = filter_scales[if filter_scales.len() == 1 {
+                                0
+                            } else {
+                                out_channel as usize
+                            }];
+                            let mut total = acc as f32 * scaling_factor * filter_scale;
+                            if let Some(bias_data) = bias_data {
+                                total += AsPrimitive::<f32>::as_(bias_data[out_channel as usize]);
+                            }
+                            let total: T =
+                                FromPrimitive::from_f32(total).ok_or(InCompatibleCasting)?;
+
+                            let output_v_idx = Self::offset(
+                                output_height,
+                                output_width,
+                                output_depth,
+                                batch,
+                                out_y,
+                                out_x,
+                                out_channel,
+                            );
+                            output_data[output_v_idx as usize] = activation_with_min_max(
+                                total,
+                                fused_activation_min,
+                                fused_activation_max,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn offset(h: i32, w: i32, d: i32, i0: i32, i1: i32, i2: i32, i3: i32) -> i32 {
+        ((i0 * h + i1) * w + i2) * d + i3
+    }
+}
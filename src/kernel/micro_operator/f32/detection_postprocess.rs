@@ -0,0 +1,622 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::flexbuffer::FlexBufferRef;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteArray, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cell::Cell;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+#[cfg(feature = "no_std")]
+use num_traits::Float;
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpDetectionPostprocess {}
+
+impl OpDetectionPostprocess {
+    pub const CUSTOM_CODE: &'static str = "TFLite_Detection_PostProcess";
+    const DEFAULT_DETECTIONS_PER_CLASS: i32 = 100;
+
+    /// The outputs (boxes, classes, scores and the number of detections) are float,
+    /// so that this operator runs only on the float runtime.
+    pub fn detection_postprocess<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S>
+    {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        // the outputs cannot be represented by integer types
+        let half: T = FromPrimitive::from_f32(0.5).ok_or(InCompatibleCasting)?;
+        if AsPrimitive::<f32>::as_(half) != 0.5 {
+            return Err(InCompatibleType);
+        }
+
+        let Some(custom_options) = op.custom_options() else {
+            return Err(NotFoundCustomOptions);
+        };
+        let options = FlexBufferRef::root(custom_options.bytes())?.as_map()?;
+        let get = |key: &str| options.get(key)?.ok_or(NotFoundCustomOptions);
+        let max_detections = get("max_detections")?.as_i32()?;
+        let max_classes_per_detection = get("max_classes_per_detection")?.as_i32()?;
+        let detections_per_class = match options.get("detections_per_class")? {
+            Some(v) => v.as_i32()?,
+            None => Self::DEFAULT_DETECTIONS_PER_CLASS,
+        };
+        let use_regular_nms = match options.get("use_regular_nms")? {
+            Some(v) => v.as_bool()?,
+            None => false,
+        };
+        let nms_score_threshold = get("nms_score_threshold")?.as_f32()?;
+        let nms_iou_threshold = get("nms_iou_threshold")?.as_f32()?;
+        let num_classes = get("num_classes")?.as_i32()?;
+        let y_scale = get("y_scale")?.as_f32()?;
+        let x_scale = get("x_scale")?.as_f32()?;
+        let h_scale = get("h_scale")?.as_f32()?;
+        let w_scale = get("w_scale")?.as_f32()?;
+        if max_detections <= 0
+            || max_classes_per_detection <= 0
+            || detections_per_class <= 0
+            || num_classes <= 0
+        {
+            return Err(NotFoundCustomOptions);
+        }
+
+        let inputs = op.inputs().unwrap();
+        let box_encodings_idx = inputs.get(0) as usize;
+        let class_predictions_idx = inputs.get(1) as usize;
+        let anchors_idx = inputs.get(2) as usize;
+        let box_encodings_dims = tensors[box_encodings_idx]._t()?.borrow().dims;
+        let class_predictions_dims = tensors[class_predictions_idx]._t()?.borrow().dims;
+        let anchors_dims = tensors[anchors_idx]._t()?.borrow().dims;
+        let num_boxes = box_encodings_dims[1];
+        if box_encodings_dims[2] < 4 {
+            return Err(InCompatibleShape(box_encodings_dims[2], 4));
+        }
+        if class_predictions_dims[1] != num_boxes {
+            return Err(InCompatibleShape(class_predictions_dims[1], num_boxes));
+        }
+        if anchors_dims[0] != num_boxes || anchors_dims[1] != 4 {
+            return Err(InCompatibleShape(anchors_dims[0], num_boxes));
+        }
+        // the classes may be preceded by the background class
+        let label_offset = class_predictions_dims[2] - num_classes;
+        if !(0..=1).contains(&label_offset) {
+            return Err(InCompatibleShape(class_predictions_dims[2], num_classes));
+        }
+
+        let num_detected_boxes = max_detections * max_classes_per_detection;
+        let outputs = op.outputs().unwrap();
+        let expected_lens = [
+            num_detected_boxes * 4,
+            num_detected_boxes,
+            num_detected_boxes,
+            1,
+        ];
+        for (i, expected_len) in expected_lens.into_iter().enumerate() {
+            let output_len = tensors[outputs.get(i) as usize]._t()?.borrow().size() as i32;
+            if output_len != expected_len {
+                return Err(InCompatibleShape(output_len, expected_len));
+            }
+        }
+
+        // scratch buffers for the decoded boxes and the non-maximum suppression
+        let num_boxes = num_boxes as usize;
+        let num_categories_per_anchor = min(max_classes_per_detection, num_classes) as usize;
+        let (num_class_indices, num_top) = if use_regular_nms {
+            (0, (max_detections + detections_per_class) as usize)
+        } else {
+            (num_boxes * num_categories_per_anchor, 0)
+        };
+        let num_selected = max(max_detections, detections_per_class) as usize;
+        let decoded_boxes = Self::alloc_cells::<f32>(allocator, num_boxes * 4)?;
+        let candidate_scores = Self::alloc_cells::<f32>(allocator, num_boxes)?;
+        let class_indices = Self::alloc_cells::<i32>(allocator, num_class_indices)?;
+        let selected = Self::alloc_cells::<i32>(allocator, num_selected)?;
+        let top_scores = Self::alloc_cells::<f32>(allocator, num_top)?;
+        let top_indices = Self::alloc_cells::<i32>(allocator, num_top)?;
+
+        Ok(BLiteBuiltinOption::DetectionPostprocessOptions {
+            max_detections,
+            max_classes_per_detection,
+            detections_per_class,
+            num_classes,
+            use_regular_nms,
+            nms_score_threshold,
+            nms_iou_threshold,
+            y_scale,
+            x_scale,
+            h_scale,
+            w_scale,
+            decoded_boxes,
+            candidate_scores,
+            class_indices,
+            selected,
+            top_scores,
+            top_indices,
+        })
+    }
+
+    fn alloc_cells<'a, E>(
+        allocator: &mut impl ArenaAllocator,
+        size: usize,
+    ) -> Result<&'a [Cell<E>]> {
+        let buffer = unsafe { alloc_array_mut::<E>(allocator, size) }?;
+        Ok(Cell::from_mut(buffer).as_slice_of_cells())
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new_custom(Self::CUSTOM_CODE, Self::eval::<T>, NotInitialize)
+    }
+
+    /// Returns the scale and the zero point of a tensor, which are (1, 0) for float tensors
+    fn dequantization_params<T: ArrayElem<T>>(tensor: &BLiteArray<T>) -> (f32, i32) {
+        match tensor.quant_params {
+            Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) => (scale[0], zero_point[0] as i32),
+            None => (1., 0),
+        }
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let box_encodings = tensors[node.inputs[0] as usize]._t()?.borrow();
+        let class_predictions = tensors[node.inputs[1] as usize]._t()?.borrow();
+        let anchors = tensors[node.inputs[2] as usize]._t()?.borrow();
+        let num_boxes = box_encodings.dims[1] as usize;
+        let box_code_size = box_encodings.dims[2] as usize;
+        let num_classes_with_background = class_predictions.dims[2] as usize;
+
+        let mut detection_boxes = tensors[node.outputs[0] as usize]._t()?.borrow_mut();
+        let mut detection_classes = tensors[node.outputs[1] as usize]._t()?.borrow_mut();
+        let mut detection_scores = tensors[node.outputs[2] as usize]._t()?.borrow_mut();
+        let mut num_detections = tensors[node.outputs[3] as usize]._t()?.borrow_mut();
+
+        let DetectionPostprocessOptions {
+            max_detections,
+            max_classes_per_detection,
+            detections_per_class,
+            num_classes,
+            use_regular_nms,
+            nms_score_threshold,
+            nms_iou_threshold,
+            y_scale,
+            x_scale,
+            h_scale,
+            w_scale,
+            decoded_boxes,
+            candidate_scores,
+            class_indices,
+            selected,
+            top_scores,
+            top_indices,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(
+            box_encodings.data,
+            class_predictions.data,
+            anchors.data,
+            detection_boxes.data,
+            detection_classes.data,
+            detection_scores.data,
+            num_detections.data,
+            num_boxes,
+            box_code_size,
+            num_classes_with_background,
+            num_classes as usize,
+            max_detections as usize,
+            max_classes_per_detection as usize,
+            detections_per_class as usize,
+            use_regular_nms,
+            nms_score_threshold,
+            nms_iou_threshold,
+            [y_scale, x_scale, h_scale, w_scale],
+            Self::dequantization_params(&box_encodings),
+            Self::dequantization_params(&class_predictions),
+            Self::dequantization_params(&anchors),
+            decoded_boxes,
+            candidate_scores,
+            class_indices,
+            selected,
+            top_scores,
+            top_indices,
+        )
+    }
+
+    /// This kernel follows the detection_postprocess custom operator of tensorflow lite:
+    /// the boxes are decoded from the anchors, and then the detections are selected
+    /// by the non-maximum suppression over all classes at once (fast) or per class (regular).
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        box_encodings_data: &[T],
+        class_predictions_data: &[T],
+        anchors_data: &[T],
+        detection_boxes_data: &mut [T],
+        detection_classes_data: &mut [T],
+        detection_scores_data: &mut [T],
+        num_detections_data: &mut [T],
+        //
+        num_boxes: usize,
+        box_code_size: usize,
+        num_classes_with_background: usize,
+        num_classes: usize,
+        max_detections: usize,
+        max_classes_per_detection: usize,
+        detections_per_class: usize,
+        use_regular_nms: bool,
+        nms_score_threshold: f32,
+        nms_iou_threshold: f32,
+        // y, x, h and w scales
+        scales: [f32; 4],
+        // for quantization
+        box_encodings_params: (f32, i32),
+        class_predictions_params: (f32, i32),
+        anchors_params: (f32, i32),
+        // scratch buffers
+        decoded_boxes: &[Cell<f32>],
+        candidate_scores: &[Cell<f32>],
+        class_indices: &[Cell<i32>],
+        selected: &[Cell<i32>],
+        top_scores: &[Cell<f32>],
+        top_indices: &[Cell<i32>],
+    ) -> Result<()> {
+        let dequantize = |v: T, (scale, zero_point): (f32, i32)| {
+            if zero_point == 0 && scale == 1. {
+                v.as_()
+            } else {
+                scale * (AsPrimitive::<i32>::as_(v) - zero_point) as f32
+            }
+        };
+        let label_offset = num_classes_with_background - num_classes;
+        let score = |anchor: usize, class: usize| {
+            let idx = anchor * num_classes_with_background + label_offset + class;
+            dequantize(class_predictions_data[idx], class_predictions_params)
+        };
+        let from_f32 =
+            |v: f32| -> Result<T> { FromPrimitive::from_f32(v).ok_or(InCompatibleCasting) };
+
+        // This computations is corresponded to DecodeCenterSizeBoxes
+        let [y_scale, x_scale, h_scale, w_scale] = scales;
+        for idx in 0..num_boxes {
+            let encoding = &box_encodings_data[idx * box_code_size..idx * box_code_size + 4];
+            let encoding = [0, 1, 2, 3].map(|i| dequantize(encoding[i], box_encodings_params));
+            let anchor = &anchors_data[idx * 4..idx * 4 + 4];
+            let [anchor_y, anchor_x, anchor_h, anchor_w] =
+                [0, 1, 2, 3].map(|i| dequantize(anchor[i], anchors_params));
+            let y_center = encoding[0] / y_scale * anchor_h + anchor_y;
+            let x_center = encoding[1] / x_scale * anchor_w + anchor_x;
+            let half_h = 0.5 * (encoding[2] / h_scale).exp() * anchor_h;
+            let half_w = 0.5 * (encoding[3] / w_scale).exp() * anchor_w;
+            let decoded_box = &decoded_boxes[idx * 4..idx * 4 + 4];
+            decoded_box[0].set(y_center - half_h);
+            decoded_box[1].set(x_center - half_w);
+            decoded_box[2].set(y_center + half_h);
+            decoded_box[3].set(x_center + half_w);
+        }
+
+        detection_boxes_data.fill(Default::default());
+        detection_classes_data.fill(Default::default());
+        detection_scores_data.fill(Default::default());
+
+        let mut write_detection = |output_idx: usize, anchor: usize, class: usize| -> Result<()> {
+            for i in 0..4 {
+                detection_boxes_data[output_idx * 4 + i] =
+                    from_f32(decoded_boxes[anchor * 4 + i].get())?;
+            }
+            detection_classes_data[output_idx] = from_f32(class as f32)?;
+            detection_scores_data[output_idx] = from_f32(score(anchor, class))?;
+            Ok(())
+        };
+
+        let num_detected = if use_regular_nms {
+            // This computations is corresponded to NonMaxSuppressionMultiClassRegularHelper
+            let mut num_top = 0;
+            for class in 0..num_classes {
+                for (anchor, candidate_score) in candidate_scores.iter().enumerate() {
+                    candidate_score.set(score(anchor, class));
+                }
+                let num_selected = Self::non_max_suppression(
+                    decoded_boxes,
+                    candidate_scores,
+                    nms_score_threshold,
+                    nms_iou_threshold,
+                    detections_per_class,
+                    selected,
+                );
+                // The detections of this class, which are in the decreasing order of scores,
+                // are merged into the top detections from the back.
+                // The detections of earlier classes come first on ties.
+                let selected_score = |j: usize| score(selected[j].get() as usize, class);
+                let (mut i, mut j) = (num_top, num_selected);
+                let mut dst = num_top + num_selected;
+                while j > 0 {
+                    dst -= 1;
+                    if i > 0 && top_scores[i - 1].get() < selected_score(j - 1) {
+                        i -= 1;
+                        top_scores[dst].set(top_scores[i].get());
+                        top_indices[dst].set(top_indices[i].get());
+                    } else {
+                        j -= 1;
+                        top_scores[dst].set(selected_score(j));
+                        top_indices[dst]
+                            .set((selected[j].get() as usize * num_classes + class) as i32);
+                    }
+                }
+                num_top = min(num_top + num_selected, max_detections);
+            }
+            for (output_idx, top_idx) in top_indices[..num_top].iter().enumerate() {
+                let top_idx = top_idx.get() as usize;
+                write_detection(output_idx, top_idx / num_classes, top_idx % num_classes)?;
+            }
+            num_top
+        } else {
+            // This computations is corresponded to NonMaxSuppressionMultiClassFastHelper
+            let num_categories_per_anchor = min(max_classes_per_detection, num_classes);
+            for (anchor, candidate_score) in candidate_scores.iter().enumerate() {
+                let indices = &class_indices
+                    [anchor * num_categories_per_anchor..(anchor + 1) * num_categories_per_anchor];
+                Self::decreasing_partial_arg_sort(
+                    num_classes,
+                    |class| score(anchor, class),
+                    indices,
+                );
+                candidate_score.set(score(anchor, indices[0].get() as usize));
+            }
+            let num_selected = Self::non_max_suppression(
+                decoded_boxes,
+                candidate_scores,
+                nms_score_threshold,
+                nms_iou_threshold,
+                max_detections,
+                selected,
+            );
+            for (output_box_idx, anchor) in selected[..num_selected].iter().enumerate() {
+                let anchor = anchor.get() as usize;
+                for col in 0..num_categories_per_anchor {
+                    let class =
+                        class_indices[anchor * num_categories_per_anchor + col].get() as usize;
+                    write_detection(
+                        max_classes_per_detection * output_box_idx + col,
+                        anchor,
+                        class,
+                    )?;
+                }
+            }
+            num_selected
+        };
+        num_detections_data[0] = from_f32(num_detected as f32)?;
+        Ok(())
+    }
+
+    /// Stores the indices of the `indices.len()` largest values in the decreasing order,
+    /// where smaller indices come first on ties.
+    fn decreasing_partial_arg_sort(
+        num_values: usize,
+        value: impl Fn(usize) -> f32,
+        indices: &[Cell<i32>],
+    ) {
+        let num_to_sort = indices.len();
+        let mut num_sorted = 0;
+        for i in 0..num_values {
+            let v = value(i);
+            let mut pos = num_sorted;
+            while pos > 0 && v > value(indices[pos - 1].get() as usize) {
+                pos -= 1;
+            }
+            if pos >= num_to_sort {
+                continue;
+            }
+            for j in (pos..min(num_sorted, num_to_sort - 1)).rev() {
+                indices[j + 1].set(indices[j].get());
+            }
+            indices[pos].set(i as i32);
+            num_sorted = min(num_sorted + 1, num_to_sort);
+        }
+    }
+
+    /// Selects at most `max_output` boxes in the decreasing order of `scores`,
+    /// suppressing the boxes which overlap with selected ones. `scores` is consumed.
+    fn non_max_suppression(
+        decoded_boxes: &[Cell<f32>],
+        scores: &[Cell<f32>],
+        score_threshold: f32,
+        iou_threshold: f32,
+        max_output: usize,
+        selected: &[Cell<i32>],
+    ) -> usize {
+        // suppressed boxes are marked by the negative infinity
+        let suppressed = f32::NEG_INFINITY;
+        for score in scores {
+            if score.get() < score_threshold {
+                score.set(suppressed);
+            }
+        }
+
+        let mut num_selected = 0;
+        while num_selected < max_output {
+            let mut best = None;
+            let mut best_score = suppressed;
+            for (i, score) in scores.iter().enumerate() {
+                if score.get() > best_score {
+                    best = Some(i);
+                    best_score = score.get();
+                }
+            }
+            let Some(best) = best else {
+                break;
+            };
+            selected[num_selected].set(best as i32);
+            num_selected += 1;
+            scores[best].set(suppressed);
+
+            for (i, score) in scores.iter().enumerate() {
+                if score.get() > suppressed
+                    && Self::intersection_over_union(decoded_boxes, best, i) > iou_threshold
+                {
+                    score.set(suppressed);
+                }
+            }
+        }
+        num_selected
+    }
+
+    fn intersection_over_union(decoded_boxes: &[Cell<f32>], i: usize, j: usize) -> f32 {
+        let [i_ymin, i_xmin, i_ymax, i_xmax] = [0, 1, 2, 3].map(|k| decoded_boxes[i * 4 + k].get());
+        let [j_ymin, j_xmin, j_ymax, j_xmax] = [0, 1, 2, 3].map(|k| decoded_boxes[j * 4 + k].get());
+        let area_i = (i_ymax - i_ymin) * (i_xmax - i_xmin);
+        let area_j = (j_ymax - j_ymin) * (j_xmax - j_xmin);
+        if area_i <= 0. || area_j <= 0. {
+            return 0.;
+        }
+        let intersection_h = (i_ymax.min(j_ymax) - i_ymin.max(j_ymin)).max(0.);
+        let intersection_w = (i_xmax.min(j_xmax) - i_xmin.max(j_xmin)).max(0.);
+        let intersection_area = intersection_h * intersection_w;
+        intersection_area / (area_i + area_j - intersection_area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpDetectionPostprocess;
+    use core::cell::Cell;
+
+    const NUM_BOXES: usize = 6;
+    const BOX_ENCODINGS: [f32; 24] = [
+        0., 0., 0., 0., //
+        0., 1., 0., 0., //
+        0., -1., 0., 0., //
+        0., 0., 0., 0., //
+        0., 1., 0., 0., //
+        0., 0., 0., 0., //
+    ];
+    // the first class is the background
+    const CLASS_PREDICTIONS: [f32; 18] = [
+        0., 0.9, 0.8, //
+        0., 0.75, 0.72, //
+        0., 0.6, 0.5, //
+        0., 0.93, 0.95, //
+        0., 0.5, 0.4, //
+        0., 0.3, 0.2, //
+    ];
+    const ANCHORS: [f32; 24] = [
+        0.5, 0.5, 1., 1., //
+        0.5, 0.1, 1., 1., //
+        0.5, -0.1, 1., 1., //
+        0.5, 10.5, 1., 1., //
+        0.5, 10.1, 1., 1., //
+        0.5, 100.5, 1., 1., //
+    ];
+
+    fn run(
+        use_regular_nms: bool,
+        detections_per_class: usize,
+    ) -> (Vec<f32>, Vec<f32>, Vec<f32>, f32) {
+        let max_detections = 3;
+        let cells_f32 = |n: usize| vec![Cell::new(0f32); n];
+        let cells_i32 = |n: usize| vec![Cell::new(0i32); n];
+        let mut boxes = vec![0f32; max_detections * 4];
+        let mut classes = vec![0f32; max_detections];
+        let mut scores = vec![0f32; max_detections];
+        let mut num_detections = vec![0f32; 1];
+        OpDetectionPostprocess::kernel(
+            &BOX_ENCODINGS,
+            &CLASS_PREDICTIONS,
+            &ANCHORS,
+            &mut boxes,
+            &mut classes,
+            &mut scores,
+            &mut num_detections,
+            NUM_BOXES,
+            4,
+            3,
+            2,
+            max_detections,
+            1,
+            detections_per_class,
+            use_regular_nms,
+            0.5,
+            0.5,
+            [10., 10., 5., 5.],
+            (1., 0),
+            (1., 0),
+            (1., 0),
+            &cells_f32(NUM_BOXES * 4),
+            &cells_f32(NUM_BOXES),
+            &cells_i32(NUM_BOXES),
+            &cells_i32(max_detections.max(detections_per_class)),
+            &cells_f32(max_detections + detections_per_class),
+            &cells_i32(max_detections + detections_per_class),
+        )
+        .unwrap();
+        (boxes, classes, scores, num_detections[0])
+    }
+
+    fn assert_close(expected: &[f32], actual: &[f32]) {
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!(
+                (e - a).abs() < 1e-5,
+                "expected {:?}, but got {:?}",
+                expected,
+                actual
+            );
+        }
+    }
+
+    #[test]
+    fn test_detection_postprocess_fast_nms() {
+        let (boxes, classes, scores, num_detections) = run(false, 1);
+        #[rustfmt::skip]
+        assert_close(&[
+            0., 10., 1., 11.,
+            0., 0., 1., 1.,
+            0., -0.7, 1., 0.3,
+        ], &boxes);
+        assert_close(&[1., 0., 0.], &classes);
+        assert_close(&[0.95, 0.9, 0.6], &scores);
+        assert_eq!(3., num_detections);
+    }
+
+    #[test]
+    fn test_detection_postprocess_regular_nms() {
+        let (boxes, classes, scores, num_detections) = run(true, 2);
+        #[rustfmt::skip]
+        assert_close(&[
+            0., 10., 1., 11.,
+            0., 10., 1., 11.,
+            0., 0., 1., 1.,
+        ], &boxes);
+        assert_close(&[1., 0., 0.], &classes);
+        assert_close(&[0.95, 0.93, 0.9], &scores);
+        assert_eq!(3., num_detections);
+
+        let (_, classes, scores, num_detections) = run(true, 1);
+        assert_close(&[1., 0., 0.], &classes);
+        assert_close(&[0.95, 0.93, 0.], &scores);
+        assert_eq!(2., num_detections);
+    }
+}
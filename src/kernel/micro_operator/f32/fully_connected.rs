@@ -1,14 +1,17 @@
 use crate::kernel::micro_activation::{activation_with_min_max, calculate_fused_activation_range};
 use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
 use crate::kernel::micro_operator::BLiteOperator;
+#[cfg(feature = "simd")]
+use crate::kernel::utils::simd::{cast_slice, cast_slice_mut, cast_value, dot_f32};
 use crate::kernel::utils::types::flat_skip_dims;
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::ArrayElem;
-use crate::micro_context::BLiteContext;
+use crate::micro_context::{BLiteContext, ParallelElem};
 use crate::micro_errors::BLiteError::*;
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
 use crate::micro_registration::BLiteRegistration;
+use crate::micro_sparsity::BLiteSparsity;
 use crate::micro_tensor::BLiteTensor;
 use crate::tflite_schema_generated::tflite::Operator;
 
@@ -18,17 +21,20 @@ pub struct OpFullyConnected {}
 impl OpFullyConnected {
     const OPCODE: i32 = 9;
 
-    pub fn fully_connected<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+    pub fn fully_connected<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 8,
         }
     }
 
     pub fn parser<'a, T: ArrayElem<T>>(
         _allocator: &mut impl ArenaAllocator,
         op: Operator,
-        _tensors: &mut [BLiteTensor<'a, T>],
+        tensors: &mut [BLiteTensor<'a, T>],
     ) -> Result<BLiteBuiltinOption<'a, T>> {
         let builtin_option = op.builtin_options_as_fully_connected_options();
         let mut op_code = -1;
@@ -37,19 +43,30 @@ impl OpFullyConnected {
         }
         let (fused_activation_min, fused_activation_max) =
             calculate_fused_activation_range(op_code)?;
+
+        // the sparse weights are kept compressed and evaluated by the sparse kernel
+        let filter_idx = op.inputs().unwrap().get(1) as usize;
+        let filter_sparsity = tensors[filter_idx]
+            ._t()?
+            .borrow_mut()
+            .sparsity
+            .take()
+            .copied();
+
         Ok(BLiteBuiltinOption::FullyConnectedOptions {
             op_code,
             fused_activation_min,
             fused_activation_max,
+            filter_sparsity,
         })
     }
 
-    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
         BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
     }
 
-    pub fn eval<'a, T: ArrayElem<T>>(
-        _context: &BLiteContext,
+    pub fn eval<'a, T: ArrayElem<T> + ParallelElem>(
+        context: &BLiteContext,
         tensors: &'a mut [BLiteTensor<'a, T>],
         node: &BLiteNode<'a>,
         builtin_option: BLiteBuiltinOption<T>,
@@ -69,6 +86,7 @@ impl OpFullyConnected {
             op_code: _,
             fused_activation_min,
             fused_activation_max,
+            filter_sparsity,
         } = builtin_option
         else {
             return Err(NotInitializeActivation);
@@ -78,32 +96,87 @@ impl OpFullyConnected {
         let batches = flat_skip_dims(output.dims, output.dims.len() - 1);
         let output_depth = filter.dims[filter.dims.len() - 2];
         let accum_depth = filter.dims[filter.dims.len() - 1];
-        if idx_bias >= 0 {
-            let bias = tensors[idx_bias as usize]._t()?.borrow();
-            Self::kernel(
-                input.data,
-                Some(&bias.data),
-                filter.data,
-                output.data,
-                batches,
-                output_depth,
-                accum_depth,
-                fused_activation_min,
-                fused_activation_max,
-            )
+        let bias = if idx_bias >= 0 {
+            Some(tensors[idx_bias as usize]._t()?.borrow())
         } else {
+            None
+        };
+        let bias_data = bias.as_ref().map(|bias| &bias.data[..]);
+        let input_data = &*input.data;
+        let filter_data = &*filter.data;
+
+        // the output channels of a single batch are split across the worker pool,
+        // otherwise the batches are split
+        let split_channels = batches == 1 && filter_sparsity.is_none();
+        let unit_len = if split_channels {
+            1
+        } else {
+            output_depth as usize
+        };
+        let output_data = &mut output.data[..(batches * output_depth) as usize];
+        context.parallel_for(output_data, unit_len, &[], |units, output_data, _| {
+            let (channels, batch_range) = if split_channels {
+                (units, 0..1)
+            } else {
+                (0..output_depth as usize, units)
+            };
+            let accum_depth_usize = accum_depth as usize;
+            let input_data = &input_data
+                [batch_range.start * accum_depth_usize..batch_range.end * accum_depth_usize];
+            // the sparse filter holds only the nonzero blocks, which is not split
+            let filter_data = if split_channels {
+                &filter_data[channels.start * accum_depth_usize..channels.end * accum_depth_usize]
+            } else {
+                filter_data
+            };
+            let bias_data = bias_data.map(|bias_data| &bias_data[channels.clone()]);
+            let batches = batch_range.len() as i32;
+            let output_depth = channels.len() as i32;
+
+            if let Some(sparsity) = &filter_sparsity {
+                return Self::sparse_kernel(
+                    input_data,
+                    bias_data,
+                    filter_data,
+                    sparsity,
+                    output_data,
+                    batches,
+                    output_depth,
+                    accum_depth,
+                    fused_activation_min,
+                    fused_activation_max,
+                );
+            }
+            #[cfg(feature = "simd")]
+            if let (Some(input_data), Some(filter_data), Some(output_data)) = (
+                cast_slice::<T, f32>(input_data),
+                cast_slice::<T, f32>(filter_data),
+                cast_slice_mut::<T, f32>(output_data),
+            ) {
+                return Self::simd_kernel(
+                    input_data,
+                    bias_data.and_then(cast_slice::<T, f32>),
+                    filter_data,
+                    output_data,
+                    batches,
+                    output_depth,
+                    accum_depth,
+                    cast_value(fused_activation_min).unwrap(),
+                    cast_value(fused_activation_max).unwrap(),
+                );
+            }
             Self::kernel(
-                input.data,
-                None,
-                filter.data,
-                output.data,
+                input_data,
+                bias_data,
+                filter_data,
+                output_data,
                 batches,
                 output_depth,
                 accum_depth,
                 fused_activation_min,
                 fused_activation_max,
             )
-        }
+        })
     }
 
     #[inline(always)]
@@ -138,4 +211,176 @@ impl OpFullyConnected {
 
         Ok(())
     }
+
+    /// The accumulation of each output channel is the vectorised dot product
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    pub fn simd_kernel(
+        input_data: &[f32],
+        bias_data: Option<&[f32]>,
+        filter_data: &[f32],
+        output_data: &mut [f32],
+        batches: i32,
+        output_depth: i32,
+        accum_depth: i32,
+        fused_activation_min: f32,
+        fused_activation_max: f32,
+    ) -> Result<()> {
+        let accum_depth = accum_depth as usize;
+        for batch in 0..batches as usize {
+            let input = &input_data[batch * accum_depth..(batch + 1) * accum_depth];
+            for out_d in 0..output_depth as usize {
+                let filter = &filter_data[out_d * accum_depth..(out_d + 1) * accum_depth];
+                let mut total = dot_f32(input, filter);
+                if let Some(bias_data) = bias_data {
+                    total += bias_data[out_d];
+                }
+
+                total = activation_with_min_max(total, fused_activation_min, fused_activation_max);
+                output_data[batch * output_depth as usize + out_d] = total;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The filter holds only the nonzero blocks of each output channel,
+    /// and the zero blocks are skipped
+    #[inline(always)]
+    pub fn sparse_kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        bias_data: Option<&[T]>,
+        filter_data: &[T],
+        filter_sparsity: &BLiteSparsity,
+        output_data: &mut [T],
+        batches: i32,
+        output_depth: i32,
+        accum_depth: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        let block_size = filter_sparsity.block_size;
+        for batch in 0..batches as usize {
+            let input = &input_data[batch * accum_depth as usize..];
+            for out_d in 0..output_depth as usize {
+                let mut total: T = Default::default();
+                for (acc_d, offset) in filter_sparsity.row_blocks(out_d) {
+                    for i in 0..block_size {
+                        total += input[acc_d + i] * filter_data[offset + i];
+                    }
+                }
+
+                if let Some(bias_data) = bias_data {
+                    total += bias_data[out_d];
+                }
+
+                total = activation_with_min_max(total, fused_activation_min, fused_activation_max);
+                output_data[batch * output_depth as usize + out_d] = total;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpFullyConnected;
+    use crate::micro_sparsity::BLiteSparsity;
+
+    #[test]
+    fn test_sparse_fully_connected_matches_dense() {
+        const BATCHES: usize = 2;
+        const OUTPUT_DEPTH: usize = 3;
+        const ACCUM_DEPTH: usize = 4;
+        let input = [0.5f32, -1.25, 2., 0.75, -0.3, 0.9, 0.1, -2.2];
+        // the blocks of 2 values along the accumulation depth
+        let sparsity = BLiteSparsity {
+            segments: &[0, 1, 1, 3],
+            indices: &[1, 0, 1],
+            block_size: 2,
+        };
+        let values = [1.5f32, -2., 0.25, 3., -1., 0.5];
+        let mut filter = [0f32; OUTPUT_DEPTH * ACCUM_DEPTH];
+        sparsity.densify(&values, &mut filter, ACCUM_DEPTH);
+        assert_eq!(
+            [0., 0., 1.5, -2., 0., 0., 0., 0., 0.25, 3., -1., 0.5],
+            filter
+        );
+        let bias = [0.1f32, -0.2, 0.3];
+
+        let mut expected = [0f32; BATCHES * OUTPUT_DEPTH];
+        OpFullyConnected::kernel(
+            &input,
+            Some(&bias),
+            &filter,
+            &mut expected,
+            BATCHES as i32,
+            OUTPUT_DEPTH as i32,
+            ACCUM_DEPTH as i32,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+
+        let mut output = [0f32; BATCHES * OUTPUT_DEPTH];
+        OpFullyConnected::sparse_kernel(
+            &input,
+            Some(&bias),
+            &values,
+            &sparsity,
+            &mut output,
+            BATCHES as i32,
+            OUTPUT_DEPTH as i32,
+            ACCUM_DEPTH as i32,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+        assert_eq!(expected, output);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_fully_connected_matches_reference() {
+        const BATCHES: usize = 2;
+        const OUTPUT_DEPTH: usize = 5;
+        const ACCUM_DEPTH: usize = 37;
+        let input: [f32; BATCHES * ACCUM_DEPTH] =
+            core::array::from_fn(|i| (i % 9) as f32 * 0.3 - 1.2);
+        let filter: [f32; OUTPUT_DEPTH * ACCUM_DEPTH] =
+            core::array::from_fn(|i| (i % 7) as f32 * 0.2 - 0.6);
+        let bias = [0.1f32, -0.2, 0.3, 0.4, -0.5];
+
+        let mut expected = [0f32; BATCHES * OUTPUT_DEPTH];
+        OpFullyConnected::kernel(
+            &input,
+            Some(&bias),
+            &filter,
+            &mut expected,
+            BATCHES as i32,
+            OUTPUT_DEPTH as i32,
+            ACCUM_DEPTH as i32,
+            -6.,
+            6.,
+        )
+        .unwrap();
+
+        let mut output = [0f32; BATCHES * OUTPUT_DEPTH];
+        OpFullyConnected::simd_kernel(
+            &input,
+            Some(&bias),
+            &filter,
+            &mut output,
+            BATCHES as i32,
+            OUTPUT_DEPTH as i32,
+            ACCUM_DEPTH as i32,
+            -6.,
+            6.,
+        )
+        .unwrap();
+        for (o, e) in output.iter().zip(expected.iter()) {
+            assert!((o - e).abs() < 1e-4, "{o} vs {e}");
+        }
+    }
 }
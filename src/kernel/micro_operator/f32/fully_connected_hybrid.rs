@@ -0,0 +1,324 @@
+use crate::kernel::micro_activation::{activation_with_min_max, calculate_fused_activation_range};
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::f32::fully_connected::OpFullyConnected;
+use crate::kernel::micro_operator::BLiteOperator;
+use crate::kernel::utils::check_quantized_dimension;
+use crate::kernel::utils::quantization::{asymmetric_quantize_floats, symmetric_quantize_floats};
+use crate::kernel::utils::types::flat_skip_dims;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::{BLiteContext, ParallelElem};
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cell::Cell;
+use num_traits::{AsPrimitive, FromPrimitive};
+
+/// FULLY_CONNECTED of the hybrid (dynamic range) quantization,
+/// whose input and output are float, filter is int8 and bias is float.
+/// Models whose filter is float are evaluated by OpFullyConnected.
+#[derive(Debug, Clone, Copy)]
+pub struct OpFullyConnectedHybrid {}
+
+impl OpFullyConnectedHybrid {
+    const OPCODE: i32 = 9;
+
+    pub fn fully_connected_hybrid<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 9,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        // the activations of the hybrid quantization are float
+        let half: T = FromPrimitive::from_f32(0.5).ok_or(InCompatibleCasting)?;
+        if AsPrimitive::<f32>::as_(half) != 0.5 {
+            return Err(InCompatibleType);
+        }
+
+        let filter_idx = op.inputs().unwrap().get(1) as usize;
+        let BLiteTensor::I8Tensor(filter) = &tensors[filter_idx] else {
+            return OpFullyConnected::parser(allocator, op, tensors);
+        };
+        let filter_dims = filter.borrow().dims;
+        let output_depth = filter_dims[filter_dims.len() - 2] as usize;
+        let accum_depth = filter_dims[filter_dims.len() - 1];
+        let filter_scales = {
+            let Some(filter_quant_params) = filter.borrow().quant_params else {
+                return Err(NotFoundQuantParams);
+            };
+            // the weights of the hybrid quantization are symmetric
+            let zero_point = filter_quant_params.zero_point;
+            if let Some(&zp) = zero_point.iter().find(|&&zp| zp != 0) {
+                return Err(NotMatchZeroPoint(zp as i32));
+            }
+            check_quantized_dimension(&filter_quant_params, 0, output_depth)?;
+            filter_quant_params.scale
+        };
+
+        let builtin_option = op.builtin_options_as_fully_connected_options();
+        let mut op_code = -1;
+        let mut asymmetric_quantize_inputs = false;
+        if let Some(builtin_option) = builtin_option {
+            op_code = builtin_option.fused_activation_function().0 as i32;
+            asymmetric_quantize_inputs = builtin_option.asymmetric_quantize_inputs();
+        }
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range(op_code)?;
+
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let input_size = tensors[input_idx]._t()?.borrow().size();
+        let batches = input_size / accum_depth as usize;
+
+        let quantized_input = Self::alloc_cells::<i8>(allocator, input_size)?;
+        let scaling_factors = Self::alloc_cells::<f32>(allocator, batches)?;
+        let input_offsets = Self::alloc_cells::<i32>(allocator, batches)?;
+
+        Ok(BLiteBuiltinOption::HybridFullyConnectedOptions {
+            op_code,
+            fused_activation_min,
+            fused_activation_max,
+            asymmetric_quantize_inputs,
+            filter_scales,
+            quantized_input,
+            scaling_factors,
+            input_offsets,
+        })
+    }
+
+    fn alloc_cells<'a, E>(
+        allocator: &mut impl ArenaAllocator,
+        size: usize,
+    ) -> Result<&'a [Cell<E>]> {
+        let buffer = unsafe { alloc_array_mut::<E>(allocator, size) }?;
+        Ok(Cell::from_mut(buffer).as_slice_of_cells())
+    }
+
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T> + ParallelElem>(
+        context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let HybridFullyConnectedOptions {
+            op_code: _,
+            fused_activation_min,
+            fused_activation_max,
+            asymmetric_quantize_inputs,
+            filter_scales,
+            quantized_input,
+            scaling_factors,
+            input_offsets,
+        } = builtin_option
+        else {
+            return OpFullyConnected::eval(context, tensors, node, builtin_option);
+        };
+
+        let idx_input = node.inputs[0];
+        let input = tensors[idx_input as usize]._t()?.borrow();
+
+        let idx_filter = node.inputs[1];
+        let filter = tensors[idx_filter as usize]._i8()?.borrow();
+
+        let idx_output = node.outputs[0];
+        let mut output = tensors[idx_output as usize]._t()?.borrow_mut();
+
+        let batches = flat_skip_dims(output.dims, output.dims.len() - 1);
+        let output_depth = filter.dims[filter.dims.len() - 2];
+        let accum_depth = filter.dims[filter.dims.len() - 1];
+
+        // This computations is corresponded to the quantization of the inputs in EvalHybrid
+        Self::quantize_inputs(
+            input.data,
+            quantized_input,
+            scaling_factors,
+            input_offsets,
+            batches,
+            accum_depth,
+            asymmetric_quantize_inputs,
+        );
+
+        let idx_bias = node.inputs[2];
+        let bias = if idx_bias >= 0 {
+            Some(tensors[idx_bias as usize]._t()?.borrow())
+        } else {
+            None
+        };
+        Self::kernel(
+            quantized_input,
+            input_offsets,
+            scaling_factors,
+            bias.as_ref().map(|bias| &bias.data[..]),
+            filter.data,
+            filter_scales,
+            output.data,
+            batches,
+            output_depth,
+            accum_depth,
+            fused_activation_min,
+            fused_activation_max,
+        )
+    }
+
+    /// Quantizes each batch of the inputs into int8 with its own scaling factor and offset
+    pub fn quantize_inputs<T: ArrayElem<T>>(
+        input_data: &[T],
+        quantized_input: &[Cell<i8>],
+        scaling_factors: &[Cell<f32>],
+        input_offsets: &[Cell<i32>],
+        batches: i32,
+        accum_depth: i32,
+        asymmetric_quantize_inputs: bool,
+    ) {
+        let accum_depth = accum_depth as usize;
+        for batch in 0..batches as usize {
+            let values = &input_data[batch * accum_depth..(batch + 1) * accum_depth];
+            let quantized = &quantized_input[batch * accum_depth..(batch + 1) * accum_depth];
+            let (scaling_factor, input_offset) = if asymmetric_quantize_inputs {
+                asymmetric_quantize_floats(values, quantized)
+            } else {
+                (symmetric_quantize_floats(values, quantized), 0)
+            };
+            scaling_factors[batch].set(scaling_factor);
+            input_offsets[batch].set(input_offset);
+        }
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        quantized_input: &[Cell<i8>],
+        input_offsets: &[Cell<i32>],
+        scaling_factors: &[Cell<f32>],
+        bias_data: Option<&[T]>,
+        filter_data: &[i8],
+        filter_scales: &[f32],
+        output_data: &mut [T],
+        //
+        batches: i32,
+        output_depth: i32,
+        accum_depth: i32,
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        let accum_depth = accum_depth as usize;
+        for batch in 0..batches as usize {
+            let input_offset = input_offsets[batch].get();
+            let scaling_factor = scaling_factors[batch].get();
+            for out_d in 0..output_depth as usize {
+                let mut acc: i32 = 0;
+                for acc_d in 0..accum_depth {
+                    let input_v = quantized_input[batch * accum_depth + acc_d].get() as i32;
+                    let filter_v = filter_data[out_d * accum_depth + acc_d] as i32;
+                    acc += (input_v - input_offset) * filter_v;
+                }
+
+                // a per-tensor quantized filter has only one scale
+                let filter_scale = filter_scales[if filter_scales.len() == 1 { 0 } else { out_d }];
+                let mut total = acc as f32 * scaling_factor * filter_scale;
+                if let Some(bias_data) = bias_data {
+                    total += AsPrimitive::<f32>::as_(bias_data[out_d]);
+                }
+
+                let total: T = FromPrimitive::from_f32(total).ok_or(InCompatibleCasting)?;
+                output_data[batch * output_depth as usize + out_d] =
+                    activation_with_min_max(total, fused_activation_min, fused_activation_max);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpFullyConnectedHybrid;
+    use crate::kernel::micro_operator::f32::fully_connected::OpFullyConnected;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_hybrid_fully_connected_matches_float() {
+        const BATCHES: usize = 2;
+        const OUTPUT_DEPTH: usize = 3;
+        const ACCUM_DEPTH: usize = 4;
+        let input = [0.5f32, -1.25, 2., 0.75, -0.3, 0.9, 0.1, -2.2];
+        let filter = [
+            12i8, -127, 40, 3, //
+            -64, 5, 127, -90, //
+            0, 33, -21, 100,
+        ];
+        // per-channel filter scales
+        let filter_scales = [0.01f32, 0.02, 0.005];
+        let bias = [0.1f32, -0.2, 0.3];
+
+        let float_filter: [f32; OUTPUT_DEPTH * ACCUM_DEPTH] =
+            core::array::from_fn(|i| filter[i] as f32 * filter_scales[i / ACCUM_DEPTH]);
+        let mut expected = [0f32; BATCHES * OUTPUT_DEPTH];
+        OpFullyConnected::kernel(
+            &input,
+            Some(&bias),
+            &float_filter,
+            &mut expected,
+            BATCHES as i32,
+            OUTPUT_DEPTH as i32,
+            ACCUM_DEPTH as i32,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+
+        for asymmetric_quantize_inputs in [false, true] {
+            let mut quantized_input = [0i8; BATCHES * ACCUM_DEPTH];
+            let mut scaling_factors = [0f32; BATCHES];
+            let mut input_offsets = [0i32; BATCHES];
+            let quantized_input = Cell::from_mut(&mut quantized_input[..]).as_slice_of_cells();
+            let scaling_factors = Cell::from_mut(&mut scaling_factors[..]).as_slice_of_cells();
+            let input_offsets = Cell::from_mut(&mut input_offsets[..]).as_slice_of_cells();
+            OpFullyConnectedHybrid::quantize_inputs(
+                &input,
+                quantized_input,
+                scaling_factors,
+                input_offsets,
+                BATCHES as i32,
+                ACCUM_DEPTH as i32,
+                asymmetric_quantize_inputs,
+            );
+
+            let mut output = [0f32; BATCHES * OUTPUT_DEPTH];
+            OpFullyConnectedHybrid::kernel(
+                quantized_input,
+                input_offsets,
+                scaling_factors,
+                Some(&bias),
+                &filter,
+                &filter_scales,
+                &mut output,
+                BATCHES as i32,
+                OUTPUT_DEPTH as i32,
+                ACCUM_DEPTH as i32,
+                f32::MIN,
+                f32::MAX,
+            )
+            .unwrap();
+
+            for (o, e) in output.iter().zip(expected.iter()) {
+                assert!((o - e).abs() < 0.02, "{o} vs {e}");
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::kernel::micro_activation::activation_with_min_max;
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+use num_traits::FromPrimitive;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpHardSwish {}
+
+impl OpHardSwish {
+    const OPCODE: i32 = 117;
+
+    pub fn hard_swish<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Ok(BLiteBuiltinOption::HardSwishOptions {})
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let HardSwishOptions {} = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites the input if the memory planner gives them the same buffer
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let mut output = tensors[idx_output]._t()?.borrow_mut();
+            return Self::kernel_in_place(output.data);
+        }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        Self::kernel(input.data, output.data)
+    }
+
+    /// hard_swish(x) = x * relu6(x + 3) / 6
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(input_data: &[T], output_data: &mut [T]) -> Result<()> {
+        let zero: T = FromPrimitive::from_f32(0.).unwrap();
+        let three: T = FromPrimitive::from_f32(3.).unwrap();
+        let six: T = FromPrimitive::from_f32(6.).unwrap();
+        for (output_v, &input_v) in output_data.iter_mut().zip(input_data.iter()) {
+            let relu6 = activation_with_min_max(input_v + three, zero, six);
+            *output_v = input_v * relu6 / six;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(data: &mut [T]) -> Result<()> {
+        let zero: T = FromPrimitive::from_f32(0.).unwrap();
+        let three: T = FromPrimitive::from_f32(3.).unwrap();
+        let six: T = FromPrimitive::from_f32(6.).unwrap();
+        for v in data.iter_mut() {
+            let relu6 = activation_with_min_max(*v + three, zero, six);
+            *v = *v * relu6 / six;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpHardSwish;
+
+    #[test]
+    fn test_hard_swish() {
+        // the inputs below -3 are zero, and the inputs above 3 are the identity
+        let input = [-10f32, -3.5, -3., -1.5, -0.25, 0., 0.5, 1.5, 3., 4.5, 10.];
+        let expected = [
+            0f32,
+            0.,
+            0.,
+            -0.375,
+            -0.114583336,
+            0.,
+            0.29166666,
+            1.125,
+            3.,
+            4.5,
+            10.,
+        ];
+        let mut output = [0f32; 11];
+        OpHardSwish::kernel(&input, &mut output).unwrap();
+        assert_eq!(expected, output);
+
+        let mut data = input;
+        OpHardSwish::kernel_in_place(&mut data).unwrap();
+        assert_eq!(expected, data);
+    }
+}
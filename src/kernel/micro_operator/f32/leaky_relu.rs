@@ -0,0 +1,120 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+use num_traits::FromPrimitive;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpLeakyRelu {}
+
+impl OpLeakyRelu {
+    const OPCODE: i32 = 98;
+
+    pub fn leaky_relu<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_leaky_relu_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        let alpha = builtin_option.alpha();
+        Ok(BLiteBuiltinOption::LeakyReluOptions { alpha })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let LeakyReluOptions { alpha } = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+        let Some(alpha) = FromPrimitive::from_f32(alpha) else {
+            return Err(InCompatibleCasting);
+        };
+
+        // the output overwrites the input if the memory planner gives them the same buffer
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let mut output = tensors[idx_output]._t()?.borrow_mut();
+            return Self::kernel_in_place(output.data, alpha);
+        }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        Self::kernel(input.data, output.data, alpha)
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        alpha: T,
+    ) -> Result<()> {
+        let zero: T = Default::default();
+        for (output_v, &input_v) in output_data.iter_mut().zip(input_data.iter()) {
+            *output_v = if input_v > zero {
+                input_v
+            } else {
+                input_v * alpha
+            };
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(data: &mut [T], alpha: T) -> Result<()> {
+        let zero: T = Default::default();
+        for v in data.iter_mut() {
+            *v = if *v > zero { *v } else { *v * alpha };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpLeakyRelu;
+
+    #[test]
+    fn test_leaky_relu() {
+        let input = [-4., -0.5, 0., 0.5, 4.];
+        let expected = [-0.8, -0.1, 0., 0.5, 4.];
+
+        let mut output = [0.; 5];
+        OpLeakyRelu::kernel(&input, &mut output, 0.2).unwrap();
+        assert_eq!(expected, output);
+
+        let mut data = input;
+        OpLeakyRelu::kernel_in_place(&mut data, 0.2).unwrap();
+        assert_eq!(expected, data);
+    }
+}
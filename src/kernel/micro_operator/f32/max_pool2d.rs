@@ -6,6 +6,8 @@ use crate::kernel::micro_builtin_options::{
     BLiteBuiltinOption::{MaxPool2DOptions, NotInitialize},
 };
 use crate::kernel::utils::padding::compute_padding_height_width;
+#[cfg(feature = "simd")]
+use crate::kernel::utils::simd::{cast_slice, cast_slice_mut, cast_value, max_f32};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::ArrayElem;
 use crate::micro_context::BLiteContext;
@@ -29,6 +31,8 @@ impl OpMaxPool2D {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
         }
     }
 
@@ -120,6 +124,30 @@ impl OpMaxPool2D {
         else {
             return Err(NotCompatibleOption);
         };
+        #[cfg(feature = "simd")]
+        if let (Some(input_data), Some(output_data)) = (
+            cast_slice::<T, f32>(input.data),
+            cast_slice_mut::<T, f32>(output.data),
+        ) {
+            return Self::simd_kernel(
+                input_data,
+                output_data,
+                input_height,
+                input_width,
+                input_depth,
+                output_height,
+                output_width,
+                stride_w,
+                stride_h,
+                filter_w,
+                filter_h,
+                padding_w,
+                padding_h,
+                batches,
+                cast_value(fused_activation_min).unwrap(),
+                cast_value(fused_activation_max).unwrap(),
+            );
+        }
         Self::kernel(
             input.data,
             output.data,
@@ -218,8 +246,132 @@ impl OpMaxPool2D {
         Ok(())
     }
 
+    /// The channels of each output pixel are reduced by the vectorised max
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    pub fn simd_kernel(
+        input_data: &[f32],
+        output_data: &mut [f32],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        output_height: i32,
+        output_width: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        filter_w: i32,
+        filter_h: i32,
+        padding_w: i32,
+        padding_h: i32,
+        //
+        batches: i32,
+        fused_activation_min: f32,
+        fused_activation_max: f32,
+    ) -> Result<()> {
+        let depth = input_depth as usize;
+        for batch in 0..batches {
+            for out_y in 0..output_height {
+                for out_x in 0..output_width {
+                    let in_x_origin = (out_x * stride_w) - padding_w;
+                    let in_y_origin = (out_y * stride_h) - padding_h;
+                    let filter_x_start = core::cmp::max(0, -in_x_origin);
+                    let filter_x_end = core::cmp::min(filter_w, input_width - in_x_origin);
+                    let filter_y_start = core::cmp::max(0, -in_y_origin);
+                    let filter_y_end = core::cmp::min(filter_h, input_height - in_y_origin);
+                    let output_idx = Self::offset(
+                        output_height,
+                        output_width,
+                        input_depth,
+                        batch,
+                        out_y,
+                        out_x,
+                        0,
+                    ) as usize;
+                    let output = &mut output_data[output_idx..output_idx + depth];
+                    output.fill(f32::MIN);
+                    for filter_y in filter_y_start..filter_y_end {
+                        for filter_x in filter_x_start..filter_x_end {
+                            let input_idx = Self::offset(
+                                input_height,
+                                input_width,
+                                input_depth,
+                                batch,
+                                in_y_origin + filter_y,
+                                in_x_origin + filter_x,
+                                0,
+                            ) as usize;
+                            max_f32(output, &input_data[input_idx..input_idx + depth]);
+                        }
+                    }
+                    for v in output.iter_mut() {
+                        *v =
+                            activation_with_min_max(*v, fused_activation_min, fused_activation_max);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[inline(always)]
     fn offset(h: i32, w: i32, d: i32, i0: i32, i1: i32, i2: i32, i3: i32) -> i32 {
         ((i0 * h + i1) * w + i2) * d + i3
     }
 }
+
+#[cfg(all(test, feature = "simd"))]
+mod tests {
+    use super::OpMaxPool2D;
+
+    #[test]
+    fn test_simd_max_pool2d_matches_reference() {
+        // input 2x5x5x35, the filter 3x3 with the stride 2 and the padding 1, output 2x3x3x35
+        const DEPTH: usize = 35;
+        let input: [f32; 2 * 25 * DEPTH] = core::array::from_fn(|i| (i % 13) as f32 * 0.7 - 4.);
+        let mut expected = [0f32; 2 * 9 * DEPTH];
+        let mut output = [0f32; 2 * 9 * DEPTH];
+        OpMaxPool2D::kernel(
+            &input,
+            &mut expected,
+            5,
+            5,
+            DEPTH as i32,
+            3,
+            3,
+            DEPTH as i32,
+            2,
+            2,
+            3,
+            3,
+            1,
+            1,
+            2,
+            0.,
+            3.,
+        )
+        .unwrap();
+        OpMaxPool2D::simd_kernel(
+            &input,
+            &mut output,
+            5,
+            5,
+            DEPTH as i32,
+            3,
+            3,
+            2,
+            2,
+            3,
+            3,
+            1,
+            1,
+            2,
+            0.,
+            3.,
+        )
+        .unwrap();
+        assert_eq!(expected, output);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::broadcast::broadcast_index;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpPRelu {}
+
+impl OpPRelu {
+    const OPCODE: i32 = 54;
+
+    pub fn prelu<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Ok(BLiteBuiltinOption::PReluOptions {})
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+
+        let idx_alpha = node.inputs[1] as usize;
+        let alpha = tensors[idx_alpha]._t()?.borrow();
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        let PReluOptions {} = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(input.data, input.dims, alpha.data, alpha.dims, output.data)
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        alpha_data: &[T],
+        alpha_dims: &[i32],
+        output_data: &mut [T],
+    ) -> Result<()> {
+        let zero: T = Default::default();
+        for (i, output_v) in output_data.iter_mut().enumerate() {
+            let input_v = input_data[i];
+            *output_v = if input_v >= zero {
+                input_v
+            } else {
+                input_v * alpha_data[broadcast_index(i, input_dims, alpha_dims)]
+            };
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpPRelu;
+
+    #[test]
+    fn test_prelu_alpha_broadcast() {
+        // input of shape [2, 2, 3]
+        let input: Vec<f32> = (0..12).map(|x| x as f32 - 8.).collect();
+
+        // alpha of shape [3] is shared by the channels of all the pixels
+        let alpha = [0.1, 0.2, 0.3];
+        let mut output = vec![0.; 12];
+        OpPRelu::kernel(&input, &[2, 2, 3], &alpha, &[3], &mut output).unwrap();
+        for (i, (&input_v, &output_v)) in input.iter().zip(output.iter()).enumerate() {
+            let expected = if input_v >= 0. {
+                input_v
+            } else {
+                input_v * alpha[i % 3]
+            };
+            assert_eq!(expected, output_v);
+        }
+
+        // alpha of shape [2, 1, 1] is shared by all the elements of each batch
+        let alpha = [0.5, 0.25];
+        OpPRelu::kernel(&input, &[2, 2, 3], &alpha, &[2, 1, 1], &mut output).unwrap();
+        for (i, (&input_v, &output_v)) in input.iter().zip(output.iter()).enumerate() {
+            let expected = if input_v >= 0. {
+                input_v
+            } else {
+                input_v * alpha[i / 6]
+            };
+            assert_eq!(expected, output_v);
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::kernel::micro_activation::{activation_with_min_max, calculate_fused_activation_range};
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// RELU, RELU_N1_TO_1 and RELU6 only differ in their clamping range,
+/// so they share a kernel and are registered under different operator codes.
+#[derive(Debug, Clone, Copy)]
+pub struct OpRelu {}
+
+impl OpRelu {
+    const RELU_OPCODE: i32 = 19;
+    const RELU_N1_TO_1_OPCODE: i32 = 20;
+    const RELU6_OPCODE: i32 = 21;
+
+    pub fn relu<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(Self::RELU_OPCODE),
+            parser: Self::relu_parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn relu_n1_to_1<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(Self::RELU_N1_TO_1_OPCODE),
+            parser: Self::relu_n1_to_1_parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn relu6<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(Self::RELU6_OPCODE),
+            parser: Self::relu6_parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn relu_parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Self::parser(1 /* Relu */)
+    }
+
+    pub fn relu_n1_to_1_parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Self::parser(2 /* ReluN1To1 */)
+    }
+
+    pub fn relu6_parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Self::parser(3 /* Relu6 */)
+    }
+
+    fn parser<'a, T: ArrayElem<T>>(activation: i32) -> Result<BLiteBuiltinOption<'a, T>> {
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range(activation)?;
+        Ok(BLiteBuiltinOption::ReluOptions {
+            fused_activation_min,
+            fused_activation_max,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>(op_code: i32) -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(op_code, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let ReluOptions {
+            fused_activation_min,
+            fused_activation_max,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites the input if the memory planner gives them the same buffer
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let mut output = tensors[idx_output]._t()?.borrow_mut();
+            return Self::kernel_in_place(output.data, fused_activation_min, fused_activation_max);
+        }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        Self::kernel(
+            input.data,
+            output.data,
+            fused_activation_min,
+            fused_activation_max,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        for (output_v, &input_v) in output_data.iter_mut().zip(input_data.iter()) {
+            *output_v =
+                activation_with_min_max(input_v, fused_activation_min, fused_activation_max);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(
+        data: &mut [T],
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        for v in data.iter_mut() {
+            *v = activation_with_min_max(*v, fused_activation_min, fused_activation_max);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpRelu;
+    use crate::kernel::micro_builtin_options::BLiteBuiltinOption::ReluOptions;
+
+    #[test]
+    fn test_relu_clamping_ranges() {
+        let input = [-8., -1.5, -0.5, 0., 0.5, 1.5, 5., 8.];
+        for (activation, expected) in [
+            (1, [0., 0., 0., 0., 0.5, 1.5, 5., 8.]),
+            (2, [-1., -1., -0.5, 0., 0.5, 1., 1., 1.]),
+            (3, [0., 0., 0., 0., 0.5, 1.5, 5., 6.]),
+        ] {
+            let Ok(ReluOptions {
+                fused_activation_min,
+                fused_activation_max,
+            }) = OpRelu::parser::<f32>(activation)
+            else {
+                panic!("activation: {activation}");
+            };
+
+            let mut output = [0.; 8];
+            OpRelu::kernel(
+                &input,
+                &mut output,
+                fused_activation_min,
+                fused_activation_max,
+            )
+            .unwrap();
+            assert_eq!(expected, output, "activation: {activation}");
+
+            let mut data = input;
+            OpRelu::kernel_in_place(&mut data, fused_activation_min, fused_activation_max).unwrap();
+            assert_eq!(expected, data, "activation: {activation}");
+        }
+    }
+}
@@ -5,7 +5,7 @@ use crate::micro_context::BLiteContext;
 use crate::micro_errors::BLiteError::InCompatibleShape;
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
-use crate::micro_registration::BLiteRegistration;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
 use crate::micro_tensor::BLiteTensor;
 use crate::tflite_schema_generated::tflite::Operator;
 use core::fmt::Debug;
@@ -22,6 +22,8 @@ impl OpReshape {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
         }
     }
 
@@ -35,6 +37,7 @@ impl OpReshape {
 
     pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
         BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::OutputAliasesInput)
     }
 
     pub fn eval<'a, T: ArrayElem<T>>(
@@ -44,21 +47,22 @@ impl OpReshape {
         _builtin_option: BLiteBuiltinOption<T>,
     ) -> Result<()> {
         let idx_input = node.inputs[0] as usize;
-        let input = tensors[idx_input]._t()?.borrow();
-
         let idx_output = node.outputs[0] as usize;
-        let mut output = tensors[idx_output]._t()?.borrow_mut();
 
         // shape checking
-        let input_elems = input.dims.iter().fold(1, |x, acc| x * acc);
-        let output_elems = output.dims.iter().fold(1, |x, acc| x * acc);
+        let input_elems = tensors[idx_input].dims().iter().fold(1, |x, acc| x * acc);
+        let output_elems = tensors[idx_output].dims().iter().fold(1, |x, acc| x * acc);
         if input_elems != output_elems {
             return Err(InCompatibleShape(input_elems, output_elems));
         }
 
-        for i in 0..input_elems {
-            output.data[i as usize] = input.data[i as usize];
+        // the output already holds the data if the memory planner aliases it to the input
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            return Ok(());
         }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+        output.data.copy_from_slice(input.data);
         Ok(())
     }
 }
@@ -0,0 +1,226 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+#[cfg(feature = "no_std")]
+use num_traits::Float;
+use num_traits::FromPrimitive;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpResizeBilinear {}
+
+impl OpResizeBilinear {
+    const OPCODE: i32 = 23;
+
+    pub fn resize_bilinear<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_resize_bilinear_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+
+        // The output size is given by a constant tensor of [height, width]
+        let size_idx = op.inputs().unwrap().get(1) as usize;
+        let size = tensors[size_idx]._i32()?.borrow();
+        let [output_height, output_width] = size.data[..] else {
+            return Err(NotFoundBufferData);
+        };
+
+        Ok(BLiteBuiltinOption::ResizeBilinearOptions {
+            align_corners: builtin_option.align_corners(),
+            half_pixel_centers: builtin_option.half_pixel_centers(),
+            output_height,
+            output_width,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+        let batches = input.dims[0];
+        let input_height = input.dims[1];
+        let input_width = input.dims[2];
+        let depth = input.dims[3];
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        let ResizeBilinearOptions {
+            align_corners,
+            half_pixel_centers,
+            output_height,
+            output_width,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(
+            input.data,
+            output.data,
+            batches,
+            input_height,
+            input_width,
+            depth,
+            output_height,
+            output_width,
+            align_corners,
+            half_pixel_centers,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        //
+        batches: i32,
+        input_height: i32,
+        input_width: i32,
+        depth: i32,
+        output_height: i32,
+        output_width: i32,
+        //
+        align_corners: bool,
+        half_pixel_centers: bool,
+    ) -> Result<()> {
+        if output_data.len() != (batches * output_height * output_width * depth) as usize {
+            return Err(NotMatchSize);
+        }
+        let height_scale = Self::scale(input_height, output_height, align_corners);
+        let width_scale = Self::scale(input_width, output_width, align_corners);
+
+        let mut output_idx = 0;
+        for b in 0..batches {
+            for y in 0..output_height {
+                let (input_y, y0, y1) =
+                    Self::interpolation_values(y, height_scale, half_pixel_centers, input_height);
+                for x in 0..output_width {
+                    let (input_x, x0, x1) =
+                        Self::interpolation_values(x, width_scale, half_pixel_centers, input_width);
+                    let offset = |y: i32, x: i32| {
+                        (((b * input_height + y) * input_width + x) * depth) as usize
+                    };
+                    let (offset_00, offset_10) = (offset(y0, x0), offset(y1, x0));
+                    let (offset_01, offset_11) = (offset(y0, x1), offset(y1, x1));
+                    let (dy, dx) = (input_y - y0 as f32, input_x - x0 as f32);
+                    for c in 0..depth as usize {
+                        let v_00: f32 = input_data[offset_00 + c].as_();
+                        let v_10: f32 = input_data[offset_10 + c].as_();
+                        let v_01: f32 = input_data[offset_01 + c].as_();
+                        let v_11: f32 = input_data[offset_11 + c].as_();
+                        let interpolation = v_00 * (1. - dy) * (1. - dx)
+                            + v_10 * dy * (1. - dx)
+                            + v_01 * (1. - dy) * dx
+                            + v_11 * dy * dx;
+                        output_data[output_idx] = FromPrimitive::from_f32(interpolation).unwrap();
+                        output_idx += 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn scale(input_size: i32, output_size: i32, align_corners: bool) -> f32 {
+        if align_corners && output_size > 1 {
+            (input_size - 1) as f32 / (output_size - 1) as f32
+        } else {
+            input_size as f32 / output_size as f32
+        }
+    }
+
+    /// This computations is corresponded to ComputeInterpolationValues
+    #[inline(always)]
+    fn interpolation_values(
+        value: i32,
+        scale: f32,
+        half_pixel_centers: bool,
+        input_size: i32,
+    ) -> (f32, i32, i32) {
+        let scaled_value = if half_pixel_centers {
+            (value as f32 + 0.5) * scale - 0.5
+        } else {
+            value as f32 * scale
+        };
+        let lower_bound = max(scaled_value.floor() as i32, 0);
+        let upper_bound = min(scaled_value.ceil() as i32, input_size - 1);
+        (scaled_value, lower_bound, upper_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpResizeBilinear;
+
+    #[test]
+    fn test_resize_bilinear() {
+        // 1x2x2x1 is resized to 1x3x3x1
+        let input = [3.0f32, 6.0, 9.0, 12.0];
+        let mut output = [0.0f32; 9];
+        OpResizeBilinear::kernel(&input, &mut output, 1, 2, 2, 1, 3, 3, false, false).unwrap();
+        let expected = [3.0, 5.0, 6.0, 7.0, 9.0, 10.0, 9.0, 11.0, 12.0];
+        for (&y, expected) in output.iter().zip(expected) {
+            assert!(
+                (y - expected).abs() < 1e-5,
+                "expected {}, but got {}",
+                expected,
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn test_resize_bilinear_align_corners_and_half_pixel_centers() {
+        // 2x2x2x1 is resized to 2x3x3x1 with align_corners
+        let input = [0.0f32, 40.0, 80.0, 120.0, 0.0, -40.0, -80.0, -120.0];
+        let mut output = [0.0f32; 18];
+        OpResizeBilinear::kernel(&input, &mut output, 2, 2, 2, 1, 3, 3, true, false).unwrap();
+        let expected = [
+            0.0, 20.0, 40.0, 40.0, 60.0, 80.0, 80.0, 100.0, 120.0, 0.0, -20.0, -40.0, -40.0, -60.0,
+            -80.0, -80.0, -100.0, -120.0,
+        ];
+        assert_eq!(expected, output);
+
+        // half pixel centers clamp the corners to the input
+        let mut output = [0.0f32; 16];
+        OpResizeBilinear::kernel(&input[..4], &mut output, 1, 2, 2, 1, 4, 4, false, true).unwrap();
+        let expected = [
+            0.0, 10.0, 30.0, 40.0, 20.0, 30.0, 50.0, 60.0, 60.0, 70.0, 90.0, 100.0, 80.0, 90.0,
+            110.0, 120.0,
+        ];
+        assert_eq!(expected, output);
+    }
+}
@@ -28,6 +28,8 @@ impl OpSoftMax {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
         }
     }
 
@@ -67,10 +69,20 @@ impl OpSoftMax {
             return Err(NotFoundOption);
         };
 
+        Self::kernel(input.data, output.data, outer_size, depth, beta)
+    }
+
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        outer_size: i32,
+        depth: i32,
+        beta: f32,
+    ) -> Result<()> {
         for i in 0..outer_size {
             let mut max = Default::default();
             for c in 0..depth {
-                let input_v = input.data[(i * depth + c) as usize];
+                let input_v = input_data[(i * depth + c) as usize];
                 if input_v > max {
                     max = input_v;
                 }
@@ -80,18 +92,18 @@ impl OpSoftMax {
             for c in 0..depth {
                 let idx = (i * depth + c) as usize;
                 let Some(exp_c) = FromPrimitive::from_f32(
-                    (AsPrimitive::<f32>::as_(input.data[idx] - max) * beta).exp(),
+                    (AsPrimitive::<f32>::as_(input_data[idx] - max) * beta).exp(),
                 ) else {
                     return Err(InCompatibleCasting);
                 };
 
-                output.data[idx] = exp_c;
+                output_data[idx] = exp_c;
                 sum = sum + exp_c;
             }
 
             for c in 0..depth {
                 let idx = (i * depth + c) as usize;
-                output.data[idx] = output.data[idx] / sum;
+                output_data[idx] = output_data[idx] / sum;
             }
         }
         Ok(())
@@ -0,0 +1,120 @@
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+// Importing Float module is necessary to calculate the hyperbolic tangent of a f32 value.
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpTanh {}
+
+impl OpTanh {
+    const OPCODE: i32 = 28;
+
+    pub fn tanh<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Ok(BLiteBuiltinOption::TanhOptions {})
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let TanhOptions {} = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites the input if the memory planner gives them the same buffer
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let mut output = tensors[idx_output]._t()?.borrow_mut();
+            return Self::kernel_in_place(output.data);
+        }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        Self::kernel(input.data, output.data)
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(input_data: &[T], output_data: &mut [T]) -> Result<()> {
+        for (output_v, &input_v) in output_data.iter_mut().zip(input_data.iter()) {
+            *output_v = Self::tanh_value(input_v)?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(data: &mut [T]) -> Result<()> {
+        for v in data.iter_mut() {
+            *v = Self::tanh_value(*v)?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn tanh_value<T: ArrayElem<T>>(x: T) -> Result<T> {
+        FromPrimitive::from_f32(Self::tanh_f32(AsPrimitive::<f32>::as_(x)))
+            .ok_or(InCompatibleCasting)
+    }
+
+    #[inline(always)]
+    pub fn tanh_f32(x: f32) -> f32 {
+        x.tanh()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpTanh;
+
+    #[test]
+    fn test_tanh_close_to_float() {
+        // the large inputs must saturate without overflowing exp
+        let mut input: Vec<f32> = (-40..=40).map(|x| x as f32 * 0.25).collect();
+        input.extend([-100., 100.]);
+        let mut output = vec![0f32; input.len()];
+        OpTanh::kernel(&input, &mut output).unwrap();
+        let mut in_place = input.clone();
+        OpTanh::kernel_in_place(&mut in_place).unwrap();
+        assert_eq!(output, in_place);
+
+        for (&x, &y) in input.iter().zip(output.iter()) {
+            let expected = (x as f64).tanh();
+            assert!((expected - y as f64).abs() <= 1e-6, "{expected} != {y}");
+        }
+    }
+}
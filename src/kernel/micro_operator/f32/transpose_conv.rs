@@ -0,0 +1,319 @@
+use crate::kernel::micro_activation::{activation_with_min_max, calculate_fused_activation_range};
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::padding::compute_padding_height_width;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpTransposeConv {}
+
+impl OpTransposeConv {
+    const OPCODE: i32 = 67;
+
+    pub fn transpose_conv<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 4,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_transpose_conv_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        let op_code = builtin_option.fused_activation_function().0 as i32;
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range(op_code)?;
+        let padding = builtin_option.padding().0 as usize;
+        let stride_w = builtin_option.stride_w();
+        let stride_h = builtin_option.stride_h();
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let output_shape_idx = op.inputs().unwrap().get(0) as usize;
+        Self::check_output_shape(tensors, output_shape_idx, output_idx)?;
+        let output_h = tensors[output_idx]._t()?.borrow().dims[1];
+        let output_w = tensors[output_idx]._t()?.borrow().dims[2];
+
+        let filter_idx = op.inputs().unwrap().get(1) as usize;
+        let filter_h = tensors[filter_idx]._t()?.borrow().dims[1];
+        let filter_w = tensors[filter_idx]._t()?.borrow().dims[2];
+
+        let input_idx = op.inputs().unwrap().get(2) as usize;
+        let input_h = tensors[input_idx]._t()?.borrow().dims[1];
+        let input_w = tensors[input_idx]._t()?.borrow().dims[2];
+
+        // The padding of a transpose convolution is the one of the convolution
+        // that maps the output back to the input
+        let (padding_h, padding_h_offset, padding_w, padding_w_offset) =
+            compute_padding_height_width(
+                padding, stride_h, stride_w, 1, 1, output_h, output_w, filter_h, filter_w, input_h,
+                input_w,
+            );
+        Ok(BLiteBuiltinOption::TransposeConvOptions {
+            op_code,
+            fused_activation_min,
+            fused_activation_max,
+            padding,
+            padding_w,
+            padding_h,
+            padding_w_offset,
+            padding_h_offset,
+            stride_w,
+            stride_h,
+        })
+    }
+
+    /// The output shape is given by a constant int32 tensor,
+    /// which has to agree with the shape of the output tensor.
+    pub fn check_output_shape<'a, T: ArrayElem<T>>(
+        tensors: &[BLiteTensor<'a, T>],
+        output_shape_idx: usize,
+        output_idx: usize,
+    ) -> Result<()> {
+        let output_shape = tensors[output_shape_idx]._i32()?.borrow();
+        let output = tensors[output_idx]._t()?.borrow();
+        if output.dims.len() != 4 {
+            return Err(InCompatibleShape(output.dims.len() as i32, 4));
+        }
+        for (&expected, &actual) in output_shape.data.iter().zip(output.dims.iter()) {
+            if expected != actual {
+                return Err(InCompatibleShape(expected, actual));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_filter = node.inputs[1] as usize;
+        let filter = tensors[idx_filter]._t()?.borrow();
+        let filter_height = filter.dims[1];
+        let filter_width = filter.dims[2];
+
+        let idx_input = node.inputs[2] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+        let input_height = input.dims[1];
+        let input_width = input.dims[2];
+        let input_depth = input.dims[3];
+
+        // the bias is optional
+        let bias = match node.inputs.get(3) {
+            Some(&idx_bias) if idx_bias >= 0 => Some(tensors[idx_bias as usize]._t()?.borrow()),
+            _ => None,
+        };
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+        let output_height = output.dims[1];
+        let output_width = output.dims[2];
+        let output_depth = output.dims[3];
+
+        let batches = input.dims[0] as usize;
+
+        let TransposeConvOptions {
+            op_code: _,
+            fused_activation_min,
+            fused_activation_max,
+            padding: _,
+            padding_w,
+            padding_h,
+            padding_w_offset: _,
+            padding_h_offset: _,
+            stride_w,
+            stride_h,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(
+            input.data,
+            filter.data,
+            bias.as_ref().map(|bias| &*bias.data),
+            output.data,
+            input_height,
+            input_width,
+            input_depth,
+            filter_height,
+            filter_width,
+            output_height,
+            output_width,
+            output_depth,
+            stride_w,
+            stride_h,
+            padding_w,
+            padding_h,
+            batches,
+            fused_activation_min,
+            fused_activation_max,
+        )
+    }
+
+    /// This kernel follows reference_ops::TransposeConv of tensorflow lite,
+    /// which scatters each input element to the output elements it influences.
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: &[T],
+        bias_data: Option<&[T]>,
+        output_data: &mut [T],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        output_height: i32,
+        output_width: i32,
+        output_depth: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        padding_w: i32,
+        padding_h: i32,
+        //
+        batches: usize,
+        fused_activation_min: T,
+        fused_activation_max: T,
+    ) -> Result<()> {
+        output_data.fill(Default::default());
+
+        for batch in 0..batches as i32 {
+            for in_y in 0..input_height {
+                let out_y_origin = (in_y * stride_h) - padding_h;
+                for in_x in 0..input_width {
+                    let out_x_origin = (in_x * stride_w) - padding_w;
+                    for in_channel in 0..input_depth {
+                        let input_v_idx = Self::offset(
+                            input_height,
+                            input_width,
+                            input_depth,
+                            batch,
+                            in_y,
+                            in_x,
+                            in_channel,
+                        );
+                        let input_v = input_data[input_v_idx as usize];
+                        for filter_y in 0..filter_height {
+                            let out_y = out_y_origin + filter_y;
+                            for filter_x in 0..filter_width {
+                                let out_x = out_x_origin + filter_x;
+                                let is_point_inside_image = (out_x >= 0)
+                                    && (out_x < output_width)
+                                    && (out_y >= 0)
+                                    && (out_y < output_height);
+                                if !is_point_inside_image {
+                                    continue;
+                                }
+
+                                for out_channel in 0..output_depth {
+                                    let filter_v_idx = Self::offset(
+                                        filter_height,
+                                        filter_width,
+                                        input_depth,
+                                        out_channel,
+                                        filter_y,
+                                        filter_x,
+                                        in_channel,
+                                    );
+                                    let output_v_idx = Self::offset(
+                                        output_height,
+                                        output_width,
+                                        output_depth,
+                                        batch,
+                                        out_y,
+                                        out_x,
+                                        out_channel,
+                                    );
+                                    output_data[output_v_idx as usize] +=
+                                        input_v * filter_data[filter_v_idx as usize];
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for output_pixel in output_data.chunks_exact_mut(output_depth as usize) {
+            for (out_channel, output_v) in output_pixel.iter_mut().enumerate() {
+                if let Some(bias_data) = bias_data {
+                    *output_v += bias_data[out_channel];
+                }
+                *output_v =
+                    activation_with_min_max(*output_v, fused_activation_min, fused_activation_max);
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn offset(h: i32, w: i32, d: i32, i0: i32, i1: i32, i2: i32, i3: i32) -> i32 {
+        ((i0 * h + i1) * w + i2) * d + i3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpTransposeConv;
+
+    #[test]
+    fn test_transpose_conv_stride_2() {
+        // 1x2x2x1 is upsampled to 1x4x4x1 by a 2x2 filter of ones
+        let input = [1.0f32, 2.0, 3.0, 4.0];
+        let filter = [1.0f32; 4];
+        let bias = [0.5f32];
+        let mut output = [0.0f32; 16];
+        OpTransposeConv::kernel(
+            &input,
+            &filter,
+            Some(&bias),
+            &mut output,
+            2,
+            2,
+            1,
+            2,
+            2,
+            4,
+            4,
+            1,
+            2,
+            2,
+            0,
+            0,
+            1,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+        let expected = [
+            1.5, 1.5, 2.5, 2.5, 1.5, 1.5, 2.5, 2.5, 3.5, 3.5, 4.5, 4.5, 3.5, 3.5, 4.5, 4.5,
+        ];
+        assert_eq!(expected, output);
+    }
+}
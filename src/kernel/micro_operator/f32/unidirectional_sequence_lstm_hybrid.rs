@@ -0,0 +1,714 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::f32::logistic::OpLogistic;
+use crate::kernel::micro_operator::f32::tanh::OpTanh;
+use crate::kernel::micro_operator::BLiteOperator;
+use crate::kernel::utils::quantization::{asymmetric_quantize_values, symmetric_quantize_values};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteArray};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cell::{Cell, Ref};
+use num_traits::{AsPrimitive, FromPrimitive};
+
+// the gates in the order of gates in HybridUnidirectionalSequenceLSTMOptions
+const INPUT_GATE: usize = 0;
+const FORGET_GATE: usize = 1;
+const CELL_GATE: usize = 2;
+const OUTPUT_GATE: usize = 3;
+
+// the indices of the inputs of UNIDIRECTIONAL_SEQUENCE_LSTM
+const INPUT: usize = 0;
+const INPUT_TO_GATE_WEIGHTS: usize = 1;
+const RECURRENT_TO_GATE_WEIGHTS: usize = 5;
+// the peepholes of the input, forget and output gates
+const CELL_TO_GATE_WEIGHTS: [usize; 3] = [9, 10, 11];
+const GATE_BIASES: usize = 12;
+const PROJECTION_WEIGHTS: usize = 16;
+const PROJECTION_BIAS: usize = 17;
+const OUTPUT_STATE: usize = 18;
+const CELL_STATE: usize = 19;
+const LAYER_NORM_COEFFICIENTS: usize = 20;
+
+/// The int8 weights of the hybrid quantization, whose scale is per tensor
+#[derive(Debug, Clone, Copy)]
+pub struct HybridWeights<'b> {
+    pub data: &'b [i8],
+    pub scale: f32,
+}
+
+/// The weights and biases of the gates in the order of the input, forget, cell and output gates.
+/// The input gate is absent with CIFG, and the peepholes and the projection are optional.
+#[derive(Debug, Clone, Copy)]
+pub struct HybridLSTMWeights<'b, T> {
+    pub input_to_gates: [Option<HybridWeights<'b>>; 4],
+    pub recurrent_to_gates: [Option<HybridWeights<'b>>; 4],
+    pub cell_to_gates: [Option<HybridWeights<'b>>; 4],
+    pub gate_biases: [Option<&'b [T]>; 4],
+    pub projection: Option<HybridWeights<'b>>,
+    pub projection_bias: Option<&'b [T]>,
+}
+
+/// UNIDIRECTIONAL_SEQUENCE_LSTM of the hybrid (dynamic range) quantization,
+/// whose input, states and output are float, weights are int8 and biases are float.
+/// The states are variable tensors, which keep their values across invocations.
+/// Models whose weights are float or which normalize the gates by layers are not supported.
+#[derive(Debug, Clone, Copy)]
+pub struct OpUnidirectionalSequenceLSTMHybrid {}
+
+impl OpUnidirectionalSequenceLSTMHybrid {
+    const OPCODE: i32 = 44;
+
+    pub fn unidirectional_sequence_lstm_hybrid<'a, T: ArrayElem<T>, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        // the activations of the hybrid quantization are float
+        let half: T = FromPrimitive::from_f32(0.5).ok_or(InCompatibleCasting)?;
+        if AsPrimitive::<f32>::as_(half) != 0.5 {
+            return Err(InCompatibleType);
+        }
+
+        let inputs = op.inputs().unwrap();
+        let input_idx = |idx: usize| {
+            if idx < inputs.len() {
+                inputs.get(idx)
+            } else {
+                -1
+            }
+        };
+        // the weights of the hybrid quantization are symmetric int8
+        let weight_indices = (INPUT_TO_GATE_WEIGHTS..GATE_BIASES).chain([PROJECTION_WEIGHTS]);
+        for idx in weight_indices.map(input_idx).filter(|&idx| idx >= 0) {
+            let BLiteTensor::I8Tensor(weights) = &tensors[idx as usize] else {
+                return Err(InCompatibleType);
+            };
+            let Some(quant_params) = weights.borrow().quant_params else {
+                return Err(NotFoundQuantParams);
+            };
+            if let Some(&zp) = quant_params.zero_point.iter().find(|&&zp| zp != 0) {
+                return Err(NotMatchZeroPoint(zp as i32));
+            }
+        }
+        if (LAYER_NORM_COEFFICIENTS..LAYER_NORM_COEFFICIENTS + 4).any(|idx| input_idx(idx) >= 0) {
+            return Err(NotCompatibleOption);
+        }
+
+        let builtin_option = op.builtin_options_as_unidirectional_sequence_lstmoptions();
+        let mut op_code = 0;
+        let mut cell_clip = 0.;
+        let mut proj_clip = 0.;
+        let mut time_major = false;
+        let mut asymmetric_quantize_inputs = false;
+        if let Some(builtin_option) = builtin_option {
+            op_code = builtin_option.fused_activation_function().0 as i32;
+            cell_clip = builtin_option.cell_clip();
+            proj_clip = builtin_option.proj_clip();
+            time_major = builtin_option.time_major();
+            asymmetric_quantize_inputs = builtin_option.asymmetric_quantize_inputs();
+        }
+        if !(0..=4).contains(&op_code) {
+            return Err(NotFoundFusedActivation(op_code));
+        }
+
+        // the output gate is not optional, whose weights give the sizes of the cell and the output
+        let n_input = *tensors[input_idx(INPUT) as usize]
+            ._t()?
+            .borrow()
+            .dims
+            .last()
+            .unwrap();
+        let output_weights_idx = input_idx(INPUT_TO_GATE_WEIGHTS + OUTPUT_GATE) as usize;
+        let n_cell = tensors[output_weights_idx]._i8()?.borrow().dims[0];
+        let recurrent_weights_idx = input_idx(RECURRENT_TO_GATE_WEIGHTS + OUTPUT_GATE) as usize;
+        let n_output = tensors[recurrent_weights_idx]._i8()?.borrow().dims[1];
+        let n_hidden = if input_idx(PROJECTION_WEIGHTS) >= 0 {
+            n_cell
+        } else {
+            0
+        };
+
+        let quantized_input = Self::alloc_cells::<i8>(allocator, n_input as usize)?;
+        let quantized_output_state = Self::alloc_cells::<i8>(allocator, n_output as usize)?;
+        let quantized_hidden = Self::alloc_cells::<i8>(allocator, n_hidden as usize)?;
+        let gates = Self::alloc_cells::<f32>(allocator, 4 * n_cell as usize)?;
+        let hidden = Self::alloc_cells::<f32>(allocator, n_cell as usize)?;
+
+        Ok(
+            BLiteBuiltinOption::HybridUnidirectionalSequenceLSTMOptions {
+                op_code,
+                cell_clip,
+                proj_clip,
+                time_major,
+                asymmetric_quantize_inputs,
+                quantized_input,
+                quantized_output_state,
+                quantized_hidden,
+                gates,
+                hidden,
+            },
+        )
+    }
+
+    fn alloc_cells<'a, E>(
+        allocator: &mut impl ArenaAllocator,
+        size: usize,
+    ) -> Result<&'a [Cell<E>]> {
+        let buffer = unsafe { alloc_array_mut::<E>(allocator, size) }?;
+        Ok(Cell::from_mut(buffer).as_slice_of_cells())
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let HybridUnidirectionalSequenceLSTMOptions {
+            op_code,
+            cell_clip,
+            proj_clip,
+            time_major,
+            asymmetric_quantize_inputs,
+            quantized_input,
+            quantized_output_state,
+            quantized_hidden,
+            gates,
+            hidden,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the optional inputs are negative or omitted
+        let input_idx = |idx: usize| node.inputs.get(idx).copied().unwrap_or(-1);
+        let tensors = &*tensors;
+        let weights = |idx: usize| Self::borrow_weights(tensors, input_idx(idx));
+        let input_to_gates = [0, 1, 2, 3].map(|gate| weights(INPUT_TO_GATE_WEIGHTS + gate));
+        let recurrent_to_gates = [0, 1, 2, 3].map(|gate| weights(RECURRENT_TO_GATE_WEIGHTS + gate));
+        // the cell gate has no peephole
+        let cell_to_gates = [
+            weights(CELL_TO_GATE_WEIGHTS[0]),
+            weights(CELL_TO_GATE_WEIGHTS[1]),
+            Ok(None),
+            weights(CELL_TO_GATE_WEIGHTS[2]),
+        ];
+        let biases = |idx: usize| Self::borrow_bias(tensors, input_idx(idx));
+        let gate_biases = [0, 1, 2, 3].map(|gate| biases(GATE_BIASES + gate));
+        let projection = weights(PROJECTION_WEIGHTS)?;
+        let projection_bias = biases(PROJECTION_BIAS)?;
+
+        let mut hybrid_weights = HybridLSTMWeights {
+            input_to_gates: [None; 4],
+            recurrent_to_gates: [None; 4],
+            cell_to_gates: [None; 4],
+            gate_biases: [None; 4],
+            projection: Self::hybrid_weights(&projection)?,
+            projection_bias: projection_bias.as_ref().map(|bias| &bias.data[..]),
+        };
+        for gate in 0..4 {
+            let (input_to_gate, recurrent_to_gate, cell_to_gate, gate_bias) = (
+                input_to_gates[gate].as_ref().map_err(|_| NotI8Tensor)?,
+                recurrent_to_gates[gate].as_ref().map_err(|_| NotI8Tensor)?,
+                cell_to_gates[gate].as_ref().map_err(|_| NotI8Tensor)?,
+                gate_biases[gate].as_ref().map_err(|_| NotBTensor)?,
+            );
+            hybrid_weights.input_to_gates[gate] = Self::hybrid_weights(input_to_gate)?;
+            hybrid_weights.recurrent_to_gates[gate] = Self::hybrid_weights(recurrent_to_gate)?;
+            hybrid_weights.cell_to_gates[gate] = Self::hybrid_weights(cell_to_gate)?;
+            hybrid_weights.gate_biases[gate] = gate_bias.as_ref().map(|bias| &bias.data[..]);
+        }
+
+        let input = tensors[input_idx(INPUT) as usize]._t()?.borrow();
+        let mut output_state = tensors[input_idx(OUTPUT_STATE) as usize]._t()?.borrow_mut();
+        let mut cell_state = tensors[input_idx(CELL_STATE) as usize]._t()?.borrow_mut();
+        let mut output = tensors[node.outputs[0] as usize]._t()?.borrow_mut();
+
+        let (max_time, n_batch) = if time_major {
+            (input.dims[0], input.dims[1])
+        } else {
+            (input.dims[1], input.dims[0])
+        };
+        let n_input = input.dims[2];
+        let n_cell = cell_state.dims[cell_state.dims.len() - 1];
+        let n_output = output_state.dims[output_state.dims.len() - 1];
+
+        Self::kernel(
+            input.data,
+            &hybrid_weights,
+            output_state.data,
+            cell_state.data,
+            output.data,
+            quantized_input,
+            quantized_output_state,
+            quantized_hidden,
+            gates,
+            hidden,
+            max_time as usize,
+            n_batch as usize,
+            n_input as usize,
+            n_cell as usize,
+            n_output as usize,
+            time_major,
+            op_code,
+            cell_clip,
+            proj_clip,
+            asymmetric_quantize_inputs,
+        )
+    }
+
+    fn borrow_weights<'b, 'a, T: ArrayElem<T>>(
+        tensors: &'b [BLiteTensor<'a, T>],
+        idx: i32,
+    ) -> Result<Option<Ref<'b, BLiteArray<'a, i8>>>> {
+        if idx < 0 {
+            return Ok(None);
+        }
+        Ok(Some(tensors[idx as usize]._i8()?.borrow()))
+    }
+
+    fn borrow_bias<'b, 'a, T: ArrayElem<T>>(
+        tensors: &'b [BLiteTensor<'a, T>],
+        idx: i32,
+    ) -> Result<Option<Ref<'b, BLiteArray<'a, T>>>> {
+        if idx < 0 {
+            return Ok(None);
+        }
+        Ok(Some(tensors[idx as usize]._t()?.borrow()))
+    }
+
+    fn hybrid_weights<'b>(
+        weights: &'b Option<Ref<BLiteArray<i8>>>,
+    ) -> Result<Option<HybridWeights<'b>>> {
+        let Some(weights) = weights else {
+            return Ok(None);
+        };
+        let Some(quant_params) = weights.quant_params else {
+            return Err(NotFoundQuantParams);
+        };
+        Ok(Some(HybridWeights {
+            data: weights.data,
+            scale: quant_params.scale[0],
+        }))
+    }
+
+    /// This kernel follows the hybrid LstmStep of tensorflow lite,
+    /// which quantizes the input and the output state of each batch on the fly.
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        weights: &HybridLSTMWeights<T>,
+        output_state: &mut [T],
+        cell_state: &mut [T],
+        output_data: &mut [T],
+        // scratch buffers
+        quantized_input: &[Cell<i8>],
+        quantized_output_state: &[Cell<i8>],
+        quantized_hidden: &[Cell<i8>],
+        gates: &[Cell<f32>],
+        hidden: &[Cell<f32>],
+        max_time: usize,
+        n_batch: usize,
+        n_input: usize,
+        n_cell: usize,
+        n_output: usize,
+        time_major: bool,
+        op_code: i32,
+        cell_clip: f32,
+        proj_clip: f32,
+        asymmetric_quantize_inputs: bool,
+    ) -> Result<()> {
+        let quantize = |values: &[T], quantized: &[Cell<i8>]| {
+            let values = values.iter().map(|&v| AsPrimitive::<f32>::as_(v));
+            Self::quantize(values, quantized, asymmetric_quantize_inputs)
+        };
+        for t in 0..max_time {
+            for batch in 0..n_batch {
+                let step = if time_major {
+                    t * n_batch + batch
+                } else {
+                    batch * max_time + t
+                };
+                let input = &input_data[step * n_input..(step + 1) * n_input];
+                let output_state = &mut output_state[batch * n_output..(batch + 1) * n_output];
+                let cell_state = &mut cell_state[batch * n_cell..(batch + 1) * n_cell];
+                let input_quantization = quantize(input, quantized_input);
+                let output_state_quantization = quantize(output_state, quantized_output_state);
+
+                for gate in 0..4 {
+                    for cell in 0..n_cell {
+                        let mut v = weights.gate_biases[gate]
+                            .map_or(0., |bias| AsPrimitive::<f32>::as_(bias[cell]));
+                        if let Some(input_to_gate) = weights.input_to_gates[gate] {
+                            v +=
+                                Self::dot(quantized_input, input_quantization, input_to_gate, cell);
+                        }
+                        if let Some(recurrent_to_gate) = weights.recurrent_to_gates[gate] {
+                            v += Self::dot(
+                                quantized_output_state,
+                                output_state_quantization,
+                                recurrent_to_gate,
+                                cell,
+                            );
+                        }
+                        gates[gate * n_cell + cell].set(v);
+                    }
+                }
+
+                for cell in 0..n_cell {
+                    let gate = |gate: usize| gates[gate * n_cell + cell].get();
+                    let peephole = |gate: usize, c: f32| {
+                        weights.cell_to_gates[gate]
+                            .map_or(0., |w| w.data[cell] as f32 * w.scale * c)
+                    };
+                    let prev_c = AsPrimitive::<f32>::as_(cell_state[cell]);
+                    let forget_gate =
+                        OpLogistic::logistic_f32(gate(FORGET_GATE) + peephole(FORGET_GATE, prev_c));
+                    // CIFG couples the input gate with the forget gate
+                    let input_gate = if weights.input_to_gates[INPUT_GATE].is_none() {
+                        1. - forget_gate
+                    } else {
+                        OpLogistic::logistic_f32(gate(INPUT_GATE) + peephole(INPUT_GATE, prev_c))
+                    };
+                    let mut c = forget_gate * prev_c
+                        + input_gate * Self::activation(gate(CELL_GATE), op_code);
+                    if cell_clip > 0. {
+                        c = c.clamp(-cell_clip, cell_clip);
+                    }
+                    cell_state[cell] = FromPrimitive::from_f32(c).ok_or(InCompatibleCasting)?;
+                    let output_gate =
+                        OpLogistic::logistic_f32(gate(OUTPUT_GATE) + peephole(OUTPUT_GATE, c));
+                    hidden[cell].set(output_gate * Self::activation(c, op_code));
+                }
+
+                let output = &mut output_data[step * n_output..(step + 1) * n_output];
+                if let Some(projection) = weights.projection {
+                    let hidden_values = hidden.iter().map(Cell::get);
+                    let hidden_quantization =
+                        Self::quantize(hidden_values, quantized_hidden, asymmetric_quantize_inputs);
+                    for (out_d, output_v) in output.iter_mut().enumerate() {
+                        let mut v = weights
+                            .projection_bias
+                            .map_or(0., |bias| AsPrimitive::<f32>::as_(bias[out_d]));
+                        v += Self::dot(quantized_hidden, hidden_quantization, projection, out_d);
+                        if proj_clip > 0. {
+                            v = v.clamp(-proj_clip, proj_clip);
+                        }
+                        *output_v = FromPrimitive::from_f32(v).ok_or(InCompatibleCasting)?;
+                    }
+                } else {
+                    for (output_v, hidden_v) in output.iter_mut().zip(hidden.iter()) {
+                        *output_v =
+                            FromPrimitive::from_f32(hidden_v.get()).ok_or(InCompatibleCasting)?;
+                    }
+                }
+                output_state.copy_from_slice(output);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the scaling factor and the offset of the values quantized into `quantized`
+    #[inline(always)]
+    fn quantize(
+        values: impl Iterator<Item = f32> + Clone,
+        quantized: &[Cell<i8>],
+        asymmetric_quantize_inputs: bool,
+    ) -> (f32, i32) {
+        if asymmetric_quantize_inputs {
+            asymmetric_quantize_values(values, quantized)
+        } else {
+            (symmetric_quantize_values(values, quantized), 0)
+        }
+    }
+
+    /// Returns the float product of the quantized values and the row of the weights
+    #[inline(always)]
+    fn dot(
+        quantized: &[Cell<i8>],
+        (scaling_factor, offset): (f32, i32),
+        weights: HybridWeights,
+        row: usize,
+    ) -> f32 {
+        let depth = quantized.len();
+        let acc: i32 = quantized
+            .iter()
+            .zip(weights.data[row * depth..(row + 1) * depth].iter())
+            .map(|(q, &w)| (q.get() as i32 - offset) * w as i32)
+            .sum();
+        acc as f32 * scaling_factor * weights.scale
+    }
+
+    #[inline(always)]
+    fn activation(x: f32, op_code: i32) -> f32 {
+        match op_code {
+            1 /* Relu */ => x.max(0.),
+            2 /* ReluN1To1 */ => x.clamp(-1., 1.),
+            3 /* Relu6 */ => x.clamp(0., 6.),
+            4 /* Tanh */ => OpTanh::tanh_f32(x),
+            _ /* None */ => x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HybridLSTMWeights, HybridWeights, OpUnidirectionalSequenceLSTMHybrid};
+    use crate::kernel::micro_operator::f32::logistic::OpLogistic;
+    use crate::kernel::micro_operator::f32::tanh::OpTanh;
+    use core::cell::Cell;
+
+    const N_INPUT: usize = 3;
+    const N_CELL: usize = 4;
+    const MAX_TIME: usize = 3;
+    const SCALE: f32 = 1. / 32.;
+    const TANH: i32 = 4;
+
+    fn weights_data(seed: usize, len: usize) -> Vec<i8> {
+        (0..len)
+            .map(|i| (((i * 37 + seed * 53 + 11) % 255) as i32 - 127) as i8)
+            .collect()
+    }
+
+    fn hybrid(data: &[i8]) -> Option<HybridWeights<'_>> {
+        Some(HybridWeights { data, scale: SCALE })
+    }
+
+    /// Runs the float LSTM of a batch with the dequantized weights, and returns its outputs
+    fn float_lstm(
+        weights: &HybridLSTMWeights<f32>,
+        input: &[f32],
+        n_output: usize,
+        cell_clip: f32,
+        proj_clip: f32,
+    ) -> Vec<f32> {
+        let dequantize =
+            |w: Option<HybridWeights>, i: usize| w.map_or(0., |w| w.data[i] as f32 * w.scale);
+        let mut h = vec![0f32; n_output];
+        let mut c = [0f32; N_CELL];
+        let mut outputs = vec![];
+        for x in input.chunks(N_INPUT) {
+            let gates: Vec<f32> = (0..4 * N_CELL)
+                .map(|i| {
+                    let (gate, cell) = (i / N_CELL, i % N_CELL);
+                    let mut v = weights.gate_biases[gate].map_or(0., |bias| bias[cell]);
+                    for (d, &x_v) in x.iter().enumerate() {
+                        v += dequantize(weights.input_to_gates[gate], cell * N_INPUT + d) * x_v;
+                    }
+                    for (d, &h_v) in h.iter().enumerate() {
+                        v +=
+                            dequantize(weights.recurrent_to_gates[gate], cell * n_output + d) * h_v;
+                    }
+                    v
+                })
+                .collect();
+            let mut hidden = [0f32; N_CELL];
+            for cell in 0..N_CELL {
+                let peephole =
+                    |gate: usize, c: f32| dequantize(weights.cell_to_gates[gate], cell) * c;
+                let f = OpLogistic::logistic_f32(gates[N_CELL + cell] + peephole(1, c[cell]));
+                let i = if weights.input_to_gates[0].is_none() {
+                    1. - f
+                } else {
+                    OpLogistic::logistic_f32(gates[cell] + peephole(0, c[cell]))
+                };
+                c[cell] = f * c[cell] + i * OpTanh::tanh_f32(gates[2 * N_CELL + cell]);
+                c[cell] = c[cell].clamp(-cell_clip, cell_clip);
+                let o = OpLogistic::logistic_f32(gates[3 * N_CELL + cell] + peephole(3, c[cell]));
+                hidden[cell] = o * OpTanh::tanh_f32(c[cell]);
+            }
+            h = match weights.projection {
+                Some(projection) => (0..n_output)
+                    .map(|out_d| {
+                        let mut v = weights.projection_bias.map_or(0., |bias| bias[out_d]);
+                        for (d, &hidden_v) in hidden.iter().enumerate() {
+                            v += dequantize(Some(projection), out_d * N_CELL + d) * hidden_v;
+                        }
+                        v.clamp(-proj_clip, proj_clip)
+                    })
+                    .collect(),
+                None => hidden.to_vec(),
+            };
+            outputs.extend_from_slice(&h);
+        }
+        outputs
+    }
+
+    fn run_hybrid_lstm(
+        weights: &HybridLSTMWeights<f32>,
+        input: &[f32],
+        n_batch: usize,
+        n_output: usize,
+        time_major: bool,
+        cell_clip: f32,
+        proj_clip: f32,
+        asymmetric_quantize_inputs: bool,
+    ) -> Vec<f32> {
+        let mut output_state = vec![0f32; n_batch * n_output];
+        let mut cell_state = vec![0f32; n_batch * N_CELL];
+        let mut output = vec![0f32; MAX_TIME * n_batch * n_output];
+        let n_hidden = if weights.projection.is_some() {
+            N_CELL
+        } else {
+            0
+        };
+        let quantized_input = vec![Cell::new(0i8); N_INPUT];
+        let quantized_output_state = vec![Cell::new(0i8); n_output];
+        let quantized_hidden = vec![Cell::new(0i8); n_hidden];
+        let gates = vec![Cell::new(0f32); 4 * N_CELL];
+        let hidden = vec![Cell::new(0f32); N_CELL];
+        OpUnidirectionalSequenceLSTMHybrid::kernel(
+            input,
+            weights,
+            &mut output_state,
+            &mut cell_state,
+            &mut output,
+            &quantized_input,
+            &quantized_output_state,
+            &quantized_hidden,
+            &gates,
+            &hidden,
+            MAX_TIME,
+            n_batch,
+            N_INPUT,
+            N_CELL,
+            n_output,
+            time_major,
+            TANH,
+            cell_clip,
+            proj_clip,
+            asymmetric_quantize_inputs,
+        )
+        .unwrap();
+        output
+    }
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected.iter()) {
+            assert!((a - e).abs() < 0.03, "{actual:?} vs {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_hybrid_lstm_with_peepholes_and_projection_matches_float() {
+        const N_OUTPUT: usize = 2;
+        let input_to_gates: Vec<_> = (0..4).map(|g| weights_data(g, N_CELL * N_INPUT)).collect();
+        let recurrent_to_gates: Vec<_> = (0..4)
+            .map(|g| weights_data(g + 4, N_CELL * N_OUTPUT))
+            .collect();
+        let cell_to_gates: Vec<_> = (0..4).map(|g| weights_data(g + 8, N_CELL)).collect();
+        let projection = weights_data(12, N_OUTPUT * N_CELL);
+        let biases: Vec<_> = (0..4).map(|g| vec![0.1 * g as f32 - 0.1; N_CELL]).collect();
+        let projection_bias = [0.05f32, -0.05];
+        let weights = HybridLSTMWeights {
+            input_to_gates: core::array::from_fn(|g| hybrid(&input_to_gates[g])),
+            recurrent_to_gates: core::array::from_fn(|g| hybrid(&recurrent_to_gates[g])),
+            // the cell gate has no peephole
+            cell_to_gates: core::array::from_fn(|g| {
+                (g != 2).then(|| hybrid(&cell_to_gates[g])).flatten()
+            }),
+            gate_biases: core::array::from_fn(|g| Some(&biases[g][..])),
+            projection: hybrid(&projection),
+            projection_bias: Some(&projection_bias[..]),
+        };
+        let input = [0.5f32, -1., 0.25, 1.5, 0.75, -0.5, -2., 0.1, 1.];
+        // the clips saturate some of the cells and outputs
+        let (cell_clip, proj_clip) = (1., 0.5);
+        let expected = float_lstm(&weights, &input, N_OUTPUT, cell_clip, proj_clip);
+
+        for asymmetric_quantize_inputs in [false, true] {
+            let output = run_hybrid_lstm(
+                &weights,
+                &input,
+                1,
+                N_OUTPUT,
+                false,
+                cell_clip,
+                proj_clip,
+                asymmetric_quantize_inputs,
+            );
+            assert_close(&output, &expected);
+        }
+    }
+
+    #[test]
+    fn test_hybrid_lstm_with_cifg_in_time_major_matches_float() {
+        const N_BATCH: usize = 2;
+        let input_to_gates: Vec<_> = (0..4).map(|g| weights_data(g, N_CELL * N_INPUT)).collect();
+        let recurrent_to_gates: Vec<_> = (0..4)
+            .map(|g| weights_data(g + 4, N_CELL * N_CELL))
+            .collect();
+        let biases: Vec<_> = (0..4).map(|g| vec![0.2 - 0.1 * g as f32; N_CELL]).collect();
+        // the input gate is coupled with the forget gate
+        let weights = HybridLSTMWeights {
+            input_to_gates: core::array::from_fn(|g| {
+                (g != 0).then(|| hybrid(&input_to_gates[g])).flatten()
+            }),
+            recurrent_to_gates: core::array::from_fn(|g| {
+                (g != 0).then(|| hybrid(&recurrent_to_gates[g])).flatten()
+            }),
+            cell_to_gates: [None; 4],
+            gate_biases: core::array::from_fn(|g| (g != 0).then(|| &biases[g][..])),
+            projection: None,
+            projection_bias: None,
+        };
+        let batches = [
+            [0.5f32, -1., 0.25, 1.5, 0.75, -0.5, -2., 0.1, 1.],
+            [-0.3f32, 0.8, 1.2, 0., -0.6, 0.4, 0.9, -1.1, 0.2],
+        ];
+        // the steps of the batches are interleaved in time major
+        let input: Vec<f32> = (0..MAX_TIME)
+            .flat_map(|t| {
+                batches
+                    .iter()
+                    .flat_map(move |batch| batch[t * N_INPUT..(t + 1) * N_INPUT].to_vec())
+            })
+            .collect();
+        let expected: Vec<_> = batches
+            .iter()
+            .map(|batch| float_lstm(&weights, batch, N_CELL, f32::MAX, f32::MAX))
+            .collect();
+        let expected: Vec<f32> = (0..MAX_TIME)
+            .flat_map(|t| {
+                expected
+                    .iter()
+                    .flat_map(move |batch| batch[t * N_CELL..(t + 1) * N_CELL].to_vec())
+            })
+            .collect();
+
+        for asymmetric_quantize_inputs in [false, true] {
+            let output = run_hybrid_lstm(
+                &weights,
+                &input,
+                N_BATCH,
+                N_CELL,
+                true,
+                0.,
+                0.,
+                asymmetric_quantize_inputs,
+            );
+            assert_close(&output, &expected);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub mod expand_dims;
+pub mod gather;
+pub mod gather_nd;
+pub mod pack;
+pub mod pad;
+pub mod resize_nearest_neighbor;
+pub mod slice;
+pub mod squeeze;
+pub mod strided_slice;
+pub mod transpose;
+pub mod unpack;
@@ -0,0 +1,204 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpGather {}
+
+impl OpGather {
+    const OPCODE: i32 = 36;
+
+    pub fn gather<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 4,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_gather_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        Ok(BLiteBuiltinOption::GatherOptions {
+            axis: builtin_option.axis(),
+            batch_dims: builtin_option.batch_dims(),
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+        let coords = tensors[node.inputs[1] as usize]._i32()?.borrow();
+
+        let GatherOptions { axis, batch_dims } = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+
+        match (&tensors[idx_input], &tensors[idx_output]) {
+            (BTensor(input), BTensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(
+                    input.data,
+                    input.dims,
+                    output.data,
+                    coords.data,
+                    coords.dims,
+                    axis,
+                    batch_dims,
+                )
+            }
+            (I32Tensor(input), I32Tensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(
+                    input.data,
+                    input.dims,
+                    output.data,
+                    coords.data,
+                    coords.dims,
+                    axis,
+                    batch_dims,
+                )
+            }
+            _ => Err(InCompatibleType),
+        }
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        output_data: &mut [T],
+        coords_data: &[i32],
+        coords_dims: &[i32],
+        axis: i32,
+        batch_dims: i32,
+    ) -> Result<()> {
+        let rank = input_dims.len() as i32;
+        let axis = if axis < 0 { axis + rank } else { axis };
+        let batch_dims = if batch_dims < 0 {
+            batch_dims + coords_dims.len() as i32
+        } else {
+            batch_dims
+        };
+        if axis < 0 || axis >= rank || batch_dims < 0 || batch_dims > axis {
+            return Err(InCompatibleShape(axis, rank));
+        }
+        let (axis, batch_dims) = (axis as usize, batch_dims as usize);
+
+        let product = |dims: &[i32]| dims.iter().fold(1, |acc, &x| acc * x as usize);
+        let batch_size = product(&input_dims[..batch_dims]);
+        let outer_size = product(&input_dims[batch_dims..axis]);
+        let inner_size = product(&input_dims[axis + 1..]);
+        let coord_size = product(&coords_dims[batch_dims..]);
+        let axis_size = input_dims[axis];
+        if output_data.len() != batch_size * outer_size * coord_size * inner_size {
+            return Err(NotMatchSize);
+        }
+
+        for batch in 0..batch_size {
+            for outer in 0..outer_size {
+                for i in 0..coord_size {
+                    let coord = coords_data[batch * coord_size + i];
+                    if coord < 0 || coord >= axis_size {
+                        return Err(InCompatibleShape(coord, axis_size));
+                    }
+                    let from = ((batch * outer_size + outer) * axis_size as usize + coord as usize)
+                        * inner_size;
+                    let to = ((batch * outer_size + outer) * coord_size + i) * inner_size;
+                    output_data[to..to + inner_size]
+                        .copy_from_slice(&input_data[from..from + inner_size]);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpGather;
+    use crate::kernel::micro_builtin_options::BLiteBuiltinOption::GatherOptions;
+    use crate::kernel::utils::testing::{eval_i32, Tensor};
+    use crate::micro_errors::BLiteError::InCompatibleShape;
+
+    #[test]
+    fn test_gather_axes() {
+        // input of shape [2, 3]
+        let input = [0., 1., 2., 3., 4., 5.];
+        let dims = [2, 3];
+
+        // the negative axis counts from the last one
+        let mut output = [0.; 4];
+        OpGather::kernel(&input, &dims, &mut output, &[2, 0], &[2], -1, 0).unwrap();
+        assert_eq!([2., 0., 5., 3.], output);
+
+        // each batch gathers with its own coords
+        let mut output = [0.; 2];
+        OpGather::kernel(&input, &dims, &mut output, &[1, 2], &[2, 1], 1, 1).unwrap();
+        assert_eq!([1., 5.], output);
+
+        for axis in [2, -3] {
+            let result = OpGather::kernel(&input, &dims, &mut output, &[0, 0], &[2], axis, 0);
+            assert!(matches!(result, Err(InCompatibleShape(..))), "{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_gather_out_of_range_coords() {
+        let input = [0., 1., 2., 3., 4., 5.];
+        for coord in [-1, 3] {
+            let mut output = [0.; 2];
+            let result = OpGather::kernel(&input, &[2, 3], &mut output, &[coord], &[1], 1, 0);
+            assert!(
+                matches!(result, Err(InCompatibleShape(c, 3)) if c == coord),
+                "{result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_gather_int32() {
+        let outputs = eval_i32(
+            OpGather::eval::<f32>,
+            &[
+                Tensor::from(vec![10, 11, 20, 21, 30, 31], vec![3, 2]),
+                Tensor::from(vec![2, 0, 2], vec![3]),
+            ],
+            &[&[3, 2]],
+            GatherOptions {
+                axis: 0,
+                batch_dims: 0,
+            },
+        )
+        .unwrap();
+        assert_eq!(vec![30, 31, 10, 11, 30, 31], outputs[0]);
+    }
+}
@@ -0,0 +1,178 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpGatherNd {}
+
+impl OpGatherNd {
+    const OPCODE: i32 = 107;
+
+    pub fn gather_nd<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Ok(BLiteBuiltinOption::GatherNdOptions {})
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        _builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_params = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+        let indices = tensors[node.inputs[1] as usize]._i32()?.borrow();
+
+        match (&tensors[idx_params], &tensors[idx_output]) {
+            (BTensor(params), BTensor(output)) => {
+                let params = params.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(
+                    params.data,
+                    params.dims,
+                    output.data,
+                    indices.data,
+                    indices.dims,
+                )
+            }
+            (I32Tensor(params), I32Tensor(output)) => {
+                let params = params.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(
+                    params.data,
+                    params.dims,
+                    output.data,
+                    indices.data,
+                    indices.dims,
+                )
+            }
+            _ => Err(InCompatibleType),
+        }
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        params_data: &[T],
+        params_dims: &[i32],
+        output_data: &mut [T],
+        indices_data: &[i32],
+        indices_dims: &[i32],
+    ) -> Result<()> {
+        let Some((&indices_nd, batch_dims)) = indices_dims.split_last() else {
+            return Err(InCompatibleShape(0, params_dims.len() as i32));
+        };
+        let indices_nd = indices_nd as usize;
+        if indices_nd > params_dims.len() {
+            return Err(InCompatibleShape(
+                indices_nd as i32,
+                params_dims.len() as i32,
+            ));
+        }
+
+        let n_slices = batch_dims.iter().fold(1, |acc, &x| acc * x as usize);
+        let slice_size = params_dims[indices_nd..]
+            .iter()
+            .fold(1, |acc, &x| acc * x as usize);
+        if output_data.len() != n_slices * slice_size {
+            return Err(NotMatchSize);
+        }
+        if slice_size == 0 {
+            return Ok(());
+        }
+
+        for (i, output_slice) in output_data.chunks_exact_mut(slice_size).enumerate() {
+            let index = &indices_data[i * indices_nd..(i + 1) * indices_nd];
+            let mut from = 0;
+            for (&coord, &dim) in index.iter().zip(params_dims.iter()) {
+                if coord < 0 || coord >= dim {
+                    return Err(InCompatibleShape(coord, dim));
+                }
+                from = from * dim as usize + coord as usize;
+            }
+            from *= slice_size;
+            output_slice.copy_from_slice(&params_data[from..from + slice_size]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpGatherNd;
+    use crate::kernel::micro_builtin_options::BLiteBuiltinOption::GatherNdOptions;
+    use crate::kernel::utils::testing::{eval_i32, Tensor};
+    use crate::micro_errors::BLiteError::InCompatibleShape;
+
+    #[test]
+    fn test_gather_nd_slices() {
+        // params of shape [2, 2, 2]
+        let params = [0., 1., 2., 3., 4., 5., 6., 7.];
+        let dims = [2, 2, 2];
+
+        // the indices of 2 axes gather the slices of the last axis
+        let mut output = [0.; 4];
+        OpGatherNd::kernel(&params, &dims, &mut output, &[1, 0, 0, 1], &[2, 2]).unwrap();
+        assert_eq!([4., 5., 2., 3.], output);
+
+        // the indices of all the axes gather the elements
+        let mut output = [0.; 2];
+        OpGatherNd::kernel(&params, &dims, &mut output, &[1, 1, 1, 0, 0, 1], &[2, 3]).unwrap();
+        assert_eq!([7., 1.], output);
+
+        // the indices can not have more axes than the params
+        let mut output = [0.; 1];
+        let result = OpGatherNd::kernel(&params, &dims, &mut output, &[0; 4], &[1, 4]);
+        assert!(matches!(result, Err(InCompatibleShape(4, 3))), "{result:?}");
+    }
+
+    #[test]
+    fn test_gather_nd_out_of_range_indices() {
+        let params = [0., 1., 2., 3., 4., 5., 6., 7.];
+        for index in [[-1, 0], [0, 2]] {
+            let mut output = [0.; 2];
+            let result = OpGatherNd::kernel(&params, &[2, 2, 2], &mut output, &index, &[1, 2]);
+            assert!(matches!(result, Err(InCompatibleShape(_, 2))), "{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_gather_nd_int32() {
+        let outputs = eval_i32(
+            OpGatherNd::eval::<f32>,
+            &[
+                Tensor::from(vec![10, 11, 20, 21, 30, 31], vec![3, 2]),
+                Tensor::from(vec![2, 0], vec![2, 1]),
+            ],
+            &[&[2, 2]],
+            GatherNdOptions {},
+        )
+        .unwrap();
+        assert_eq!(vec![30, 31, 10, 11], outputs[0]);
+    }
+}
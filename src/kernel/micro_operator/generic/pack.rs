@@ -0,0 +1,184 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpPack {}
+
+impl OpPack {
+    const OPCODE: i32 = 83;
+
+    pub fn pack<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_pack_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        Ok(BLiteBuiltinOption::PackOptions {
+            axis: builtin_option.axis(),
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_output = node.outputs[0] as usize;
+
+        let PackOptions { axis } = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+
+        let values_count = node.inputs.len();
+        for (i, &idx_input) in node.inputs.iter().enumerate() {
+            match (&tensors[idx_input as usize], &tensors[idx_output]) {
+                (BTensor(input), BTensor(output)) => {
+                    let mut output = output.borrow_mut();
+                    let output_dims = output.dims;
+                    Self::kernel(
+                        input.borrow().data,
+                        output.data,
+                        output_dims,
+                        axis,
+                        i,
+                        values_count,
+                    )?;
+                }
+                (I32Tensor(input), I32Tensor(output)) => {
+                    let mut output = output.borrow_mut();
+                    let output_dims = output.dims;
+                    Self::kernel(
+                        input.borrow().data,
+                        output.data,
+                        output_dims,
+                        axis,
+                        i,
+                        values_count,
+                    )?;
+                }
+                _ => return Err(InCompatibleType),
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the `value_idx`-th input into its place along `axis` of the packed output
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        output_dims: &[i32],
+        axis: i32,
+        value_idx: usize,
+        values_count: usize,
+    ) -> Result<()> {
+        let rank = output_dims.len() as i32;
+        let axis = if axis < 0 { axis + rank } else { axis };
+        if axis < 0 || axis >= rank || output_dims[axis as usize] as usize != values_count {
+            return Err(InCompatibleShape(axis, rank));
+        }
+        let copy_size = output_dims[axis as usize + 1..]
+            .iter()
+            .fold(1, |acc, &x| acc * x as usize);
+        if input_data.len() * values_count != output_data.len() {
+            return Err(NotMatchSize);
+        }
+        if copy_size == 0 {
+            return Ok(());
+        }
+
+        for (k, input_chunk) in input_data.chunks_exact(copy_size).enumerate() {
+            let to = (k * values_count + value_idx) * copy_size;
+            output_data[to..to + copy_size].copy_from_slice(input_chunk);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpPack;
+    use crate::kernel::micro_builtin_options::BLiteBuiltinOption::PackOptions;
+    use crate::kernel::utils::testing::{eval_i32, Tensor};
+    use crate::micro_errors::{BLiteError::InCompatibleShape, Result};
+
+    fn pack(inputs: &[&[f32]], output_dims: &[i32], axis: i32) -> Result<Vec<f32>> {
+        let mut output = vec![0.; inputs.len() * inputs[0].len()];
+        for (i, input) in inputs.iter().enumerate() {
+            OpPack::kernel(input, &mut output, output_dims, axis, i, inputs.len())?;
+        }
+        Ok(output)
+    }
+
+    #[test]
+    fn test_pack_axes() {
+        // inputs of shape [2, 3]
+        let a = [0., 1., 2., 3., 4., 5.];
+        let b = [10., 11., 12., 13., 14., 15.];
+
+        let output = pack(&[&a, &b], &[2, 2, 3], 0).unwrap();
+        assert_eq!([a, b].concat(), output);
+
+        let output = pack(&[&a, &b], &[2, 2, 3], 1).unwrap();
+        assert_eq!(
+            vec![0., 1., 2., 10., 11., 12., 3., 4., 5., 13., 14., 15.],
+            output
+        );
+
+        // the negative axis counts from the last one of the output
+        let output = pack(&[&a, &b], &[2, 3, 2], -1).unwrap();
+        assert_eq!(
+            vec![0., 10., 1., 11., 2., 12., 3., 13., 4., 14., 5., 15.],
+            output
+        );
+
+        // the axis must be within the rank, and have as many elements as the inputs
+        for (output_dims, axis) in [([2, 3, 2], 3), ([2, 3, 2], -4), ([3, 2, 2], 0)] {
+            let result = pack(&[&a, &b], &output_dims, axis);
+            assert!(matches!(result, Err(InCompatibleShape(..))), "{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_pack_int32() {
+        let outputs = eval_i32(
+            OpPack::eval::<f32>,
+            &[
+                Tensor::from(vec![0, 1, 2], vec![3]),
+                Tensor::from(vec![10, 11, 12], vec![3]),
+            ],
+            &[&[3, 2]],
+            PackOptions { axis: -1 },
+        )
+        .unwrap();
+        assert_eq!(vec![0, 10, 1, 11, 2, 12], outputs[0]);
+    }
+}
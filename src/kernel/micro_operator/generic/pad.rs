@@ -0,0 +1,145 @@
+use num_traits::FromPrimitive;
+
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// PAD fills the borders with zero, which is the zero point for the quantized tensors.
+/// The paddings are the constant int32 tensor of the shape [rank, 2].
+#[derive(Debug, Clone, Copy)]
+pub struct OpPad {}
+
+impl OpPad {
+    const OPCODE: i32 = 34;
+    const MAX_DIMS: usize = 5;
+
+    pub fn pad<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let zero_point = match tensors[output_idx].quant_params() {
+            Some(quant_params) => quant_params.zero_point[0],
+            None => 0,
+        };
+        let constant_value: T = FromPrimitive::from_i64(zero_point).ok_or(InCompatibleCasting)?;
+        Ok(BLiteBuiltinOption::PadOptions { constant_value })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+        let idx_paddings = node.inputs[1] as usize;
+        let paddings = tensors[idx_paddings]._i32()?.borrow();
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        let PadOptions { constant_value } = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+        Self::kernel(
+            input.data,
+            input.dims,
+            output.data,
+            paddings.data,
+            constant_value,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        output_data: &mut [T],
+        paddings: &[i32],
+        constant_value: T,
+    ) -> Result<()> {
+        let rank = input_dims.len();
+        if rank > Self::MAX_DIMS || paddings.len() != 2 * rank {
+            return Err(InCompatibleShape(paddings.len() as i32, rank as i32));
+        }
+
+        let mut output_dims = [1usize; Self::MAX_DIMS];
+        for axis in 0..rank {
+            let (before, after) = (paddings[2 * axis], paddings[2 * axis + 1]);
+            if before < 0 || after < 0 {
+                return Err(InCompatibleShape(before, after));
+            }
+            output_dims[axis] = (input_dims[axis] + before + after) as usize;
+        }
+        if output_data.len() != output_dims[..rank].iter().product::<usize>() {
+            return Err(NotMatchSize);
+        }
+
+        // the output is filled and the input is copied into the inner box row by row
+        output_data.fill(constant_value);
+        if input_data.is_empty() {
+            return Ok(());
+        }
+        let row_len = input_dims.last().map_or(1, |&d| d as usize);
+        let mut index = [0usize; Self::MAX_DIMS];
+        for input_row in input_data.chunks(row_len) {
+            let mut output_offset = 0;
+            for axis in 0..rank {
+                output_offset =
+                    output_offset * output_dims[axis] + index[axis] + paddings[2 * axis] as usize;
+            }
+            output_data[output_offset..output_offset + row_len].copy_from_slice(input_row);
+            // the row index is incremented over the outer axes
+            for axis in (0..rank.saturating_sub(1)).rev() {
+                index[axis] += 1;
+                if index[axis] < input_dims[axis] as usize {
+                    break;
+                }
+                index[axis] = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpPad;
+
+    #[test]
+    fn test_pad_2d() {
+        // input of shape [2, 3] is padded to [3, 5] by [[1, 0], [1, 1]]
+        let input = [1, 2, 3, 4, 5, 6];
+        let mut output = vec![0; 15];
+        OpPad::kernel(&input, &[2, 3], &mut output, &[1, 0, 1, 1], -1).unwrap();
+        assert_eq!(
+            vec![-1, -1, -1, -1, -1, -1, 1, 2, 3, -1, -1, 4, 5, 6, -1],
+            output
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+#[cfg(feature = "no_std")]
+use num_traits::Float;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpResizeNearestNeighbor {}
+
+impl OpResizeNearestNeighbor {
+    const OPCODE: i32 = 97;
+
+    pub fn resize_nearest_neighbor<'a, T: ArrayElem<T>, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_resize_nearest_neighbor_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+
+        // The output size is given by a constant tensor of [height, width]
+        let size_idx = op.inputs().unwrap().get(1) as usize;
+        let size = tensors[size_idx]._i32()?.borrow();
+        let [output_height, output_width] = size.data[..] else {
+            return Err(NotFoundBufferData);
+        };
+
+        Ok(BLiteBuiltinOption::ResizeNearestNeighborOptions {
+            align_corners: builtin_option.align_corners(),
+            half_pixel_centers: builtin_option.half_pixel_centers(),
+            output_height,
+            output_width,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let ResizeNearestNeighborOptions {
+            align_corners,
+            half_pixel_centers,
+            output_height,
+            output_width,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        match (&tensors[idx_input], &tensors[idx_output]) {
+            (BTensor(input), BTensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(
+                    input.data,
+                    input.dims,
+                    output.data,
+                    output_height,
+                    output_width,
+                    align_corners,
+                    half_pixel_centers,
+                )
+            }
+            (I32Tensor(input), I32Tensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(
+                    input.data,
+                    input.dims,
+                    output.data,
+                    output_height,
+                    output_width,
+                    align_corners,
+                    half_pixel_centers,
+                )
+            }
+            _ => Err(InCompatibleType),
+        }
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        output_data: &mut [T],
+        output_height: i32,
+        output_width: i32,
+        //
+        align_corners: bool,
+        half_pixel_centers: bool,
+    ) -> Result<()> {
+        let &[batches, input_height, input_width, depth] = input_dims else {
+            return Err(InCompatibleShape(input_dims.len() as i32, 4));
+        };
+        if output_data.len() != (batches * output_height * output_width * depth) as usize {
+            return Err(NotMatchSize);
+        }
+
+        let depth = depth as usize;
+        let mut output_idx = 0;
+        for b in 0..batches {
+            for y in 0..output_height {
+                let in_y = Self::nearest_neighbor(
+                    y,
+                    input_height,
+                    output_height,
+                    align_corners,
+                    half_pixel_centers,
+                );
+                for x in 0..output_width {
+                    let in_x = Self::nearest_neighbor(
+                        x,
+                        input_width,
+                        output_width,
+                        align_corners,
+                        half_pixel_centers,
+                    );
+                    let input_idx =
+                        ((b * input_height + in_y) * input_width + in_x) as usize * depth;
+                    output_data[output_idx..output_idx + depth]
+                        .copy_from_slice(&input_data[input_idx..input_idx + depth]);
+                    output_idx += depth;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// This computations is corresponded to GetNearestNeighbor
+    #[inline(always)]
+    fn nearest_neighbor(
+        value: i32,
+        input_size: i32,
+        output_size: i32,
+        align_corners: bool,
+        half_pixel_centers: bool,
+    ) -> i32 {
+        let scale = if align_corners && output_size > 1 {
+            (input_size - 1) as f32 / (output_size - 1) as f32
+        } else {
+            input_size as f32 / output_size as f32
+        };
+        let offset = if half_pixel_centers { 0.5 } else { 0.0 };
+        let scaled_value = (value as f32 + offset) * scale;
+        let nearest = if align_corners {
+            scaled_value.round() as i32
+        } else {
+            scaled_value.floor() as i32
+        };
+        let nearest = min(nearest, input_size - 1);
+        if half_pixel_centers {
+            max(nearest, 0)
+        } else {
+            nearest
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpResizeNearestNeighbor;
+    use crate::micro_errors::BLiteError::NotMatchSize;
+
+    #[test]
+    fn test_resize_nearest_neighbor_upsampling() {
+        // 1x2x2x1 is resized to 1x3x3x1
+        let input = [1.0f32, 2.0, 3.0, 4.0];
+        let tt = [
+            (
+                (false, false),
+                [1.0, 1.0, 2.0, 1.0, 1.0, 2.0, 3.0, 3.0, 4.0],
+            ),
+            ((true, false), [1.0, 2.0, 2.0, 3.0, 4.0, 4.0, 3.0, 4.0, 4.0]),
+            ((false, true), [1.0, 2.0, 2.0, 3.0, 4.0, 4.0, 3.0, 4.0, 4.0]),
+        ];
+        for ((align_corners, half_pixel_centers), expected) in tt {
+            let mut output = [0.0f32; 9];
+            OpResizeNearestNeighbor::kernel(
+                &input,
+                &[1, 2, 2, 1],
+                &mut output,
+                3,
+                3,
+                align_corners,
+                half_pixel_centers,
+            )
+            .unwrap();
+            assert_eq!(expected, output);
+        }
+    }
+
+    #[test]
+    fn test_resize_nearest_neighbor_downsampling() {
+        // 2x1x4x2 is resized to 2x1x2x2, where the flags pick different neighbors
+        let input: Vec<i32> = (0..16).collect();
+        let tt = [
+            ((false, false), [0, 1, 4, 5, 8, 9, 12, 13]),
+            ((true, false), [0, 1, 6, 7, 8, 9, 14, 15]),
+            ((false, true), [2, 3, 6, 7, 10, 11, 14, 15]),
+        ];
+        for ((align_corners, half_pixel_centers), expected) in tt {
+            let mut output = [0i32; 8];
+            OpResizeNearestNeighbor::kernel(
+                &input,
+                &[2, 1, 4, 2],
+                &mut output,
+                1,
+                2,
+                align_corners,
+                half_pixel_centers,
+            )
+            .unwrap();
+            assert_eq!(expected, output);
+        }
+
+        let mut output = [0i32; 6];
+        let result =
+            OpResizeNearestNeighbor::kernel(&input, &[2, 1, 4, 2], &mut output, 1, 2, false, false);
+        assert!(matches!(result, Err(NotMatchSize)));
+    }
+}
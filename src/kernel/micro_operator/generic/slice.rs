@@ -0,0 +1,157 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::generic::strided_slice::OpStridedSlice;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpSlice {}
+
+impl OpSlice {
+    const OPCODE: i32 = 65;
+
+    pub fn slice<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Ok(BLiteBuiltinOption::SliceOptions {})
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        _builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+        let begin = tensors[node.inputs[1] as usize]._i32()?.borrow();
+        let size = tensors[node.inputs[2] as usize]._i32()?.borrow();
+
+        match (&tensors[idx_input], &tensors[idx_output]) {
+            (BTensor(input), BTensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(input.data, input.dims, output.data, begin.data, size.data)
+            }
+            (I32Tensor(input), I32Tensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(input.data, input.dims, output.data, begin.data, size.data)
+            }
+            _ => Err(InCompatibleType),
+        }
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        output_data: &mut [T],
+        begin: &[i32],
+        size: &[i32],
+    ) -> Result<()> {
+        let rank = input_dims.len();
+        if rank > OpStridedSlice::MAX_DIMS || begin.len() != rank || size.len() != rank {
+            return Err(InCompatibleShape(begin.len() as i32, rank as i32));
+        }
+
+        // size of -1 means all the remaining elements of the axis
+        let mut counts = [0i32; OpStridedSlice::MAX_DIMS];
+        for axis in 0..rank {
+            counts[axis] = if size[axis] == -1 {
+                input_dims[axis] - begin[axis]
+            } else {
+                size[axis]
+            };
+            if begin[axis] < 0 || counts[axis] < 0 || begin[axis] + counts[axis] > input_dims[axis]
+            {
+                return Err(InCompatibleShape(
+                    begin[axis] + counts[axis],
+                    input_dims[axis],
+                ));
+            }
+        }
+        let strides = [1i32; OpStridedSlice::MAX_DIMS];
+
+        OpStridedSlice::copy_strided(
+            input_data,
+            input_dims,
+            output_data,
+            begin,
+            &counts[..rank],
+            &strides[..rank],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpSlice;
+    use crate::kernel::micro_builtin_options::BLiteBuiltinOption::SliceOptions;
+    use crate::kernel::utils::testing::{eval_i32, Tensor};
+    use crate::micro_errors::BLiteError::InCompatibleShape;
+
+    #[test]
+    fn test_slice_begin_and_size() {
+        // input of shape [3, 4]
+        let input: Vec<f32> = (0..12).map(|x| x as f32).collect();
+        let dims = [3, 4];
+
+        // size of -1 takes the rest of the axis
+        let mut output = [0.; 6];
+        OpSlice::kernel(&input, &dims, &mut output, &[1, 1], &[2, -1]).unwrap();
+        assert_eq!([5., 6., 7., 9., 10., 11.], output);
+
+        // the slice must be within the input, and have a begin and a size for each axis
+        for (begin, size) in [
+            (vec![2, 0], vec![2, 1]),
+            (vec![-1, 0], vec![1, 1]),
+            (vec![0, 0], vec![1, -2]),
+            (vec![0], vec![1]),
+        ] {
+            let mut output = [0.; 2];
+            let result = OpSlice::kernel(&input, &dims, &mut output, &begin, &size);
+            assert!(matches!(result, Err(InCompatibleShape(..))), "{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_slice_int32() {
+        let outputs = eval_i32(
+            OpSlice::eval::<f32>,
+            &[
+                Tensor::from((0..12).collect(), vec![3, 4]),
+                Tensor::from(vec![0, 2], vec![2]),
+                Tensor::from(vec![-1, 1], vec![2]),
+            ],
+            &[&[3, 1]],
+            SliceOptions {},
+        )
+        .unwrap();
+        assert_eq!(vec![2, 6, 10], outputs[0]);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpSqueeze {}
+
+impl OpSqueeze {
+    const OPCODE: i32 = 43;
+
+    pub fn squeeze<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Ok(BLiteBuiltinOption::SqueezeOptions {})
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::OutputAliasesInput)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        _builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        // the output already holds the data if the memory planner aliases it to the input
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let (input_len, output_len) = (tensors[idx_input].len(), tensors[idx_output].len());
+            if input_len != output_len {
+                return Err(InCompatibleShape(input_len as i32, output_len as i32));
+            }
+            return Ok(());
+        }
+
+        // The output shape is already known from the model, so only the data is copied
+        match (&tensors[idx_input], &tensors[idx_output]) {
+            (BTensor(input), BTensor(output)) => {
+                Self::kernel(input.borrow().data, output.borrow_mut().data)
+            }
+            (I32Tensor(input), I32Tensor(output)) => {
+                Self::kernel(input.borrow().data, output.borrow_mut().data)
+            }
+            _ => Err(InCompatibleType),
+        }
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(input_data: &[T], output_data: &mut [T]) -> Result<()> {
+        if input_data.len() != output_data.len() {
+            return Err(InCompatibleShape(
+                input_data.len() as i32,
+                output_data.len() as i32,
+            ));
+        }
+        output_data.copy_from_slice(input_data);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpSqueeze;
+    use crate::kernel::micro_builtin_options::BLiteBuiltinOption::SqueezeOptions;
+    use crate::kernel::utils::testing::{eval_i32, Tensor};
+    use crate::micro_errors::BLiteError::InCompatibleShape;
+
+    #[test]
+    fn test_squeeze_int32() {
+        let input = Tensor::from(vec![1, 2, 3], vec![1, 3, 1]);
+        let outputs =
+            eval_i32(OpSqueeze::eval::<f32>, &[input], &[&[3]], SqueezeOptions {}).unwrap();
+        assert_eq!(vec![1, 2, 3], outputs[0]);
+
+        // the output must have as many elements as the input
+        let input = Tensor::from(vec![1, 2, 3], vec![1, 3, 1]);
+        let result = eval_i32(OpSqueeze::eval::<f32>, &[input], &[&[2]], SqueezeOptions {});
+        assert!(matches!(result, Err(InCompatibleShape(3, 2))), "{result:?}");
+    }
+}
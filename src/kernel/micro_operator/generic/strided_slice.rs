@@ -0,0 +1,371 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpStridedSlice {}
+
+impl OpStridedSlice {
+    const OPCODE: i32 = 45;
+    pub const MAX_DIMS: usize = 8;
+
+    pub fn strided_slice<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 4,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_strided_slice_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        Ok(BLiteBuiltinOption::StridedSliceOptions {
+            begin_mask: builtin_option.begin_mask(),
+            end_mask: builtin_option.end_mask(),
+            ellipsis_mask: builtin_option.ellipsis_mask(),
+            new_axis_mask: builtin_option.new_axis_mask(),
+            shrink_axis_mask: builtin_option.shrink_axis_mask(),
+            offset: builtin_option.offset(),
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+        let begin = tensors[node.inputs[1] as usize]._i32()?.borrow();
+        let end = tensors[node.inputs[2] as usize]._i32()?.borrow();
+        let strides = tensors[node.inputs[3] as usize]._i32()?.borrow();
+
+        let StridedSliceOptions {
+            begin_mask,
+            end_mask,
+            ellipsis_mask,
+            new_axis_mask,
+            shrink_axis_mask,
+            offset,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        match (&tensors[idx_input], &tensors[idx_output]) {
+            (BTensor(input), BTensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(
+                    input.data,
+                    input.dims,
+                    output.data,
+                    begin.data,
+                    end.data,
+                    strides.data,
+                    begin_mask,
+                    end_mask,
+                    ellipsis_mask,
+                    new_axis_mask,
+                    shrink_axis_mask,
+                    offset,
+                )
+            }
+            (I32Tensor(input), I32Tensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(
+                    input.data,
+                    input.dims,
+                    output.data,
+                    begin.data,
+                    end.data,
+                    strides.data,
+                    begin_mask,
+                    end_mask,
+                    ellipsis_mask,
+                    new_axis_mask,
+                    shrink_axis_mask,
+                    offset,
+                )
+            }
+            _ => Err(InCompatibleType),
+        }
+    }
+
+    /// This computations is corresponded to BuildStridedSliceParams, StartForAxis and StopForAxis
+    /// of tensorflow lite. The ellipsis and the new axes are expanded into an effective shape,
+    /// so that the slice is taken by `copy_strided`.
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        output_data: &mut [T],
+        begin: &[i32],
+        end: &[i32],
+        strides: &[i32],
+        //
+        begin_mask: i32,
+        end_mask: i32,
+        ellipsis_mask: i32,
+        new_axis_mask: i32,
+        shrink_axis_mask: i32,
+        offset: bool,
+    ) -> Result<()> {
+        let input_rank = input_dims.len();
+        let begin_count = begin.len();
+        if end.len() != begin_count || strides.len() != begin_count {
+            return Err(NotMatchSize);
+        }
+
+        // new axes that are not overridden by the ellipsis
+        let num_add_axis = (0..begin_count)
+            .filter(|&i| ellipsis_mask & (1 << i) == 0 && new_axis_mask & (1 << i) != 0)
+            .count();
+        let effective_dims = input_rank + num_add_axis;
+        if effective_dims > Self::MAX_DIMS {
+            return Err(InCompatibleShape(
+                effective_dims as i32,
+                Self::MAX_DIMS as i32,
+            ));
+        }
+
+        // The ellipsis is expanded to the dimensions that begin, end and strides do not cover
+        let mut effective_ellipsis_mask = 0;
+        let mut effective_new_axis_mask = 0;
+        let mut ellipsis_start_idx = effective_dims;
+        let mut expanded_ellipsis = 0;
+        let mut i = 0;
+        while i < effective_dims {
+            if ellipsis_mask & (1 << i) != 0 {
+                ellipsis_start_idx = i;
+                let uncovered = (i + 1 + num_add_axis + input_rank) as isize - begin_count as isize;
+                let ellipsis_end_idx = max(i + 1, min(uncovered, effective_dims as isize) as usize);
+                expanded_ellipsis = ellipsis_end_idx - ellipsis_start_idx - 1;
+                while i < ellipsis_end_idx {
+                    effective_ellipsis_mask |= 1 << i;
+                    i += 1;
+                }
+                continue;
+            }
+            if new_axis_mask & (1 << (i - expanded_ellipsis)) != 0 {
+                effective_new_axis_mask |= 1 << i;
+            }
+            i += 1;
+        }
+
+        let mut effective_shape = [1i32; Self::MAX_DIMS];
+        let mut starts = [0i32; Self::MAX_DIMS];
+        let mut counts = [1i32; Self::MAX_DIMS];
+        let mut effective_strides = [1i32; Self::MAX_DIMS];
+        let mut added_ellipsis = 0;
+        let mut added_axises = 0;
+        for i in 0..effective_dims {
+            if effective_ellipsis_mask & (1 << i) != 0 {
+                // the whole axis is taken
+                added_ellipsis = i.saturating_sub(ellipsis_start_idx);
+                effective_shape[i] = input_dims[i - added_axises];
+                counts[i] = effective_shape[i];
+            } else if effective_new_axis_mask & (1 << i) != 0 {
+                // a new axis is a dimension of 1 added to the input
+                added_axises += 1;
+            } else if i >= begin_count + expanded_ellipsis {
+                effective_shape[i] = input_dims[i - added_axises];
+                counts[i] = effective_shape[i];
+            } else {
+                let orig_idx = i - added_ellipsis;
+                let axis_size = input_dims[i - added_axises];
+                let stride = strides[orig_idx];
+                if stride == 0 {
+                    return Err(InCompatibleShape(orig_idx as i32, stride));
+                }
+                effective_shape[i] = axis_size;
+                effective_strides[i] = stride;
+                if axis_size == 0 {
+                    counts[i] = 0;
+                    continue;
+                }
+
+                let (lowest, highest) = if stride > 0 {
+                    (0, axis_size as i64)
+                } else {
+                    (-1, axis_size as i64 - 1)
+                };
+                let mut start = begin[orig_idx] as i64;
+                if begin_mask & (1 << orig_idx) != 0 {
+                    start = if stride > 0 {
+                        i64::MIN / 2
+                    } else {
+                        i64::MAX / 2
+                    };
+                }
+                if start < 0 {
+                    start += axis_size as i64;
+                }
+                start = start.clamp(lowest, highest);
+
+                let stop = if shrink_axis_mask & (1 << orig_idx) != 0 {
+                    // the end does not matter when the axis is shrunk
+                    start + 1
+                } else {
+                    let mut stop = end[orig_idx] as i64;
+                    if offset {
+                        stop += begin[orig_idx] as i64;
+                    }
+                    if end_mask & (1 << orig_idx) != 0 {
+                        stop = if stride > 0 {
+                            i64::MAX / 2
+                        } else {
+                            i64::MIN / 2
+                        };
+                    }
+                    if stop < 0 {
+                        stop += axis_size as i64;
+                    }
+                    stop.clamp(lowest, highest)
+                };
+
+                let stride = stride as i64;
+                let count = if stride > 0 {
+                    (stop - start + stride - 1) / stride
+                } else {
+                    (start - stop - stride - 1) / -stride
+                };
+                starts[i] = start as i32;
+                counts[i] = max(count, 0) as i32;
+            }
+        }
+
+        Self::copy_strided(
+            input_data,
+            &effective_shape[..effective_dims],
+            output_data,
+            &starts[..effective_dims],
+            &counts[..effective_dims],
+            &effective_strides[..effective_dims],
+        )
+    }
+
+    /// Copies `counts[axis]` elements from `starts[axis]` stepping by `strides[axis]` along each axis
+    #[inline(always)]
+    pub fn copy_strided<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        output_data: &mut [T],
+        starts: &[i32],
+        counts: &[i32],
+        strides: &[i32],
+    ) -> Result<()> {
+        let rank = input_dims.len();
+        if rank > Self::MAX_DIMS {
+            return Err(InCompatibleShape(rank as i32, Self::MAX_DIMS as i32));
+        }
+        let output_size = counts.iter().product::<i32>();
+        if output_data.len() != output_size as usize {
+            return Err(NotMatchSize);
+        }
+        if output_size == 0 {
+            return Ok(());
+        }
+
+        let mut input_strides = [1isize; Self::MAX_DIMS];
+        for i in (0..rank.saturating_sub(1)).rev() {
+            input_strides[i] = input_strides[i + 1] * input_dims[i + 1] as isize;
+        }
+        let mut steps = [0isize; Self::MAX_DIMS];
+        let mut input_offset = 0;
+        for axis in 0..rank {
+            steps[axis] = strides[axis] as isize * input_strides[axis];
+            input_offset += starts[axis] as isize * input_strides[axis];
+        }
+
+        let mut index = [0i32; Self::MAX_DIMS];
+        for output_v in output_data.iter_mut() {
+            *output_v = input_data[input_offset as usize];
+            for axis in (0..rank).rev() {
+                index[axis] += 1;
+                input_offset += steps[axis];
+                if index[axis] < counts[axis] {
+                    break;
+                }
+                input_offset -= steps[axis] * counts[axis] as isize;
+                index[axis] = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpStridedSlice;
+
+    #[test]
+    fn test_strided_slice_masks() {
+        // input of shape [2, 3, 4]
+        let input = (0..24).collect::<Vec<i32>>();
+        let dims = [2, 3, 4];
+
+        // input[1, :, ::-2]
+        let mut output = vec![0; 6];
+        OpStridedSlice::kernel(
+            &input,
+            &dims,
+            &mut output,
+            &[1, 0, -1],
+            &[2, 0, 0],
+            &[1, 1, -2],
+            0b010,
+            0b110,
+            0,
+            0,
+            0b001,
+            false,
+        )
+        .unwrap();
+        assert_eq!(vec![15, 13, 19, 17, 23, 21], output);
+
+        // input[..., 1:3] with a new axis in front
+        let mut output = vec![0; 12];
+        OpStridedSlice::kernel(
+            &input,
+            &dims,
+            &mut output,
+            &[0, 0, 1],
+            &[0, 0, 3],
+            &[1, 1, 1],
+            0,
+            0,
+            0b010,
+            0b001,
+            0,
+            false,
+        )
+        .unwrap();
+        assert_eq!(vec![1, 2, 5, 6, 9, 10, 13, 14, 17, 18, 21, 22], output);
+    }
+}
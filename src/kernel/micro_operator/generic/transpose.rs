@@ -0,0 +1,137 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpTranspose {}
+
+impl OpTranspose {
+    const OPCODE: i32 = 39;
+    const MAX_DIMS: usize = 6;
+
+    pub fn transpose<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 4,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        _op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Ok(BLiteBuiltinOption::TransposeOptions {})
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        _builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_perm = node.inputs[1] as usize;
+        let idx_output = node.outputs[0] as usize;
+        let perm = tensors[idx_perm]._i32()?.borrow();
+
+        match (&tensors[idx_input], &tensors[idx_output]) {
+            (BTensor(input), BTensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(input.data, input.dims, output.data, perm.data)
+            }
+            (I32Tensor(input), I32Tensor(output)) => {
+                let input = input.borrow();
+                let mut output = output.borrow_mut();
+                Self::kernel(input.data, input.dims, output.data, perm.data)
+            }
+            _ => Err(InCompatibleType),
+        }
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        output_data: &mut [T],
+        perm: &[i32],
+    ) -> Result<()> {
+        let rank = input_dims.len();
+        if rank > Self::MAX_DIMS || perm.len() != rank {
+            return Err(InCompatibleShape(perm.len() as i32, rank as i32));
+        }
+        if output_data.len() != input_data.len() {
+            return Err(NotMatchSize);
+        }
+
+        let mut input_strides = [1usize; Self::MAX_DIMS];
+        for i in (0..rank.saturating_sub(1)).rev() {
+            input_strides[i] = input_strides[i + 1] * input_dims[i + 1] as usize;
+        }
+
+        // output axis i walks through the input axis perm[i]
+        let mut output_dims = [1usize; Self::MAX_DIMS];
+        let mut strides = [0usize; Self::MAX_DIMS];
+        for (i, &p) in perm.iter().enumerate() {
+            let p = if p < 0 { p + rank as i32 } else { p };
+            if p < 0 || p as usize >= rank {
+                return Err(InCompatibleShape(p, rank as i32));
+            }
+            output_dims[i] = input_dims[p as usize] as usize;
+            strides[i] = input_strides[p as usize];
+        }
+
+        let mut index = [0usize; Self::MAX_DIMS];
+        let mut input_offset = 0;
+        for output_v in output_data.iter_mut() {
+            *output_v = input_data[input_offset];
+            for axis in (0..rank).rev() {
+                index[axis] += 1;
+                input_offset += strides[axis];
+                if index[axis] < output_dims[axis] {
+                    break;
+                }
+                input_offset -= strides[axis] * output_dims[axis];
+                index[axis] = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpTranspose;
+
+    #[test]
+    fn test_transpose_3d() {
+        // input of shape [2, 3, 4] is transposed to [4, 2, 3]
+        let input = (0..24).collect::<Vec<i32>>();
+        let mut output = vec![0; 24];
+        OpTranspose::kernel(&input, &[2, 3, 4], &mut output, &[2, 0, 1]).unwrap();
+        for k in 0..4 {
+            for i in 0..2 {
+                for j in 0..3 {
+                    assert_eq!(input[i * 12 + j * 4 + k], output[k * 6 + i * 3 + j]);
+                }
+            }
+        }
+    }
+}
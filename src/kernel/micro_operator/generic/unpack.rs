@@ -0,0 +1,177 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpUnpack {}
+
+impl OpUnpack {
+    const OPCODE: i32 = 88;
+
+    pub fn unpack<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        _tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_unpack_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        Ok(BLiteBuiltinOption::UnpackOptions {
+            axis: builtin_option.axis(),
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+
+        let UnpackOptions { axis } = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+
+        let values_count = node.outputs.len();
+        for (i, &idx_output) in node.outputs.iter().enumerate() {
+            match (&tensors[idx_input], &tensors[idx_output as usize]) {
+                (BTensor(input), BTensor(output)) => {
+                    let input = input.borrow();
+                    Self::kernel(
+                        input.data,
+                        input.dims,
+                        output.borrow_mut().data,
+                        axis,
+                        i,
+                        values_count,
+                    )?;
+                }
+                (I32Tensor(input), I32Tensor(output)) => {
+                    let input = input.borrow();
+                    Self::kernel(
+                        input.data,
+                        input.dims,
+                        output.borrow_mut().data,
+                        axis,
+                        i,
+                        values_count,
+                    )?;
+                }
+                _ => return Err(InCompatibleType),
+            }
+        }
+        Ok(())
+    }
+
+    /// Copies the `value_idx`-th slice along `axis` of the input into an output
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        output_data: &mut [T],
+        axis: i32,
+        value_idx: usize,
+        values_count: usize,
+    ) -> Result<()> {
+        let rank = input_dims.len() as i32;
+        let axis = if axis < 0 { axis + rank } else { axis };
+        if axis < 0 || axis >= rank || input_dims[axis as usize] as usize != values_count {
+            return Err(InCompatibleShape(axis, rank));
+        }
+        let copy_size = input_dims[axis as usize + 1..]
+            .iter()
+            .fold(1, |acc, &x| acc * x as usize);
+        if output_data.len() * values_count != input_data.len() {
+            return Err(NotMatchSize);
+        }
+        if copy_size == 0 {
+            return Ok(());
+        }
+
+        for (k, output_chunk) in output_data.chunks_exact_mut(copy_size).enumerate() {
+            let from = (k * values_count + value_idx) * copy_size;
+            output_chunk.copy_from_slice(&input_data[from..from + copy_size]);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpUnpack;
+    use crate::kernel::micro_builtin_options::BLiteBuiltinOption::UnpackOptions;
+    use crate::kernel::utils::testing::{eval_i32, Tensor};
+    use crate::micro_errors::{BLiteError::InCompatibleShape, Result};
+
+    fn unpack(input: &[f32], input_dims: &[i32], axis: i32, count: usize) -> Result<Vec<Vec<f32>>> {
+        (0..count)
+            .map(|i| {
+                let mut output = vec![0.; input.len() / count];
+                OpUnpack::kernel(input, input_dims, &mut output, axis, i, count)?;
+                Ok(output)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_unpack_axes() {
+        // input of shape [2, 2, 3]
+        let input: Vec<f32> = (0..12).map(|x| x as f32).collect();
+        let dims = [2, 2, 3];
+
+        let outputs = unpack(&input, &dims, 0, 2).unwrap();
+        assert_eq!(vec![input[..6].to_vec(), input[6..].to_vec()], outputs);
+
+        let outputs = unpack(&input, &dims, 1, 2).unwrap();
+        assert_eq!(vec![0., 1., 2., 6., 7., 8.], outputs[0]);
+        assert_eq!(vec![3., 4., 5., 9., 10., 11.], outputs[1]);
+
+        // the negative axis counts from the last one
+        let outputs = unpack(&input, &dims, -1, 3).unwrap();
+        assert_eq!(vec![0., 3., 6., 9.], outputs[0]);
+        assert_eq!(vec![2., 5., 8., 11.], outputs[2]);
+
+        // the axis must be within the rank, and have as many elements as the outputs
+        for (axis, count) in [(3, 2), (-4, 2), (2, 2)] {
+            let result = unpack(&input, &dims, axis, count);
+            assert!(matches!(result, Err(InCompatibleShape(..))), "{result:?}");
+        }
+    }
+
+    #[test]
+    fn test_unpack_int32() {
+        let outputs = eval_i32(
+            OpUnpack::eval::<f32>,
+            &[Tensor::from(vec![0, 10, 1, 11, 2, 12], vec![3, 2])],
+            &[&[3], &[3]],
+            UnpackOptions { axis: 1 },
+        )
+        .unwrap();
+        assert_eq!(vec![vec![0, 1, 2], vec![10, 11, 12]], outputs);
+    }
+}
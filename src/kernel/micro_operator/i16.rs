@@ -0,0 +1,6 @@
+pub mod add_i16;
+pub mod conv2d_i16;
+pub mod depthwise_conv2d_i16;
+pub mod fully_connected_i16;
+pub mod logistic_i16;
+pub mod softmax_i16;
@@ -0,0 +1,118 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::i8::add_i8::OpAddInt8;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// ADD of the int16x8 quantization, which shares the kernel with OpAddInt8.
+/// The inputs are shifted left by 15 bits instead of 20 bits so that the shifted int16 values fit in int32
+#[derive(Debug, Clone, Copy)]
+pub struct OpAddInt16 {}
+
+impl OpAddInt16 {
+    const OPCODE: i32 = 0;
+    const LEFT_SHIFT: i32 = 15;
+
+    pub fn add_int16<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 4,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        // int16 tensors are quantized symmetrically
+        let idxs = [
+            op.inputs().unwrap().get(0),
+            op.inputs().unwrap().get(1),
+            op.outputs().unwrap().get(0),
+        ];
+        for idx in idxs {
+            let Some(BLiteQuantizationParams { zero_point, .. }) =
+                tensors[idx as usize]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            if zero_point[0] != 0 {
+                return Err(NotMatchZeroPoint(zero_point[0] as i32));
+            }
+        }
+
+        OpAddInt8::parse_with_left_shift(op, tensors, Self::LEFT_SHIFT)
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, OpAddInt8::eval::<T>, NotInitialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::kernel::micro_operator::i8::add_i8::OpAddInt8;
+    use crate::kernel::utils::quantization::quantize_multiplier;
+
+    #[test]
+    fn test_add_int16() {
+        let input1_scale = 1. / 4096f32;
+        let input2_scale = 1. / 2048f32;
+        let output_scale = 1. / 1024f32;
+        let input1 = [-32768i16, -4096, 0, 1234, 32767];
+        let input2 = [-32768i16, 2048, 7, -1234, 32767];
+
+        let left_shift = 15;
+        let twice_max_input_scale = 2. * f64::max(input1_scale as f64, input2_scale as f64);
+        let (input1_multiplier, input1_shift) =
+            quantize_multiplier(input1_scale as f64 / twice_max_input_scale).unwrap();
+        let (input2_multiplier, input2_shift) =
+            quantize_multiplier(input2_scale as f64 / twice_max_input_scale).unwrap();
+        let (output_multiplier, output_shift) = quantize_multiplier(
+            twice_max_input_scale / ((1 << left_shift) as f64 * output_scale as f64),
+        )
+        .unwrap();
+
+        let mut output = [0i16; 5];
+        OpAddInt8::kernel(
+            &input1,
+            &[5],
+            &input2,
+            &[5],
+            &mut output,
+            &[5],
+            left_shift,
+            0,
+            0,
+            0,
+            input1_multiplier,
+            input1_shift,
+            input2_multiplier,
+            input2_shift,
+            output_multiplier,
+            output_shift,
+            i16::MIN as i32,
+            i16::MAX as i32,
+        )
+        .unwrap();
+
+        for (i, &output_v) in output.iter().enumerate() {
+            let expected = input1_scale * input1[i] as f32 + input2_scale * input2[i] as f32;
+            let actual = output_scale * output_v as f32;
+            assert!(
+                (expected - actual).abs() <= output_scale,
+                "{expected} != {actual}"
+            );
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::calc_fully_connected_multiplier_shift;
+use crate::kernel::utils::quantization::multiply_by_quantized_multiplier_i64;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// FULLY_CONNECTED of the int16x8 quantization,
+/// whose input and output are int16, filter is int8 and bias is int64
+#[derive(Debug, Clone, Copy)]
+pub struct OpFullyConnectedInt16 {}
+
+impl OpFullyConnectedInt16 {
+    const OPCODE: i32 = 9;
+
+    pub fn fully_connected_int16<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S>
+    {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 7,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_fully_connected_options();
+        let mut op_code = -1;
+        if let Some(builtin_option) = builtin_option {
+            op_code = builtin_option.fused_activation_function().0 as i32;
+        }
+
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let (input_scale, input_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        let filter_idx = op.inputs().unwrap().get(1) as usize;
+        let Some(filter_quant_params) = tensors[filter_idx]._i8()?.borrow().quant_params else {
+            return Err(BLiteError::NotFoundQuantParams);
+        };
+        let filter_zero_point = filter_quant_params.zero_point[0] as i32;
+        let output_depth = {
+            let filter_dims = tensors[filter_idx]._i8()?.borrow().dims;
+            filter_dims[filter_dims.len() - 2] as usize
+        };
+
+        let bias_idx = op.inputs().unwrap().get(2);
+        let bias_scales = if bias_idx >= 0 {
+            let Some(BLiteQuantizationParams { scale, .. }) =
+                tensors[bias_idx as usize]._i64()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            Some(scale)
+        } else {
+            None
+        };
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let (output_scale, output_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range_quantized::<T>(
+                output_scale,
+                output_zero_point,
+                op_code,
+            )?;
+
+        //This computations is corresponded to CalculateOpDataFullyConnected
+        let per_channel_multiplier = unsafe { alloc_array_mut(allocator, output_depth) }?;
+        let per_channel_shift = unsafe { alloc_array_mut(allocator, output_depth) }?;
+        calc_fully_connected_multiplier_shift(
+            input_scale,
+            &filter_quant_params,
+            output_scale,
+            bias_scales,
+            per_channel_multiplier,
+            per_channel_shift,
+        )?;
+
+        Ok(BLiteBuiltinOption::QuantizedFullyConnectedOptions {
+            op_code,
+            fused_activation_min,
+            fused_activation_max,
+            input_offset: -input_zero_point,
+            filter_offset: -filter_zero_point,
+            output_offset: output_zero_point,
+            per_channel_multiplier,
+            per_channel_shift,
+            filter_sparsity: None,
+            effective_bias: &[],
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let QuantizedFullyConnectedOptions {
+            op_code: _,
+            fused_activation_min,
+            fused_activation_max,
+            input_offset,
+            filter_offset,
+            output_offset,
+            per_channel_multiplier,
+            per_channel_shift,
+            filter_sparsity: _,
+            effective_bias: _,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+
+        let idx_filter = node.inputs[1] as usize;
+        let filter = tensors[idx_filter]._i8()?.borrow();
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        let idx_bias = node.inputs[2];
+
+        let accum_depth = filter.dims[filter.dims.len() - 1] as usize;
+        let output_depth = filter.dims[filter.dims.len() - 2] as usize;
+        let batches = input.size() / accum_depth;
+
+        if idx_bias >= 0 {
+            let bias = tensors[idx_bias as usize]._i64()?.borrow();
+            Self::kernel(
+                input.data,
+                filter.data,
+                Some(bias.data),
+                output.data,
+                input_offset,
+                filter_offset,
+                output_offset,
+                output_depth,
+                per_channel_multiplier,
+                per_channel_shift,
+                accum_depth,
+                batches,
+                fused_activation_min,
+                fused_activation_max,
+            )
+        } else {
+            Self::kernel(
+                input.data,
+                filter.data,
+                None,
+                output.data,
+                input_offset,
+                filter_offset,
+                output_offset,
+                output_depth,
+                per_channel_multiplier,
+                per_channel_shift,
+                accum_depth,
+                batches,
+                fused_activation_min,
+                fused_activation_max,
+            )
+        }
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: &[i8],
+        bias_data: Option<&[i64]>,
+        output_data: &mut [T],
+        // for quantization
+        input_offset: i32,
+        filter_offset: i32,
+        output_offset: i32,
+        output_depth: usize,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
+        //
+        accum_depth: usize,
+        batches: usize,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        for batch in 0usize..batches {
+            for out_d in 0usize..output_depth {
+                // the products of int16 and int8 are accumulated in int64
+                let mut total = 0i64;
+                for acc_d in 0usize..accum_depth {
+                    let input_val =
+                        AsPrimitive::<i32>::as_(input_data[batch * accum_depth + acc_d]);
+                    let filter_val = filter_data[out_d * accum_depth + acc_d] as i32;
+                    total += ((input_val + input_offset) * (filter_val + filter_offset)) as i64;
+                }
+
+                if let Some(bias_data) = bias_data {
+                    total += bias_data[out_d];
+                }
+
+                let mut total = multiply_by_quantized_multiplier_i64(
+                    total,
+                    per_channel_multiplier[out_d],
+                    per_channel_shift[out_d],
+                )?;
+
+                total += output_offset;
+                total = max(total, fused_activation_min);
+                total = min(total, fused_activation_max);
+
+                output_data[batch * output_depth + out_d] = FromPrimitive::from_i32(total).unwrap();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpFullyConnectedInt16;
+
+    #[test]
+    fn test_fully_connected_int16() {
+        // input_scale = 1/512, filter_scale = 1/64, bias_scale = 1/32768, output_scale = 1/256
+        let input_scale = 1. / 512.;
+        let filter_scale = 1. / 64.;
+        let output_scale = 1. / 256.;
+        let input = [256i16, -512, 1024, 128, -256, 0, 2048, -1024];
+        let filter = [32i8, -64, 16, 127, -128, 0, 64, 8];
+        let bias = [16384i64, -32768];
+        let (output_multiplier, output_shift) =
+            crate::kernel::utils::quantization::quantize_multiplier(
+                (input_scale * filter_scale / output_scale) as f64,
+            )
+            .unwrap();
+
+        let mut output = [0i16; 4];
+        OpFullyConnectedInt16::kernel(
+            &input,
+            &filter,
+            Some(&bias),
+            &mut output,
+            0,
+            0,
+            0,
+            2,
+            &[output_multiplier; 2],
+            &[output_shift; 2],
+            4,
+            2,
+            i16::MIN as i32,
+            i16::MAX as i32,
+        )
+        .unwrap();
+
+        for batch in 0..2 {
+            for out_d in 0..2 {
+                let mut expected = bias[out_d] as f32 * input_scale * filter_scale;
+                for d in 0..4 {
+                    expected += input[batch * 4 + d] as f32
+                        * input_scale
+                        * filter[out_d * 4 + d] as f32
+                        * filter_scale;
+                }
+                let actual = output[batch * 2 + out_d] as f32 * output_scale;
+                assert!(
+                    (expected - actual).abs() <= output_scale,
+                    "{expected} != {actual}"
+                );
+            }
+        }
+    }
+}
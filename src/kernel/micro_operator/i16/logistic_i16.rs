@@ -0,0 +1,244 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::lookup_table::{populate_sigmoid_table_uint16, SIGMOID_TABLE_SIZE};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+#[cfg(feature = "no_std")]
+use num_traits::Float;
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// LOGISTIC of the int16x8 quantization, which interpolates the table of sigmoid(i / 24).
+/// The outputs are on the scale of 1/32768 with the zero point 0
+#[derive(Debug, Clone, Copy)]
+pub struct OpLogisticInt16 {}
+
+impl OpLogisticInt16 {
+    const OPCODE: i32 = 14;
+    const INPUT_INTEGER_BITS: i32 = 3;
+    const OUTPUT_FRACTIONAL_BITS: i32 = 15;
+
+    pub fn logistic_int16<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let input_scale = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            if zero_point[0] != 0 {
+                return Err(NotMatchZeroPoint(zero_point[0] as i32));
+            }
+            scale[0]
+        };
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            if zero_point[0] != 0 {
+                return Err(NotMatchZeroPoint(zero_point[0] as i32));
+            }
+            let (is_pot, output_scale_log2) = Self::checked_log2(scale[0]);
+            if !is_pot || output_scale_log2 != -Self::OUTPUT_FRACTIONAL_BITS {
+                return Err(NotMatchScale(scale[0] as f64));
+            }
+        }
+
+        // This computations is corresponded to LogisticPrepare of int16
+        let (is_pot, input_scale_log2) = Self::checked_log2(input_scale);
+        let mut input_left_shift = (15 - Self::INPUT_INTEGER_BITS) + input_scale_log2;
+        let input_multiplier = if is_pot && input_left_shift == 0 {
+            0
+        } else {
+            // change the input scale to 1/(3 * 4096), where +/-2^17 represents +/-10.7
+            let mut multiplier = input_scale as f64 * 4096. * 3.;
+            input_left_shift = 0;
+            while multiplier <= 32767. / 2. && input_left_shift <= 30 {
+                input_left_shift += 1;
+                multiplier *= 2.;
+            }
+            multiplier as i32
+        };
+
+        let sigmoid_table = unsafe { alloc_array_mut(allocator, SIGMOID_TABLE_SIZE) }?;
+        populate_sigmoid_table_uint16(sigmoid_table)?;
+
+        Ok(BLiteBuiltinOption::QuantizedLogisticInt16Options {
+            input_multiplier,
+            input_left_shift,
+            sigmoid_table,
+        })
+    }
+
+    /// Returns whether `x` is a power of two and the rounded log2 of `x`
+    fn checked_log2(x: f32) -> (bool, i32) {
+        let x_log2 = x.ln() * (1. / 2f32.ln());
+        let x_log2_rounded = x_log2.round();
+        (
+            (x_log2 - x_log2_rounded).abs() < 1e-3,
+            x_log2_rounded as i32,
+        )
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        let QuantizedLogisticInt16Options {
+            input_multiplier,
+            input_left_shift,
+            sigmoid_table,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(
+            input.data,
+            output.data,
+            input_multiplier,
+            input_left_shift,
+            sigmoid_table,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        // for quantization
+        input_multiplier: i32,
+        input_left_shift: i32,
+        sigmoid_table: &[u16],
+    ) -> Result<()> {
+        // the input is scaled by 3 so that the table of 256 entries covers [-10.7, 10.7]
+        let (input_multiplier, input_left_shift) = if input_multiplier == 0 {
+            (3 << input_left_shift, 0)
+        } else {
+            (input_multiplier, input_left_shift)
+        };
+        let round = if input_left_shift > 0 {
+            1 << (input_left_shift - 1)
+        } else {
+            0
+        };
+
+        for (input_v, output_v) in input_data.iter().zip(output_data.iter_mut()) {
+            let input_v =
+                (AsPrimitive::<i32>::as_(*input_v) * input_multiplier + round) >> input_left_shift;
+
+            // the interpolation is done on the absolute value, since sigmoid(-x) = 1 - sigmoid(x)
+            let abs_input_v = input_v.unsigned_abs();
+            let uh = (abs_input_v >> 9) as usize;
+            let mut result = if uh >= 255 {
+                0x7FFF << 10
+            } else {
+                let ua = sigmoid_table[uh] as u32;
+                let ub = sigmoid_table[uh + 1] as u32;
+                let ut = abs_input_v & 0x1ff;
+                (ua << 9) + ut * (ub - ua)
+            };
+            result = if input_v >= 0 {
+                result + (1 << 9)
+            } else {
+                (1 << (16 + 9)) - result + (1 << 9) - 1
+            };
+            // back to 16 bits
+            result >>= 10;
+            *output_v = FromPrimitive::from_u32(result).unwrap();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpLogisticInt16;
+    use crate::kernel::utils::lookup_table::{populate_sigmoid_table_uint16, SIGMOID_TABLE_SIZE};
+
+    #[test]
+    fn test_logistic_int16() {
+        let mut sigmoid_table = [0u16; SIGMOID_TABLE_SIZE];
+        populate_sigmoid_table_uint16(&mut sigmoid_table).unwrap();
+
+        // the input scale of 2^-12 is handled without the multiplier,
+        // and the input scale of 1/5000 is rescaled by the multiplier
+        let (input_multiplier, input_left_shift) = (0, 0);
+        let input = [-32768i16, -8192, -100, 0, 100, 4096, 12288, 32767];
+        let mut output = [0i16; 8];
+        OpLogisticInt16::kernel(
+            &input,
+            &mut output,
+            input_multiplier,
+            input_left_shift,
+            &sigmoid_table,
+        )
+        .unwrap();
+        for (&x, &y) in input.iter().zip(output.iter()) {
+            let expected = 1. / (1. + (-(x as f32) / 4096.).exp());
+            let actual = y as f32 / 32768.;
+            assert!((expected - actual).abs() <= 1e-3, "{expected} != {actual}");
+        }
+
+        let input_scale = 1. / 5000f64;
+        let mut multiplier = input_scale * 4096. * 3.;
+        let mut input_left_shift = 0;
+        while multiplier <= 32767. / 2. && input_left_shift <= 30 {
+            input_left_shift += 1;
+            multiplier *= 2.;
+        }
+        OpLogisticInt16::kernel(
+            &input,
+            &mut output,
+            multiplier as i32,
+            input_left_shift,
+            &sigmoid_table,
+        )
+        .unwrap();
+        for (&x, &y) in input.iter().zip(output.iter()) {
+            let expected = 1. / (1. + (-(x as f64 * input_scale) as f32).exp());
+            let actual = y as f32 / 32768.;
+            assert!((expected - actual).abs() <= 1e-3, "{expected} != {actual}");
+        }
+    }
+}
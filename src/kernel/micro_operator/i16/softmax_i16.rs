@@ -0,0 +1,253 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::BLiteOperator;
+use crate::kernel::utils::lookup_table::{gen_int16_lut, int16_table_lookup, INT16_LUT_SIZE};
+use crate::kernel::utils::quantization::{multiply_by_quantized_multiplier, quantize_multiplier};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::max;
+use core::fmt::Debug;
+#[cfg(feature = "no_std")]
+use num_traits::Float;
+use num_traits::{AsPrimitive, FromPrimitive};
+
+/// SOFTMAX of the int16x8 quantization, which approximates exp and the reciprocal by
+/// the int16 lookup tables. The outputs are on the scale of 1/32768 with the zero point 0
+#[derive(Debug, Clone, Copy)]
+pub struct OpSoftMaxInt16 {}
+
+impl OpSoftMaxInt16 {
+    const OPCODE: i32 = 25;
+
+    pub fn softmax_int16<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_softmax_options();
+        let mut beta = 1.0;
+        if let Some(builtin_option) = builtin_option {
+            beta = builtin_option.beta();
+        }
+
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let input_scale = {
+            let Some(BLiteQuantizationParams { scale, .. }) =
+                tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            scale[0]
+        };
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            let output_zero_point = zero_point[0] as i32;
+            if output_zero_point != 0 {
+                return Err(NotMatchZeroPoint(output_zero_point));
+            }
+            let expected_scale = 1. / 32768.;
+            if (scale[0] - expected_scale).abs() >= 0.001 * expected_scale {
+                return Err(NotMatchScale(scale[0] as f64));
+            }
+        }
+
+        // This computations is corresponded to SoftmaxPrepare of int16
+        let exp_lut = unsafe { alloc_array_mut(allocator, INT16_LUT_SIZE) }?;
+        gen_int16_lut(|x| x.exp(), -10., 0., -1., 1., exp_lut)?;
+        let one_over_one_plus_x_lut = unsafe { alloc_array_mut(allocator, INT16_LUT_SIZE) }?;
+        gen_int16_lut(|x| 1. / (1. + x), 0., 1., -1., 1., one_over_one_plus_x_lut)?;
+
+        // scale the input differences so that [-65535, 0] corresponds to [-10.0, 0.0]
+        let input_scale_beta_rescale = input_scale as f64 * beta as f64 / (10. / 65535.);
+        let (input_multiplier, input_left_shift) = quantize_multiplier(input_scale_beta_rescale)?;
+
+        Ok(BLiteBuiltinOption::QuantizedSoftMaxInt16Options {
+            input_multiplier,
+            input_left_shift,
+            exp_lut,
+            one_over_one_plus_x_lut,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        let depth = input.dims[input.dims.len() - 1] as usize;
+        let outer_size = input.data.len() / depth;
+
+        let QuantizedSoftMaxInt16Options {
+            input_multiplier,
+            input_left_shift,
+            exp_lut,
+            one_over_one_plus_x_lut,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(
+            input.data,
+            output.data,
+            depth,
+            outer_size,
+            // for quantization
+            input_multiplier,
+            input_left_shift,
+            exp_lut,
+            one_over_one_plus_x_lut,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        depth: usize,
+        outer_size: usize,
+        // for quantization
+        input_multiplier: i32,
+        input_left_shift: i32,
+        exp_lut: &[i16],
+        one_over_one_plus_x_lut: &[i16],
+    ) -> Result<()> {
+        for i in 0..outer_size {
+            let input_row = &input_data[i * depth..(i + 1) * depth];
+            let output_row = &mut output_data[i * depth..(i + 1) * depth];
+
+            let max_in_row = input_row
+                .iter()
+                .map(|&x| AsPrimitive::<i32>::as_(x))
+                .fold(i16::MIN as i32, max);
+
+            // exp(input - max_in_row) in Q0.15 is kept in the output temporarily,
+            // and their sum is in Q16.15
+            let mut sum_of_exps = 0i32;
+            for (input_v, output_v) in input_row.iter().zip(output_row.iter_mut()) {
+                let input_diff = AsPrimitive::<i32>::as_(*input_v) - max_in_row;
+                let scaled_diff = multiply_by_quantized_multiplier(
+                    input_diff,
+                    input_multiplier,
+                    input_left_shift,
+                )?;
+                // recenter [-65535, 0] to [-32768, 32767]
+                let sym_scaled_diff = (scaled_diff + 32767).clamp(-32768, 32767);
+                let exp_v = int16_table_lookup(sym_scaled_diff as i16, exp_lut);
+                sum_of_exps += exp_v as i32;
+                *output_v = FromPrimitive::from_i16(exp_v).unwrap();
+            }
+
+            // normalize the sum to [1, 2) in Q1.15, and then compute 1 / sum by the lookup table of 1 / (1 + x),
+            // whose input x = sum - 1 is recentered from [0, 65535] to [-32768, 32767]
+            let headroom_plus_one = (sum_of_exps as u32).leading_zeros() as i64;
+            let shifted_sum = (((sum_of_exps as i64) << (headroom_plus_one - 1)) + (1 << 13)) >> 14;
+            let sym_shifted_sum = shifted_sum - ((1 << 15) + (1 << 16));
+            let sym_shifted_sum = sym_shifted_sum.clamp(-32768, 32767);
+            let reciprocal_scale =
+                int16_table_lookup(sym_shifted_sum as i16, one_over_one_plus_x_lut);
+
+            // rescale the exps with the reciprocal, whose range [0, 32767] corresponds to [0.0, 1.0]
+            let right_shift = 31 - headroom_plus_one;
+            let round = 1i64 << (right_shift - 1);
+            for output_v in output_row.iter_mut() {
+                let exp_v = AsPrimitive::<i32>::as_(*output_v) as i64;
+                let result = (exp_v * reciprocal_scale as i64 + round) >> right_shift;
+                let result = result.clamp(0, 32767);
+                *output_v = FromPrimitive::from_i64(result).unwrap();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpSoftMaxInt16;
+    use crate::kernel::utils::lookup_table::{gen_int16_lut, INT16_LUT_SIZE};
+    use crate::kernel::utils::quantization::quantize_multiplier;
+
+    #[test]
+    fn test_softmax_int16() {
+        let input_scale = 1. / 2048f32;
+        let input = [-8192i16, -2048, 0, 1024, 4096, -32768, 32767, 12345];
+
+        let mut exp_lut = [0i16; INT16_LUT_SIZE];
+        gen_int16_lut(|x| x.exp(), -10., 0., -1., 1., &mut exp_lut).unwrap();
+        let mut one_over_one_plus_x_lut = [0i16; INT16_LUT_SIZE];
+        gen_int16_lut(
+            |x| 1. / (1. + x),
+            0.,
+            1.,
+            -1.,
+            1.,
+            &mut one_over_one_plus_x_lut,
+        )
+        .unwrap();
+        let (input_multiplier, input_left_shift) =
+            quantize_multiplier(input_scale as f64 / (10. / 65535.)).unwrap();
+
+        let mut output = [0i16; 8];
+        OpSoftMaxInt16::kernel(
+            &input,
+            &mut output,
+            4,
+            2,
+            input_multiplier,
+            input_left_shift,
+            &exp_lut,
+            &one_over_one_plus_x_lut,
+        )
+        .unwrap();
+
+        for row in 0..2 {
+            let row_input = &input[row * 4..(row + 1) * 4];
+            let max_v = row_input
+                .iter()
+                .fold(f32::MIN, |m, &x| m.max(x as f32 * input_scale));
+            let sum: f32 = row_input
+                .iter()
+                .map(|&x| (x as f32 * input_scale - max_v).exp())
+                .sum();
+            for (c, &x) in row_input.iter().enumerate() {
+                let expected = (x as f32 * input_scale - max_v).exp() / sum;
+                let actual = output[row * 4 + c] as f32 / 32768.;
+                assert!((expected - actual).abs() <= 2e-3, "{expected} != {actual}");
+            }
+        }
+    }
+}
@@ -1,7 +1,16 @@
+pub mod add_i8;
 pub mod avg_pool2d_i8;
 pub mod conv2d_i8;
 pub mod depthwise_conv2d_i8;
 pub mod fully_connected_i8;
+pub mod hard_swish_i8;
+pub mod leaky_relu_i8;
+pub mod logistic_i8;
 pub mod max_pool2d_i8;
+pub mod prelu_i8;
+pub mod relu_i8;
 pub mod reshape_i8;
+pub mod resize_bilinear_i8;
 pub mod softmax_i8;
+pub mod tanh_i8;
+pub mod transpose_conv_i8;
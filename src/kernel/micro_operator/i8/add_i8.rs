@@ -0,0 +1,532 @@
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::broadcast::broadcast_index;
+use crate::kernel::utils::quantization::{multiply_by_quantized_multiplier, quantize_multiplier};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpAddInt8 {}
+
+impl OpAddInt8 {
+    const OPCODE: i32 = 0;
+    const LEFT_SHIFT: i32 = 20;
+
+    pub fn add_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Self::parse_with_left_shift(op, tensors, Self::LEFT_SHIFT)
+    }
+
+    /// The inputs are shifted left by `left_shift` so that the rescaled values keep their precision
+    pub fn parse_with_left_shift<'a, T: ArrayElem<T>>(
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+        left_shift: i32,
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_add_options();
+        let mut op_code = -1;
+        if let Some(builtin_option) = builtin_option {
+            op_code = builtin_option.fused_activation_function().0 as i32;
+        }
+
+        let mut quant_params = [(0f32, 0i32); 3];
+        let idxs = [
+            op.inputs().unwrap().get(0),
+            op.inputs().unwrap().get(1),
+            op.outputs().unwrap().get(0),
+        ];
+        for (params, idx) in quant_params.iter_mut().zip(idxs) {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[idx as usize]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            *params = (scale[0], zero_point[0] as i32);
+        }
+        let [(input1_scale, input1_zero_point), (input2_scale, input2_zero_point), (output_scale, output_zero_point)] =
+            quant_params;
+
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range_quantized::<T>(
+                output_scale,
+                output_zero_point,
+                op_code,
+            )?;
+
+        // This computations is corresponded to CalculateOpDataAdd
+        let twice_max_input_scale = 2. * f64::max(input1_scale as f64, input2_scale as f64);
+        let real_input1_multiplier = input1_scale as f64 / twice_max_input_scale;
+        let real_input2_multiplier = input2_scale as f64 / twice_max_input_scale;
+        let real_output_multiplier =
+            twice_max_input_scale / ((1i64 << left_shift) as f64 * output_scale as f64);
+
+        let (input1_multiplier, input1_shift) = quantize_multiplier(real_input1_multiplier)?;
+        let (input2_multiplier, input2_shift) = quantize_multiplier(real_input2_multiplier)?;
+        let (output_multiplier, output_shift) = quantize_multiplier(real_output_multiplier)?;
+
+        Ok(BLiteBuiltinOption::QuantizedAddOptions {
+            op_code,
+            fused_activation_min,
+            fused_activation_max,
+            left_shift,
+            input1_offset: -input1_zero_point,
+            input2_offset: -input2_zero_point,
+            output_offset: output_zero_point,
+            input1_multiplier,
+            input1_shift,
+            input2_multiplier,
+            input2_shift,
+            output_multiplier,
+            output_shift,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input1 = node.inputs[0] as usize;
+        let idx_input2 = node.inputs[1] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let QuantizedAddOptions {
+            op_code: _,
+            fused_activation_min,
+            fused_activation_max,
+            left_shift,
+            input1_offset,
+            input2_offset,
+            output_offset,
+            input1_multiplier,
+            input1_shift,
+            input2_multiplier,
+            input2_shift,
+            output_multiplier,
+            output_shift,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites one of the inputs if the memory planner gives them the same buffer,
+        // whose scaled values are summed in the same way if the inputs are swapped
+        let input1_params = (idx_input1, input1_offset, input1_multiplier, input1_shift);
+        let input2_params = (idx_input2, input2_offset, input2_multiplier, input2_shift);
+        for (
+            (idx_input, input_offset, input_multiplier, input_shift),
+            (idx_other, other_offset, other_multiplier, other_shift),
+        ) in [
+            (input1_params, input2_params),
+            (input2_params, input1_params),
+        ] {
+            if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+                let other = tensors[idx_other]._t()?.borrow();
+                let mut output = tensors[idx_output]._t()?.borrow_mut();
+                let output_dims = output.dims;
+                return Self::kernel_in_place(
+                    output.data,
+                    output_dims,
+                    other.data,
+                    other.dims,
+                    // for quantization
+                    left_shift,
+                    input_offset,
+                    other_offset,
+                    output_offset,
+                    input_multiplier,
+                    input_shift,
+                    other_multiplier,
+                    other_shift,
+                    output_multiplier,
+                    output_shift,
+                    //
+                    fused_activation_min,
+                    fused_activation_max,
+                );
+            }
+        }
+        let input1 = tensors[idx_input1]._t()?.borrow();
+        let input2 = tensors[idx_input2]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+        let output_dims = output.dims;
+
+        Self::kernel(
+            input1.data,
+            input1.dims,
+            input2.data,
+            input2.dims,
+            output.data,
+            output_dims,
+            // for quantization
+            left_shift,
+            input1_offset,
+            input2_offset,
+            output_offset,
+            input1_multiplier,
+            input1_shift,
+            input2_multiplier,
+            input2_shift,
+            output_multiplier,
+            output_shift,
+            //
+            fused_activation_min,
+            fused_activation_max,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input1_data: &[T],
+        input1_dims: &[i32],
+        input2_data: &[T],
+        input2_dims: &[i32],
+        output_data: &mut [T],
+        output_dims: &[i32],
+        // for quantization
+        left_shift: i32,
+        input1_offset: i32,
+        input2_offset: i32,
+        output_offset: i32,
+        input1_multiplier: i32,
+        input1_shift: i32,
+        input2_multiplier: i32,
+        input2_shift: i32,
+        output_multiplier: i32,
+        output_shift: i32,
+        //
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        for (i, output_v) in output_data.iter_mut().enumerate() {
+            *output_v = Self::add_value(
+                input1_data[broadcast_index(i, output_dims, input1_dims)],
+                input2_data[broadcast_index(i, output_dims, input2_dims)],
+                left_shift,
+                input1_offset,
+                input2_offset,
+                output_offset,
+                input1_multiplier,
+                input1_shift,
+                input2_multiplier,
+                input2_shift,
+                output_multiplier,
+                output_shift,
+                fused_activation_min,
+                fused_activation_max,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// `data` holds the input1 which is not broadcast, and is overwritten by the output
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(
+        data: &mut [T],
+        output_dims: &[i32],
+        input2_data: &[T],
+        input2_dims: &[i32],
+        // for quantization
+        left_shift: i32,
+        input1_offset: i32,
+        input2_offset: i32,
+        output_offset: i32,
+        input1_multiplier: i32,
+        input1_shift: i32,
+        input2_multiplier: i32,
+        input2_shift: i32,
+        output_multiplier: i32,
+        output_shift: i32,
+        //
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        for (i, v) in data.iter_mut().enumerate() {
+            *v = Self::add_value(
+                *v,
+                input2_data[broadcast_index(i, output_dims, input2_dims)],
+                left_shift,
+                input1_offset,
+                input2_offset,
+                output_offset,
+                input1_multiplier,
+                input1_shift,
+                input2_multiplier,
+                input2_shift,
+                output_multiplier,
+                output_shift,
+                fused_activation_min,
+                fused_activation_max,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    fn add_value<T: ArrayElem<T>>(
+        input1_v: T,
+        input2_v: T,
+        // for quantization
+        left_shift: i32,
+        input1_offset: i32,
+        input2_offset: i32,
+        output_offset: i32,
+        input1_multiplier: i32,
+        input1_shift: i32,
+        input2_multiplier: i32,
+        input2_shift: i32,
+        output_multiplier: i32,
+        output_shift: i32,
+        //
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<T> {
+        let input1_v = AsPrimitive::<i32>::as_(input1_v) + input1_offset;
+        let input2_v = AsPrimitive::<i32>::as_(input2_v) + input2_offset;
+
+        let scaled_input1_v = multiply_by_quantized_multiplier(
+            input1_v * (1 << left_shift),
+            input1_multiplier,
+            input1_shift,
+        )?;
+        let scaled_input2_v = multiply_by_quantized_multiplier(
+            input2_v * (1 << left_shift),
+            input2_multiplier,
+            input2_shift,
+        )?;
+
+        let raw_sum = scaled_input1_v + scaled_input2_v;
+        let mut total = multiply_by_quantized_multiplier(raw_sum, output_multiplier, output_shift)?;
+        total += output_offset;
+        total = max(total, fused_activation_min);
+        total = min(total, fused_activation_max);
+        Ok(FromPrimitive::from_i32(total).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpAddInt8;
+    use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
+    use crate::kernel::utils::quantization::quantize_multiplier;
+
+    #[test]
+    fn test_add_int8_broadcast() {
+        let (input1_scale, input1_zero_point) = (0.05f32, -3);
+        let (input2_scale, input2_zero_point) = (0.02f32, 5);
+        let (output_scale, output_zero_point) = (0.08f32, 1);
+        let input1 = [-128i8, -40, 0, 25, 77, 127];
+        let input2 = [-100i8, 60];
+
+        let left_shift = 20;
+        let twice_max_input_scale = 2. * f64::max(input1_scale as f64, input2_scale as f64);
+        let (input1_multiplier, input1_shift) =
+            quantize_multiplier(input1_scale as f64 / twice_max_input_scale).unwrap();
+        let (input2_multiplier, input2_shift) =
+            quantize_multiplier(input2_scale as f64 / twice_max_input_scale).unwrap();
+        let (output_multiplier, output_shift) = quantize_multiplier(
+            twice_max_input_scale / ((1 << left_shift) as f64 * output_scale as f64),
+        )
+        .unwrap();
+
+        let mut output = [0i8; 6];
+        OpAddInt8::kernel(
+            &input1,
+            &[3, 2],
+            &input2,
+            &[2],
+            &mut output,
+            &[3, 2],
+            left_shift,
+            -input1_zero_point,
+            -input2_zero_point,
+            output_zero_point,
+            input1_multiplier,
+            input1_shift,
+            input2_multiplier,
+            input2_shift,
+            output_multiplier,
+            output_shift,
+            i8::MIN as i32,
+            i8::MAX as i32,
+        )
+        .unwrap();
+
+        for (i, &output_v) in output.iter().enumerate() {
+            let expected = input1_scale * (input1[i] as i32 - input1_zero_point) as f32
+                + input2_scale * (input2[i % 2] as i32 - input2_zero_point) as f32;
+            let actual = output_scale * (output_v as i32 - output_zero_point) as f32;
+            assert!(
+                (expected - actual).abs() <= output_scale,
+                "{expected} != {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_int8_in_place() {
+        let (input1_zero_point, input2_zero_point, output_zero_point) = (-3, 5, 1);
+        let input1 = [-128i8, -40, 0, 25, 77, 127];
+        let input2 = [-100i8, 60, 3, -7, 90, -128];
+        let left_shift = 20;
+        let (input1_multiplier, input1_shift) = quantize_multiplier(0.5).unwrap();
+        let (input2_multiplier, input2_shift) = quantize_multiplier(0.2).unwrap();
+        let (output_multiplier, output_shift) =
+            quantize_multiplier(0.1 / (1 << left_shift) as f64 / 0.08).unwrap();
+        let input1_params = (-input1_zero_point, input1_multiplier, input1_shift);
+        let input2_params = (-input2_zero_point, input2_multiplier, input2_shift);
+
+        let mut expected = [0i8; 6];
+        OpAddInt8::kernel(
+            &input1,
+            &[6],
+            &input2,
+            &[6],
+            &mut expected,
+            &[6],
+            left_shift,
+            input1_params.0,
+            input2_params.0,
+            output_zero_point,
+            input1_params.1,
+            input1_params.2,
+            input2_params.1,
+            input2_params.2,
+            output_multiplier,
+            output_shift,
+            i8::MIN as i32,
+            i8::MAX as i32,
+        )
+        .unwrap();
+
+        // the output overwrites either of the inputs, whose parameters come first
+        for (data, other, params, other_params) in [
+            (input1, input2, input1_params, input2_params),
+            (input2, input1, input2_params, input1_params),
+        ] {
+            let mut data = data;
+            OpAddInt8::kernel_in_place(
+                &mut data,
+                &[6],
+                &other,
+                &[6],
+                left_shift,
+                params.0,
+                other_params.0,
+                output_zero_point,
+                params.1,
+                params.2,
+                other_params.1,
+                other_params.2,
+                output_multiplier,
+                output_shift,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+            assert_eq!(data, expected);
+        }
+    }
+
+    #[test]
+    fn test_add_int8_fused_activation() {
+        let (input1_scale, input1_zero_point) = (0.05f32, -3);
+        let (input2_scale, input2_zero_point) = (0.02f32, 5);
+        let (output_scale, output_zero_point) = (0.04f32, -10);
+        let input1 = [-128i8, -40, 0, 25, 77, 127];
+        let input2 = [-100i8, 60, 3, -7, 90, 127];
+
+        let left_shift = 20;
+        let twice_max_input_scale = 2. * f64::max(input1_scale as f64, input2_scale as f64);
+        let (input1_multiplier, input1_shift) =
+            quantize_multiplier(input1_scale as f64 / twice_max_input_scale).unwrap();
+        let (input2_multiplier, input2_shift) =
+            quantize_multiplier(input2_scale as f64 / twice_max_input_scale).unwrap();
+        let (output_multiplier, output_shift) = quantize_multiplier(
+            twice_max_input_scale / ((1 << left_shift) as f64 * output_scale as f64),
+        )
+        .unwrap();
+
+        // (fused activation, expected range)
+        for (op_code, (min, max)) in [(1, (0., f32::MAX)), (2, (-1., 1.)), (3, (0., 6.))] {
+            let (fused_activation_min, fused_activation_max) =
+                calculate_fused_activation_range_quantized::<i8>(
+                    output_scale,
+                    output_zero_point,
+                    op_code,
+                )
+                .unwrap();
+            let mut output = [0i8; 6];
+            OpAddInt8::kernel(
+                &input1,
+                &[6],
+                &input2,
+                &[6],
+                &mut output,
+                &[6],
+                left_shift,
+                -input1_zero_point,
+                -input2_zero_point,
+                output_zero_point,
+                input1_multiplier,
+                input1_shift,
+                input2_multiplier,
+                input2_shift,
+                output_multiplier,
+                output_shift,
+                fused_activation_min,
+                fused_activation_max,
+            )
+            .unwrap();
+
+            for (i, &output_v) in output.iter().enumerate() {
+                let expected = (input1_scale * (input1[i] as i32 - input1_zero_point) as f32
+                    + input2_scale * (input2[i] as i32 - input2_zero_point) as f32)
+                    .clamp(min, max)
+                    .clamp(
+                        output_scale * (i8::MIN as i32 - output_zero_point) as f32,
+                        output_scale * (i8::MAX as i32 - output_zero_point) as f32,
+                    );
+                let actual = output_scale * (output_v as i32 - output_zero_point) as f32;
+                assert!(
+                    (expected - actual).abs() <= output_scale,
+                    "op_code {op_code}: {expected} != {actual}"
+                );
+            }
+        }
+    }
+}
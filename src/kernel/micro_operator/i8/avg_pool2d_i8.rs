@@ -29,6 +29,8 @@ impl OpAvgPool2DInt8 {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
         }
     }
 
@@ -57,8 +59,9 @@ impl OpAvgPool2DInt8 {
         let output_h = tensors[output_idx]._t()?.borrow().dims[1];
         let output_w = tensors[output_idx]._t()?.borrow().dims[2];
         let (output_scale, output_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[output_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
@@ -2,19 +2,25 @@ use num_traits::{AsPrimitive, FromPrimitive};
 
 use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
 use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
-use crate::kernel::utils::calc_per_channel_multiplier_shift;
-use crate::kernel::utils::padding::compute_padding_height_width;
+use crate::kernel::utils::gemm::{
+    alloc_im2col_buffer, gemm_quantized, im2col, is_pointwise, Im2ColGeometry, IM2COL_TILE_ROWS,
+};
+use crate::kernel::utils::int4::{FilterData, FilterRef};
+use crate::kernel::utils::padding::{compute_padding_height_width, for_each_output_rows};
 use crate::kernel::utils::quantization::multiply_by_quantized_multiplier;
+use crate::kernel::utils::{calc_effective_bias, calc_per_channel_multiplier_shift};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
-use crate::micro_context::BLiteContext;
+use crate::micro_context::{BLiteContext, ParallelElem};
 use crate::micro_errors::BLiteError::{self, *};
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
 use crate::micro_registration::BLiteRegistration;
 use crate::micro_slice::alloc_array_mut;
+use crate::micro_sparsity::BLiteSparsity;
 use crate::micro_tensor::BLiteTensor;
 use crate::tflite_schema_generated::tflite::Operator;
+use core::cell::Cell;
 use core::cmp::{max, min};
 use core::fmt::Debug;
 
@@ -26,10 +32,13 @@ pub struct OpConv2DInt8 {}
 impl OpConv2DInt8 {
     const OPCODE: i32 = 3;
 
-    pub fn conv2d_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+    pub fn conv2d_int8<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 7,
         }
     }
 
@@ -54,8 +63,9 @@ impl OpConv2DInt8 {
         let input_h = tensors[input_idx]._t()?.borrow().dims[1];
         let input_w = tensors[input_idx]._t()?.borrow().dims[2];
         let (input_scale, input_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[input_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
@@ -63,24 +73,34 @@ impl OpConv2DInt8 {
         };
 
         let filter_idx = op.inputs().unwrap().get(1) as usize;
-        let filter_h = tensors[filter_idx]._t()?.borrow().dims[1];
-        let filter_w = tensors[filter_idx]._t()?.borrow().dims[2];
+        // the filter may be packed int4 values
+        let filter_h = tensors[filter_idx].dims()[1];
+        let filter_w = tensors[filter_idx].dims()[2];
         let (filter_scales, filter_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[filter_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[filter_idx].quant_params()
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
             (scale, zero_point[0] as i32)
         };
+        // the sparse weights are kept compressed and evaluated by the sparse kernel,
+        // whose skipped values have to be the zero point
+        let filter_sparsity = match &tensors[filter_idx] {
+            BLiteTensor::I4Tensor(_) => None,
+            _ if filter_zero_point != 0 => None,
+            filter => filter._t()?.borrow_mut().sparsity.take().copied(),
+        };
 
         let output_idx = op.outputs().unwrap().get(0) as usize;
         let output_h = tensors[output_idx]._t()?.borrow().dims[1];
         let output_w = tensors[output_idx]._t()?.borrow().dims[2];
         let output_ch = tensors[output_idx]._t()?.borrow().dims[3];
         let (output_scale, output_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[output_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
@@ -117,6 +137,48 @@ impl OpConv2DInt8 {
             per_channel_shift,
         )?;
 
+        // the GEMM kernel unfolds the input by im2col except for the 1x1 convolutions,
+        // and the grouped, sparse or packed int4 filters are evaluated by the reference kernels
+        let input_depth = tensors[input_idx]._t()?.borrow().dims[3];
+        let filter_depth = tensors[filter_idx].dims()[3];
+        let use_gemm = filter_sparsity.is_none()
+            && !matches!(tensors[filter_idx], BLiteTensor::I4Tensor(_))
+            && input_depth == filter_depth;
+        let im2col_buffer = if use_gemm && !is_pointwise(filter_h, filter_w, stride_w, stride_h) {
+            alloc_im2col_buffer(
+                allocator,
+                (output_h * output_w) as usize,
+                (filter_h * filter_w * filter_depth) as usize,
+            )?
+        } else {
+            &[]
+        };
+
+        // the effective bias is empty if the reference kernels are used
+        let effective_bias: &[i32] = if use_gemm {
+            let effective_bias = unsafe { alloc_array_mut(allocator, output_ch as usize) }?;
+            let bias_idx = op.inputs().unwrap().get(2);
+            let bias = if bias_idx >= 0 {
+                Some(tensors[bias_idx as usize]._i32()?.borrow())
+            } else {
+                None
+            };
+            let depth = (filter_h * filter_w * filter_depth) as usize;
+            calc_effective_bias(
+                FilterRef::borrow(&tensors[filter_idx])?.data(),
+                bias.as_ref().map(|bias| &bias.data[..]),
+                -input_zero_point,
+                -filter_zero_point,
+                depth,
+                depth,
+                1,
+                effective_bias,
+            );
+            effective_bias
+        } else {
+            &[]
+        };
+
         Ok(BLiteBuiltinOption::QuantizedConv2DOptions {
             op_code,
             fused_activation_min,
@@ -136,15 +198,18 @@ impl OpConv2DInt8 {
             output_offset: output_zero_point,
             per_channel_multiplier,
             per_channel_shift,
+            filter_sparsity,
+            im2col_buffer,
+            effective_bias,
         })
     }
 
-    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
         BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
     }
 
-    pub fn eval<'a, T: ArrayElem<T>>(
-        _context: &BLiteContext,
+    pub fn eval<'a, T: ArrayElem<T> + ParallelElem>(
+        context: &BLiteContext,
         tensors: &'a mut [BLiteTensor<'a, T>],
         node: &BLiteNode<'a>,
         builtin_option: BLiteBuiltinOption<T>,
@@ -156,10 +221,10 @@ impl OpConv2DInt8 {
         let input_depth = input.dims[3];
 
         let idx_filter = node.inputs[1] as usize;
-        let filter = tensors[idx_filter]._t()?.borrow();
-        let filter_height = filter.dims[1];
-        let filter_width = filter.dims[2];
-        let filter_depth = filter.dims[3];
+        let filter = FilterRef::borrow(&tensors[idx_filter])?;
+        let filter_height = filter.dims()[1];
+        let filter_width = filter.dims()[2];
+        let filter_depth = filter.dims()[3];
 
         // Why does a bias exist depsite of setting use_bias=False
         let idx_bias = node.inputs[2] as usize;
@@ -171,8 +236,6 @@ impl OpConv2DInt8 {
         let output_width = output.dims[2];
         let output_depth = output.dims[3];
 
-        let batches = input.dims[0] as usize; // TODO: min(input.dims[0], output.dims[0])
-
         // TODO: This setting is needed for grouped convolutions
         let groups = input_depth / filter_depth;
         let filters_per_group = output_depth / groups;
@@ -196,48 +259,145 @@ impl OpConv2DInt8 {
             output_offset,
             per_channel_multiplier,
             per_channel_shift,
+            filter_sparsity,
+            im2col_buffer,
+            effective_bias,
         } = builtin_option
         else {
             return Err(NotCompatibleOption);
         };
 
-        Self::kernel(
-            input.data,
-            filter.data,
-            bias.data,
+        let input_data = &*input.data;
+        let filter_data = filter.data();
+        let bias_data = &*bias.data;
+        let use_gemm = !effective_bias.is_empty();
+
+        // the output rows are split across the worker pool, and each part is evaluated as a single batch
+        let output_row_len = (output_width * output_depth) as usize;
+        context.parallel_for(
             output.data,
-            input_height,
-            input_width,
-            input_depth,
-            filter_height,
-            filter_width,
-            filter_depth,
-            output_height,
-            output_width,
-            output_depth,
-            stride_w,
-            stride_h,
-            dilation_w_factor,
-            dilation_h_factor,
-            padding_w,
-            padding_h,
-            filters_per_group,
-            // for quantization
-            input_offset,
-            filter_offset,
-            output_offset,
-            per_channel_multiplier,
-            per_channel_shift,
-            batches,
-            fused_activation_min,
-            fused_activation_max,
+            output_row_len,
+            im2col_buffer,
+            |units, output_chunk, im2col_buffer| {
+                for_each_output_rows(
+                    input_data,
+                    output_chunk,
+                    units,
+                    input_height,
+                    (input_width * input_depth) as usize,
+                    output_height,
+                    output_row_len,
+                    stride_h,
+                    padding_h,
+                    |input_data, output_data, rows| {
+                        if let Some(sparsity) = &filter_sparsity {
+                            return Self::sparse_kernel(
+                                input_data,
+                                filter_data,
+                                sparsity,
+                                bias_data,
+                                output_data,
+                                rows.input_height,
+                                input_width,
+                                input_depth,
+                                filter_height,
+                                filter_width,
+                                filter_depth,
+                                rows.output_height,
+                                output_width,
+                                output_depth,
+                                stride_w,
+                                stride_h,
+                                dilation_w_factor,
+                                dilation_h_factor,
+                                padding_w,
+                                rows.padding_h,
+                                filters_per_group,
+                                // for quantization
+                                input_offset,
+                                output_offset,
+                                per_channel_multiplier,
+                                per_channel_shift,
+                                1,
+                                fused_activation_min,
+                                fused_activation_max,
+                            );
+                        }
+
+                        if let (true, FilterData::Dense(filter_data)) = (use_gemm, filter_data) {
+                            return Self::gemm_kernel(
+                                input_data,
+                                filter_data,
+                                effective_bias,
+                                output_data,
+                                im2col_buffer,
+                                rows.input_height,
+                                input_width,
+                                input_depth,
+                                filter_height,
+                                filter_width,
+                                rows.output_height,
+                                output_width,
+                                output_depth,
+                                stride_w,
+                                stride_h,
+                                dilation_w_factor,
+                                dilation_h_factor,
+                                padding_w,
+                                rows.padding_h,
+                                // for quantization
+                                input_offset,
+                                filter_offset,
+                                output_offset,
+                                per_channel_multiplier,
+                                per_channel_shift,
+                                1,
+                                fused_activation_min,
+                                fused_activation_max,
+                            );
+                        }
+
+                        Self::kernel(
+                            input_data,
+                            filter_data,
+                            bias_data,
+                            output_data,
+                            rows.input_height,
+                            input_width,
+                            input_depth,
+                            filter_height,
+                            filter_width,
+                            filter_depth,
+                            rows.output_height,
+                            output_width,
+                            output_depth,
+                            stride_w,
+                            stride_h,
+                            dilation_w_factor,
+                            dilation_h_factor,
+                            padding_w,
+                            rows.padding_h,
+                            filters_per_group,
+                            // for quantization
+                            input_offset,
+                            filter_offset,
+                            output_offset,
+                            per_channel_multiplier,
+                            per_channel_shift,
+                            1,
+                            fused_activation_min,
+                            fused_activation_max,
+                        )
+                    },
+                )
+            },
         )
     }
 
     #[inline(always)]
     pub fn kernel<T: ArrayElem<T>>(
         input_data: &[T],
-        filter_data: &[T],
+        filter_data: FilterData<T>,
         bias_data: &[i32],
         output_data: &mut [T],
         //
@@ -260,7 +420,7 @@ impl OpConv2DInt8 {
         filters_per_group: i32,
         // for quantization
         input_offset: i32,
-        _filter_offset: i32,
+        filter_offset: i32,
         output_offset: i32,
         per_channel_multiplier: &[i32],
         per_channel_shift: &[i32],
@@ -310,10 +470,239 @@ impl OpConv2DInt8 {
                                         filter_x,
                                         in_channel,
                                     );
-                                    let filter_v =
-                                        AsPrimitive::<i32>::as_(filter_data[filter_v_idx as usize]);
-                                    // All filter_offset is 0 by the implementation of tensorflow lite
-                                    total += filter_v * (input_v + input_offset);
+                                    let filter_v = filter_data.get(filter_v_idx as usize);
+                                    // filter_offset is 0 for int8 filters, but not for the legacy uint8 filters
+                                    total += (filter_v + filter_offset) * (input_v + input_offset);
+                                }
+                            }
+                        }
+                        let bias_v = bias_data[out_channel as usize];
+
+                        total += bias_v;
+
+                        total = multiply_by_quantized_multiplier(
+                            total,
+                            per_channel_multiplier[out_channel as usize],
+                            per_channel_shift[out_channel as usize],
+                        )?;
+
+                        total += output_offset;
+                        total = max(total, fused_activation_min);
+                        total = min(total, fused_activation_max);
+                        let output_v_idx = Self::offset(
+                            output_height,
+                            output_width,
+                            output_depth,
+                            batch as i32,
+                            out_y,
+                            out_x,
+                            out_channel,
+                        );
+
+                        output_data[output_v_idx as usize] =
+                            FromPrimitive::from_i32(total).unwrap();
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// This computations is corresponded to the im2col and the GEMM of the optimized kernels,
+    /// whose results are the same as the reference kernel.
+    /// The 1x1 convolutions read the input as the rows of the GEMM without im2col.
+    #[inline(always)]
+    pub fn gemm_kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: &[T],
+        effective_bias: &[i32],
+        output_data: &mut [T],
+        im2col_buffer: &[Cell<T>],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        output_height: i32,
+        output_width: i32,
+        output_depth: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        padding_w: i32,
+        padding_h: i32,
+        // for quantization
+        input_offset: i32,
+        filter_offset: i32,
+        output_offset: i32,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
+        //
+        batches: usize,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        let pixels = (output_height * output_width) as usize;
+        let output_depth = output_depth as usize;
+        let depth = (filter_height * filter_width * input_depth) as usize;
+        let mut store = |first_pixel: usize, row: usize, out_channel: usize, acc: i32| {
+            // the input offset is folded into the effective bias
+            let mut total = acc + effective_bias[out_channel];
+            total = multiply_by_quantized_multiplier(
+                total,
+                per_channel_multiplier[out_channel],
+                per_channel_shift[out_channel],
+            )?;
+            total += output_offset;
+            total = max(total, fused_activation_min);
+            total = min(total, fused_activation_max);
+            output_data[(first_pixel + row) * output_depth + out_channel] =
+                FromPrimitive::from_i32(total).unwrap();
+            Ok(())
+        };
+
+        if is_pointwise(filter_height, filter_width, stride_w, stride_h) {
+            return gemm_quantized(
+                input_data,
+                filter_data,
+                filter_offset,
+                batches * pixels,
+                output_depth,
+                depth,
+                |row, out_channel, acc| store(0, row, out_channel, acc),
+            );
+        }
+
+        // the padded points are the zero point, which cancel the folded input offset
+        let pad_value: T = FromPrimitive::from_i32(-input_offset).ok_or(InCompatibleCasting)?;
+        let tile_rows = IM2COL_TILE_ROWS.min(im2col_buffer.len() / depth);
+        let geometry = Im2ColGeometry {
+            input_height,
+            input_width,
+            input_depth,
+            filter_height,
+            filter_width,
+            output_width,
+            stride_w,
+            stride_h,
+            dilation_w_factor,
+            dilation_h_factor,
+            padding_w,
+            padding_h,
+        };
+        for batch in 0..batches {
+            let mut first_pixel = 0;
+            while first_pixel < pixels {
+                let rows = tile_rows.min(pixels - first_pixel);
+                im2col(
+                    input_data,
+                    im2col_buffer,
+                    &geometry,
+                    batch,
+                    first_pixel,
+                    rows,
+                    pad_value,
+                );
+                gemm_quantized(
+                    im2col_buffer,
+                    filter_data,
+                    filter_offset,
+                    rows,
+                    output_depth,
+                    depth,
+                    |row, out_channel, acc| {
+                        store(batch * pixels + first_pixel, row, out_channel, acc)
+                    },
+                )?;
+                first_pixel += rows;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The filter holds only the nonzero blocks along the input channels of each
+    /// (out_channel, filter_y, filter_x), and the zero blocks are skipped
+    #[inline(always)]
+    pub fn sparse_kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: FilterData<T>,
+        filter_sparsity: &BLiteSparsity,
+        bias_data: &[i32],
+        output_data: &mut [T],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        filter_depth: i32,
+        output_height: i32,
+        output_width: i32,
+        output_depth: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        padding_w: i32,
+        padding_h: i32,
+        filters_per_group: i32,
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
+        //
+        batches: usize,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        let block_size = filter_sparsity.block_size;
+        for batch in 0..batches {
+            for out_y in 0..output_height {
+                let in_y_origin = (out_y * stride_h) - padding_h;
+                for out_x in 0..output_width {
+                    let in_x_origin = (out_x * stride_w) - padding_w;
+                    for out_channel in 0..output_depth {
+                        let group = out_channel / filters_per_group;
+                        let mut total = 0;
+                        for filter_y in 0..filter_height {
+                            let in_y = in_y_origin + dilation_h_factor * filter_y;
+                            for filter_x in 0..filter_width {
+                                let in_x = in_x_origin + dilation_w_factor * filter_x;
+                                let is_point_inside_image = (in_x >= 0)
+                                    && (in_x < input_width)
+                                    && (in_y >= 0)
+                                    && (in_y < input_height);
+                                if !is_point_inside_image {
+                                    continue;
+                                }
+
+                                let input_v_idx = Self::offset(
+                                    input_height,
+                                    input_width,
+                                    input_depth,
+                                    batch as i32,
+                                    in_y,
+                                    in_x,
+                                    group * filter_depth,
+                                ) as usize;
+                                let row = (out_channel * filter_height + filter_y) * filter_width
+                                    + filter_x;
+                                for (in_channel, offset) in filter_sparsity.row_blocks(row as usize)
+                                {
+                                    for i in 0..block_size {
+                                        let input_v = AsPrimitive::<i32>::as_(
+                                            input_data[input_v_idx + in_channel + i],
+                                        );
+                                        let filter_v = filter_data.get(offset + i);
+                                        total += filter_v * (input_v + input_offset);
+                                    }
                                 }
                             }
                         }
@@ -355,3 +744,183 @@ impl OpConv2DInt8 {
         ((i0 * h + i1) * w + i2) * d + i3
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OpConv2DInt8;
+    use crate::kernel::utils::calc_effective_bias;
+    use crate::kernel::utils::gemm::IM2COL_TILE_ROWS;
+    use crate::kernel::utils::int4::FilterData;
+    use crate::kernel::utils::quantization::quantize_multiplier;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_gemm_kernel_matches_reference() {
+        const BATCHES: usize = 2;
+        const INPUT_H: i32 = 5;
+        const INPUT_W: i32 = 6;
+        const INPUT_D: i32 = 3;
+        const OUTPUT_D: i32 = 6;
+        let mut seed = 7u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) as i8
+        };
+        let input: Vec<i8> = (0..BATCHES * (INPUT_H * INPUT_W * INPUT_D) as usize)
+            .map(|_| next())
+            .collect();
+        let filter: Vec<i8> = (0..(OUTPUT_D * 3 * 3 * INPUT_D) as usize)
+            .map(|_| next())
+            .collect();
+        let bias: Vec<i32> = (0..OUTPUT_D).map(|d| d * 300 - 700).collect();
+        let (multiplier, shift) = quantize_multiplier(0.0004).unwrap();
+        let per_channel_multiplier = [multiplier; OUTPUT_D as usize];
+        let per_channel_shift = [shift; OUTPUT_D as usize];
+
+        // (filter size, stride, dilation, padding)
+        for (filter_size, stride, dilation, padding) in [
+            (3, 1, 1, 1),
+            (3, 2, 1, 0),
+            (3, 1, 2, 2),
+            (1, 1, 1, 0),
+            (1, 2, 1, 0),
+        ] {
+            let output_h = (INPUT_H + 2 * padding - dilation * (filter_size - 1) - 1) / stride + 1;
+            let output_w = (INPUT_W + 2 * padding - dilation * (filter_size - 1) - 1) / stride + 1;
+            let output_size = BATCHES * (output_h * output_w * OUTPUT_D) as usize;
+            let filter = &filter[..(OUTPUT_D * filter_size * filter_size * INPUT_D) as usize];
+
+            let mut expected = vec![0i8; output_size];
+            OpConv2DInt8::kernel(
+                &input,
+                FilterData::Dense(filter),
+                &bias,
+                &mut expected,
+                INPUT_H,
+                INPUT_W,
+                INPUT_D,
+                filter_size,
+                filter_size,
+                INPUT_D,
+                output_h,
+                output_w,
+                OUTPUT_D,
+                stride,
+                stride,
+                dilation,
+                dilation,
+                padding,
+                padding,
+                OUTPUT_D,
+                5,
+                -3,
+                -4,
+                &per_channel_multiplier,
+                &per_channel_shift,
+                BATCHES,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+
+            let depth = (filter_size * filter_size * INPUT_D) as usize;
+            let mut im2col_buffer = vec![0i8; IM2COL_TILE_ROWS * depth];
+            let im2col_buffer = Cell::from_mut(&mut im2col_buffer[..]).as_slice_of_cells();
+            let mut effective_bias = [0i32; OUTPUT_D as usize];
+            calc_effective_bias(
+                FilterData::Dense(filter),
+                Some(&bias),
+                5,
+                -3,
+                depth,
+                depth,
+                1,
+                &mut effective_bias,
+            );
+            let mut output = vec![0i8; output_size];
+            OpConv2DInt8::gemm_kernel(
+                &input,
+                filter,
+                &effective_bias,
+                &mut output,
+                im2col_buffer,
+                INPUT_H,
+                INPUT_W,
+                INPUT_D,
+                filter_size,
+                filter_size,
+                output_h,
+                output_w,
+                OUTPUT_D,
+                stride,
+                stride,
+                dilation,
+                dilation,
+                padding,
+                padding,
+                5,
+                -3,
+                -4,
+                &per_channel_multiplier,
+                &per_channel_shift,
+                BATCHES,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+            assert_eq!(expected, output);
+        }
+    }
+
+    #[test]
+    fn test_conv2d_int8_packed_int4() {
+        // input 1x4x4x2, filter 3x3x3x2 with the padding 1
+        let input: [i8; 32] = core::array::from_fn(|i| (i as i32 * 37 % 255 - 127) as i8);
+        let filter: [i8; 54] = core::array::from_fn(|i| (i as i32 * 5 % 16 - 8) as i8);
+        // two values per byte with the low nibble first
+        let packed: [u8; 27] = core::array::from_fn(|i| {
+            (filter[2 * i] as u8 & 0x0f) | ((filter[2 * i + 1] as u8) << 4)
+        });
+        let bias = [512i32, -800, 40];
+        let (multiplier, shift) = quantize_multiplier(0.004).unwrap();
+
+        let mut outputs = [[0i8; 48]; 2];
+        for (output, filter_data) in outputs
+            .iter_mut()
+            .zip([FilterData::Dense(&filter), FilterData::PackedInt4(&packed)])
+        {
+            OpConv2DInt8::kernel(
+                &input,
+                filter_data,
+                &bias,
+                output,
+                4,
+                4,
+                2,
+                3,
+                3,
+                2,
+                4,
+                4,
+                3,
+                1,
+                1,
+                1,
+                1,
+                1,
+                1,
+                3,
+                5,
+                0,
+                -4,
+                &[multiplier; 3],
+                &[shift; 3],
+                1,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+        }
+        assert_eq!(outputs[0], outputs[1]);
+    }
+}
@@ -2,12 +2,15 @@ use num_traits::{AsPrimitive, FromPrimitive};
 
 use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
 use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
-use crate::kernel::utils::calc_per_channel_multiplier_shift;
-use crate::kernel::utils::padding::compute_padding_height_width;
+use crate::kernel::utils::int4::{FilterData, FilterRef};
+use crate::kernel::utils::padding::{compute_padding_height_width, for_each_output_rows};
 use crate::kernel::utils::quantization::multiply_by_quantized_multiplier;
+#[cfg(feature = "simd")]
+use crate::kernel::utils::simd::{cast_slice, cast_slice_mut, mac_i8, CHANNEL_BLOCK};
+use crate::kernel::utils::{calc_effective_bias, calc_per_channel_multiplier_shift};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
-use crate::micro_context::BLiteContext;
+use crate::micro_context::{BLiteContext, ParallelElem};
 use crate::micro_errors::BLiteError::{self, *};
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
@@ -17,6 +20,7 @@ use crate::micro_tensor::BLiteTensor;
 use crate::tflite_schema_generated::tflite::Operator;
 use core::cmp::{max, min};
 use core::fmt::Debug;
+use core::ops::Range;
 
 use crate::kernel::micro_operator::BLiteOperator;
 
@@ -26,11 +30,13 @@ pub struct OpDepthWiseConv2DInt8 {}
 impl OpDepthWiseConv2DInt8 {
     const OPCODE: i32 = 4;
 
-    pub fn depthwise_conv2d_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S>
-    {
+    pub fn depthwise_conv2d_int8<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 7,
         }
     }
 
@@ -55,8 +61,9 @@ impl OpDepthWiseConv2DInt8 {
         let input_h = tensors[input_idx]._t()?.borrow().dims[1];
         let input_w = tensors[input_idx]._t()?.borrow().dims[2];
         let (input_scale, input_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[input_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
@@ -64,11 +71,13 @@ impl OpDepthWiseConv2DInt8 {
         };
 
         let filter_idx = op.inputs().unwrap().get(1) as usize;
-        let filter_h = tensors[filter_idx]._t()?.borrow().dims[1];
-        let filter_w = tensors[filter_idx]._t()?.borrow().dims[2];
+        // the filter may be packed int4 values
+        let filter_h = tensors[filter_idx].dims()[1];
+        let filter_w = tensors[filter_idx].dims()[2];
         let (filter_scales, filter_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[filter_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[filter_idx].quant_params()
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
@@ -80,8 +89,9 @@ impl OpDepthWiseConv2DInt8 {
         let output_w = tensors[output_idx]._t()?.borrow().dims[2];
         let output_ch = tensors[output_idx]._t()?.borrow().dims[3];
         let (output_scale, output_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[output_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
@@ -117,6 +127,38 @@ impl OpDepthWiseConv2DInt8 {
             per_channel_multiplier,
             per_channel_shift,
         )?;
+
+        // the sparse filter is expanded before its sums are folded into the bias
+        unsafe { tensors[filter_idx].densify(allocator) }?;
+        let effective_bias = unsafe { alloc_array_mut(allocator, output_ch as usize) }?;
+        let bias_idx = op.inputs().unwrap().get(2);
+        let bias = if bias_idx >= 0 {
+            Some(tensors[bias_idx as usize]._i32()?.borrow())
+        } else {
+            None
+        };
+        // the filter is [1, filter_h, filter_w, output_ch]
+        calc_effective_bias(
+            FilterRef::borrow(&tensors[filter_idx])?.data(),
+            bias.as_ref().map(|bias| &bias.data[..]),
+            -input_zero_point,
+            -filter_zero_point,
+            (filter_h * filter_w) as usize,
+            1,
+            output_ch as usize,
+            effective_bias,
+        );
+
+        // nearly all the depthwise layers of MobileNet are 3x3 with the stride 1 or 2
+        let is_3x3_kernel = filter_h == 3
+            && filter_w == 3
+            && stride_w == stride_h
+            && (stride_w == 1 || stride_w == 2)
+            && dilation_w_factor == 1
+            && dilation_h_factor == 1
+            && depth_multiplier == 1
+            && !matches!(tensors[filter_idx], BLiteTensor::I4Tensor(_));
+
         Ok(BLiteBuiltinOption::QuantizedDepthWiseConv2DOptions {
             op_code,
             fused_activation_min,
@@ -136,15 +178,17 @@ impl OpDepthWiseConv2DInt8 {
             output_offset: output_zero_point,
             per_channel_multiplier,
             per_channel_shift,
+            effective_bias,
+            is_3x3_kernel,
         })
     }
 
-    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
         BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
     }
 
-    pub fn eval<'a, T: ArrayElem<T>>(
-        _context: &BLiteContext,
+    pub fn eval<'a, T: ArrayElem<T> + ParallelElem>(
+        context: &BLiteContext,
         tensors: &'a mut [BLiteTensor<'a, T>],
         node: &BLiteNode<'a>,
         builtin_option: BLiteBuiltinOption<T>,
@@ -156,13 +200,10 @@ impl OpDepthWiseConv2DInt8 {
         let input_depth = input.dims[3];
 
         let idx_filter = node.inputs[1] as usize;
-        let filter = tensors[idx_filter]._t()?.borrow();
-        let filter_height = filter.dims[1];
-        let filter_width = filter.dims[2];
-        let filter_depth = filter.dims[3];
-
-        let idx_bias = node.inputs[2] as usize;
-        let bias = tensors[idx_bias]._i32()?.borrow();
+        let filter = FilterRef::borrow(&tensors[idx_filter])?;
+        let filter_height = filter.dims()[1];
+        let filter_width = filter.dims()[2];
+        let filter_depth = filter.dims()[3];
 
         let idx_output = node.outputs[0] as usize;
         let mut output = tensors[idx_output]._t()?.borrow_mut();
@@ -170,9 +211,6 @@ impl OpDepthWiseConv2DInt8 {
         let output_width = output.dims[2];
         let output_depth = output.dims[3];
 
-        // TODO: What is this?
-        let batches = input.dims[0] as usize; // TODO: min(input.dims[0], output.dims[0])
-
         let QuantizedDepthWiseConv2DOptions {
             op_code: _,
             fused_activation_min,
@@ -193,47 +231,140 @@ impl OpDepthWiseConv2DInt8 {
             output_offset,
             per_channel_multiplier,
             per_channel_shift,
+            effective_bias,
+            is_3x3_kernel,
         } = builtin_option
         else {
             return Err(NotCompatibleOption);
         };
-        Self::kernel(
-            input.data,
-            filter.data,
-            bias.data,
+        let input_data = &*input.data;
+        let filter_data = filter.data();
+
+        // the output rows are split across the worker pool, and each part is evaluated as a single batch
+        let output_row_len = (output_width * output_depth) as usize;
+        context.parallel_for(
             output.data,
-            input_height,
-            input_width,
-            input_depth,
-            filter_height,
-            filter_width,
-            filter_depth,
-            output_height,
-            output_width,
-            output_depth,
-            stride_w,
-            stride_h,
-            dilation_w_factor,
-            dilation_h_factor,
-            padding_w,
-            padding_h,
-            depth_multiplier,
-            input_offset,
-            filter_offset,
-            output_offset,
-            per_channel_multiplier,
-            per_channel_shift,
-            batches,
-            fused_activation_min,
-            fused_activation_max,
+            output_row_len,
+            &[],
+            |units, output_chunk, _| {
+                for_each_output_rows(
+                    input_data,
+                    output_chunk,
+                    units,
+                    input_height,
+                    (input_width * input_depth) as usize,
+                    output_height,
+                    output_row_len,
+                    stride_h,
+                    padding_h,
+                    |input_data, output_data, rows| {
+                        #[cfg(feature = "simd")]
+                        if let (
+                            Some(input_data),
+                            FilterData::Dense(filter_data),
+                            Some(output_data),
+                            1,
+                            0,
+                        ) = (
+                            cast_slice::<T, i8>(input_data),
+                            filter_data,
+                            cast_slice_mut::<T, i8>(output_data),
+                            depth_multiplier,
+                            filter_offset,
+                        ) {
+                            return Self::simd_kernel(
+                                input_data,
+                                cast_slice(filter_data).unwrap(),
+                                effective_bias,
+                                output_data,
+                                rows.input_height,
+                                input_width,
+                                input_depth,
+                                filter_height,
+                                filter_width,
+                                rows.output_height,
+                                output_width,
+                                stride_w,
+                                stride_h,
+                                dilation_w_factor,
+                                dilation_h_factor,
+                                padding_w,
+                                rows.padding_h,
+                                input_offset,
+                                output_offset,
+                                per_channel_multiplier,
+                                per_channel_shift,
+                                1,
+                                fused_activation_min,
+                                fused_activation_max,
+                            );
+                        }
+                        if let (true, FilterData::Dense(filter_data)) = (is_3x3_kernel, filter_data)
+                        {
+                            return Self::kernel_3x3(
+                                input_data,
+                                filter_data,
+                                effective_bias,
+                                output_data,
+                                rows.input_height,
+                                input_width,
+                                input_depth,
+                                rows.output_height,
+                                output_width,
+                                stride_w,
+                                padding_w,
+                                rows.padding_h,
+                                input_offset,
+                                filter_offset,
+                                output_offset,
+                                per_channel_multiplier,
+                                per_channel_shift,
+                                1,
+                                fused_activation_min,
+                                fused_activation_max,
+                            );
+                        }
+                        Self::kernel(
+                            input_data,
+                            filter_data,
+                            effective_bias,
+                            output_data,
+                            rows.input_height,
+                            input_width,
+                            input_depth,
+                            filter_height,
+                            filter_width,
+                            filter_depth,
+                            rows.output_height,
+                            output_width,
+                            output_depth,
+                            stride_w,
+                            stride_h,
+                            dilation_w_factor,
+                            dilation_h_factor,
+                            padding_w,
+                            rows.padding_h,
+                            depth_multiplier,
+                            input_offset,
+                            filter_offset,
+                            output_offset,
+                            per_channel_multiplier,
+                            per_channel_shift,
+                            1,
+                            fused_activation_min,
+                            fused_activation_max,
+                        )
+                    },
+                )
+            },
         )
     }
 
     #[inline(always)]
     pub fn kernel<T: ArrayElem<T>>(
         input_data: &[T],
-        filter_data: &[T],
-        bias_data: &[i32],
+        filter_data: FilterData<T>,
+        effective_bias: &[i32],
         output_data: &mut [T],
         //
         input_height: i32,
@@ -255,7 +386,7 @@ impl OpDepthWiseConv2DInt8 {
         depth_multiplier: i32,
         // for quantization
         input_offset: i32,
-        _filter_offset: i32,
+        filter_offset: i32,
         output_offset: i32,
         per_channel_multiplier: &[i32],
         per_channel_shift: &[i32],
@@ -266,52 +397,63 @@ impl OpDepthWiseConv2DInt8 {
     ) -> Result<()> {
         for batch in 0..batches {
             for out_y in 0..output_height {
+                let in_y_origin = (out_y * stride_h) - padding_h;
+                let taps_y =
+                    Self::valid_taps(in_y_origin, dilation_h_factor, filter_height, input_height);
                 for out_x in 0..output_width {
+                    let in_x_origin = (out_x * stride_w) - padding_w;
+                    let taps_x =
+                        Self::valid_taps(in_x_origin, dilation_w_factor, filter_width, input_width);
+                    let is_border =
+                        taps_y.len() as i32 != filter_height || taps_x.len() as i32 != filter_width;
                     for in_channel in 0..input_depth {
                         for m in 0..depth_multiplier {
                             let out_channel = m + in_channel * depth_multiplier;
-                            let in_x_origin = (out_x * stride_w) - padding_w;
-                            let in_y_origin = (out_y * stride_h) - padding_h;
-                            let mut total = 0;
-                            for filter_y in 0..filter_height {
-                                for filter_x in 0..filter_width {
+                            let filter_v = |filter_y: i32, filter_x: i32| -> i32 {
+                                let filter_v_idx = Self::offset(
+                                    filter_height,
+                                    filter_width,
+                                    filter_depth,
+                                    0,
+                                    filter_y,
+                                    filter_x,
+                                    out_channel,
+                                );
+                                filter_data.get(filter_v_idx as usize) + filter_offset
+                            };
+                            // the input offset is folded into the effective bias,
+                            // so that only the taps inside of the image are visited
+                            let mut total = effective_bias[out_channel as usize];
+                            for filter_y in taps_y.clone() {
+                                let in_y = in_y_origin + dilation_h_factor * filter_y;
+                                for filter_x in taps_x.clone() {
                                     let in_x = in_x_origin + dilation_w_factor * filter_x;
-                                    let in_y = in_y_origin + dilation_h_factor * filter_y;
-                                    let is_point_inside_image = (in_x >= 0)
-                                        && (in_x < input_width)
-                                        && (in_y >= 0)
-                                        && (in_y < input_height);
-                                    if is_point_inside_image {
-                                        let input_v_idx = Self::offset(
-                                            input_height,
-                                            input_width,
-                                            input_depth,
-                                            batch as i32,
-                                            in_y,
-                                            in_x,
-                                            in_channel,
-                                        );
-                                        let input_v = AsPrimitive::<i32>::as_(
-                                            input_data[input_v_idx as usize],
-                                        );
-                                        let filter_v_idx = Self::offset(
-                                            filter_height,
-                                            filter_width,
-                                            filter_depth,
-                                            0,
-                                            filter_y,
-                                            filter_x,
-                                            out_channel,
-                                        );
-                                        let filter_v = AsPrimitive::<i32>::as_(
-                                            filter_data[filter_v_idx as usize],
-                                        );
-                                        total += filter_v * (input_v + input_offset);
-                                    }
+                                    let input_v_idx = Self::offset(
+                                        input_height,
+                                        input_width,
+                                        input_depth,
+                                        batch as i32,
+                                        in_y,
+                                        in_x,
+                                        in_channel,
+                                    );
+                                    let input_v =
+                                        AsPrimitive::<i32>::as_(input_data[input_v_idx as usize]);
+                                    total += filter_v(filter_y, filter_x) * input_v;
                                 }
                             }
-                            let bias_v = bias_data[out_channel as usize];
-                            total += bias_v;
+                            if is_border {
+                                // the skipped taps are padded with the zero point,
+                                // whose input offset has to be taken out of the folded bias
+                                total -= input_offset
+                                    * Self::skipped_filter_sum(
+                                        &taps_y,
+                                        &taps_x,
+                                        filter_height,
+                                        filter_width,
+                                        filter_v,
+                                    );
+                            }
                             total = multiply_by_quantized_multiplier(
                                 total,
                                 per_channel_multiplier[out_channel as usize],
@@ -341,8 +483,652 @@ impl OpDepthWiseConv2DInt8 {
         Ok(())
     }
 
+    /// The 3x3 filter with the stride 1 or 2 and the depth multiplier 1.
+    /// The output pixels whose receptive fields are inside of the image unroll the 9 taps without the bounds checks,
+    /// and the others on the border skip the outside points and correct the folded bias.
+    #[inline(always)]
+    pub fn kernel_3x3<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: &[T],
+        effective_bias: &[i32],
+        output_data: &mut [T],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        output_height: i32,
+        output_width: i32,
+        //
+        stride: i32,
+        padding_w: i32,
+        padding_h: i32,
+        // for quantization
+        input_offset: i32,
+        filter_offset: i32,
+        output_offset: i32,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
+        //
+        batches: usize,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        let depth = input_depth as usize;
+        let filter_v = |tap: usize, channel: usize| -> i32 {
+            AsPrimitive::<i32>::as_(filter_data[tap * depth + channel]) + filter_offset
+        };
+        for batch in 0..batches {
+            for out_y in 0..output_height {
+                for out_x in 0..output_width {
+                    let in_x_origin = (out_x * stride) - padding_w;
+                    let in_y_origin = (out_y * stride) - padding_h;
+                    let output_idx = Self::offset(
+                        output_height,
+                        output_width,
+                        input_depth,
+                        batch as i32,
+                        out_y,
+                        out_x,
+                        0,
+                    ) as usize;
+                    let is_interior = in_x_origin >= 0
+                        && in_x_origin + 3 <= input_width
+                        && in_y_origin >= 0
+                        && in_y_origin + 3 <= input_height;
+                    if is_interior {
+                        // the 3 rows of the receptive field, each of which holds 3 pixels
+                        let rows: [&[T]; 3] = core::array::from_fn(|filter_y| {
+                            let start = Self::offset(
+                                input_height,
+                                input_width,
+                                input_depth,
+                                batch as i32,
+                                in_y_origin + filter_y as i32,
+                                in_x_origin,
+                                0,
+                            ) as usize;
+                            &input_data[start..start + 3 * depth]
+                        });
+                        for channel in 0..depth {
+                            let input_v = |filter_y: usize, filter_x: usize| -> i32 {
+                                AsPrimitive::<i32>::as_(rows[filter_y][filter_x * depth + channel])
+                            };
+                            // the input offset is folded into the effective bias
+                            let total = effective_bias[channel]
+                                + filter_v(0, channel) * input_v(0, 0)
+                                + filter_v(1, channel) * input_v(0, 1)
+                                + filter_v(2, channel) * input_v(0, 2)
+                                + filter_v(3, channel) * input_v(1, 0)
+                                + filter_v(4, channel) * input_v(1, 1)
+                                + filter_v(5, channel) * input_v(1, 2)
+                                + filter_v(6, channel) * input_v(2, 0)
+                                + filter_v(7, channel) * input_v(2, 1)
+                                + filter_v(8, channel) * input_v(2, 2);
+                            output_data[output_idx + channel] = Self::requantize(
+                                total,
+                                per_channel_multiplier[channel],
+                                per_channel_shift[channel],
+                                output_offset,
+                                fused_activation_min,
+                                fused_activation_max,
+                            )?;
+                        }
+                        continue;
+                    }
+
+                    let taps_y = Self::valid_taps(in_y_origin, 1, 3, input_height);
+                    let taps_x = Self::valid_taps(in_x_origin, 1, 3, input_width);
+                    for channel in 0..depth {
+                        let filter_v = |filter_y: i32, filter_x: i32| {
+                            filter_v((filter_y * 3 + filter_x) as usize, channel)
+                        };
+                        let mut total = effective_bias[channel];
+                        for filter_y in taps_y.clone() {
+                            for filter_x in taps_x.clone() {
+                                let input_v_idx = Self::offset(
+                                    input_height,
+                                    input_width,
+                                    input_depth,
+                                    batch as i32,
+                                    in_y_origin + filter_y,
+                                    in_x_origin + filter_x,
+                                    channel as i32,
+                                );
+                                let input_v =
+                                    AsPrimitive::<i32>::as_(input_data[input_v_idx as usize]);
+                                total += filter_v(filter_y, filter_x) * input_v;
+                            }
+                        }
+                        // the skipped taps are padded with the zero point,
+                        // whose input offset has to be taken out of the folded bias
+                        total -= input_offset
+                            * Self::skipped_filter_sum(&taps_y, &taps_x, 3, 3, filter_v);
+                        output_data[output_idx + channel] = Self::requantize(
+                            total,
+                            per_channel_multiplier[channel],
+                            per_channel_shift[channel],
+                            output_offset,
+                            fused_activation_min,
+                            fused_activation_max,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn requantize<T: ArrayElem<T>>(
+        total: i32,
+        multiplier: i32,
+        shift: i32,
+        output_offset: i32,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<T> {
+        let mut total = multiply_by_quantized_multiplier(total, multiplier, shift)?;
+        total += output_offset;
+        total = max(total, fused_activation_min);
+        total = min(total, fused_activation_max);
+        FromPrimitive::from_i32(total).ok_or(InCompatibleCasting)
+    }
+
+    /// The channels of each output pixel are accumulated by the vectorised multiply-add,
+    /// which needs the depth multiplier of 1 and the dense int8 filter whose offset is zero
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    pub fn simd_kernel(
+        input_data: &[i8],
+        filter_data: &[i8],
+        effective_bias: &[i32],
+        output_data: &mut [i8],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        output_height: i32,
+        output_width: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        dilation_w_factor: i32,
+        dilation_h_factor: i32,
+        padding_w: i32,
+        padding_h: i32,
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
+        //
+        batches: usize,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        let depth = input_depth as usize;
+        let mut acc = [0i32; CHANNEL_BLOCK];
+        for batch in 0..batches {
+            for out_y in 0..output_height {
+                for out_x in 0..output_width {
+                    let in_x_origin = (out_x * stride_w) - padding_w;
+                    let in_y_origin = (out_y * stride_h) - padding_h;
+                    let taps_y = Self::valid_taps(
+                        in_y_origin,
+                        dilation_h_factor,
+                        filter_height,
+                        input_height,
+                    );
+                    let taps_x =
+                        Self::valid_taps(in_x_origin, dilation_w_factor, filter_width, input_width);
+                    let is_border =
+                        taps_y.len() as i32 != filter_height || taps_x.len() as i32 != filter_width;
+                    let output_idx = Self::offset(
+                        output_height,
+                        output_width,
+                        input_depth,
+                        batch as i32,
+                        out_y,
+                        out_x,
+                        0,
+                    ) as usize;
+                    let mut first_channel = 0;
+                    while first_channel < depth {
+                        let block = CHANNEL_BLOCK.min(depth - first_channel);
+                        let channels = first_channel..first_channel + block;
+                        let acc = &mut acc[..block];
+                        // the input offset is folded into the effective bias
+                        acc.copy_from_slice(&effective_bias[channels.clone()]);
+                        let filter_at = |filter_y: i32, filter_x: i32| {
+                            let filter_idx = (filter_y * filter_width + filter_x) as usize * depth;
+                            &filter_data[filter_idx..][channels.clone()]
+                        };
+                        for filter_y in taps_y.clone() {
+                            let in_y = in_y_origin + dilation_h_factor * filter_y;
+                            for filter_x in taps_x.clone() {
+                                let in_x = in_x_origin + dilation_w_factor * filter_x;
+                                let input_idx = Self::offset(
+                                    input_height,
+                                    input_width,
+                                    input_depth,
+                                    batch as i32,
+                                    in_y,
+                                    in_x,
+                                    0,
+                                ) as usize;
+                                mac_i8(
+                                    acc,
+                                    &input_data[input_idx..][channels.clone()],
+                                    filter_at(filter_y, filter_x),
+                                );
+                            }
+                        }
+                        if is_border {
+                            // the skipped taps are padded with the zero point,
+                            // whose input offset has to be taken out of the folded bias
+                            for filter_y in 0..filter_height {
+                                for filter_x in 0..filter_width {
+                                    if taps_y.contains(&filter_y) && taps_x.contains(&filter_x) {
+                                        continue;
+                                    }
+                                    for (acc, &filter_v) in
+                                        acc.iter_mut().zip(filter_at(filter_y, filter_x))
+                                    {
+                                        *acc -= filter_v as i32 * input_offset;
+                                    }
+                                }
+                            }
+                        }
+                        for (channel, &total) in channels.zip(acc.iter()) {
+                            let mut total = multiply_by_quantized_multiplier(
+                                total,
+                                per_channel_multiplier[channel],
+                                per_channel_shift[channel],
+                            )?;
+                            total += output_offset;
+                            total = max(total, fused_activation_min);
+                            total = min(total, fused_activation_max);
+                            output_data[output_idx + channel] = total as i8;
+                        }
+                        first_channel += block;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the range of the filter taps whose input points are inside of the image,
+    /// where `origin` is the input point of the first tap
+    #[inline(always)]
+    fn valid_taps(origin: i32, dilation: i32, filter_size: i32, input_size: i32) -> Range<i32> {
+        let start = min(max((dilation - 1 - origin) / dilation, 0), filter_size);
+        let end = max(
+            min((input_size - origin + dilation - 1) / dilation, filter_size),
+            start,
+        );
+        start..end
+    }
+
+    /// Returns the sum of the filter values of the taps outside of `taps_y` x `taps_x`
+    #[inline(always)]
+    fn skipped_filter_sum(
+        taps_y: &Range<i32>,
+        taps_x: &Range<i32>,
+        filter_height: i32,
+        filter_width: i32,
+        filter_v: impl Fn(i32, i32) -> i32,
+    ) -> i32 {
+        let mut sum = 0;
+        for filter_y in 0..filter_height {
+            for filter_x in 0..filter_width {
+                if !taps_y.contains(&filter_y) || !taps_x.contains(&filter_x) {
+                    sum += filter_v(filter_y, filter_x);
+                }
+            }
+        }
+        sum
+    }
+
     #[inline(always)]
     fn offset(h: i32, w: i32, d: i32, i0: i32, i1: i32, i2: i32, i3: i32) -> i32 {
         ((i0 * h + i1) * w + i2) * d + i3
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OpDepthWiseConv2DInt8;
+    use crate::kernel::utils::calc_effective_bias;
+    use crate::kernel::utils::int4::FilterData;
+    use crate::kernel::utils::quantization::{
+        multiply_by_quantized_multiplier, quantize_multiplier,
+    };
+
+    #[test]
+    fn test_folded_bias_matches_offsets() {
+        // input 1x4x4x2, filter 1x3x3x4 with the depth multiplier 2
+        let input: [i8; 32] = core::array::from_fn(|i| (i as i32 * 37 % 255 - 127) as i8);
+        let filter: [i8; 36] = core::array::from_fn(|i| (i as i32 * 53 % 255 - 127) as i8);
+        let bias = [100i32, -200, 300, -400];
+        let (input_offset, filter_offset, output_offset) = (7, -2, -3);
+        let (multiplier, shift) = quantize_multiplier(0.0005).unwrap();
+        let mut effective_bias = [0i32; 4];
+        calc_effective_bias(
+            FilterData::Dense(&filter),
+            Some(&bias),
+            input_offset,
+            filter_offset,
+            9,
+            1,
+            4,
+            &mut effective_bias,
+        );
+
+        // (stride, dilation, padding, output size), where the border skips different taps
+        for (stride, dilation, padding, output_size) in
+            [(1, 1, 1, 4), (2, 1, 1, 2), (1, 2, 2, 4), (1, 3, 2, 2)]
+        {
+            let mut expected = vec![0i8; (output_size * output_size * 4) as usize];
+            for out_y in 0..output_size {
+                for out_x in 0..output_size {
+                    for out_channel in 0..4usize {
+                        let mut total = bias[out_channel];
+                        for filter_y in 0..3i32 {
+                            for filter_x in 0..3i32 {
+                                let in_y = out_y * stride + filter_y * dilation - padding;
+                                let in_x = out_x * stride + filter_x * dilation - padding;
+                                if !(0..4).contains(&in_y) || !(0..4).contains(&in_x) {
+                                    continue;
+                                }
+                                let input_v =
+                                    input[((in_y * 4 + in_x) * 2) as usize + out_channel / 2];
+                                let filter_v =
+                                    filter[((filter_y * 3 + filter_x) * 4) as usize + out_channel];
+                                total += (filter_v as i32 + filter_offset)
+                                    * (input_v as i32 + input_offset);
+                            }
+                        }
+                        let total =
+                            multiply_by_quantized_multiplier(total, multiplier, shift).unwrap();
+                        expected[((out_y * output_size + out_x) * 4) as usize + out_channel] =
+                            (total + output_offset).clamp(-128, 127) as i8;
+                    }
+                }
+            }
+
+            let mut output = vec![0i8; expected.len()];
+            OpDepthWiseConv2DInt8::kernel(
+                &input,
+                FilterData::Dense(&filter),
+                &effective_bias,
+                &mut output,
+                4,
+                4,
+                2,
+                3,
+                3,
+                4,
+                output_size,
+                output_size,
+                4,
+                stride,
+                stride,
+                dilation,
+                dilation,
+                padding,
+                padding,
+                2,
+                input_offset,
+                filter_offset,
+                output_offset,
+                &[multiplier; 4],
+                &[shift; 4],
+                1,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+            assert_eq!(expected, output);
+        }
+    }
+
+    #[test]
+    fn test_depthwise_conv2d_int8_packed_int4() {
+        // input 1x4x4x2, filter 1x3x3x4 with the depth multiplier 2 and the padding 1
+        let input: [i8; 32] = core::array::from_fn(|i| (i as i32 * 37 % 255 - 127) as i8);
+        let filter: [i8; 36] = core::array::from_fn(|i| (i as i32 * 5 % 16 - 8) as i8);
+        // two values per byte with the low nibble first
+        let packed: [u8; 18] = core::array::from_fn(|i| {
+            (filter[2 * i] as u8 & 0x0f) | ((filter[2 * i + 1] as u8) << 4)
+        });
+        let bias = [100i32, -200, 300, -400];
+        let (multiplier, shift) = quantize_multiplier(0.005).unwrap();
+
+        let mut outputs = [[0i8; 64]; 2];
+        for (output, filter_data) in outputs
+            .iter_mut()
+            .zip([FilterData::Dense(&filter), FilterData::PackedInt4(&packed)])
+        {
+            let mut effective_bias = [0i32; 4];
+            calc_effective_bias(filter_data, Some(&bias), 7, 0, 9, 1, 4, &mut effective_bias);
+            OpDepthWiseConv2DInt8::kernel(
+                &input,
+                filter_data,
+                &effective_bias,
+                output,
+                4,
+                4,
+                2,
+                3,
+                3,
+                4,
+                4,
+                4,
+                4,
+                1,
+                1,
+                1,
+                1,
+                1,
+                1,
+                2,
+                7,
+                0,
+                -3,
+                &[multiplier; 4],
+                &[shift; 4],
+                1,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+        }
+        assert_eq!(outputs[0], outputs[1]);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_depthwise_conv2d_int8_is_bit_exact() {
+        // input 2x5x6x37, filter 1x3x3x37 with the padding 1, which covers the tail of the channel blocks
+        const DEPTH: usize = 37;
+        let input: [i8; 2 * 5 * 6 * DEPTH] =
+            core::array::from_fn(|i| (i as i32 * 37 % 256 - 128) as i8);
+        let filter: [i8; 9 * DEPTH] = core::array::from_fn(|i| (i as i32 * 53 % 255 - 127) as i8);
+        let bias: [i32; DEPTH] = core::array::from_fn(|i| i as i32 * 50 - 900);
+        let input_offset = 9;
+        let (multiplier, shift) = quantize_multiplier(0.0005).unwrap();
+        let mut effective_bias = [0i32; DEPTH];
+        calc_effective_bias(
+            FilterData::Dense(&filter),
+            Some(&bias),
+            input_offset,
+            0,
+            9,
+            1,
+            DEPTH,
+            &mut effective_bias,
+        );
+
+        // (stride, dilation, output height, output width)
+        for (stride, dilation, output_height, output_width) in
+            [(1, 1, 5, 6), (2, 1, 3, 3), (1, 2, 3, 4)]
+        {
+            let output_size = 2 * output_height * output_width * DEPTH;
+            let mut expected = vec![0i8; output_size];
+            OpDepthWiseConv2DInt8::kernel(
+                &input,
+                FilterData::Dense(&filter),
+                &effective_bias,
+                &mut expected,
+                5,
+                6,
+                DEPTH as i32,
+                3,
+                3,
+                DEPTH as i32,
+                output_height as i32,
+                output_width as i32,
+                DEPTH as i32,
+                stride,
+                stride,
+                dilation,
+                dilation,
+                1,
+                1,
+                1,
+                input_offset,
+                0,
+                -4,
+                &[multiplier; DEPTH],
+                &[shift; DEPTH],
+                2,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+
+            let mut output = vec![0i8; output_size];
+            OpDepthWiseConv2DInt8::simd_kernel(
+                &input,
+                &filter,
+                &effective_bias,
+                &mut output,
+                5,
+                6,
+                DEPTH as i32,
+                3,
+                3,
+                output_height as i32,
+                output_width as i32,
+                stride,
+                stride,
+                dilation,
+                dilation,
+                1,
+                1,
+                input_offset,
+                -4,
+                &[multiplier; DEPTH],
+                &[shift; DEPTH],
+                2,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+            assert_eq!(expected, output);
+        }
+    }
+
+    #[test]
+    fn test_3x3_kernel_matches_generic() {
+        // input 2x7x6x5, filter 1x3x3x5 with the depth multiplier 1
+        const DEPTH: usize = 5;
+        let input: [i8; 2 * 7 * 6 * DEPTH] =
+            core::array::from_fn(|i| (i as i32 * 37 % 256 - 128) as i8);
+        let filter: [i8; 9 * DEPTH] = core::array::from_fn(|i| (i as i32 * 53 % 255 - 127) as i8);
+        let bias: [i32; DEPTH] = core::array::from_fn(|i| i as i32 * 300 - 600);
+        let (input_offset, filter_offset, output_offset) = (5, 1, -2);
+        let (multiplier, shift) = quantize_multiplier(0.0007).unwrap();
+        let mut effective_bias = [0i32; DEPTH];
+        calc_effective_bias(
+            FilterData::Dense(&filter),
+            Some(&bias),
+            input_offset,
+            filter_offset,
+            9,
+            1,
+            DEPTH,
+            &mut effective_bias,
+        );
+
+        // (stride, padding, output height, output width) of SAME and VALID
+        for (stride, padding, output_height, output_width) in
+            [(1, 1, 7, 6), (1, 0, 5, 4), (2, 1, 4, 3), (2, 0, 3, 2)]
+        {
+            let output_size = 2 * output_height * output_width * DEPTH;
+            let mut expected = vec![0i8; output_size];
+            OpDepthWiseConv2DInt8::kernel(
+                &input,
+                FilterData::Dense(&filter),
+                &effective_bias,
+                &mut expected,
+                7,
+                6,
+                DEPTH as i32,
+                3,
+                3,
+                DEPTH as i32,
+                output_height as i32,
+                output_width as i32,
+                DEPTH as i32,
+                stride,
+                stride,
+                1,
+                1,
+                padding,
+                padding,
+                1,
+                input_offset,
+                filter_offset,
+                output_offset,
+                &[multiplier; DEPTH],
+                &[shift; DEPTH],
+                2,
+                -100,
+                i8::MAX as i32,
+            )
+            .unwrap();
+
+            let mut output = vec![0i8; output_size];
+            OpDepthWiseConv2DInt8::kernel_3x3(
+                &input,
+                &filter,
+                &effective_bias,
+                &mut output,
+                7,
+                6,
+                DEPTH as i32,
+                output_height as i32,
+                output_width as i32,
+                stride,
+                padding,
+                padding,
+                input_offset,
+                filter_offset,
+                output_offset,
+                &[multiplier; DEPTH],
+                &[shift; DEPTH],
+                2,
+                -100,
+                i8::MAX as i32,
+            )
+            .unwrap();
+            assert_eq!(expected, output, "stride {stride} padding {padding}");
+        }
+    }
+}
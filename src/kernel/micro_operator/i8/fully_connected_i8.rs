@@ -2,16 +2,20 @@ use num_traits::{AsPrimitive, FromPrimitive};
 
 use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
 use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
-use crate::kernel::utils::quantization::{
-    get_quantized_convolution_multiplier, multiply_by_quantized_multiplier, quantize_multiplier,
-};
+use crate::kernel::utils::int4::{FilterData, FilterRef};
+use crate::kernel::utils::quantization::multiply_by_quantized_multiplier;
+#[cfg(feature = "simd")]
+use crate::kernel::utils::simd::{cast_slice, cast_slice_mut, dot_i8};
+use crate::kernel::utils::{calc_effective_bias, calc_fully_connected_multiplier_shift};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
-use crate::micro_context::BLiteContext;
+use crate::micro_context::{BLiteContext, ParallelElem};
 use crate::micro_errors::BLiteError::{self, *};
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
 use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_sparsity::BLiteSparsity;
 use crate::micro_tensor::BLiteTensor;
 use crate::tflite_schema_generated::tflite::Operator;
 use core::cmp::{max, min};
@@ -25,16 +29,18 @@ pub struct OpFullyConnectedInt8 {}
 impl OpFullyConnectedInt8 {
     const OPCODE: i32 = 9;
 
-    pub fn fully_connected_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S>
-    {
+    pub fn fully_connected_int8<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 12,
         }
     }
 
     pub fn parser<'a, T: ArrayElem<T>>(
-        _allocator: &mut impl ArenaAllocator,
+        allocator: &mut impl ArenaAllocator,
         op: Operator,
         tensors: &mut [BLiteTensor<'a, T>],
     ) -> Result<BLiteBuiltinOption<'a, T>> {
@@ -46,8 +52,9 @@ impl OpFullyConnectedInt8 {
 
         let input_idx = op.inputs().unwrap().get(0) as usize;
         let (input_scale, input_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[input_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
@@ -55,31 +62,43 @@ impl OpFullyConnectedInt8 {
         };
 
         let filter_idx = op.inputs().unwrap().get(1) as usize;
-        let (filter_scale, filter_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[filter_idx]._t()?.borrow().quant_params
-            else {
-                return Err(BLiteError::NotFoundQuantParams);
-            };
-            (scale[0], zero_point[0] as i32)
+        // the filter may be packed int4 values
+        let Some(filter_quant_params) = tensors[filter_idx].quant_params() else {
+            return Err(BLiteError::NotFoundQuantParams);
+        };
+        let filter_zero_point = filter_quant_params.zero_point[0] as i32;
+        let (output_depth, accum_depth) = {
+            let filter_dims = tensors[filter_idx].dims();
+            (
+                filter_dims[filter_dims.len() - 2] as usize,
+                filter_dims[filter_dims.len() - 1] as usize,
+            )
+        };
+        // the sparse weights are kept compressed and evaluated by the sparse kernel,
+        // whose skipped values have to be the zero point
+        let filter_sparsity = match &tensors[filter_idx] {
+            BLiteTensor::I4Tensor(_) => None,
+            _ if filter_zero_point != 0 => None,
+            filter => filter._t()?.borrow_mut().sparsity.take().copied(),
         };
 
         let bias_idx = op.inputs().unwrap().get(2);
-        let bias_scale = if bias_idx >= 0 {
+        let bias_scales = if bias_idx >= 0 {
             let Some(BLiteQuantizationParams { scale, .. }) =
                 tensors[bias_idx as usize]._i32()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
-            Some(scale[0])
+            Some(scale)
         } else {
             None
         };
 
         let output_idx = op.outputs().unwrap().get(0) as usize;
         let (output_scale, output_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[output_idx]._t()?.borrow().quant_params
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
@@ -93,13 +112,39 @@ impl OpFullyConnectedInt8 {
             )?;
 
         //This computations is corresponded to CalculateOpDataFullyConnected
-        let real_multiplier = get_quantized_convolution_multiplier(
+        let per_channel_multiplier = unsafe { alloc_array_mut(allocator, output_depth) }?;
+        let per_channel_shift = unsafe { alloc_array_mut(allocator, output_depth) }?;
+        calc_fully_connected_multiplier_shift(
             input_scale,
-            filter_scale,
+            &filter_quant_params,
             output_scale,
-            bias_scale,
+            bias_scales,
+            per_channel_multiplier,
+            per_channel_shift,
         )?;
-        let (output_multiplier, output_shift) = quantize_multiplier(real_multiplier)?;
+
+        // the sparse kernel accumulates the input offset by itself
+        let effective_bias: &[i32] = if filter_sparsity.is_none() {
+            let effective_bias = unsafe { alloc_array_mut(allocator, output_depth) }?;
+            let bias = if bias_idx >= 0 {
+                Some(tensors[bias_idx as usize]._i32()?.borrow())
+            } else {
+                None
+            };
+            calc_effective_bias(
+                FilterRef::borrow(&tensors[filter_idx])?.data(),
+                bias.as_ref().map(|bias| &bias.data[..]),
+                -input_zero_point,
+                -filter_zero_point,
+                accum_depth,
+                accum_depth,
+                1,
+                effective_bias,
+            );
+            effective_bias
+        } else {
+            &[]
+        };
 
         Ok(BLiteBuiltinOption::QuantizedFullyConnectedOptions {
             op_code,
@@ -108,17 +153,19 @@ impl OpFullyConnectedInt8 {
             input_offset: -input_zero_point,
             filter_offset: -filter_zero_point,
             output_offset: output_zero_point,
-            output_multiplier,
-            output_shift,
+            per_channel_multiplier,
+            per_channel_shift,
+            filter_sparsity,
+            effective_bias,
         })
     }
 
-    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
         BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
     }
 
-    pub fn eval<'a, T: ArrayElem<T>>(
-        _context: &BLiteContext,
+    pub fn eval<'a, T: ArrayElem<T> + ParallelElem>(
+        context: &BLiteContext,
         tensors: &'a mut [BLiteTensor<'a, T>],
         node: &BLiteNode<'a>,
         builtin_option: BLiteBuiltinOption<T>,
@@ -130,8 +177,10 @@ impl OpFullyConnectedInt8 {
             input_offset,
             filter_offset,
             output_offset,
-            output_multiplier,
-            output_shift,
+            per_channel_multiplier,
+            per_channel_shift,
+            filter_sparsity,
+            effective_bias,
         } = builtin_option
         else {
             return Err(NotInitializeActivation);
@@ -141,7 +190,8 @@ impl OpFullyConnectedInt8 {
         let input = tensors[idx_input]._t()?.borrow();
 
         let idx_filter = node.inputs[1] as usize;
-        let filter = tensors[idx_filter]._t()?.borrow();
+        let filter = FilterRef::borrow(&tensors[idx_filter])?;
+        let filter_dims = filter.dims();
 
         let idx_output = node.outputs[0] as usize;
         let mut output = tensors[idx_output]._t()?.borrow_mut();
@@ -149,61 +199,108 @@ impl OpFullyConnectedInt8 {
         let idx_bias = node.inputs[2];
 
         let batches = 1usize;
-        let output_depth = filter.dims[filter.dims.len() - 2] as usize;
-        let accum_depth = filter.dims[filter.dims.len() - 1] as usize;
+        let output_depth = filter_dims[filter_dims.len() - 2] as usize;
+        let accum_depth = filter_dims[filter_dims.len() - 1] as usize;
+
+        let bias = if filter_sparsity.is_some() && idx_bias >= 0 {
+            Some(tensors[idx_bias as usize]._i32()?.borrow())
+        } else {
+            None
+        };
+        let bias_data = bias.as_ref().map(|bias| &bias.data[..]);
+        let input_data = &*input.data;
+        let filter_data = filter.data();
 
-        if idx_bias >= 0 {
-            let bias = tensors[idx_bias as usize]._i32()?.borrow();
+        // the output channels of the dense filter are split across the worker pool,
+        // and the others are evaluated at once
+        let split_channels =
+            filter_sparsity.is_none() && matches!(filter_data, FilterData::Dense(_));
+        let unit_len = if split_channels { 1 } else { output_depth };
+        let output_data = &mut output.data[..batches * output_depth];
+        context.parallel_for(output_data, unit_len, &[], |units, output_data, _| {
+            let channels = units.start * unit_len..units.end * unit_len;
+            let output_depth = channels.len();
+            let filter_data = match filter_data {
+                FilterData::Dense(filter_data) if split_channels => FilterData::Dense(
+                    &filter_data[channels.start * accum_depth..channels.end * accum_depth],
+                ),
+                filter_data => filter_data,
+            };
+            let per_channel_multiplier = &per_channel_multiplier[channels.clone()];
+            let per_channel_shift = &per_channel_shift[channels.clone()];
 
-            Self::kernel(
-                input.data,
-                filter.data,
-                Some(bias.data.as_ref()),
-                output.data,
-                input_offset,
+            if let Some(sparsity) = &filter_sparsity {
+                return Self::sparse_kernel(
+                    input_data,
+                    filter_data,
+                    sparsity,
+                    bias_data,
+                    output_data,
+                    input_offset,
+                    output_offset,
+                    output_depth,
+                    per_channel_multiplier,
+                    per_channel_shift,
+                    batches,
+                    accum_depth,
+                    fused_activation_min,
+                    fused_activation_max,
+                );
+            }
+
+            let effective_bias = &effective_bias[channels];
+            #[cfg(feature = "simd")]
+            if let (Some(input_data), FilterData::Dense(filter_data), Some(output_data), 0) = (
+                cast_slice::<T, i8>(input_data),
+                filter_data,
+                cast_slice_mut::<T, i8>(output_data),
                 filter_offset,
-                output_offset,
-                output_depth,
-                output_multiplier,
-                output_shift,
-                accum_depth,
-                batches,
-                fused_activation_min,
-                fused_activation_max,
-            )
-        } else {
+            ) {
+                return Self::simd_kernel(
+                    input_data,
+                    cast_slice(filter_data).unwrap(),
+                    effective_bias,
+                    output_data,
+                    output_offset,
+                    output_depth,
+                    per_channel_multiplier,
+                    per_channel_shift,
+                    accum_depth,
+                    batches,
+                    fused_activation_min,
+                    fused_activation_max,
+                );
+            }
             Self::kernel(
-                input.data,
-                filter.data,
-                None,
-                output.data,
-                input_offset,
+                input_data,
+                filter_data,
+                effective_bias,
+                output_data,
                 filter_offset,
                 output_offset,
                 output_depth,
-                output_multiplier,
-                output_shift,
+                per_channel_multiplier,
+                per_channel_shift,
                 accum_depth,
                 batches,
                 fused_activation_min,
                 fused_activation_max,
             )
-        }
+        })
     }
 
     #[inline(always)]
-    fn kernel<'a, T: ArrayElem<T>>(
+    pub fn kernel<T: ArrayElem<T>>(
         input_data: &[T],
-        filter_data: &[T],
-        bias_data: Option<&[i32]>,
+        filter_data: FilterData<T>,
+        effective_bias: &[i32],
         output_data: &mut [T],
         // for quantization
-        input_offset: i32,
         filter_offset: i32,
         output_offset: i32,
         output_depth: usize,
-        output_multiplier: i32,
-        output_shift: i32,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
         //
         accum_depth: usize,
         batches: usize,
@@ -212,20 +309,117 @@ impl OpFullyConnectedInt8 {
     ) -> Result<()> {
         for batch in 0usize..batches {
             for out_d in 0usize..output_depth {
-                let mut total = 0;
+                // the input offset is folded into the effective bias
+                let mut total = effective_bias[out_d];
                 for acc_d in 0usize..accum_depth {
                     let input_val =
                         AsPrimitive::<i32>::as_(input_data[batch * accum_depth + acc_d]);
-                    let filter_val =
-                        AsPrimitive::<i32>::as_(filter_data[out_d * accum_depth + acc_d]);
-                    total += (input_val + input_offset) * (filter_val + filter_offset);
+                    let filter_val = filter_data.get(out_d * accum_depth + acc_d);
+                    total += input_val * (filter_val + filter_offset);
+                }
+
+                total = multiply_by_quantized_multiplier(
+                    total,
+                    per_channel_multiplier[out_d],
+                    per_channel_shift[out_d],
+                )?;
+
+                total += output_offset;
+                total = max(total, fused_activation_min);
+                total = min(total, fused_activation_max);
+
+                output_data[batch * output_depth + out_d] = FromPrimitive::from_i32(total).unwrap();
+            }
+        }
+        Ok(())
+    }
+
+    /// The accumulation of each output channel is the vectorised dot product,
+    /// which needs the dense int8 filter whose offset is zero
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn simd_kernel(
+        input_data: &[i8],
+        filter_data: &[i8],
+        effective_bias: &[i32],
+        output_data: &mut [i8],
+        // for quantization
+        output_offset: i32,
+        output_depth: usize,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
+        //
+        accum_depth: usize,
+        batches: usize,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        for batch in 0usize..batches {
+            let input = &input_data[batch * accum_depth..(batch + 1) * accum_depth];
+            for out_d in 0usize..output_depth {
+                let filter = &filter_data[out_d * accum_depth..(out_d + 1) * accum_depth];
+                // the input offset is folded into the effective bias
+                let mut total = effective_bias[out_d] + dot_i8(input, filter);
+
+                total = multiply_by_quantized_multiplier(
+                    total,
+                    per_channel_multiplier[out_d],
+                    per_channel_shift[out_d],
+                )?;
+
+                total += output_offset;
+                total = max(total, fused_activation_min);
+                total = min(total, fused_activation_max);
+
+                output_data[batch * output_depth + out_d] = total as i8;
+            }
+        }
+        Ok(())
+    }
+
+    /// The filter holds only the nonzero blocks of each output channel,
+    /// and the zero blocks are skipped
+    #[inline(always)]
+    fn sparse_kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: FilterData<T>,
+        filter_sparsity: &BLiteSparsity,
+        bias_data: Option<&[i32]>,
+        output_data: &mut [T],
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        output_depth: usize,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
+        //
+        batches: usize,
+        accum_depth: usize,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        let block_size = filter_sparsity.block_size;
+        for batch in 0usize..batches {
+            for out_d in 0usize..output_depth {
+                let mut total = 0;
+                for (acc_d, offset) in filter_sparsity.row_blocks(out_d) {
+                    for i in 0..block_size {
+                        let input_val =
+                            AsPrimitive::<i32>::as_(input_data[batch * accum_depth + acc_d + i]);
+                        let filter_val = filter_data.get(offset + i);
+                        total += (input_val + input_offset) * filter_val;
+                    }
                 }
 
                 if let Some(bias_data) = bias_data {
                     total += bias_data[out_d];
                 }
 
-                total = multiply_by_quantized_multiplier(total, output_multiplier, output_shift)?;
+                total = multiply_by_quantized_multiplier(
+                    total,
+                    per_channel_multiplier[out_d],
+                    per_channel_shift[out_d],
+                )?;
 
                 total += output_offset;
                 total = max(total, fused_activation_min);
@@ -237,3 +431,213 @@ impl OpFullyConnectedInt8 {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OpFullyConnectedInt8;
+    use crate::kernel::utils::int4::FilterData;
+    use crate::kernel::utils::{calc_effective_bias, calc_fully_connected_multiplier_shift};
+    use crate::micro_array::BLiteQuantizationParams;
+    use crate::micro_errors::BLiteError;
+
+    #[test]
+    fn test_fully_connected_int8_per_channel() {
+        let input_scale = 1. / 32.;
+        let input_zero_point = 3;
+        // each output channel has its own scale
+        let filter_scales = [1. / 64., 1. / 512., 1. / 16.];
+        let filter_zero_points = [0i64; 3];
+        let output_scale = 1. / 8.;
+        let output_zero_point = -5;
+        let input = [35i8, -29, 67, 3, 127, -128, 0, 99];
+        let filter = [
+            32i8, -64, 16, 127, //
+            -128, 0, 64, 8, //
+            5, -7, 11, -13,
+        ];
+        let bias_scales: [f32; 3] = core::array::from_fn(|i| input_scale * filter_scales[i]);
+        let bias = [512i32, -8192, 4];
+
+        let filter_quant_params =
+            BLiteQuantizationParams::new(&filter_scales, &filter_zero_points, 0);
+        let mut per_channel_multiplier = [0i32; 3];
+        let mut per_channel_shift = [0i32; 3];
+        calc_fully_connected_multiplier_shift(
+            input_scale,
+            &filter_quant_params,
+            output_scale,
+            Some(&bias_scales),
+            &mut per_channel_multiplier,
+            &mut per_channel_shift,
+        )
+        .unwrap();
+
+        let mut effective_bias = [0i32; 3];
+        calc_effective_bias(
+            FilterData::Dense(&filter),
+            Some(&bias),
+            -input_zero_point,
+            0,
+            4,
+            4,
+            1,
+            &mut effective_bias,
+        );
+
+        let mut output = [0i8; 6];
+        OpFullyConnectedInt8::kernel(
+            &input,
+            FilterData::Dense(&filter),
+            &effective_bias,
+            &mut output,
+            0,
+            output_zero_point,
+            3,
+            &per_channel_multiplier,
+            &per_channel_shift,
+            4,
+            2,
+            i8::MIN as i32,
+            i8::MAX as i32,
+        )
+        .unwrap();
+
+        for batch in 0..2 {
+            for out_d in 0..3 {
+                let mut expected = bias[out_d] as f32 * bias_scales[out_d];
+                for d in 0..4 {
+                    expected += (input[batch * 4 + d] as i32 - input_zero_point) as f32
+                        * input_scale
+                        * filter[out_d * 4 + d] as f32
+                        * filter_scales[out_d];
+                }
+                let actual =
+                    (output[batch * 3 + out_d] as i32 - output_zero_point) as f32 * output_scale;
+                assert!(
+                    (expected - actual).abs() <= output_scale,
+                    "{expected} != {actual}"
+                );
+            }
+        }
+
+        // the filter of FULLY_CONNECTED must be quantized along the output channels
+        let filter_quant_params =
+            BLiteQuantizationParams::new(&filter_scales, &filter_zero_points, 1);
+        assert!(matches!(
+            calc_fully_connected_multiplier_shift(
+                input_scale,
+                &filter_quant_params,
+                output_scale,
+                Some(&bias_scales),
+                &mut per_channel_multiplier,
+                &mut per_channel_shift,
+            ),
+            Err(BLiteError::NotMatchQuantizedDimension(1))
+        ));
+    }
+
+    #[test]
+    fn test_fully_connected_int8_packed_int4() {
+        let input = [35i8, -29, 67, 3, 127, -128, 0, 99];
+        let filter = [
+            1i8, -2, 7, -8, //
+            0, 3, -5, 4, //
+            -1, 6, 2, -7,
+        ];
+        // two values per byte with the low nibble first
+        let packed: [u8; 6] = core::array::from_fn(|i| {
+            (filter[2 * i] as u8 & 0x0f) | ((filter[2 * i + 1] as u8) << 4)
+        });
+        let bias = [512i32, -8192, 4];
+        let (multiplier, shift) =
+            crate::kernel::utils::quantization::quantize_multiplier(0.01).unwrap();
+
+        let mut outputs = [[0i8; 6]; 2];
+        for (output, filter_data) in outputs
+            .iter_mut()
+            .zip([FilterData::Dense(&filter), FilterData::PackedInt4(&packed)])
+        {
+            let mut effective_bias = [0i32; 3];
+            calc_effective_bias(filter_data, Some(&bias), 3, 0, 4, 4, 1, &mut effective_bias);
+            OpFullyConnectedInt8::kernel(
+                &input,
+                filter_data,
+                &effective_bias,
+                output,
+                0,
+                -5,
+                3,
+                &[multiplier; 3],
+                &[shift; 3],
+                4,
+                2,
+                i8::MIN as i32,
+                i8::MAX as i32,
+            )
+            .unwrap();
+        }
+        assert_eq!(outputs[0], outputs[1]);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn test_simd_fully_connected_int8_is_bit_exact() {
+        const BATCHES: usize = 3;
+        const OUTPUT_DEPTH: usize = 7;
+        const ACCUM_DEPTH: usize = 45;
+        let input: [i8; BATCHES * ACCUM_DEPTH] =
+            core::array::from_fn(|i| (i as i32 * 37 % 256 - 128) as i8);
+        let filter: [i8; OUTPUT_DEPTH * ACCUM_DEPTH] =
+            core::array::from_fn(|i| (i as i32 * 101 % 255 - 127) as i8);
+        let bias: [i32; OUTPUT_DEPTH] = core::array::from_fn(|i| i as i32 * 1000 - 3000);
+        let (multiplier, shift) =
+            crate::kernel::utils::quantization::quantize_multiplier(0.0002).unwrap();
+
+        let mut effective_bias = [0i32; OUTPUT_DEPTH];
+        calc_effective_bias(
+            FilterData::Dense(&filter),
+            Some(&bias),
+            17,
+            0,
+            ACCUM_DEPTH,
+            ACCUM_DEPTH,
+            1,
+            &mut effective_bias,
+        );
+        let mut expected = [0i8; BATCHES * OUTPUT_DEPTH];
+        OpFullyConnectedInt8::kernel(
+            &input,
+            FilterData::Dense(&filter),
+            &effective_bias,
+            &mut expected,
+            0,
+            -3,
+            OUTPUT_DEPTH,
+            &[multiplier; OUTPUT_DEPTH],
+            &[shift; OUTPUT_DEPTH],
+            ACCUM_DEPTH,
+            BATCHES,
+            i8::MIN as i32,
+            i8::MAX as i32,
+        )
+        .unwrap();
+
+        let mut output = [0i8; BATCHES * OUTPUT_DEPTH];
+        OpFullyConnectedInt8::simd_kernel(
+            &input,
+            &filter,
+            &effective_bias,
+            &mut output,
+            -3,
+            OUTPUT_DEPTH,
+            &[multiplier; OUTPUT_DEPTH],
+            &[shift; OUTPUT_DEPTH],
+            ACCUM_DEPTH,
+            BATCHES,
+            i8::MIN as i32,
+            i8::MAX as i32,
+        )
+        .unwrap();
+        assert_eq!(expected, output);
+    }
+}
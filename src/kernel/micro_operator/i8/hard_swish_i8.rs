@@ -0,0 +1,412 @@
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::quantization::quantize_multiplier;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpHardSwishInt8 {}
+
+impl OpHardSwishInt8 {
+    const OPCODE: i32 = 117;
+
+    pub fn hard_swish_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let (input_scale, input_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let (output_scale, output_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        // This computations is corresponded to HardSwishPrepare
+        let hires_input_scale = (1.0f32 / 128.0) * input_scale;
+        let reluish_scale = 3.0f32 / 32768.0;
+
+        let output_multiplier = hires_input_scale / output_scale;
+        let (output_multiplier_fixedpoint_int32, output_multiplier_exponent) =
+            quantize_multiplier(output_multiplier as f64)?;
+        if output_multiplier_exponent > 0 {
+            return Err(InCompatibleCasting);
+        }
+
+        let reluish_multiplier = hires_input_scale / reluish_scale;
+        let (reluish_multiplier_fixedpoint_int32, reluish_multiplier_exponent) =
+            quantize_multiplier(reluish_multiplier as f64)?;
+
+        Ok(BLiteBuiltinOption::QuantizedHardSwishOptions {
+            input_zero_point,
+            output_zero_point,
+            reluish_multiplier_fixedpoint_int16: Self::down_scale_int32_to_int16_multiplier(
+                reluish_multiplier_fixedpoint_int32,
+            ),
+            reluish_multiplier_exponent,
+            output_multiplier_fixedpoint_int16: Self::down_scale_int32_to_int16_multiplier(
+                output_multiplier_fixedpoint_int32,
+            ),
+            output_multiplier_exponent,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let QuantizedHardSwishOptions {
+            input_zero_point,
+            output_zero_point,
+            reluish_multiplier_fixedpoint_int16,
+            reluish_multiplier_exponent,
+            output_multiplier_fixedpoint_int16,
+            output_multiplier_exponent,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites the input if the memory planner gives them the same buffer
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let mut output = tensors[idx_output]._t()?.borrow_mut();
+            return Self::kernel_in_place(
+                output.data,
+                input_zero_point,
+                output_zero_point,
+                reluish_multiplier_fixedpoint_int16,
+                reluish_multiplier_exponent,
+                output_multiplier_fixedpoint_int16,
+                output_multiplier_exponent,
+            );
+        }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        Self::kernel(
+            input.data,
+            output.data,
+            input_zero_point,
+            output_zero_point,
+            reluish_multiplier_fixedpoint_int16,
+            reluish_multiplier_exponent,
+            output_multiplier_fixedpoint_int16,
+            output_multiplier_exponent,
+        )
+    }
+
+    /// This kernel follows reference_ops::HardSwish of tensorflow lite,
+    /// which works on 16-bit fixed-point values to stay bit-exact.
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        // for quantization
+        input_zero_point: i32,
+        output_zero_point: i32,
+        reluish_multiplier_fixedpoint_int16: i16,
+        reluish_multiplier_exponent: i32,
+        output_multiplier_fixedpoint_int16: i16,
+        output_multiplier_exponent: i32,
+    ) -> Result<()> {
+        for (output_v, &input_v) in output_data.iter_mut().zip(input_data.iter()) {
+            *output_v = Self::hard_swish_value(
+                input_v,
+                input_zero_point,
+                output_zero_point,
+                reluish_multiplier_fixedpoint_int16,
+                reluish_multiplier_exponent,
+                output_multiplier_fixedpoint_int16,
+                output_multiplier_exponent,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(
+        data: &mut [T],
+        // for quantization
+        input_zero_point: i32,
+        output_zero_point: i32,
+        reluish_multiplier_fixedpoint_int16: i16,
+        reluish_multiplier_exponent: i32,
+        output_multiplier_fixedpoint_int16: i16,
+        output_multiplier_exponent: i32,
+    ) -> Result<()> {
+        for v in data.iter_mut() {
+            *v = Self::hard_swish_value(
+                *v,
+                input_zero_point,
+                output_zero_point,
+                reluish_multiplier_fixedpoint_int16,
+                reluish_multiplier_exponent,
+                output_multiplier_fixedpoint_int16,
+                output_multiplier_exponent,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn hard_swish_value<T: ArrayElem<T>>(
+        input_v: T,
+        // for quantization
+        input_zero_point: i32,
+        output_zero_point: i32,
+        reluish_multiplier_fixedpoint_int16: i16,
+        reluish_multiplier_exponent: i32,
+        output_multiplier_fixedpoint_int16: i16,
+        output_multiplier_exponent: i32,
+    ) -> Result<T> {
+        let quantized_min = AsPrimitive::<i32>::as_(T::MIN);
+        let quantized_max = AsPrimitive::<i32>::as_(T::MAX);
+        let input_value = (AsPrimitive::<i32>::as_(input_v) - input_zero_point) as i16;
+        // Left-shift as much as we can without overflow to keep the significant bits
+        let input_value_on_hires_input_scale = input_value.wrapping_mul(1 << 7);
+        let input_value_on_preshift_output_scale = Self::saturating_rounding_doubling_high_mul(
+            input_value_on_hires_input_scale,
+            output_multiplier_fixedpoint_int16,
+        );
+
+        // Rescale the input from [-3, 3] to [-1, 1] with saturation
+        let mut reluish_value = input_value_on_hires_input_scale;
+        if reluish_multiplier_exponent > 0 {
+            reluish_value =
+                Self::saturating_left_shift(reluish_value, reluish_multiplier_exponent - 1);
+        }
+        reluish_value = Self::saturating_rounding_doubling_high_mul(
+            reluish_value,
+            reluish_multiplier_fixedpoint_int16,
+        );
+        if reluish_multiplier_exponent > 0 {
+            reluish_value = Self::saturating_left_shift(reluish_value, 1);
+        }
+        if reluish_multiplier_exponent < 0 {
+            reluish_value =
+                Self::rounding_divide_by_pot(reluish_value, -reluish_multiplier_exponent);
+        }
+        // Convert [-1, 1] to [0, 1]
+        reluish_value = ((reluish_value as i32 + (1 << 15)) >> 1) as i16;
+
+        let preshift_output_value =
+            Self::saturating_doubling_high_mul(reluish_value, input_value_on_preshift_output_scale);
+        let mut output_value =
+            Self::rounding_divide_by_pot(preshift_output_value, -output_multiplier_exponent) as i32;
+        output_value += output_zero_point;
+        output_value = min(output_value, quantized_max);
+        output_value = max(output_value, quantized_min);
+        Ok(FromPrimitive::from_i32(output_value).unwrap())
+    }
+
+    fn down_scale_int32_to_int16_multiplier(multiplier: i32) -> i16 {
+        const ROUNDING_OFFSET: i32 = 1 << 15;
+        if multiplier >= i32::MAX - ROUNDING_OFFSET {
+            return i16::MAX;
+        }
+        ((multiplier + ROUNDING_OFFSET) >> 16) as i16
+    }
+
+    #[inline(always)]
+    fn saturating_left_shift(x: i16, amount: i32) -> i16 {
+        let result = (x as i32) * (1 << amount);
+        max(min(result, i16::MAX as i32), i16::MIN as i32) as i16
+    }
+
+    #[inline(always)]
+    fn saturating_doubling_high_mul(a: i16, b: i16) -> i16 {
+        if a == b && a == i16::MIN {
+            return i16::MAX;
+        }
+        // the division rounds toward zero as SaturatingDoublingHighMul of tensorflow lite
+        ((a as i32 * b as i32) / (1 << 15)) as i16
+    }
+
+    #[inline(always)]
+    fn saturating_rounding_doubling_high_mul(a: i16, b: i16) -> i16 {
+        if a == b && a == i16::MIN {
+            return i16::MAX;
+        }
+        let ab = a as i32 * b as i32;
+        let nudge = if ab >= 0 { 1 << 14 } else { 1 - (1 << 14) };
+        ((ab + nudge) / (1 << 15)) as i16
+    }
+
+    #[inline(always)]
+    fn rounding_divide_by_pot(x: i16, exponent: i32) -> i16 {
+        let mask = ((1i32 << exponent) - 1) as i16;
+        let remainder = x & mask;
+        let threshold = (mask >> 1) + if x < 0 { 1 } else { 0 };
+        (x >> exponent) + if remainder > threshold { 1 } else { 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpHardSwishInt8;
+    use crate::kernel::utils::quantization::quantize_multiplier;
+
+    #[test]
+    fn test_hard_swish_int8_close_to_float() {
+        let (input_scale, input_zero_point) = (6.0f32 / 128.0, 0);
+        let (output_scale, output_zero_point) = (8.0f32 / 256.0, -96);
+
+        let hires_input_scale = (1.0f32 / 128.0) * input_scale;
+        let (output_multiplier, output_exponent) =
+            quantize_multiplier((hires_input_scale / output_scale) as f64).unwrap();
+        let (reluish_multiplier, reluish_exponent) =
+            quantize_multiplier((hires_input_scale / (3.0f32 / 32768.0)) as f64).unwrap();
+
+        let input = (-128..=127).map(|x| x as i8).collect::<Vec<i8>>();
+        let mut output = vec![0i8; input.len()];
+        OpHardSwishInt8::kernel(
+            &input,
+            &mut output,
+            input_zero_point,
+            output_zero_point,
+            OpHardSwishInt8::down_scale_int32_to_int16_multiplier(reluish_multiplier),
+            reluish_exponent,
+            OpHardSwishInt8::down_scale_int32_to_int16_multiplier(output_multiplier),
+            output_exponent,
+        )
+        .unwrap();
+
+        for (&q_x, &q_y) in input.iter().zip(output.iter()) {
+            let x = (q_x as i32 - input_zero_point) as f32 * input_scale;
+            let expected = x * (x + 3.0).clamp(0.0, 6.0) / 6.0;
+            let y = (q_y as i32 - output_zero_point) as f32 * output_scale;
+            assert!(
+                (y - expected).abs() <= output_scale,
+                "expected {} for {}, but got {}",
+                expected,
+                x,
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn test_hard_swish_int8_matches_reference() {
+        // the outputs of reference_ops::HardSwish of tensorflow lite for every int8 input,
+        // whose reluish multipliers are scaled up and down respectively
+        let cases: [(f32, i32, f32, i32, [i8; 256]); 2] = [
+            (
+                6.0 / 128.0,
+                0,
+                8.0 / 256.0,
+                -96,
+                [
+                    -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96,
+                    -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96,
+                    -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96,
+                    -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96,
+                    -96, -97, -97, -98, -99, -99, -100, -101, -101, -102, -102, -103, -103, -104,
+                    -104, -105, -105, -105, -106, -106, -106, -107, -107, -107, -107, -107, -108,
+                    -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -107,
+                    -107, -107, -107, -107, -106, -106, -106, -105, -105, -105, -104, -104, -103,
+                    -103, -102, -102, -101, -101, -100, -99, -99, -98, -97, -97, -96, -95, -94,
+                    -94, -93, -92, -91, -90, -89, -88, -87, -86, -85, -84, -83, -82, -81, -80, -79,
+                    -78, -76, -75, -74, -73, -71, -70, -69, -67, -66, -64, -63, -61, -60, -58, -57,
+                    -55, -54, -52, -51, -49, -47, -46, -44, -42, -40, -39, -37, -35, -33, -31, -29,
+                    -27, -25, -23, -21, -19, -17, -15, -13, -11, -9, -7, -4, -2, 0, 1, 3, 4, 6, 7,
+                    9, 10, 12, 13, 15, 16, 18, 19, 21, 22, 24, 25, 27, 28, 30, 31, 33, 34, 36, 37,
+                    39, 40, 42, 43, 45, 46, 48, 49, 51, 52, 54, 55, 57, 58, 60, 61, 63, 64, 66, 67,
+                    69, 70, 72, 73, 75, 76, 78, 79, 81, 82, 84, 85, 87, 88, 90, 91, 93, 94,
+                ],
+            ),
+            (
+                0.005,
+                10,
+                0.003,
+                -60,
+                [
+                    -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128,
+                    -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128,
+                    -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128,
+                    -128, -128, -128, -127, -127, -126, -125, -125, -124, -124, -123, -123, -122,
+                    -121, -121, -120, -120, -119, -118, -118, -117, -117, -116, -115, -115, -114,
+                    -113, -113, -112, -112, -111, -110, -110, -109, -108, -108, -107, -106, -106,
+                    -105, -104, -104, -103, -102, -102, -101, -100, -100, -99, -98, -98, -97, -96,
+                    -95, -95, -94, -93, -93, -92, -91, -90, -90, -89, -88, -87, -87, -86, -85, -85,
+                    -84, -83, -82, -81, -81, -80, -79, -78, -78, -77, -76, -75, -75, -74, -73, -72,
+                    -71, -71, -70, -69, -68, -67, -67, -66, -65, -64, -63, -62, -62, -61, -60, -59,
+                    -58, -57, -57, -56, -55, -54, -53, -52, -52, -51, -50, -49, -48, -47, -46, -45,
+                    -45, -44, -43, -42, -41, -40, -39, -38, -37, -36, -36, -35, -34, -33, -32, -31,
+                    -30, -29, -28, -27, -26, -25, -24, -24, -23, -22, -21, -20, -19, -18, -17, -16,
+                    -15, -14, -13, -12, -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1, 0, 1, 2, 3,
+                    4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+                    26, 27, 28, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 41, 42, 43, 44, 45, 46, 47,
+                    48, 50, 51, 52, 53, 54, 55, 57,
+                ],
+            ),
+        ];
+        for (input_scale, input_zero_point, output_scale, output_zero_point, expected) in cases {
+            let hires_input_scale = (1.0f32 / 128.0) * input_scale;
+            let (output_multiplier, output_exponent) =
+                quantize_multiplier((hires_input_scale / output_scale) as f64).unwrap();
+            let (reluish_multiplier, reluish_exponent) =
+                quantize_multiplier((hires_input_scale / (3.0f32 / 32768.0)) as f64).unwrap();
+
+            let input = (-128..=127).map(|x| x as i8).collect::<Vec<i8>>();
+            let mut output = vec![0i8; input.len()];
+            OpHardSwishInt8::kernel(
+                &input,
+                &mut output,
+                input_zero_point,
+                output_zero_point,
+                OpHardSwishInt8::down_scale_int32_to_int16_multiplier(reluish_multiplier),
+                reluish_exponent,
+                OpHardSwishInt8::down_scale_int32_to_int16_multiplier(output_multiplier),
+                output_exponent,
+            )
+            .unwrap();
+            assert_eq!(expected, output[..]);
+        }
+    }
+}
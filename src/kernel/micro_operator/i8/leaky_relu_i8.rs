@@ -0,0 +1,264 @@
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::quantization::{multiply_by_quantized_multiplier, quantize_multiplier};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpLeakyReluInt8 {}
+
+impl OpLeakyReluInt8 {
+    const OPCODE: i32 = 98;
+
+    pub fn leaky_relu_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_leaky_relu_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        let alpha = builtin_option.alpha();
+
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let (input_scale, input_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let (output_scale, output_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        // This computations is corresponded to CalculateOpDataLeakyRelu
+        let alpha_multiplier = input_scale as f64 * alpha as f64 / output_scale as f64;
+        let (output_multiplier_alpha, output_shift_alpha) = quantize_multiplier(alpha_multiplier)?;
+        let identity_multiplier = input_scale as f64 / output_scale as f64;
+        let (output_multiplier_identity, output_shift_identity) =
+            quantize_multiplier(identity_multiplier)?;
+
+        Ok(BLiteBuiltinOption::QuantizedLeakyReluOptions {
+            input_offset: input_zero_point,
+            output_offset: output_zero_point,
+            output_multiplier_alpha,
+            output_shift_alpha,
+            output_multiplier_identity,
+            output_shift_identity,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let QuantizedLeakyReluOptions {
+            input_offset,
+            output_offset,
+            output_multiplier_alpha,
+            output_shift_alpha,
+            output_multiplier_identity,
+            output_shift_identity,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites the input if the memory planner gives them the same buffer
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let mut output = tensors[idx_output]._t()?.borrow_mut();
+            return Self::kernel_in_place(
+                output.data,
+                input_offset,
+                output_offset,
+                output_multiplier_alpha,
+                output_shift_alpha,
+                output_multiplier_identity,
+                output_shift_identity,
+            );
+        }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        Self::kernel(
+            input.data,
+            output.data,
+            input_offset,
+            output_offset,
+            output_multiplier_alpha,
+            output_shift_alpha,
+            output_multiplier_identity,
+            output_shift_identity,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        output_multiplier_alpha: i32,
+        output_shift_alpha: i32,
+        output_multiplier_identity: i32,
+        output_shift_identity: i32,
+    ) -> Result<()> {
+        for (output_v, &input_v) in output_data.iter_mut().zip(input_data.iter()) {
+            *output_v = Self::leaky_relu_value(
+                input_v,
+                input_offset,
+                output_offset,
+                output_multiplier_alpha,
+                output_shift_alpha,
+                output_multiplier_identity,
+                output_shift_identity,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(
+        data: &mut [T],
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        output_multiplier_alpha: i32,
+        output_shift_alpha: i32,
+        output_multiplier_identity: i32,
+        output_shift_identity: i32,
+    ) -> Result<()> {
+        for v in data.iter_mut() {
+            *v = Self::leaky_relu_value(
+                *v,
+                input_offset,
+                output_offset,
+                output_multiplier_alpha,
+                output_shift_alpha,
+                output_multiplier_identity,
+                output_shift_identity,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn leaky_relu_value<T: ArrayElem<T>>(
+        input_v: T,
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        output_multiplier_alpha: i32,
+        output_shift_alpha: i32,
+        output_multiplier_identity: i32,
+        output_shift_identity: i32,
+    ) -> Result<T> {
+        let quantized_min = AsPrimitive::<i32>::as_(T::MIN);
+        let quantized_max = AsPrimitive::<i32>::as_(T::MAX);
+        let input_v = AsPrimitive::<i32>::as_(input_v) - input_offset;
+        let mut total = if input_v >= 0 {
+            multiply_by_quantized_multiplier(
+                input_v,
+                output_multiplier_identity,
+                output_shift_identity,
+            )?
+        } else {
+            multiply_by_quantized_multiplier(input_v, output_multiplier_alpha, output_shift_alpha)?
+        };
+        total += output_offset;
+        total = max(total, quantized_min);
+        total = min(total, quantized_max);
+        Ok(FromPrimitive::from_i32(total).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpLeakyReluInt8;
+    use crate::kernel::utils::quantization::quantize_multiplier;
+
+    #[test]
+    fn test_leaky_relu_int8_alpha() {
+        let (input_scale, input_zero_point) = (0.1f32, 7);
+        let (output_scale, output_zero_point) = (0.07f32, 40);
+        let input: Vec<i8> = (-128..=127).step_by(3).collect();
+
+        // alpha of 0.3 is requantized with the input and output scales, as the identity is
+        let alpha = 0.3f32;
+        let (output_multiplier_alpha, output_shift_alpha) =
+            quantize_multiplier(input_scale as f64 * alpha as f64 / output_scale as f64).unwrap();
+        let (output_multiplier_identity, output_shift_identity) =
+            quantize_multiplier(input_scale as f64 / output_scale as f64).unwrap();
+
+        let mut output = vec![0i8; input.len()];
+        OpLeakyReluInt8::kernel(
+            &input,
+            &mut output,
+            input_zero_point,
+            output_zero_point,
+            output_multiplier_alpha,
+            output_shift_alpha,
+            output_multiplier_identity,
+            output_shift_identity,
+        )
+        .unwrap();
+
+        for (&input_v, &output_v) in input.iter().zip(output.iter()) {
+            let x = input_scale * (input_v as i32 - input_zero_point) as f32;
+            let expected = if x >= 0. { x } else { x * alpha };
+            let expected = expected.clamp(
+                output_scale * (-128 - output_zero_point) as f32,
+                output_scale * (127 - output_zero_point) as f32,
+            );
+            let actual = output_scale * (output_v as i32 - output_zero_point) as f32;
+            assert!(
+                (expected - actual).abs() <= output_scale / 2. + 1e-5,
+                "{expected} != {actual}"
+            );
+        }
+    }
+}
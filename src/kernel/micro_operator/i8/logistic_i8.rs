@@ -0,0 +1,150 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::f32::logistic::OpLogistic;
+use crate::kernel::utils::lookup_table::{lookup, lookup_table_size, populate_lookup_table};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpLogisticInt8 {}
+
+impl OpLogisticInt8 {
+    const OPCODE: i32 = 14;
+
+    pub fn logistic_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let (input_scale, input_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let (output_scale, output_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        // The table is computed once here, so that eval does not call exp per element
+        let table = unsafe { alloc_array_mut(allocator, lookup_table_size::<T>()) }?;
+        populate_lookup_table(
+            table,
+            input_scale,
+            input_zero_point,
+            output_scale,
+            output_zero_point,
+            OpLogistic::logistic_f32,
+        )?;
+
+        Ok(BLiteBuiltinOption::QuantizedLogisticOptions { table })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let QuantizedLogisticOptions { table } = builtin_option else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites the input if the memory planner gives them the same buffer
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let mut output = tensors[idx_output]._t()?.borrow_mut();
+            return Self::kernel_in_place(output.data, table);
+        }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        Self::kernel(input.data, output.data, table)
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        table: &[T],
+    ) -> Result<()> {
+        for (output_v, &input_v) in output_data.iter_mut().zip(input_data.iter()) {
+            *output_v = lookup(table, input_v);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(data: &mut [T], table: &[T]) -> Result<()> {
+        for v in data.iter_mut() {
+            *v = lookup(table, *v);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpLogisticInt8;
+    use crate::kernel::utils::lookup_table::lookup_table_size;
+    use crate::kernel::utils::testing::eval_unary_i8;
+
+    #[test]
+    fn test_logistic_int8() {
+        // the output of LOGISTIC must be quantized with the scale 1/256 and the zero point -128
+        let input = [-128i8, -60, -20, -1, 3, 4, 10, 30, 70, 127];
+        let (output, used) = eval_unary_i8(
+            OpLogisticInt8::parser::<i8>,
+            OpLogisticInt8::eval::<i8>,
+            &input,
+            (0.08, 3),
+            (1. / 256., -128),
+        )
+        .unwrap();
+        // the outputs of PopulateLookupTable of tensorflow lite, which saturate at both ends
+        assert_eq!(
+            [-128i8, -126, -93, -20, 0, 5, 35, 102, 127, 127],
+            output[..]
+        );
+        // the table of every int8 value is the only allocation in the arena
+        assert_eq!(lookup_table_size::<i8>(), used);
+    }
+}
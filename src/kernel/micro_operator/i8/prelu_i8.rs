@@ -0,0 +1,231 @@
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::broadcast::broadcast_index;
+use crate::kernel::utils::quantization::{multiply_by_quantized_multiplier, quantize_multiplier};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpPReluInt8 {}
+
+impl OpPReluInt8 {
+    const OPCODE: i32 = 54;
+
+    pub fn prelu_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let (input_scale, input_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        let alpha_idx = op.inputs().unwrap().get(1) as usize;
+        let (alpha_scale, alpha_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[alpha_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let (output_scale, output_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        // This computations is corresponded to PreluPrepare
+        let real_multiplier_1 = input_scale as f64 / output_scale as f64;
+        let (output_multiplier_1, output_shift_1) = quantize_multiplier(real_multiplier_1)?;
+        let real_multiplier_2 = input_scale as f64 * alpha_scale as f64 / output_scale as f64;
+        let (output_multiplier_2, output_shift_2) = quantize_multiplier(real_multiplier_2)?;
+
+        Ok(BLiteBuiltinOption::QuantizedPReluOptions {
+            input_offset: -input_zero_point,
+            alpha_offset: -alpha_zero_point,
+            output_offset: output_zero_point,
+            output_multiplier_1,
+            output_shift_1,
+            output_multiplier_2,
+            output_shift_2,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+
+        let idx_alpha = node.inputs[1] as usize;
+        let alpha = tensors[idx_alpha]._t()?.borrow();
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        let QuantizedPReluOptions {
+            input_offset,
+            alpha_offset,
+            output_offset,
+            output_multiplier_1,
+            output_shift_1,
+            output_multiplier_2,
+            output_shift_2,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(
+            input.data,
+            input.dims,
+            alpha.data,
+            alpha.dims,
+            output.data,
+            input_offset,
+            alpha_offset,
+            output_offset,
+            output_multiplier_1,
+            output_shift_1,
+            output_multiplier_2,
+            output_shift_2,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        input_dims: &[i32],
+        alpha_data: &[T],
+        alpha_dims: &[i32],
+        output_data: &mut [T],
+        // for quantization
+        input_offset: i32,
+        alpha_offset: i32,
+        output_offset: i32,
+        output_multiplier_1: i32,
+        output_shift_1: i32,
+        output_multiplier_2: i32,
+        output_shift_2: i32,
+    ) -> Result<()> {
+        let quantized_min = AsPrimitive::<i32>::as_(T::MIN);
+        let quantized_max = AsPrimitive::<i32>::as_(T::MAX);
+        for (i, output_v) in output_data.iter_mut().enumerate() {
+            let input_v = input_offset + AsPrimitive::<i32>::as_(input_data[i]);
+            let mut total = if input_v >= 0 {
+                multiply_by_quantized_multiplier(input_v, output_multiplier_1, output_shift_1)?
+            } else {
+                let alpha_v = alpha_offset
+                    + AsPrimitive::<i32>::as_(
+                        alpha_data[broadcast_index(i, input_dims, alpha_dims)],
+                    );
+                multiply_by_quantized_multiplier(
+                    input_v * alpha_v,
+                    output_multiplier_2,
+                    output_shift_2,
+                )?
+            };
+            total += output_offset;
+            total = max(total, quantized_min);
+            total = min(total, quantized_max);
+            *output_v = FromPrimitive::from_i32(total).unwrap();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpPReluInt8;
+    use crate::kernel::utils::quantization::quantize_multiplier;
+
+    #[test]
+    fn test_prelu_int8_alpha_broadcast() {
+        let (input_scale, input_zero_point) = (0.05f32, 10);
+        let (alpha_scale, alpha_zero_point) = (0.004f32, -20);
+        let (output_scale, output_zero_point) = (0.04f32, 30);
+        let (output_multiplier_1, output_shift_1) =
+            quantize_multiplier(input_scale as f64 / output_scale as f64).unwrap();
+        let (output_multiplier_2, output_shift_2) =
+            quantize_multiplier(input_scale as f64 * alpha_scale as f64 / output_scale as f64)
+                .unwrap();
+
+        // input of shape [2, 2, 3], whose alpha of shape [1, 3] is broadcast along the pixels
+        let input = [-128i8, -90, -50, -10, 0, 10, 30, 60, 127, -70, -30, 5];
+        let alpha = [0i8, 50, 120];
+        let mut output = [0i8; 12];
+        OpPReluInt8::kernel(
+            &input,
+            &[2, 2, 3],
+            &alpha,
+            &[1, 3],
+            &mut output,
+            -input_zero_point,
+            -alpha_zero_point,
+            output_zero_point,
+            output_multiplier_1,
+            output_shift_1,
+            output_multiplier_2,
+            output_shift_2,
+        )
+        .unwrap();
+
+        for (i, (&input_v, &output_v)) in input.iter().zip(output.iter()).enumerate() {
+            let x = input_scale * (input_v as i32 - input_zero_point) as f32;
+            let a = alpha_scale * (alpha[i % 3] as i32 - alpha_zero_point) as f32;
+            let expected = if x >= 0. { x } else { x * a };
+            let expected = expected.clamp(
+                output_scale * (-128 - output_zero_point) as f32,
+                output_scale * (127 - output_zero_point) as f32,
+            );
+            let actual = output_scale * (output_v as i32 - output_zero_point) as f32;
+            assert!(
+                (expected - actual).abs() <= output_scale / 2. + 1e-5,
+                "{i}: {expected} != {actual}"
+            );
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::utils::quantization::{multiply_by_quantized_multiplier, quantize_multiplier};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// The int8 version of `OpRelu`, whose clamping range is quantized with the output
+#[derive(Debug, Clone, Copy)]
+pub struct OpReluInt8 {}
+
+impl OpReluInt8 {
+    const RELU_OPCODE: i32 = 19;
+    const RELU_N1_TO_1_OPCODE: i32 = 20;
+    const RELU6_OPCODE: i32 = 21;
+
+    pub fn relu_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(Self::RELU_OPCODE),
+            parser: Self::relu_parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn relu_n1_to_1_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(Self::RELU_N1_TO_1_OPCODE),
+            parser: Self::relu_n1_to_1_parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn relu6_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(Self::RELU6_OPCODE),
+            parser: Self::relu6_parser,
+            min_version: 1,
+            max_version: 2,
+        }
+    }
+
+    pub fn relu_parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Self::parser(op, tensors, 1 /* Relu */)
+    }
+
+    pub fn relu_n1_to_1_parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Self::parser(op, tensors, 2 /* ReluN1To1 */)
+    }
+
+    pub fn relu6_parser<'a, T: ArrayElem<T>>(
+        _allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        Self::parser(op, tensors, 3 /* Relu6 */)
+    }
+
+    fn parser<'a, T: ArrayElem<T>>(
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+        activation: i32,
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let input_idx = op.inputs().unwrap().get(0) as usize;
+        let (input_scale, input_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let (output_scale, output_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range_quantized::<T>(
+                output_scale,
+                output_zero_point,
+                activation,
+            )?;
+
+        let real_multiplier = input_scale as f64 / output_scale as f64;
+        let (output_multiplier, output_shift) = quantize_multiplier(real_multiplier)?;
+
+        Ok(BLiteBuiltinOption::QuantizedReluOptions {
+            fused_activation_min,
+            fused_activation_max,
+            input_offset: input_zero_point,
+            output_offset: output_zero_point,
+            output_multiplier,
+            output_shift,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>(op_code: i32) -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(op_code, Self::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::MayRunInPlace)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let idx_output = node.outputs[0] as usize;
+
+        let QuantizedReluOptions {
+            fused_activation_min,
+            fused_activation_max,
+            input_offset,
+            output_offset,
+            output_multiplier,
+            output_shift,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        // the output overwrites the input if the memory planner gives them the same buffer
+        if tensors[idx_output].shares_buffer(&tensors[idx_input]) {
+            let mut output = tensors[idx_output]._t()?.borrow_mut();
+            return Self::kernel_in_place(
+                output.data,
+                input_offset,
+                output_offset,
+                output_multiplier,
+                output_shift,
+                fused_activation_min,
+                fused_activation_max,
+            );
+        }
+        let input = tensors[idx_input]._t()?.borrow();
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        Self::kernel(
+            input.data,
+            output.data,
+            input_offset,
+            output_offset,
+            output_multiplier,
+            output_shift,
+            fused_activation_min,
+            fused_activation_max,
+        )
+    }
+
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        output_multiplier: i32,
+        output_shift: i32,
+        //
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        for (output_v, &input_v) in output_data.iter_mut().zip(input_data.iter()) {
+            *output_v = Self::relu_value(
+                input_v,
+                input_offset,
+                output_offset,
+                output_multiplier,
+                output_shift,
+                fused_activation_min,
+                fused_activation_max,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn kernel_in_place<T: ArrayElem<T>>(
+        data: &mut [T],
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        output_multiplier: i32,
+        output_shift: i32,
+        //
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        for v in data.iter_mut() {
+            *v = Self::relu_value(
+                *v,
+                input_offset,
+                output_offset,
+                output_multiplier,
+                output_shift,
+                fused_activation_min,
+                fused_activation_max,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn relu_value<T: ArrayElem<T>>(
+        input_v: T,
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        output_multiplier: i32,
+        output_shift: i32,
+        //
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<T> {
+        let input_v = AsPrimitive::<i32>::as_(input_v);
+        let mut total = output_offset
+            + multiply_by_quantized_multiplier(
+                input_v - input_offset,
+                output_multiplier,
+                output_shift,
+            )?;
+        total = max(total, fused_activation_min);
+        total = min(total, fused_activation_max);
+        Ok(FromPrimitive::from_i32(total).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpReluInt8;
+    use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
+    use crate::kernel::utils::quantization::quantize_multiplier;
+
+    #[test]
+    fn test_relu_int8_clamping_ranges() {
+        let (input_scale, input_zero_point) = (0.06f32, -10);
+        let (output_scale, output_zero_point) = (0.05f32, -100);
+        let (output_multiplier, output_shift) =
+            quantize_multiplier(input_scale as f64 / output_scale as f64).unwrap();
+        let input: Vec<i8> = (-128..=127).step_by(5).collect();
+
+        for (activation, min, max) in [(1, 0., f32::MAX), (2, -1., 1.), (3, 0., 6.)] {
+            let (fused_activation_min, fused_activation_max) =
+                calculate_fused_activation_range_quantized::<i8>(
+                    output_scale,
+                    output_zero_point,
+                    activation,
+                )
+                .unwrap();
+            let mut output = vec![0i8; input.len()];
+            OpReluInt8::kernel(
+                &input,
+                &mut output,
+                input_zero_point,
+                output_zero_point,
+                output_multiplier,
+                output_shift,
+                fused_activation_min,
+                fused_activation_max,
+            )
+            .unwrap();
+
+            for (&input_v, &output_v) in input.iter().zip(output.iter()) {
+                let x = input_scale * (input_v as i32 - input_zero_point) as f32;
+                let expected = x.clamp(min, max);
+                let actual = output_scale * (output_v as i32 - output_zero_point) as f32;
+                assert!(
+                    (expected - actual).abs() <= output_scale,
+                    "activation: {activation}, {expected} != {actual}"
+                );
+            }
+            // the inputs reach both ends of the range
+            assert_eq!(fused_activation_min, *output.iter().min().unwrap() as i32);
+            if activation != 1 {
+                assert_eq!(fused_activation_max, *output.iter().max().unwrap() as i32);
+            }
+        }
+    }
+}
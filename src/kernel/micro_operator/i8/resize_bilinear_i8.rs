@@ -0,0 +1,202 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::f32::resize_bilinear::OpResizeBilinear;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::*;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpResizeBilinearInt8 {}
+
+impl OpResizeBilinearInt8 {
+    const OPCODE: i32 = 23;
+
+    pub fn resize_bilinear_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S>
+    {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 3,
+        }
+    }
+
+    /// The input and the output share the quantization parameters,
+    /// so that the options are the same as the float operator.
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        OpResizeBilinear::parser(allocator, op, tensors)
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_input = node.inputs[0] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+        let batches = input.dims[0];
+        let input_height = input.dims[1];
+        let input_width = input.dims[2];
+        let depth = input.dims[3];
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+
+        let ResizeBilinearOptions {
+            align_corners,
+            half_pixel_centers,
+            output_height,
+            output_width,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(
+            input.data,
+            output.data,
+            batches,
+            input_height,
+            input_width,
+            depth,
+            output_height,
+            output_width,
+            align_corners,
+            half_pixel_centers,
+        )
+    }
+
+    /// This kernel follows reference_ops::ResizeBilinearInteger of tensorflow lite,
+    /// which interpolates with 10 fractional bits per axis.
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        //
+        batches: i32,
+        input_height: i32,
+        input_width: i32,
+        depth: i32,
+        output_height: i32,
+        output_width: i32,
+        //
+        align_corners: bool,
+        half_pixel_centers: bool,
+    ) -> Result<()> {
+        if output_data.len() != (batches * output_height * output_width * depth) as usize {
+            return Err(NotMatchSize);
+        }
+        let height_scale_10 = Self::scale_10(input_height, output_height, align_corners);
+        let width_scale_10 = Self::scale_10(input_width, output_width, align_corners);
+
+        let mut output_idx = 0;
+        for b in 0..batches {
+            for y in 0..output_height {
+                let (input_y, y0, y1) = Self::interpolation_values(
+                    y,
+                    height_scale_10,
+                    half_pixel_centers,
+                    input_height,
+                );
+                for x in 0..output_width {
+                    let (input_x, x0, x1) = Self::interpolation_values(
+                        x,
+                        width_scale_10,
+                        half_pixel_centers,
+                        input_width,
+                    );
+                    let offset = |y: i32, x: i32| {
+                        (((b * input_height + y) * input_width + x) * depth) as usize
+                    };
+                    let (offset_00, offset_10) = (offset(y0, x0), offset(y1, x0));
+                    let (offset_01, offset_11) = (offset(y0, x1), offset(y1, x1));
+                    let dy = (input_y - (1 << 10) * y0) as i64;
+                    let dx = (input_x - (1 << 10) * x0) as i64;
+                    for c in 0..depth as usize {
+                        let v_00 = AsPrimitive::<i32>::as_(input_data[offset_00 + c]) as i64;
+                        let v_10 = AsPrimitive::<i32>::as_(input_data[offset_10 + c]) as i64;
+                        let v_01 = AsPrimitive::<i32>::as_(input_data[offset_01 + c]) as i64;
+                        let v_11 = AsPrimitive::<i32>::as_(input_data[offset_11 + c]) as i64;
+                        let output_20 = v_00 * ((1 << 10) - dy) * ((1 << 10) - dx)
+                            + v_10 * dy * ((1 << 10) - dx)
+                            + v_01 * ((1 << 10) - dy) * dx
+                            + v_11 * dy * dx;
+                        // single rounding
+                        let interpolation = ((output_20 + (1 << 19)) >> 20) as i32;
+                        output_data[output_idx] = FromPrimitive::from_i32(interpolation).unwrap();
+                        output_idx += 1;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn scale_10(input_size: i32, output_size: i32, align_corners: bool) -> i32 {
+        if align_corners && output_size > 1 {
+            ((1 << 10) * (input_size - 1) + (output_size - 1) / 2) / (output_size - 1)
+        } else {
+            ((1 << 10) * input_size + output_size / 2) / output_size
+        }
+    }
+
+    /// This computations is corresponded to ComputeInterpolationValuesInteger
+    #[inline(always)]
+    fn interpolation_values(
+        value: i32,
+        scale_10: i32,
+        half_pixel_centers: bool,
+        input_size: i32,
+    ) -> (i32, i32, i32) {
+        let scaled_value = if half_pixel_centers {
+            value * scale_10 + scale_10 / 2 - (1 << 9)
+        } else {
+            value * scale_10
+        };
+        let lower_bound = max(scaled_value / (1 << 10), 0);
+        let upper_bound = min((scaled_value + (1 << 10) - 1) / (1 << 10), input_size - 1);
+        (scaled_value, lower_bound, upper_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpResizeBilinearInt8;
+
+    #[test]
+    fn test_resize_bilinear_int8() {
+        // 1x2x2x1 is resized to 1x3x3x1 with align_corners
+        let input = [0i8, 40, 80, 120];
+        let mut output = [0i8; 9];
+        OpResizeBilinearInt8::kernel(&input, &mut output, 1, 2, 2, 1, 3, 3, true, false).unwrap();
+        assert_eq!([0, 20, 40, 40, 60, 80, 80, 100, 120], output);
+
+        // half pixel centers clamp the corners to the input
+        let mut output = [0i8; 16];
+        OpResizeBilinearInt8::kernel(&input, &mut output, 1, 2, 2, 1, 4, 4, false, true).unwrap();
+        assert_eq!(
+            [0, 10, 30, 40, 20, 30, 50, 60, 60, 70, 90, 100, 80, 90, 110, 120],
+            output
+        );
+    }
+}
@@ -1,15 +1,21 @@
 use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
 use crate::kernel::micro_operator::BLiteOperator;
-use crate::kernel::utils::quantization::{dequantize, quantize};
+use crate::kernel::utils::fixed_point::{
+    exp_on_negative_values, get_reciprocal, rounding_divide_by_pot, FixedPoint,
+};
+use crate::kernel::utils::lookup_table::lookup_table_size;
+use crate::kernel::utils::quantization::{multiply_by_quantized_multiplier, quantize_multiplier};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
 use crate::micro_context::BLiteContext;
-use crate::micro_errors::BLiteError::{self, NotFoundOption};
+use crate::micro_errors::BLiteError::{self, *};
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
 use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
 use crate::micro_tensor::BLiteTensor;
 use crate::tflite_schema_generated::tflite::Operator;
+use core::cmp::{max, min};
 use core::fmt::Debug;
 #[cfg(feature = "no_std")]
 use num_traits::Float;
@@ -20,16 +26,21 @@ pub struct OpSoftMaxInt8 {}
 
 impl OpSoftMaxInt8 {
     const OPCODE: i32 = 25;
+    // The input of exp is represented as Q5.26, and the sum of exps as Q12.19
+    const SCALED_DIFF_INTEGER_BITS: i32 = 5;
+    const ACCUMULATION_INTEGER_BITS: i32 = 12;
 
     pub fn softmax_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
         BLiteOperator {
             registration: Self::registration(),
             parser: Self::parser,
+            min_version: 1,
+            max_version: 2,
         }
     }
 
     pub fn parser<'a, T: ArrayElem<T>>(
-        _allocator: &mut impl ArenaAllocator,
+        allocator: &mut impl ArenaAllocator,
         op: Operator,
         tensors: &mut [BLiteTensor<'a, T>],
     ) -> Result<BLiteBuiltinOption<'a, T>> {
@@ -40,32 +51,75 @@ impl OpSoftMaxInt8 {
         }
 
         let input_idx = op.inputs().unwrap().get(0) as usize;
-        let (input_scale, input_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
+        let input_scale = {
+            let Some(BLiteQuantizationParams { scale, .. }) =
                 tensors[input_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
-            (scale[0], zero_point[0] as i32)
+            scale[0]
         };
 
+        // The kernel always produces outputs on the scale of 1/256 with the minimum zero point
         let output_idx = op.outputs().unwrap().get(0) as usize;
-        let (output_scale, output_zero_point) = {
-            let Some(BLiteQuantizationParams { scale, zero_point }) =
-                tensors[output_idx]._t()?.borrow().quant_params
+        {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
             else {
                 return Err(BLiteError::NotFoundQuantParams);
             };
-            (scale[0], zero_point[0] as i32)
-        };
+            let output_zero_point = zero_point[0] as i32;
+            if output_zero_point != AsPrimitive::<i32>::as_(T::MIN) {
+                return Err(NotMatchZeroPoint(output_zero_point));
+            }
+            let expected_scale = 1. / lookup_table_size::<T>() as f32;
+            if (scale[0] - expected_scale).abs() >= 0.001 * expected_scale {
+                return Err(NotMatchScale(scale[0] as f64));
+            }
+        }
+
+        // exp only depends on the difference from the row maximum, so that it is computed here once
+        let exp_table = unsafe { alloc_array_mut(allocator, lookup_table_size::<T>()) }?;
+        Self::populate_exp_table(exp_table, beta, input_scale)?;
+
+        Ok(BLiteBuiltinOption::QuantizedSoftMaxOptions { exp_table })
+    }
 
-        Ok(BLiteBuiltinOption::QuantizedSoftMaxOptions {
-            beta,
-            input_scale,
-            input_zero_point,
-            output_scale,
-            output_zero_point,
-        })
+    fn populate_exp_table(exp_table: &mut [i32], beta: f32, input_scale: f32) -> Result<()> {
+        // This computations is corresponded to PreprocessSoftmaxScaling and CalculateInputRadius
+        let max_real_multiplier = ((1i64 << 30) - 1) as f64;
+        let input_beta_real_multiplier = f64::min(
+            beta as f64
+                * input_scale as f64
+                * (1i64 << (31 - Self::SCALED_DIFF_INTEGER_BITS)) as f64,
+            max_real_multiplier,
+        );
+        let (input_multiplier, input_left_shift) = quantize_multiplier(input_beta_real_multiplier)?;
+        if input_left_shift < 0 {
+            return Err(InCompatibleCasting);
+        }
+        let max_input_rescaled = ((1i64 << Self::SCALED_DIFF_INTEGER_BITS) - 1) as f64
+            * (1i64 << (31 - Self::SCALED_DIFF_INTEGER_BITS)) as f64
+            / (1i64 << input_left_shift) as f64;
+        let diff_min = -(max_input_rescaled.floor() as i32);
+
+        for (i, exp_v) in exp_table.iter_mut().enumerate() {
+            let input_diff = -(i as i32);
+            *exp_v = if input_diff >= diff_min {
+                let input_diff_rescaled = multiply_by_quantized_multiplier(
+                    input_diff,
+                    input_multiplier,
+                    input_left_shift,
+                )?;
+                let scaled_diff =
+                    FixedPoint::from_raw(input_diff_rescaled, Self::SCALED_DIFF_INTEGER_BITS);
+                exp_on_negative_values(scaled_diff).raw
+            } else {
+                0
+            };
+        }
+        Ok(())
     }
 
     pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
@@ -84,56 +138,149 @@ impl OpSoftMaxInt8 {
         let idx_output = node.outputs[0] as usize;
         let mut output = tensors[idx_output]._t()?.borrow_mut();
 
-        let trailing_dims = input.dims.len() - 1;
-        let outer_size = input.dims[0..trailing_dims]
-            .iter()
-            .fold(1, |x, &acc| x * acc);
-        let depth = input.dims[input.dims.len() - 1];
-        let QuantizedSoftMaxOptions {
-            beta,
-            input_scale,
-            input_zero_point,
-            output_scale,
-            output_zero_point,
-        } = builtin_option
-        else {
-            return Err(NotFoundOption);
+        let depth = input.dims[input.dims.len() - 1] as usize;
+        let QuantizedSoftMaxOptions { exp_table } = builtin_option else {
+            return Err(NotCompatibleOption);
         };
 
-        for i in 0..outer_size {
-            let mut max = core::f32::MIN;
-            for c in 0..depth {
-                let input_v = AsPrimitive::<i32>::as_(input.data[(i * depth + c) as usize]);
-                let dequantize_v = dequantize(input_scale, input_zero_point, input_v)?;
-                if dequantize_v > max {
-                    max = dequantize_v;
-                }
+        Self::kernel(input.data, output.data, depth, exp_table)
+    }
+
+    /// This kernel follows reference_ops::Softmax of tensorflow lite for quantized values,
+    /// and the exps are looked up from the table computed in the parser.
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        output_data: &mut [T],
+        depth: usize,
+        // for quantization
+        exp_table: &[i32],
+    ) -> Result<()> {
+        let quantized_min = AsPrimitive::<i32>::as_(T::MIN);
+        let quantized_max = AsPrimitive::<i32>::as_(T::MAX);
+        let output_bits = lookup_table_size::<T>().trailing_zeros() as i32;
+        for (input_row, output_row) in input_data
+            .chunks_exact(depth)
+            .zip(output_data.chunks_exact_mut(depth))
+        {
+            let max_in_row = input_row
+                .iter()
+                .map(|&v| AsPrimitive::<i32>::as_(v))
+                .fold(quantized_min, max);
+
+            let mut sum_of_exps = FixedPoint::zero(Self::ACCUMULATION_INTEGER_BITS);
+            for &input_v in input_row {
+                let input_diff = AsPrimitive::<i32>::as_(input_v) - max_in_row;
+                let exp_v = FixedPoint::from_raw(exp_table[-input_diff as usize], 0);
+                sum_of_exps = sum_of_exps + exp_v.rescale(Self::ACCUMULATION_INTEGER_BITS);
             }
 
-            let mut sum: f32 = 0.0;
-            for c in 0..depth {
-                let idx = (i * depth + c) as usize;
-                let input_v = AsPrimitive::<i32>::as_(input.data[idx as usize]);
-                let dequantize_v = dequantize(input_scale, input_zero_point, input_v)?;
-                let exp_c = ((dequantize_v - max) * beta).exp();
-                let mut quantize_exp_c = quantize(output_scale, output_zero_point, exp_c)?;
-
-                quantize_exp_c = core::cmp::max(quantize_exp_c, T::MIN.as_());
-                quantize_exp_c = core::cmp::min(quantize_exp_c, T::MAX.as_());
-                output.data[idx] = FromPrimitive::from_i32(quantize_exp_c).unwrap();
-                sum = sum + exp_c;
+            let (shifted_scale, num_bits_over_unit) =
+                get_reciprocal(sum_of_exps.raw, Self::ACCUMULATION_INTEGER_BITS);
+            let shifted_scale = FixedPoint::from_raw(shifted_scale, 0);
+
+            for (output_v, &input_v) in output_row.iter_mut().zip(input_row.iter()) {
+                let input_diff = AsPrimitive::<i32>::as_(input_v) - max_in_row;
+                let exp_v = FixedPoint::from_raw(exp_table[-input_diff as usize], 0);
+                let unsat_output = rounding_divide_by_pot(
+                    (shifted_scale * exp_v).raw,
+                    num_bits_over_unit + 31 - output_bits,
+                );
+                let shifted_output = unsat_output + quantized_min;
+                let output_value = max(min(shifted_output, quantized_max), quantized_min);
+                *output_v = FromPrimitive::from_i32(output_value).unwrap();
             }
+        }
+        Ok(())
+    }
+}
 
-            for c in 0..depth {
-                let idx = (i * depth + c) as usize;
-                let out_v = AsPrimitive::<i32>::as_(output.data[idx]);
-                let dequantize_out_v = dequantize(output_scale, output_zero_point, out_v)?;
-                let v = dequantize_out_v / sum;
-                let quantize_out_v = quantize(output_scale, output_zero_point, v)?;
-                output.data[idx] = FromPrimitive::from_i32(quantize_out_v).unwrap();
+#[cfg(test)]
+mod tests {
+    use super::OpSoftMaxInt8;
+
+    #[test]
+    fn test_softmax_int8_close_to_float() {
+        let (beta, input_scale) = (1.0f32, 0.1f32);
+        let mut exp_table = vec![0i32; 256];
+        OpSoftMaxInt8::populate_exp_table(&mut exp_table, beta, input_scale).unwrap();
+
+        let depth = 8;
+        let input = (0..64)
+            .map(|x| ((x * 37) % 256 - 128) as i8)
+            .collect::<Vec<i8>>();
+        let mut output = vec![0i8; input.len()];
+        OpSoftMaxInt8::kernel(&input, &mut output, depth, &exp_table).unwrap();
+
+        for (input_row, output_row) in input.chunks(depth).zip(output.chunks(depth)) {
+            let max_in_row = *input_row.iter().max().unwrap() as f32;
+            let exps = input_row
+                .iter()
+                .map(|&x| ((x as f32 - max_in_row) * input_scale * beta).exp())
+                .collect::<Vec<f32>>();
+            let sum = exps.iter().sum::<f32>();
+            for (&exp_v, &q_y) in exps.iter().zip(output_row.iter()) {
+                let expected = exp_v / sum;
+                let y = (q_y as i32 + 128) as f32 / 256.0;
+                assert!(
+                    (y - expected).abs() <= 1.0 / 256.0,
+                    "expected {}, but got {}",
+                    expected,
+                    y
+                );
             }
         }
+    }
 
-        Ok(())
+    #[test]
+    fn test_softmax_int8_golden() {
+        // the expected outputs are computed with reference_ops::Softmax of tensorflow lite
+        // and the fixed-point arithmetic of gemmlowp for the int8 input and output
+        let tt: [(&[i8], usize, f32, f32, &[i8]); 4] = [
+            (
+                &[-128, -64, 0, 64, 127, 10, 20, 30, 5, 5, 5, 5, 5, 5, 5, 5],
+                8,
+                1.0,
+                0.1,
+                &[
+                    -128, -128, -128, -128, 127, -128, -128, -128, -96, -96, -96, -96, -96, -96,
+                    -96, -96,
+                ],
+            ),
+            (
+                &[0, 1, 2, 3, 4, -10, -20, -30, 100, 90, 80, 70],
+                4,
+                1.0,
+                0.0625,
+                &[-70, -66, -62, -58, 18, -67, -96, -111, 2, -59, -91, -108],
+            ),
+            (
+                // the differences below diff_min are out of the exp table
+                &[
+                    127, 126, 120, 112, 100, 96, 80, -128, 0, 0, 0, -1, -2, -16, -17, -18,
+                ],
+                8,
+                1.0,
+                1.0,
+                &[
+                    59, -59, -128, -128, -128, -128, -128, -128, -55, -55, -55, -101, -118, -128,
+                    -128, -128,
+                ],
+            ),
+            (
+                &[-60, -20, 10, 40, 10, 25, -5, 40],
+                8,
+                0.5,
+                0.2,
+                &[-128, -128, -123, -18, -123, -104, -127, -18],
+            ),
+        ];
+        for (input, depth, beta, input_scale, expected) in tt {
+            let mut exp_table = vec![0i32; 256];
+            OpSoftMaxInt8::populate_exp_table(&mut exp_table, beta, input_scale).unwrap();
+            let mut output = vec![0i8; input.len()];
+            OpSoftMaxInt8::kernel(input, &mut output, depth, &exp_table).unwrap();
+            assert_eq!(expected, &output[..]);
+        }
     }
 }
@@ -0,0 +1,484 @@
+use num_traits::{AsPrimitive, FromPrimitive};
+
+use crate::kernel::micro_activation::calculate_fused_activation_range_quantized;
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::f32::transpose_conv::OpTransposeConv;
+use crate::kernel::utils::calc_per_channel_multiplier_shift;
+use crate::kernel::utils::padding::compute_padding_height_width;
+use crate::kernel::utils::quantization::multiply_by_quantized_multiplier;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::BLiteError::{self, *};
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::cell::Cell;
+use core::cmp::{max, min};
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OpTransposeConvInt8 {}
+
+impl OpTransposeConvInt8 {
+    const OPCODE: i32 = 67;
+
+    pub fn transpose_conv_int8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S>
+    {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 4,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let builtin_option = op.builtin_options_as_transpose_conv_options();
+        let Some(builtin_option) = builtin_option else {
+            return Err(NotFoundOption);
+        };
+        let op_code = builtin_option.fused_activation_function().0 as i32;
+        let padding = builtin_option.padding().0 as usize;
+        let stride_w = builtin_option.stride_w();
+        let stride_h = builtin_option.stride_h();
+
+        let output_idx = op.outputs().unwrap().get(0) as usize;
+        let output_shape_idx = op.inputs().unwrap().get(0) as usize;
+        OpTransposeConv::check_output_shape(tensors, output_shape_idx, output_idx)?;
+        let output_h = tensors[output_idx]._t()?.borrow().dims[1];
+        let output_w = tensors[output_idx]._t()?.borrow().dims[2];
+        let output_ch = tensors[output_idx]._t()?.borrow().dims[3];
+        let output_size = tensors[output_idx].size();
+        let (output_scale, output_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[output_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+        let (fused_activation_min, fused_activation_max) =
+            calculate_fused_activation_range_quantized::<T>(
+                output_scale,
+                output_zero_point,
+                op_code,
+            )?;
+
+        let filter_idx = op.inputs().unwrap().get(1) as usize;
+        let filter_h = tensors[filter_idx]._t()?.borrow().dims[1];
+        let filter_w = tensors[filter_idx]._t()?.borrow().dims[2];
+        let filter_scales = {
+            let Some(BLiteQuantizationParams { scale, .. }) =
+                tensors[filter_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            scale
+        };
+
+        let input_idx = op.inputs().unwrap().get(2) as usize;
+        let input_h = tensors[input_idx]._t()?.borrow().dims[1];
+        let input_w = tensors[input_idx]._t()?.borrow().dims[2];
+        let (input_scale, input_zero_point) = {
+            let Some(BLiteQuantizationParams {
+                scale, zero_point, ..
+            }) = tensors[input_idx]._t()?.borrow().quant_params
+            else {
+                return Err(BLiteError::NotFoundQuantParams);
+            };
+            (scale[0], zero_point[0] as i32)
+        };
+
+        // The padding of a transpose convolution is the one of the convolution
+        // that maps the output back to the input
+        let (padding_h, padding_h_offset, padding_w, padding_w_offset) =
+            compute_padding_height_width(
+                padding, stride_h, stride_w, 1, 1, output_h, output_w, filter_h, filter_w, input_h,
+                input_w,
+            );
+
+        let per_channel_multiplier = unsafe { alloc_array_mut(allocator, output_ch as usize) }?;
+        let per_channel_shift = unsafe { alloc_array_mut(allocator, output_ch as usize) }?;
+        calc_per_channel_multiplier_shift(
+            input_scale,
+            filter_scales,
+            output_scale,
+            per_channel_multiplier,
+            per_channel_shift,
+        )?;
+        // a per-tensor quantized filter shares its multiplier among all the channels
+        if filter_scales.len() == 1 {
+            per_channel_multiplier.fill(per_channel_multiplier[0]);
+            per_channel_shift.fill(per_channel_shift[0]);
+        }
+
+        let scratch_buffer = unsafe { alloc_array_mut::<i32>(allocator, output_size) }?;
+        let scratch_buffer = Cell::from_mut(scratch_buffer).as_slice_of_cells();
+
+        Ok(BLiteBuiltinOption::QuantizedTransposeConvOptions {
+            op_code,
+            fused_activation_min,
+            fused_activation_max,
+            padding,
+            padding_w,
+            padding_h,
+            padding_w_offset,
+            padding_h_offset,
+            stride_w,
+            stride_h,
+            // for quantization parameters
+            input_offset: -input_zero_point,
+            output_offset: output_zero_point,
+            per_channel_multiplier,
+            per_channel_shift,
+            scratch_buffer,
+        })
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, Self::eval::<T>, NotInitialize)
+    }
+
+    pub fn eval<'a, T: ArrayElem<T>>(
+        _context: &BLiteContext,
+        tensors: &'a mut [BLiteTensor<'a, T>],
+        node: &BLiteNode<'a>,
+        builtin_option: BLiteBuiltinOption<T>,
+    ) -> Result<()> {
+        let idx_filter = node.inputs[1] as usize;
+        let filter = tensors[idx_filter]._t()?.borrow();
+        let filter_height = filter.dims[1];
+        let filter_width = filter.dims[2];
+
+        let idx_input = node.inputs[2] as usize;
+        let input = tensors[idx_input]._t()?.borrow();
+        let input_height = input.dims[1];
+        let input_width = input.dims[2];
+        let input_depth = input.dims[3];
+
+        // the bias is optional
+        let bias = match node.inputs.get(3) {
+            Some(&idx_bias) if idx_bias >= 0 => Some(tensors[idx_bias as usize]._i32()?.borrow()),
+            _ => None,
+        };
+
+        let idx_output = node.outputs[0] as usize;
+        let mut output = tensors[idx_output]._t()?.borrow_mut();
+        let output_height = output.dims[1];
+        let output_width = output.dims[2];
+        let output_depth = output.dims[3];
+
+        let batches = input.dims[0] as usize;
+
+        let QuantizedTransposeConvOptions {
+            op_code: _,
+            fused_activation_min,
+            fused_activation_max,
+            padding: _,
+            padding_w,
+            padding_h,
+            padding_w_offset: _,
+            padding_h_offset: _,
+            stride_w,
+            stride_h,
+            // for quantization
+            input_offset,
+            output_offset,
+            per_channel_multiplier,
+            per_channel_shift,
+            scratch_buffer,
+        } = builtin_option
+        else {
+            return Err(NotCompatibleOption);
+        };
+
+        Self::kernel(
+            input.data,
+            filter.data,
+            bias.as_ref().map(|bias| &*bias.data),
+            output.data,
+            input_height,
+            input_width,
+            input_depth,
+            filter_height,
+            filter_width,
+            output_height,
+            output_width,
+            output_depth,
+            stride_w,
+            stride_h,
+            padding_w,
+            padding_h,
+            // for quantization
+            input_offset,
+            output_offset,
+            per_channel_multiplier,
+            per_channel_shift,
+            scratch_buffer,
+            batches,
+            fused_activation_min,
+            fused_activation_max,
+        )
+    }
+
+    /// This kernel follows reference_integer_ops::TransposeConv of tensorflow lite,
+    /// which accumulates the scattered products in the int32 scratch buffer.
+    #[inline(always)]
+    pub fn kernel<T: ArrayElem<T>>(
+        input_data: &[T],
+        filter_data: &[T],
+        bias_data: Option<&[i32]>,
+        output_data: &mut [T],
+        //
+        input_height: i32,
+        input_width: i32,
+        input_depth: i32,
+        filter_height: i32,
+        filter_width: i32,
+        output_height: i32,
+        output_width: i32,
+        output_depth: i32,
+        //
+        stride_w: i32,
+        stride_h: i32,
+        padding_w: i32,
+        padding_h: i32,
+        // for quantization
+        input_offset: i32,
+        output_offset: i32,
+        per_channel_multiplier: &[i32],
+        per_channel_shift: &[i32],
+        scratch_buffer: &[Cell<i32>],
+        //
+        batches: usize,
+        fused_activation_min: i32,
+        fused_activation_max: i32,
+    ) -> Result<()> {
+        if scratch_buffer.len() != output_data.len() {
+            return Err(NotMatchSize);
+        }
+        for acc in scratch_buffer {
+            acc.set(0);
+        }
+
+        for batch in 0..batches as i32 {
+            for in_y in 0..input_height {
+                let out_y_origin = (in_y * stride_h) - padding_h;
+                for in_x in 0..input_width {
+                    let out_x_origin = (in_x * stride_w) - padding_w;
+                    for in_channel in 0..input_depth {
+                        let input_v_idx = Self::offset(
+                            input_height,
+                            input_width,
+                            input_depth,
+                            batch,
+                            in_y,
+                            in_x,
+                            in_channel,
+                        );
+                        let input_v = AsPrimitive::<i32>::as_(input_data[input_v_idx as usize]);
+                        for filter_y in 0..filter_height {
+                            let out_y = out_y_origin + filter_y;
+                            for filter_x in 0..filter_width {
+                                let out_x = out_x_origin + filter_x;
+                                let is_point_inside_image = (out_x >= 0)
+                                    && (out_x < output_width)
+                                    && (out_y >= 0)
+                                    && (out_y < output_height);
+                                if !is_point_inside_image {
+                                    continue;
+                                }
+
+                                for out_channel in 0..output_depth {
+                                    let filter_v_idx = Self::offset(
+                                        filter_height,
+                                        filter_width,
+                                        input_depth,
+                                        out_channel,
+                                        filter_y,
+                                        filter_x,
+                                        in_channel,
+                                    );
+                                    let filter_v =
+                                        AsPrimitive::<i32>::as_(filter_data[filter_v_idx as usize]);
+                                    let output_v_idx = Self::offset(
+                                        output_height,
+                                        output_width,
+                                        output_depth,
+                                        batch,
+                                        out_y,
+                                        out_x,
+                                        out_channel,
+                                    );
+                                    let acc = &scratch_buffer[output_v_idx as usize];
+                                    acc.set(acc.get() + (input_v + input_offset) * filter_v);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for (output_pixel, acc_pixel) in output_data
+            .chunks_exact_mut(output_depth as usize)
+            .zip(scratch_buffer.chunks_exact(output_depth as usize))
+        {
+            for (out_channel, (output_v, acc)) in
+                output_pixel.iter_mut().zip(acc_pixel.iter()).enumerate()
+            {
+                let mut total = acc.get();
+                if let Some(bias_data) = bias_data {
+                    total += bias_data[out_channel];
+                }
+                total = multiply_by_quantized_multiplier(
+                    total,
+                    per_channel_multiplier[out_channel],
+                    per_channel_shift[out_channel],
+                )?;
+                total += output_offset;
+                total = max(total, fused_activation_min);
+                total = min(total, fused_activation_max);
+                *output_v = FromPrimitive::from_i32(total).unwrap();
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn offset(h: i32, w: i32, d: i32, i0: i32, i1: i32, i2: i32, i3: i32) -> i32 {
+        ((i0 * h + i1) * w + i2) * d + i3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpTransposeConvInt8;
+    use crate::kernel::micro_operator::f32::transpose_conv::OpTransposeConv;
+    use crate::kernel::utils::calc_per_channel_multiplier_shift;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_transpose_conv_int8_per_channel_close_to_float() {
+        // 1x3x3x2 is upsampled to 1x5x5x3 by a 3x3 filter with the stride 2 and the padding 1
+        const INPUT_D: i32 = 2;
+        const OUTPUT_D: i32 = 3;
+        let (input_scale, input_zero_point) = (0.05f32, -10);
+        let filter_scales = [0.01f32, 0.02, 0.005];
+        let (output_scale, output_zero_point) = (0.12f32, 5);
+
+        let mut seed = 11u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) as i8
+        };
+        let input: Vec<i8> = (0..3 * 3 * INPUT_D).map(|_| next()).collect();
+        let filter: Vec<i8> = (0..OUTPUT_D * 3 * 3 * INPUT_D).map(|_| next()).collect();
+        let bias = [300i32, -150, 2000];
+
+        let mut per_channel_multiplier = [0i32; OUTPUT_D as usize];
+        let mut per_channel_shift = [0i32; OUTPUT_D as usize];
+        calc_per_channel_multiplier_shift(
+            input_scale,
+            &filter_scales,
+            output_scale,
+            &mut per_channel_multiplier,
+            &mut per_channel_shift,
+        )
+        .unwrap();
+        let mut scratch_buffer = [0i32; (5 * 5 * OUTPUT_D) as usize];
+        let scratch_buffer = Cell::from_mut(&mut scratch_buffer[..]).as_slice_of_cells();
+        let mut output = [0i8; (5 * 5 * OUTPUT_D) as usize];
+        OpTransposeConvInt8::kernel(
+            &input,
+            &filter,
+            Some(&bias),
+            &mut output,
+            3,
+            3,
+            INPUT_D,
+            3,
+            3,
+            5,
+            5,
+            OUTPUT_D,
+            2,
+            2,
+            1,
+            1,
+            -input_zero_point,
+            output_zero_point,
+            &per_channel_multiplier,
+            &per_channel_shift,
+            scratch_buffer,
+            1,
+            i8::MIN as i32,
+            i8::MAX as i32,
+        )
+        .unwrap();
+
+        // the float kernel runs on the dequantized values
+        let input_f32: Vec<f32> = input
+            .iter()
+            .map(|&x| (x as i32 - input_zero_point) as f32 * input_scale)
+            .collect();
+        let filter_f32: Vec<f32> = filter
+            .chunks((3 * 3 * INPUT_D) as usize)
+            .zip(filter_scales)
+            .flat_map(|(channel, scale)| channel.iter().map(move |&x| x as f32 * scale))
+            .collect();
+        let bias_f32: Vec<f32> = bias
+            .iter()
+            .zip(filter_scales)
+            .map(|(&x, scale)| x as f32 * input_scale * scale)
+            .collect();
+        let mut expected = [0.0f32; (5 * 5 * OUTPUT_D) as usize];
+        OpTransposeConv::kernel(
+            &input_f32,
+            &filter_f32,
+            Some(&bias_f32),
+            &mut expected,
+            3,
+            3,
+            INPUT_D,
+            3,
+            3,
+            5,
+            5,
+            OUTPUT_D,
+            2,
+            2,
+            1,
+            1,
+            1,
+            f32::MIN,
+            f32::MAX,
+        )
+        .unwrap();
+
+        for (&q_y, &expected) in output.iter().zip(expected.iter()) {
+            let expected = expected.clamp(
+                (i8::MIN as i32 - output_zero_point) as f32 * output_scale,
+                (i8::MAX as i32 - output_zero_point) as f32 * output_scale,
+            );
+            let y = (q_y as i32 - output_zero_point) as f32 * output_scale;
+            assert!(
+                (y - expected).abs() <= output_scale,
+                "expected {}, but got {}",
+                expected,
+                y
+            );
+        }
+    }
+}
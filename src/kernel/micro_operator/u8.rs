@@ -0,0 +1,7 @@
+pub mod avg_pool2d_u8;
+pub mod conv2d_u8;
+pub mod depthwise_conv2d_u8;
+pub mod fully_connected_u8;
+pub mod max_pool2d_u8;
+pub mod reshape_u8;
+pub mod softmax_u8;
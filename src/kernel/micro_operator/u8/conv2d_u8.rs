@@ -0,0 +1,158 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8;
+use crate::kernel::utils::check_uint8_zero_points;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::ParallelElem;
+use crate::micro_errors::Result;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// CONV_2D of the legacy uint8 quantization, whose tensors including the filter are quantized
+/// per-tensor with zero points in [0, 255]. It shares the kernel with OpConv2DInt8
+#[derive(Debug, Clone, Copy)]
+pub struct OpConv2DUInt8 {}
+
+impl OpConv2DUInt8 {
+    const OPCODE: i32 = 3;
+
+    pub fn conv2d_uint8<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let inputs = op.inputs().unwrap();
+        let output_idx = op.outputs().unwrap().get(0);
+        check_uint8_zero_points(tensors, &[inputs.get(0), inputs.get(1), output_idx])?;
+
+        OpConv2DInt8::parser(allocator, op, tensors)
+    }
+
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, OpConv2DInt8::eval::<T>, NotInitialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpConv2DUInt8;
+    use crate::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8;
+    use crate::kernel::utils::testing::{eval_quantized, Tensor};
+    use crate::micro_errors::{BLiteError, Result};
+    use crate::tflite_schema_generated::tflite::{
+        BuiltinOptions, Conv2DOptions, Conv2DOptionsArgs, Padding,
+    };
+    use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+
+    const INPUT_SCALE: f32 = 1. / 16.;
+    const FILTER_SCALE: f32 = 1. / 32.;
+    const OUTPUT_SCALE: f32 = 1. / 2.;
+
+    fn conv2d_options(
+        fbb: &mut FlatBufferBuilder<'_>,
+    ) -> (BuiltinOptions, WIPOffset<UnionWIPOffset>) {
+        let options = Conv2DOptions::create(
+            fbb,
+            &Conv2DOptionsArgs {
+                padding: Padding::VALID,
+                stride_w: 1,
+                stride_h: 1,
+                dilation_w_factor: 1,
+                dilation_h_factor: 1,
+                ..Default::default()
+            },
+        );
+        (BuiltinOptions::Conv2DOptions, options.as_union_value())
+    }
+
+    /// Runs CONV_2D of the input 1x3x3x2 and the filter 2x2x2x2 without the padding,
+    /// whose tensors have the zero points `zero_points`
+    fn conv2d_uint8(
+        input: &Tensor<u8>,
+        filter: &Tensor<u8>,
+        bias: &Tensor<i32>,
+        zero_points: [i64; 3],
+    ) -> Result<Vec<u8>> {
+        eval_quantized(
+            OpConv2DUInt8::parser::<u8>,
+            OpConv2DInt8::eval::<u8>,
+            Some(conv2d_options),
+            &[
+                (input, (INPUT_SCALE, zero_points[0])),
+                (filter, (FILTER_SCALE, zero_points[1])),
+            ],
+            Some((bias, (INPUT_SCALE * FILTER_SCALE, 0))),
+            &[1, 2, 2, 2],
+            (OUTPUT_SCALE, zero_points[2]),
+        )
+    }
+
+    #[test]
+    fn test_conv2d_uint8() {
+        let input = Tensor::from(
+            (0..18).map(|i| (i * 97 % 256) as u8).collect(),
+            vec![1, 3, 3, 2],
+        );
+        let filter = Tensor::from(
+            (0..16).map(|i| (i * 61 % 256) as u8).collect(),
+            vec![2, 2, 2, 2],
+        );
+        let bias = Tensor::from(vec![300, -2000], vec![2]);
+        let (input_zero_point, filter_zero_point, output_zero_point) = (128, 120, 128);
+        let output = conv2d_uint8(
+            &input,
+            &filter,
+            &bias,
+            [input_zero_point, filter_zero_point, output_zero_point],
+        )
+        .unwrap();
+
+        for (i, &output_v) in output.iter().enumerate() {
+            let (y, x, out_d) = (i / 4, i / 2 % 2, i % 2);
+            let mut expected = bias.data[out_d] as f32 * INPUT_SCALE * FILTER_SCALE;
+            for (fy, fx, in_d) in (0..8).map(|k| (k / 4, k / 2 % 2, k % 2)) {
+                let input_v = input.data[((y + fy) * 3 + x + fx) * 2 + in_d] as i64;
+                let filter_v = filter.data[((out_d * 2 + fy) * 2 + fx) * 2 + in_d] as i64;
+                expected += (input_v - input_zero_point) as f32
+                    * INPUT_SCALE
+                    * (filter_v - filter_zero_point) as f32
+                    * FILTER_SCALE;
+            }
+            let expected = (expected / OUTPUT_SCALE).round() + output_zero_point as f32;
+            let expected = expected.clamp(0., 255.);
+            assert!(
+                (expected - output_v as f32).abs() <= 1.,
+                "expected {expected}, but got {output_v}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_conv2d_uint8_rejects_zero_point_out_of_range() {
+        let input = Tensor::zeros(&[1, 3, 3, 2]);
+        let filter = Tensor::zeros(&[2, 2, 2, 2]);
+        let bias = Tensor::zeros(&[2]);
+        for (zero_points, zero_point) in [([256, 0, 0], 256), ([0, -1, 0], -1), ([0, 0, 300], 300)]
+        {
+            let result = conv2d_uint8(&input, &filter, &bias, zero_points);
+            assert!(
+                matches!(result, Err(BLiteError::NotMatchZeroPoint(z)) if z == zero_point),
+                "{result:?}"
+            );
+        }
+    }
+}
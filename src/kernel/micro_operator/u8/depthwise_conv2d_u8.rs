@@ -0,0 +1,167 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::i8::depthwise_conv2d_i8::OpDepthWiseConv2DInt8;
+use crate::kernel::utils::check_uint8_zero_points;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::ParallelElem;
+use crate::micro_errors::Result;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// DEPTHWISE_CONV_2D of the legacy uint8 quantization, whose tensors including the filter are quantized
+/// per-tensor with zero points in [0, 255]. It shares the kernel with OpDepthWiseConv2DInt8
+#[derive(Debug, Clone, Copy)]
+pub struct OpDepthWiseConv2DUInt8 {}
+
+impl OpDepthWiseConv2DUInt8 {
+    const OPCODE: i32 = 4;
+
+    pub fn depthwise_conv2d_uint8<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let inputs = op.inputs().unwrap();
+        let output_idx = op.outputs().unwrap().get(0);
+        check_uint8_zero_points(tensors, &[inputs.get(0), inputs.get(1), output_idx])?;
+
+        OpDepthWiseConv2DInt8::parser(allocator, op, tensors)
+    }
+
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(
+            Self::OPCODE,
+            OpDepthWiseConv2DInt8::eval::<T>,
+            NotInitialize,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpDepthWiseConv2DUInt8;
+    use crate::kernel::micro_operator::i8::depthwise_conv2d_i8::OpDepthWiseConv2DInt8;
+    use crate::kernel::utils::testing::{eval_quantized, Tensor};
+    use crate::micro_errors::{BLiteError, Result};
+    use crate::tflite_schema_generated::tflite::{
+        BuiltinOptions, DepthwiseConv2DOptions, DepthwiseConv2DOptionsArgs, Padding,
+    };
+    use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+
+    const INPUT_SCALE: f32 = 1. / 16.;
+    const FILTER_SCALE: f32 = 1. / 32.;
+    const OUTPUT_SCALE: f32 = 1. / 2.;
+
+    fn depthwise_conv2d_options(
+        fbb: &mut FlatBufferBuilder<'_>,
+    ) -> (BuiltinOptions, WIPOffset<UnionWIPOffset>) {
+        let options = DepthwiseConv2DOptions::create(
+            fbb,
+            &DepthwiseConv2DOptionsArgs {
+                padding: Padding::VALID,
+                stride_w: 1,
+                stride_h: 1,
+                depth_multiplier: 1,
+                dilation_w_factor: 1,
+                dilation_h_factor: 1,
+                ..Default::default()
+            },
+        );
+        (
+            BuiltinOptions::DepthwiseConv2DOptions,
+            options.as_union_value(),
+        )
+    }
+
+    /// Runs DEPTHWISE_CONV_2D of the input 1x3x3x2 and the filter 1x2x2x2 without the padding,
+    /// whose tensors have the zero points `zero_points`
+    fn depthwise_conv2d_uint8(
+        input: &Tensor<u8>,
+        filter: &Tensor<u8>,
+        bias: &Tensor<i32>,
+        zero_points: [i64; 3],
+    ) -> Result<Vec<u8>> {
+        eval_quantized(
+            OpDepthWiseConv2DUInt8::parser::<u8>,
+            OpDepthWiseConv2DInt8::eval::<u8>,
+            Some(depthwise_conv2d_options),
+            &[
+                (input, (INPUT_SCALE, zero_points[0])),
+                (filter, (FILTER_SCALE, zero_points[1])),
+            ],
+            Some((bias, (INPUT_SCALE * FILTER_SCALE, 0))),
+            &[1, 2, 2, 2],
+            (OUTPUT_SCALE, zero_points[2]),
+        )
+    }
+
+    #[test]
+    fn test_depthwise_conv2d_uint8() {
+        let input = Tensor::from(
+            (0..18).map(|i| (i * 97 % 256) as u8).collect(),
+            vec![1, 3, 3, 2],
+        );
+        let filter = Tensor::from(
+            (0..8).map(|i| (i * 61 % 256) as u8).collect(),
+            vec![1, 2, 2, 2],
+        );
+        let bias = Tensor::from(vec![300, -2000], vec![2]);
+        let (input_zero_point, filter_zero_point, output_zero_point) = (128, 120, 128);
+        let output = depthwise_conv2d_uint8(
+            &input,
+            &filter,
+            &bias,
+            [input_zero_point, filter_zero_point, output_zero_point],
+        )
+        .unwrap();
+
+        for (i, &output_v) in output.iter().enumerate() {
+            let (y, x, d) = (i / 4, i / 2 % 2, i % 2);
+            let mut expected = bias.data[d] as f32 * INPUT_SCALE * FILTER_SCALE;
+            for (fy, fx) in (0..4).map(|k| (k / 2, k % 2)) {
+                let input_v = input.data[((y + fy) * 3 + x + fx) * 2 + d] as i64;
+                let filter_v = filter.data[(fy * 2 + fx) * 2 + d] as i64;
+                expected += (input_v - input_zero_point) as f32
+                    * INPUT_SCALE
+                    * (filter_v - filter_zero_point) as f32
+                    * FILTER_SCALE;
+            }
+            let expected = (expected / OUTPUT_SCALE).round() + output_zero_point as f32;
+            let expected = expected.clamp(0., 255.);
+            assert!(
+                (expected - output_v as f32).abs() <= 1.,
+                "expected {expected}, but got {output_v}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_depthwise_conv2d_uint8_rejects_zero_point_out_of_range() {
+        let input = Tensor::zeros(&[1, 3, 3, 2]);
+        let filter = Tensor::zeros(&[1, 2, 2, 2]);
+        let bias = Tensor::zeros(&[2]);
+        for (zero_points, zero_point) in
+            [([-1, 0, 0], -1), ([0, 256, 0], 256), ([0, 0, -128], -128)]
+        {
+            let result = depthwise_conv2d_uint8(&input, &filter, &bias, zero_points);
+            assert!(
+                matches!(result, Err(BLiteError::NotMatchZeroPoint(z)) if z == zero_point),
+                "{result:?}"
+            );
+        }
+    }
+}
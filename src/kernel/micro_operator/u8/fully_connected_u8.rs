@@ -0,0 +1,145 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8;
+use crate::kernel::utils::check_uint8_zero_points;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_context::ParallelElem;
+use crate::micro_errors::Result;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// FULLY_CONNECTED of the legacy uint8 quantization, whose tensors including the filter are quantized
+/// per-tensor with zero points in [0, 255]. It shares the kernel with OpFullyConnectedInt8
+#[derive(Debug, Clone, Copy)]
+pub struct OpFullyConnectedUInt8 {}
+
+impl OpFullyConnectedUInt8 {
+    const OPCODE: i32 = 9;
+
+    pub fn fully_connected_uint8<'a, T: ArrayElem<T> + ParallelElem, S: ArenaAllocator>(
+    ) -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let inputs = op.inputs().unwrap();
+        let output_idx = op.outputs().unwrap().get(0);
+        check_uint8_zero_points(tensors, &[inputs.get(0), inputs.get(1), output_idx])?;
+
+        OpFullyConnectedInt8::parser(allocator, op, tensors)
+    }
+
+    pub fn registration<'a, T: ArrayElem<T> + ParallelElem>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, OpFullyConnectedInt8::eval::<T>, NotInitialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpFullyConnectedUInt8;
+    use crate::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8;
+    use crate::kernel::utils::testing::{eval_quantized, Tensor};
+    use crate::micro_errors::{BLiteError, Result};
+    use crate::tflite_schema_generated::tflite::{
+        BuiltinOptions, FullyConnectedOptions, FullyConnectedOptionsArgs,
+    };
+    use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+
+    const INPUT_SCALE: f32 = 1. / 16.;
+    const FILTER_SCALE: f32 = 1. / 32.;
+    const OUTPUT_SCALE: f32 = 1. / 2.;
+
+    fn fully_connected_options(
+        fbb: &mut FlatBufferBuilder<'_>,
+    ) -> (BuiltinOptions, WIPOffset<UnionWIPOffset>) {
+        let options = FullyConnectedOptions::create(fbb, &FullyConnectedOptionsArgs::default());
+        (
+            BuiltinOptions::FullyConnectedOptions,
+            options.as_union_value(),
+        )
+    }
+
+    /// Runs FULLY_CONNECTED of the input 1x4 and the filter 3x4,
+    /// whose tensors have the zero points `zero_points`
+    fn fully_connected_uint8(
+        input: &Tensor<u8>,
+        filter: &Tensor<u8>,
+        bias: &Tensor<i32>,
+        zero_points: [i64; 3],
+    ) -> Result<Vec<u8>> {
+        eval_quantized(
+            OpFullyConnectedUInt8::parser::<u8>,
+            OpFullyConnectedInt8::eval::<u8>,
+            Some(fully_connected_options),
+            &[
+                (input, (INPUT_SCALE, zero_points[0])),
+                (filter, (FILTER_SCALE, zero_points[1])),
+            ],
+            Some((bias, (INPUT_SCALE * FILTER_SCALE, 0))),
+            &[1, 3],
+            (OUTPUT_SCALE, zero_points[2]),
+        )
+    }
+
+    #[test]
+    fn test_fully_connected_uint8() {
+        let input = Tensor::from(vec![3, 250, 97, 160], vec![1, 4]);
+        let filter = Tensor::from((0..12).map(|i| (i * 61 % 256) as u8).collect(), vec![3, 4]);
+        let bias = Tensor::from(vec![300, -2000, 512], vec![3]);
+        let (input_zero_point, filter_zero_point, output_zero_point) = (128, 120, 128);
+        let output = fully_connected_uint8(
+            &input,
+            &filter,
+            &bias,
+            [input_zero_point, filter_zero_point, output_zero_point],
+        )
+        .unwrap();
+
+        for (out_d, &output_v) in output.iter().enumerate() {
+            let mut expected = bias.data[out_d] as f32 * INPUT_SCALE * FILTER_SCALE;
+            for d in 0..4 {
+                let input_v = input.data[d] as i64;
+                let filter_v = filter.data[out_d * 4 + d] as i64;
+                expected += (input_v - input_zero_point) as f32
+                    * INPUT_SCALE
+                    * (filter_v - filter_zero_point) as f32
+                    * FILTER_SCALE;
+            }
+            let expected = (expected / OUTPUT_SCALE).round() + output_zero_point as f32;
+            let expected = expected.clamp(0., 255.);
+            assert!(
+                (expected - output_v as f32).abs() <= 1.,
+                "expected {expected}, but got {output_v}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_fully_connected_uint8_rejects_zero_point_out_of_range() {
+        let input = Tensor::zeros(&[1, 4]);
+        let filter = Tensor::zeros(&[3, 4]);
+        let bias = Tensor::zeros(&[3]);
+        for (zero_points, zero_point) in
+            [([256, 0, 0], 256), ([0, -1, 0], -1), ([0, 0, 1000], 1000)]
+        {
+            let result = fully_connected_uint8(&input, &filter, &bias, zero_points);
+            assert!(
+                matches!(result, Err(BLiteError::NotMatchZeroPoint(z)) if z == zero_point),
+                "{result:?}"
+            );
+        }
+    }
+}
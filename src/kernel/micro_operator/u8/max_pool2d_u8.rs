@@ -0,0 +1,119 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::i8::max_pool2d_i8::OpMaxPool2DInt8;
+use crate::kernel::utils::check_uint8_zero_points;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_errors::Result;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// MAX_POOL_2D of the legacy uint8 quantization, whose tensors are quantized
+/// per-tensor with zero points in [0, 255]. It shares the kernel with OpMaxPool2DInt8
+#[derive(Debug, Clone, Copy)]
+pub struct OpMaxPool2DUInt8 {}
+
+impl OpMaxPool2DUInt8 {
+    const OPCODE: i32 = 17;
+
+    pub fn max_pool2d_uint8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let inputs = op.inputs().unwrap();
+        let output_idx = op.outputs().unwrap().get(0);
+        check_uint8_zero_points(tensors, &[inputs.get(0), output_idx])?;
+
+        OpMaxPool2DInt8::parser(allocator, op, tensors)
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, OpMaxPool2DInt8::eval::<T>, NotInitialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpMaxPool2DUInt8;
+    use crate::kernel::micro_operator::i8::max_pool2d_i8::OpMaxPool2DInt8;
+    use crate::kernel::utils::testing::{eval_quantized, Tensor};
+    use crate::micro_errors::{BLiteError, Result};
+    use crate::tflite_schema_generated::tflite::{
+        BuiltinOptions, Padding, Pool2DOptions, Pool2DOptionsArgs,
+    };
+    use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+
+    fn pool2d_options(
+        fbb: &mut FlatBufferBuilder<'_>,
+    ) -> (BuiltinOptions, WIPOffset<UnionWIPOffset>) {
+        let options = Pool2DOptions::create(
+            fbb,
+            &Pool2DOptionsArgs {
+                padding: Padding::VALID,
+                stride_w: 2,
+                stride_h: 2,
+                filter_width: 2,
+                filter_height: 2,
+                ..Default::default()
+            },
+        );
+        (BuiltinOptions::Pool2DOptions, options.as_union_value())
+    }
+
+    /// Runs MAX_POOL_2D of the input 1x4x4x2 with the filter 2x2 and the stride 2,
+    /// whose input and output have the zero points `zero_points`
+    fn max_pool2d_uint8(input: &Tensor<u8>, zero_points: [i64; 2]) -> Result<Vec<u8>> {
+        eval_quantized(
+            OpMaxPool2DUInt8::parser::<u8>,
+            OpMaxPool2DInt8::eval::<u8>,
+            Some(pool2d_options),
+            &[(input, (0.5, zero_points[0]))],
+            None,
+            &[1, 2, 2, 2],
+            (0.5, zero_points[1]),
+        )
+    }
+
+    #[test]
+    fn test_max_pool2d_uint8() {
+        let input = Tensor::from(
+            (0..32).map(|i| (i * 97 % 256) as u8).collect(),
+            vec![1, 4, 4, 2],
+        );
+        let output = max_pool2d_uint8(&input, [128, 128]).unwrap();
+
+        for (i, &output_v) in output.iter().enumerate() {
+            let (y, x, d) = (i / 4, i / 2 % 2, i % 2);
+            let max = (0..4)
+                .map(|k| input.data[((2 * y + k / 2) * 4 + 2 * x + k % 2) * 2 + d])
+                .max()
+                .unwrap();
+            assert_eq!(max, output_v);
+        }
+    }
+
+    #[test]
+    fn test_max_pool2d_uint8_rejects_zero_point_out_of_range() {
+        let input = Tensor::zeros(&[1, 4, 4, 2]);
+        for (zero_points, zero_point) in [([-128, 0], -128), ([0, 256], 256)] {
+            let result = max_pool2d_uint8(&input, zero_points);
+            assert!(
+                matches!(result, Err(BLiteError::NotMatchZeroPoint(z)) if z == zero_point),
+                "{result:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::i8::reshape_i8::OpReshapeInt8;
+use crate::kernel::utils::check_uint8_zero_points;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_errors::Result;
+use crate::micro_registration::{BLiteInPlace, BLiteRegistration};
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// RESHAPE of the legacy uint8 quantization, whose tensors are quantized
+/// per-tensor with zero points in [0, 255]. It shares the kernel with OpReshapeInt8
+#[derive(Debug, Clone, Copy)]
+pub struct OpReshapeUInt8 {}
+
+impl OpReshapeUInt8 {
+    const OPCODE: i32 = 22;
+
+    pub fn reshape_uint8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let inputs = op.inputs().unwrap();
+        let output_idx = op.outputs().unwrap().get(0);
+        check_uint8_zero_points(tensors, &[inputs.get(0), output_idx])?;
+
+        OpReshapeInt8::parser(allocator, op, tensors)
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, OpReshapeInt8::eval::<T>, NotInitialize)
+            .with_in_place(BLiteInPlace::OutputAliasesInput)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpReshapeUInt8;
+    use crate::kernel::micro_operator::i8::reshape_i8::OpReshapeInt8;
+    use crate::kernel::utils::testing::{eval_quantized, Tensor};
+    use crate::micro_errors::{BLiteError, Result};
+
+    /// Runs RESHAPE of the input 1x2x3 to the output 3x2,
+    /// whose input and output have the zero points `zero_points`
+    fn reshape_uint8(input: &Tensor<u8>, zero_points: [i64; 2]) -> Result<Vec<u8>> {
+        eval_quantized(
+            OpReshapeUInt8::parser::<u8>,
+            OpReshapeInt8::eval::<u8>,
+            None,
+            &[(input, (0.5, zero_points[0]))],
+            None,
+            &[3, 2],
+            (0.5, zero_points[1]),
+        )
+    }
+
+    #[test]
+    fn test_reshape_uint8() {
+        let input = Tensor::from(vec![0, 17, 128, 200, 254, 255], vec![1, 2, 3]);
+        let output = reshape_uint8(&input, [128, 128]).unwrap();
+        assert_eq!(input.data, output);
+    }
+
+    #[test]
+    fn test_reshape_uint8_rejects_zero_point_out_of_range() {
+        let input = Tensor::zeros(&[1, 2, 3]);
+        for (zero_points, zero_point) in [([-1, -1], -1), ([0, 256], 256)] {
+            let result = reshape_uint8(&input, zero_points);
+            assert!(
+                matches!(result, Err(BLiteError::NotMatchZeroPoint(z)) if z == zero_point),
+                "{result:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::kernel::micro_builtin_options::{BLiteBuiltinOption, BLiteBuiltinOption::*};
+use crate::kernel::micro_operator::i8::softmax_i8::OpSoftMaxInt8;
+use crate::kernel::utils::check_uint8_zero_points;
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_errors::Result;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::Operator;
+use core::fmt::Debug;
+
+use crate::kernel::micro_operator::BLiteOperator;
+
+/// SOFTMAX of the legacy uint8 quantization, whose tensors are quantized
+/// per-tensor with zero points in [0, 255]. It shares the kernel with OpSoftMaxInt8
+#[derive(Debug, Clone, Copy)]
+pub struct OpSoftMaxUInt8 {}
+
+impl OpSoftMaxUInt8 {
+    const OPCODE: i32 = 25;
+
+    pub fn softmax_uint8<'a, T: ArrayElem<T>, S: ArenaAllocator>() -> BLiteOperator<'a, T, S> {
+        BLiteOperator {
+            registration: Self::registration(),
+            parser: Self::parser,
+            min_version: 1,
+            max_version: 1,
+        }
+    }
+
+    pub fn parser<'a, T: ArrayElem<T>>(
+        allocator: &mut impl ArenaAllocator,
+        op: Operator,
+        tensors: &mut [BLiteTensor<'a, T>],
+    ) -> Result<BLiteBuiltinOption<'a, T>> {
+        let inputs = op.inputs().unwrap();
+        let output_idx = op.outputs().unwrap().get(0);
+        check_uint8_zero_points(tensors, &[inputs.get(0), output_idx])?;
+
+        OpSoftMaxInt8::parser(allocator, op, tensors)
+    }
+
+    pub fn registration<'a, T: ArrayElem<T>>() -> BLiteRegistration<'a, T> {
+        BLiteRegistration::new(Self::OPCODE, OpSoftMaxInt8::eval::<T>, NotInitialize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpSoftMaxUInt8;
+    use crate::kernel::micro_operator::i8::softmax_i8::OpSoftMaxInt8;
+    use crate::kernel::utils::testing::{eval_quantized, Tensor};
+    use crate::micro_errors::{BLiteError, Result};
+
+    const INPUT_SCALE: f32 = 0.1;
+
+    /// Runs SOFTMAX of the input 2x8, whose input and output have the zero points `zero_points`,
+    /// and the output is quantized with the scale 1/256
+    fn softmax_uint8(input: &Tensor<u8>, zero_points: [i64; 2]) -> Result<Vec<u8>> {
+        eval_quantized(
+            OpSoftMaxUInt8::parser::<u8>,
+            OpSoftMaxInt8::eval::<u8>,
+            None,
+            &[(input, (INPUT_SCALE, zero_points[0]))],
+            None,
+            &[2, 8],
+            (1. / 256., zero_points[1]),
+        )
+    }
+
+    #[test]
+    fn test_softmax_uint8() {
+        let input = Tensor::from((0..16).map(|i| (i * 37 % 256) as u8).collect(), vec![2, 8]);
+        let output = softmax_uint8(&input, [128, 0]).unwrap();
+
+        for (input_row, output_row) in input.data.chunks(8).zip(output.chunks(8)) {
+            let exps: Vec<f32> = input_row
+                .iter()
+                .map(|&x| (x as f32 * INPUT_SCALE).exp())
+                .collect();
+            let sum: f32 = exps.iter().sum();
+            for (&exp_v, &output_v) in exps.iter().zip(output_row) {
+                let expected = exp_v / sum;
+                let y = output_v as f32 / 256.;
+                assert!(
+                    (y - expected).abs() <= 1. / 256.,
+                    "expected {expected}, but got {y}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_softmax_uint8_rejects_zero_point_out_of_range() {
+        let input = Tensor::zeros(&[2, 8]);
+        for (zero_points, zero_point) in [([256, 0], 256), ([0, -128], -128)] {
+            let result = softmax_uint8(&input, zero_points);
+            assert!(
+                matches!(result, Err(BLiteError::NotMatchZeroPoint(z)) if z == zero_point),
+                "{result:?}"
+            );
+        }
+    }
+}
@@ -1,13 +1,24 @@
+pub mod bias;
+pub mod broadcast;
 pub mod fixed_point;
+pub mod flexbuffer;
+pub mod gemm;
+pub mod int4;
+pub mod lookup_table;
 pub mod padding;
 pub mod quantization;
+#[cfg(feature = "simd")]
+pub mod simd;
 pub mod types;
 
 #[cfg(test)]
 pub mod testing;
 
-use crate::micro_errors::Result;
-use quantization::quantize_multiplier;
+use crate::micro_array::{ArrayElem, BLiteQuantizationParams};
+use crate::micro_errors::{BLiteError, Result};
+use crate::micro_tensor::BLiteTensor;
+use int4::FilterData;
+use quantization::{get_quantized_convolution_multiplier, quantize_multiplier};
 
 pub fn calc_per_channel_multiplier_shift(
     input_scale: f32,
@@ -16,7 +27,13 @@ pub fn calc_per_channel_multiplier_shift(
     per_channel_multiplier: &mut [i32],
     per_channel_shift: &mut [i32],
 ) -> Result<()> {
-    for (i, &filter_scale) in filter_scales.iter().enumerate() {
+    // a per-tensor quantized filter has a single scale, which is shared by all the channels
+    for i in 0..per_channel_multiplier.len() {
+        let filter_scale = if filter_scales.len() == 1 {
+            filter_scales[0]
+        } else {
+            filter_scales[i]
+        };
         let effective_output_scale = input_scale as f64 * filter_scale as f64 / output_scale as f64;
         let (multiplier, shift) = quantize_multiplier(effective_output_scale)?;
         per_channel_multiplier[i] = multiplier;
@@ -24,3 +41,97 @@ pub fn calc_per_channel_multiplier_shift(
     }
     Ok(())
 }
+
+/// Checks that a per-channel quantized tensor has `num_channels` scales along `quantized_dimension`,
+/// a per-tensor quantized tensor with a single scale always passes
+pub fn check_quantized_dimension(
+    quant_params: &BLiteQuantizationParams<'_>,
+    quantized_dimension: i32,
+    num_channels: usize,
+) -> Result<()> {
+    let scales = quant_params.scale.len();
+    if scales != 1
+        && (quant_params.quantized_dimension != quantized_dimension || scales != num_channels)
+    {
+        return Err(BLiteError::NotMatchQuantizedDimension(
+            quant_params.quantized_dimension,
+        ));
+    }
+    Ok(())
+}
+
+/// Calculates the multipliers and shifts of each output channel of FULLY_CONNECTED,
+/// whose filter is quantized per-tensor or per-channel along the output dimension
+pub fn calc_fully_connected_multiplier_shift(
+    input_scale: f32,
+    filter_quant_params: &BLiteQuantizationParams<'_>,
+    output_scale: f32,
+    bias_scales: Option<&[f32]>,
+    per_channel_multiplier: &mut [i32],
+    per_channel_shift: &mut [i32],
+) -> Result<()> {
+    // the filter of FULLY_CONNECTED is [output_depth, accum_depth]
+    check_quantized_dimension(filter_quant_params, 0, per_channel_multiplier.len())?;
+    let channel = |scales: &[f32], i: usize| {
+        if scales.len() == 1 {
+            scales[0]
+        } else {
+            scales[i]
+        }
+    };
+    for i in 0..per_channel_multiplier.len() {
+        let real_multiplier = get_quantized_convolution_multiplier(
+            input_scale,
+            channel(filter_quant_params.scale, i),
+            output_scale,
+            bias_scales.map(|bias_scales| channel(bias_scales, i)),
+        )?;
+        let (multiplier, shift) = quantize_multiplier(real_multiplier)?;
+        per_channel_multiplier[i] = multiplier;
+        per_channel_shift[i] = shift;
+    }
+    Ok(())
+}
+
+/// Folds `input_offset * sum(filter + filter_offset)` of each output channel into the bias once,
+/// so that the kernels accumulate only `(filter + filter_offset) * input`.
+/// The filter value of (channel, k) is at `channel * channel_stride + k * depth_stride`.
+pub fn calc_effective_bias<T: ArrayElem<T>>(
+    filter_data: FilterData<T>,
+    bias_data: Option<&[i32]>,
+    input_offset: i32,
+    filter_offset: i32,
+    depth: usize,
+    channel_stride: usize,
+    depth_stride: usize,
+    effective_bias: &mut [i32],
+) {
+    for (channel, effective_bias) in effective_bias.iter_mut().enumerate() {
+        let filter_sum: i32 = (0..depth)
+            .map(|k| filter_data.get(channel * channel_stride + k * depth_stride) + filter_offset)
+            .sum();
+        let bias = bias_data.map_or(0, |bias_data| bias_data[channel]);
+        *effective_bias = bias + input_offset * filter_sum;
+    }
+}
+
+/// Checks that the zero points of the uint8 tensors `idxs` are in [0, 255]
+pub fn check_uint8_zero_points<T: ArrayElem<T>>(
+    tensors: &[BLiteTensor<'_, T>],
+    idxs: &[i32],
+) -> Result<()> {
+    for &idx in idxs {
+        if idx < 0 {
+            continue;
+        }
+        let Some(BLiteQuantizationParams { zero_point, .. }) =
+            tensors[idx as usize]._t()?.borrow().quant_params
+        else {
+            return Err(BLiteError::NotFoundQuantParams);
+        };
+        if let Some(&zero_point) = zero_point.iter().find(|&&z| !(0..=255).contains(&z)) {
+            return Err(BLiteError::NotMatchZeroPoint(zero_point as i32));
+        }
+    }
+    Ok(())
+}
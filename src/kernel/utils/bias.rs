@@ -0,0 +1,44 @@
+use crate::micro_array::{ArrayElem, BLiteArray};
+use crate::micro_errors::{BLiteError, Result};
+use crate::micro_tensor::BLiteTensor;
+use core::cell::Ref;
+
+/// Bias of the int16x8 kernels, which tensorflow lite stores as int64 or int32
+#[derive(Debug, Clone, Copy)]
+pub enum BiasData<'a> {
+    I32(&'a [i32]),
+    I64(&'a [i64]),
+}
+
+impl<'a> BiasData<'a> {
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> i64 {
+        match self {
+            BiasData::I32(data) => data[idx] as i64,
+            BiasData::I64(data) => data[idx],
+        }
+    }
+}
+
+/// Borrowed bias tensor of the int16x8 kernels, which is either I32Tensor or I64Tensor
+pub enum BiasRef<'b, 'a> {
+    I32(Ref<'b, BLiteArray<'a, i32>>),
+    I64(Ref<'b, BLiteArray<'a, i64>>),
+}
+
+impl<'b, 'a> BiasRef<'b, 'a> {
+    pub fn borrow<T: ArrayElem<T>>(tensor: &'b BLiteTensor<'a, T>) -> Result<Self> {
+        match tensor {
+            BLiteTensor::I32Tensor(bias) => Ok(BiasRef::I32(bias.borrow())),
+            BLiteTensor::I64Tensor(bias) => Ok(BiasRef::I64(bias.borrow())),
+            _ => Err(BLiteError::InCompatibleType),
+        }
+    }
+
+    pub fn data(&self) -> BiasData<'_> {
+        match self {
+            BiasRef::I32(bias) => BiasData::I32(bias.data),
+            BiasRef::I64(bias) => BiasData::I64(bias.data),
+        }
+    }
+}
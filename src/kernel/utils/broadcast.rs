@@ -0,0 +1,24 @@
+/// Maps a flat index of a tensor shaped `dims` to the flat index of a tensor
+/// shaped `broadcast_dims` that is broadcast against it (numpy-style, aligned
+/// to the innermost axis).
+pub fn broadcast_index(flat_index: usize, dims: &[i32], broadcast_dims: &[i32]) -> usize {
+    let rank = dims.len();
+    let broadcast_rank = broadcast_dims.len();
+    let mut rest = flat_index;
+    let mut offset = 0;
+    let mut stride = 1;
+    for axis in (0..rank).rev() {
+        let dim = dims[axis] as usize;
+        let coord = rest % dim;
+        rest /= dim;
+        let Some(broadcast_axis) = (axis + broadcast_rank).checked_sub(rank) else {
+            continue;
+        };
+        let broadcast_dim = broadcast_dims[broadcast_axis] as usize;
+        if broadcast_dim != 1 {
+            offset += coord * stride;
+        }
+        stride *= broadcast_dim;
+    }
+    offset
+}
@@ -1,86 +1,388 @@
-// use core::fmt::Debug;
-// use core::mem::size_of;
-// use core::ops::BitAnd;
-// use num_traits::FromPrimitive;
-// use std::cmp::{Ord, PartialOrd};
-// use std::ops::{Add, Div, Mul, Not, Shr, Sub};
-
-// pub trait FixedPointTrait<T> = Debug
-//     + Clone
-//     + Copy
-//     + PartialEq
-//     + Eq
-//     + PartialOrd
-//     + Ord
-//     + FromPrimitive
-//     + Add<Output = T>
-//     + Mul<Output = T>
-//     + Sub<Output = T>
-//     + Div<Output = T>
-//     + BitAnd<Output = T>
-//     + Not<Output = T>
-//     + Shr<Output = T>;
-
-// pub struct FixedPoint<T>
-// where
-//     T: FixedPointTrait<T>,
-// {
-//     pub total_bits: i32,
-//     pub integer_bits: i32,
-//     pub fraction_bits: i32,
-//     raw_val: T,
-// }
-
-// impl<T> FixedPoint<T>
-// where
-//     T: FixedPointTrait<T>,
-// {
-//     pub const fn new(x: T, n: i32) -> Self {
-//         Self {
-//             total_bits: 8 * size_of::<T>() as i32,
-//             integer_bits: n,
-//             fraction_bits: 8 * size_of::<T>() as i32 - 1 - n,
-//             raw_val: x,
-//         }
-//     }
-
-//     pub fn rescale(&self, dst_integer_bits: i32) -> FixedPoint<T> {
-//         let exponent = self.integer_bits - dst_integer_bits;
-//         let x = Self::saturating_rounding_muliply_by_pot(self.raw_val, exponent);
-//         let result = FixedPoint::new(x, dst_integer_bits);
-//         return result;
-//     }
-
-//     pub fn saturating_rounding_muliply_by_pot(x: T, exponent: i32) -> T {
-//         Self::rounding_divide_by_pot(x, -exponent)
-//     }
-
-//     fn rounding_divide_by_pot(x: T, exponent: i32) -> T {
-//         // have to implement Dup
-//         let mask: T = FromPrimitive::from_i64(((1 as i64) << exponent) - 1).unwrap();
-//         let zero: T = FromPrimitive::from_i64(0i64).unwrap();
-//         let one: T = FromPrimitive::from_i64(1i64).unwrap();
-//         let exp: T = FromPrimitive::from_i32(exponent).unwrap();
-//         let remainder = x & mask;
-//         let threshold = (mask >> one) + (Self::mask_if_less_than(x, zero) & one);
-//         let result = (x >> exp) + (Self::mask_greater_than(remainder, threshold) & one);
-//         return result;
-//     }
-
-//     fn mask_if_less_than(x: T, y: T) -> T {
-//         Self::mask_if_non_zero(x < y)
-//     }
-
-//     fn mask_greater_than(x: T, y: T) -> T {
-//         Self::mask_if_non_zero(x > y)
-//     }
-
-//     fn mask_if_non_zero(b: bool) -> T {
-//         let zero: T = FromPrimitive::from_i64(0).unwrap();
-//         if b {
-//             !zero
-//         } else {
-//             zero
-//         }
-//     }
-// }
+// This module is a port of the fixed-point arithmetic of gemmlowp
+// (https://github.com/google/gemmlowp/blob/master/fixedpoint/fixedpoint.h),
+// which is used by tensorflow lite to implement bit-exact quantized kernels.
+use core::ops::{Add, BitAnd, Mul, Sub};
+
+/// A 32-bit fixed-point number with `integer_bits` integer bits and
+/// `31 - integer_bits` fractional bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedPoint {
+    pub raw: i32,
+    pub integer_bits: i32,
+}
+
+impl FixedPoint {
+    pub const fn from_raw(raw: i32, integer_bits: i32) -> Self {
+        Self { raw, integer_bits }
+    }
+
+    pub const fn zero(integer_bits: i32) -> Self {
+        Self::from_raw(0, integer_bits)
+    }
+
+    /// 1.0 is not representable without integer bits, so it saturates to the max value
+    pub const fn one(integer_bits: i32) -> Self {
+        if integer_bits == 0 {
+            Self::from_raw(i32::MAX, integer_bits)
+        } else {
+            Self::from_raw(1 << (31 - integer_bits), integer_bits)
+        }
+    }
+
+    /// Returns 2^exponent
+    pub const fn constant_pot(exponent: i32, integer_bits: i32) -> Self {
+        Self::from_raw(1 << (31 - integer_bits + exponent), integer_bits)
+    }
+
+    #[inline(always)]
+    pub const fn fractional_bits(&self) -> i32 {
+        31 - self.integer_bits
+    }
+
+    /// Changes the number of integer bits keeping the represented value
+    #[inline(always)]
+    pub fn rescale(self, dst_integer_bits: i32) -> Self {
+        let exponent = self.integer_bits - dst_integer_bits;
+        let raw = saturating_rounding_multiply_by_pot(self.raw, exponent);
+        Self::from_raw(raw, dst_integer_bits)
+    }
+
+    /// Multiplies by 2^exponent by reinterpreting the number of integer bits
+    #[inline(always)]
+    pub const fn exact_mul_by_pot(self, exponent: i32) -> Self {
+        Self::from_raw(self.raw, self.integer_bits + exponent)
+    }
+}
+
+impl Add for FixedPoint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        debug_assert_eq!(self.integer_bits, rhs.integer_bits);
+        Self::from_raw(self.raw.wrapping_add(rhs.raw), self.integer_bits)
+    }
+}
+
+impl Sub for FixedPoint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        debug_assert_eq!(self.integer_bits, rhs.integer_bits);
+        Self::from_raw(self.raw.wrapping_sub(rhs.raw), self.integer_bits)
+    }
+}
+
+impl Mul for FixedPoint {
+    type Output = Self;
+
+    /// The product of numbers with I1 and I2 integer bits has I1 + I2 integer bits
+    #[inline(always)]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_raw(
+            saturating_rounding_doubling_high_mul(self.raw, rhs.raw),
+            self.integer_bits + rhs.integer_bits,
+        )
+    }
+}
+
+impl BitAnd<i32> for FixedPoint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn bitand(self, rhs: i32) -> Self::Output {
+        Self::from_raw(self.raw & rhs, self.integer_bits)
+    }
+}
+
+/// Returns x / 2^exponent rounded to the nearest, with ties away from zero
+#[inline(always)]
+pub fn rounding_divide_by_pot(x: i32, exponent: i32) -> i32 {
+    debug_assert!((0..=31).contains(&exponent));
+    let mask = ((1i64 << exponent) - 1) as i32;
+    let remainder = x & mask;
+    let threshold = (mask >> 1) + if x < 0 { 1 } else { 0 };
+    (x >> exponent) + if remainder > threshold { 1 } else { 0 }
+}
+
+/// Returns the high 32 bits of 2 * a * b with rounding and saturation
+#[inline(always)]
+pub fn saturating_rounding_doubling_high_mul(a: i32, b: i32) -> i32 {
+    if a == b && a == i32::MIN {
+        return i32::MAX;
+    }
+    let ab = a as i64 * b as i64;
+    let nudge = if ab >= 0 { 1 << 30 } else { 1 - (1 << 30) };
+    ((ab + nudge) / (1i64 << 31)) as i32
+}
+
+/// Returns x * 2^exponent, which saturates for a positive exponent and rounds for a negative one
+#[inline(always)]
+pub fn saturating_rounding_multiply_by_pot(x: i32, exponent: i32) -> i32 {
+    if exponent > 0 {
+        let threshold = ((1i64 << (31 - exponent)) - 1) as i32;
+        if x > threshold {
+            i32::MAX
+        } else if x < -threshold {
+            i32::MIN
+        } else {
+            x << exponent
+        }
+    } else if exponent < 0 {
+        rounding_divide_by_pot(x, -exponent)
+    } else {
+        x
+    }
+}
+
+/// Returns (a + b) / 2 rounded to the nearest, with ties away from zero
+#[inline(always)]
+pub fn rounding_half_sum(a: i32, b: i32) -> i32 {
+    let sum = a as i64 + b as i64;
+    let sign = if sum >= 0 { 1 } else { -1 };
+    ((sum + sign) / 2) as i32
+}
+
+/// Returns exp(a) for a in [-1/4, 0) with 0 integer bits
+pub fn exp_on_interval_between_negative_one_quarter_and_0_excl(a: FixedPoint) -> FixedPoint {
+    debug_assert_eq!(a.integer_bits, 0);
+    // exp(-1/8) and 1/3
+    let constant_term = FixedPoint::from_raw(1895147668, 0);
+    let constant_1_over_3 = FixedPoint::from_raw(715827883, 0);
+    // Taylor expansion around -1/8, so that x = a + 1/8
+    let x = a + FixedPoint::constant_pot(-3, 0);
+    let x2 = x * x;
+    let x3 = x2 * x;
+    let x4 = x2 * x2;
+    let x4_over_4 = FixedPoint::from_raw(saturating_rounding_multiply_by_pot(x4.raw, -2), 0);
+    let x4_over_24_plus_x3_over_6_plus_x2_over_2 = FixedPoint::from_raw(
+        saturating_rounding_multiply_by_pot((((x4_over_4 + x3) * constant_1_over_3) + x2).raw, -1),
+        0,
+    );
+    constant_term + constant_term * (x + x4_over_24_plus_x3_over_6_plus_x2_over_2)
+}
+
+/// Returns exp(a) for a <= 0 with 0 integer bits
+pub fn exp_on_negative_values(a: FixedPoint) -> FixedPoint {
+    let integer_bits = a.integer_bits;
+    let fractional_bits = a.fractional_bits();
+    let one_quarter = FixedPoint::constant_pot(-2, integer_bits);
+    let mask = one_quarter.raw - 1;
+    let a_mod_quarter_minus_one_quarter = (a & mask) - one_quarter;
+    let mut result = exp_on_interval_between_negative_one_quarter_and_0_excl(
+        a_mod_quarter_minus_one_quarter.rescale(0),
+    );
+    let remainder = (a_mod_quarter_minus_one_quarter - a).raw;
+
+    // exp(-2^exponent) for exponent in [-2, 4]
+    const BARREL_SHIFTER: [(i32, i32); 7] = [
+        (-2, 1672461947),
+        (-1, 1302514674),
+        (0, 790015084),
+        (1, 290630308),
+        (2, 39332535),
+        (3, 720401),
+        (4, 242),
+    ];
+    for (exponent, multiplier) in BARREL_SHIFTER {
+        if integer_bits > exponent {
+            let shift_amount = fractional_bits + exponent;
+            if remainder & (1 << shift_amount) != 0 {
+                result = result * FixedPoint::from_raw(multiplier, 0);
+            }
+        }
+    }
+
+    if integer_bits > 5 {
+        let clamp = FixedPoint::from_raw(-(1 << (36 - integer_bits)), integer_bits);
+        if a.raw < clamp.raw {
+            result = FixedPoint::zero(0);
+        }
+    }
+
+    if a.raw == 0 {
+        result = FixedPoint::one(0);
+    }
+    result
+}
+
+/// Returns 1 / (1 + a) for a in [0, 1) with 0 integer bits
+pub fn one_over_one_plus_x_for_x_in_0_1(a: FixedPoint) -> FixedPoint {
+    debug_assert_eq!(a.integer_bits, 0);
+    let half_denominator =
+        FixedPoint::from_raw(rounding_half_sum(a.raw, FixedPoint::one(0).raw), 0);
+    // Newton-Raphson division with the initial values of 48/17 and -32/17
+    let constant_48_over_17 = FixedPoint::from_raw(1515870810, 2);
+    let constant_neg_32_over_17 = FixedPoint::from_raw(-1010580540, 2);
+    let mut x = constant_48_over_17 + half_denominator * constant_neg_32_over_17;
+    for _ in 0..3 {
+        let half_denominator_times_x = half_denominator * x;
+        let one_minus_half_denominator_times_x = FixedPoint::one(2) - half_denominator_times_x;
+        x = x + (x * one_minus_half_denominator_times_x).rescale(2);
+    }
+    x.exact_mul_by_pot(-1).rescale(0)
+}
+
+/// Returns the reciprocal of x with `x_integer_digits` integer bits,
+/// and the number of bits that the result has to be shifted by (num_bits_over_unit)
+pub fn get_reciprocal(x: i32, x_integer_digits: i32) -> (i32, i32) {
+    let headroom_plus_one = (x as u32).leading_zeros() as i32;
+    let num_bits_over_unit = x_integer_digits - headroom_plus_one;
+    let shifted_sum_minus_one = ((x as u32) << headroom_plus_one).wrapping_sub(1u32 << 31) as i32;
+    let shifted_scale =
+        one_over_one_plus_x_for_x_in_0_1(FixedPoint::from_raw(shifted_sum_minus_one, 0));
+    (shifted_scale.raw, num_bits_over_unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_f64(x: FixedPoint) -> f64 {
+        x.raw as f64 / (1u64 << x.fractional_bits()) as f64
+    }
+
+    #[test]
+    fn test_rounding_divide_by_pot() {
+        let tt = [
+            ((5, 1), 3),
+            ((-5, 1), -3),
+            ((4, 2), 1),
+            ((-6, 2), -2),
+            ((7, 0), 7),
+        ];
+        for ((x, exponent), expected) in tt {
+            assert_eq!(expected, rounding_divide_by_pot(x, exponent));
+        }
+    }
+
+    #[test]
+    fn test_saturating_rounding_doubling_high_mul() {
+        assert_eq!(
+            i32::MAX,
+            saturating_rounding_doubling_high_mul(i32::MIN, i32::MIN)
+        );
+        assert_eq!(
+            1 << 29,
+            saturating_rounding_doubling_high_mul(1 << 30, 1 << 30)
+        );
+        assert_eq!(
+            -(1 << 29),
+            saturating_rounding_doubling_high_mul(-(1 << 30), 1 << 30)
+        );
+    }
+
+    #[test]
+    fn test_exp_on_negative_values() {
+        for i in 0..=100 {
+            let x = -(i as f64) * 0.1;
+            let a = FixedPoint::from_raw((x * (1 << 26) as f64) as i32, 5);
+            let y = to_f64(exp_on_negative_values(a));
+            assert!(
+                (y - x.exp()).abs() < 1e-6,
+                "exp({}) = {}, but got {}",
+                x,
+                x.exp(),
+                y
+            );
+        }
+    }
+
+    #[test]
+    fn test_one_over_one_plus_x_for_x_in_0_1() {
+        for i in 0..100 {
+            let x = i as f64 / 100.0;
+            let a = FixedPoint::from_raw((x * (1u64 << 31) as f64) as i32, 0);
+            let y = to_f64(one_over_one_plus_x_for_x_in_0_1(a));
+            assert!((y - 1.0 / (1.0 + x)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_saturating_rounding_multiply_by_pot() {
+        let tt = [
+            ((3, 2), 12),
+            ((1 << 29, 1), 1 << 30),
+            ((1 << 30, 1), i32::MAX),
+            ((-(1 << 30), 2), i32::MIN),
+            ((-6, -2), -2),
+            ((7, 0), 7),
+        ];
+        for ((x, exponent), expected) in tt {
+            assert_eq!(expected, saturating_rounding_multiply_by_pot(x, exponent));
+        }
+    }
+
+    #[test]
+    fn test_rounding_half_sum() {
+        let tt = [
+            ((3, 4), 4),
+            ((-3, -4), -4),
+            ((2, 4), 3),
+            ((i32::MAX, i32::MAX), i32::MAX),
+        ];
+        for ((a, b), expected) in tt {
+            assert_eq!(expected, rounding_half_sum(a, b));
+        }
+    }
+
+    #[test]
+    fn test_rescale() {
+        let x = FixedPoint::from_raw(3 << 25, 5);
+        assert_eq!(FixedPoint::from_raw(3 << 28, 2), x.rescale(2));
+        assert_eq!(x, x.rescale(2).rescale(5));
+        // 3/2 is not representable with 0 integer bits
+        assert_eq!(FixedPoint::from_raw(i32::MAX, 0), x.rescale(0));
+    }
+
+    // the golden raw values of the functions below are computed with the gemmlowp implementation
+
+    #[test]
+    fn test_exp_on_negative_values_golden() {
+        let tt = [
+            (-(1 << 24), 1672462419),
+            (-(1 << 26), 790015308),
+            (-(3 << 25), 479168506),
+            (-(5 << 26), 14469635),
+            (-(10 << 26), 97496),
+            (-123456789, 341177383),
+            (-(31 << 26), 0),
+            (-1, 2147483124),
+            (0, i32::MAX),
+        ];
+        for (raw, expected) in tt {
+            let y = exp_on_negative_values(FixedPoint::from_raw(raw, 5));
+            assert_eq!(FixedPoint::from_raw(expected, 0), y);
+        }
+    }
+
+    #[test]
+    fn test_one_over_one_plus_x_for_x_in_0_1_golden() {
+        let tt = [
+            (0, 2147483647),
+            (1 << 30, 1431655762),
+            (1 << 29, 1717986914),
+            (1234567890, 1363576508),
+            (i32::MAX, 1073741820),
+        ];
+        for (raw, expected) in tt {
+            let y = one_over_one_plus_x_for_x_in_0_1(FixedPoint::from_raw(raw, 0));
+            assert_eq!(FixedPoint::from_raw(expected, 0), y);
+        }
+    }
+
+    #[test]
+    fn test_get_reciprocal() {
+        let tt = [
+            ((1 << 19, 12), (i32::MAX, 0)),
+            ((3 << 19, 12), (1431655762, 1)),
+            ((123456789, 12), (1167333038, 7)),
+            ((1 << 30, 0), (i32::MAX, -1)),
+        ];
+        for ((x, x_integer_digits), expected) in tt {
+            assert_eq!(expected, get_reciprocal(x, x_integer_digits));
+        }
+    }
+}
@@ -0,0 +1,385 @@
+// This module is a small reader of FlexBuffers
+// (https://flatbuffers.dev/flexbuffers.html), which is the format that
+// tensorflow lite uses for `custom_options` of custom operators.
+// Scalars, strings, blobs, vectors and maps can be read without allocations.
+use crate::micro_errors::{BLiteError::*, Result};
+
+const FBT_NULL: u8 = 0;
+const FBT_INT: u8 = 1;
+const FBT_UINT: u8 = 2;
+const FBT_FLOAT: u8 = 3;
+const FBT_KEY: u8 = 4;
+const FBT_STRING: u8 = 5;
+const FBT_INDIRECT_INT: u8 = 6;
+const FBT_INDIRECT_UINT: u8 = 7;
+const FBT_INDIRECT_FLOAT: u8 = 8;
+const FBT_MAP: u8 = 9;
+const FBT_VECTOR: u8 = 10;
+const FBT_VECTOR_INT: u8 = 11;
+const FBT_VECTOR_KEY: u8 = 14;
+const FBT_VECTOR_INT2: u8 = 16;
+const FBT_VECTOR_FLOAT4: u8 = 24;
+const FBT_BLOB: u8 = 25;
+const FBT_BOOL: u8 = 26;
+const FBT_VECTOR_BOOL: u8 = 36;
+
+#[inline(always)]
+fn read_bytes(buffer: &[u8], offset: usize, byte_width: usize) -> Result<&[u8]> {
+    buffer
+        .get(offset..offset + byte_width)
+        .ok_or(InvalidFlexBuffer)
+}
+
+fn read_u64(buffer: &[u8], offset: usize, byte_width: usize) -> Result<u64> {
+    let bytes = read_bytes(buffer, offset, byte_width)?;
+    Ok(match byte_width {
+        1 => bytes[0] as u64,
+        2 => u16::from_le_bytes([bytes[0], bytes[1]]) as u64,
+        4 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64,
+        8 => u64::from_le_bytes(bytes.try_into().map_err(|_| InvalidFlexBuffer)?),
+        _ => return Err(InvalidFlexBuffer),
+    })
+}
+
+fn read_i64(buffer: &[u8], offset: usize, byte_width: usize) -> Result<i64> {
+    let v = read_u64(buffer, offset, byte_width)?;
+    // sign extension from the width of the stored value
+    let shift = 64 - 8 * byte_width as u32;
+    Ok(((v << shift) as i64) >> shift)
+}
+
+fn read_f64(buffer: &[u8], offset: usize, byte_width: usize) -> Result<f64> {
+    let v = read_u64(buffer, offset, byte_width)?;
+    match byte_width {
+        4 => Ok(f32::from_bits(v as u32) as f64),
+        8 => Ok(f64::from_bits(v)),
+        _ => Err(InvalidFlexBuffer),
+    }
+}
+
+/// Follows the offset stored at `offset`, which points backwards in the buffer
+fn indirect(buffer: &[u8], offset: usize, byte_width: usize) -> Result<usize> {
+    let relative = read_u64(buffer, offset, byte_width)? as usize;
+    offset.checked_sub(relative).ok_or(InvalidFlexBuffer)
+}
+
+/// A value in a flexbuffer
+#[derive(Debug, Clone, Copy)]
+pub struct FlexBufferRef<'a> {
+    buffer: &'a [u8],
+    // the position where the value (or the offset to the value) is stored
+    offset: usize,
+    // the byte width of the stored value (or the offset)
+    parent_width: usize,
+    // the byte width of the value pointed by the offset
+    byte_width: usize,
+    value_type: u8,
+}
+
+impl<'a> FlexBufferRef<'a> {
+    /// Returns the root value of a flexbuffer
+    pub fn root(buffer: &'a [u8]) -> Result<Self> {
+        let len = buffer.len();
+        if len < 3 {
+            return Err(InvalidFlexBuffer);
+        }
+        let root_width = buffer[len - 1] as usize;
+        let packed_type = buffer[len - 2];
+        let offset = (len - 2).checked_sub(root_width).ok_or(InvalidFlexBuffer)?;
+        Ok(Self::new(buffer, offset, root_width, packed_type))
+    }
+
+    fn new(buffer: &'a [u8], offset: usize, parent_width: usize, packed_type: u8) -> Self {
+        Self {
+            buffer,
+            offset,
+            parent_width,
+            byte_width: 1 << (packed_type & 3),
+            value_type: packed_type >> 2,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.value_type == FBT_NULL
+    }
+
+    pub fn as_i64(&self) -> Result<i64> {
+        let buffer = self.buffer;
+        match self.value_type {
+            FBT_INT => read_i64(buffer, self.offset, self.parent_width),
+            FBT_UINT | FBT_BOOL => Ok(read_u64(buffer, self.offset, self.parent_width)? as i64),
+            FBT_FLOAT => Ok(read_f64(buffer, self.offset, self.parent_width)? as i64),
+            FBT_INDIRECT_INT => {
+                let target = indirect(buffer, self.offset, self.parent_width)?;
+                read_i64(buffer, target, self.byte_width)
+            }
+            FBT_INDIRECT_UINT => {
+                let target = indirect(buffer, self.offset, self.parent_width)?;
+                Ok(read_u64(buffer, target, self.byte_width)? as i64)
+            }
+            FBT_INDIRECT_FLOAT => {
+                let target = indirect(buffer, self.offset, self.parent_width)?;
+                Ok(read_f64(buffer, target, self.byte_width)? as i64)
+            }
+            _ => Err(InvalidFlexBuffer),
+        }
+    }
+
+    pub fn as_i32(&self) -> Result<i32> {
+        i32::try_from(self.as_i64()?).map_err(|_| InvalidFlexBuffer)
+    }
+
+    pub fn as_f64(&self) -> Result<f64> {
+        let buffer = self.buffer;
+        match self.value_type {
+            FBT_FLOAT => read_f64(buffer, self.offset, self.parent_width),
+            FBT_INDIRECT_FLOAT => {
+                let target = indirect(buffer, self.offset, self.parent_width)?;
+                read_f64(buffer, target, self.byte_width)
+            }
+            _ => Ok(self.as_i64()? as f64),
+        }
+    }
+
+    pub fn as_f32(&self) -> Result<f32> {
+        Ok(self.as_f64()? as f32)
+    }
+
+    pub fn as_bool(&self) -> Result<bool> {
+        Ok(self.as_i64()? != 0)
+    }
+
+    /// Returns the bytes of a blob, or of a string without the null terminator
+    pub fn as_blob(&self) -> Result<&'a [u8]> {
+        let buffer = self.buffer;
+        let target = indirect(buffer, self.offset, self.parent_width)?;
+        match self.value_type {
+            FBT_STRING | FBT_BLOB => {
+                let size_offset = target
+                    .checked_sub(self.byte_width)
+                    .ok_or(InvalidFlexBuffer)?;
+                let size = read_u64(buffer, size_offset, self.byte_width)? as usize;
+                read_bytes(buffer, target, size)
+            }
+            FBT_KEY => {
+                let bytes = buffer.get(target..).ok_or(InvalidFlexBuffer)?;
+                let size = bytes
+                    .iter()
+                    .position(|&b| b == 0)
+                    .ok_or(InvalidFlexBuffer)?;
+                Ok(&bytes[..size])
+            }
+            _ => Err(InvalidFlexBuffer),
+        }
+    }
+
+    pub fn as_str(&self) -> Result<&'a str> {
+        if self.value_type == FBT_BLOB {
+            return Err(InvalidFlexBuffer);
+        }
+        core::str::from_utf8(self.as_blob()?).map_err(|_| InvalidFlexBuffer)
+    }
+
+    pub fn as_vector(&self) -> Result<FlexBufferVector<'a>> {
+        let buffer = self.buffer;
+        let byte_width = self.byte_width;
+        let values = indirect(buffer, self.offset, self.parent_width)?;
+        let read_len = || {
+            let size_offset = values.checked_sub(byte_width).ok_or(InvalidFlexBuffer)?;
+            Ok(read_u64(buffer, size_offset, byte_width)? as usize)
+        };
+        // the element type of untyped vectors is stored for each element after the values
+        let (len, element_type) = match self.value_type {
+            FBT_VECTOR | FBT_MAP => (read_len()?, None),
+            FBT_VECTOR_INT..=FBT_VECTOR_KEY => (
+                read_len()?,
+                Some(self.value_type - FBT_VECTOR_INT + FBT_INT),
+            ),
+            FBT_VECTOR_BOOL => (read_len()?, Some(FBT_BOOL)),
+            FBT_VECTOR_INT2..=FBT_VECTOR_FLOAT4 => {
+                // fixed length vectors of 2, 3 or 4 elements have no size
+                let n = self.value_type - FBT_VECTOR_INT2;
+                ((n / 3 + 2) as usize, Some(n % 3 + FBT_INT))
+            }
+            _ => return Err(InvalidFlexBuffer),
+        };
+        Ok(FlexBufferVector {
+            buffer,
+            values,
+            byte_width,
+            len,
+            element_type,
+        })
+    }
+
+    pub fn as_map(&self) -> Result<FlexBufferMap<'a>> {
+        if self.value_type != FBT_MAP {
+            return Err(InvalidFlexBuffer);
+        }
+        let buffer = self.buffer;
+        let byte_width = self.byte_width;
+        let values = indirect(buffer, self.offset, self.parent_width)?;
+        // A map is laid out as [keys offset, keys byte width, size, values..., types...]
+        let prefix = |n: usize| values.checked_sub(n * byte_width).ok_or(InvalidFlexBuffer);
+        let len = read_u64(buffer, prefix(1)?, byte_width)? as usize;
+        let keys_byte_width = read_u64(buffer, prefix(2)?, byte_width)? as usize;
+        let keys = indirect(buffer, prefix(3)?, byte_width)?;
+        Ok(FlexBufferMap {
+            buffer,
+            values,
+            byte_width,
+            len,
+            keys,
+            keys_byte_width,
+        })
+    }
+}
+
+/// A vector of a flexbuffer, which is either typed or untyped
+#[derive(Debug, Clone, Copy)]
+pub struct FlexBufferVector<'a> {
+    buffer: &'a [u8],
+    values: usize,
+    byte_width: usize,
+    len: usize,
+    element_type: Option<u8>,
+}
+
+impl<'a> FlexBufferVector<'a> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, idx: usize) -> Result<FlexBufferRef<'a>> {
+        if idx >= self.len {
+            return Err(InvalidFlexBuffer);
+        }
+        let packed_type = match self.element_type {
+            // the elements of typed vectors have the width of the vector
+            Some(element_type) => (element_type << 2) | self.byte_width.trailing_zeros() as u8,
+            None => {
+                let types = self.values + self.len * self.byte_width;
+                *self.buffer.get(types + idx).ok_or(InvalidFlexBuffer)?
+            }
+        };
+        Ok(FlexBufferRef::new(
+            self.buffer,
+            self.values + idx * self.byte_width,
+            self.byte_width,
+            packed_type,
+        ))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Result<FlexBufferRef<'a>>> + '_ {
+        (0..self.len).map(|idx| self.get(idx))
+    }
+}
+
+/// A map of a flexbuffer, whose keys are looked up by names
+#[derive(Debug, Clone, Copy)]
+pub struct FlexBufferMap<'a> {
+    buffer: &'a [u8],
+    values: usize,
+    byte_width: usize,
+    len: usize,
+    keys: usize,
+    keys_byte_width: usize,
+}
+
+impl<'a> FlexBufferMap<'a> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn key_matches(&self, idx: usize, key: &str) -> Result<bool> {
+        let key_offset = self.keys + idx * self.keys_byte_width;
+        let start = indirect(self.buffer, key_offset, self.keys_byte_width)?;
+        let key = key.as_bytes();
+        let Some(stored) = self.buffer.get(start..start + key.len() + 1) else {
+            return Ok(false);
+        };
+        // keys are null-terminated strings
+        Ok(&stored[..key.len()] == key && stored[key.len()] == 0)
+    }
+
+    /// Returns the value of `key`, or `None` when the map does not have it
+    pub fn get(&self, key: &str) -> Result<Option<FlexBufferRef<'a>>> {
+        for idx in 0..self.len {
+            if self.key_matches(idx, key)? {
+                let types = self.values + self.len * self.byte_width;
+                let packed_type = *self.buffer.get(types + idx).ok_or(InvalidFlexBuffer)?;
+                return Ok(Some(FlexBufferRef::new(
+                    self.buffer,
+                    self.values + idx * self.byte_width,
+                    self.byte_width,
+                    packed_type,
+                )));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlexBufferRef;
+
+    // The flexbuffer of {"a": 3, "b": 0.5, "c": true} with values of 32 bits
+    const MAP: [u8; 42] = [
+        b'a', 0, b'b', 0, b'c', 0, // keys
+        3, 7, 6, 5, // keys vector: size and offsets to "a", "b" and "c"
+        0, 0, // padding
+        5, 0, 0, 0, // offset to the keys vector
+        1, 0, 0, 0, // byte width of the keys vector
+        3, 0, 0, 0, // size
+        3, 0, 0, 0, // "a"
+        0, 0, 0, 0x3f, // "b"
+        1, 0, 0, 0, // "c"
+        0x06, 0x0e, 0x6a, // types: int, float and bool
+        15, 0x26, 1, // root: offset to the values, type of the map and byte width
+    ];
+
+    // The flexbuffer of {"name": "ab", "v": [1, 2, 3]} with values of 8 bits
+    const MAP_OF_VECTOR: [u8; 28] = [
+        b'n', b'a', b'm', b'e', 0, b'v', 0, // keys
+        2, b'a', b'b', 0, // "ab"
+        3, 1, 2, 3, // [1, 2, 3] as a typed vector of int
+        2, 16, 12, // keys vector: size and offsets to "name" and "v"
+        2, 1, 2, // keys offset, keys byte width and size
+        13, 10, // offsets to "ab" and [1, 2, 3]
+        0x14, 0x2c, // types: string and vector of int
+        4, 0x24, 1, // root: offset to the values, type of the map and byte width
+    ];
+
+    #[test]
+    fn test_flexbuffer_map() {
+        let map = FlexBufferRef::root(&MAP).unwrap().as_map().unwrap();
+        assert_eq!(3, map.len());
+        assert_eq!(3, map.get("a").unwrap().unwrap().as_i32().unwrap());
+        assert_eq!(0.5, map.get("b").unwrap().unwrap().as_f32().unwrap());
+        assert!(map.get("c").unwrap().unwrap().as_bool().unwrap());
+        assert!(map.get("d").unwrap().is_none());
+        assert!(FlexBufferRef::root(&MAP[..2]).is_err());
+    }
+
+    #[test]
+    fn test_flexbuffer_string_and_vector() {
+        let map = FlexBufferRef::root(&MAP_OF_VECTOR)
+            .unwrap()
+            .as_map()
+            .unwrap();
+        assert_eq!("ab", map.get("name").unwrap().unwrap().as_str().unwrap());
+        let v = map.get("v").unwrap().unwrap().as_vector().unwrap();
+        let v = v.iter().map(|x| x.unwrap().as_i32().unwrap());
+        assert_eq!(vec![1, 2, 3], v.collect::<Vec<i32>>());
+        assert!(map.get("name").unwrap().unwrap().as_vector().is_err());
+    }
+}
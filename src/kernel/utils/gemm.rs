@@ -0,0 +1,309 @@
+#[cfg(feature = "simd")]
+use crate::kernel::utils::simd::{cast_slice, cast_value, cells_as_slice, dot_f32, dot_i8};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_errors::Result;
+use crate::micro_slice::alloc_array_mut;
+use core::cell::Cell;
+use core::ops::{AddAssign, Mul};
+use num_traits::AsPrimitive;
+
+// the number of the output pixels which are unfolded by im2col at once
+pub const IM2COL_TILE_ROWS: usize = 32;
+// the number of the output channels which are accumulated at once in registers
+const GEMM_TILE_COLS: usize = 4;
+
+/// Left hand side of the GEMM, which is either the input itself or the im2col scratch in the arena
+pub trait GemmLhs<T> {
+    fn at(&self, idx: usize) -> T;
+
+    /// Returns all the values as a slice for the vectorised loops
+    #[cfg(feature = "simd")]
+    fn values(&self) -> &[T];
+}
+
+impl<T: Copy> GemmLhs<T> for [T] {
+    #[inline(always)]
+    fn at(&self, idx: usize) -> T {
+        self[idx]
+    }
+
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn values(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: Copy> GemmLhs<T> for [Cell<T>] {
+    #[inline(always)]
+    fn at(&self, idx: usize) -> T {
+        self[idx].get()
+    }
+
+    // the im2col scratch is not written while the GEMM reads it
+    #[cfg(feature = "simd")]
+    #[inline(always)]
+    fn values(&self) -> &[T] {
+        unsafe { cells_as_slice(self) }
+    }
+}
+
+/// Returns true if the 1x1 convolution can read the input as the rows of the GEMM without im2col
+#[inline(always)]
+pub fn is_pointwise(filter_height: i32, filter_width: i32, stride_w: i32, stride_h: i32) -> bool {
+    filter_height == 1 && filter_width == 1 && stride_w == 1 && stride_h == 1
+}
+
+/// Allocates the im2col scratch in the arena, which holds `IM2COL_TILE_ROWS` output pixels at most
+pub fn alloc_im2col_buffer<'a, T>(
+    allocator: &mut impl ArenaAllocator,
+    output_pixels: usize,
+    depth: usize,
+) -> Result<&'a [Cell<T>]> {
+    let rows = output_pixels.min(IM2COL_TILE_ROWS);
+    let buffer = unsafe { alloc_array_mut::<T>(allocator, rows * depth) }?;
+    Ok(Cell::from_mut(buffer).as_slice_of_cells())
+}
+
+/// The shapes, strides, dilations and paddings of a convolution which are read by im2col
+#[derive(Debug, Clone, Copy)]
+pub struct Im2ColGeometry {
+    pub input_height: i32,
+    pub input_width: i32,
+    pub input_depth: i32,
+    pub filter_height: i32,
+    pub filter_width: i32,
+    pub output_width: i32,
+    //
+    pub stride_w: i32,
+    pub stride_h: i32,
+    pub dilation_w_factor: i32,
+    pub dilation_h_factor: i32,
+    pub padding_w: i32,
+    pub padding_h: i32,
+}
+
+/// Unfolds the receptive fields of `num_pixels` output pixels from `first_pixel` into the rows of `im2col`,
+/// whose columns are ordered as (filter_y, filter_x, in_channel) like the filter of CONV_2D.
+/// The points outside of the image are filled with `pad_value`.
+#[inline(always)]
+pub fn im2col<T: Copy>(
+    input_data: &[T],
+    im2col: &[Cell<T>],
+    geometry: &Im2ColGeometry,
+    //
+    batch: usize,
+    first_pixel: usize,
+    num_pixels: usize,
+    pad_value: T,
+) {
+    let Im2ColGeometry {
+        input_height,
+        input_width,
+        input_depth,
+        filter_height,
+        filter_width,
+        output_width,
+        stride_w,
+        stride_h,
+        dilation_w_factor,
+        dilation_h_factor,
+        padding_w,
+        padding_h,
+    } = *geometry;
+    let input_depth = input_depth as usize;
+    let mut dst = 0;
+    for pixel in first_pixel..first_pixel + num_pixels {
+        let out_y = pixel as i32 / output_width;
+        let out_x = pixel as i32 % output_width;
+        let in_y_origin = (out_y * stride_h) - padding_h;
+        let in_x_origin = (out_x * stride_w) - padding_w;
+        for filter_y in 0..filter_height {
+            let in_y = in_y_origin + dilation_h_factor * filter_y;
+            for filter_x in 0..filter_width {
+                let in_x = in_x_origin + dilation_w_factor * filter_x;
+                let row = &im2col[dst..dst + input_depth];
+                if (in_x >= 0) && (in_x < input_width) && (in_y >= 0) && (in_y < input_height) {
+                    let src = ((batch * input_height as usize + in_y as usize)
+                        * input_width as usize
+                        + in_x as usize)
+                        * input_depth;
+                    for (cell, &v) in row.iter().zip(&input_data[src..src + input_depth]) {
+                        cell.set(v);
+                    }
+                } else {
+                    for cell in row {
+                        cell.set(pad_value);
+                    }
+                }
+                dst += input_depth;
+            }
+        }
+    }
+}
+
+/// Multiplies `lhs` [rows, depth] by the transposed `rhs` [cols, depth] with the offset of `rhs`,
+/// and passes each int32 accumulator to `store(row, col, acc)`.
+/// The offset of `lhs` is expected to be folded into the bias by the caller.
+/// The output channels are blocked to reuse each value of `lhs` from registers.
+#[inline(always)]
+pub fn gemm_quantized<T, L>(
+    lhs: &L,
+    rhs: &[T],
+    rhs_offset: i32,
+    rows: usize,
+    cols: usize,
+    depth: usize,
+    mut store: impl FnMut(usize, usize, i32) -> Result<()>,
+) -> Result<()>
+where
+    T: Copy + AsPrimitive<i32>,
+    L: GemmLhs<T> + ?Sized,
+{
+    // the int8 rows are multiplied by the vectorised dot product if the offset of `rhs` is zero
+    #[cfg(feature = "simd")]
+    if let (Some(lhs), Some(rhs), 0) = (
+        cast_slice::<T, i8>(lhs.values()),
+        cast_slice::<T, i8>(rhs),
+        rhs_offset,
+    ) {
+        for row in 0..rows {
+            let lhs_row = &lhs[row * depth..(row + 1) * depth];
+            for col in 0..cols {
+                store(
+                    row,
+                    col,
+                    dot_i8(lhs_row, &rhs[col * depth..(col + 1) * depth]),
+                )?;
+            }
+        }
+        return Ok(());
+    }
+
+    let mut col = 0;
+    while col < cols {
+        let tile_cols = GEMM_TILE_COLS.min(cols - col);
+        for row in 0..rows {
+            let mut acc = [0i32; GEMM_TILE_COLS];
+            for d in 0..depth {
+                let lhs_v: i32 = lhs.at(row * depth + d).as_();
+                for (j, acc) in acc.iter_mut().enumerate().take(tile_cols) {
+                    *acc += lhs_v * (rhs[(col + j) * depth + d].as_() + rhs_offset);
+                }
+            }
+            for (j, &acc) in acc.iter().enumerate().take(tile_cols) {
+                store(row, col + j, acc)?;
+            }
+        }
+        col += tile_cols;
+    }
+    Ok(())
+}
+
+/// Multiplies `lhs` [rows, depth] by the transposed `rhs` [cols, depth],
+/// and passes each accumulator to `store(row, col, acc)`
+#[inline(always)]
+pub fn gemm_float<T, L>(
+    lhs: &L,
+    rhs: &[T],
+    rows: usize,
+    cols: usize,
+    depth: usize,
+    mut store: impl FnMut(usize, usize, T),
+) where
+    T: 'static + Copy + Default + AddAssign + Mul<Output = T>,
+    L: GemmLhs<T> + ?Sized,
+{
+    #[cfg(feature = "simd")]
+    if let (Some(lhs), Some(rhs)) = (
+        cast_slice::<T, f32>(lhs.values()),
+        cast_slice::<T, f32>(rhs),
+    ) {
+        for row in 0..rows {
+            let lhs_row = &lhs[row * depth..(row + 1) * depth];
+            for col in 0..cols {
+                let acc = dot_f32(lhs_row, &rhs[col * depth..(col + 1) * depth]);
+                store(row, col, cast_value(acc).unwrap());
+            }
+        }
+        return;
+    }
+
+    let mut col = 0;
+    while col < cols {
+        let tile_cols = GEMM_TILE_COLS.min(cols - col);
+        for row in 0..rows {
+            let mut acc = [T::default(); GEMM_TILE_COLS];
+            for d in 0..depth {
+                let lhs_v = lhs.at(row * depth + d);
+                for (j, acc) in acc.iter_mut().enumerate().take(tile_cols) {
+                    *acc += lhs_v * rhs[(col + j) * depth + d];
+                }
+            }
+            for (j, &acc) in acc.iter().enumerate().take(tile_cols) {
+                store(row, col + j, acc);
+            }
+        }
+        col += tile_cols;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gemm_quantized, im2col, Im2ColGeometry};
+    use crate::kernel::utils::calc_effective_bias;
+    use crate::kernel::utils::int4::FilterData;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_im2col_gemm() {
+        // input 1x2x2x1 with a 2x2 filter and SAME padding of the right and bottom
+        let input = [1i8, 2, 3, 4];
+        let mut buffer = [0i8; 16];
+        let buffer = Cell::from_mut(&mut buffer[..]).as_slice_of_cells();
+        let geometry = Im2ColGeometry {
+            input_height: 2,
+            input_width: 2,
+            input_depth: 1,
+            filter_height: 2,
+            filter_width: 2,
+            output_width: 2,
+            stride_w: 1,
+            stride_h: 1,
+            dilation_w_factor: 1,
+            dilation_h_factor: 1,
+            padding_w: 0,
+            padding_h: 0,
+        };
+        im2col(&input, buffer, &geometry, 0, 0, 4, -1);
+        let unfolded: [i8; 16] = core::array::from_fn(|i| buffer[i].get());
+        assert_eq!(
+            [1, 2, 3, 4, 2, -1, 4, -1, 3, 4, -1, -1, 4, -1, -1, -1],
+            unfolded
+        );
+
+        let filter = [1i8, 1, 1, 1, 1, 0, 0, 0];
+        let mut output = [0i32; 8];
+        gemm_quantized(buffer, &filter, 0, 4, 2, 4, |row, col, acc| {
+            output[row * 2 + col] = acc;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!([10, 1, 4, 2, 5, 3, 1, 4], output);
+
+        // the input offset of 1 folded into the bias is cancelled by the padded values
+        let mut effective_bias = [0i32; 2];
+        calc_effective_bias(
+            FilterData::Dense(&filter),
+            None,
+            1,
+            0,
+            4,
+            4,
+            1,
+            &mut effective_bias,
+        );
+        let folded: [i32; 8] = core::array::from_fn(|i| output[i] + effective_bias[i % 2]);
+        assert_eq!([14, 2, 8, 3, 9, 4, 5, 5], folded);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::micro_array::{ArrayElem, BLiteArray};
+use crate::micro_errors::Result;
+use crate::micro_tensor::BLiteTensor;
+use core::cell::Ref;
+use num_traits::AsPrimitive;
+
+/// Returns the `idx`-th value of the packed int4 values,
+/// which are stored two per byte with the low nibble first as tensorflow lite does
+#[inline(always)]
+pub fn unpack_int4(packed: &[u8], idx: usize) -> i8 {
+    let byte = packed[idx / 2];
+    let nibble = if idx & 1 == 0 { byte << 4 } else { byte & 0xf0 };
+    // the arithmetic shift extends the sign of the nibble
+    (nibble as i8) >> 4
+}
+
+/// Unpacks all the packed int4 values into `unpacked`
+pub fn unpack_int4_into(packed: &[u8], unpacked: &mut [i8]) {
+    for (idx, v) in unpacked.iter_mut().enumerate() {
+        *v = unpack_int4(packed, idx);
+    }
+}
+
+/// Filter of the int8 kernels, whose values are stored as T or packed int4
+#[derive(Debug, Clone, Copy)]
+pub enum FilterData<'a, T> {
+    Dense(&'a [T]),
+    PackedInt4(&'a [u8]),
+}
+
+impl<'a, T: ArrayElem<T>> FilterData<'a, T> {
+    #[inline(always)]
+    pub fn get(&self, idx: usize) -> i32 {
+        match self {
+            FilterData::Dense(data) => AsPrimitive::<i32>::as_(data[idx]),
+            FilterData::PackedInt4(packed) => unpack_int4(packed, idx) as i32,
+        }
+    }
+}
+
+/// Borrowed filter tensor of the int8 kernels, which is either BTensor or I4Tensor
+pub enum FilterRef<'b, 'a, T: ArrayElem<T>> {
+    Dense(Ref<'b, BLiteArray<'a, T>>),
+    PackedInt4(Ref<'b, BLiteArray<'a, u8>>),
+}
+
+impl<'b, 'a, T: ArrayElem<T>> FilterRef<'b, 'a, T> {
+    pub fn borrow(tensor: &'b BLiteTensor<'a, T>) -> Result<Self> {
+        match tensor {
+            BLiteTensor::I4Tensor(filter) => Ok(FilterRef::PackedInt4(filter.borrow())),
+            _ => Ok(FilterRef::Dense(tensor._t()?.borrow())),
+        }
+    }
+
+    pub fn dims(&self) -> &'a [i32] {
+        match self {
+            FilterRef::Dense(filter) => filter.dims,
+            FilterRef::PackedInt4(filter) => filter.dims,
+        }
+    }
+
+    pub fn data(&self) -> FilterData<'_, T> {
+        match self {
+            FilterRef::Dense(filter) => FilterData::Dense(filter.data),
+            FilterRef::PackedInt4(filter) => FilterData::PackedInt4(filter.data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{unpack_int4_into, FilterData};
+
+    #[test]
+    fn test_unpack_int4() {
+        // [1, -1], [7, -8], [0, 3], [-5]
+        let packed = [0xf1u8, 0x87, 0x30, 0x0b];
+        let mut unpacked = [0i8; 7];
+        unpack_int4_into(&packed, &mut unpacked);
+        assert_eq!([1, -1, 7, -8, 0, 3, -5], unpacked);
+
+        let filter = FilterData::<i8>::PackedInt4(&packed);
+        let values: [i32; 7] = core::array::from_fn(|idx| filter.get(idx));
+        assert_eq!([1, -1, 7, -8, 0, 3, -5], values);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::micro_array::ArrayElem;
+use crate::micro_errors::{BLiteError, Result};
+#[cfg(feature = "no_std")]
+use num_traits::Float;
+use num_traits::{AsPrimitive, FromPrimitive};
+
+/// Returns the number of entries needed to cover every value of `T`
+pub fn lookup_table_size<T: ArrayElem<T>>() -> usize {
+    let min_v = AsPrimitive::<i32>::as_(T::MIN);
+    let max_v = AsPrimitive::<i32>::as_(T::MAX);
+    (max_v - min_v + 1) as usize
+}
+
+/// Fills `table` so that `table[q - T::MIN]` holds the quantized value of
+/// `transform(dequantize(q))`, which is the same as PopulateLookupTable of tensorflow lite.
+pub fn populate_lookup_table<T: ArrayElem<T>>(
+    table: &mut [T],
+    input_scale: f32,
+    input_zero_point: i32,
+    output_scale: f32,
+    output_zero_point: i32,
+    transform: fn(f32) -> f32,
+) -> Result<()> {
+    let min_v = AsPrimitive::<i32>::as_(T::MIN);
+    let max_v = AsPrimitive::<i32>::as_(T::MAX);
+    if table.len() != lookup_table_size::<T>() {
+        return Err(BLiteError::NotMatchSize);
+    }
+
+    let inverse_scale = 1. / output_scale;
+    for v in min_v..=max_v {
+        let dequantized = input_scale * (v - input_zero_point) as f32;
+        let transformed = transform(dequantized);
+        let rescaled = (transformed * inverse_scale).round();
+        let quantized = (rescaled + output_zero_point as f32) as i32;
+        let quantized = core::cmp::max(core::cmp::min(max_v, quantized), min_v);
+        table[(v - min_v) as usize] = FromPrimitive::from_i32(quantized).unwrap();
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub fn lookup<T: ArrayElem<T>>(table: &[T], x: T) -> T {
+    let idx = AsPrimitive::<i32>::as_(x) - AsPrimitive::<i32>::as_(T::MIN);
+    table[idx as usize]
+}
+
+/// The number of entries of the int16 lookup tables, which are 512 samples and the end point
+pub const INT16_LUT_SIZE: usize = 513;
+
+/// Fills `lut` with `func` sampled over [input_min, input_max] and scaled from
+/// [output_min, output_max] to the int16 range, which is the same as gen_lut of tensorflow lite.
+/// Each sample is biased by the half of the error at the midpoint so that
+/// the linear interpolation of `int16_table_lookup` is balanced.
+pub fn gen_int16_lut(
+    func: fn(f64) -> f64,
+    input_min: f64,
+    input_max: f64,
+    output_min: f64,
+    output_max: f64,
+    lut: &mut [i16],
+) -> Result<()> {
+    if lut.len() != INT16_LUT_SIZE {
+        return Err(BLiteError::NotMatchSize);
+    }
+
+    let nb_steps = INT16_LUT_SIZE - 1;
+    let step = (input_max - input_min) / nb_steps as f64;
+    let half_step = step / 2.;
+    let output_scaling_inv = 65536. / (output_max - output_min);
+    let table_min = i16::MIN as f64;
+    let table_max = i16::MAX as f64;
+    for (i, lut_v) in lut.iter_mut().take(nb_steps).enumerate() {
+        let val = func(input_min + i as f64 * step);
+        let val_midpoint = func(input_min + i as f64 * step + half_step);
+        let val_next = func(input_min + (i + 1) as f64 * step);
+
+        let sample_val = (val * output_scaling_inv).round();
+        let midpoint_interp_val =
+            ((val_next * output_scaling_inv + (val * output_scaling_inv).round()) / 2.).round();
+        let midpoint_val = (val_midpoint * output_scaling_inv).round();
+        let midpoint_err = midpoint_interp_val - midpoint_val;
+        let bias = (midpoint_err / 2.).round();
+        *lut_v = (sample_val - bias).max(table_min).min(table_max) as i16;
+    }
+    lut[nb_steps] = (func(input_max) * output_scaling_inv)
+        .round()
+        .max(table_min)
+        .min(table_max) as i16;
+    Ok(())
+}
+
+/// Looks up `lut` generated by `gen_int16_lut` with the linear interpolation,
+/// where the upper 9 bits of `value` select the entry and the lower 7 bits interpolate it
+#[inline(always)]
+pub fn int16_table_lookup(value: i16, lut: &[i16]) -> i16 {
+    let index = (256 + (value >> 7) as i32) as usize;
+    let offset = (value & 0x7f) as i32;
+
+    let base = lut[index] as i32;
+    let slope = lut[index + 1] as i32 - base;
+    let delta = (slope * offset + 64) >> 7;
+    (base + delta) as i16
+}
+
+/// The number of entries of the sigmoid table of the int16 logistic
+pub const SIGMOID_TABLE_SIZE: usize = 256;
+
+/// Fills `table` with sigmoid(i / 24) in the 0.16 fixed-point format,
+/// which is the same as sigmoid_table_uint16 of tensorflow lite
+pub fn populate_sigmoid_table_uint16(table: &mut [u16]) -> Result<()> {
+    if table.len() != SIGMOID_TABLE_SIZE {
+        return Err(BLiteError::NotMatchSize);
+    }
+
+    for (i, v) in table.iter_mut().enumerate() {
+        let sigmoid = 1. / (1. + (-(i as f64) / 24.).exp());
+        *v = (sigmoid * 65536.).round().min(u16::MAX as f64) as u16;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        gen_int16_lut, int16_table_lookup, lookup, lookup_table_size, populate_lookup_table,
+        INT16_LUT_SIZE,
+    };
+
+    #[test]
+    fn test_identity_lookup_table() {
+        let mut table = vec![0i8; lookup_table_size::<i8>()];
+        populate_lookup_table(&mut table, 0.5, 3, 0.5, 3, |x| x).unwrap();
+        for x in i8::MIN..=i8::MAX {
+            assert_eq!(x, lookup(&table, x));
+        }
+    }
+
+    #[test]
+    fn test_lookup_table_saturates() {
+        let mut table = vec![0i8; lookup_table_size::<i8>()];
+        populate_lookup_table(&mut table, 1.0, 0, 1.0, 0, |x| 2. * x).unwrap();
+        assert_eq!(i8::MIN, lookup(&table, -100));
+        assert_eq!(100, lookup(&table, 50));
+        assert_eq!(i8::MAX, lookup(&table, 100));
+    }
+
+    #[test]
+    fn test_int16_lut_interpolation() {
+        let mut lut = [0i16; INT16_LUT_SIZE];
+        gen_int16_lut(|x| x.exp(), -10., 0., -1., 1., &mut lut).unwrap();
+        // [-32768, 32767] corresponds to [-10.0, 0.0]
+        for value in [-32768i32, -16384, -3277, 0, 16384, 32767] {
+            let x = (value + 32768) as f64 * 10. / 65536. - 10.;
+            let expected = x.exp() * 32768.;
+            let actual = int16_table_lookup(value as i16, &lut) as f64;
+            assert!((expected - actual).abs() <= 2., "{expected} != {actual}");
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use crate::micro_errors::Result;
+use core::ops::Range;
+
 pub fn compute_padding_height_width(
     padding: usize,
     stride_h: i32,
@@ -57,3 +60,65 @@ pub(super) fn compute_padding_with_offset(
     let pad = total_padding / 2;
     return (pad, offset);
 }
+
+/// The output rows of one batch, which are evaluated as an image of `output_height` rows
+/// from the input starting at `input_first_row` with the shifted padding
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutputRows {
+    pub batch: usize,
+    pub first_row: i32,
+    pub output_height: i32,
+    pub input_first_row: i32,
+    pub input_height: i32,
+    pub padding_h: i32,
+}
+
+/// Splits the output rows `units`, which are numbered as `batch * output_height + out_y`, into each batch,
+/// and evaluates `kernel(input, output, rows)` with the input of the batch from `rows.input_first_row`
+/// and the part of `output_chunk` holding the rows.
+/// The kernels then see the rows as a single batch, whose input y is the same as the whole image.
+pub fn for_each_output_rows<T>(
+    input_data: &[T],
+    output_chunk: &mut [T],
+    units: Range<usize>,
+    input_height: i32,
+    input_row_len: usize,
+    output_height: i32,
+    output_row_len: usize,
+    stride_h: i32,
+    padding_h: i32,
+    mut kernel: impl FnMut(&[T], &mut [T], OutputRows) -> Result<()>,
+) -> Result<()> {
+    let output_height_usize = output_height.max(1) as usize;
+    let mut unit = units.start;
+    while unit < units.end {
+        let batch = unit / output_height_usize;
+        let first_row = unit % output_height_usize;
+        let num_rows = (output_height_usize - first_row).min(units.end - unit);
+
+        // the first input row read by the first output row, whose upper side is padded
+        let origin = first_row as i32 * stride_h - padding_h;
+        let input_first_row = origin.clamp(0, input_height);
+        let rows = OutputRows {
+            batch,
+            first_row: first_row as i32,
+            output_height: num_rows as i32,
+            input_first_row,
+            input_height: input_height - input_first_row,
+            padding_h: input_first_row - origin,
+        };
+
+        let input_start =
+            (batch * input_height as usize + input_first_row as usize) * input_row_len;
+        let input_end = (batch + 1) * input_height as usize * input_row_len;
+        let output_start = (unit - units.start) * output_row_len;
+        let output_end = output_start + num_rows * output_row_len;
+        kernel(
+            &input_data[input_start..input_end],
+            &mut output_chunk[output_start..output_end],
+            rows,
+        )?;
+        unit += num_rows;
+    }
+    Ok(())
+}
@@ -1,5 +1,8 @@
+use crate::micro_array::ArrayElem;
 use crate::micro_errors::BLiteError::{self, NotMatchScale};
 use crate::micro_errors::Result;
+use core::cell::Cell;
+use num_traits::AsPrimitive;
 #[cfg(feature = "no_std")]
 use num_traits::Float;
 
@@ -87,6 +90,117 @@ pub fn multiply_by_quantized_multiplier(
     Ok(result as i32)
 }
 
+/// The int64 version of multiply_by_quantized_multiplier for the int64 accumulators of the int16x8 kernels.
+/// The multiplier is reduced to 16 bits so that the product does not overflow,
+/// which is the same as MultiplyByQuantizedMultiplier(int64_t, ...) of tensorflow lite
+pub fn multiply_by_quantized_multiplier_i64(
+    x: i64,
+    quantized_multiplier: i32,
+    shift: i32,
+) -> Result<i32> {
+    if !(quantized_multiplier >= 0 && (-31..8).contains(&shift)) {
+        return Err(BLiteError::InCompatibleCasting);
+    }
+    if !(-(1i64 << 47)..(1i64 << 47)).contains(&x) {
+        return Err(BLiteError::InCompatibleCasting);
+    }
+
+    let reduced_multiplier = if quantized_multiplier < 0x7FFF0000 {
+        ((quantized_multiplier + (1 << 15)) >> 16) as i64
+    } else {
+        0x7FFF
+    };
+    let total_shift = (15 - shift) as i64;
+    let round = 1i64 << (total_shift - 1);
+    let mut result = x * reduced_multiplier + round;
+    result >>= total_shift;
+
+    if !(i32::MIN as i64..=i32::MAX as i64).contains(&result) {
+        return Err(BLiteError::InCompatibleCasting);
+    }
+
+    Ok(result as i32)
+}
+
+/// Quantizes `values` into int8 symmetrically on the fly for the hybrid kernels,
+/// and returns the scaling factor, which is the same as SymmetricQuantizeFloats of tensorflow lite
+pub fn symmetric_quantize_floats<T: ArrayElem<T>>(values: &[T], quantized: &[Cell<i8>]) -> f32 {
+    symmetric_quantize_values(
+        values.iter().map(|&v| AsPrimitive::<f32>::as_(v)),
+        quantized,
+    )
+}
+
+/// Quantizes the float `values` of `symmetric_quantize_floats`, which may be read from cells
+pub fn symmetric_quantize_values(
+    values: impl Iterator<Item = f32> + Clone,
+    quantized: &[Cell<i8>],
+) -> f32 {
+    const SCALE: f32 = 127.;
+    let range = values.clone().fold(0f32, |range, v| range.max(v.abs()));
+    if range == 0. {
+        quantized.iter().for_each(|q| q.set(0));
+        return 1.;
+    }
+
+    let scaling_factor_inv = SCALE / range;
+    for (q, v) in quantized.iter().zip(values) {
+        let quantized_v = (v * scaling_factor_inv).round();
+        q.set(quantized_v.clamp(-SCALE, SCALE) as i8);
+    }
+    range / SCALE
+}
+
+/// Quantizes `values` into int8 asymmetrically on the fly for the hybrid kernels,
+/// and returns the scaling factor and the zero point,
+/// which is the same as AsymmetricQuantizeFloats of tensorflow lite
+pub fn asymmetric_quantize_floats<T: ArrayElem<T>>(
+    values: &[T],
+    quantized: &[Cell<i8>],
+) -> (f32, i32) {
+    asymmetric_quantize_values(
+        values.iter().map(|&v| AsPrimitive::<f32>::as_(v)),
+        quantized,
+    )
+}
+
+/// Quantizes the float `values` of `asymmetric_quantize_floats`, which may be read from cells
+pub fn asymmetric_quantize_values(
+    values: impl Iterator<Item = f32> + Clone,
+    quantized: &[Cell<i8>],
+) -> (f32, i32) {
+    let qmin = i8::MIN as f64;
+    let qmax = i8::MAX as f64;
+    let (rmin, rmax) = values.clone().fold((0f64, 0f64), |(rmin, rmax), v| {
+        let v = v as f64;
+        (rmin.min(v), rmax.max(v))
+    });
+    if rmin == rmax {
+        quantized.iter().for_each(|q| q.set(0));
+        return (1., 0);
+    }
+
+    let scale = (rmax - rmin) / (qmax - qmin);
+    let zero_point_from_min = qmin - rmin / scale;
+    let zero_point_from_max = qmax - rmax / scale;
+    let zero_point_from_min_error = qmin.abs() + (rmin / scale).abs();
+    let zero_point_from_max_error = qmax.abs() + (rmax / scale).abs();
+    let zero_point = if zero_point_from_min_error < zero_point_from_max_error {
+        zero_point_from_min
+    } else {
+        zero_point_from_max
+    };
+    let nudged_zero_point = zero_point.round().clamp(qmin, qmax) as i32;
+
+    let scaling_factor = scale as f32;
+    let scaling_factor_inv = 1. / scaling_factor;
+    for (q, v) in quantized.iter().zip(values) {
+        let quantized_v = (nudged_zero_point as f32 + v * scaling_factor_inv).round();
+        q.set(quantized_v.clamp(qmin as f32, qmax as f32) as i8);
+    }
+    (scaling_factor, nudged_zero_point)
+}
+
 pub fn quantize(scale: f32, zero_point: i32, f: f32) -> Result<i32> {
     let tmp = (f / scale).round();
 
@@ -139,4 +253,38 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn multiply_by_quantized_multiplier_i64() {
+        let (quantized_multiplier, shift) = super::quantize_multiplier(0.25).unwrap();
+        let tt = [(1000i64, 250), (-1000, -250), (1i64 << 32, 1 << 30)];
+        for (x, expected) in tt {
+            assert_eq!(
+                expected,
+                super::multiply_by_quantized_multiplier_i64(x, quantized_multiplier, shift)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn quantize_floats() {
+        use core::cell::Cell;
+
+        let values = [-1.5f32, -0.25, 0., 0.5, 3.];
+        let mut quantized = [0i8; 5];
+        let quantized = Cell::from_mut(&mut quantized[..]).as_slice_of_cells();
+
+        let scaling_factor = super::symmetric_quantize_floats(&values, quantized);
+        assert_eq!(3. / 127., scaling_factor);
+        for (q, &v) in quantized.iter().zip(values.iter()) {
+            assert!((q.get() as f32 * scaling_factor - v).abs() <= scaling_factor / 2.);
+        }
+
+        let (scaling_factor, zero_point) = super::asymmetric_quantize_floats(&values, quantized);
+        for (q, &v) in quantized.iter().zip(values.iter()) {
+            let dequantized = (q.get() as i32 - zero_point) as f32 * scaling_factor;
+            assert!((dequantized - v).abs() <= scaling_factor);
+        }
+    }
 }
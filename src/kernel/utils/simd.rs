@@ -0,0 +1,637 @@
+//! Vectorised inner loops of the kernels, which are enabled by the `simd` feature.
+//! The instruction set is detected at runtime on x86_64 (AVX2 + FMA or SSE4.1),
+//! and NEON is used on aarch64. The other targets fall back to the scalar loops,
+//! which are the same as the reference kernels.
+//! The int8 loops are exact, so their results are bit-exact with the reference kernels.
+use core::any::TypeId;
+use core::cell::Cell;
+
+// the number of the channels which are accumulated at once on the stack
+pub const CHANNEL_BLOCK: usize = 32;
+
+/// Reinterprets `data` as `&[E]` if `T` is `E`, which dispatches the generic kernels to the vectorised loops
+#[inline(always)]
+pub fn cast_slice<T: 'static, E: 'static>(data: &[T]) -> Option<&[E]> {
+    if TypeId::of::<T>() != TypeId::of::<E>() {
+        return None;
+    }
+    Some(unsafe { core::slice::from_raw_parts(data.as_ptr() as *const E, data.len()) })
+}
+
+/// Reinterprets `data` as `&mut [E]` if `T` is `E`
+#[inline(always)]
+pub fn cast_slice_mut<T: 'static, E: 'static>(data: &mut [T]) -> Option<&mut [E]> {
+    if TypeId::of::<T>() != TypeId::of::<E>() {
+        return None;
+    }
+    Some(unsafe { core::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut E, data.len()) })
+}
+
+/// Reinterprets `value` as `E` if `T` is `E`
+#[inline(always)]
+pub fn cast_value<T: 'static + Copy, E: 'static + Copy>(value: T) -> Option<E> {
+    cast_slice::<T, E>(core::slice::from_ref(&value)).map(|v| v[0])
+}
+
+/// Reads the cells as a slice
+///
+/// # Safety
+/// The cells must not be written while the returned slice is alive
+#[inline(always)]
+pub unsafe fn cells_as_slice<T>(cells: &[Cell<T>]) -> &[T] {
+    core::slice::from_raw_parts(cells.as_ptr() as *const T, cells.len())
+}
+
+/*-----------------------------------------------------------------------------*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Isa {
+    Scalar,
+    #[cfg(target_arch = "x86_64")]
+    Sse41,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "no_std")))]
+#[inline(always)]
+fn isa() -> Isa {
+    if std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma") {
+        Isa::Avx2
+    } else if std::arch::is_x86_feature_detected!("sse4.1") {
+        Isa::Sse41
+    } else {
+        Isa::Scalar
+    }
+}
+
+// the runtime detection needs std, so no_std relies on the target features of the build
+#[cfg(all(target_arch = "x86_64", feature = "no_std"))]
+#[inline(always)]
+fn isa() -> Isa {
+    if cfg!(all(target_feature = "avx2", target_feature = "fma")) {
+        Isa::Avx2
+    } else if cfg!(target_feature = "sse4.1") {
+        Isa::Sse41
+    } else {
+        Isa::Scalar
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+fn isa() -> Isa {
+    if cfg!(target_feature = "neon") {
+        Isa::Neon
+    } else {
+        Isa::Scalar
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline(always)]
+fn isa() -> Isa {
+    Isa::Scalar
+}
+
+/*-----------------------------------------------------------------------------*/
+/// Returns the sum of `lhs[i] * rhs[i]` in int32
+#[inline(always)]
+pub fn dot_i8(lhs: &[i8], rhs: &[i8]) -> i32 {
+    let len = lhs.len().min(rhs.len());
+    let (lhs, rhs) = (&lhs[..len], &rhs[..len]);
+    match isa() {
+        #[cfg(target_arch = "x86_64")]
+        Isa::Avx2 => unsafe { x86::dot_i8_avx2(lhs, rhs) },
+        #[cfg(target_arch = "x86_64")]
+        Isa::Sse41 => unsafe { x86::dot_i8_sse41(lhs, rhs) },
+        #[cfg(target_arch = "aarch64")]
+        Isa::Neon => unsafe { neon::dot_i8(lhs, rhs) },
+        _ => scalar::dot_i8(lhs, rhs),
+    }
+}
+
+/// Adds `lhs[i] * rhs[i]` to `acc[i]` in int32
+#[inline(always)]
+pub fn mac_i8(acc: &mut [i32], lhs: &[i8], rhs: &[i8]) {
+    let len = acc.len();
+    let (lhs, rhs) = (&lhs[..len], &rhs[..len]);
+    match isa() {
+        #[cfg(target_arch = "x86_64")]
+        Isa::Avx2 => unsafe { x86::mac_i8_avx2(acc, lhs, rhs) },
+        #[cfg(target_arch = "x86_64")]
+        Isa::Sse41 => unsafe { x86::mac_i8_sse41(acc, lhs, rhs) },
+        #[cfg(target_arch = "aarch64")]
+        Isa::Neon => unsafe { neon::mac_i8(acc, lhs, rhs) },
+        _ => scalar::mac_i8(acc, lhs, rhs),
+    }
+}
+
+/// Replaces `acc[i]` with the max of `acc[i]` and `src[i]`
+#[inline(always)]
+pub fn max_i8(acc: &mut [i8], src: &[i8]) {
+    let src = &src[..acc.len()];
+    match isa() {
+        #[cfg(target_arch = "x86_64")]
+        Isa::Avx2 => unsafe { x86::max_i8_avx2(acc, src) },
+        #[cfg(target_arch = "x86_64")]
+        Isa::Sse41 => unsafe { x86::max_i8_sse41(acc, src) },
+        #[cfg(target_arch = "aarch64")]
+        Isa::Neon => unsafe { neon::max_i8(acc, src) },
+        _ => scalar::max_i8(acc, src),
+    }
+}
+
+/// Returns the sum of `lhs[i] * rhs[i]`, whose order of the additions differs from the scalar loop
+#[inline(always)]
+pub fn dot_f32(lhs: &[f32], rhs: &[f32]) -> f32 {
+    let len = lhs.len().min(rhs.len());
+    let (lhs, rhs) = (&lhs[..len], &rhs[..len]);
+    match isa() {
+        #[cfg(target_arch = "x86_64")]
+        Isa::Avx2 => unsafe { x86::dot_f32_fma(lhs, rhs) },
+        // SSE2 is the baseline of x86_64
+        #[cfg(target_arch = "x86_64")]
+        _ => unsafe { x86::dot_f32_sse(lhs, rhs) },
+        #[cfg(target_arch = "aarch64")]
+        Isa::Neon => unsafe { neon::dot_f32(lhs, rhs) },
+        #[cfg(not(target_arch = "x86_64"))]
+        _ => scalar::dot_f32(lhs, rhs),
+    }
+}
+
+/// Adds `lhs[i] * rhs[i]` to `acc[i]`, which is fused on AVX2 and NEON
+#[inline(always)]
+pub fn mac_f32(acc: &mut [f32], lhs: &[f32], rhs: &[f32]) {
+    let len = acc.len();
+    let (lhs, rhs) = (&lhs[..len], &rhs[..len]);
+    match isa() {
+        #[cfg(target_arch = "x86_64")]
+        Isa::Avx2 => unsafe { x86::mac_f32_fma(acc, lhs, rhs) },
+        #[cfg(target_arch = "x86_64")]
+        _ => unsafe { x86::mac_f32_sse(acc, lhs, rhs) },
+        #[cfg(target_arch = "aarch64")]
+        Isa::Neon => unsafe { neon::mac_f32(acc, lhs, rhs) },
+        #[cfg(not(target_arch = "x86_64"))]
+        _ => scalar::mac_f32(acc, lhs, rhs),
+    }
+}
+
+/// Replaces `acc[i]` with the max of `acc[i]` and `src[i]`, which ignores NaN of `src` like the scalar loop
+#[inline(always)]
+pub fn max_f32(acc: &mut [f32], src: &[f32]) {
+    let src = &src[..acc.len()];
+    match isa() {
+        #[cfg(target_arch = "x86_64")]
+        Isa::Avx2 => unsafe { x86::max_f32_avx(acc, src) },
+        #[cfg(target_arch = "x86_64")]
+        _ => unsafe { x86::max_f32_sse(acc, src) },
+        #[cfg(target_arch = "aarch64")]
+        Isa::Neon => unsafe { neon::max_f32(acc, src) },
+        #[cfg(not(target_arch = "x86_64"))]
+        _ => scalar::max_f32(acc, src),
+    }
+}
+
+/*-----------------------------------------------------------------------------*/
+// the reference loops, which also process the tails of the vectorised loops
+mod scalar {
+    #[inline(always)]
+    pub fn dot_i8(lhs: &[i8], rhs: &[i8]) -> i32 {
+        lhs.iter()
+            .zip(rhs)
+            .map(|(&l, &r)| l as i32 * r as i32)
+            .sum()
+    }
+
+    #[inline(always)]
+    pub fn mac_i8(acc: &mut [i32], lhs: &[i8], rhs: &[i8]) {
+        for ((acc, &l), &r) in acc.iter_mut().zip(lhs).zip(rhs) {
+            *acc += l as i32 * r as i32;
+        }
+    }
+
+    #[inline(always)]
+    pub fn max_i8(acc: &mut [i8], src: &[i8]) {
+        for (acc, &v) in acc.iter_mut().zip(src) {
+            if v > *acc {
+                *acc = v;
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn dot_f32(lhs: &[f32], rhs: &[f32]) -> f32 {
+        let mut total = 0.;
+        for (&l, &r) in lhs.iter().zip(rhs) {
+            total += l * r;
+        }
+        total
+    }
+
+    #[inline(always)]
+    pub fn mac_f32(acc: &mut [f32], lhs: &[f32], rhs: &[f32]) {
+        for ((acc, &l), &r) in acc.iter_mut().zip(lhs).zip(rhs) {
+            *acc += l * r;
+        }
+    }
+
+    #[inline(always)]
+    pub fn max_f32(acc: &mut [f32], src: &[f32]) {
+        for (acc, &v) in acc.iter_mut().zip(src) {
+            if v > *acc {
+                *acc = v;
+            }
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------*/
+// the slices of the arguments have the same length, which is checked by the dispatchers
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::scalar;
+    use core::arch::x86_64::*;
+
+    #[inline(always)]
+    unsafe fn hsum_epi32(v: __m128i) -> i32 {
+        let v = _mm_add_epi32(v, _mm_shuffle_epi32(v, 0b01_00_11_10));
+        let v = _mm_add_epi32(v, _mm_shuffle_epi32(v, 0b10_11_00_01));
+        _mm_cvtsi128_si32(v)
+    }
+
+    #[inline(always)]
+    unsafe fn hsum_ps(v: __m128) -> f32 {
+        let v = _mm_add_ps(v, _mm_movehl_ps(v, v));
+        let v = _mm_add_ss(v, _mm_shuffle_ps(v, v, 0b01_01_01_01));
+        _mm_cvtss_f32(v)
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn dot_i8_avx2(lhs: &[i8], rhs: &[i8]) -> i32 {
+        let len = lhs.len();
+        let mut acc = _mm256_setzero_si256();
+        let mut i = 0;
+        while i + 16 <= len {
+            let l = _mm_loadu_si128(lhs.as_ptr().add(i) as *const __m128i);
+            let r = _mm_loadu_si128(rhs.as_ptr().add(i) as *const __m128i);
+            // the products of int8 are summed pairwise into int32 without overflow
+            let prod = _mm256_madd_epi16(_mm256_cvtepi8_epi16(l), _mm256_cvtepi8_epi16(r));
+            acc = _mm256_add_epi32(acc, prod);
+            i += 16;
+        }
+        let acc = _mm_add_epi32(
+            _mm256_castsi256_si128(acc),
+            _mm256_extracti128_si256(acc, 1),
+        );
+        hsum_epi32(acc) + scalar::dot_i8(&lhs[i..], &rhs[i..])
+    }
+
+    #[target_feature(enable = "sse4.1")]
+    pub unsafe fn dot_i8_sse41(lhs: &[i8], rhs: &[i8]) -> i32 {
+        let len = lhs.len();
+        let mut acc = _mm_setzero_si128();
+        let mut i = 0;
+        while i + 8 <= len {
+            let l = _mm_loadl_epi64(lhs.as_ptr().add(i) as *const __m128i);
+            let r = _mm_loadl_epi64(rhs.as_ptr().add(i) as *const __m128i);
+            let prod = _mm_madd_epi16(_mm_cvtepi8_epi16(l), _mm_cvtepi8_epi16(r));
+            acc = _mm_add_epi32(acc, prod);
+            i += 8;
+        }
+        hsum_epi32(acc) + scalar::dot_i8(&lhs[i..], &rhs[i..])
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn mac_i8_avx2(acc: &mut [i32], lhs: &[i8], rhs: &[i8]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 8 <= len {
+            let l = _mm256_cvtepi8_epi32(_mm_loadl_epi64(lhs.as_ptr().add(i) as *const __m128i));
+            let r = _mm256_cvtepi8_epi32(_mm_loadl_epi64(rhs.as_ptr().add(i) as *const __m128i));
+            let dst = acc.as_mut_ptr().add(i) as *mut __m256i;
+            let sum = _mm256_add_epi32(_mm256_loadu_si256(dst), _mm256_mullo_epi32(l, r));
+            _mm256_storeu_si256(dst, sum);
+            i += 8;
+        }
+        scalar::mac_i8(&mut acc[i..], &lhs[i..], &rhs[i..]);
+    }
+
+    #[target_feature(enable = "sse4.1")]
+    pub unsafe fn mac_i8_sse41(acc: &mut [i32], lhs: &[i8], rhs: &[i8]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 4 <= len {
+            let l = (lhs.as_ptr().add(i) as *const i32).read_unaligned();
+            let r = (rhs.as_ptr().add(i) as *const i32).read_unaligned();
+            let l = _mm_cvtepi8_epi32(_mm_cvtsi32_si128(l));
+            let r = _mm_cvtepi8_epi32(_mm_cvtsi32_si128(r));
+            let dst = acc.as_mut_ptr().add(i) as *mut __m128i;
+            _mm_storeu_si128(
+                dst,
+                _mm_add_epi32(_mm_loadu_si128(dst), _mm_mullo_epi32(l, r)),
+            );
+            i += 4;
+        }
+        scalar::mac_i8(&mut acc[i..], &lhs[i..], &rhs[i..]);
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn max_i8_avx2(acc: &mut [i8], src: &[i8]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 32 <= len {
+            let dst = acc.as_mut_ptr().add(i) as *mut __m256i;
+            let v = _mm256_loadu_si256(src.as_ptr().add(i) as *const __m256i);
+            _mm256_storeu_si256(dst, _mm256_max_epi8(_mm256_loadu_si256(dst), v));
+            i += 32;
+        }
+        scalar::max_i8(&mut acc[i..], &src[i..]);
+    }
+
+    #[target_feature(enable = "sse4.1")]
+    pub unsafe fn max_i8_sse41(acc: &mut [i8], src: &[i8]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 16 <= len {
+            let dst = acc.as_mut_ptr().add(i) as *mut __m128i;
+            let v = _mm_loadu_si128(src.as_ptr().add(i) as *const __m128i);
+            _mm_storeu_si128(dst, _mm_max_epi8(_mm_loadu_si128(dst), v));
+            i += 16;
+        }
+        scalar::max_i8(&mut acc[i..], &src[i..]);
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn dot_f32_fma(lhs: &[f32], rhs: &[f32]) -> f32 {
+        let len = lhs.len();
+        let mut acc = _mm256_setzero_ps();
+        let mut i = 0;
+        while i + 8 <= len {
+            let l = _mm256_loadu_ps(lhs.as_ptr().add(i));
+            let r = _mm256_loadu_ps(rhs.as_ptr().add(i));
+            acc = _mm256_fmadd_ps(l, r, acc);
+            i += 8;
+        }
+        let acc = _mm_add_ps(_mm256_castps256_ps128(acc), _mm256_extractf128_ps(acc, 1));
+        hsum_ps(acc) + scalar::dot_f32(&lhs[i..], &rhs[i..])
+    }
+
+    pub unsafe fn dot_f32_sse(lhs: &[f32], rhs: &[f32]) -> f32 {
+        let len = lhs.len();
+        let mut acc = _mm_setzero_ps();
+        let mut i = 0;
+        while i + 4 <= len {
+            let l = _mm_loadu_ps(lhs.as_ptr().add(i));
+            let r = _mm_loadu_ps(rhs.as_ptr().add(i));
+            acc = _mm_add_ps(acc, _mm_mul_ps(l, r));
+            i += 4;
+        }
+        hsum_ps(acc) + scalar::dot_f32(&lhs[i..], &rhs[i..])
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn mac_f32_fma(acc: &mut [f32], lhs: &[f32], rhs: &[f32]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 8 <= len {
+            let l = _mm256_loadu_ps(lhs.as_ptr().add(i));
+            let r = _mm256_loadu_ps(rhs.as_ptr().add(i));
+            let dst = acc.as_mut_ptr().add(i);
+            _mm256_storeu_ps(dst, _mm256_fmadd_ps(l, r, _mm256_loadu_ps(dst)));
+            i += 8;
+        }
+        scalar::mac_f32(&mut acc[i..], &lhs[i..], &rhs[i..]);
+    }
+
+    pub unsafe fn mac_f32_sse(acc: &mut [f32], lhs: &[f32], rhs: &[f32]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 4 <= len {
+            let l = _mm_loadu_ps(lhs.as_ptr().add(i));
+            let r = _mm_loadu_ps(rhs.as_ptr().add(i));
+            let dst = acc.as_mut_ptr().add(i);
+            _mm_storeu_ps(dst, _mm_add_ps(_mm_loadu_ps(dst), _mm_mul_ps(l, r)));
+            i += 4;
+        }
+        scalar::mac_f32(&mut acc[i..], &lhs[i..], &rhs[i..]);
+    }
+
+    // maxps returns the second operand if either is NaN, which keeps the accumulator
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn max_f32_avx(acc: &mut [f32], src: &[f32]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 8 <= len {
+            let dst = acc.as_mut_ptr().add(i);
+            let v = _mm256_loadu_ps(src.as_ptr().add(i));
+            _mm256_storeu_ps(dst, _mm256_max_ps(v, _mm256_loadu_ps(dst)));
+            i += 8;
+        }
+        scalar::max_f32(&mut acc[i..], &src[i..]);
+    }
+
+    pub unsafe fn max_f32_sse(acc: &mut [f32], src: &[f32]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 4 <= len {
+            let dst = acc.as_mut_ptr().add(i);
+            let v = _mm_loadu_ps(src.as_ptr().add(i));
+            _mm_storeu_ps(dst, _mm_max_ps(v, _mm_loadu_ps(dst)));
+            i += 4;
+        }
+        scalar::max_f32(&mut acc[i..], &src[i..]);
+    }
+}
+
+/*-----------------------------------------------------------------------------*/
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use super::scalar;
+    use core::arch::aarch64::*;
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn dot_i8(lhs: &[i8], rhs: &[i8]) -> i32 {
+        let len = lhs.len();
+        let mut acc = vdupq_n_s32(0);
+        let mut i = 0;
+        while i + 16 <= len {
+            let l = vld1q_s8(lhs.as_ptr().add(i));
+            let r = vld1q_s8(rhs.as_ptr().add(i));
+            // the products of int8 fit in int16, and they are summed pairwise into int32
+            acc = vpadalq_s16(acc, vmull_s8(vget_low_s8(l), vget_low_s8(r)));
+            acc = vpadalq_s16(acc, vmull_high_s8(l, r));
+            i += 16;
+        }
+        vaddvq_s32(acc) + scalar::dot_i8(&lhs[i..], &rhs[i..])
+    }
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn mac_i8(acc: &mut [i32], lhs: &[i8], rhs: &[i8]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 8 <= len {
+            let prod = vmull_s8(vld1_s8(lhs.as_ptr().add(i)), vld1_s8(rhs.as_ptr().add(i)));
+            let dst = acc.as_mut_ptr().add(i);
+            vst1q_s32(dst, vaddw_s16(vld1q_s32(dst), vget_low_s16(prod)));
+            vst1q_s32(dst.add(4), vaddw_high_s16(vld1q_s32(dst.add(4)), prod));
+            i += 8;
+        }
+        scalar::mac_i8(&mut acc[i..], &lhs[i..], &rhs[i..]);
+    }
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn max_i8(acc: &mut [i8], src: &[i8]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 16 <= len {
+            let dst = acc.as_mut_ptr().add(i);
+            vst1q_s8(dst, vmaxq_s8(vld1q_s8(dst), vld1q_s8(src.as_ptr().add(i))));
+            i += 16;
+        }
+        scalar::max_i8(&mut acc[i..], &src[i..]);
+    }
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn dot_f32(lhs: &[f32], rhs: &[f32]) -> f32 {
+        let len = lhs.len();
+        let mut acc = vdupq_n_f32(0.);
+        let mut i = 0;
+        while i + 4 <= len {
+            acc = vfmaq_f32(
+                acc,
+                vld1q_f32(lhs.as_ptr().add(i)),
+                vld1q_f32(rhs.as_ptr().add(i)),
+            );
+            i += 4;
+        }
+        vaddvq_f32(acc) + scalar::dot_f32(&lhs[i..], &rhs[i..])
+    }
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn mac_f32(acc: &mut [f32], lhs: &[f32], rhs: &[f32]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 4 <= len {
+            let dst = acc.as_mut_ptr().add(i);
+            let l = vld1q_f32(lhs.as_ptr().add(i));
+            let r = vld1q_f32(rhs.as_ptr().add(i));
+            vst1q_f32(dst, vfmaq_f32(vld1q_f32(dst), l, r));
+            i += 4;
+        }
+        scalar::mac_f32(&mut acc[i..], &lhs[i..], &rhs[i..]);
+    }
+
+    // fmaxnm returns the number if the other is NaN, which keeps the accumulator
+    #[target_feature(enable = "neon")]
+    pub unsafe fn max_f32(acc: &mut [f32], src: &[f32]) {
+        let len = acc.len();
+        let mut i = 0;
+        while i + 4 <= len {
+            let dst = acc.as_mut_ptr().add(i);
+            vst1q_f32(
+                dst,
+                vmaxnmq_f32(vld1q_f32(dst), vld1q_f32(src.as_ptr().add(i))),
+            );
+            i += 4;
+        }
+        scalar::max_f32(&mut acc[i..], &src[i..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dot_f32, dot_i8, mac_f32, mac_i8, max_f32, max_i8, scalar};
+
+    // a linear congruential generator, which covers the extremes of int8
+    fn values(len: usize, seed: u32) -> Vec<i8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                (state >> 16) as i8
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_int8_loops_are_bit_exact() {
+        for len in 0..100 {
+            let mut lhs = values(len, len as u32);
+            let rhs = values(len, len as u32 + 7);
+            if len > 0 {
+                lhs[0] = i8::MIN;
+            }
+            assert_eq!(scalar::dot_i8(&lhs, &rhs), dot_i8(&lhs, &rhs), "{len}");
+
+            let mut expected: Vec<i32> = (0..len as i32).map(|i| i * 1000 - 50000).collect();
+            let mut acc = expected.clone();
+            scalar::mac_i8(&mut expected, &lhs, &rhs);
+            mac_i8(&mut acc, &lhs, &rhs);
+            assert_eq!(expected, acc, "{len}");
+
+            let mut expected = rhs.clone();
+            let mut acc = rhs.clone();
+            scalar::max_i8(&mut expected, &lhs);
+            max_i8(&mut acc, &lhs);
+            assert_eq!(expected, acc, "{len}");
+        }
+    }
+
+    // the dispatchers pick AVX2 on most hosts, so SSE4.1 is checked directly
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_sse41_loops_are_bit_exact() {
+        if !std::arch::is_x86_feature_detected!("sse4.1") {
+            return;
+        }
+        for len in 0..40 {
+            let lhs = values(len, 11);
+            let rhs = values(len, 13);
+            let dot = unsafe { super::x86::dot_i8_sse41(&lhs, &rhs) };
+            assert_eq!(scalar::dot_i8(&lhs, &rhs), dot, "{len}");
+
+            let mut expected = vec![7i32; len];
+            let mut acc = vec![7i32; len];
+            scalar::mac_i8(&mut expected, &lhs, &rhs);
+            unsafe { super::x86::mac_i8_sse41(&mut acc, &lhs, &rhs) };
+            assert_eq!(expected, acc, "{len}");
+
+            let mut expected = rhs.clone();
+            let mut acc = rhs.clone();
+            scalar::max_i8(&mut expected, &lhs);
+            unsafe { super::x86::max_i8_sse41(&mut acc, &lhs) };
+            assert_eq!(expected, acc, "{len}");
+        }
+    }
+
+    #[test]
+    fn test_float_loops_match_scalar() {
+        for len in 0..100 {
+            let lhs: Vec<f32> = values(len, 3).iter().map(|&v| v as f32 / 64.).collect();
+            let rhs: Vec<f32> = values(len, 5).iter().map(|&v| v as f32 / 32.).collect();
+            let expected = scalar::dot_f32(&lhs, &rhs);
+            assert!((expected - dot_f32(&lhs, &rhs)).abs() < 1e-3, "{len}");
+
+            let mut expected = lhs.clone();
+            let mut acc = lhs.clone();
+            scalar::mac_f32(&mut expected, &lhs, &rhs);
+            mac_f32(&mut acc, &lhs, &rhs);
+            for (e, a) in expected.iter().zip(&acc) {
+                assert!((e - a).abs() < 1e-5, "{len}");
+            }
+
+            // NaN of the source is ignored
+            let mut src = rhs.clone();
+            if len > 0 {
+                src[len / 2] = f32::NAN;
+            }
+            let mut expected = lhs.clone();
+            let mut acc = lhs.clone();
+            scalar::max_f32(&mut expected, &src);
+            max_f32(&mut acc, &src);
+            assert_eq!(expected, acc, "{len}");
+        }
+    }
+}
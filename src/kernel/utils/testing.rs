@@ -1,6 +1,15 @@
+use core::cell::RefCell;
+use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
 use num_traits::FromPrimitive;
 
-use crate::micro_array::ArrayElem;
+use crate::kernel::micro_builtin_options::BLiteBuiltinOption;
+use crate::micro_allocator::{ArenaAllocator, BumpArenaAllocator};
+use crate::micro_array::{ArrayElem, BLiteArray, BLiteQuantizationParams};
+use crate::micro_context::BLiteContext;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_tensor::BLiteTensor::{self, BTensor, I32Tensor};
+use crate::tflite_schema_generated::tflite::{BuiltinOptions, Operator, OperatorArgs};
 
 pub struct Tensor<T>
 where
@@ -19,20 +28,235 @@ where
     }
 
     pub fn ones(shape: &[i32]) -> Self {
-        assert!(shape.len() > 0, "expected non empty, but got 0");
+        assert!(!shape.is_empty(), "expected non empty, but got 0");
         let tot = shape.iter().fold(1usize, |x, &acc| x * acc as usize);
         let v = FromPrimitive::from_usize(1).unwrap();
         let data = vec![v; tot];
-        let shape = shape.iter().cloned().collect();
+        let shape = shape.to_vec();
         Self { data, shape }
     }
 
     pub fn zeros(shape: &[i32]) -> Self {
-        assert!(shape.len() > 0, "expected non empty, but got 0");
+        assert!(!shape.is_empty(), "expected non empty, but got 0");
         let tot = shape.iter().fold(1, |x, &acc| x * acc as usize);
         let v = FromPrimitive::from_usize(0).unwrap();
         let data = vec![v; tot];
-        let shape = shape.iter().cloned().collect();
+        let shape = shape.to_vec();
         Self { data, shape }
     }
 }
+
+/// The eval function of an operator, which is called on the tensors of a test
+pub type Eval<T = f32> = for<'b> fn(
+    &BLiteContext,
+    &'b mut [BLiteTensor<'b, T>],
+    &BLiteNode<'b>,
+    BLiteBuiltinOption<T>,
+) -> Result<()>;
+
+/// The parser of an operator, which allocates the options from the arena of a test
+pub type Parser<T> = for<'b> fn(
+    &mut BumpArenaAllocator,
+    Operator,
+    &mut [BLiteTensor<'b, T>],
+) -> Result<BLiteBuiltinOption<'b, T>>;
+
+const ARENA_SIZE: usize = 1024;
+
+/// Runs `eval` on the int32 tensors of `inputs`,
+/// and returns the data of the outputs whose shapes are `output_shapes`
+pub fn eval_i32(
+    eval: Eval,
+    inputs: &[Tensor<i32>],
+    output_shapes: &[&[i32]],
+    builtin_option: BLiteBuiltinOption<f32>,
+) -> Result<Vec<Vec<i32>>> {
+    let mut input_datas: Vec<Vec<i32>> = inputs.iter().map(|x| x.data.clone()).collect();
+    let mut output_datas: Vec<Vec<i32>> = output_shapes
+        .iter()
+        .map(|shape| vec![0; shape.iter().product::<i32>() as usize])
+        .collect();
+
+    let mut tensors: Vec<BLiteTensor<f32>> = input_datas
+        .iter_mut()
+        .zip(inputs.iter().map(|x| x.shape.as_slice()))
+        .chain(output_datas.iter_mut().zip(output_shapes.iter().copied()))
+        .map(|(data, dims)| {
+            I32Tensor(RefCell::new(BLiteArray {
+                data,
+                dims,
+                quant_params: None,
+                sparsity: None,
+            }))
+        })
+        .collect();
+    let node_inputs: Vec<i32> = (0..inputs.len() as i32).collect();
+    let node_outputs: Vec<i32> = (inputs.len() as i32..tensors.len() as i32).collect();
+    let node = BLiteNode {
+        inputs: &node_inputs,
+        outputs: &node_outputs,
+    };
+    eval(&BLiteContext::new(), &mut tensors, &node, builtin_option)?;
+    Ok(output_datas)
+}
+
+/// Parses and runs an int8 operator of one input and one output, whose quantization parameters
+/// are (scale, zero point), and returns the output and the bytes the parser allocated in the arena
+pub fn eval_unary_i8(
+    parser: Parser<i8>,
+    eval: Eval<i8>,
+    input: &[i8],
+    input_quantization: (f32, i64),
+    output_quantization: (f32, i64),
+) -> Result<(Vec<i8>, usize)> {
+    let mut fbb = FlatBufferBuilder::new();
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[1]);
+    let op = Operator::create(
+        &mut fbb,
+        &OperatorArgs {
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            ..Default::default()
+        },
+    );
+    fbb.finish_minimal(op);
+    let op = flatbuffers::root::<Operator>(fbb.finished_data()).unwrap();
+
+    let mut input_data = input.to_vec();
+    let mut output_data = vec![0; input.len()];
+    let dims = [input.len() as i32];
+    let quantizations = [input_quantization, output_quantization];
+    let scales = quantizations.map(|(scale, _)| [scale]);
+    let zero_points = quantizations.map(|(_, zero_point)| [zero_point]);
+    let mut tensors: Vec<BLiteTensor<i8>> = [&mut input_data, &mut output_data]
+        .into_iter()
+        .zip(scales.iter().zip(zero_points.iter()))
+        .map(|(data, (scale, zero_point))| {
+            BTensor(RefCell::new(BLiteArray {
+                data,
+                dims: &dims,
+                quant_params: Some(BLiteQuantizationParams::new(scale, zero_point, 0)),
+                sparsity: None,
+            }))
+        })
+        .collect();
+
+    let mut arena = vec![0u8; ARENA_SIZE];
+    // the options do not outlive the arena, which is dropped at the end of this function
+    let arena_ref = unsafe { &mut *(arena.as_mut_slice() as *mut [u8]) };
+    let mut allocator = unsafe { BumpArenaAllocator::new(arena_ref) };
+    let builtin_option = parser(&mut allocator, op, &mut tensors)?;
+    let (_, used) = allocator.description()?;
+
+    let node = BLiteNode {
+        inputs: &[0],
+        outputs: &[1],
+    };
+    eval(&BLiteContext::new(), &mut tensors, &node, builtin_option)?;
+    Ok((output_data, used))
+}
+
+/// Builds the builtin options of an operator of a test
+pub type BuildOptions =
+    for<'f> fn(&mut FlatBufferBuilder<'f>) -> (BuiltinOptions, WIPOffset<UnionWIPOffset>);
+
+/// Parses and runs a quantized operator on `inputs` and an optional int32 `bias`, whose
+/// quantization parameters are (scale, zero point), and returns the output of `output_shape`
+pub fn eval_quantized<T: ArrayElem<T>>(
+    parser: Parser<T>,
+    eval: Eval<T>,
+    build_options: Option<BuildOptions>,
+    inputs: &[(&Tensor<T>, (f32, i64))],
+    bias: Option<(&Tensor<i32>, (f32, i64))>,
+    output_shape: &[i32],
+    output_quantization: (f32, i64),
+) -> Result<Vec<T>> {
+    // the tensors are the inputs, the bias and the output in this order
+    let num_inputs = (inputs.len() + bias.iter().len()) as i32;
+    let node_inputs: Vec<i32> = (0..num_inputs).collect();
+    let node_outputs = [num_inputs];
+
+    let mut fbb = FlatBufferBuilder::new();
+    let op_inputs = fbb.create_vector(&node_inputs);
+    let op_outputs = fbb.create_vector(&node_outputs);
+    let (builtin_options_type, builtin_options) = match build_options {
+        Some(build_options) => {
+            let (builtin_options_type, builtin_options) = build_options(&mut fbb);
+            (builtin_options_type, Some(builtin_options))
+        }
+        None => (BuiltinOptions::NONE, None),
+    };
+    let op = Operator::create(
+        &mut fbb,
+        &OperatorArgs {
+            inputs: Some(op_inputs),
+            outputs: Some(op_outputs),
+            builtin_options_type,
+            builtin_options,
+            ..Default::default()
+        },
+    );
+    fbb.finish_minimal(op);
+    let op = flatbuffers::root::<Operator>(fbb.finished_data()).unwrap();
+
+    let mut input_datas: Vec<Vec<T>> = inputs.iter().map(|(x, _)| x.data.clone()).collect();
+    let mut bias_data = bias.map(|(x, _)| x.data.clone());
+    let mut output_data = Tensor::<T>::zeros(output_shape).data;
+    let quantizations: Vec<(f32, i64)> = inputs
+        .iter()
+        .map(|&(_, quantization)| quantization)
+        .chain(bias.map(|(_, quantization)| quantization))
+        .chain([output_quantization])
+        .collect();
+    let scales: Vec<[f32; 1]> = quantizations.iter().map(|&(scale, _)| [scale]).collect();
+    let zero_points: Vec<[i64; 1]> = quantizations
+        .iter()
+        .map(|&(_, zero_point)| [zero_point])
+        .collect();
+    let quant_params =
+        |i: usize| Some(BLiteQuantizationParams::new(&scales[i], &zero_points[i], 0));
+
+    let mut tensors: Vec<BLiteTensor<T>> = input_datas
+        .iter_mut()
+        .zip(inputs.iter())
+        .map(|(data, (x, _))| (data, x.shape.as_slice()))
+        .chain([(&mut output_data, output_shape)])
+        .enumerate()
+        .map(|(i, (data, dims))| {
+            // the output follows the bias in the quantizations
+            let i = if i < inputs.len() {
+                i
+            } else {
+                num_inputs as usize
+            };
+            BTensor(RefCell::new(BLiteArray {
+                data,
+                dims,
+                quant_params: quant_params(i),
+                sparsity: None,
+            }))
+        })
+        .collect();
+    if let (Some(data), Some((x, _))) = (bias_data.as_mut(), bias) {
+        let bias_tensor = I32Tensor(RefCell::new(BLiteArray {
+            data,
+            dims: &x.shape,
+            quant_params: quant_params(inputs.len()),
+            sparsity: None,
+        }));
+        tensors.insert(inputs.len(), bias_tensor);
+    }
+
+    // the options allocated by the parser live as long as the leaked arena
+    let arena = Box::leak(vec![0u8; ARENA_SIZE].into_boxed_slice());
+    let mut allocator = unsafe { BumpArenaAllocator::new(arena) };
+    let builtin_option = parser(&mut allocator, op, &mut tensors)?;
+
+    let node = BLiteNode {
+        inputs: &node_inputs,
+        outputs: &node_outputs,
+    };
+    eval(&BLiteContext::new(), &mut tensors, &node, builtin_option)?;
+    Ok(output_data)
+}
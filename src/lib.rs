@@ -1,7 +1,13 @@
 #![feature(trait_alias)]
 #![feature(core_intrinsics)]
 #![cfg_attr(feature = "no_std", no_std)]
+#[cfg(all(feature = "threads", feature = "no_std"))]
+compile_error!("the `threads` feature needs std");
+#[cfg(all(feature = "codegen", feature = "no_std"))]
+compile_error!("the `codegen` feature needs std");
 pub mod builtin_op_data;
+#[cfg(feature = "codegen")]
+pub mod codegen;
 pub mod kernel;
 pub mod memory_planner;
 pub mod micro_allocator;
@@ -12,7 +18,11 @@ pub mod micro_graph;
 pub mod micro_interpreter;
 pub mod micro_node;
 pub mod micro_op_resolver;
+pub mod micro_optimizer;
 pub mod micro_registration;
 pub mod micro_slice;
+pub mod micro_sparsity;
 pub mod micro_tensor;
+#[cfg(feature = "threads")]
+pub mod micro_threads;
 pub mod tflite_schema_generated;
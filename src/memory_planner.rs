@@ -1,8 +1,27 @@
+pub mod best_order_memory_planner;
 pub mod greedy_memory_planner;
+pub mod linear_memory_planner;
+pub mod offline_memory_plan;
 
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_errors::Result;
 
 pub trait MemoryPlanner<'c> {
     fn commit_memory_plan(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()>;
+
+    /// Returns the byte offset of each tensor from the start of the planned tensors,
+    /// or -1 if it is not planned, which can be embedded as an offline memory plan
+    fn memory_plan(&self) -> &'c [i32];
+}
+
+/// The memory planner which lays out the intermediate tensors of each subgraph
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BLiteMemoryPlannerKind {
+    /// `GreedyMemoryPlanner`
+    #[default]
+    Greedy,
+    /// `LinearMemoryPlanner`
+    Linear,
+    /// `BestOrderMemoryPlanner`
+    BestOrder,
 }
@@ -0,0 +1,362 @@
+//! Plans the intermediate tensors in the order which needs the smallest arena.
+//! All orders of placing the tensors first fit are searched by branch and bound for the small graphs,
+//! and the larger ones try the tensors in the order of the size and of the size times the lifetime.
+use crate::{
+    memory_planner::greedy_memory_planner::{GreedyMemoryPlanner, Requirement},
+    memory_planner::offline_memory_plan::BLiteOfflineMemoryPlan,
+    micro_allocator::ArenaAllocator,
+    micro_array::ArrayElem,
+    micro_errors::Result,
+    micro_graph::TFLiteSubGraph,
+    micro_node::BLiteNode,
+    micro_registration::BLiteRegistration,
+    micro_slice::{alloc_array_mut, dealloc_array_mut},
+    micro_tensor::BLiteTensor,
+};
+
+use super::MemoryPlanner;
+
+// the number of the tensors whose all orders are searched
+const MAX_BRANCH_AND_BOUND_LEN: usize = 8;
+
+/*-----------------------------------------------------------------------------*/
+/* Placement of the requirements                                               */
+/*-----------------------------------------------------------------------------*/
+#[inline(always)]
+fn overlaps_in_time(a: &Requirement, b: &Requirement) -> bool {
+    a.first_time_used.unwrap() <= b.last_time_used.unwrap()
+        && b.first_time_used.unwrap() <= a.last_time_used.unwrap()
+}
+
+#[inline(always)]
+fn lifetime(requirement: &Requirement) -> usize {
+    requirement.last_time_used.unwrap() - requirement.first_time_used.unwrap() + 1
+}
+
+/// Returns the lowest offset where the requirement `idx` fits
+/// between the placed requirements while they are alive
+fn first_fit_offset(
+    requirements: &[Requirement],
+    offsets: &[usize],
+    is_placed: &[bool],
+    idx: usize,
+) -> usize {
+    let wanted = &requirements[idx];
+    let alive = || {
+        (0..requirements.len())
+            .filter(move |&j| is_placed[j] && overlaps_in_time(wanted, &requirements[j]))
+    };
+    let fits = |offset: usize| {
+        alive().all(|j| {
+            offset + wanted.size <= offsets[j] || offsets[j] + requirements[j].size <= offset
+        })
+    };
+
+    // the lowest offset is 0 or the end of a placed requirement
+    let mut best_offset = if fits(0) { 0 } else { usize::MAX };
+    for j in alive() {
+        let offset = offsets[j] + requirements[j].size;
+        if offset < best_offset && fits(offset) {
+            best_offset = offset;
+        }
+    }
+    best_offset
+}
+
+/// Places the requirements of `order` one by one, and returns the end of the arena
+fn place_in_order(
+    requirements: &[Requirement],
+    order: &[usize],
+    offsets: &mut [usize],
+    is_placed: &mut [bool],
+    mut end_offset: usize,
+) -> usize {
+    for &idx in order.iter() {
+        offsets[idx] = first_fit_offset(requirements, offsets, is_placed, idx);
+        is_placed[idx] = true;
+        end_offset = end_offset.max(offsets[idx] + requirements[idx].size);
+    }
+    end_offset
+}
+
+/// Tries the orders of `order[depth..]` whose arena can be smaller than `best_end_offset`
+#[allow(clippy::too_many_arguments)]
+fn branch_and_bound(
+    requirements: &[Requirement],
+    order: &mut [usize],
+    depth: usize,
+    end_offset: usize,
+    offsets: &mut [usize],
+    is_placed: &mut [bool],
+    best_offsets: &mut [usize],
+    best_end_offset: &mut usize,
+) {
+    if depth == order.len() {
+        if end_offset < *best_end_offset {
+            *best_end_offset = end_offset;
+            best_offsets.copy_from_slice(offsets);
+        }
+        return;
+    }
+    for i in depth..order.len() {
+        order.swap(depth, i);
+        let idx = order[depth];
+        offsets[idx] = first_fit_offset(requirements, offsets, is_placed, idx);
+        is_placed[idx] = true;
+        let next_end_offset = end_offset.max(offsets[idx] + requirements[idx].size);
+        if next_end_offset < *best_end_offset {
+            branch_and_bound(
+                requirements,
+                order,
+                depth + 1,
+                next_end_offset,
+                offsets,
+                is_placed,
+                best_offsets,
+                best_end_offset,
+            );
+        }
+        is_placed[idx] = false;
+        order.swap(depth, i);
+    }
+}
+
+/// Places the requirements which are not placed yet at `offsets`,
+/// whose order is searched to make the arena as small as possible.
+/// The requirements must be sorted by their sizes in descending order,
+/// and the scratch of the search is released from the arena after placing them.
+unsafe fn place_requirements(
+    allocator: &mut impl ArenaAllocator,
+    requirements: &[Requirement],
+    offsets: &mut [usize],
+    is_placed: &mut [bool],
+) -> Result<()> {
+    let len = is_placed.iter().filter(|&&is_placed| !is_placed).count();
+    let order = alloc_array_mut::<usize>(allocator, len)?;
+    for (slot, idx) in order
+        .iter_mut()
+        .zip((0..requirements.len()).filter(|&idx| !is_placed[idx]))
+    {
+        *slot = idx;
+    }
+    let best_offsets = alloc_array_mut::<usize>(allocator, requirements.len())?;
+    best_offsets.copy_from_slice(offsets);
+    let end_offset = (0..requirements.len())
+        .filter(|&idx| is_placed[idx])
+        .map(|idx| offsets[idx] + requirements[idx].size)
+        .max()
+        .unwrap_or(0);
+
+    if len <= MAX_BRANCH_AND_BOUND_LEN {
+        // the first order tried is the one of the greedy memory planner
+        let mut best_end_offset = usize::MAX;
+        branch_and_bound(
+            requirements,
+            order,
+            0,
+            end_offset,
+            offsets,
+            is_placed,
+            best_offsets,
+            &mut best_end_offset,
+        );
+        offsets.copy_from_slice(best_offsets);
+    } else {
+        // the order of the size, which is the one of the greedy memory planner
+        let by_size_end_offset =
+            place_in_order(requirements, order, best_offsets, is_placed, end_offset);
+        for &idx in order.iter() {
+            is_placed[idx] = false;
+        }
+
+        // the order of the size times the lifetime, which places the long-lived large tensors first
+        order.sort_unstable_by(|&a, &b| {
+            let key = |idx: usize| requirements[idx].size * lifetime(&requirements[idx]);
+            key(b).cmp(&key(a)).then(a.cmp(&b))
+        });
+        let by_area_end_offset =
+            place_in_order(requirements, order, offsets, is_placed, end_offset);
+        if by_size_end_offset <= by_area_end_offset {
+            offsets.copy_from_slice(best_offsets);
+        }
+    }
+
+    // released in the reverse order of the allocation
+    dealloc_array_mut(allocator, best_offsets);
+    dealloc_array_mut(allocator, order);
+    Ok(())
+}
+
+/*-----------------------------------------------------------------------------*/
+/* Struct for a BestOrderMemoryPlanner                                         */
+/*-----------------------------------------------------------------------------*/
+/// Places the tensors first fit in the order which needs the smallest arena
+#[derive(Debug)]
+pub struct BestOrderMemoryPlanner<'a, 'b, 'c, 'd, T: ArrayElem<T>> {
+    planner: GreedyMemoryPlanner<'a, 'b, 'c, 'd, T>,
+}
+
+impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> BestOrderMemoryPlanner<'a, 'b, 'c, 'd, T> {
+    /// Plans the intermediate tensors of `node_and_registrations` as `GreedyMemoryPlanner::new`
+    pub fn new(
+        allocator: &mut impl ArenaAllocator,
+        subgraph: &'d TFLiteSubGraph<'c>,
+        node_and_registrations: &'d [(BLiteNode<'c>, BLiteRegistration<'c, T>)],
+        aliases: &'d [i32],
+        tensors: &'c mut [BLiteTensor<'c, T>],
+    ) -> Result<Self> {
+        let planner = GreedyMemoryPlanner::new(
+            allocator,
+            subgraph,
+            node_and_registrations,
+            aliases,
+            tensors,
+        )?;
+        Ok(Self { planner })
+    }
+
+    /// Places the tensors at the offsets of `offline_plan`, and searches the order of the others
+    pub fn with_offline_plan(self, offline_plan: Option<BLiteOfflineMemoryPlan<'c>>) -> Self {
+        Self {
+            planner: self.planner.with_offline_plan(offline_plan),
+        }
+    }
+
+    fn place_in_best_order(
+        planner: &mut GreedyMemoryPlanner<'a, 'b, 'c, 'd, T>,
+        allocator: &mut impl ArenaAllocator,
+    ) -> Result<()> {
+        let len = planner.requirements().len();
+        let offsets = unsafe { alloc_array_mut::<usize>(allocator, len)? };
+        let is_placed = unsafe { alloc_array_mut::<bool>(allocator, len)? };
+        for i in 0..len {
+            let offline_offset = planner.requirement_offline_offset(i)?;
+            offsets[i] = offline_offset.unwrap_or(0);
+            is_placed[i] = offline_offset.is_some();
+        }
+        unsafe { place_requirements(allocator, planner.requirements(), offsets, is_placed)? };
+        for (i, &offset) in offsets.iter().enumerate() {
+            if planner.requirement_offline_offset(i)?.is_none() {
+                planner.place(i, offset)?;
+            }
+        }
+        // the offsets are kept in the offset list, so the scratch is released
+        unsafe {
+            dealloc_array_mut(allocator, is_placed);
+            dealloc_array_mut(allocator, offsets);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> MemoryPlanner<'c>
+    for BestOrderMemoryPlanner<'a, 'b, 'c, 'd, T>
+{
+    fn commit_memory_plan(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+        self.planner
+            .commit_memory_plan_with(allocator, true, Self::place_in_best_order)
+    }
+
+    fn memory_plan(&self) -> &'c [i32] {
+        self.planner.memory_plan()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::micro_allocator::BumpArenaAllocator;
+
+    const ARENA_SIZE: usize = 1024;
+
+    fn requirement(
+        idx: usize,
+        size: usize,
+        first_time_used: usize,
+        last_time_used: usize,
+    ) -> Requirement {
+        Requirement::new(size, idx, Some(first_time_used), Some(last_time_used), true)
+    }
+
+    #[test]
+    fn test_branch_and_bound_beats_first_fit() {
+        // placing the largest one first at the bottom pushes the long-lived one above it
+        let requirements = [
+            requirement(0, 8, 3, 4),
+            requirement(1, 6, 0, 3),
+            requirement(2, 6, 0, 2),
+            requirement(3, 4, 0, 2),
+        ];
+        let mut offsets = [0; 4];
+        let mut is_placed = [false; 4];
+        let first_fit_end_offset = place_in_order(
+            &requirements,
+            &[0, 1, 2, 3],
+            &mut offsets,
+            &mut is_placed,
+            0,
+        );
+
+        let mut order = [0, 1, 2, 3];
+        let mut offsets = [0; 4];
+        let mut is_placed = [false; 4];
+        let mut best_offsets = [0; 4];
+        let mut best_end_offset = usize::MAX;
+        branch_and_bound(
+            &requirements,
+            &mut order,
+            0,
+            0,
+            &mut offsets,
+            &mut is_placed,
+            &mut best_offsets,
+            &mut best_end_offset,
+        );
+        assert_eq!(first_fit_end_offset, 18);
+        assert_eq!(best_end_offset, 16);
+
+        // the requirements alive at the same time do not overlap
+        for i in 0..4 {
+            for j in 0..i {
+                if overlaps_in_time(&requirements[i], &requirements[j]) {
+                    assert!(
+                        best_offsets[i] + requirements[i].size <= best_offsets[j]
+                            || best_offsets[j] + requirements[j].size <= best_offsets[i]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_place_requirements_releases_scratch() {
+        let arena = Box::leak(vec![0u8; ARENA_SIZE].into_boxed_slice());
+        let mut allocator = unsafe { BumpArenaAllocator::new(arena) };
+        // the padding to align the scratch is not released
+        unsafe { alloc_array_mut::<usize>(&mut allocator, 0) }.unwrap();
+        // the orders are searched by branch and bound for 4 requirements, and not for 12
+        for len in [4, 12] {
+            let requirements: Vec<_> = (0..len)
+                .map(|idx| requirement(idx, 64 - 4 * idx, idx % 3, idx % 3 + idx % 4))
+                .collect();
+            let mut offsets = vec![0; len];
+            let mut is_placed = vec![false; len];
+            let (_, used) = allocator.description().unwrap();
+            unsafe {
+                place_requirements(&mut allocator, &requirements, &mut offsets, &mut is_placed)
+            }
+            .unwrap();
+            assert_eq!(allocator.description().unwrap(), (ARENA_SIZE, used));
+            for i in 0..len {
+                for j in 0..i {
+                    if overlaps_in_time(&requirements[i], &requirements[j]) {
+                        assert!(
+                            offsets[i] + requirements[i].size <= offsets[j]
+                                || offsets[j] + requirements[j].size <= offsets[i]
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -4,11 +4,15 @@ use crate::{
     micro_errors::BLiteError,
     micro_errors::Result,
     micro_graph::TFLiteSubGraph,
+    micro_node::BLiteNode,
+    micro_registration::{BLiteInPlace, BLiteRegistration},
     micro_slice::{alloc_array_from_offset, alloc_array_mut},
     micro_tensor::BLiteTensor,
 };
 use core::mem::size_of;
+use core::slice::from_raw_parts_mut;
 
+use super::offline_memory_plan::BLiteOfflineMemoryPlan;
 use super::MemoryPlanner;
 
 /*-----------------------------------------------------------------------------*/
@@ -187,13 +191,14 @@ impl<'a> OffsetList<'a> {
     }
 
     pub fn insert_entry(&mut self, entry: ListEntry) -> Result<()> {
+        let first_entry_idx = self.first_entry_idx;
         let entry_idx = self.add_entry(entry)?;
         let entry_offset = entry.offset;
 
-        if let Some(first_entry_idx) = self.first_entry_idx {
+        if let Some(first_entry_idx) = first_entry_idx {
             let first_entry = self.list[first_entry_idx];
             if first_entry.offset > entry_offset {
-                self.list[first_entry_idx].next_entry_idx = Some(first_entry_idx);
+                self.list[entry_idx].next_entry_idx = Some(first_entry_idx);
                 self.first_entry_idx = Some(entry_idx);
             } else {
                 let mut cur_entry_idx = first_entry_idx;
@@ -241,18 +246,31 @@ impl<'a> OffsetList<'a> {
 /*-----------------------------------------------------------------------------*/
 /* Struct for a GreedyMemoryPlanner                                            */
 /*-----------------------------------------------------------------------------*/
+/// Places the largest tensor first at the lowest offset which is free while it is alive,
+/// as TensorFlow Lite Micro
 #[derive(Debug)]
 pub struct GreedyMemoryPlanner<'a, 'b, 'c, 'd, T: ArrayElem<T>> {
     allocation_info: AllocationInfo<'a>,
     offset_list: OffsetList<'b>,
     subgraph: &'d TFLiteSubGraph<'c>,
+    node_and_registrations: &'d [(BLiteNode<'c>, BLiteRegistration<'c, T>)],
+    // the tensor whose buffer each tensor shares, or a negative value if it has its own
+    aliases: &'d [i32],
     tensors: &'c mut [BLiteTensor<'c, T>],
+    offline_plan: Option<BLiteOfflineMemoryPlan<'c>>,
+    // the byte offset of each planned tensor, or -1 if it is not planned
+    memory_plan: &'c [i32],
 }
 
 impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
+    /// Plans the intermediate tensors of `node_and_registrations`, which are evaluated in order.
+    /// The tensors in `aliases` are not planned and share the buffers of the tensors they point to,
+    /// and so do the outputs of the operators running in place.
     pub fn new(
         allocator: &mut impl ArenaAllocator,
         subgraph: &'d TFLiteSubGraph<'c>,
+        node_and_registrations: &'d [(BLiteNode<'c>, BLiteRegistration<'c, T>)],
+        aliases: &'d [i32],
         tensors: &'c mut [BLiteTensor<'c, T>],
     ) -> Result<Self> {
         let dummy_allocation_info = unsafe { AllocationInfo::new(allocator, 0) }?;
@@ -261,17 +279,187 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
             allocation_info: dummy_allocation_info,
             offset_list: dummy_offset_list,
             subgraph,
+            node_and_registrations,
+            aliases,
             tensors,
+            offline_plan: None,
+            memory_plan: &[],
         })
     }
 
-    fn commit_memory_plan(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+    /// Places the tensors at the offsets of `offline_plan` instead of planning them,
+    /// and plans the tensors whose offsets are -1 around them
+    pub fn with_offline_plan(self, offline_plan: Option<BLiteOfflineMemoryPlan<'c>>) -> Self {
+        Self {
+            offline_plan,
+            ..self
+        }
+    }
+
+    /// Runs the steps shared by the planners, where `place` inserts the entries of the tensors
+    /// which are not planned offline into the offset list, and the outputs of the operators
+    /// running in place share the buffers of their inputs if `shares_buffers`
+    pub(super) fn commit_memory_plan_with<A: ArenaAllocator>(
+        &mut self,
+        allocator: &mut A,
+        shares_buffers: bool,
+        place: impl FnOnce(&mut Self, &mut A) -> Result<()>,
+    ) -> Result<()> {
+        if self
+            .offline_plan
+            .is_some_and(|plan| plan.len() != self.tensors.len())
+        {
+            return Err(BLiteError::InvalidOfflineMemoryPlan);
+        }
         self.allocate_inputs_outputs(allocator)?;
+        // the offline plan decides which buffers are shared even if the planner shares nothing
+        if shares_buffers || self.offline_plan.is_some() {
+            self.share_in_place_buffers(allocator)?;
+        }
         let allocation_info = self.calculate_allocation_info(allocator)?;
         let offset_list = OffsetList::new(allocator, allocation_info.info.len())?;
         self.allocation_info = allocation_info;
         self.offset_list = offset_list;
-        self.allocate_intermediate_tensors(allocator)?;
+        self.insert_offline_entries()?;
+        place(self, allocator)?;
+        unsafe { self.allocate_tensors_following_plan(allocator)? };
+        self.allocate_aliases()?;
+        self.record_memory_plan(allocator)?;
+        Ok(())
+    }
+
+    /// The offline offset of the tensor `idx` in the units of T
+    fn offline_offset(&self, idx: usize) -> Result<Option<usize>> {
+        let Some(offset) = self.offline_plan.and_then(|plan| plan.offset(idx)) else {
+            return Ok(None);
+        };
+        if offset % size_of::<T>() != 0 {
+            return Err(BLiteError::MisalignedOfflineMemoryPlan(idx as i32));
+        }
+        Ok(Some(offset / size_of::<T>()))
+    }
+
+    fn record_memory_plan(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+        let memory_plan = unsafe { alloc_array_mut::<i32>(allocator, self.tensors.len())? };
+        memory_plan.fill(-1);
+        for entry in self.offset_list.list.iter() {
+            let requirement_idx = entry.requirement_idx.unwrap();
+            let tensor_idx = self.allocation_info.info[requirement_idx].idx;
+            memory_plan[tensor_idx] = (size_of::<T>() * entry.offset) as i32;
+        }
+        for idx in 0..memory_plan.len() {
+            memory_plan[idx] = memory_plan[self.root(idx)];
+        }
+        self.memory_plan = memory_plan;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn root(&self, idx: usize) -> usize {
+        Self::root_of(self.aliases, idx)
+    }
+
+    #[inline(always)]
+    fn root_of(aliases: &[i32], idx: usize) -> usize {
+        match aliases.get(idx) {
+            Some(&root) if root >= 0 => root as usize,
+            _ => idx,
+        }
+    }
+
+    /// The intermediate tensors are planned, whose data is empty before the memory planning
+    fn is_planned(&self, idx: usize) -> bool {
+        self.tensors[idx]._t().is_ok() && self.tensors[idx].len() == 0
+    }
+
+    /// The output of an operator running in place aliases the input whose buffer it may share,
+    /// so that the planner gives them a single buffer living as long as both of them
+    fn share_in_place_buffers(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+        let nodes = self.node_and_registrations;
+        if nodes
+            .iter()
+            .all(|(_, registration)| registration.in_place == BLiteInPlace::NotInPlace)
+        {
+            return Ok(());
+        }
+
+        let aliases = unsafe { alloc_array_mut::<i32>(allocator, self.tensors.len())? };
+        for (idx, alias) in aliases.iter_mut().enumerate() {
+            *alias = self.aliases.get(idx).copied().unwrap_or(-1);
+        }
+        for (time_step, (node, registration)) in nodes.iter().enumerate() {
+            let candidates = match registration.in_place {
+                BLiteInPlace::NotInPlace => continue,
+                BLiteInPlace::MayRunInPlace => node.inputs,
+                BLiteInPlace::OutputAliasesInput => &node.inputs[..1],
+            };
+            let Some(&output) = node.outputs.first() else {
+                continue;
+            };
+            let output = output as usize;
+            if !self.is_planned(output) || aliases[output] >= 0 {
+                continue;
+            }
+            for (position, &input) in candidates.iter().enumerate() {
+                if input < 0 {
+                    continue;
+                }
+                let root = Self::root_of(aliases, input as usize);
+                if !self.is_planned(root)
+                    || self.tensors[input as usize].size() != self.tensors[output].size()
+                {
+                    continue;
+                }
+                // the offline plan decides whether the output shares the buffer
+                let offline_plan = self.offline_plan;
+                if offline_plan.and_then(|plan| plan.offset(output))
+                    != offline_plan.and_then(|plan| plan.offset(root))
+                {
+                    continue;
+                }
+                if registration.in_place == BLiteInPlace::MayRunInPlace {
+                    let reads_root =
+                        |idx: &i32| *idx >= 0 && Self::root_of(aliases, *idx as usize) == root;
+                    // the other operands, including the same tensor given twice,
+                    // must not read the buffer at the other elements,
+                    // and the buffer must not be read after it is overwritten
+                    let is_read_by_others = node
+                        .inputs
+                        .iter()
+                        .enumerate()
+                        .filter(|&(other, _)| other != position)
+                        .any(|(_, idx)| reads_root(idx));
+                    let is_read_later = nodes[time_step + 1..]
+                        .iter()
+                        .any(|(node, _)| node.inputs.iter().any(reads_root));
+                    if is_read_by_others || is_read_later {
+                        continue;
+                    }
+                }
+                // the tensors aliasing the output are retargeted to the root
+                for alias in aliases.iter_mut() {
+                    if *alias == output as i32 {
+                        *alias = root as i32;
+                    }
+                }
+                aliases[output] = root as i32;
+                break;
+            }
+        }
+        self.aliases = aliases;
+        Ok(())
+    }
+
+    fn allocate_aliases(&mut self) -> Result<()> {
+        for idx in 0..self.aliases.len() {
+            let root = self.root(idx);
+            if root == idx {
+                continue;
+            }
+            let data = self.tensors[root]._t()?.borrow_mut().data.as_mut_ptr();
+            let size = self.tensors[idx].size();
+            self.tensors[idx]._t()?.borrow_mut().data = unsafe { from_raw_parts_mut(data, size) };
+        }
         Ok(())
     }
 
@@ -281,13 +469,10 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
     ) -> Result<AllocationInfo<'a>> {
         // TODO: should be drop this all allocation infos after creating allocation infos
         let mut all_alloc_info = unsafe { AllocationInfo::new(allocator, self.tensors.len()) }?;
-        for (time_step, op) in self.subgraph.operators().unwrap().iter().enumerate() {
-            let inputs = op.inputs().unwrap();
-            let outputs = op.outputs().unwrap();
-
-            // check last_time_used using inputs
-            for idx in inputs {
-                let idx = idx as usize;
+        for (time_step, (node, _)) in self.node_and_registrations.iter().enumerate() {
+            // check last_time_used using inputs, whose optional ones are negative
+            for &idx in node.inputs.iter().filter(|&&idx| idx >= 0) {
+                let idx = self.root(idx as usize);
                 let size = self.tensors[idx].size();
                 let last_time_used = time_step;
                 let need_allocation = self.tensors[idx].len() == 0;
@@ -296,8 +481,8 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
             }
 
             // check first_time_used using outputs
-            for idx in outputs {
-                let idx = idx as usize;
+            for &idx in node.outputs.iter() {
+                let idx = self.root(idx as usize);
                 let size = self.tensors[idx].size();
                 let first_time_used = time_step;
                 let need_allocation = self.tensors[idx].len() == 0;
@@ -322,7 +507,12 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
         let mut allocation_info = unsafe { AllocationInfo::new(allocator, need_allocation_count) }?;
         for info in all_alloc_info.info.iter() {
             if info.need_allocation {
-                allocation_info.add_info(info)?;
+                // the output which no node reads is alive only while it is written
+                let mut info = *info;
+                if info.last_time_used.is_none() {
+                    info.last_time_used = info.first_time_used;
+                }
+                allocation_info.add_info(&info)?;
             }
         }
 
@@ -331,13 +521,6 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
         Ok(allocation_info)
     }
 
-    fn allocate_intermediate_tensors(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
-        self.calculate_offsets_if_needed()?;
-
-        // allocate tensors following memory plan
-        unsafe { self.allocate_tensors_following_plan(allocator) }
-    }
-
     fn allocate_inputs_outputs(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
         let inputs = self.subgraph.inputs().unwrap();
         let outputs = self.subgraph.outputs().unwrap();
@@ -355,6 +538,18 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
             let mut tensor = self.tensors[output_idx as usize]._t()?.borrow_mut();
             tensor.data = data;
         }
+
+        // the variable tensors such as the states of LSTM keep their values across invocations,
+        // so they are not planned and start from zero
+        for (idx, tensor) in self.subgraph.tensors().unwrap().iter().enumerate() {
+            if !tensor.is_variable() || !self.is_planned(idx) {
+                continue;
+            }
+            let size = self.tensors[idx].size();
+            let data: &mut [T] = unsafe { alloc_array_mut(allocator, size)? };
+            data.fill(T::default());
+            self.tensors[idx]._t()?.borrow_mut().data = data;
+        }
         Ok(())
     }
 
@@ -414,8 +609,10 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
             } else {
                 return Ok(result);
             }
+        } else if let Some(first_entry) = self.offset_list.get_first_entry() {
+            candidate_next_entry = first_entry;
         } else {
-            candidate_next_entry = self.offset_list.get_first_entry().unwrap();
+            return Ok(result);
         }
 
         loop {
@@ -438,61 +635,121 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
         return Ok(result);
     }
 
-    fn add_entry(&mut self, entry: ListEntry) -> Result<usize> {
-        self.offset_list.add_entry(entry)
-    }
-
     fn insert_entry(&mut self, entry: ListEntry) -> Result<()> {
         self.offset_list.insert_entry(entry)
     }
 
-    pub fn calculate_offsets_if_needed(&mut self) -> Result<()> {
-        // add first entry to offset list
-        let buffer_offset = 0;
-        let requirement_idx = 0;
-        let first_entry = ListEntry::new(buffer_offset, Some(requirement_idx), None);
-        let first_entry_idx = self.add_entry(first_entry)?;
-
-        for i in 1..self.offset_list.len() {
-            let buffer_id = i;
-            let wanted_requirement = self.allocation_info.info[i];
-            let wanted_size = wanted_requirement.size;
-            let wanted_first_time_used = wanted_requirement.first_time_used.unwrap();
-            let wanted_last_time_used = wanted_requirement.last_time_used.unwrap();
-
-            let mut candidate_offset = first_entry_idx;
-            let mut prior_entry = None;
-            loop {
-                let next_entry = self.next_simultaneous_active_buffer(
-                    prior_entry,
-                    wanted_first_time_used,
-                    wanted_last_time_used,
-                )?;
-
-                if let Some(prior_entry) = prior_entry {
-                    let candidate_requirement =
-                        self.allocation_info.info[prior_entry.requirement_idx.unwrap()];
-                    let entry_offset = prior_entry.offset + candidate_requirement.size;
-                    if entry_offset > candidate_offset {
-                        candidate_offset = entry_offset;
-                    }
+    /// The requirements of the planned tensors, which are sorted from the largest
+    pub(super) fn requirements(&self) -> &[Requirement] {
+        self.allocation_info.info
+    }
+
+    /// Places the requirement `i` at `offset` in the units of T
+    pub(super) fn place(&mut self, i: usize, offset: usize) -> Result<()> {
+        self.insert_entry(ListEntry::new(offset, Some(i), None))
+    }
+
+    /// The offline offset of the requirement `i` in the units of T,
+    /// at which it is placed before the others
+    pub(super) fn requirement_offline_offset(&self, i: usize) -> Result<Option<usize>> {
+        self.offline_offset(self.allocation_info.info[i].idx)
+    }
+
+    /// The offset in the units of T, where the offline plan ends
+    pub(super) fn offline_end_offset(&self) -> Result<usize> {
+        let mut end_offset = 0;
+        for requirement in self.allocation_info.info.iter() {
+            if let Some(offset) = self.offline_offset(requirement.idx)? {
+                end_offset = end_offset.max(offset + requirement.size);
+            }
+        }
+        Ok(end_offset)
+    }
+
+    /// Verifies that the tensor of `requirement` placed at `offset` does not overlap
+    /// the tensors placed offline before, while both of them are alive
+    fn verify_offline_offset(&self, requirement: Requirement, offset: usize) -> Result<()> {
+        let mut entry = self.offset_list.get_first_entry();
+        while let Some(cur_entry) = entry {
+            let cur_requirement = self.allocation_info.info[cur_entry.requirement_idx.unwrap()];
+            let overlaps_in_space = cur_entry.offset < offset + requirement.size
+                && offset < cur_entry.offset + cur_requirement.size;
+            if overlaps_in_space
+                && self.does_entry_overlap_in_time(
+                    cur_entry,
+                    requirement.first_time_used.unwrap(),
+                    requirement.last_time_used.unwrap(),
+                )?
+            {
+                return Err(BLiteError::OverlappedOfflineMemoryPlan(
+                    cur_requirement.idx as i32,
+                    requirement.idx as i32,
+                ));
+            }
+            entry = cur_entry
+                .next_entry_idx
+                .map(|idx| self.offset_list.list[idx]);
+        }
+        Ok(())
+    }
+
+    /// Returns the lowest offset where the tensor of `requirement` fits
+    /// between the tensors placed before while they are alive
+    fn first_fit_offset(&self, requirement: Requirement) -> Result<usize> {
+        let wanted_size = requirement.size;
+        let wanted_first_time_used = requirement.first_time_used.unwrap();
+        let wanted_last_time_used = requirement.last_time_used.unwrap();
+
+        let mut candidate_offset = 0;
+        let mut prior_entry = None;
+        loop {
+            let next_entry = self.next_simultaneous_active_buffer(
+                prior_entry,
+                wanted_first_time_used,
+                wanted_last_time_used,
+            )?;
+
+            if let Some(prior_entry) = prior_entry {
+                let candidate_requirement =
+                    self.allocation_info.info[prior_entry.requirement_idx.unwrap()];
+                let entry_offset = prior_entry.offset + candidate_requirement.size;
+                if entry_offset > candidate_offset {
+                    candidate_offset = entry_offset;
                 }
-                if let Some(next_entry) = next_entry {
-                    let gap = next_entry.offset as i32 - candidate_offset as i32;
-                    if gap >= wanted_size as i32 {
-                        break;
-                    }
-                } else {
+            }
+            if let Some(next_entry) = next_entry {
+                let gap = next_entry.offset as i32 - candidate_offset as i32;
+                if gap >= wanted_size as i32 {
                     break;
                 }
-                prior_entry = next_entry;
+            } else {
+                break;
             }
+            prior_entry = next_entry;
+        }
+        Ok(candidate_offset)
+    }
 
-            // add this entry to list
-            let new_entry_offset = candidate_offset;
-            let new_entry_requirement_idx = Some(buffer_id);
-            let new_entry = ListEntry::new(new_entry_offset, new_entry_requirement_idx, None);
-            self.insert_entry(new_entry)?;
+    /// Inserts the entries of the tensors planned offline,
+    /// around which the other tensors are placed
+    fn insert_offline_entries(&mut self) -> Result<()> {
+        for i in 0..self.offset_list.len() {
+            let requirement = self.allocation_info.info[i];
+            if let Some(offset) = self.offline_offset(requirement.idx)? {
+                self.verify_offline_offset(requirement, offset)?;
+                self.place(i, offset)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn place_first_fit(&mut self) -> Result<()> {
+        for i in 0..self.offset_list.len() {
+            if self.requirement_offline_offset(i)?.is_some() {
+                continue;
+            }
+            let offset = self.first_fit_offset(self.allocation_info.info[i])?;
+            self.place(i, offset)?;
         }
         Ok(())
     }
@@ -500,7 +757,50 @@ impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
 
 impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> MemoryPlanner<'c> for GreedyMemoryPlanner<'a, 'b, 'c, 'd, T> {
     fn commit_memory_plan(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
-        self.commit_memory_plan(allocator)?;
+        self.commit_memory_plan_with(allocator, true, |planner, _| planner.place_first_fit())
+    }
+
+    fn memory_plan(&self) -> &'c [i32] {
+        self.memory_plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::micro_allocator::BumpArenaAllocator;
+
+    const ARENA_SIZE: usize = 1024;
+
+    fn offsets(offset_list: &OffsetList) -> Vec<usize> {
+        let mut offsets = vec![];
+        let mut entry = offset_list.get_first_entry();
+        while let Some(cur_entry) = entry {
+            assert!(
+                offsets.len() < offset_list.len(),
+                "the entries form a cycle"
+            );
+            offsets.push(cur_entry.offset);
+            entry = cur_entry.next_entry_idx.map(|idx| offset_list.list[idx]);
+        }
+        offsets
+    }
+
+    #[test]
+    fn test_insert_entry_keeps_offsets_sorted() -> Result<()> {
+        let arena = Box::leak(vec![0; ARENA_SIZE].into_boxed_slice());
+        let mut allocator = unsafe { BumpArenaAllocator::new(arena) };
+        let mut offset_list = OffsetList::new(&mut allocator, 4)?;
+
+        // the entry inserted before the first one used to link the old first one to itself,
+        // which left the rest of the list unreachable from the new first one
+        offset_list.insert_entry(ListEntry::new(16, Some(0), None))?;
+        assert_eq!(offsets(&offset_list), [16]);
+        offset_list.insert_entry(ListEntry::new(0, Some(1), None))?;
+        assert_eq!(offsets(&offset_list), [0, 16]);
+        offset_list.insert_entry(ListEntry::new(32, Some(2), None))?;
+        offset_list.insert_entry(ListEntry::new(8, Some(3), None))?;
+        assert_eq!(offsets(&offset_list), [0, 8, 16, 32]);
         Ok(())
     }
 }
@@ -0,0 +1,69 @@
+use crate::{
+    memory_planner::greedy_memory_planner::GreedyMemoryPlanner,
+    memory_planner::offline_memory_plan::BLiteOfflineMemoryPlan, micro_allocator::ArenaAllocator,
+    micro_array::ArrayElem, micro_errors::Result, micro_graph::TFLiteSubGraph,
+    micro_node::BLiteNode, micro_registration::BLiteRegistration, micro_tensor::BLiteTensor,
+};
+
+use super::MemoryPlanner;
+
+/*-----------------------------------------------------------------------------*/
+/* Struct for a LinearMemoryPlanner                                            */
+/*-----------------------------------------------------------------------------*/
+/// Places the tensors one after another without sharing any buffers,
+/// which is useful to find the kernels overwriting the other tensors
+#[derive(Debug)]
+pub struct LinearMemoryPlanner<'a, 'b, 'c, 'd, T: ArrayElem<T>> {
+    planner: GreedyMemoryPlanner<'a, 'b, 'c, 'd, T>,
+}
+
+impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> LinearMemoryPlanner<'a, 'b, 'c, 'd, T> {
+    /// Plans the intermediate tensors of `node_and_registrations` as `GreedyMemoryPlanner::new`
+    pub fn new(
+        allocator: &mut impl ArenaAllocator,
+        subgraph: &'d TFLiteSubGraph<'c>,
+        node_and_registrations: &'d [(BLiteNode<'c>, BLiteRegistration<'c, T>)],
+        aliases: &'d [i32],
+        tensors: &'c mut [BLiteTensor<'c, T>],
+    ) -> Result<Self> {
+        let planner = GreedyMemoryPlanner::new(
+            allocator,
+            subgraph,
+            node_and_registrations,
+            aliases,
+            tensors,
+        )?;
+        Ok(Self { planner })
+    }
+
+    /// Places the tensors at the offsets of `offline_plan`, and the others after them
+    pub fn with_offline_plan(self, offline_plan: Option<BLiteOfflineMemoryPlan<'c>>) -> Self {
+        Self {
+            planner: self.planner.with_offline_plan(offline_plan),
+        }
+    }
+
+    fn place_linearly(planner: &mut GreedyMemoryPlanner<'a, 'b, 'c, 'd, T>) -> Result<()> {
+        let mut end_offset = planner.offline_end_offset()?;
+        for i in 0..planner.requirements().len() {
+            if planner.requirement_offline_offset(i)?.is_some() {
+                continue;
+            }
+            let size = planner.requirements()[i].size;
+            planner.place(i, end_offset)?;
+            end_offset += size;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b, 'c, 'd, T: ArrayElem<T>> MemoryPlanner<'c> for LinearMemoryPlanner<'a, 'b, 'c, 'd, T> {
+    fn commit_memory_plan(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+        self.planner
+            .commit_memory_plan_with(allocator, false, |planner, _| Self::place_linearly(planner))
+    }
+
+    fn memory_plan(&self) -> &'c [i32] {
+        self.planner.memory_plan()
+    }
+}
@@ -0,0 +1,136 @@
+use crate::micro_errors::{BLiteError, Result};
+use crate::tflite_schema_generated::tflite::Model;
+use core::mem::size_of;
+
+/*-----------------------------------------------------------------------------*/
+/* Struct for an offline memory plan                                           */
+/*-----------------------------------------------------------------------------*/
+/// The arena offsets precomputed for the tensors of a model, which are stored in the buffer of
+/// the `OfflineMemoryAllocation` metadata as the int32 values of
+/// `[version, subgraph, number of offsets, offsets...]` in the same way as TensorFlow Lite Micro.
+/// The offsets are in bytes from the start of the planned tensors, and -1 is planned online.
+#[derive(Debug, Clone, Copy)]
+pub struct BLiteOfflineMemoryPlan<'a> {
+    // the little endian offsets, which may not be aligned in the model
+    offsets: &'a [u8],
+}
+
+impl<'a> BLiteOfflineMemoryPlan<'a> {
+    pub const METADATA_NAME: &'static str = "OfflineMemoryAllocation";
+    const VERSION: i32 = 1;
+    const HEADER_LEN: usize = 3;
+
+    /// Reads the plan of the first subgraph from the metadata of `model`, if it has one
+    pub fn from_model(model: &Model<'a>) -> Result<Option<Self>> {
+        let Some(metadata) = model.metadata() else {
+            return Ok(None);
+        };
+        let Some(metadata) = metadata
+            .iter()
+            .find(|metadata| metadata.name() == Some(Self::METADATA_NAME))
+        else {
+            return Ok(None);
+        };
+        let buffers = model.buffers().ok_or(BLiteError::NotFoundBuffers)?;
+        if metadata.buffer() as usize >= buffers.len() {
+            return Err(BLiteError::InvalidOfflineMemoryPlan);
+        }
+        let data = buffers
+            .get(metadata.buffer() as usize)
+            .data()
+            .ok_or(BLiteError::InvalidOfflineMemoryPlan)?;
+        Self::from_bytes(data.bytes()).map(Some)
+    }
+
+    /// Reads the plan from the bytes of the metadata buffer
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let word = |i: usize| {
+            let bytes = bytes.get(i * size_of::<i32>()..(i + 1) * size_of::<i32>())?;
+            Some(i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        };
+        let (Some(version), Some(subgraph), Some(len)) = (word(0), word(1), word(2)) else {
+            return Err(BLiteError::InvalidOfflineMemoryPlan);
+        };
+        // only the first subgraph is supported as the interpreter
+        let expected_len = (Self::HEADER_LEN + len.max(0) as usize) * size_of::<i32>();
+        if version != Self::VERSION || subgraph != 0 || len < 0 || bytes.len() != expected_len {
+            return Err(BLiteError::InvalidOfflineMemoryPlan);
+        }
+        Ok(Self {
+            offsets: &bytes[Self::HEADER_LEN * size_of::<i32>()..],
+        })
+    }
+
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.offsets.len() / size_of::<i32>()
+    }
+
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Returns the byte offset of the tensor `idx`, or None if it is planned online
+    pub fn offset(&self, idx: usize) -> Option<usize> {
+        let bytes = self
+            .offsets
+            .get(idx * size_of::<i32>()..(idx + 1) * size_of::<i32>())?;
+        let offset = i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        (offset >= 0).then_some(offset as usize)
+    }
+
+    /// Returns the number of bytes of the metadata buffer holding `len` offsets
+    pub fn metadata_size(len: usize) -> usize {
+        (Self::HEADER_LEN + len) * size_of::<i32>()
+    }
+
+    /// Writes `offsets` as the buffer of the `OfflineMemoryAllocation` metadata to `out`,
+    /// and returns the number of the written bytes
+    pub fn write_metadata(offsets: &[i32], out: &mut [u8]) -> Result<usize> {
+        let size = Self::metadata_size(offsets.len());
+        if out.len() < size {
+            return Err(BLiteError::InvalidOfflineMemoryPlan);
+        }
+        let header = [Self::VERSION, 0, offsets.len() as i32];
+        for (word, value) in out[..size]
+            .chunks_exact_mut(size_of::<i32>())
+            .zip(header.iter().chain(offsets.iter()))
+        {
+            word.copy_from_slice(&value.to_le_bytes());
+        }
+        Ok(size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_and_read_metadata() {
+        let offsets = [0, -1, 64, 128];
+        let mut bytes = [0u8; 28];
+        let size = BLiteOfflineMemoryPlan::write_metadata(&offsets, &mut bytes).unwrap();
+        assert_eq!(size, 28);
+
+        let plan = BLiteOfflineMemoryPlan::from_bytes(&bytes).unwrap();
+        assert_eq!(plan.len(), 4);
+        assert_eq!(plan.offset(0), Some(0));
+        assert_eq!(plan.offset(1), None);
+        assert_eq!(plan.offset(3), Some(128));
+        assert_eq!(plan.offset(4), None);
+    }
+
+    #[test]
+    fn test_invalid_metadata() {
+        let mut bytes = [0u8; 28];
+        assert!(BLiteOfflineMemoryPlan::write_metadata(&[0, 1, 2, 3], &mut bytes[..24]).is_err());
+        BLiteOfflineMemoryPlan::write_metadata(&[0, 1, 2, 3], &mut bytes).unwrap();
+        assert!(BLiteOfflineMemoryPlan::from_bytes(&bytes[..24]).is_err());
+
+        // the version must be 1
+        bytes[0] = 2;
+        assert!(BLiteOfflineMemoryPlan::from_bytes(&bytes).is_err());
+    }
+}
@@ -88,8 +88,11 @@ impl ArenaAllocator for BumpArenaAllocator {
         Ok(())
     }
 
-    unsafe fn dealloc(&mut self, _ptr: *mut u8, _size: usize, _align: usize) {
-        todo!()
+    // only the last allocation can be released, which is temporary such as the scratch of planning
+    unsafe fn dealloc(&mut self, ptr: *mut u8, size: usize, _align: usize) {
+        if ptr as usize + size == self.next {
+            self.next = ptr as usize;
+        }
     }
 
     fn description(&self) -> Result<(usize, usize)> {
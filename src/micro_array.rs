@@ -4,6 +4,7 @@ use num_traits::{AsPrimitive, FromPrimitive};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_errors::{BLiteError::*, Result};
 use crate::micro_slice::{alloc_array_mut, from_tflite_vector, from_tflite_vector_mut};
+use crate::micro_sparsity::BLiteSparsity;
 use crate::tflite_schema_generated::tflite::Buffer;
 use core::fmt::Debug;
 use core::mem::{align_of, size_of};
@@ -16,11 +17,17 @@ use min_max_traits::{Max, Min};
 pub struct BLiteQuantizationParams<'a> {
     pub scale: &'a [f32],
     pub zero_point: &'a [i64],
+    // the axis of the per-channel quantization
+    pub quantized_dimension: i32,
 }
 
 impl<'a> BLiteQuantizationParams<'a> {
-    pub const fn new(scale: &'a [f32], zero_point: &'a [i64]) -> Self {
-        Self { scale, zero_point }
+    pub const fn new(scale: &'a [f32], zero_point: &'a [i64], quantized_dimension: i32) -> Self {
+        Self {
+            scale,
+            zero_point,
+            quantized_dimension,
+        }
     }
 }
 
@@ -57,6 +64,8 @@ where
     pub data: &'a mut [T],
     pub dims: &'a [i32],
     pub quant_params: Option<BLiteQuantizationParams<'a>>,
+    // the compressed weights, whose data holds only the nonzero blocks
+    pub sparsity: Option<&'a BLiteSparsity<'a>>,
 }
 
 impl<'a, T: ArrayElem<T>> BLiteArray<'a, T> {
@@ -91,6 +100,7 @@ impl<'a, T: ArrayElem<T>> BLiteArray<'a, T> {
             data,
             dims: copied_dims,
             quant_params,
+            sparsity: None,
         });
     }
 
@@ -107,6 +117,7 @@ impl<'a, T: ArrayElem<T>> BLiteArray<'a, T> {
                 data,
                 dims,
                 quant_params,
+                sparsity: None,
             })
         } else {
             let dims = from_tflite_vector(&shape);
@@ -116,10 +127,27 @@ impl<'a, T: ArrayElem<T>> BLiteArray<'a, T> {
                 data,
                 dims,
                 quant_params: quant_params,
+                sparsity: None,
             })
         }
     }
 
+    /// Expands the compressed weights into the arena, which are used by the kernels
+    /// that do not have the sparse paths
+    ///
+    /// # Safety
+    /// The dense copy is allocated from the arena with `alloc_array_mut`
+    pub unsafe fn densify(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+        let Some(sparsity) = self.sparsity.take() else {
+            return Ok(());
+        };
+        let dense = alloc_array_mut(allocator, self.size())?;
+        let cols = self.dims[self.dims.len() - 1] as usize;
+        sparsity.densify(self.data, dense, cols);
+        self.data = dense;
+        Ok(())
+    }
+
     #[inline(always)]
     pub fn len(&self) -> usize {
         self.data.len()
@@ -1,10 +1,62 @@
+use core::cell::Cell;
 use core::fmt::Debug;
+use core::ops::Range;
+
+use crate::micro_errors::Result;
+#[cfg(feature = "threads")]
+use crate::micro_threads::BLiteThreadPool;
+#[cfg(feature = "threads")]
+use std::sync::Arc;
+
+/// The elements of the outputs which `parallel_for` evaluates,
+/// which are sent to the worker pool only with the `threads` feature
+#[cfg(feature = "threads")]
+pub trait ParallelElem = Copy + Default + Send + Sync + 'static;
+#[cfg(not(feature = "threads"))]
+pub trait ParallelElem = Copy + Default;
+
+/// The kernels of `parallel_for`, which the workers share with the `threads` feature
+#[cfg(feature = "threads")]
+pub trait ParallelKernel<E> = Fn(Range<usize>, &mut [E], &[Cell<E>]) -> Result<()> + Sync;
+#[cfg(not(feature = "threads"))]
+pub trait ParallelKernel<E> = Fn(Range<usize>, &mut [E], &[Cell<E>]) -> Result<()>;
 
 #[derive(Debug)]
-pub struct BLiteContext {}
+pub struct BLiteContext {
+    #[cfg(feature = "threads")]
+    thread_pool: Option<Arc<BLiteThreadPool>>,
+}
 
 impl BLiteContext {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            #[cfg(feature = "threads")]
+            thread_pool: None,
+        }
+    }
+
+    /// Creates the context whose heavy kernels are split across the worker pool
+    #[cfg(feature = "threads")]
+    pub fn with_thread_pool(thread_pool: Option<Arc<BLiteThreadPool>>) -> Self {
+        Self { thread_pool }
+    }
+
+    /// Evaluates `kernel(units, chunk, scratch)` over `output`, which consists of the units of `unit_len` elements.
+    /// With the worker pool, `output` is split into the contiguous chunks of the units evaluated in parallel,
+    /// and each worker has its own scratch of the same length as `scratch`, which the pool keeps.
+    /// Without it, the whole `output` is evaluated at once with `scratch`.
+    pub fn parallel_for<E: ParallelElem>(
+        &self,
+        output: &mut [E],
+        unit_len: usize,
+        scratch: &[Cell<E>],
+        kernel: impl ParallelKernel<E>,
+    ) -> Result<()> {
+        #[cfg(feature = "threads")]
+        if let Some(thread_pool) = self.thread_pool.as_ref().filter(|p| p.num_threads() > 1) {
+            return thread_pool.parallel_for(output, unit_len, scratch.len(), &kernel);
+        }
+        let units = output.len() / unit_len.max(1);
+        kernel(0..units, output, scratch)
     }
 }
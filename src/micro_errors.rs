@@ -5,6 +5,10 @@ pub enum BLiteError {
     // greedy memory planner
     OutOfListEntrySize,
     NotFoundRequirementIdx,
+    // offline memory plan, index of the misaligned tensor or the overlapped tensors
+    InvalidOfflineMemoryPlan,
+    MisalignedOfflineMemoryPlan(i32),
+    OverlappedOfflineMemoryPlan(i32, i32),
 
     // allocation info
     InfoIndexOutOfBound,
@@ -18,6 +22,9 @@ pub enum BLiteError {
     // micro tensors
     NotBTensor,
     NotI32Tensor,
+    NotI8Tensor,
+    NotI64Tensor,
+    NotI4Tensor,
 
     // micro graph errors
     FailedToCreateGraph,
@@ -33,6 +40,9 @@ pub enum BLiteError {
 
     // micro operator resolver
     NotFoundOperator(i32),
+    NotFoundCustomOperator,
+    // op_code and version of the model
+    NotSupportedOperatorVersion(i32, i32),
     OpIndexOutOfBound,
 
     // micro builtin options
@@ -46,10 +56,24 @@ pub enum BLiteError {
     // micro fully connected int8
     NotMatchScale(f64),
     NotFoundQuantParams,
+    // quantized_dimension of the per-channel quantization
+    NotMatchQuantizedDimension(i32),
+
+    // micro softmax int8
+    NotMatchZeroPoint(i32),
+
+    // sparse weights
+    InvalidSparsity,
+
+    // flexbuffer
+    InvalidFlexBuffer,
+    NotFoundCustomOptions,
 
     // micro reshape
     InCompatibleShape(i32, i32),
     // micro_activation
     NotFoundFusedActivation(i32),
+    // code generator, op_code of the operator which cannot be generated
+    NotSupportedCodegen(i32),
     FatalError,
 }
@@ -1,7 +1,10 @@
 use flatbuffers::{ForwardsUOffset, Vector};
 
+use crate::memory_planner::best_order_memory_planner::BestOrderMemoryPlanner;
 use crate::memory_planner::greedy_memory_planner::GreedyMemoryPlanner;
-use crate::memory_planner::MemoryPlanner;
+use crate::memory_planner::linear_memory_planner::LinearMemoryPlanner;
+use crate::memory_planner::offline_memory_plan::BLiteOfflineMemoryPlan;
+use crate::memory_planner::{BLiteMemoryPlannerKind, MemoryPlanner};
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::{ArrayElem, BLiteArray, BLiteQuantizationParams};
 use crate::micro_context::BLiteContext;
@@ -11,19 +14,27 @@ use crate::micro_errors::{
 };
 use crate::micro_node::BLiteNode;
 use crate::micro_op_resolver::BLiteOpResolver;
+use crate::micro_optimizer::{BLiteGraphOptimizer, BLiteOptimizations};
 use crate::micro_registration::BLiteRegistration;
-use crate::micro_slice::from_tflite_vector;
+use crate::micro_slice::{alloc_array_mut, from_tflite_vector};
+use crate::micro_sparsity::{densify_tflite_sparsity, BLiteSparsity};
 use crate::micro_tensor::BLiteTensor;
 use crate::micro_tensor::BLiteTensor::*;
+#[cfg(feature = "threads")]
+use crate::micro_threads::BLiteThreadPool;
 use crate::tflite_schema_generated::tflite::{
-    self, Buffer, Model, Operator, OperatorCode, QuantizationParameters, TensorType,
+    self, Buffer, BuiltinOperator, Model, Operator, OperatorCode, QuantizationParameters,
+    SparsityParameters, TensorType,
 };
+use core::any::TypeId;
 use core::cell::RefCell;
 use core::fmt::Debug;
 use core::{
     mem::{align_of, size_of},
     slice::from_raw_parts_mut,
 };
+#[cfg(feature = "threads")]
+use std::sync::Arc;
 
 /*-----------------------------------------------------------------------------*/
 /* Type synonyms for TFLiteGraph                                               */
@@ -33,6 +44,18 @@ pub(crate) type TFLiteOperators<'a> = Vector<'a, ForwardsUOffset<Operator<'a>>>;
 type TFLiteOperatorCodes<'a> = Vector<'a, ForwardsUOffset<OperatorCode<'a>>>;
 type TFLiteBuffers<'a> = Vector<'a, ForwardsUOffset<Buffer<'a>>>;
 
+/*-----------------------------------------------------------------------------*/
+/* Struct for the options of allocating a graph                                */
+/*-----------------------------------------------------------------------------*/
+/// How a graph is allocated, whose default is the graph as written and the greedy memory planner
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BLiteGraphOptions {
+    /// the passes rewriting the subgraphs before the memory planning
+    pub optimizations: BLiteOptimizations,
+    /// the memory planner laying out the intermediate tensors
+    pub memory_planner: BLiteMemoryPlannerKind,
+}
+
 /*-----------------------------------------------------------------------------*/
 /* Struct for a graph                                                          */
 /*-----------------------------------------------------------------------------*/
@@ -42,6 +65,9 @@ where
     T: ArrayElem<T> + 'a,
 {
     pub subgraphs: &'a [RefCell<BLiteSubgraph<'a, T>>],
+    // the worker pool, which is passed to the kernels through the context
+    #[cfg(feature = "threads")]
+    pub thread_pool: Option<Arc<BLiteThreadPool>>,
 }
 
 impl<'a, T> BLiteGraph<'a, T>
@@ -52,6 +78,21 @@ where
         allocator: &mut S,
         op_resolver: &'a BLiteOpResolver<'a, N, T, S>,
         model: &Model<'a>,
+    ) -> Result<Self> {
+        Self::allocate_graph_with_options(
+            allocator,
+            op_resolver,
+            model,
+            BLiteGraphOptions::default(),
+        )
+    }
+
+    /// Allocates the graph whose subgraphs are rewritten and planned as `options`
+    pub fn allocate_graph_with_options<const N: usize, S: ArenaAllocator>(
+        allocator: &mut S,
+        op_resolver: &'a BLiteOpResolver<'a, N, T, S>,
+        model: &Model<'a>,
+        options: BLiteGraphOptions,
     ) -> Result<Self> {
         let Some(subgraphs) = model.subgraphs() else {
             return Err(NotFoundSubgraphs);
@@ -69,6 +110,8 @@ where
             "expected the length of subgraphs is 1, but got {}",
             subgraphs.len()
         );
+        // the offsets in the metadata are used instead of the greedy memory planning
+        let offline_plan = BLiteOfflineMemoryPlan::from_model(model)?;
         let blite_subgraphs = unsafe {
             let row_ptr = allocator.alloc(
                 subgraphs.len() * size_of::<RefCell<BLiteSubgraph<'a, T>>>(),
@@ -81,28 +124,31 @@ where
             )
         };
         for (i, subgraph) in subgraphs.iter().enumerate() {
-            let Some(operators) = subgraph.operators() else {
-                return Err(NotFoundOperators);
-            };
-
             let blite_subgraph = BLiteSubgraph::allocate_subgraph(
                 allocator,
                 op_resolver,
                 &subgraph,
-                &operators,
                 &operator_codes,
                 &buffers,
+                options,
+                offline_plan,
             )?;
             blite_subgraphs[i] = RefCell::new(blite_subgraph);
         }
         Ok(Self {
             subgraphs: blite_subgraphs,
+            #[cfg(feature = "threads")]
+            thread_pool: None,
         })
     }
 
     pub fn invoke(&self) -> Result<()> {
+        #[cfg(not(feature = "threads"))]
+        let ctx = BLiteContext::new();
+        #[cfg(feature = "threads")]
+        let ctx = BLiteContext::with_thread_pool(self.thread_pool.clone());
         for subgraph in self.subgraphs {
-            subgraph.borrow_mut().invoke()?;
+            subgraph.borrow_mut().invoke_with_context(&ctx)?;
         }
 
         Ok(())
@@ -119,6 +165,8 @@ where
 {
     pub node_and_registrations: &'a [(BLiteNode<'a>, BLiteRegistration<'a, T>)],
     pub tensors: &'a mut [BLiteTensor<'a, T>],
+    // the byte offset of each planned tensor, or -1 if it is not planned
+    pub memory_plan: &'a [i32],
 }
 
 impl<'a, T> BLiteSubgraph<'a, T>
@@ -132,6 +180,7 @@ where
         Self {
             node_and_registrations,
             tensors,
+            memory_plan: &[],
         }
     }
 
@@ -139,37 +188,108 @@ where
         allocator: &mut S,
         op_resolver: &'a BLiteOpResolver<'a, N, T, S>,
         subgraph: &TFLiteSubGraph<'a>,
-        operators: &TFLiteOperators<'a>,
         operator_codes: &TFLiteOperatorCodes<'a>,
         buffers: &TFLiteBuffers<'a>,
+        options: BLiteGraphOptions,
+        offline_plan: Option<BLiteOfflineMemoryPlan<'a>>,
     ) -> Result<Self> {
-        let tensors = Self::allocate_eval_tensors(allocator, subgraph, buffers)?;
-
-        unsafe {
-            let unsafe_duplicate_tensors = tensors as *mut [BLiteTensor<'a, T>];
-            let mut greedy_memory_planner = GreedyMemoryPlanner::new(
-                allocator,
-                subgraph,
-                &mut *unsafe_duplicate_tensors as &mut [BLiteTensor<'a, T>],
-            )?;
-            greedy_memory_planner.commit_memory_plan(allocator)?;
+        let Some(operators) = subgraph.operators() else {
+            return Err(NotFoundOperators);
         };
+        let tensors = Self::allocate_eval_tensors(allocator, subgraph, buffers)?;
 
+        // the operators are parsed before the memory planning,
+        // so that the optimizations see the options and the planner sees the rewritten nodes
         let node_and_registrations = unsafe {
             Self::allocate_node_and_registrations(
                 op_resolver,
                 allocator,
-                operators,
+                &operators,
                 operator_codes,
                 tensors,
             )?
         };
+        let (node_and_registrations, aliases) = if options.optimizations.is_none() {
+            (&*node_and_registrations, &[][..])
+        } else {
+            unsafe {
+                BLiteGraphOptimizer::optimize(
+                    allocator,
+                    options.optimizations,
+                    node_and_registrations,
+                    tensors,
+                    from_tflite_vector(&subgraph.inputs().ok_or(NotFoundTensor)?),
+                    from_tflite_vector(&subgraph.outputs().ok_or(NotFoundTensor)?),
+                )?
+            }
+        };
+
+        let memory_plan = unsafe {
+            let unsafe_duplicate_tensors = tensors as *mut [BLiteTensor<'a, T>];
+            let tensors = &mut *unsafe_duplicate_tensors as &mut [BLiteTensor<'a, T>];
+            match options.memory_planner {
+                BLiteMemoryPlannerKind::Greedy => {
+                    let memory_planner = GreedyMemoryPlanner::new(
+                        allocator,
+                        subgraph,
+                        node_and_registrations,
+                        aliases,
+                        tensors,
+                    )?
+                    .with_offline_plan(offline_plan);
+                    Self::commit_memory_plan(memory_planner, allocator)?
+                }
+                BLiteMemoryPlannerKind::Linear => {
+                    let memory_planner = LinearMemoryPlanner::new(
+                        allocator,
+                        subgraph,
+                        node_and_registrations,
+                        aliases,
+                        tensors,
+                    )?
+                    .with_offline_plan(offline_plan);
+                    Self::commit_memory_plan(memory_planner, allocator)?
+                }
+                BLiteMemoryPlannerKind::BestOrder => {
+                    let memory_planner = BestOrderMemoryPlanner::new(
+                        allocator,
+                        subgraph,
+                        node_and_registrations,
+                        aliases,
+                        tensors,
+                    )?
+                    .with_offline_plan(offline_plan);
+                    Self::commit_memory_plan(memory_planner, allocator)?
+                }
+            }
+        };
+
         Ok(Self {
             node_and_registrations,
             tensors,
+            memory_plan,
         })
     }
 
+    fn commit_memory_plan(
+        mut memory_planner: impl MemoryPlanner<'a>,
+        allocator: &mut impl ArenaAllocator,
+    ) -> Result<&'a [i32]> {
+        memory_planner.commit_memory_plan(allocator)?;
+        Ok(memory_planner.memory_plan())
+    }
+
+    /// Returns the bytes of the arena used by the planned tensors
+    pub fn planned_size(&self) -> usize {
+        self.memory_plan
+            .iter()
+            .enumerate()
+            .filter(|(_, &offset)| offset >= 0)
+            .map(|(idx, &offset)| offset as usize + size_of::<T>() * self.tensors[idx].size())
+            .max()
+            .unwrap_or(0)
+    }
+
     fn allocate_eval_tensors(
         allocator: &mut impl ArenaAllocator,
         subgraph: &TFLiteSubGraph<'a>,
@@ -203,19 +323,41 @@ where
                 let buffer = buffers.get(tensor_idx as usize);
                 let dims = tensor.shape().unwrap();
                 let ttype = tensor.type_();
-                if ttype == TensorType::INT32 {
+                // the tensors of the activation type T are BTensor and
+                // the others are kept in the tensors of their own type
+                if Self::is_activation_type(ttype) {
+                    let tflite_tensor = unsafe {
+                        BLiteArray::from_tflite_buffer(allocator, buffer, dims, blite_quant_params)?
+                    };
+                    tensors[i] = BTensor(RefCell::new(tflite_tensor));
+                } else if ttype == TensorType::INT32 {
                     let tflite_tensor = unsafe {
                         BLiteArray::from_tflite_buffer(allocator, buffer, dims, blite_quant_params)?
                     };
                     tensors[i] = I32Tensor(RefCell::new(tflite_tensor));
-                } else if ttype == TensorType::INT8 || ttype == TensorType::FLOAT32 {
+                } else if ttype == TensorType::INT8 {
                     let tflite_tensor = unsafe {
                         BLiteArray::from_tflite_buffer(allocator, buffer, dims, blite_quant_params)?
                     };
-                    tensors[i] = BTensor(RefCell::new(tflite_tensor));
+                    tensors[i] = I8Tensor(RefCell::new(tflite_tensor));
+                } else if ttype == TensorType::INT64 {
+                    let tflite_tensor = unsafe {
+                        BLiteArray::from_tflite_buffer(allocator, buffer, dims, blite_quant_params)?
+                    };
+                    tensors[i] = I64Tensor(RefCell::new(tflite_tensor));
+                } else if ttype == TensorType::INT4 {
+                    // the packed int4 weights are kept in the flatbuffer and unpacked by the kernels
+                    let tflite_tensor = unsafe {
+                        BLiteArray::from_tflite_buffer(allocator, buffer, dims, blite_quant_params)?
+                    };
+                    tensors[i] = I4Tensor(RefCell::new(tflite_tensor));
                 } else {
                     return Err(BLiteError::InCompatibleType);
                 }
+
+                if let Some(sparsity) = tensor.sparsity() {
+                    unsafe { Self::load_sparse_tensor(allocator, &tensors[i], &sparsity)? };
+                }
             }
             Ok(tensors)
         } else {
@@ -223,6 +365,56 @@ where
         }
     }
 
+    /// Keeps the sparse weights compressed if the sparse kernels can handle their format,
+    /// and otherwise densifies them into the arena
+    unsafe fn load_sparse_tensor(
+        allocator: &mut impl ArenaAllocator,
+        tensor: &BLiteTensor<'a, T>,
+        sparsity: &SparsityParameters<'a>,
+    ) -> Result<()> {
+        match tensor {
+            BTensor(x) => Self::load_sparse_array(allocator, &mut x.borrow_mut(), sparsity),
+            I32Tensor(x) => Self::load_sparse_array(allocator, &mut x.borrow_mut(), sparsity),
+            I8Tensor(x) => Self::load_sparse_array(allocator, &mut x.borrow_mut(), sparsity),
+            I64Tensor(x) => Self::load_sparse_array(allocator, &mut x.borrow_mut(), sparsity),
+            // the packed int4 weights cannot be sparse
+            I4Tensor(_) => Err(InvalidSparsity),
+        }
+    }
+
+    unsafe fn load_sparse_array<E: ArrayElem<E>>(
+        allocator: &mut impl ArenaAllocator,
+        array: &mut BLiteArray<'a, E>,
+        sparsity: &SparsityParameters<'a>,
+    ) -> Result<()> {
+        if let Some(sparsity) =
+            BLiteSparsity::from_tflite_sparsity(allocator, sparsity, array.dims)?
+        {
+            // only the sparse tensors hold the compressed form in the arena
+            let [compressed] = alloc_array_mut(allocator, 1)? else {
+                return Err(FailedToAllocateMemory);
+            };
+            *compressed = sparsity;
+            array.sparsity = Some(compressed);
+        } else {
+            let dense = alloc_array_mut(allocator, array.size())?;
+            densify_tflite_sparsity(sparsity, array.dims, array.data, dense)?;
+            array.data = dense;
+        }
+        Ok(())
+    }
+
+    fn is_activation_type(ttype: TensorType) -> bool {
+        let t = TypeId::of::<T>();
+        match ttype {
+            TensorType::FLOAT32 => t == TypeId::of::<f32>(),
+            TensorType::INT16 => t == TypeId::of::<i16>(),
+            TensorType::INT8 => t == TypeId::of::<i8>(),
+            TensorType::UINT8 => t == TypeId::of::<u8>(),
+            _ => false,
+        }
+    }
+
     fn parse_quant_params(
         quant_params: Option<QuantizationParameters<'a>>,
     ) -> Option<BLiteQuantizationParams> {
@@ -237,7 +429,11 @@ where
             let scales = unsafe { from_tflite_vector(&scale_vec) };
             let zero_points = unsafe { from_tflite_vector(&zero_point_vec) };
 
-            Some(BLiteQuantizationParams::new(scales, zero_points))
+            Some(BLiteQuantizationParams::new(
+                scales,
+                zero_points,
+                quant_params.quantized_dimension(),
+            ))
         } else {
             None
         }
@@ -249,7 +445,7 @@ where
         operators: &TFLiteOperators<'a>,
         operator_codes: &TFLiteOperatorCodes<'a>,
         tensors: &mut [BLiteTensor<'a, T>],
-    ) -> Result<&'a [(BLiteNode<'a>, BLiteRegistration<'a, T>)]> {
+    ) -> Result<&'a mut [(BLiteNode<'a>, BLiteRegistration<'a, T>)]> {
         let node_and_registrations_row_ptr = allocator.alloc(
             size_of::<(BLiteNode<'_>, BLiteRegistration<T>)>() * operators.len(),
             align_of::<(BLiteNode<'_>, BLiteRegistration<T>)>(),
@@ -297,28 +493,48 @@ where
         let tf_op = operator_codes.get(idx as usize);
         let builtin_code = tf_op.builtin_code().0;
         let deprecated_builtin_code = tf_op.deprecated_builtin_code() as i32;
-        let blite_op = if builtin_code != deprecated_builtin_code {
-            op_resolver.find_op(deprecated_builtin_code)?
+        let op_code = if builtin_code != deprecated_builtin_code {
+            deprecated_builtin_code
         } else {
-            op_resolver.find_op(builtin_code)?
+            builtin_code
+        };
+        let blite_op = if op_code == BuiltinOperator::CUSTOM.0 {
+            let Some(custom_code) = tf_op.custom_code() else {
+                return Err(NotFoundCustomOperator);
+            };
+            op_resolver.find_custom_op(custom_code, tf_op.version())?
+        } else {
+            op_resolver.find_op(op_code, tf_op.version())?
         };
         let mut registration = blite_op.get_registration();
         let parser = blite_op.get_parser();
         let builtin_option = parser(allocator, *op, tensors)?;
 
+        // the compressed weights which the sparse kernels have not taken are densified
+        for idx in op.inputs().unwrap() {
+            if idx >= 0 && tensors[idx as usize].is_sparse() {
+                tensors[idx as usize].densify(allocator)?;
+            }
+        }
+
         registration.builtin_option = builtin_option;
 
         return Ok(registration);
     }
 
     pub fn invoke(&mut self) -> Result<()> {
+        self.invoke_with_context(&BLiteContext::new())
+    }
+
+    /// Evaluates the nodes in order with `ctx`,
+    /// whose worker pool splits the outputs of the heavy kernels if there is
+    pub fn invoke_with_context(&mut self, ctx: &BLiteContext) -> Result<()> {
         let node_and_registrations = self.node_and_registrations;
-        let ctx = BLiteContext::new();
         for (_, (node, registration)) in node_and_registrations.iter().enumerate() {
             let tensors = unsafe { &mut *(self.tensors as *mut [BLiteTensor<_>]) };
             let builtin_option = registration.builtin_option;
             let eval = registration.eval;
-            eval(&ctx, tensors, node, builtin_option)?;
+            eval(ctx, tensors, node, builtin_option)?;
         }
         Ok(())
     }
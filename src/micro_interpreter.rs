@@ -1,9 +1,14 @@
+use crate::memory_planner::offline_memory_plan::BLiteOfflineMemoryPlan;
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::{ArrayElem, BLiteArray};
 use crate::micro_errors::Result;
-use crate::micro_graph::BLiteGraph;
+use crate::micro_graph::{BLiteGraph, BLiteGraphOptions};
 use crate::micro_op_resolver::BLiteOpResolver;
+#[cfg(feature = "threads")]
+use crate::micro_threads::BLiteThreadPool;
 use crate::tflite_schema_generated::tflite::Model;
+#[cfg(feature = "threads")]
+use std::sync::Arc;
 
 #[derive(Debug)]
 pub struct BLiteInterpreter<'a, T>
@@ -13,7 +18,7 @@ where
     version: u32,
     pub input: &'a mut BLiteArray<'a, T>,
     pub output: &'a BLiteArray<'a, T>,
-    graph: BLiteGraph<'a, T>,
+    pub(crate) graph: BLiteGraph<'a, T>,
 }
 
 impl<'a, T> BLiteInterpreter<'a, T>
@@ -24,6 +29,16 @@ where
         allocator: &mut S,
         op_resolver: &'a BLiteOpResolver<'a, N, T, S>,
         model: &'a Model<'a>,
+    ) -> Result<Self> {
+        Self::new_with_options(allocator, op_resolver, model, BLiteGraphOptions::default())
+    }
+
+    /// Creates the interpreter whose graph is rewritten and planned as `options`
+    pub fn new_with_options<const N: usize, S: ArenaAllocator>(
+        allocator: &mut S,
+        op_resolver: &'a BLiteOpResolver<'a, N, T, S>,
+        model: &'a Model<'a>,
+        options: BLiteGraphOptions,
     ) -> Result<Self> {
         let version = model.version();
         assert_eq!(
@@ -32,7 +47,8 @@ where
             version
         );
 
-        let graph = BLiteGraph::allocate_graph(allocator, op_resolver, model)?;
+        let graph =
+            BLiteGraph::allocate_graph_with_options(allocator, op_resolver, model, options)?;
 
         let subgraph = model.subgraphs().unwrap().get(0);
         assert_eq!(
@@ -75,6 +91,31 @@ where
         self.graph.invoke()
     }
 
+    /// Sets the worker pool, which evaluates CONV_2D, DEPTHWISE_CONV_2D and FULLY_CONNECTED in parallel.
+    /// The outputs are the same as the single-threaded invoke.
+    #[cfg(feature = "threads")]
+    pub fn set_thread_pool(&mut self, thread_pool: Option<Arc<BLiteThreadPool>>) {
+        self.graph.thread_pool = thread_pool;
+    }
+
+    /// Returns the byte offset of each tensor from the start of the planned tensors,
+    /// or -1 if it is not planned
+    pub fn memory_plan(&self) -> &'a [i32] {
+        self.graph.subgraphs[0].borrow().memory_plan
+    }
+
+    /// Returns the bytes of the arena used by the intermediate tensors, which the memory planner lays out
+    pub fn planned_size(&self) -> usize {
+        self.graph.subgraphs[0].borrow().planned_size()
+    }
+
+    /// Writes the memory plan as the buffer of the `OfflineMemoryAllocation` metadata to `out`,
+    /// so that the model embedding it is allocated without the memory planning,
+    /// and returns the number of the written bytes
+    pub fn write_offline_memory_plan(&self, out: &mut [u8]) -> Result<usize> {
+        BLiteOfflineMemoryPlan::write_metadata(self.memory_plan(), out)
+    }
+
     pub fn get_input_quantization_params(&self) -> Option<(f32, i32)> {
         self.input
             .get_quantization_scale_and_zero_point()
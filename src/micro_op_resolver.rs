@@ -4,6 +4,7 @@ use crate::kernel::micro_operator::BLiteOperator;
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_array::ArrayElem;
 use crate::micro_errors::{BLiteError::*, Result};
+use crate::tflite_schema_generated::tflite::BuiltinOperator;
 
 #[derive(Debug, Clone)]
 pub struct BLiteOpResolver<'a, const N: usize, T, S>
@@ -25,18 +26,47 @@ impl<'a, const N: usize, T: ArrayElem<T>, S: ArenaAllocator> BLiteOpResolver<'a,
         }
     }
 
-    pub fn find_op(&self, op_code: i32) -> Result<&'a BLiteOperator<T, S>> {
-        for operator in &self.operators {
-            if let Some(blite_op) = operator {
-                let blite_op_code = blite_op.get_op_code();
-                if blite_op_code == op_code {
+    /// Returns the operator of `op_code` which supports `version`.
+    /// Several operators of the same code may be added for different versions.
+    pub fn find_op(&'a self, op_code: i32, version: i32) -> Result<&'a BLiteOperator<'a, T, S>> {
+        let mut found_op_code = false;
+        for blite_op in self.operators.iter().flatten() {
+            if blite_op.get_op_code() == op_code && blite_op.get_custom_code().is_none() {
+                if blite_op.supports_version(version) {
                     return Ok(blite_op);
                 }
+                found_op_code = true;
             }
         }
+        if found_op_code {
+            return Err(NotSupportedOperatorVersion(op_code, version));
+        }
         Err(NotFoundOperator(op_code))
     }
 
+    pub fn find_custom_op(
+        &'a self,
+        custom_code: &str,
+        version: i32,
+    ) -> Result<&'a BLiteOperator<'a, T, S>> {
+        let mut found_custom_code = false;
+        for blite_op in self.operators.iter().flatten() {
+            if blite_op.get_custom_code() == Some(custom_code) {
+                if blite_op.supports_version(version) {
+                    return Ok(blite_op);
+                }
+                found_custom_code = true;
+            }
+        }
+        if found_custom_code {
+            return Err(NotSupportedOperatorVersion(
+                BuiltinOperator::CUSTOM.0,
+                version,
+            ));
+        }
+        Err(NotFoundCustomOperator)
+    }
+
     pub fn add_op(&mut self, operator: BLiteOperator<'a, T, S>) -> Result<()> {
         if self.idx >= self.operators.len() {
             return Err(OpIndexOutOfBound);
@@ -47,3 +77,38 @@ impl<'a, const N: usize, T: ArrayElem<T>, S: ArenaAllocator> BLiteOpResolver<'a,
         return Ok(());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BLiteOpResolver;
+    use crate::kernel::micro_operator::f32::fully_connected::OpFullyConnected;
+    use crate::kernel::micro_operator::BLiteOperator;
+    use crate::micro_allocator::BumpArenaAllocator;
+    use crate::micro_errors::BLiteError::*;
+
+    #[test]
+    fn test_find_op_by_version() {
+        let mut op_resolver = BLiteOpResolver::<2, f32, BumpArenaAllocator>::new();
+        op_resolver
+            .add_op(OpFullyConnected::fully_connected())
+            .unwrap();
+        let fully_connected_v7 = BLiteOperator::new(
+            OpFullyConnected::registration(),
+            OpFullyConnected::parser,
+            7,
+            7,
+        );
+        op_resolver.add_op(fully_connected_v7).unwrap();
+
+        assert!(op_resolver.find_op(9, 4).unwrap().supports_version(1));
+        assert!(op_resolver.find_op(9, 7).unwrap().supports_version(7));
+        assert!(matches!(
+            op_resolver.find_op(9, 9),
+            Err(NotSupportedOperatorVersion(9, 9))
+        ));
+        assert!(matches!(
+            op_resolver.find_op(3, 1),
+            Err(NotFoundOperator(3))
+        ));
+    }
+}
@@ -0,0 +1,841 @@
+use crate::kernel::micro_builtin_options::BLiteBuiltinOption::{self, *};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::ArrayElem;
+use crate::micro_errors::Result;
+use crate::micro_node::BLiteNode;
+use crate::micro_registration::BLiteRegistration;
+use crate::micro_slice::alloc_array_mut;
+use crate::micro_tensor::BLiteTensor::{self, *};
+use crate::tflite_schema_generated::tflite::BuiltinOperator;
+
+/*-----------------------------------------------------------------------------*/
+/* Struct for the optimizations of a graph                                     */
+/*-----------------------------------------------------------------------------*/
+/// The passes over the nodes of a subgraph, which run after the operators are parsed
+/// and before the memory planning. All of them are disabled by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BLiteOptimizations {
+    /// RESHAPE and SQUEEZE are removed, and their outputs share the buffers of their inputs
+    pub elide_reshapes: bool,
+    /// PAD or PADV2 of the height and the width with the zero point is folded into the padding
+    /// of the following CONV_2D or DEPTHWISE_CONV_2D
+    pub fold_pads: bool,
+    /// RELU, RELU_N1_TO_1 and RELU6 are fused into the activation range of the preceding
+    /// CONV_2D, DEPTHWISE_CONV_2D or FULLY_CONNECTED
+    pub fuse_activations: bool,
+    /// the nodes none of whose outputs are read are removed
+    pub remove_unused_nodes: bool,
+}
+
+impl BLiteOptimizations {
+    pub const fn none() -> Self {
+        Self {
+            elide_reshapes: false,
+            fold_pads: false,
+            fuse_activations: false,
+            remove_unused_nodes: false,
+        }
+    }
+
+    pub const fn all() -> Self {
+        Self {
+            elide_reshapes: true,
+            fold_pads: true,
+            fuse_activations: true,
+            remove_unused_nodes: true,
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        *self == Self::none()
+    }
+}
+
+/*-----------------------------------------------------------------------------*/
+/* Struct for an optimizer of a subgraph                                       */
+/*-----------------------------------------------------------------------------*/
+// the nodes which are left and the aliases of the tensors
+type BLiteOptimizedGraph<'a, T> = (&'a [(BLiteNode<'a>, BLiteRegistration<'a, T>)], &'a [i32]);
+
+pub(crate) struct BLiteGraphOptimizer<'a, 't, T>
+where
+    T: ArrayElem<T>,
+{
+    nodes: &'a mut [(BLiteNode<'a>, BLiteRegistration<'a, T>)],
+    // the number of the nodes which are left
+    len: usize,
+    tensors: &'t [BLiteTensor<'a, T>],
+    graph_inputs: &'a [i32],
+    graph_outputs: &'a [i32],
+    // the tensor whose buffer each tensor shares, or -1 if it has its own
+    aliases: &'a mut [i32],
+}
+
+impl<'a, 't, T> BLiteGraphOptimizer<'a, 't, T>
+where
+    T: ArrayElem<T>,
+{
+    /// Runs the enabled passes, and returns the nodes which are left
+    /// and the aliases of the tensors for the memory planner
+    pub(crate) unsafe fn optimize(
+        allocator: &mut impl ArenaAllocator,
+        optimizations: BLiteOptimizations,
+        nodes: &'a mut [(BLiteNode<'a>, BLiteRegistration<'a, T>)],
+        tensors: &'t [BLiteTensor<'a, T>],
+        graph_inputs: &'a [i32],
+        graph_outputs: &'a [i32],
+    ) -> Result<BLiteOptimizedGraph<'a, T>> {
+        let aliases = if optimizations.elide_reshapes {
+            let aliases = alloc_array_mut(allocator, tensors.len())?;
+            aliases.fill(-1);
+            aliases
+        } else {
+            &mut []
+        };
+        let len = nodes.len();
+        let mut optimizer = Self {
+            nodes,
+            len,
+            tensors,
+            graph_inputs,
+            graph_outputs,
+            aliases,
+        };
+
+        // the unused nodes are removed first, so that the other passes count only the live readers
+        if optimizations.remove_unused_nodes {
+            optimizer.remove_unused_nodes();
+        }
+        if optimizations.fold_pads {
+            optimizer.fold_pads(allocator)?;
+        }
+        if optimizations.fuse_activations {
+            optimizer.fuse_activations(allocator)?;
+        }
+        if optimizations.elide_reshapes {
+            optimizer.elide_reshapes();
+        }
+
+        let Self {
+            nodes,
+            len,
+            aliases,
+            ..
+        } = optimizer;
+        Ok((&nodes[..len], aliases))
+    }
+
+    fn remove(&mut self, i: usize) {
+        self.nodes[i..self.len].rotate_left(1);
+        self.len -= 1;
+    }
+
+    fn is_graph_output(&self, idx: i32) -> bool {
+        self.graph_outputs.contains(&idx)
+    }
+
+    fn is_graph_input(&self, idx: i32) -> bool {
+        self.graph_inputs.contains(&idx)
+    }
+
+    /// Returns the nodes which read the tensor
+    fn readers(&self, idx: i32) -> impl Iterator<Item = usize> + use<'_, 'a, 't, T> {
+        self.nodes[..self.len]
+            .iter()
+            .enumerate()
+            .filter(move |(_, (node, _))| node.inputs.contains(&idx))
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the only node reading the tensor, unless the tensor is read by the caller
+    fn single_reader(&self, idx: i32) -> Option<usize> {
+        if self.is_graph_output(idx) {
+            return None;
+        }
+        let mut readers = self.readers(idx);
+        match (readers.next(), readers.next()) {
+            (Some(i), None) => Some(i),
+            _ => None,
+        }
+    }
+
+    fn producer(&self, idx: i32) -> Option<usize> {
+        self.nodes[..self.len]
+            .iter()
+            .position(|(node, _)| node.outputs.contains(&idx))
+    }
+
+    fn root(&self, idx: i32) -> i32 {
+        match self.aliases[idx as usize] {
+            root if root >= 0 => root,
+            _ => idx,
+        }
+    }
+
+    /// The activation tensors are planned, whose data is empty before the memory planning
+    fn is_activation(&self, idx: i32) -> bool {
+        let tensor = &self.tensors[idx as usize];
+        tensor._t().is_ok() && tensor.len() == 0
+    }
+
+    unsafe fn replace(
+        allocator: &mut impl ArenaAllocator,
+        indices: &[i32],
+        i: usize,
+        idx: i32,
+    ) -> Result<&'a [i32]> {
+        let replaced = alloc_array_mut(allocator, indices.len())?;
+        replaced.copy_from_slice(indices);
+        replaced[i] = idx;
+        Ok(replaced)
+    }
+
+    fn remove_unused_nodes(&mut self) {
+        // the nodes are visited backwards, so that the producers of the removed nodes are removed too
+        for i in (0..self.len).rev() {
+            let outputs = self.nodes[i].0.outputs;
+            let is_unused = !outputs.is_empty()
+                && outputs
+                    .iter()
+                    .all(|&idx| !self.is_graph_output(idx) && self.readers(idx).next().is_none());
+            if is_unused {
+                self.remove(i);
+            }
+        }
+    }
+
+    unsafe fn fold_pads(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+        let mut i = 0;
+        while i < self.len {
+            let (node, registration) = &self.nodes[i];
+            let is_pad =
+                [BuiltinOperator::PAD.0, BuiltinOperator::PADV2.0].contains(&registration.op_code);
+            if !is_pad || node.inputs.len() < 2 || !self.pads_zero_point(node, registration) {
+                i += 1;
+                continue;
+            }
+            let (input, paddings) = (node.inputs[0], node.inputs[1]);
+            let Some(&output) = node.outputs.first() else {
+                i += 1;
+                continue;
+            };
+            let Some(j) = self.single_reader(output) else {
+                i += 1;
+                continue;
+            };
+            let Some((top, left)) = self.height_width_paddings(paddings) else {
+                i += 1;
+                continue;
+            };
+            // the reader has to be a convolution taking the output as its input,
+            // and the pointwise convolutions are evaluated by GEMM on the unpadded input
+            let conv_inputs = self.nodes[j].0.inputs;
+            let (Some(&conv_input), Some(&filter)) = (conv_inputs.first(), conv_inputs.get(1))
+            else {
+                i += 1;
+                continue;
+            };
+            if conv_input != output
+                || !self.has_same_quantization(input, output)
+                || self.is_pointwise_filter(filter)
+            {
+                i += 1;
+                continue;
+            }
+            let (conv, conv_registration) = &mut self.nodes[j];
+            let Some(builtin_option) =
+                Self::add_padding(conv_registration.builtin_option, top, left)
+            else {
+                i += 1;
+                continue;
+            };
+            conv_registration.builtin_option = builtin_option;
+            conv.inputs = Self::replace(allocator, conv.inputs, 0, input)?;
+            self.remove(i);
+        }
+        Ok(())
+    }
+
+    /// Returns whether the pad value is the zero point of the input (0 for the float tensors),
+    /// which is the value of the paddings of the convolutions
+    fn pads_zero_point(
+        &self,
+        node: &BLiteNode<'a>,
+        registration: &BLiteRegistration<'a, T>,
+    ) -> bool {
+        let zero_point = match self.tensors[node.inputs[0] as usize].quant_params() {
+            Some(quant_params) => quant_params.zero_point[0],
+            None => 0,
+        };
+        let Some(zero_point) = T::from_i64(zero_point) else {
+            return false;
+        };
+        // PADV2 takes the pad value as the third input
+        let pad_value = match (node.inputs.get(2), registration.builtin_option) {
+            (Some(&idx), _) if idx >= 0 => match &self.tensors[idx as usize] {
+                BTensor(pad_value) => match *pad_value.borrow().data {
+                    [pad_value] => pad_value,
+                    _ => return false,
+                },
+                _ => return false,
+            },
+            (_, PadOptions { constant_value }) => constant_value,
+            _ => return false,
+        };
+        pad_value == zero_point
+    }
+
+    /// Returns the top and left paddings of the constant NHWC paddings,
+    /// which must not pad the batches and the channels
+    fn height_width_paddings(&self, paddings: i32) -> Option<(i32, i32)> {
+        let I32Tensor(paddings) = &self.tensors[paddings as usize] else {
+            return None;
+        };
+        match *paddings.borrow().data {
+            [0, 0, top, bottom, left, right, 0, 0] if top.min(bottom).min(left).min(right) >= 0 => {
+                Some((top, left))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_pointwise_filter(&self, filter: i32) -> bool {
+        match &self.tensors[filter as usize] {
+            BTensor(filter) => filter.borrow().dims[1..3] == [1, 1],
+            I8Tensor(filter) => filter.borrow().dims[1..3] == [1, 1],
+            _ => true,
+        }
+    }
+
+    fn add_padding(
+        builtin_option: BLiteBuiltinOption<'a, T>,
+        top: i32,
+        left: i32,
+    ) -> Option<BLiteBuiltinOption<'a, T>> {
+        let mut builtin_option = builtin_option;
+        match &mut builtin_option {
+            Conv2DOptions {
+                padding_w,
+                padding_h,
+                ..
+            }
+            | DepthWiseConv2DOptions {
+                padding_w,
+                padding_h,
+                ..
+            }
+            | QuantizedConv2DOptions {
+                padding_w,
+                padding_h,
+                ..
+            }
+            | QuantizedDepthWiseConv2DOptions {
+                padding_w,
+                padding_h,
+                ..
+            } => {
+                *padding_w += left;
+                *padding_h += top;
+            }
+            _ => return None,
+        }
+        Some(builtin_option)
+    }
+
+    unsafe fn fuse_activations(&mut self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+        let mut i = 0;
+        while i < self.len {
+            let (node, registration) = &self.nodes[i];
+            let is_relu = [
+                BuiltinOperator::RELU.0,
+                BuiltinOperator::RELU_N1_TO_1.0,
+                BuiltinOperator::RELU6.0,
+            ]
+            .contains(&registration.op_code);
+            // the other nodes, such as the custom operators, may have no inputs or outputs
+            let (true, Some(&input), Some(&output)) =
+                (is_relu, node.inputs.first(), node.outputs.first())
+            else {
+                i += 1;
+                continue;
+            };
+            let relu_option = registration.builtin_option;
+            let (Some(j), Some(_)) = (self.producer(input), self.single_reader(input)) else {
+                i += 1;
+                continue;
+            };
+            // the output of the RELU replaces the only output of the producer,
+            // which the RELU has to read
+            if self.nodes[j].0.outputs != [input] || !self.has_same_quantization(input, output) {
+                i += 1;
+                continue;
+            }
+            let (producer, producer_registration) = &mut self.nodes[j];
+            let Some(builtin_option) =
+                Self::clamp_activation(producer_registration.builtin_option, relu_option)
+            else {
+                i += 1;
+                continue;
+            };
+            producer_registration.builtin_option = builtin_option;
+            producer.outputs = Self::replace(allocator, producer.outputs, 0, output)?;
+            self.remove(i);
+        }
+        Ok(())
+    }
+
+    /// The quantized RELU requantizes unless its input and output have the same parameters
+    fn has_same_quantization(&self, input: i32, output: i32) -> bool {
+        let input = self.tensors[input as usize].quant_params();
+        let output = self.tensors[output as usize].quant_params();
+        match (input, output) {
+            (Some(input), Some(output)) => {
+                input.scale == output.scale && input.zero_point == output.zero_point
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn clamp_activation(
+        builtin_option: BLiteBuiltinOption<'a, T>,
+        relu_option: BLiteBuiltinOption<'a, T>,
+    ) -> Option<BLiteBuiltinOption<'a, T>> {
+        let mut builtin_option = builtin_option;
+        match (&mut builtin_option, relu_option) {
+            (
+                Conv2DOptions {
+                    fused_activation_min,
+                    fused_activation_max,
+                    ..
+                }
+                | DepthWiseConv2DOptions {
+                    fused_activation_min,
+                    fused_activation_max,
+                    ..
+                }
+                | FullyConnectedOptions {
+                    fused_activation_min,
+                    fused_activation_max,
+                    ..
+                },
+                ReluOptions {
+                    fused_activation_min: relu_min,
+                    fused_activation_max: relu_max,
+                },
+            ) => {
+                if *fused_activation_min < relu_min {
+                    *fused_activation_min = relu_min;
+                }
+                if *fused_activation_max > relu_max {
+                    *fused_activation_max = relu_max;
+                }
+            }
+            (
+                QuantizedConv2DOptions {
+                    fused_activation_min,
+                    fused_activation_max,
+                    ..
+                }
+                | QuantizedDepthWiseConv2DOptions {
+                    fused_activation_min,
+                    fused_activation_max,
+                    ..
+                }
+                | QuantizedFullyConnectedOptions {
+                    fused_activation_min,
+                    fused_activation_max,
+                    ..
+                },
+                QuantizedReluOptions {
+                    fused_activation_min: relu_min,
+                    fused_activation_max: relu_max,
+                    ..
+                },
+            ) => {
+                *fused_activation_min = (*fused_activation_min).max(relu_min);
+                *fused_activation_max = (*fused_activation_max).min(relu_max);
+            }
+            _ => return None,
+        }
+        Some(builtin_option)
+    }
+
+    fn elide_reshapes(&mut self) {
+        let mut i = 0;
+        while i < self.len {
+            let (node, registration) = &self.nodes[i];
+            let is_reshape = registration.op_code == BuiltinOperator::RESHAPE.0
+                || registration.op_code == BuiltinOperator::SQUEEZE.0;
+            // the other nodes, such as the custom operators, may have no inputs or outputs
+            let (true, Some(&input), Some(&output)) =
+                (is_reshape, node.inputs.first(), node.outputs.first())
+            else {
+                i += 1;
+                continue;
+            };
+            if !self.is_activation(input)
+                || !self.is_activation(output)
+                || self.tensors[input as usize].size() != self.tensors[output as usize].size()
+            {
+                i += 1;
+                continue;
+            }
+
+            let root = self.root(input);
+            if !self.is_graph_output(output) {
+                self.aliases[output as usize] = root;
+            } else if !self.is_graph_input(root) && !self.is_graph_output(root) {
+                // the graph output has its own buffer, into which the producers write directly
+                for alias in self.aliases.iter_mut() {
+                    if *alias == root {
+                        *alias = output;
+                    }
+                }
+                self.aliases[root as usize] = output;
+            } else {
+                i += 1;
+                continue;
+            }
+            self.remove(i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::micro_allocator::BumpArenaAllocator;
+    use crate::micro_array::BLiteArray;
+    use crate::micro_context::BLiteContext;
+    use core::cell::RefCell;
+
+    const ARENA_SIZE: usize = 1024;
+
+    type Node = (BLiteNode<'static>, BLiteRegistration<'static, f32>);
+
+    fn eval(
+        _: &BLiteContext,
+        _: &'static mut [BLiteTensor<'static, f32>],
+        _: &BLiteNode<'static>,
+        _: BLiteBuiltinOption<f32>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    fn leak<E>(v: Vec<E>) -> &'static mut [E] {
+        Box::leak(v.into_boxed_slice())
+    }
+
+    /// The tensor whose data is planned later
+    fn activation(dims: &[i32]) -> BLiteTensor<'static, f32> {
+        BTensor(RefCell::new(BLiteArray {
+            data: &mut [],
+            dims: leak(dims.to_vec()),
+            quant_params: None,
+            sparsity: None,
+        }))
+    }
+
+    fn constant(data: &[f32], dims: &[i32]) -> BLiteTensor<'static, f32> {
+        BTensor(RefCell::new(BLiteArray {
+            data: leak(data.to_vec()),
+            dims: leak(dims.to_vec()),
+            quant_params: None,
+            sparsity: None,
+        }))
+    }
+
+    fn constant_i32(data: &[i32], dims: &[i32]) -> BLiteTensor<'static, f32> {
+        I32Tensor(RefCell::new(BLiteArray {
+            data: leak(data.to_vec()),
+            dims: leak(dims.to_vec()),
+            quant_params: None,
+            sparsity: None,
+        }))
+    }
+
+    fn node(
+        op_code: BuiltinOperator,
+        inputs: &[i32],
+        outputs: &[i32],
+        builtin_option: BLiteBuiltinOption<'static, f32>,
+    ) -> Node {
+        let node = BLiteNode {
+            inputs: leak(inputs.to_vec()),
+            outputs: leak(outputs.to_vec()),
+        };
+        (
+            node,
+            BLiteRegistration::new(op_code.0, eval, builtin_option),
+        )
+    }
+
+    /// The custom operator which has neither inputs nor outputs
+    fn custom() -> Node {
+        let node = BLiteNode {
+            inputs: &[],
+            outputs: &[],
+        };
+        (
+            node,
+            BLiteRegistration::new_custom("custom", eval, NotInitialize),
+        )
+    }
+
+    fn relu(min: f32, max: f32) -> BLiteBuiltinOption<'static, f32> {
+        ReluOptions {
+            fused_activation_min: min,
+            fused_activation_max: max,
+        }
+    }
+
+    fn conv2d() -> BLiteBuiltinOption<'static, f32> {
+        Conv2DOptions {
+            op_code: BuiltinOperator::CONV_2D.0,
+            fused_activation_min: f32::MIN,
+            fused_activation_max: f32::MAX,
+            padding: 1,
+            padding_w: 0,
+            padding_h: 0,
+            padding_w_offset: 0,
+            padding_h_offset: 0,
+            stride_w: 1,
+            stride_h: 1,
+            dilation_w_factor: 1,
+            dilation_h_factor: 1,
+            filter_sparsity: None,
+            im2col_buffer: &[],
+        }
+    }
+
+    fn fully_connected() -> BLiteBuiltinOption<'static, f32> {
+        FullyConnectedOptions {
+            op_code: BuiltinOperator::FULLY_CONNECTED.0,
+            fused_activation_min: f32::MIN,
+            fused_activation_max: f32::MAX,
+            filter_sparsity: None,
+        }
+    }
+
+    fn optimize(
+        optimizations: BLiteOptimizations,
+        nodes: Vec<Node>,
+        tensors: Vec<BLiteTensor<'static, f32>>,
+        graph_outputs: &[i32],
+    ) -> Result<BLiteOptimizedGraph<'static, f32>> {
+        let mut allocator = unsafe { BumpArenaAllocator::new(leak(vec![0; ARENA_SIZE])) };
+        unsafe {
+            BLiteGraphOptimizer::optimize(
+                &mut allocator,
+                optimizations,
+                leak(nodes),
+                leak(tensors),
+                &[0],
+                leak(graph_outputs.to_vec()),
+            )
+        }
+    }
+
+    fn outputs(nodes: &[Node]) -> Vec<&[i32]> {
+        nodes.iter().map(|(node, _)| node.outputs).collect()
+    }
+
+    #[test]
+    fn test_remove_unused_nodes() -> Result<()> {
+        let optimizations = BLiteOptimizations {
+            remove_unused_nodes: true,
+            ..BLiteOptimizations::none()
+        };
+        let tensors = || (0..5).map(|_| activation(&[1, 4])).collect();
+
+        // 2 is not read, and neither is 4 whose producer reads 2
+        let nodes = vec![
+            node(BuiltinOperator::RELU, &[0], &[1], relu(0., f32::MAX)),
+            node(BuiltinOperator::RELU, &[0], &[2], relu(0., f32::MAX)),
+            node(BuiltinOperator::RELU, &[2], &[4], relu(0., f32::MAX)),
+            custom(),
+            node(BuiltinOperator::RELU, &[1], &[3], relu(0., f32::MAX)),
+        ];
+        let (nodes, aliases) = optimize(optimizations, nodes, tensors(), &[3])?;
+        assert_eq!(outputs(nodes), [&[1][..], &[], &[3]]);
+        assert!(aliases.is_empty());
+
+        // all the nodes are used
+        let nodes = vec![
+            node(BuiltinOperator::RELU, &[0], &[1], relu(0., f32::MAX)),
+            node(BuiltinOperator::RELU, &[1], &[2], relu(0., f32::MAX)),
+            node(BuiltinOperator::RELU, &[1], &[3], relu(0., f32::MAX)),
+        ];
+        let (nodes, _) = optimize(optimizations, nodes, tensors(), &[2, 3])?;
+        assert_eq!(outputs(nodes), [&[1][..], &[2], &[3]]);
+        Ok(())
+    }
+
+    /// Runs fold_pads on PAD of `constant_value` followed by `reader`,
+    /// whose filter is [1, filter_size, filter_size, 1]
+    fn fold_pads(
+        constant_value: f32,
+        reader: BuiltinOperator,
+        filter_size: i32,
+    ) -> Result<BLiteOptimizedGraph<'static, f32>> {
+        let optimizations = BLiteOptimizations {
+            fold_pads: true,
+            ..BLiteOptimizations::none()
+        };
+        let filter_dims = [1, filter_size, filter_size, 1];
+        let tensors = vec![
+            activation(&[1, 4, 4, 1]),
+            constant_i32(&[0, 0, 1, 1, 2, 2, 0, 0], &[4, 2]),
+            activation(&[1, 6, 8, 1]),
+            constant(
+                &vec![1.; (filter_size * filter_size) as usize],
+                &filter_dims,
+            ),
+            activation(&[1, 4, 6, 1]),
+        ];
+        let reader = match reader {
+            BuiltinOperator::CONV_2D => node(reader, &[2, 3, -1], &[4], conv2d()),
+            _ => node(reader, &[2], &[4], relu(0., f32::MAX)),
+        };
+        let nodes = vec![
+            node(
+                BuiltinOperator::PAD,
+                &[0, 1],
+                &[2],
+                PadOptions { constant_value },
+            ),
+            reader,
+        ];
+        optimize(optimizations, nodes, tensors, &[4])
+    }
+
+    #[test]
+    fn test_fold_pads() -> Result<()> {
+        let (nodes, _) = fold_pads(0., BuiltinOperator::CONV_2D, 3)?;
+        let [(conv, registration)] = nodes else {
+            panic!(
+                "expected only the convolution, but got {} nodes",
+                nodes.len()
+            );
+        };
+        assert_eq!(conv.inputs, [0, 3, -1]);
+        let Conv2DOptions {
+            padding_w,
+            padding_h,
+            ..
+        } = registration.builtin_option
+        else {
+            panic!("expected Conv2DOptions");
+        };
+        assert_eq!((padding_w, padding_h), (2, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fold_pads_skips() -> Result<()> {
+        // the pad value is not the zero point
+        let (nodes, _) = fold_pads(1., BuiltinOperator::CONV_2D, 3)?;
+        assert_eq!(nodes.len(), 2);
+        // the reader is not a convolution, and has no filter
+        let (nodes, _) = fold_pads(0., BuiltinOperator::RELU, 3)?;
+        assert_eq!(nodes.len(), 2);
+        // the pointwise convolution is evaluated on the unpadded input
+        let (nodes, _) = fold_pads(0., BuiltinOperator::CONV_2D, 1)?;
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[1].0.inputs, [2, 3, -1]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuse_activations() -> Result<()> {
+        let optimizations = BLiteOptimizations {
+            fuse_activations: true,
+            ..BLiteOptimizations::none()
+        };
+        let tensors = || {
+            vec![
+                activation(&[1, 4]),
+                constant(&[1.; 12], &[3, 4]),
+                activation(&[1, 3]),
+                activation(&[1, 3]),
+                activation(&[1, 3]),
+            ]
+        };
+
+        let nodes = vec![
+            custom(),
+            node(
+                BuiltinOperator::FULLY_CONNECTED,
+                &[0, 1, -1],
+                &[2],
+                fully_connected(),
+            ),
+            node(BuiltinOperator::RELU6, &[2], &[3], relu(0., 6.)),
+        ];
+        let (nodes, _) = optimize(optimizations, nodes, tensors(), &[3])?;
+        assert_eq!(outputs(nodes), [&[][..], &[3]]);
+        let FullyConnectedOptions {
+            fused_activation_min,
+            fused_activation_max,
+            ..
+        } = nodes[1].1.builtin_option
+        else {
+            panic!("expected FullyConnectedOptions");
+        };
+        assert_eq!((fused_activation_min, fused_activation_max), (0., 6.));
+
+        // the output of FULLY_CONNECTED is read by another node too
+        let nodes = vec![
+            node(
+                BuiltinOperator::FULLY_CONNECTED,
+                &[0, 1, -1],
+                &[2],
+                fully_connected(),
+            ),
+            node(BuiltinOperator::RELU6, &[2], &[3], relu(0., 6.)),
+            node(BuiltinOperator::RELU, &[2], &[4], relu(0., f32::MAX)),
+        ];
+        let (nodes, _) = optimize(optimizations, nodes, tensors(), &[3, 4])?;
+        assert_eq!(outputs(nodes), [&[2][..], &[3], &[4]]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_elide_reshapes() -> Result<()> {
+        let optimizations = BLiteOptimizations {
+            elide_reshapes: true,
+            ..BLiteOptimizations::none()
+        };
+        let tensors = || {
+            vec![
+                activation(&[1, 4]),
+                activation(&[1, 4]),
+                activation(&[4]),
+                constant_i32(&[4], &[1]),
+                activation(&[4]),
+                constant(&[1.; 4], &[1, 4]),
+            ]
+        };
+
+        let nodes = vec![
+            custom(),
+            node(BuiltinOperator::RELU, &[0], &[1], relu(0., f32::MAX)),
+            node(BuiltinOperator::RESHAPE, &[1, 3], &[2], ReshapeOptions {}),
+            node(BuiltinOperator::RELU, &[2], &[4], relu(0., f32::MAX)),
+        ];
+        let (nodes, aliases) = optimize(optimizations, nodes, tensors(), &[4])?;
+        assert_eq!(outputs(nodes), [&[][..], &[1], &[4]]);
+        assert_eq!(aliases, [-1, -1, 1, -1, -1, -1]);
+
+        // the constant is not planned, and cannot be shared
+        let nodes = vec![
+            node(BuiltinOperator::RESHAPE, &[5, 3], &[2], ReshapeOptions {}),
+            node(BuiltinOperator::ADD, &[0, 2], &[4], NotInitialize),
+        ];
+        let (nodes, aliases) = optimize(optimizations, nodes, tensors(), &[4])?;
+        assert_eq!(outputs(nodes), [&[2][..], &[4]]);
+        assert_eq!(aliases, [-1; 6]);
+        Ok(())
+    }
+}
@@ -6,6 +6,28 @@ use crate::micro_context::BLiteContext;
 use crate::micro_errors::Result;
 use crate::micro_node::BLiteNode;
 use crate::micro_tensor::BLiteTensor;
+use crate::tflite_schema_generated::tflite::BuiltinOperator;
+
+/// The signature of the eval function of operators
+pub type BLiteEval<'a, T> = fn(
+    context: &BLiteContext,
+    tensors: &'a mut [BLiteTensor<'a, T>],
+    node: &BLiteNode<'a>,
+    builtin_option: BLiteBuiltinOption<T>,
+) -> Result<()>;
+
+/// How the output of an operator may share the buffer of its inputs in the memory plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BLiteInPlace {
+    /// the output has its own buffer
+    NotInPlace,
+    /// each element of the output is written after the same element of the inputs is read,
+    /// so that the output may overwrite an input of the same size which is not read later
+    MayRunInPlace,
+    /// the output has the same bytes as the first input,
+    /// so that they may share the buffer even if the input is read later
+    OutputAliasesInput,
+}
 
 #[derive(Clone, Copy)]
 pub struct BLiteRegistration<'a, T>
@@ -13,33 +35,49 @@ where
     T: ArrayElem<T>,
 {
     pub op_code: i32,
-    pub eval: fn(
-        context: &BLiteContext,
-        tensors: &'a mut [BLiteTensor<'a, T>],
-        node: &BLiteNode<'a>,
-        builtin_option: BLiteBuiltinOption<T>,
-    ) -> Result<()>,
+    // the name of a custom operator, which is looked up instead of the op_code
+    pub custom_code: Option<&'static str>,
+    pub eval: BLiteEval<'a, T>,
     pub builtin_option: BLiteBuiltinOption<'a, T>,
+    pub in_place: BLiteInPlace,
 }
 
 impl<'a, T: ArrayElem<T>> BLiteRegistration<'a, T> {
     pub fn new(
         op_code: i32,
-        eval: fn(
-            _context: &BLiteContext,
-            tensors: &'a mut [BLiteTensor<'a, T>],
-            node: &BLiteNode<'a>,
-            builtin_option: BLiteBuiltinOption<T>,
-        ) -> Result<()>,
+        eval: BLiteEval<'a, T>,
         builtin_option: BLiteBuiltinOption<'a, T>,
     ) -> Self {
         Self {
             op_code,
+            custom_code: None,
             eval,
             builtin_option,
+            in_place: BLiteInPlace::NotInPlace,
         }
     }
 
+    /// Creates the registration of a custom operator,
+    /// which is resolved by `OperatorCode.custom_code` of the model.
+    pub fn new_custom(
+        custom_code: &'static str,
+        eval: BLiteEval<'a, T>,
+        builtin_option: BLiteBuiltinOption<'a, T>,
+    ) -> Self {
+        Self {
+            op_code: BuiltinOperator::CUSTOM.0,
+            custom_code: Some(custom_code),
+            eval,
+            builtin_option,
+            in_place: BLiteInPlace::NotInPlace,
+        }
+    }
+
+    /// Declares how the output may share the buffer of the inputs
+    pub fn with_in_place(self, in_place: BLiteInPlace) -> Self {
+        Self { in_place, ..self }
+    }
+
     pub fn call_eval(
         &self,
         tensors: &'a mut [BLiteTensor<'a, T>],
@@ -56,8 +94,8 @@ impl<'a, T: ArrayElem<T>> Debug for BLiteRegistration<'a, T> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "Registration {{ op_code: {}, eval: {:p}, builtin_option: {:?} }}",
-            self.op_code, self.eval, self.builtin_option
+            "Registration {{ op_code: {}, custom_code: {:?}, eval: {:p}, builtin_option: {:?}, in_place: {:?} }}",
+            self.op_code, self.custom_code, self.eval, self.builtin_option, self.in_place
         )?;
         Ok(())
     }
@@ -3,21 +3,29 @@ use flatbuffers::Vector;
 
 use crate::micro_allocator::ArenaAllocator;
 use crate::micro_errors::Result;
-use core::mem::size_of;
+use core::mem::{size_of, size_of_val};
 use core::{fmt::Debug, mem::align_of};
 
-// because of changing lifetime 'b to 'a
+/// Reinterprets the bytes of a flatbuffer vector as `U`, because of changing lifetime 'b to 'a
+///
+/// # Safety
+/// The bytes must be aligned for `U` and hold valid values of `U`
 pub unsafe fn from_tflite_vector<'b, S, U: Debug>(vector: &Vector<'b, S>) -> &'b [U] {
     let bytes = vector.bytes();
     let data = unsafe {
         core::slice::from_raw_parts(bytes.as_ptr() as *const U, bytes.len() / size_of::<U>())
     };
 
-    return data;
+    data
 }
 
-// This function is used for tflite flatbeffer's vector only
-// because of changing lifetimes 'b to 'a
+/// This function is used for tflite flatbeffer's vector only
+/// because of changing lifetimes 'b to 'a
+///
+/// # Safety
+/// The bytes must be aligned for `U` and hold valid values of `U`.
+/// They are written through the returned slice, so that the buffer must be writable
+/// and must not be read through any other reference while the slice lives
 pub unsafe fn from_tflite_vector_mut<'b, S, U: Debug>(vector: &Vector<'b, S>) -> &'b mut [U] {
     let bytes = vector.bytes();
     let data = unsafe {
@@ -27,9 +35,14 @@ pub unsafe fn from_tflite_vector_mut<'b, S, U: Debug>(vector: &Vector<'b, S>) ->
         )
     };
 
-    return data;
+    data
 }
 
+/// Allocates an uninitialized array of `size` values from the arena
+///
+/// # Safety
+/// The values are not initialized, so that `T` must be valid for any bytes in the arena.
+/// The array must not outlive the arena, whose lifetime is not tied to `'a`
 pub unsafe fn alloc_array_mut<'a, T>(
     allocator: &mut impl ArenaAllocator,
     size: usize,
@@ -39,7 +52,24 @@ pub unsafe fn alloc_array_mut<'a, T>(
     Ok(data)
 }
 
-// Must update arena_start after allocating all tensors
+/// Releases an array allocated by `alloc_array_mut`
+///
+/// # Safety
+/// The array must be the last one allocated to be released,
+/// and must not be used after it is released
+pub unsafe fn dealloc_array_mut<T>(allocator: &mut impl ArenaAllocator, data: &mut [T]) {
+    allocator.dealloc(
+        data.as_mut_ptr() as *mut u8,
+        size_of_val(data),
+        align_of::<T>(),
+    );
+}
+
+/// Allocates an uninitialized array of `size` values at `start_offset` values from the head of the arena
+///
+/// # Safety
+/// Must update arena_start after allocating all tensors.
+/// The same conditions as `alloc_array_mut` apply to the values and the lifetime
 pub unsafe fn alloc_array_from_offset<'a, T>(
     allocator: &mut impl ArenaAllocator,
     start_offset: usize,
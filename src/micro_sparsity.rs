@@ -0,0 +1,558 @@
+use flatbuffers::{ForwardsUOffset, Vector};
+
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_errors::{BLiteError::*, Result};
+use crate::micro_slice::{alloc_array_mut, from_tflite_vector};
+use crate::tflite_schema_generated::tflite::{
+    DimensionMetadata, DimensionType, SparseIndexVector, SparsityParameters,
+};
+
+// the maximum rank of the traversal order including the block dimensions
+const MAX_SPARSE_RANK: usize = 8;
+
+// the traversal order, the block map and the metadata of each dimension
+type SparsityVectors<'a> = (
+    Vector<'a, i32>,
+    Vector<'a, i32>,
+    Vector<'a, ForwardsUOffset<DimensionMetadata<'a>>>,
+);
+
+/*-----------------------------------------------------------------------------*/
+/// Compressed weights whose last dimension is stored in the CSR format of tensorflow lite
+/// and whose leading dimensions are flattened into rows.
+/// The nonzero blocks of the row `r` are `indices[segments[r]..segments[r + 1]]`,
+/// and each block holds `block_size` consecutive values along the last dimension.
+#[derive(Debug, Clone, Copy)]
+pub struct BLiteSparsity<'a> {
+    pub segments: &'a [i32],
+    pub indices: &'a [i32],
+    pub block_size: usize,
+}
+
+impl<'a> BLiteSparsity<'a> {
+    /// Returns the first column of each nonzero block in the `row` and the offset of its values
+    #[inline(always)]
+    pub fn row_blocks(&self, row: usize) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let block_size = self.block_size;
+        let start = self.segments[row] as usize;
+        let end = self.segments[row + 1] as usize;
+        self.indices[start..end]
+            .iter()
+            .enumerate()
+            .map(move |(i, &idx)| (idx as usize * block_size, (start + i) * block_size))
+    }
+
+    /// Scatters the compressed `values` into `dense`, whose rows have `cols` elements
+    pub fn densify<E: Copy + Default>(&self, values: &[E], dense: &mut [E], cols: usize) {
+        dense.fill(Default::default());
+        for row in 0..self.segments.len() - 1 {
+            for (col, offset) in self.row_blocks(row) {
+                let dense_offset = row * cols + col;
+                dense[dense_offset..dense_offset + self.block_size]
+                    .copy_from_slice(&values[offset..offset + self.block_size]);
+            }
+        }
+    }
+
+    /// Returns the compressed form of the weights if the leading dimensions are dense and
+    /// only the last dimension is sparse, which may be blocked along the last dimension.
+    /// The other formats return None and have to be densified.
+    ///
+    /// # Safety
+    /// The widened index vectors are allocated from the arena with `alloc_array_mut`
+    pub unsafe fn from_tflite_sparsity(
+        allocator: &mut impl ArenaAllocator,
+        sparsity: &SparsityParameters<'a>,
+        dims: &[i32],
+    ) -> Result<Option<Self>> {
+        let (traversal_order, block_map, dim_metadata) = Self::parse(sparsity)?;
+        let rank = dims.len();
+        if traversal_order.len() != rank + block_map.len() {
+            return Err(InvalidSparsity);
+        }
+        let is_identity = traversal_order
+            .iter()
+            .enumerate()
+            .all(|(i, d)| i == d as usize);
+        let block_size = match block_map.len() {
+            0 => 1,
+            1 if block_map.get(0) as usize == rank - 1 => dim_metadata.get(rank).dense_size(),
+            _ => return Ok(None),
+        };
+        let is_row_sparse = (0..rank).all(|i| {
+            let is_dense = dim_metadata.get(i).format() == DimensionType::DENSE;
+            is_dense == (i + 1 != rank)
+        });
+        if !is_identity || !is_row_sparse || block_size <= 0 || dims[rank - 1] % block_size != 0 {
+            return Ok(None);
+        }
+
+        let last_dim = dim_metadata.get(rank - 1);
+        let (Some(segments), Some(indices)) = (
+            Self::alloc_index_vector(allocator, last_dim.array_segments_type(), &last_dim, true)?,
+            Self::alloc_index_vector(allocator, last_dim.array_indices_type(), &last_dim, false)?,
+        ) else {
+            return Err(InvalidSparsity);
+        };
+        Ok(Some(Self {
+            segments,
+            indices,
+            block_size: block_size as usize,
+        }))
+    }
+
+    fn parse(sparsity: &SparsityParameters<'a>) -> Result<SparsityVectors<'a>> {
+        let (Some(traversal_order), Some(dim_metadata)) =
+            (sparsity.traversal_order(), sparsity.dim_metadata())
+        else {
+            return Err(InvalidSparsity);
+        };
+        let block_map = sparsity.block_map().unwrap_or_default();
+        if traversal_order.len() > MAX_SPARSE_RANK || traversal_order.len() != dim_metadata.len() {
+            return Err(InvalidSparsity);
+        }
+        Ok((traversal_order, block_map, dim_metadata))
+    }
+
+    // the segments and the indices are stored as int32, uint16 or uint8,
+    // and the latter two are widened into int32 in the arena
+    unsafe fn alloc_index_vector(
+        allocator: &mut impl ArenaAllocator,
+        vector_type: SparseIndexVector,
+        dim: &DimensionMetadata<'a>,
+        is_segments: bool,
+    ) -> Result<Option<&'a [i32]>> {
+        let Some(vector) = IndexVector::new(vector_type, dim, is_segments) else {
+            return Ok(None);
+        };
+        if let IndexVector::I32(values) = vector {
+            return Ok(Some(from_tflite_vector(&values)));
+        }
+        let widened = alloc_array_mut::<i32>(allocator, vector.len())?;
+        for (i, v) in widened.iter_mut().enumerate() {
+            *v = vector.get(i);
+        }
+        Ok(Some(widened))
+    }
+}
+
+/*-----------------------------------------------------------------------------*/
+#[derive(Clone, Copy)]
+enum IndexVector<'a> {
+    I32(Vector<'a, i32>),
+    U16(Vector<'a, u16>),
+    U8(Vector<'a, u8>),
+}
+
+impl<'a> IndexVector<'a> {
+    fn new(
+        vector_type: SparseIndexVector,
+        dim: &DimensionMetadata<'a>,
+        is_segments: bool,
+    ) -> Option<Self> {
+        match (vector_type, is_segments) {
+            (SparseIndexVector::Int32Vector, true) => dim
+                .array_segments_as_int_32_vector()?
+                .values()
+                .map(Self::I32),
+            (SparseIndexVector::Uint16Vector, true) => dim
+                .array_segments_as_uint_16_vector()?
+                .values()
+                .map(Self::U16),
+            (SparseIndexVector::Uint8Vector, true) => dim
+                .array_segments_as_uint_8_vector()?
+                .values()
+                .map(Self::U8),
+            (SparseIndexVector::Int32Vector, false) => dim
+                .array_indices_as_int_32_vector()?
+                .values()
+                .map(Self::I32),
+            (SparseIndexVector::Uint16Vector, false) => dim
+                .array_indices_as_uint_16_vector()?
+                .values()
+                .map(Self::U16),
+            (SparseIndexVector::Uint8Vector, false) => {
+                dim.array_indices_as_uint_8_vector()?.values().map(Self::U8)
+            }
+            _ => None,
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Self::I32(v) => v.len(),
+            Self::U16(v) => v.len(),
+            Self::U8(v) => v.len(),
+        }
+    }
+
+    fn get(&self, i: usize) -> i32 {
+        match self {
+            Self::I32(v) => v.get(i),
+            Self::U16(v) => v.get(i) as i32,
+            Self::U8(v) => v.get(i) as i32,
+        }
+    }
+}
+
+/*-----------------------------------------------------------------------------*/
+/// Converts the sparse `values` of any format into `dense`,
+/// which is the same as FormatConverter::SparseToDense of tensorflow lite
+pub fn densify_tflite_sparsity<E: Copy + Default>(
+    sparsity: &SparsityParameters<'_>,
+    dims: &[i32],
+    values: &[E],
+    dense: &mut [E],
+) -> Result<()> {
+    let (traversal_order, block_map, dim_metadata) = BLiteSparsity::parse(sparsity)?;
+    let rank = dims.len();
+    let levels = traversal_order.len();
+    if levels != rank + block_map.len() || dense.len() != dims.iter().product::<i32>() as usize {
+        return Err(InvalidSparsity);
+    }
+
+    let mut formats = [(DimensionType::DENSE, 0i32, None, None); MAX_SPARSE_RANK];
+    for (level, format) in formats.iter_mut().enumerate().take(levels) {
+        let dim = dim_metadata.get(level);
+        *format = (
+            dim.format(),
+            dim.dense_size(),
+            IndexVector::new(dim.array_segments_type(), &dim, true),
+            IndexVector::new(dim.array_indices_type(), &dim, false),
+        );
+        if format.0 != DimensionType::DENSE && (format.2.is_none() || format.3.is_none()) {
+            return Err(InvalidSparsity);
+        }
+    }
+
+    let mut block_size = [1i32; MAX_SPARSE_RANK];
+    for (i, size) in block_size.iter_mut().enumerate().take(block_map.len()) {
+        *size = dim_metadata.get(rank + i).dense_size();
+    }
+
+    let mut converter = SparseToDense {
+        dims,
+        traversal_order: &traversal_order,
+        block_map: &block_map,
+        block_size: &block_size,
+        formats: &formats[..levels],
+        indices: [0; MAX_SPARSE_RANK],
+        src_idx: 0,
+    };
+    dense.fill(Default::default());
+    converter.populate(values, dense, 0, 0)
+}
+
+type LevelFormat<'a> = (
+    DimensionType,
+    i32,
+    Option<IndexVector<'a>>,
+    Option<IndexVector<'a>>,
+);
+
+struct SparseToDense<'b, 'a> {
+    dims: &'b [i32],
+    traversal_order: &'b Vector<'a, i32>,
+    block_map: &'b Vector<'a, i32>,
+    block_size: &'b [i32],
+    formats: &'b [LevelFormat<'a>],
+    indices: [i32; MAX_SPARSE_RANK],
+    src_idx: usize,
+}
+
+impl<'b, 'a> SparseToDense<'b, 'a> {
+    fn populate<E: Copy>(
+        &mut self,
+        values: &[E],
+        dense: &mut [E],
+        level: usize,
+        prev_idx: usize,
+    ) -> Result<()> {
+        let rank = self.dims.len();
+        if level == self.formats.len() {
+            let mut orig_idx = [0i32; MAX_SPARSE_RANK];
+            for i in 0..rank {
+                orig_idx[self.traversal_order.get(i) as usize] = self.indices[i];
+            }
+            for i in rank..self.formats.len() {
+                let block_idx = self.traversal_order.get(i) as usize - rank;
+                let orig_dim = self.block_map.get(block_idx) as usize;
+                orig_idx[orig_dim] =
+                    orig_idx[orig_dim] * self.block_size[block_idx] + self.indices[i];
+            }
+            let dense_idx = (0..rank).fold(0, |acc, d| acc * self.dims[d] + orig_idx[d]) as usize;
+            let (Some(dense_v), Some(&v)) = (dense.get_mut(dense_idx), values.get(self.src_idx))
+            else {
+                return Err(InvalidSparsity);
+            };
+            *dense_v = v;
+            self.src_idx += 1;
+            return Ok(());
+        }
+
+        match self.formats[level] {
+            (DimensionType::DENSE, dense_size, _, _) => {
+                for i in 0..dense_size {
+                    self.indices[level] = i;
+                    self.populate(
+                        values,
+                        dense,
+                        level + 1,
+                        prev_idx * dense_size as usize + i as usize,
+                    )?;
+                }
+            }
+            (_, _, Some(segments), Some(indices)) => {
+                for i in segments.get(prev_idx)..segments.get(prev_idx + 1) {
+                    self.indices[level] = indices.get(i as usize);
+                    self.populate(values, dense, level + 1, i as usize)?;
+                }
+            }
+            _ => return Err(InvalidSparsity),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{densify_tflite_sparsity, BLiteSparsity};
+    use crate::micro_allocator::BumpArenaAllocator;
+    use crate::micro_errors::BLiteError;
+    use crate::tflite_schema_generated::tflite::{
+        DimensionMetadata, DimensionMetadataArgs, DimensionType, Int32Vector, Int32VectorArgs,
+        SparseIndexVector, SparsityParameters, SparsityParametersArgs, Uint16Vector,
+        Uint16VectorArgs, Uint8Vector, Uint8VectorArgs,
+    };
+    use flatbuffers::{FlatBufferBuilder, UnionWIPOffset, WIPOffset};
+
+    // [[0, 0, 1, 2], [0, 0, 0, 0], [3, 4, 5, 6]]
+    const DENSE: [i32; 12] = [0, 0, 1, 2, 0, 0, 0, 0, 3, 4, 5, 6];
+
+    // the dense dimension of `dense_size`, or the CSR dimension of `segments` and `indices`
+    type Dim<'v> = (i32, Option<(SparseIndexVector, &'v [i32], &'v [i32])>);
+
+    fn index_vector(
+        fbb: &mut FlatBufferBuilder<'_>,
+        index_type: SparseIndexVector,
+        values: &[i32],
+    ) -> WIPOffset<UnionWIPOffset> {
+        match index_type {
+            SparseIndexVector::Uint8Vector => {
+                let values =
+                    fbb.create_vector(&values.iter().map(|&v| v as u8).collect::<Vec<_>>());
+                let values = Some(values);
+                Uint8Vector::create(fbb, &Uint8VectorArgs { values }).as_union_value()
+            }
+            SparseIndexVector::Uint16Vector => {
+                let values =
+                    fbb.create_vector(&values.iter().map(|&v| v as u16).collect::<Vec<_>>());
+                let values = Some(values);
+                Uint16Vector::create(fbb, &Uint16VectorArgs { values }).as_union_value()
+            }
+            _ => {
+                let values = Some(fbb.create_vector(values));
+                Int32Vector::create(fbb, &Int32VectorArgs { values }).as_union_value()
+            }
+        }
+    }
+
+    /// Builds the sparsity parameters whose levels are `dims` in the traversal order
+    fn sparsity(traversal_order: &[i32], block_map: &[i32], dims: &[Dim]) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+        let dims: Vec<_> = dims
+            .iter()
+            .map(|&(dense_size, csr)| {
+                let Some((index_type, segments, indices)) = csr else {
+                    return DimensionMetadata::create(
+                        &mut fbb,
+                        &DimensionMetadataArgs {
+                            format: DimensionType::DENSE,
+                            dense_size,
+                            ..Default::default()
+                        },
+                    );
+                };
+                let segments = index_vector(&mut fbb, index_type, segments);
+                let indices = index_vector(&mut fbb, index_type, indices);
+                DimensionMetadata::create(
+                    &mut fbb,
+                    &DimensionMetadataArgs {
+                        format: DimensionType::SPARSE_CSR,
+                        dense_size,
+                        array_segments_type: index_type,
+                        array_segments: Some(segments),
+                        array_indices_type: index_type,
+                        array_indices: Some(indices),
+                    },
+                )
+            })
+            .collect();
+        let traversal_order = fbb.create_vector(traversal_order);
+        let block_map = fbb.create_vector(block_map);
+        let dim_metadata = fbb.create_vector(&dims);
+        let sparsity = SparsityParameters::create(
+            &mut fbb,
+            &SparsityParametersArgs {
+                traversal_order: Some(traversal_order),
+                block_map: Some(block_map),
+                dim_metadata: Some(dim_metadata),
+            },
+        );
+        fbb.finish_minimal(sparsity);
+        fbb.finished_data().to_vec()
+    }
+
+    fn allocator() -> BumpArenaAllocator {
+        let arena = Box::leak(vec![0u8; 256].into_boxed_slice());
+        unsafe { BumpArenaAllocator::new(arena) }
+    }
+
+    #[test]
+    fn test_blocked_rows_are_kept_compressed() {
+        // the rows are dense and the columns are CSR of the blocks of 2 values
+        for index_type in [
+            SparseIndexVector::Int32Vector,
+            SparseIndexVector::Uint16Vector,
+            SparseIndexVector::Uint8Vector,
+        ] {
+            let buffer = sparsity(
+                &[0, 1, 2],
+                &[1],
+                &[
+                    (3, None),
+                    (0, Some((index_type, &[0, 1, 1, 3], &[1, 0, 1]))),
+                    (2, None),
+                ],
+            );
+            let params = flatbuffers::root::<SparsityParameters>(&buffer).unwrap();
+            let values = [1, 2, 3, 4, 5, 6];
+
+            let mut allocator = allocator();
+            let compressed =
+                unsafe { BLiteSparsity::from_tflite_sparsity(&mut allocator, &params, &[3, 4]) }
+                    .unwrap()
+                    .unwrap();
+            assert_eq!([0, 1, 1, 3], compressed.segments);
+            assert_eq!([1, 0, 1], compressed.indices);
+            assert_eq!(2, compressed.block_size);
+            assert_eq!(vec![(2, 0)], compressed.row_blocks(0).collect::<Vec<_>>());
+            assert_eq!(0, compressed.row_blocks(1).count());
+            assert_eq!(
+                vec![(0, 2), (2, 4)],
+                compressed.row_blocks(2).collect::<Vec<_>>()
+            );
+
+            let mut dense = [-1; 12];
+            compressed.densify(&values, &mut dense, 4);
+            assert_eq!(DENSE, dense);
+
+            let mut dense = [-1; 12];
+            densify_tflite_sparsity(&params, &[3, 4], &values, &mut dense).unwrap();
+            assert_eq!(DENSE, dense);
+        }
+    }
+
+    #[test]
+    fn test_csr_rows_are_densified() {
+        // both the rows and the columns are CSR, which the sparse kernels cannot handle
+        let buffer = sparsity(
+            &[0, 1],
+            &[],
+            &[
+                (0, Some((SparseIndexVector::Int32Vector, &[0, 2], &[0, 2]))),
+                (
+                    0,
+                    Some((
+                        SparseIndexVector::Uint8Vector,
+                        &[0, 2, 6],
+                        &[2, 3, 0, 1, 2, 3],
+                    )),
+                ),
+            ],
+        );
+        let params = flatbuffers::root::<SparsityParameters>(&buffer).unwrap();
+
+        let mut allocator = allocator();
+        let compressed =
+            unsafe { BLiteSparsity::from_tflite_sparsity(&mut allocator, &params, &[3, 4]) }
+                .unwrap();
+        assert!(compressed.is_none());
+
+        let mut dense = [-1; 12];
+        densify_tflite_sparsity(&params, &[3, 4], &[1, 2, 3, 4, 5, 6], &mut dense).unwrap();
+        assert_eq!(DENSE, dense);
+    }
+
+    #[test]
+    fn test_column_major_traversal_is_densified() {
+        // the columns are traversed first and the rows are CSR in each column
+        let buffer = sparsity(
+            &[1, 0],
+            &[],
+            &[
+                (4, None),
+                (
+                    0,
+                    Some((
+                        SparseIndexVector::Int32Vector,
+                        &[0, 1, 2, 4, 6],
+                        &[2, 2, 0, 2, 0, 2],
+                    )),
+                ),
+            ],
+        );
+        let params = flatbuffers::root::<SparsityParameters>(&buffer).unwrap();
+
+        let mut allocator = allocator();
+        let compressed =
+            unsafe { BLiteSparsity::from_tflite_sparsity(&mut allocator, &params, &[3, 4]) }
+                .unwrap();
+        assert!(compressed.is_none());
+
+        let mut dense = [-1; 12];
+        densify_tflite_sparsity(&params, &[3, 4], &[3, 4, 1, 5, 2, 6], &mut dense).unwrap();
+        assert_eq!(DENSE, dense);
+    }
+
+    #[test]
+    fn test_densify_rejects_invalid_sparsity() {
+        let buffer = sparsity(
+            &[0, 1],
+            &[],
+            &[
+                (3, None),
+                (
+                    0,
+                    Some((
+                        SparseIndexVector::Int32Vector,
+                        &[0, 2, 2, 6],
+                        &[2, 3, 0, 1, 2, 3],
+                    )),
+                ),
+            ],
+        );
+        let params = flatbuffers::root::<SparsityParameters>(&buffer).unwrap();
+        let mut dense = [0; 12];
+
+        // fewer values than the nonzero positions
+        let result = densify_tflite_sparsity(&params, &[3, 4], &[1, 2, 3], &mut dense);
+        assert!(
+            matches!(result, Err(BLiteError::InvalidSparsity)),
+            "{result:?}"
+        );
+        // the rank does not match the traversal order
+        let result = densify_tflite_sparsity(&params, &[12], &[1, 2, 3, 4, 5, 6], &mut dense);
+        assert!(
+            matches!(result, Err(BLiteError::InvalidSparsity)),
+            "{result:?}"
+        );
+        // the dense buffer does not match the shape
+        let result =
+            densify_tflite_sparsity(&params, &[3, 4], &[1, 2, 3, 4, 5, 6], &mut dense[..8]);
+        assert!(
+            matches!(result, Err(BLiteError::InvalidSparsity)),
+            "{result:?}"
+        );
+    }
+}
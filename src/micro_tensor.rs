@@ -1,4 +1,5 @@
-use crate::micro_array::{ArrayElem, BLiteArray};
+use crate::micro_allocator::ArenaAllocator;
+use crate::micro_array::{ArrayElem, BLiteArray, BLiteQuantizationParams};
 use crate::micro_errors::{BLiteError, Result};
 use core::cell::RefCell;
 
@@ -12,6 +13,13 @@ where
 {
     BTensor(BLiteInnerTensor<'a, T>),
     I32Tensor(BLiteInnerTensor<'a, i32>),
+    // int8 weights of the int16x8 quantized models
+    I8Tensor(BLiteInnerTensor<'a, i8>),
+    // int64 biases of the int16x8 quantized models
+    I64Tensor(BLiteInnerTensor<'a, i64>),
+    // packed int4 weights, whose data holds two values per byte (the low nibble first)
+    // and whose dims are the shape of the unpacked values
+    I4Tensor(BLiteInnerTensor<'a, u8>),
 }
 
 impl<'a, T> BLiteTensor<'a, T>
@@ -21,14 +29,80 @@ where
     pub fn _t(&self) -> Result<&BLiteInnerTensor<'a, T>> {
         match self {
             BLiteTensor::BTensor(e) => Ok(e),
-            BLiteTensor::I32Tensor(_) => Err(BLiteError::NotBTensor),
+            _ => Err(BLiteError::NotBTensor),
         }
     }
 
     pub fn _i32(&self) -> Result<&BLiteInnerTensor<'a, i32>> {
         match self {
-            BLiteTensor::BTensor(_) => Err(BLiteError::NotI32Tensor),
             BLiteTensor::I32Tensor(e) => Ok(e),
+            _ => Err(BLiteError::NotI32Tensor),
+        }
+    }
+
+    pub fn _i8(&self) -> Result<&BLiteInnerTensor<'a, i8>> {
+        match self {
+            BLiteTensor::I8Tensor(e) => Ok(e),
+            _ => Err(BLiteError::NotI8Tensor),
+        }
+    }
+
+    pub fn _i64(&self) -> Result<&BLiteInnerTensor<'a, i64>> {
+        match self {
+            BLiteTensor::I64Tensor(e) => Ok(e),
+            _ => Err(BLiteError::NotI64Tensor),
+        }
+    }
+
+    pub fn _i4(&self) -> Result<&BLiteInnerTensor<'a, u8>> {
+        match self {
+            BLiteTensor::I4Tensor(e) => Ok(e),
+            _ => Err(BLiteError::NotI4Tensor),
+        }
+    }
+
+    pub fn dims(&self) -> &'a [i32] {
+        match self {
+            BLiteTensor::BTensor(x) => x.borrow().dims,
+            BLiteTensor::I32Tensor(x) => x.borrow().dims,
+            BLiteTensor::I8Tensor(x) => x.borrow().dims,
+            BLiteTensor::I64Tensor(x) => x.borrow().dims,
+            BLiteTensor::I4Tensor(x) => x.borrow().dims,
+        }
+    }
+
+    /// Whether the weights are still compressed
+    pub fn is_sparse(&self) -> bool {
+        match self {
+            BLiteTensor::BTensor(x) => x.borrow().sparsity.is_some(),
+            BLiteTensor::I32Tensor(x) => x.borrow().sparsity.is_some(),
+            BLiteTensor::I8Tensor(x) => x.borrow().sparsity.is_some(),
+            BLiteTensor::I64Tensor(x) => x.borrow().sparsity.is_some(),
+            BLiteTensor::I4Tensor(x) => x.borrow().sparsity.is_some(),
+        }
+    }
+
+    /// Expands the compressed weights which no sparse kernel has taken
+    ///
+    /// # Safety
+    /// The dense copy is allocated from the arena with `alloc_array_mut`
+    pub unsafe fn densify(&self, allocator: &mut impl ArenaAllocator) -> Result<()> {
+        match self {
+            BLiteTensor::BTensor(x) => x.borrow_mut().densify(allocator),
+            BLiteTensor::I32Tensor(x) => x.borrow_mut().densify(allocator),
+            BLiteTensor::I8Tensor(x) => x.borrow_mut().densify(allocator),
+            BLiteTensor::I64Tensor(x) => x.borrow_mut().densify(allocator),
+            BLiteTensor::I4Tensor(x) => x.borrow_mut().densify(allocator),
+        }
+    }
+
+    pub fn quant_params(&self) -> Option<BLiteQuantizationParams<'a>> {
+        match self {
+            BLiteTensor::BTensor(x) => x.borrow().quant_params,
+            BLiteTensor::I32Tensor(x) => x.borrow().quant_params,
+            BLiteTensor::I8Tensor(x) => x.borrow().quant_params,
+            BLiteTensor::I64Tensor(x) => x.borrow().quant_params,
+            BLiteTensor::I4Tensor(x) => x.borrow().quant_params,
         }
     }
 
@@ -37,6 +111,25 @@ where
         match self {
             BLiteTensor::BTensor(x) => x.borrow().len(),
             BLiteTensor::I32Tensor(x) => x.borrow().len(),
+            BLiteTensor::I8Tensor(x) => x.borrow().len(),
+            BLiteTensor::I64Tensor(x) => x.borrow().len(),
+            BLiteTensor::I4Tensor(x) => x.borrow().len(),
+        }
+    }
+
+    /// Whether the memory planner gives the tensors the same buffer,
+    /// which must be checked before borrowing their data as an input and an output
+    pub fn shares_buffer(&self, other: &Self) -> bool {
+        self.len() != 0 && self.data_ptr() == other.data_ptr()
+    }
+
+    fn data_ptr(&self) -> *const u8 {
+        match self {
+            BLiteTensor::BTensor(x) => x.borrow().data.as_ptr().cast(),
+            BLiteTensor::I32Tensor(x) => x.borrow().data.as_ptr().cast(),
+            BLiteTensor::I8Tensor(x) => x.borrow().data.as_ptr().cast(),
+            BLiteTensor::I64Tensor(x) => x.borrow().data.as_ptr().cast(),
+            BLiteTensor::I4Tensor(x) => x.borrow().data.as_ptr().cast(),
         }
     }
 
@@ -44,6 +137,9 @@ where
         match self {
             BLiteTensor::BTensor(x) => x.borrow().size(),
             BLiteTensor::I32Tensor(x) => x.borrow().size(),
+            BLiteTensor::I8Tensor(x) => x.borrow().size(),
+            BLiteTensor::I64Tensor(x) => x.borrow().size(),
+            BLiteTensor::I4Tensor(x) => x.borrow().size(),
         }
     }
 }
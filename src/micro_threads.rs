@@ -0,0 +1,323 @@
+use crate::micro_context::{ParallelElem, ParallelKernel};
+use crate::micro_errors::{BLiteError, Result};
+use core::any::Any;
+use core::cell::Cell;
+use core::fmt::Debug;
+use core::ops::Range;
+use core::slice::from_raw_parts_mut;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+
+/*-----------------------------------------------------------------------------*/
+/// Pool of the worker threads, which evaluate the heavy kernels in parallel.
+/// The pool is shared by the interpreters with `BLiteInterpreter::set_thread_pool`,
+/// and the thread calling `invoke` also evaluates the tasks as one of the workers.
+pub struct BLiteThreadPool {
+    num_threads: usize,
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+    // the tasks of only one kernel are queued at once
+    dispatch: Mutex<()>,
+    // the scratch of each worker, which is reused by the following kernels
+    scratch: Vec<Mutex<Option<Box<dyn Any + Send>>>>,
+}
+
+// the job borrows the stack of the thread calling `run`,
+// which waits for all the tasks of the job before returning.
+// It is called with the task and the worker evaluating it, where the calling thread is the worker 0.
+type Job = *const (dyn Fn(usize, usize) + Sync + 'static);
+
+// the output split by `parallel_for`, whose tasks take the disjoint chunks of it
+struct SharedOutput<E>(*mut E);
+
+unsafe impl<E: Send> Sync for SharedOutput<E> {}
+
+impl<E> SharedOutput<E> {
+    /// # Safety
+    /// The chunks taken by the tasks must not overlap.
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn chunk(&self, range: Range<usize>) -> &mut [E] {
+        from_raw_parts_mut(self.0.add(range.start), range.len())
+    }
+}
+
+struct State {
+    job: Option<Job>,
+    next_task: usize,
+    num_tasks: usize,
+    pending: usize,
+    panicked: Option<Box<dyn std::any::Any + Send>>,
+    shutdown: bool,
+}
+
+// the job is only dereferenced while `run` is waiting for it
+unsafe impl Send for State {}
+
+struct Shared {
+    state: Mutex<State>,
+    queued: Condvar,
+    finished: Condvar,
+}
+
+impl BLiteThreadPool {
+    /// Creates the pool of `num_threads` threads including the thread calling `invoke`,
+    /// so that `num_threads - 1` workers are spawned
+    pub fn new(num_threads: usize) -> Self {
+        let num_threads = num_threads.max(1);
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State {
+                job: None,
+                next_task: 0,
+                num_tasks: 0,
+                pending: 0,
+                panicked: None,
+                shutdown: false,
+            }),
+            queued: Condvar::new(),
+            finished: Condvar::new(),
+        });
+        let workers = (1..num_threads)
+            .map(|worker| {
+                let shared = shared.clone();
+                std::thread::spawn(move || shared.work(worker))
+            })
+            .collect();
+        Self {
+            num_threads,
+            shared,
+            workers,
+            dispatch: Mutex::new(()),
+            scratch: (0..num_threads).map(|_| Mutex::new(None)).collect(),
+        }
+    }
+
+    pub fn num_threads(&self) -> usize {
+        self.num_threads
+    }
+
+    /// Evaluates `job(task)` for each task in `0..num_tasks` on the workers and the calling thread,
+    /// and returns after all of them are finished
+    pub fn run(&self, num_tasks: usize, job: &(dyn Fn(usize) + Sync)) {
+        self.run_on_workers(num_tasks, &|task, _| job(task));
+    }
+
+    fn run_on_workers(&self, num_tasks: usize, job: &(dyn Fn(usize, usize) + Sync)) {
+        if num_tasks == 0 {
+            return;
+        }
+        let _dispatch = self.dispatch.lock().unwrap_or_else(|e| e.into_inner());
+        // the lifetime of the job is erased, which outlives the tasks since this waits for them
+        let job: Job = unsafe { core::mem::transmute(job as *const (dyn Fn(usize, usize) + Sync)) };
+        {
+            let mut state = self.shared.lock();
+            state.job = Some(job);
+            state.next_task = 0;
+            state.num_tasks = num_tasks;
+            state.pending = num_tasks;
+            state.panicked = None;
+        }
+        self.shared.queued.notify_all();
+
+        self.shared.work(0);
+
+        let mut state = self.shared.lock();
+        while state.pending > 0 {
+            state = self
+                .shared
+                .finished
+                .wait(state)
+                .unwrap_or_else(|e| e.into_inner());
+        }
+        state.job = None;
+        if let Some(payload) = state.panicked.take() {
+            drop(state);
+            resume_unwind(payload);
+        }
+    }
+
+    /// Splits `output` into the contiguous chunks of the units of `unit_len` elements,
+    /// and evaluates `kernel(units, chunk, scratch)` for each chunk in parallel.
+    /// Each worker has its own scratch of `scratch_len` elements, which the pool keeps for the next kernels,
+    /// and the first error of the tasks is returned.
+    pub fn parallel_for<E: ParallelElem>(
+        &self,
+        output: &mut [E],
+        unit_len: usize,
+        scratch_len: usize,
+        kernel: &dyn ParallelKernel<E>,
+    ) -> Result<()> {
+        let unit_len = unit_len.max(1);
+        let units = output.len() / unit_len;
+        let units_per_task = units.div_ceil(self.num_threads.min(units).max(1)).max(1);
+        let chunk_len = units_per_task * unit_len;
+        let output_len = output.len();
+        let output = SharedOutput(output.as_mut_ptr());
+        let error: Mutex<Option<BLiteError>> = Mutex::new(None);
+        self.run_on_workers(output_len.div_ceil(chunk_len), &|task, worker| {
+            let start = task * chunk_len;
+            // each task is run once, so that its chunk is not aliased
+            let chunk = unsafe { output.chunk(start..output_len.min(start + chunk_len)) };
+            let first_unit = task * units_per_task;
+            let units = first_unit..first_unit + chunk.len() / unit_len;
+
+            let mut scratch = self.scratch[worker]
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            let scratch = Self::worker_scratch::<E>(&mut scratch, scratch_len);
+            let scratch = Cell::from_mut(scratch).as_slice_of_cells();
+            if let Err(e) = kernel(units, chunk, scratch) {
+                error.lock().unwrap().get_or_insert(e);
+            }
+        });
+        match error.into_inner().unwrap() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    // the scratch is allocated again only when the kernel needs a longer one or another element type
+    fn worker_scratch<E: ParallelElem>(
+        scratch: &mut Option<Box<dyn Any + Send>>,
+        len: usize,
+    ) -> &mut [E] {
+        if len == 0 {
+            return &mut [];
+        }
+        let reusable = scratch
+            .as_ref()
+            .and_then(|scratch| scratch.downcast_ref::<Vec<E>>())
+            .is_some_and(|scratch| scratch.len() >= len);
+        if !reusable {
+            *scratch = Some(Box::new(vec![E::default(); len]));
+        }
+        let scratch = scratch.as_mut().unwrap().downcast_mut::<Vec<E>>().unwrap();
+        &mut scratch[..len]
+    }
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // the workers wait for the next job, and the thread calling `run` (the worker 0) returns when no task is left
+    fn work(&self, worker: usize) {
+        let mut state = self.lock();
+        loop {
+            if state.shutdown {
+                return;
+            }
+            let Some(job) = state.job.filter(|_| state.next_task < state.num_tasks) else {
+                if worker == 0 {
+                    return;
+                }
+                state = self.queued.wait(state).unwrap_or_else(|e| e.into_inner());
+                continue;
+            };
+            let task = state.next_task;
+            state.next_task += 1;
+            drop(state);
+
+            let result = catch_unwind(AssertUnwindSafe(|| unsafe { (*job)(task, worker) }));
+
+            state = self.lock();
+            if let Err(payload) = result {
+                state.panicked.get_or_insert(payload);
+            }
+            state.pending -= 1;
+            if state.pending == 0 {
+                self.finished.notify_all();
+            }
+        }
+    }
+}
+
+impl Drop for BLiteThreadPool {
+    fn drop(&mut self) {
+        self.shared.lock().shutdown = true;
+        self.shared.queued.notify_all();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Debug for BLiteThreadPool {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "BLiteThreadPool {{ num_threads: {} }}", self.num_threads)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BLiteThreadPool;
+    use crate::micro_errors::BLiteError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_parallel_for_covers_all_units() {
+        let pool = BLiteThreadPool::new(4);
+        for len in [0usize, 1, 3, 4, 5, 17, 64] {
+            let mut output = vec![0usize; len * 3];
+            pool.parallel_for(&mut output, 3, 2, &|units, chunk, scratch| {
+                assert_eq!(2, scratch.len());
+                assert_eq!(units.len() * 3, chunk.len());
+                for (unit, values) in units.zip(chunk.chunks_mut(3)) {
+                    values.fill(unit + 1);
+                }
+                Ok(())
+            })
+            .unwrap();
+            let expected: Vec<usize> = (0..len * 3).map(|i| i / 3 + 1).collect();
+            assert_eq!(expected, output);
+        }
+
+        let calls = AtomicUsize::new(0);
+        pool.run(10, &|_| {
+            calls.fetch_add(1, Ordering::Relaxed);
+        });
+        assert_eq!(10, calls.load(Ordering::Relaxed));
+
+        let mut output = [0i8; 8];
+        let result = pool.parallel_for(&mut output, 1, 0, &|units, _, _| {
+            if units.contains(&5) {
+                return Err(BLiteError::NotMatchSize);
+            }
+            Ok(())
+        });
+        assert!(matches!(result, Err(BLiteError::NotMatchSize)));
+    }
+
+    #[test]
+    fn test_parallel_for_reuses_worker_scratch() {
+        let pool = BLiteThreadPool::new(3);
+        let scratch_ptrs = |output: &mut [i32], scratch_len| {
+            let ptrs = Mutex::new(Vec::new());
+            pool.parallel_for(output, 1, scratch_len, &|_, _, scratch| {
+                assert_eq!(scratch_len, scratch.len());
+                ptrs.lock().unwrap().push(scratch.as_ptr() as usize);
+                Ok(())
+            })
+            .unwrap();
+            ptrs.into_inner().unwrap()
+        };
+        let first = scratch_ptrs(&mut [0; 9], 16);
+        assert!(scratch_ptrs(&mut [0; 9], 8)
+            .iter()
+            .all(|ptr| first.contains(ptr)));
+
+        // the scratch of another element type is allocated again
+        let mut output = [0.0f32; 9];
+        pool.parallel_for(&mut output, 1, 4, &|units, chunk, scratch| {
+            assert_eq!(4, scratch.len());
+            for (unit, v) in units.zip(chunk.iter_mut()) {
+                *v = unit as f32;
+            }
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], output);
+    }
+}
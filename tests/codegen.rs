@@ -0,0 +1,180 @@
+use berrylite::kernel::micro_operator::f32::{
+    conv2d::OpConv2D, fully_connected::OpFullyConnected, max_pool2d::OpMaxPool2D,
+    reshape::OpReshape,
+};
+use berrylite::kernel::micro_operator::i8::{
+    avg_pool2d_i8::OpAvgPool2DInt8, conv2d_i8::OpConv2DInt8,
+    depthwise_conv2d_i8::OpDepthWiseConv2DInt8, fully_connected_i8::OpFullyConnectedInt8,
+    max_pool2d_i8::OpMaxPool2DInt8, reshape_i8::OpReshapeInt8,
+};
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite;
+use float_eq::assert_float_eq;
+
+// the modules generated by `berrylite-codegen`
+mod simple_conv_sin_filter_32_max_pool_6x6_int8 {
+    include!("codegen/simple_conv_sin_filter_32_max_pool_6x6_int8.rs");
+}
+mod simple_depthwise_conv_avg_pool_sin_int8 {
+    include!("codegen/simple_depthwise_conv_avg_pool_sin_int8.rs");
+}
+mod simple_conv_sin_filter_32_max_pool_6x6 {
+    include!("codegen/simple_conv_sin_filter_32_max_pool_6x6.rs");
+}
+mod hello_world_float {
+    include!("codegen/hello_world_float.rs");
+}
+
+const CONV_INT8: &[u8; 8392] =
+    include_bytes!("../resources/models/simple_conv_sin_filter_32_max_pool_6x6_int8.tflite");
+const DEPTHWISE_CONV_INT8: &[u8; 8856] =
+    include_bytes!("../resources/models/simple_depthwise_conv_avg_pool_sin_int8.tflite");
+const CONV_FLOAT: &[u8; 70460] =
+    include_bytes!("../resources/models/simple_conv_sin_filter_32_max_pool_6x6.tflite");
+const HELLO_WORLD_FLOAT: &[u8; 3164] =
+    include_bytes!("../resources/models/hello_world_float.tflite");
+
+const INT8_ARENA_SIZE: usize = 16 * 1024;
+static mut INT8_ARENA: [u8; INT8_ARENA_SIZE] = [0; INT8_ARENA_SIZE];
+const FLOAT_ARENA_SIZE: usize = 32 * 1024;
+static mut FLOAT_ARENA: [u8; FLOAT_ARENA_SIZE] = [0; FLOAT_ARENA_SIZE];
+
+fn predict_int8(buffer: &[u8], input: &[i8]) -> Result<Vec<i8>> {
+    let model = tflite::root_as_model(buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut INT8_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<6, i8, _>::new();
+    op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8())?;
+    op_resolver.add_op(OpReshapeInt8::reshape_int8())?;
+    op_resolver.add_op(OpConv2DInt8::conv2d_int8())?;
+    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8())?;
+    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8())?;
+    op_resolver.add_op(OpMaxPool2DInt8::max_pool2d_int8())?;
+
+    let mut interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    Ok(interpreter.output.data.to_vec())
+}
+
+fn predict_float(buffer: &[u8], input: &[f32]) -> Result<Vec<f32>> {
+    let model = tflite::root_as_model(buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut FLOAT_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<4, f32, _>::new();
+    op_resolver.add_op(OpFullyConnected::fully_connected())?;
+    op_resolver.add_op(OpReshape::reshape())?;
+    op_resolver.add_op(OpConv2D::conv2d())?;
+    op_resolver.add_op(OpMaxPool2D::max_pool2d())?;
+
+    let mut interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    Ok(interpreter.output.data.to_vec())
+}
+
+// a 6x6 image of sin(x) like the training data of the simple conv models
+fn sin_image(x: f32) -> Vec<f32> {
+    (0..36).map(|i| (x + i as f32 * 0.1).sin()).collect()
+}
+
+fn quantize(input: &[f32], scale: f32, zero_point: i32) -> Vec<i8> {
+    input
+        .iter()
+        .map(|&x| ((x / scale).round() as i32 + zero_point).clamp(-128, 127) as i8)
+        .collect()
+}
+
+#[test]
+fn test_codegen_int8_matches_interpreter() {
+    use simple_conv_sin_filter_32_max_pool_6x6_int8 as conv;
+    use simple_depthwise_conv_avg_pool_sin_int8 as depthwise_conv;
+
+    let mut conv_arena = conv::Arena::new();
+    let mut depthwise_conv_arena = depthwise_conv::Arena::new();
+    for x in [0., 0.5, 1., 2., 3.] {
+        let input = quantize(&sin_image(x), conv::INPUT_SCALE, conv::INPUT_ZERO_POINT);
+        conv_arena.input().copy_from_slice(&input);
+        conv::invoke(&mut conv_arena).unwrap();
+        let expected = predict_int8(CONV_INT8, &input).unwrap();
+        assert_eq!(expected, conv_arena.output(), "x: {x}");
+
+        let input = quantize(
+            &sin_image(x),
+            depthwise_conv::INPUT_SCALE,
+            depthwise_conv::INPUT_ZERO_POINT,
+        );
+        depthwise_conv_arena.input().copy_from_slice(&input);
+        depthwise_conv::invoke(&mut depthwise_conv_arena).unwrap();
+        let expected = predict_int8(DEPTHWISE_CONV_INT8, &input).unwrap();
+        assert_eq!(expected, depthwise_conv_arena.output(), "x: {x}");
+    }
+}
+
+// the generated module calls the scalar float kernels, which round differently from the simd ones
+#[test]
+fn test_codegen_float_matches_interpreter() {
+    use hello_world_float as hello_world;
+    use simple_conv_sin_filter_32_max_pool_6x6 as conv;
+
+    let mut conv_arena = conv::Arena::new();
+    let mut hello_world_arena = hello_world::Arena::new();
+    for x in [0., 0.5, 1., 2., 3.] {
+        let input = sin_image(x);
+        conv_arena.input().copy_from_slice(&input);
+        conv::invoke(&mut conv_arena).unwrap();
+        let expected = predict_float(CONV_FLOAT, &input).unwrap();
+        assert_float_eq!(
+            expected,
+            conv_arena.output().to_vec(),
+            abs_all <= 1e-5,
+            "x: {x}"
+        );
+
+        hello_world_arena.input()[0] = x;
+        hello_world::invoke(&mut hello_world_arena).unwrap();
+        let expected = predict_float(HELLO_WORLD_FLOAT, &[x]).unwrap();
+        assert_float_eq!(
+            expected,
+            hello_world_arena.output().to_vec(),
+            abs_all <= 1e-5,
+            "x: {x}"
+        );
+    }
+}
+
+#[cfg(feature = "codegen")]
+#[test]
+fn test_codegen_is_up_to_date() {
+    use berrylite::codegen::generate_module;
+
+    let golden_files = [
+        (
+            "simple_conv_sin_filter_32_max_pool_6x6_int8",
+            &CONV_INT8[..],
+            include_str!("codegen/simple_conv_sin_filter_32_max_pool_6x6_int8.rs"),
+        ),
+        (
+            "simple_depthwise_conv_avg_pool_sin_int8",
+            &DEPTHWISE_CONV_INT8[..],
+            include_str!("codegen/simple_depthwise_conv_avg_pool_sin_int8.rs"),
+        ),
+        (
+            "simple_conv_sin_filter_32_max_pool_6x6",
+            &CONV_FLOAT[..],
+            include_str!("codegen/simple_conv_sin_filter_32_max_pool_6x6.rs"),
+        ),
+        (
+            "hello_world_float",
+            &HELLO_WORLD_FLOAT[..],
+            include_str!("codegen/hello_world_float.rs"),
+        ),
+    ];
+    for (name, buffer, golden) in golden_files {
+        let module = generate_module(buffer, &format!("{name}.tflite")).unwrap();
+        assert!(module == golden, "regenerate tests/codegen/{name}.rs");
+    }
+}
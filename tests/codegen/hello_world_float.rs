@@ -0,0 +1,137 @@
+// @generated by berrylite-codegen from hello_world_float.tflite, do not edit
+
+use berrylite::kernel::micro_operator::f32::fully_connected::OpFullyConnected;
+use berrylite::micro_errors::Result;
+
+pub const ARENA_SIZE: usize = 136;
+pub const INPUT_OFFSET: usize = 128;
+pub const INPUT_LEN: usize = 1;
+pub const OUTPUT_OFFSET: usize = 132;
+pub const OUTPUT_LEN: usize = 1;
+
+/// The memory of the activations, which is laid out by the greedy memory planner
+#[repr(C, align(16))]
+pub struct Arena(pub [u8; ARENA_SIZE]);
+
+impl Arena {
+    pub const fn new() -> Self {
+        Self([0; ARENA_SIZE])
+    }
+
+    pub fn input(&mut self) -> &mut [f32] {
+        unsafe { slice_mut(self.0.as_mut_ptr(), INPUT_OFFSET, INPUT_LEN) }
+    }
+
+    pub fn output(&self) -> &[f32] {
+        unsafe { slice(self.0.as_ptr() as *mut u8, OUTPUT_OFFSET, OUTPUT_LEN) }
+    }
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the `len` elements of `E` at the byte `offset` of the arena.
+///
+/// # Safety
+///
+/// The elements must be in the arena and aligned for `E`, as the offsets of the memory plan are.
+/// The slice must not overlap a slice returned by `slice_mut` while both of them are alive,
+/// which holds for the tensors of an operator, as the memory plan keeps its inputs and outputs apart.
+unsafe fn slice<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a [E] {
+    core::slice::from_raw_parts(arena.add(offset) as *const E, len)
+}
+
+/// Returns the `len` mutable elements of `E` at the byte `offset` of the arena.
+///
+/// # Safety
+///
+/// As `slice`, and the slice must not overlap any other slice of the arena while it is alive.
+unsafe fn slice_mut<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a mut [E] {
+    core::slice::from_raw_parts_mut(arena.add(offset) as *mut E, len)
+}
+
+static TENSOR_3: [f32; 16] = [
+    0.0, 0.0, 0.3786514, -0.7221675, 0.09094737, 0.85765374, 0.0, 0.0, 0.0, 1.1846195, -0.20879397, 0.0, 0.0, -0.011825153, 0.0, 0.0,
+];
+
+static TENSOR_4: [f32; 16] = [
+    -0.12180936, -0.3929499, 0.19624813, 0.69562256, 0.14335781, -0.064395666, -0.18021363, -0.054397643, -0.46190804, 0.40556964, 0.2796269, -0.51414484, -0.42096663, 0.6256063, -0.37430817, -0.33132315,
+];
+
+static TENSOR_1: [f32; 16] = [
+    0.0, 0.50274307, 0.40767395, -0.25548035, 0.0, 0.17490886, 0.0, -0.2416463, 0.070216745, -0.43093863, 0.59722185, 0.0, 0.22026278, 0.5447701, -0.19466281, -0.07130638,
+];
+
+static TENSOR_5: [f32; 256] = [
+    0.0027225912, 0.18983474, 0.4133679, -0.18240014, 0.20078048, -0.19718045, -0.061389178, -0.12958911, -0.124843985, -0.42960903, -0.34902713, 0.3468422, 0.36845788, 0.1826956, 0.238758, -0.23239864,
+    -0.02578047, 0.19623694, 0.033692636, -0.6074578, 0.07136325, 0.40108338, -0.3989518, -0.124836236, -0.37892875, 0.26399913, -0.36989167, 0.34108874, -0.26812106, 0.16135937, -0.13421604, 0.36499324,
+    0.14257464, -0.18008947, 0.35641763, -0.57783246, -0.39382735, 0.8056795, -0.2635701, -0.01627323, 0.30258426, 0.456614, 0.21970704, -0.21923679, 0.15798768, -0.5030085, 0.3337007, -0.14506403,
+    -0.20739867, -0.3588965, 0.17972116, 0.45933795, 0.20270543, -0.397044, 0.37328306, -0.30636978, 0.31757972, -0.18506755, 0.2977259, -0.2707941, -0.22227117, -0.17527382, -0.23541269, -0.0353972,
+    0.07093546, -0.28561807, -0.119500786, 0.37393418, -0.032085925, -0.4295157, 0.37865922, 0.06391224, 0.104233295, -0.00770694, 0.14500055, 0.03934163, 0.033643812, -0.34992033, -0.13348708, -0.38161552,
+    0.0033965409, 0.11320624, 0.020028206, 0.115726724, -0.005094919, 0.4231835, -0.0019834042, 0.27106974, -0.073908806, -0.18350361, -0.09491399, -0.060689956, 0.23715535, 0.2811556, -0.043907553, 0.1475378,
+    -0.3940481, -0.2901196, -0.022123963, 0.25533912, -0.35065156, -0.4268346, 0.29267815, 0.049579263, 0.25725535, -0.36734736, 0.0944998, 0.39635405, -0.3343581, -0.07587239, -0.34268427, 0.41813824,
+    -0.02212438, 0.0018830597, 0.12194328, 0.5240872, -0.39801762, -0.43094856, -0.31799856, -0.24505843, -0.20615867, -0.23297451, 0.37499183, 0.0129288435, 0.012023956, -0.20294255, -0.109084964, 0.13056108,
+    0.11879358, 0.2040452, -0.16572046, 0.14493358, 0.12137464, 0.07978657, -0.29415494, -0.09883362, 0.08394334, 0.27311856, -0.048777234, -0.40059465, 0.40299037, 0.3822925, -0.42965972, 0.01964584,
+    0.025413752, -0.3499252, -0.021377536, 0.40229988, -0.19355755, -0.45235372, -0.30786943, 0.19118044, -0.21551165, -0.5623782, 0.40723878, 0.006060183, -0.21781985, 0.3745593, -0.06200233, -0.27808982,
+    0.028215528, 0.25770387, 0.18670712, -0.42594305, -0.0051159114, 0.8945463, 0.37028524, 0.25698254, 0.045720607, -0.024414042, -0.068048835, 0.28413215, -0.1455859, -0.64022726, 0.32649282, 0.4267445,
+    -0.24531065, 0.383572, 0.1622667, -0.1139445, 0.05304867, -0.01547429, 0.3377662, -0.036667556, -0.06950125, -0.36590648, -0.08370888, 0.25855932, 0.057923585, 0.4006575, 0.007971644, -0.34719506,
+    -0.39980155, -0.22428659, 0.20614503, -0.13268887, 0.24915159, 0.8166563, 0.12804529, -0.3000734, -0.15637004, 0.47431985, -0.19108781, 0.3316286, 0.05137837, -0.39568335, -0.10591194, 0.34808633,
+    0.36735162, 0.3183262, -0.24144241, -0.24891663, -0.2468416, 0.79412955, 0.4072605, 0.013025254, -0.3264743, -0.1159352, 0.55750686, 0.015669495, 0.13400051, -0.6853724, 0.086509556, 0.25718686,
+    -0.09131631, -0.0897761, -0.15830882, 0.26290536, 0.18760274, -0.40700132, 0.11684796, -0.42951262, -0.268528, 0.24691413, -0.14726855, 0.26559004, -0.28075987, 0.25861657, -0.41818663, -0.08954474,
+    0.21914831, 0.28791657, -0.26394495, -0.32984206, 0.16919017, 0.042184554, -0.20519525, -0.37808445, -0.0048269033, 0.33323872, 0.30983004, -0.178857, -0.1724168, 0.05706723, -0.3148446, -0.39680383,
+];
+
+static TENSOR_2: [f32; 1] = [
+    -0.16013235,
+];
+
+static TENSOR_6: [f32; 16] = [
+    -0.4717248, 1.204008, 1.2589, 0.35855156, 0.11061287, -0.26265654, 0.3309257, 0.8805835, -0.18485287, 1.0813982, -0.73736197, 0.31201863, -0.8503407, -0.93093497, 0.13627851, 0.11121345,
+];
+
+pub fn invoke(arena: &mut Arena) -> Result<()> {
+    let arena = arena.0.as_mut_ptr();
+    // 0: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<f32>(arena, 128, 1), slice_mut::<f32>(arena, 64, 16)) };
+    OpFullyConnected::kernel(
+        input,
+        Some(&TENSOR_3),
+        &TENSOR_4,
+        output,
+        1,
+        16,
+        1,
+        0.0,
+        3.4028235e38,
+    )?;
+    // 1: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<f32>(arena, 64, 16), slice_mut::<f32>(arena, 0, 16)) };
+    OpFullyConnected::kernel(
+        input,
+        Some(&TENSOR_1),
+        &TENSOR_5,
+        output,
+        1,
+        16,
+        16,
+        0.0,
+        3.4028235e38,
+    )?;
+    // 2: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<f32>(arena, 0, 16), slice_mut::<f32>(arena, 132, 1)) };
+    OpFullyConnected::kernel(
+        input,
+        Some(&TENSOR_2),
+        &TENSOR_6,
+        output,
+        1,
+        1,
+        16,
+        -3.4028235e38,
+        3.4028235e38,
+    )?;
+    Ok(())
+}
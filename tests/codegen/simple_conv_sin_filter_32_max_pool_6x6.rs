@@ -0,0 +1,1225 @@
+// @generated by berrylite-codegen from simple_conv_sin_filter_32_max_pool_6x6.tflite, do not edit
+
+use berrylite::kernel::micro_operator::f32::conv2d::OpConv2D;
+use berrylite::kernel::micro_operator::f32::fully_connected::OpFullyConnected;
+use berrylite::kernel::micro_operator::f32::max_pool2d::OpMaxPool2D;
+use berrylite::micro_errors::Result;
+use core::cell::Cell;
+
+pub const ARENA_SIZE: usize = 3284;
+pub const INPUT_OFFSET: usize = 2560;
+pub const INPUT_LEN: usize = 36;
+pub const OUTPUT_OFFSET: usize = 2704;
+pub const OUTPUT_LEN: usize = 1;
+
+/// The memory of the activations, which is laid out by the greedy memory planner
+#[repr(C, align(16))]
+pub struct Arena(pub [u8; ARENA_SIZE]);
+
+impl Arena {
+    pub const fn new() -> Self {
+        Self([0; ARENA_SIZE])
+    }
+
+    pub fn input(&mut self) -> &mut [f32] {
+        unsafe { slice_mut(self.0.as_mut_ptr(), INPUT_OFFSET, INPUT_LEN) }
+    }
+
+    pub fn output(&self) -> &[f32] {
+        unsafe { slice(self.0.as_ptr() as *mut u8, OUTPUT_OFFSET, OUTPUT_LEN) }
+    }
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the `len` elements of `E` at the byte `offset` of the arena.
+///
+/// # Safety
+///
+/// The elements must be in the arena and aligned for `E`, as the offsets of the memory plan are.
+/// The slice must not overlap a slice returned by `slice_mut` while both of them are alive,
+/// which holds for the tensors of an operator, as the memory plan keeps its inputs and outputs apart.
+unsafe fn slice<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a [E] {
+    core::slice::from_raw_parts(arena.add(offset) as *const E, len)
+}
+
+/// Returns the `len` mutable elements of `E` at the byte `offset` of the arena.
+///
+/// # Safety
+///
+/// As `slice`, and the slice must not overlap any other slice of the arena while it is alive.
+unsafe fn slice_mut<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a mut [E] {
+    core::slice::from_raw_parts_mut(arena.add(offset) as *mut E, len)
+}
+
+/// Returns the `len` elements of `E` at the byte `offset` of the arena as the scratch of a kernel.
+///
+/// # Safety
+///
+/// As `slice`, where the scratch follows all the tensors in the arena and overlaps none of them.
+unsafe fn cells<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a [Cell<E>] {
+    core::slice::from_raw_parts(arena.add(offset) as *const Cell<E>, len)
+}
+
+static TENSOR_2: [f32; 288] = [
+    0.10889107, -0.10620111, 0.064522475, 0.124657564, 0.0628191, 0.006039299, 0.034159712, -0.13678809, 0.06460211, 0.10273079, 0.13338993, -0.016420841, -0.09786672, -0.026553657, -0.06556345, 0.05376988,
+    0.06083756, -0.05118399, -0.1201408, -0.06946331, -0.10331566, 0.13360277, 0.13781631, 0.0036029185, 0.060553372, -0.12107805, 0.0033130723, 0.10936429, 2.7768521e-5, -0.1358759, 0.027967306, -0.04660431,
+    -0.09351993, 0.03865278, -0.1303245, -0.068964355, 0.108272575, 0.102415524, -0.013829559, -0.116449945, -0.093946405, 0.04193063, 0.11016273, 0.0006977941, 0.026728874, -0.022466544, 0.044928655, 0.11037468,
+    0.09457473, 0.12156033, 0.08875088, 0.020223062, -0.004576579, 0.038050517, 0.058153823, -0.039616466, -0.05844286, 0.0834159, 0.057794318, 0.035580374, -0.0022898088, -0.053362526, -0.032849304, -0.12586424,
+    0.14183736, 0.008822761, -0.059702154, 0.13199669, -0.076970726, 0.13626349, -0.008357338, -0.019296745, -0.061946075, -0.08874366, -0.024203993, 0.13891459, -0.014237258, -0.07667732, 0.05638458, 0.050322264,
+    -0.118659705, 0.086422436, 0.07534198, 0.06022455, 0.053701084, -0.07828659, 0.102551825, 0.121140614, -0.04641692, -0.06843815, 0.12198703, -0.12459323, 0.052643325, -0.0353926, 0.049699973, -0.010390107,
+    0.082000405, -0.020931322, -0.018962909, 0.08525283, -0.020792108, -0.08625681, 0.045192305, -0.11775272, 0.01541211, -0.0065726554, -0.023198646, -0.024154972, 0.10324277, 0.15953077, -0.08055799, -0.0003524797,
+    -0.108880654, 0.121731594, 0.009779903, -0.07059015, 0.036034495, -0.06545374, 0.066652864, 0.14555913, -0.031942736, 0.03503654, -0.0576368, -0.11003767, -0.061763834, -0.09987372, 0.07221359, 0.00302975,
+    0.016290998, 0.033900484, -0.022125548, 0.076607764, -0.10642554, -0.038488634, 0.023036988, -0.09399914, -0.063442245, 0.05944997, -0.12679465, -0.004302542, 0.022338592, -0.11295576, 0.13450472, -0.038441777,
+    -0.10910774, 0.018623114, -0.03108776, 0.09054118, 0.0053008543, -0.06849217, -0.0316623, 0.091662504, 0.14655212, -0.05850745, 0.086417556, 0.06340913, 0.010141807, -0.088939816, 0.046217717, 0.053117916,
+    0.011361944, 0.01688199, 0.11624971, -0.05902114, 0.06024887, 0.042197097, -0.114451006, 0.020670578, 0.085032806, -0.0013603369, -0.06123459, -0.066109486, 0.035064705, 0.0030131063, -0.08250656, -0.06437916,
+    0.021185288, 0.0034988506, -0.0056064753, 0.051015522, 0.096316, -0.09065758, 0.03105489, -0.091582224, -0.113334835, 0.0020331827, -0.045027792, 0.16352125, 0.017273854, 0.14606111, -0.07959746, -0.048024073,
+    0.0766855, 0.11037184, -0.059241213, -0.12638678, -0.0977955, 0.08155842, -0.060649786, 0.035642665, 0.13264985, 0.031945456, -0.008910648, -0.13661309, 0.021719418, 0.062886275, -0.082069926, -0.01946344,
+    0.12347504, 0.06883905, 0.07874609, -0.04436025, 0.09113618, -0.058990426, 0.04879237, -0.05787106, -0.01653307, -0.06780276, 0.14152002, -0.059604097, 0.041789506, -0.09310511, 0.077273324, -0.033625383,
+    0.15209202, 0.039446272, -0.036857545, 0.011040979, 0.02952852, 0.09856355, -0.15323204, -0.04281546, 0.046830155, 0.0490349, 0.017443195, -0.12165906, -0.023823839, -0.1336656, 0.11375014, 0.0876027,
+    0.09688566, 0.0067075677, 0.011040456, -0.11857457, -0.096991256, -0.010200729, 0.034425884, -0.07999803, -0.11680897, 0.046205394, 0.13208187, 0.060818836, -0.089631155, 9.100462e-5, -0.032518815, -0.055572096,
+    -0.08775437, -0.06418586, 0.03517897, -0.063039675, -0.011698843, 0.02033381, 0.152575, -0.11689005, -0.04437559, 0.12971584, -0.04730977, 0.0817912, 0.0021869182, -0.061327823, 0.048635934, -0.11056583,
+    -0.02374473, -0.117521465, 0.016741438, -0.10772784, 0.12878017, 0.13875781, -0.07052169, 0.041602414, 0.06341167, -0.07437283, -0.08189545, -0.0018142387, 0.038587224, -0.0011537739, 0.024954453, -0.037847612,
+];
+
+static TENSOR_1: [f32; 32] = [
+    -0.08420173, -0.12818523, 0.048711658, -0.06678639, 0.017707197, -0.06617507, 0.1952264, -0.1157451, -0.08588244, 0.08862596, -0.116164975, -0.13899194, -0.1320658, 0.030754022, 0.19738488, -0.080077805,
+    -0.10566223, 0.029964333, 0.09927504, 0.06356935, 0.054824747, 0.1578589, 0.15987794, 0.11752454, -0.14149177, 0.034206178, 0.19871432, 0.054616746, 0.044913318, -0.1294537, 0.076740466, 0.10711522,
+];
+
+static TENSOR_4: [f32; 128] = [
+    -0.01203735, 0.080140926, -0.0020028846, -0.0794725, 0.012538564, 0.12716964, -0.075801015, -0.009576202, 0.13665214, 0.0, -0.012913335, 0.011475115, -0.0149694905, -0.02813323, 0.0690722, 0.051625274,
+    0.09092067, 0.08502163, 0.078777015, 0.06992377, -0.07828485, 0.09724412, -0.081816494, 0.16556467, 0.09881633, 0.081801385, 0.13301028, 0.0, -0.014752995, 0.10372309, 0.0, -0.09140167,
+    -0.016033178, -0.017859653, -0.08448304, -0.020368285, 0.01154162, -0.093853064, -0.010914087, 0.0654551, -0.082161136, -0.07343636, 0.081107326, -0.010159109, 0.09520936, -0.0070438352, -0.00839863, -0.010107498,
+    0.094791435, -0.06665113, 0.081640705, 0.07200004, 0.06084721, 0.0, -0.016058562, 0.012415144, 0.11571162, -0.06892483, 0.01235546, -0.012865812, 0.0, -0.008395998, 0.06909282, -0.071645714,
+    -0.075357564, 0.07875909, -0.012014743, 0.13673083, 0.06929056, 0.0807284, -0.06267503, 0.0, -0.07318705, 0.09127576, 0.08069215, -0.014122592, 0.09279032, -0.049348883, -0.08153051, 0.06670608,
+    -0.012493435, 0.06841309, 0.073741555, 0.0, -0.008040969, 0.070426054, 0.00828182, -0.02587922, -0.08555934, 0.07812022, 0.06842306, -0.07442356, -0.010095458, -0.075412974, 0.06971687, -0.0006931657,
+    -0.011042417, 0.084417395, -0.07065579, -0.012026729, -0.082868665, 0.08093901, 0.12672733, -0.016717508, 0.07499559, 0.08810749, -0.0145025775, 0.012304357, -0.023330087, 0.0030293562, 0.012247639, -0.010939597,
+    0.067458354, -0.10250624, 0.08352206, 0.013650521, 0.009313192, 0.069702424, -0.028623264, 0.07101442, 0.008664787, 0.011902718, 0.0077966168, -0.033700652, -0.07134812, -0.091986924, -0.01596985, -0.0109387105,
+];
+
+static TENSOR_6: [f32; 16384] = [
+    -0.11730024, 0.058566067, -0.02470601, 0.08494655, 0.10927033, -0.052177507, -0.15822397, 0.12653793, 0.14107881, -0.15546325, -0.06872054, -0.030863445, 0.10556572, -0.06324338, 0.019709688, -0.13560669,
+    -0.07379783, -0.07956502, -0.055140495, 0.038632635, 0.06417667, 0.076808535, 0.037520666, 0.04617259, 0.14422129, -0.0923265, 0.08397926, -0.15958367, 0.099033184, 0.0110808285, 0.038863864, -0.04578449,
+    0.07301603, -0.054807503, 0.07496079, 0.006939225, -0.16105625, -0.0077279713, 0.064977214, -0.078953676, 0.09536379, 0.10271205, 0.07761285, 0.13192098, 0.055955254, -0.15037799, -0.14492485, 0.08065711,
+    -0.108026735, 0.061771646, 0.086799614, 0.1265337, -0.08419014, -0.067011915, 0.049049962, 0.075610794, -0.11305111, -0.0022673684, 0.13116267, 0.0065061576, 0.011003385, 0.0343407, 0.07760677, -0.034594025,
+    0.056109086, 0.101451814, -3.3425244e-5, -0.09972271, 0.03546928, -0.16045891, 0.058600508, 0.035801113, -0.06222876, -0.16267063, 0.13487116, 0.01835129, 0.073638834, 0.11390393, 0.019341141, 0.028621292,
+    0.04638027, 0.040504243, -0.14753109, -0.029750371, 0.014639295, -0.030533666, -0.09014628, -0.0518014, 0.13568284, -0.081684925, 0.0545252, -0.020086173, -0.15857556, -0.13478571, -0.0556215, -0.16427554,
+    -0.0972787, -0.03041327, 0.044724695, 0.12350026, 0.05633807, -0.06413423, 0.1129755, 0.11397527, -0.0652914, 0.13215683, 0.1031187, 0.04378507, -0.02179486, -0.1010371, 0.06841464, -0.062409848,
+    0.10330551, -0.019070107, 0.13086362, -0.083117545, 0.08649952, -0.040814556, -0.03681512, -0.1671553, -0.048574477, 0.12860402, 0.031505156, -0.13172153, 0.084901266, 0.14086454, 0.01567976, -0.083421126,
+    -0.103168756, -0.054171003, 0.117002405, -0.08539961, -0.08356332, -0.10315501, 0.13937376, -0.037683412, 0.017085886, 0.07392085, -0.24585071, -0.17399736, -0.12819667, -0.08693692, 0.008338541, -0.16696712,
+    -0.15865377, 0.09356972, -0.016158992, 0.030875603, 0.054071415, 0.16497184, 0.08659555, -0.13477756, 0.054339614, 0.04295148, 0.17145433, -0.021900985, 0.09445554, -0.01585279, -0.016424598, -0.069108196,
+    0.10123904, 0.08366388, 0.052921236, -0.058131296, 0.11627117, -0.007226131, 0.13057055, -0.09252525, 0.08268143, 0.11225828, -0.14682782, 0.025031501, 0.059342172, 0.0653245, -0.029197745, -0.16376492,
+    -0.024675671, 0.14513378, 0.14145704, -0.022214236, -0.13937707, 0.13749413, 0.024998952, 0.139863, -0.18591674, -0.10699462, 0.13749474, -0.10548224, -0.092987746, 0.013527584, 0.11603898, 0.0846831,
+    -0.15455548, -0.03724829, -0.008268958, -0.16858771, -0.14497961, -0.03267319, 0.15427382, -0.0016046775, -0.039230034, 0.09963327, -0.07335997, 0.098045796, -0.148807, 0.1134989, -0.078803495, -0.09555649,
+    -0.1926724, -0.008790237, -0.1349118, 0.08217118, -0.04886361, -0.05968259, 0.18525003, 0.07779379, -0.11486087, 0.08116274, 0.12024284, 0.07989424, -0.12233357, -0.07841225, 0.1320787, -0.033620972,
+    0.083584964, -0.05147013, -0.06490152, -0.05849277, 0.13763595, 0.11837862, 0.12272936, -0.1215673, -0.08186844, 0.058738485, -0.07962715, 0.07035449, -0.13231997, 0.08570433, 0.035665162, -0.1483626,
+    -0.20179579, -0.10864601, 0.080465496, 0.014050333, 0.06102451, -0.072552405, -0.081382975, -0.13488816, 0.070374735, 0.1481067, 0.19719075, 0.027129028, -0.07436414, -0.064938344, 0.13599718, 0.046199948,
+    -0.1353532, -0.12873515, -0.1317491, -0.03342177, -0.108325675, 0.10701214, -0.06477359, -0.03827127, 0.024324786, 0.07115346, 0.14165069, 0.14001623, 0.120816275, 0.09722512, -0.11955652, -0.06991231,
+    -0.0066640927, 0.107195035, -0.046399996, 0.10205302, -0.060881436, 0.07650042, 0.09969972, -0.07223256, 0.04472977, -0.14750357, -0.10271051, -0.15050921, 0.04928357, 0.016398028, 0.029271305, 0.14434746,
+    0.0021306723, 0.12241854, -0.017311927, -0.12069718, 0.1053281, -0.09538032, 0.04940617, -0.023698479, -0.09642162, 0.08807662, -0.08584357, -0.034192305, 0.11928742, -0.1282202, -0.14255159, 0.03116692,
+    0.07135119, -0.008204266, -0.12101747, -0.11524107, 0.022108477, -0.10631966, -0.13613665, 0.029776901, 0.1199521, -0.085639246, 0.15054983, 0.119932644, -0.060523905, 0.13430901, 0.11626734, 0.03578119,
+    0.034362063, -0.024843484, 0.014982296, -0.08918184, 0.10448675, 0.11019908, 0.10946917, -0.10344245, 0.01968206, -0.12271392, 0.13759147, -0.052965913, -0.0604586, -0.12084456, -0.09316184, -0.013887037,
+    -0.08803405, -0.13949575, 0.008068812, -0.046727188, -0.15485293, 0.12380412, -0.07954648, -0.083877705, 0.097392425, -0.07951286, -0.05708391, -0.14583185, -0.08415296, -0.1215557, 0.14586262, -0.02354217,
+    0.035865977, -0.06046141, -0.10219319, -0.045831993, -0.06176024, 0.14761768, -0.059639215, -0.017675608, 0.11309057, -0.12554887, 0.111461654, -0.024079558, -0.06706726, 0.13426434, -0.05138122, 0.13346505,
+    -0.017708398, -0.107061416, -0.07297026, 0.042549305, 0.03603656, -0.099120356, 0.021954315, -0.024053767, 0.09360579, -0.09876262, 0.11957347, 0.10504343, 0.04031167, -0.116337806, -0.041313067, -0.00085664727,
+    0.06607358, -0.013167695, 0.093492046, -0.009839594, -0.009572519, -0.12014977, -0.05812492, -0.021142155, -0.06601836, -0.049962323, 0.14083564, 0.1515627, 0.022625757, 0.08131218, 0.089436024, 0.07562621,
+    0.15042002, 0.03356364, -0.10483178, 0.08839136, -0.14067362, -0.22132331, -0.23287436, 0.067475416, 0.1631134, -0.13360697, -0.05927598, 0.0014888184, -0.08408992, 0.10470971, 0.08237982, 0.03225824,
+    0.02230828, -0.008083937, 0.06646493, -0.06909718, -0.09061331, -0.017810037, 0.048057538, -0.08993864, -0.11401376, -0.05746561, -0.013353902, -0.11117777, 0.083643034, -0.15002553, 0.012327817, -0.027703106,
+    -0.033926714, -0.010040037, 0.0029819664, 0.05419819, 0.06512526, -0.20916373, -0.14779532, -0.04396998, 0.10488739, 0.0007706697, -0.04366645, -0.13973412, 0.007185772, 0.17231467, 0.048791382, -0.15759608,
+    0.041879866, -0.09751434, -0.038607724, -0.10121675, -0.008867738, 0.13923822, 0.017148959, -0.07462923, 0.07838453, 0.07004859, 0.12892638, -0.063532576, -0.017232077, -0.017196909, -0.16789745, -0.1336045,
+    0.121080235, -0.1316766, 0.043530162, -0.11514301, 0.026214631, -0.20943528, -0.19181518, 0.124252185, 0.023444086, -0.12347535, -0.00823426, 0.11689686, 0.06609237, -0.057120647, 0.02975158, -0.12958059,
+    -0.086074114, 0.07584525, 0.088247076, -0.013097122, 0.12958351, -0.124053426, -0.019711016, -0.13136186, -0.0056227595, 0.11937956, -0.0011697247, 0.13139959, 0.04452919, -0.11120138, 0.04381245, -0.008053049,
+    -0.058881063, 0.06793457, -0.02620489, -0.08781119, 0.0057452926, 0.010866724, -0.06588386, 0.11022545, -0.022491142, 0.0011856065, -0.025516642, -0.12478213, -0.021671504, 0.0683866, -0.11228657, -0.18802047,
+    0.14962013, -0.120342545, 0.07875296, 0.08898897, 0.104832485, 0.038955547, 0.14389056, -0.12746544, 0.02966691, 0.028350579, 0.047458094, 0.016258847, 0.06577023, -0.13467214, 0.1534974, 0.0158198,
+    0.15742148, -0.1172963, 0.0033772986, -0.062127545, 0.02086625, -0.04401434, -0.057545036, -0.04425861, 0.10784738, 0.112256475, -0.08881048, 0.070925266, -0.0070474283, -0.075475685, -0.14981496, 0.06161995,
+    0.07063947, 0.05726856, -0.12842739, -0.09367003, 0.07776162, 0.04384402, -0.07934808, 0.12389268, 0.106795914, 0.007550613, -0.09746903, -0.1308979, -0.022293195, 0.09079776, -0.096831605, -0.0017813432,
+    -0.026261654, -0.10159799, -0.12276857, -0.042770445, 0.020249432, 0.13694018, 0.020486813, 0.037642606, 0.03198804, -0.09648036, -0.057644878, 0.090927705, -0.00018188474, 0.09098408, 0.046990264, 0.096048094,
+    0.121960424, -0.024434889, -0.018261258, -0.02138998, 0.011064565, 0.04421086, 0.114945, 0.1553119, -0.04219222, -0.05970318, -0.008541058, 0.038977098, -0.08920071, -0.021460975, -0.0023054162, 0.11601572,
+    -0.081627026, -0.020633813, -0.12168623, 0.15280539, 0.100926444, 0.0086493, -0.09777623, 0.011193803, 0.14821541, -0.0026025458, 0.08779318, 0.015602244, -0.12394565, -0.006791091, 0.11700775, 0.13289101,
+    -0.009581683, 0.10271163, 0.014378637, -0.12041763, -0.08157848, -0.00846269, -0.06589515, 0.13091479, -0.01124887, 0.028698536, 0.023694899, -0.09037208, 0.123277925, -0.1027281, 0.014544337, -0.021267932,
+    0.006573136, 0.017870244, -0.09698003, 0.12991014, 0.01081659, 0.09383799, -0.043764845, 0.121464804, 0.027608916, -0.06207472, 0.0039824136, 0.044364274, 0.13181244, 0.051697224, -0.04741368, 0.10252113,
+    -0.11331611, 0.033620764, -0.12191004, 0.05623003, 0.05115698, -0.13098715, 0.06867912, -0.21101235, -0.13358717, -0.0738341, -0.030680558, -0.025670364, -0.16496515, 0.11162342, 0.14158866, 0.027087606,
+    -0.083073005, 0.036699884, -0.039457906, -0.07384428, 0.101675965, 0.07321816, -0.0009755284, 0.09355837, -0.008791193, -0.06593958, 0.19443437, -0.029982578, -0.025710132, -0.11612271, 0.055715177, -0.043032367,
+    -0.057734724, -0.08687967, -0.11680264, -0.102481425, 0.14872532, -0.09457908, 0.100581355, -0.22269855, -0.120635524, -0.088027, 0.014352449, 0.057693303, 0.08523405, -0.018190686, -0.02250394, -0.20888005,
+    -0.16864593, 0.10045854, 0.16180903, -0.115891315, -0.036368776, 0.114354305, 0.19809882, 0.047509048, -0.13411915, -0.14429612, 0.097374074, 0.011394946, -0.02443185, -0.2344547, -0.0075408937, -0.107956454,
+    -0.07879739, -0.1393416, -0.026778659, 0.026685322, 0.14124428, -0.075315125, 0.024519624, -0.21628828, 0.0852032, 0.022776376, -0.20605728, 0.03957022, 0.070539795, -0.058124047, -0.022127913, -0.21394445,
+    0.070017695, 0.0092591755, 0.14198864, 0.13668376, 0.095851906, 0.07384395, 0.26410317, -0.038999956, 0.030100463, 0.12821244, 0.063758664, -0.13300294, -0.048532963, -0.09666121, -0.15433437, -0.040366355,
+    -0.060859438, 0.064090684, -0.11169667, -0.08329813, 0.06332208, 0.031567242, 0.21046713, -0.086216256, -0.10504985, 0.097234584, -0.0077826227, -0.03181282, -0.0052593984, 0.07846244, 0.09903193, -0.08772562,
+    -0.107538745, 0.09152839, 0.15810385, 0.014787675, 0.16176166, 0.19909577, 0.16396971, -0.11501132, -0.18541372, 0.09496511, 0.25618926, -0.012148576, -0.0383666, 0.015683897, -0.10633245, -0.0911413,
+    0.11460826, -0.12220324, 0.060498443, 0.082853034, -0.05661204, -0.099643536, 0.022445684, -0.090383224, -0.092976935, 0.038968977, -0.03924632, -0.12522592, -0.10579152, -0.12690873, -0.17381638, -0.020874424,
+    0.013429505, -0.122862406, 0.06941998, 0.04383889, 0.059962284, 0.12083393, 0.040221646, -0.031138986, 0.025284592, 0.1105695, -0.22164588, -0.10115251, 0.14233804, 0.0074192206, -0.09877563, 0.03714081,
+    -0.020254992, 0.013957265, -0.030005079, 0.059008658, 0.145045, 0.12583597, 0.0425926, -0.13669866, -5.3610507e-5, -0.14314854, 0.12770091, -0.08302862, -0.13916847, 0.051494632, -0.2200194, 0.07303329,
+    -0.14024782, 0.1395678, -0.11205582, -0.03258104, -0.04569533, 0.09650317, 0.048695184, 0.14318426, -0.0060821665, -0.113666445, -0.103974715, -0.080171995, 0.08084845, 0.089389026, -0.11187411, 0.020530233,
+    -0.0033944913, -0.034783065, -0.13396582, 0.042494386, -0.118843466, -0.024791634, -0.20341706, -0.1339257, -0.13402976, -0.027323145, -0.13216184, 0.0025308318, 0.024024379, 0.028511273, -0.086644486, 0.14059769,
+    -0.060258076, 0.112416275, -0.05572373, 0.106349446, -0.13872752, -0.12657274, 0.0018602064, -0.15324235, 0.12509659, -0.15844803, -0.21473978, 0.07586779, 0.09996358, 0.001575711, -0.055384766, -0.070213854,
+    -0.07861297, -0.010650839, -0.1433602, 0.12829831, -0.039516125, 0.062543906, -0.16823238, -0.005126683, 0.14244486, 0.110440135, 0.13280864, 0.16182005, 0.07892319, 0.09583842, -0.2514131, -0.107949615,
+    0.108852506, -0.12489247, 0.075794354, 0.055108234, 0.031892855, 0.015420991, -0.040697716, -0.07583115, 0.08655825, -0.048660073, -0.2567526, 0.106539965, 0.05214816, 0.13417438, -0.012054114, 0.009815046,
+    -0.13226517, -0.08827902, 0.0745356, -0.05350867, 0.060175043, 0.097023286, -0.06688837, 0.094945386, -0.121629044, -0.08094481, 0.013819304, -0.06878938, -0.0123928385, -0.029561296, -0.1274685, -0.13897358,
+    -0.1441008, -0.13093776, -0.04819403, -0.09552901, 0.011006792, 0.0025157302, 0.041677743, 0.06301651, 0.03490806, -0.12890466, 0.12576765, 0.079384536, -0.12366366, -0.00081519916, -0.16194227, 0.02270011,
+    -0.084764645, -0.11009827, 0.051479176, 0.0035285808, 0.08866016, -0.14135201, -0.14636862, -0.009701833, -0.008836939, -0.13442, -0.04602588, 0.14424925, 0.025395393, 0.05242657, 0.08938269, 0.13590944,
+    -0.11950196, -0.017185373, -0.08394394, -0.1050421, -0.06831494, 0.04968524, -0.14277536, 0.078146346, 0.009434548, -0.10243946, -0.14936276, -0.08247099, -0.08008392, 0.05942515, -0.043224376, -0.08498589,
+    0.033521626, 0.02029246, 0.07991453, -0.1270588, 0.0780757, -0.06536764, 0.13459952, 0.14979164, -0.17895274, 0.111961536, 0.07858105, 0.08478163, 0.036771398, -0.050155565, -0.039356954, -0.11076091,
+    0.06398154, -0.10519422, -0.050344445, -0.19803995, -0.16004577, 0.14516434, -0.05976651, 0.01628961, -0.05681253, 0.13337103, 0.11865684, -0.08421683, 0.0768602, 0.14721026, -0.062197976, -0.076572135,
+    -0.07073923, -0.04871676, -0.119939074, -0.061553396, -0.030124914, -0.11075521, -0.032951146, -0.023870543, -0.07214122, -0.03854198, -0.14636482, -0.10515143, -0.094108045, 0.05403354, 0.08752684, -0.083056286,
+    -0.05305474, 0.017811662, 0.07068292, -0.05193023, 0.0064526876, 0.051700518, 0.024614275, 0.09224098, 0.015976336, 0.08216405, -0.018104717, -0.048847895, -0.025022637, 0.076457806, 0.10186755, 0.024585048,
+    -0.15482487, -0.20457697, -0.062723994, 0.052843735, -0.13005656, -0.11172086, 0.0002103739, -0.022448229, -0.09014807, 0.058980793, -0.12598823, -0.15324195, 0.09070302, -0.10577416, 0.25172138, -0.09714533,
+    0.042303886, 0.14296727, 0.10979086, -0.10991087, 0.12121869, 0.09202005, 0.066276744, -0.057659514, -0.16411416, 0.12665841, 0.027319474, -0.020151345, -0.10550234, -0.10305051, 0.09765332, -0.052969642,
+    -0.13323683, -0.015991967, 0.034731787, 0.07970079, 0.0020411706, -0.06444224, 0.21544152, -0.20409611, -0.066540055, 0.03762754, 0.057162095, -0.025768297, -0.06506131, -0.09826114, 0.19421598, -0.103510864,
+    -0.018895602, 0.12374104, 0.16229796, 0.14016052, -0.060567193, 0.1372647, 0.021851957, 0.14086181, -0.14330925, 0.08260603, 0.13986956, 0.1295743, -0.1508553, -0.027044384, 0.13935445, 0.13081281,
+    -0.08728801, 0.06169141, -0.044931483, -0.04954759, -0.08622009, 0.059212234, 0.1157068, -0.23761111, 0.100203775, -0.05431412, -0.2037643, -0.1054653, -0.09922964, 0.015278675, 0.16855451, -0.15245979,
+    -0.16545005, 0.033128385, 0.12976605, -0.046359368, 0.113572545, -0.049460687, 0.20168844, 0.05341722, -0.11321482, 0.091803476, 0.15019505, -0.1274077, 0.020049794, -0.068993874, 0.0245344, 0.021520607,
+    0.0039589675, -0.058900155, 0.036415864, -0.13023017, -0.023674028, -0.033468496, 0.0149871735, 0.022134101, -0.004230134, -0.008869617, -0.0631367, 0.024603015, -0.11089868, 0.13124564, 0.12264865, -0.2184525,
+    -0.1380101, -0.1365569, 0.069691636, -0.059014976, 0.08210414, 0.073192626, 0.20744994, 0.065976754, 0.024871266, -0.14079294, 0.010362554, 0.11897822, 0.057559084, -0.1806701, -0.12830572, 0.040625095,
+    -0.12233852, 0.057876572, -0.08462647, 0.12041496, 0.009498969, -0.12841727, 0.017275423, -0.065965064, 0.053774893, -0.06487845, -0.06652793, -0.14703874, -0.14425501, -0.09023481, -0.13728909, 0.020076558,
+    0.10354538, -0.029487886, 0.105180845, -0.005754769, -0.14156966, 0.0078102797, 0.017067835, 0.06179163, -0.019421443, -0.09096525, 0.06725378, -0.027853772, -0.09946425, -0.114883706, -0.030163072, 0.047099188,
+    -0.09375963, 0.10880391, -0.12222924, -0.07541381, 0.10880186, 0.011235759, 0.03308718, 0.07330322, -0.11504635, 0.07827951, 0.07061699, -0.10990435, -0.1320904, -0.15243918, -0.15038589, -0.033976145,
+    0.12712426, -0.03538341, -0.05279205, 0.07601899, -0.093731165, 0.018177852, -0.012656242, -0.09059535, 0.13584118, 0.13302027, -0.1245792, -0.11619936, 0.13009946, 0.068007976, -0.0022680461, 0.058437362,
+    -0.14701265, -0.016858727, 0.0075618327, 0.081621334, -0.12354179, 0.09012523, 0.012274489, -0.08538206, 0.12261741, -0.054061934, 0.117024824, -0.042477123, 0.013577029, -0.118865445, 0.12773333, -0.10542431,
+    -0.028768867, -0.12500387, -0.06991034, -0.05131931, -0.10493433, -0.061379828, 0.010534987, -0.13281941, 0.06901856, -0.13614759, -0.0872977, -0.04462987, 0.053123623, 0.05463761, -0.025066644, -0.074422136,
+    -0.041198373, 0.047076046, 0.010792941, -0.056136683, 0.09848641, -0.08195359, -0.01334241, 0.11129169, -0.07495727, -0.14249647, -0.066573486, -0.09230597, 0.09305431, 0.06887859, 0.05731027, 0.10047747,
+    0.0186117, -0.059216313, 0.05458568, 0.040981263, -0.12765427, -0.0872389, 0.024828196, 0.107629314, 0.14985956, -0.078770846, -0.13470167, -0.023249164, -0.056238115, -0.0048112124, -0.11981423, 0.09020685,
+    0.11943598, -0.014158368, 0.09412256, 0.109775156, 0.0014127791, 0.108580604, -0.06317419, 0.062178895, 0.04271057, -0.021937698, 0.003621295, -0.018125502, -0.097443275, -0.045924947, 0.05211299, -0.13364808,
+    0.09133357, -0.099667385, -0.06709829, -0.1222901, -0.045010846, -0.04426075, -0.08142691, -0.027331784, -0.062225647, -0.005013421, -0.10177834, -0.06691999, -0.047189597, -0.07024052, 0.0643936, -0.12855637,
+    -0.009474114, 0.08358103, -0.10051328, 0.13697253, 0.06536321, -0.057194054, -0.16045198, -0.03758025, -0.13913502, 0.054153323, 0.012242004, -0.027676849, -0.08360753, -0.16538246, -0.061205126, 0.042491525,
+    -0.10171913, 0.0032097548, 0.02962549, 0.13192984, 0.06544959, 0.12976992, 0.027552871, 0.03195648, 0.042663127, -0.09199309, -0.078298755, 0.0098340595, 0.07974913, -0.090223745, -0.034528043, -0.028095845,
+    -0.12446068, -0.11603409, -0.051378187, -0.026955673, -0.067489825, 0.028485373, -0.13671872, -0.030649364, 0.03462894, -0.09812875, 0.1279947, 0.036166187, -0.04882057, -0.042115055, -0.009854353, -0.030630022,
+    -0.096926905, -0.1178764, 0.029804096, -0.08528841, 0.13382293, 0.13223845, -0.06505857, -0.10964826, -0.06935287, -0.004396051, -0.09955387, -0.11823647, 0.11215251, -0.03885407, 0.04337591, -0.072568156,
+    -0.15071276, 0.15255411, 0.10980931, -0.05140618, 0.0647933, -0.03402166, -0.07002575, -0.1480318, 0.03006516, -0.0038464367, -0.12555192, -0.010808396, -0.14370145, 0.06302747, 0.06517546, -0.06174981,
+    -0.039328977, -0.12367943, 0.052148074, -0.10705646, -0.13306378, -0.021742366, 0.1088147, -0.013396099, 0.1431819, -0.07526113, -0.15328261, -0.009115478, -0.13180345, -0.14516851, 0.0429236, -0.08973079,
+    -0.06494963, -0.05626971, 0.072331935, 0.1251847, 0.06950854, -0.08936673, 0.119103245, -0.06112418, -0.04018548, -0.0752049, -0.04289727, 0.053054605, 0.05096122, 0.001528151, 0.123137824, -0.14911658,
+    0.055233758, -0.028454913, 0.014089028, -0.05247358, -0.119959734, 0.0037143857, 0.010692026, 0.004049384, 0.0727287, -0.054532114, 0.050541583, 0.099821694, 0.036766253, -0.027723493, 0.018255293, 0.09407887,
+    0.11398353, 0.029480064, -0.12327747, -0.010075046, 0.11145279, 0.018234776, 0.13319704, -0.108023755, 0.081228115, 0.010665405, -0.04588504, -0.16392756, 0.17644608, 0.07407956, 0.1261842, 0.06204687,
+    0.040100817, -0.090708606, 0.074670635, -0.07339563, 0.0281437, 0.10830783, -0.013727649, 0.017616076, 0.1660966, -0.10072744, 0.05608054, -0.13576691, 0.13654274, 0.04718337, -0.065170154, 0.14359277,
+    0.10239059, 0.017119532, -0.051745117, -0.0021315985, 0.08030057, 0.04472385, 0.048983708, 0.16128945, -0.0901382, 0.083145745, 0.03183348, 0.04078944, 0.07479488, -0.075084485, -0.07434436, 0.05112092,
+    0.043447997, -0.07490397, -0.14493006, -0.12199614, -0.13980958, -0.034384593, 0.011721798, -0.0027341212, 0.067302145, 0.08530083, 0.09588988, -0.12680732, 0.04177869, 0.008792414, -0.08681842, 0.12184179,
+    -0.10002577, 0.119265296, -0.035925113, -0.10381962, -0.07951301, 0.055930447, 0.0047217947, -0.09748817, 0.06169434, 0.110493235, 0.052695677, -0.015011032, 0.09584396, -0.10736808, 0.10672615, 0.0067565297,
+    0.087222986, -0.036828555, -0.007758704, 0.14133303, 0.14951609, 0.07141697, 0.08789459, -0.08559246, -0.049245868, 0.051029366, 0.02846748, -0.027393892, -0.024833592, -0.028234461, 0.061463773, 0.098629355,
+    -0.10693192, -0.14268348, -0.13383694, 0.07779704, 0.045253992, -0.0031888469, -0.07240738, 0.093734734, -0.1518491, -0.050223153, 0.12192371, -0.105699375, -0.11035614, -0.0010896599, -0.0500181, -0.12095246,
+    -0.0065397033, -0.068029, 0.028465966, 0.01023807, 0.13173504, 0.012357577, 0.08090341, -0.11870355, -0.048279963, 0.06608839, -0.023889132, -0.14400397, 0.09916278, 0.13997048, -0.086422704, -0.12744817,
+    0.06496375, 0.01882956, 0.060737755, -0.089174524, -0.059582844, 0.015686026, -0.062367532, -0.10900574, 0.01242598, 0.111989535, 0.11730898, 0.13152657, 0.031141544, 0.049803413, 0.13993117, 0.058829695,
+    0.054396342, 0.008873176, -0.011348895, -0.014434021, -0.07490808, 0.1307727, -0.10396503, -0.13989606, -0.076507874, -0.1130678, 0.06096198, 0.13097245, 0.12501708, 0.0072480333, -0.07576175, -0.06392659,
+    -0.16219547, -0.11975792, -0.0108715575, 0.017190041, -0.011713659, 0.0616022, -0.06507365, -0.094898, -0.11138645, 0.016170481, -0.09592184, 0.09902303, -0.15026148, -0.12315706, -0.11334063, 0.009373972,
+    -0.015775416, 0.024039026, 0.13764161, -0.07402064, 0.14209957, 0.097573236, 0.0020338946, 0.020761654, 0.010898185, 0.025879424, -0.08038371, 0.14655209, -0.11032685, 0.06568868, 0.0005458174, -0.13043192,
+    0.009797168, -0.12904745, 0.004690174, -0.1407914, 0.015000919, -0.15210778, 0.041880805, -0.10720136, -0.01683089, 0.063807525, -0.020743221, 0.0693609, 0.11987751, 0.077226415, -0.12163491, 0.1146052,
+    0.03716947, 0.11725601, -0.15678276, -0.016971512, -0.1402301, 0.0010335534, 0.13215533, -0.025891973, 0.00291539, 0.12257053, 0.039968003, 0.030536618, -0.02307689, -0.0888255, -0.019342054, 0.090786114,
+    0.11012288, -0.059806615, -0.12866095, 0.14559977, -0.12413414, 0.13349262, 0.025752306, -0.049367625, 0.10183025, 0.143662, -0.15890302, -0.10836388, 0.06986959, -0.009694843, -0.1510285, 0.09987384,
+    -0.17508653, 0.11207892, 0.037510995, 0.13665505, -0.15608148, -0.15527444, 0.10592352, 0.027453387, -0.0090493355, 0.10184208, -0.1893496, -0.11224155, 0.09761433, -0.16778007, -0.045688406, 0.031420637,
+    -0.0526534, 0.055754498, -0.021506796, 0.066039115, 0.000749268, -0.099538155, -0.09062185, -0.04899787, 0.14187597, 0.10133175, -0.067086816, 0.1551798, -0.19585241, 0.0794591, -0.060971525, -0.072103664,
+    -0.00039931992, -0.12636691, 0.110212795, 0.12499726, 0.06651513, 0.08831821, 0.05945079, -0.009050224, -0.084735684, -0.08516833, -0.16945298, -0.037254386, 0.07506411, -0.06619278, 0.102707766, -0.038572945,
+    -0.07135785, -0.052117325, 0.11596646, 0.060599733, 0.00078933727, -0.024809089, 0.056400213, 0.13714553, 0.14196728, -0.047888238, -0.013173085, -0.009416741, 0.010738647, 0.1340185, -0.09137665, -0.07142706,
+    0.080967344, -0.07997529, 0.07622028, -0.018996133, -0.090120986, 0.06776496, 0.0081682475, -0.0784457, -0.044844747, 0.020519374, -0.115676835, -0.03307303, -0.041316915, -0.11210608, 0.10441532, 0.07361296,
+    0.14026132, -0.11538533, 0.09391, -0.09857608, 0.14944087, 0.14600939, -0.15398502, -0.015667232, -0.050937023, -0.11794901, -0.025269842, -0.04183249, 0.023082292, 0.1311536, 0.09927225, -0.00019786233,
+    0.04649254, 0.092639044, -0.024668135, 0.025925072, -0.10223293, 0.030474491, -0.1557936, 0.021427715, 0.066519715, -0.04337727, -0.00080805714, -0.027834894, -0.019049909, -0.045926686, -0.01897126, -0.08856291,
+    0.08890129, -0.14384767, 0.071134165, -0.022156447, 0.0317777, -0.12753406, 0.014572162, 0.03996197, -0.12354177, -0.04286359, -0.07892351, -0.021740774, -0.104455, -0.08867397, 0.1224792, -0.15186581,
+    -0.14997716, -0.011119369, -0.02717671, 0.035835695, 0.06708007, 0.11742191, 0.11677341, 0.10142904, -0.13570935, 0.038109343, 0.28333622, -0.07646369, -0.0077385553, -0.08974765, 0.14394149, 0.0028000607,
+    -0.18045181, 0.15739736, -0.10685937, 0.060170524, -0.15261021, -0.07408883, 0.08947016, -0.06419757, 0.022028362, -0.07228611, -0.088755906, -0.020591738, -0.0063159834, -0.14469315, 0.11939831, -0.1549911,
+    -0.060923677, -0.0696575, -0.08064991, -0.11767921, -0.12819421, 0.1615157, 0.11538994, -0.019746946, -0.04568489, 0.11298577, 0.16308376, 0.044370245, -0.007346222, -0.048176486, -0.024758032, 0.006888226,
+    -0.09789502, 0.054979853, 0.13209245, 0.07263471, -0.11669884, 0.07479362, 0.20690891, -0.1277058, 0.11424458, -0.12421096, 0.03665028, -0.111312605, -0.1697253, 0.027055448, 0.19883908, -0.1541029,
+    0.1360144, -0.12220463, -0.109212026, 0.121532634, 0.00618613, -0.07661122, -0.08026253, -0.037944436, -0.060897373, -0.07707181, 0.13782725, 0.14998335, 0.11531742, -0.03501876, -0.13714108, -0.047697905,
+    -0.0060006343, 0.03689701, 0.13624966, 0.01961568, 0.001553236, -0.039791893, 0.27533013, -0.0297149, 0.12668751, -0.12734403, 0.009695922, 0.091426484, -0.061589576, -0.04476165, 0.14696564, 0.037490677,
+    0.11637545, 0.023161491, -0.04866774, 0.07751861, 0.122459434, 0.0280704, 0.07171486, 0.016284736, -0.16176084, -0.16431995, 0.20791635, -0.100438036, 0.02794858, -0.09634197, -0.0137939425, 0.022566723,
+    -0.21732824, -0.06443085, -0.121172495, -0.11522542, -0.13811544, -0.12378717, 0.04057411, -0.17898971, -0.04669324, 0.09965328, -0.03305648, -0.09839331, -0.03394174, 0.0898664, 0.19416776, -0.085375324,
+    0.01337044, -0.0484325, 0.19260187, -0.14870758, -0.055147305, 0.14640886, 0.017928865, 0.02161921, -0.19500086, 0.016102543, 0.18515027, -0.16969551, -0.13423455, -0.061714336, -0.073890366, 0.0046692323,
+    -0.17083575, 0.017079234, 0.11885709, -0.020555986, -0.09866017, -0.1849231, 0.09814896, 0.05421032, -0.19440332, 0.108024545, -0.1912186, 0.072418205, 0.04101017, -0.058485217, 0.18434936, -0.23975876,
+    -0.03727947, 0.05586703, 0.045842618, 0.053062994, -0.117109716, 0.18447831, 0.03610382, -0.14336856, -0.19513825, -0.07998686, 0.02345214, 0.0019262073, 0.034621704, -0.10430689, -0.04924017, 0.051543478,
+    -0.025735939, 0.08204086, -0.08296626, -0.07545616, -0.05819497, -0.09477335, 0.014415733, -0.099340856, -0.23085019, -0.12843765, -0.0838524, -0.19488771, -0.11694984, -0.1048141, 0.1016332, -0.09774314,
+    -0.061578725, -0.094779044, -0.04883893, 0.028639585, 0.0034269968, 0.001668562, -0.010350494, -0.14485106, -0.04233821, -0.11273547, 0.083260365, -0.15550838, -0.18579204, -0.101843506, -0.14503674, 0.1218361,
+    -0.24401508, -0.12307984, -0.0012971112, -0.089855745, -0.04736023, 0.052790653, 0.19193812, 0.09937172, -0.16897744, -0.044170722, -0.22465865, -0.15753685, -0.15745027, 0.08563855, 0.19387236, -0.040380362,
+    -0.12564334, -0.16196565, 0.18280987, 0.0841208, -0.10886334, 0.057021312, -0.00117492, -0.12538373, -0.12987728, 0.06662693, 0.07471435, -0.009432987, -0.062528454, -0.08989625, -0.12271153, 0.16958009,
+    -0.058867913, 0.092957616, 0.07614803, 0.0060066557, -0.16231868, 0.0065174666, 0.24411851, -0.03467342, -0.049408752, -0.07461219, -0.056471154, -0.13231052, -0.16236252, -0.14072834, 0.3298069, 0.11700692,
+    0.09092582, -0.16115485, -0.17003599, 0.088237494, 0.022330761, 0.0058277627, -0.054607864, -0.11227514, 0.11366339, -0.05220549, 0.25286773, 0.09977022, -0.10715517, 0.0980145, 0.046017293, 0.07080423,
+    -0.071890116, -0.063119434, -0.14359215, -0.0017614383, 0.038673487, -0.041283764, 0.080817185, -0.20058432, 0.12865426, -0.13106297, -0.08594767, 0.111320324, -0.17436115, 0.084606424, 0.3151922, -0.0045984816,
+    -0.041819472, 0.016610991, 0.0424069, 0.009946593, -0.04929106, 0.04441617, 0.11243812, -0.017155686, -0.14709388, -0.12222281, 0.20219164, 0.0068945605, 0.13907939, 0.09657055, -0.012060342, -0.0477924,
+    -0.18494269, -0.028179651, -0.0021806792, 0.11357306, -0.123145856, -0.18978812, 0.0067202267, -0.026695345, 0.058567885, -0.011703786, 0.019038875, -0.05255687, -0.18907371, -0.06690733, 0.15635316, -0.021298299,
+    0.12555148, -0.010833709, 0.07012774, 0.11839211, -0.13107409, 0.14749776, -0.096445985, -0.18335848, -0.06765109, -0.1397024, 0.05865305, 0.07409498, -0.052916005, 0.14654605, -0.027513761, -0.08734275,
+    -0.09452483, 0.08471228, 0.05943698, 0.052535117, -0.08553413, 0.08811475, 0.021864533, -0.2301211, 0.09781012, -0.027717454, -0.13161252, 0.03569108, -0.070590936, -0.09350826, 0.17080922, 0.028425025,
+    -0.1646885, 0.06141775, -0.022138057, 0.11856532, -0.12609464, 0.1533054, -0.055177372, 0.117135875, 0.040786996, 0.06371722, 0.16707157, -0.069236934, -0.12987863, -0.042725496, -0.15904357, 0.039787192,
+    -0.07916947, -0.08665958, -0.051067144, -0.010217092, -0.013687257, -0.016758557, -0.08842834, -0.1053038, 0.08448815, -0.01839086, -0.06767186, -0.18931837, -0.14752986, 0.045769975, 0.19888528, -0.07030012,
+    0.025766049, -0.006955628, 0.1349797, 0.066442885, -0.08489145, 0.13172045, -0.03140161, 0.015390773, -0.16186313, -0.075522766, 0.032731682, -0.005086372, -0.044822395, -0.20383471, -0.14877197, -0.02345259,
+    -0.10529652, -0.0002541747, -0.03911785, -0.18141602, -0.07895615, -0.017915096, 0.03593534, -0.016027523, -0.16134924, 0.09175364, 0.03156226, -0.02019795, 0.012715682, 0.06710353, 0.101639986, -0.15046777,
+    -0.06515039, -0.06735994, 0.09940587, -0.03534464, -0.051647108, 0.12494746, 0.1125068, 0.14180139, -0.08986416, -0.041657396, -0.09132371, -0.10707828, -0.12778147, 0.005929652, -0.078065865, 0.13868819,
+    0.07259822, -0.048333485, -0.012552556, 0.016064737, -0.06176079, -0.011069368, 0.0016776976, 0.12436039, 0.09653874, 0.03304544, -0.15466313, -0.03552194, -0.015631136, 0.079791225, -0.022334348, -0.15074153,
+    -0.19794229, 0.078466415, -0.076254316, -0.16684744, -0.09944044, -0.025071852, 0.24464077, -0.015721591, -0.0825324, -0.07479459, -0.041868903, 0.0104643, -0.071876876, -0.034049127, -0.16599724, -0.034240983,
+    0.112337396, -0.043139804, 0.049898334, 0.017707657, -0.08445488, -0.09115718, -0.120375395, 0.07108814, -0.13108209, 0.043217406, 0.030539224, -0.09081259, -0.008621232, 0.05668875, 0.13983019, -0.23642716,
+    -0.17429528, 0.14268984, 0.0756114, 0.0836949, 0.0157776, 0.05011504, 0.14918251, 0.057484023, -0.04514533, 0.11844498, 0.19309519, -0.09405529, 0.04785639, -0.1150913, 0.06910231, 0.050362,
+    0.024127945, 0.1425913, 0.0903975, 0.09328599, -0.115102485, -0.16030632, 0.089419976, 0.104094714, 0.0046247253, 0.056549627, -0.044273887, -0.094489135, -0.05824847, 0.03837598, 0.022399407, -0.109890506,
+    0.0030852223, 0.014351617, 0.047606193, -0.11913162, 0.10858658, 0.08532555, -0.07934859, -0.01522868, -0.11910644, -0.11435318, 0.10302349, 0.07059259, 0.14819938, 0.07630558, 0.120012596, -0.07928697,
+    0.05589247, 0.108041786, -0.071475275, -0.061829682, -0.009030323, -0.132233, 0.11574148, 0.07103427, -0.080620386, 0.02898072, 0.05873201, 0.08018861, -0.15514258, -0.065895624, 0.06453648, -0.060753375,
+    0.11339119, 0.036588218, -0.08537106, 0.09620569, -0.060491372, 0.043141954, 0.00033731, 0.038056217, -0.15552954, -0.056291007, 0.21639319, 0.13764954, 0.007191687, 0.072806895, -0.011126293, 0.09756259,
+    -0.06133168, 0.06988048, -0.061257884, 0.09380384, 0.029337263, 0.04071661, -0.0705977, 0.12603106, -0.1316238, -0.11079583, -0.15085568, -0.06987663, -0.2053932, -0.13593313, 0.22737792, 0.070473395,
+    0.10584533, -0.008597941, -0.0012118332, 0.11686394, -0.096843794, -0.021905659, 0.045807015, -0.0769671, -0.14794461, -0.0762489, 0.2530035, 0.1461998, 0.11307162, -0.08824086, -0.13575916, -0.028017804,
+    -0.06754613, 0.065983824, 0.07769439, -0.13502394, 0.12401987, -0.15150294, 0.034736414, 0.09975129, -0.049697347, -0.08092659, -0.08472878, -0.05268448, 0.0060712444, -0.12771048, -0.06417719, 0.109120466,
+    0.09908554, 0.08706492, -0.04089147, 0.010242616, -0.07098133, 0.10841546, -0.028219754, 0.012008787, 0.021674596, -0.002051002, 0.17707683, -0.027469236, 0.12624845, -0.12923391, 0.13447134, 0.13199422,
+    -0.20560439, 0.0902878, 0.013241696, 0.10471542, 0.08751707, -0.15798669, -0.0037107912, -0.030606354, 0.10208574, -0.04373456, -0.09401459, 0.070396274, -0.045958925, -0.02198836, 0.2443666, -0.034893364,
+    -0.13538183, -0.14195564, 0.07148449, 0.025116483, -0.068351, 0.06194028, 0.096537285, -0.10002795, -0.121291496, -0.004079316, 0.2883576, 0.025672594, 0.11481904, 0.1378906, -0.07021171, 0.07358744,
+    -0.03199279, 0.049555402, 0.12372116, -0.12265654, -0.015243526, -0.028795172, 0.23210372, -0.11187438, 0.1218822, -0.03939363, -0.11392634, 0.11315215, -0.07275359, -0.11858216, 0.19429795, -0.12619051,
+    -0.13815796, -0.03503469, -0.16370109, -0.070266046, 0.10362723, 0.14973539, 0.076344505, 0.120940015, 0.12828095, 0.097336784, 0.047874972, 0.026059052, 0.10675766, 0.1320174, 0.04596034, -0.0290266,
+    0.06484682, 0.12577586, -0.12038129, 0.09412174, 0.06869933, -0.13969879, 0.046138845, 0.025519522, 0.07991044, -0.15419069, 0.108927704, 0.04452187, 0.09737358, 0.14739518, 0.30640444, -0.058696687,
+    -0.09042612, -0.1409285, -0.06602456, 0.10662819, 0.14476438, 0.03509325, -0.01742922, -0.151799, 0.000118203694, -0.03735878, 0.18292846, -0.03539455, 0.07556441, -0.016804535, 0.1542533, 0.13639209,
+    -0.17670645, 0.052315768, -0.05682146, -0.087724015, -0.030199008, -0.1373966, 0.2436974, -0.17146064, 0.056970034, 0.09921371, -0.029790571, 0.038004737, -0.09248825, 0.116079114, 0.28365514, -0.11049424,
+    -0.044051036, -0.009285368, -0.03604468, 0.018262977, 0.12059035, 0.02300681, 0.0010369844, 0.023294505, -0.09174795, 0.0058232425, 0.25241584, -0.058678523, -0.13730022, -0.096677534, 0.0017795593, 0.024234196,
+    -0.07494636, 0.06505801, 0.021051932, 0.07132684, 0.11862507, 0.088414654, 0.118076116, 0.07436561, -0.047311068, -0.081029475, 0.22464825, -0.06845522, 0.023726365, -0.13080746, 0.08387241, -0.13215274,
+    0.17554413, 0.14677869, 0.12926097, -0.023956493, 0.104232125, 0.023179939, 0.06277865, -0.088415794, 0.06263791, -0.115020804, -0.19743936, -0.031643376, -0.049019605, 0.012869985, -0.065335035, 0.009627,
+    0.07213994, 0.19294883, -0.12640558, -0.032630123, 0.028846078, -0.07373899, -0.032167964, 0.031350933, -0.08019231, -0.05450958, 0.00017424289, -0.09983893, 0.19551009, 0.10329996, -0.019871663, 0.011601388,
+    0.043289986, 0.10305527, -0.05165549, 0.1395912, 0.11158972, 0.063119635, -0.0534199, 0.06465735, 0.10303069, -0.05505209, -0.11418236, -0.011579186, -0.026696533, -0.056672204, 0.02107735, -0.0018999825,
+    -0.12369986, 0.045578003, 0.10107637, -0.05201292, -0.054785557, 0.08719054, 0.114151314, 0.06890141, -0.10899385, -0.016953807, 0.1040757, -0.11119456, 0.011432141, -0.07047792, -0.16730441, 0.10628225,
+    -0.048124593, -0.06647803, -0.010433464, -0.116601594, 0.07462359, -0.015354206, -0.20556222, 0.08650815, -0.03726958, -0.051786195, 0.043775063, 0.0014792383, 0.032488137, 0.08327323, -0.14504372, -0.08332777,
+    0.059217144, 0.022251243, -0.046800416, 0.083489195, -0.017510388, 0.128854, -0.1403915, -0.082127415, -0.046326473, -0.09114647, 0.14553167, -0.12095608, -0.08051916, -0.057515368, 0.007779129, -0.13337535,
+    -0.093754694, 0.060070757, -0.11786249, -0.09139223, -0.07573438, -0.18507281, -0.17176582, -0.079228945, 0.13736175, 0.145288, -0.19887565, -0.03400337, -0.030073501, -0.037718747, -0.105431095, -0.20135848,
+    -0.14418647, -0.047044244, 0.10959616, -0.14692777, 0.12405173, 0.0131509835, 0.047554772, -0.17430058, 0.059529476, 0.017953696, 0.012828139, -0.118713394, -0.14590703, 0.03388825, 0.33008945, -0.0422737,
+    -0.17212799, 0.005467325, -0.10033722, -0.07293035, 0.037429973, 0.039111417, 0.11557687, -0.10143212, -0.045878142, -0.12246076, 0.25860387, -0.04456015, -0.023971532, -0.15102999, 0.061256487, -0.04073317,
+    -0.19820826, -0.12692843, 0.1144661, 0.086771294, -0.088662386, 0.06232666, 0.24211551, -0.032452557, 0.12635916, 0.10642301, 0.12834775, -0.04291585, 9.857157e-5, 0.084076345, 0.12704736, -0.14254203,
+    0.038730875, -0.024020566, 0.047997523, -0.1239726, 0.13948584, 0.11210818, -0.05968417, 0.09665059, 0.025628276, -0.040762834, 0.20631056, 0.108498216, 0.032010753, -0.118652605, 0.01601131, 0.08540558,
+    -0.16750063, -0.011721848, -0.059174452, -0.023449123, -0.1508198, -0.17278181, 0.043399815, -0.121832594, -0.15385439, -0.15047616, -0.05553667, -0.13231082, -0.029972263, 0.014027268, 0.16393511, -0.04958331,
+    0.08761483, 0.09770704, 0.03319479, 0.017333642, -0.10422144, 0.03993595, -0.07589982, 0.011385387, -0.14191568, 0.09361538, 0.24215843, 0.09612262, -0.04977783, 0.04243002, -0.059822205, 0.1302836,
+    -0.15952879, -0.0067186547, 0.017023472, 0.045285396, 0.0025492345, -0.14767428, 0.140776, -0.14819574, -0.10059681, -0.052431032, -0.054797206, -0.095982656, -0.09978628, 0.08052677, 0.21254633, -0.15680254,
+    -0.17100747, -0.16847327, -0.10176483, -0.08951694, -0.067335755, 0.1334704, 0.0024526804, 0.0437267, 0.053322017, 0.050416566, 0.14190647, -0.11860281, 0.070758946, 0.06947968, 0.15269616, -0.051019702,
+    0.026910273, 0.01670258, -0.032632526, 0.065665975, -0.075641, 0.12286216, 0.0743868, -0.05676668, -0.03140214, 0.035142947, 0.04891137, 0.13340156, -0.079024084, -0.107236326, 0.009085411, 0.044371814,
+    0.18060502, -0.15145259, -0.026119612, 0.07664813, -0.07299201, -0.19090882, -0.09411289, -0.06966259, 0.16048127, 0.0852223, 0.01372802, 0.14591698, -0.12607177, 0.06947323, 0.04056926, -0.17326102,
+    -0.13711768, 0.19827704, -0.10551305, -0.012521699, -0.12625116, 0.1123327, -0.1632987, 0.13585833, -0.08892427, 0.131883, -0.0007865891, -0.03972537, -0.09317174, 0.11332746, 0.066496916, -0.1042694,
+    0.1876468, 0.027942969, -0.0622353, 0.04361771, -0.065463476, -0.22008185, -0.0058167228, 0.07907393, 0.093229644, 0.040591538, 0.029957144, 0.039541915, -0.099771515, 0.09117563, 0.08248299, -0.13513009,
+    0.13390808, 0.061140597, 0.027983198, -0.04504951, 0.017009212, 0.11440927, -0.17174068, -0.04203821, -0.046936907, 0.12008024, 0.041428335, 0.029885411, 0.062240772, -0.054569982, 0.034589037, 0.05437909,
+    0.12898208, 0.1424981, -0.08851535, 0.14066957, -0.02930196, -0.2232464, -0.16419192, -0.15651476, 0.056266733, -0.019253321, 0.06280397, -0.064411975, 0.1142443, 0.1645275, -0.0664353, -0.026430817,
+    0.13085712, -0.02440697, -0.090374365, -0.07409166, 0.118293054, 0.09343314, -0.11664019, -0.05497846, 0.1481102, -0.0830174, 0.12696429, 0.13996671, 0.14948222, -0.06227252, 0.008930327, 0.03688857,
+    -0.06633274, 0.045382235, 0.14285386, -0.15193026, -0.1580639, -0.17995623, -0.04784502, -0.1157909, -0.06342462, 0.08294224, -0.09498723, 0.099362776, -0.09510481, 0.229486, -0.0154094845, 0.018000556,
+    -0.18083107, 0.047019552, -0.122990265, -0.13027096, -0.10241119, -0.122335434, 0.22282669, -0.18598539, 0.13528655, -0.009448128, -0.22740485, -0.078204624, -0.17795864, 0.13857086, 0.2865973, -0.16873668,
+    -0.15648082, -0.043927815, 0.02255266, -0.07897516, -0.016469598, 0.2036233, 0.038426466, -0.14478013, -0.04101185, -0.0993367, 0.11327052, -0.030134156, -0.13186732, -0.022171678, 0.025348326, 0.07872806,
+    -0.03676471, -0.09023308, 0.041570757, -0.0131660625, 0.07228302, -0.17436606, 0.2741862, 0.044052277, -0.15638635, 0.02606507, -0.14683552, 0.042223267, -0.1102282, 0.14245097, 0.25028956, -0.06568871,
+    -0.09828409, 0.028435739, -0.013480276, -0.08528604, 0.15864116, 0.23825762, 0.2086132, -0.11658492, -0.099918075, -0.113670796, 0.3536166, 0.10785416, 0.08771942, -0.04764725, 0.16820604, 0.17875375,
+    -0.15597561, -0.013453592, -0.08226265, -0.11900109, 0.07896312, -0.11236429, 0.05388826, 0.039730635, -0.15326458, -0.02717563, -0.012836108, -0.1098538, -0.03930189, -0.106793925, 0.27495182, -0.096460804,
+    0.08833622, -0.14811547, -0.01159968, -0.049581565, 0.03745817, 0.12201927, 0.093493536, 0.06266407, -0.15022431, -0.085594565, 0.36262432, -0.042474423, -0.022268377, -0.19009939, 0.08695231, 0.15625744,
+    -0.023051834, -0.20822419, 0.16471726, -0.16235614, -0.012803071, 0.005487204, 0.07827392, -0.110596545, -0.04567048, -0.14876853, 0.003385062, 0.071439944, 0.022144338, 0.08626137, 0.34754995, -0.14742419,
+    0.018148866, 0.07232791, -0.1574405, 0.024437124, 0.12153451, -0.018044801, 0.10349831, 0.0057674944, -0.076307155, -0.039119463, 0.23635268, -0.13604802, 0.13954614, 0.03767481, 0.090760775, 0.012699688,
+    0.041050624, -0.005987012, -0.06773628, -0.1585667, 0.00014759775, 0.07204053, -0.021937143, -0.02252573, -0.09468375, 0.023538707, -0.071770854, -0.0931371, 0.059420478, -0.017109523, -0.036632482, 0.04791982,
+    -0.17257893, -0.0020413394, 0.107957624, 0.07615348, -0.06681632, 0.10652594, 0.20980196, -0.0439968, -0.030370543, 0.03666536, 0.19962125, -0.14166003, -0.073333465, -0.1878264, -0.02677753, -0.1044042,
+    -0.0009642658, -0.1850038, -0.031641133, 0.007997823, 0.123246446, 0.012909455, -0.02458499, -0.09580001, 0.09257311, 0.053205695, -0.15077494, -0.122445166, 0.066251114, -0.029316125, 0.018250972, 0.08267278,
+    -0.02198258, 0.104334846, 0.0021328896, -0.17325425, -0.113428555, -0.02252651, 0.20057704, 0.11126519, 0.026557887, 0.1402331, -0.08168334, 0.03730713, -0.18121856, -0.1086591, 0.08517919, 0.1300505,
+    0.13555607, -0.040910892, -0.058623645, 0.0050174724, -0.09836805, -0.024506753, 0.11813106, 0.10359724, 0.038245533, 0.07143163, -0.16110218, -0.2145203, -0.15186402, -0.025042383, -0.009337952, 0.07724469,
+    0.034842417, -0.027918, 0.1382663, -0.093663014, 0.017205559, 0.13735527, 0.20479716, 0.03538886, 0.023076205, -0.091257736, 0.009543789, -0.19031373, -0.07288338, -0.23933607, -0.023620794, -0.07497168,
+    -0.05101153, -0.17845906, 0.08151136, 0.07631613, 0.10038273, -0.11037871, 0.13970426, -0.09222311, -0.05694076, 0.0027643284, -0.22771078, -0.10783745, 0.044478856, -0.017036488, 0.17676376, 0.0159768,
+    -0.21026427, -0.029458273, 0.122699775, -0.04085051, -0.049300108, 0.102804616, -0.07948164, 0.042043913, -0.11728853, 0.10193089, 0.09768936, 0.091622315, -0.0034971996, -0.2217373, -0.13880768, 0.061055966,
+    0.10126825, 0.06281219, 0.1270125, 0.03502866, -0.07292203, 0.1364616, 0.1408674, 0.08463965, -0.10176482, 0.0633716, -0.24173348, -0.057914477, -0.12637925, 0.04953681, -0.018533565, 0.071713455,
+    0.08741531, 0.047089767, -0.008103012, -0.065189466, 0.0029246144, 0.27112377, 0.03378664, 0.09601494, -0.16486341, 0.060849663, 0.16065523, -0.11712742, -0.09306285, 0.021037059, 0.1639689, 0.094337195,
+    0.059915844, -0.09374356, 0.15433526, -0.03366033, -0.047357246, -0.023022365, 0.061104517, -0.08575907, -0.089350305, -0.013259329, 0.05499979, 0.058977507, -0.07730401, -0.10405873, 0.1559112, -0.05919967,
+    -0.1241851, 0.081775114, 0.14035694, -0.10079585, 0.15250626, 0.08461691, 0.25010723, -0.018547142, 0.056989413, -0.0019140723, 0.20365573, 0.03346614, 0.0036529026, -0.19268748, 0.0631053, -0.0014365946,
+    0.106662974, -0.18550864, 0.19390723, -0.12993847, -0.08758687, 0.045142658, 0.17968197, 0.02322628, 0.116825886, -0.11755219, -0.004861544, -0.23188488, -0.10613165, -0.11570984, 0.08341625, 0.08356518,
+    0.03590159, -0.121000096, 0.03918241, 0.013279283, 0.053286433, 0.15449977, -0.020395396, -0.03261148, -0.19985445, 0.06685529, -0.019759081, -0.071701944, -0.07284845, -0.21643418, -0.010950688, -0.011527938,
+    0.023742989, 0.026478838, -0.080315754, -0.19656746, 0.043355525, -0.14665005, -0.016954893, -0.14443055, -0.03128261, 0.031041488, 0.047125746, -0.08205267, -0.08395576, -0.08398846, 0.08976768, 0.07430153,
+    0.088976935, 0.08550353, 0.022557594, -0.098594725, 0.12614119, 0.005900651, 0.2635933, 0.15541586, -0.1346516, 0.08670851, -0.031545382, -0.052600015, 0.07391894, -0.19751602, -0.07883109, 0.027746152,
+    -0.14454162, -0.1908368, 0.18351774, 0.011538068, 0.017044038, -0.12755878, 0.23619525, 0.023077652, 0.06602608, -0.03289962, -0.006994083, 0.09088626, 0.03802569, -0.0097083, 0.20657401, 0.040534902,
+    -0.17064357, -0.018036636, -0.059865583, -0.12674873, 0.029638482, 0.106122546, 0.18876663, 0.16506657, -0.08055142, -0.1049164, 0.161052, 0.037481148, 0.0037817725, -0.089070186, 0.043546546, 0.16805345,
+    0.028823646, -0.059441876, 0.11217518, -0.082313776, -0.09958218, 0.09541665, 0.1173487, -0.056839954, 0.03863544, 0.115823545, -0.08927501, 0.019209852, -0.07479726, -0.027886476, 0.16950057, -0.21633546,
+    -0.15344922, -0.11339963, -0.044798236, 0.13211769, 0.11451357, 0.20217477, 0.13226539, -0.09151974, -0.024407435, 0.11045734, 0.20854478, -0.1190872, -0.05965509, -0.056789044, 0.10018205, 0.05839562,
+    -0.027759852, 0.06555384, 0.11117682, -0.1860343, -0.08288602, 0.0037958315, 0.037564736, -0.1802623, 0.12054818, 0.13754086, -0.07803061, -0.10806956, -0.15426728, 0.004181728, 0.21985547, -0.026855126,
+    -0.0769078, 0.15444908, -0.024683574, -0.035944443, 0.05228246, 0.2015356, 0.09243118, 0.17906308, -0.11799463, 0.027113648, 0.1878847, -0.036645252, -0.07294742, -0.087514326, 0.10918746, 0.0810639,
+    0.13061047, -0.17448892, 0.06639151, -0.12020968, -0.004277462, -0.119907916, -0.026205683, -0.12597194, -0.14943975, -0.014193246, -0.17015728, -0.040972978, -0.09529276, 0.03264348, 0.1382866, -0.21035261,
+    -0.146957, -0.021653833, -0.049197037, -0.10765626, -0.0863929, -0.050732147, 0.085687034, -0.09500052, -0.13199066, -0.009608014, 0.20226161, 0.056175437, 0.027389297, -0.21834825, -0.01413933, -0.05214039,
+    0.13946886, 0.14049847, 0.09232123, -0.12556708, 0.08670749, -0.010707051, -0.022881776, -0.1467667, -0.14929578, -0.07574716, -0.14462039, -0.14150618, -0.05132003, 0.07633412, 0.007715896, 0.026337594,
+    -0.109070025, 0.05410011, -0.14871907, -0.02705063, 0.13019516, 0.11222099, 0.04173766, -0.10912292, 0.123938814, -0.09821675, -0.085118964, 0.038194403, -0.044884093, -0.106774636, -0.11874291, 0.09965001,
+    0.031086639, -0.106684774, -0.029876292, 0.0077802837, -0.099288434, -0.10867633, 0.0013344437, 0.021381289, -0.018097192, 0.11725439, 0.07390466, -0.107909754, 0.08556767, 0.14587821, -0.082622536, -0.072105974,
+    -0.08485653, 0.03915812, -0.10091865, 0.09448807, 0.01297164, 0.024905175, -0.053946704, 0.14797454, -0.09098397, 0.11489414, -0.08292607, -0.12867814, -0.038824104, -0.08959499, -0.1303165, 0.027705073,
+    -0.055316776, -0.14455082, -0.002167195, 0.08878608, -0.027755484, -0.13034496, -0.13684376, -0.11287503, 0.06088963, -0.02218017, -0.10109447, 0.046103597, -0.085204706, -0.10396883, -0.0026366264, -0.031798683,
+    0.123791054, -0.14326338, 0.109269276, 0.1383156, -0.111842945, 0.12611015, 0.048287734, 0.11238603, 0.09067522, -0.0859407, 0.0066440254, 0.11648907, -0.13389239, -0.11693969, -0.105471104, -0.122976586,
+    -0.11997801, -0.14282425, 0.12818356, 0.11282207, 0.09324129, 0.1504461, 0.11819555, -0.1071531, 0.00048786402, -0.09218016, 0.03390923, 0.12537421, -0.07950476, 0.018393204, 0.12297936, -0.06141235,
+    0.041095227, 0.035834044, -0.1350078, 0.015984192, 0.10718076, -0.08583642, -0.06972692, 0.0076223463, -0.049093373, 0.0067489296, -0.021773785, 0.11594637, 0.045505553, 0.025744021, -0.115086325, -0.06655816,
+    -0.06753968, -0.11308813, 0.14475149, 0.08714476, -0.006534062, -0.09264839, 0.10223511, 0.03678199, -0.09341781, -0.026177913, 0.118853375, 0.10404198, -0.20422433, -0.1433198, 0.12318904, 0.09558542,
+    -0.031759292, -0.020798218, 0.1394077, -0.016621253, 0.00012055343, -0.07741391, 0.0111967055, -0.026836494, 0.03265308, 0.10958333, -0.007365209, 0.12795058, 0.11276394, -0.06376654, -0.08148029, 0.027070109,
+    0.13007042, 0.028364947, -0.12864646, 0.04269927, 0.03405082, 0.08314063, 0.10785243, 0.031141099, 0.09551096, 0.057031706, 0.0606365, -0.12089272, -0.12833545, -0.043033965, 0.081629746, 0.13903314,
+    0.06162113, 0.1401023, 0.07254616, -0.06858429, 0.12351338, -0.052089907, -0.031107482, 0.04487834, 0.06676412, 0.024555437, -0.019985853, 0.069691, 0.0058432356, -0.089136876, 0.1382357, 0.059724547,
+    -0.123726115, 0.0811227, -0.107567385, 0.02369685, 0.13470098, 0.048955183, -0.11049214, -0.0721082, -0.09790179, -0.02594085, -0.119941026, -0.12826727, -0.18080318, 0.031772308, 0.0057579908, -0.040236104,
+    -0.059181895, -0.03465529, -0.11020329, 0.1400973, -0.14726703, -0.08446539, -0.08485445, 0.13842861, -0.16863458, 0.12678136, 0.06419095, -0.10511282, -0.13209355, -0.14809662, -0.024724497, 0.13941735,
+    0.06159821, -0.093737416, 0.1284633, 0.00481003, -0.054789167, 0.103007406, -0.120202884, -0.015632944, -0.036827397, -0.13325109, 0.09114893, -0.12516788, -0.07146081, -0.040264107, -0.1108126, 0.12823898,
+    0.10859302, 0.07658105, 0.11856034, -0.044279255, 0.06712086, -0.08939198, 0.027764982, 0.043248825, -0.15783486, -0.03565822, 0.011973255, 0.052332096, 0.09904227, -0.1042344, -0.13232838, -0.051377736,
+    0.034169666, 0.03677371, 0.17108786, 0.08841657, -0.08649136, -0.14459126, 0.051958285, -0.26876274, 0.041347526, 0.07639921, -0.19107302, -0.10709322, 0.038519572, 0.07468801, 0.17707382, -0.15858312,
+    0.070224315, -0.037884302, 0.06798227, -0.13309911, -0.017193932, 0.04825392, 0.066026576, -0.026245292, -0.010250727, 0.022950599, 0.22342783, -0.015091406, -0.07440971, -0.23090123, -0.071180955, 0.16375886,
+    0.073346294, -0.18292767, 0.13170275, -0.114774026, -0.051775955, -0.11355142, -0.046224676, -0.052959334, -0.11877517, 0.14630534, 0.029097492, 0.048310503, -0.13659781, -0.10386611, -0.0031516794, -0.07189505,
+    -0.13094212, -0.003025641, -0.09794718, 0.072009854, -0.021514706, -0.025677772, 0.119790204, 0.1198297, -0.1797436, -0.14369026, 0.2054848, 0.121471904, -0.016862605, -0.21536048, -0.07957448, 0.11009147,
+    0.07549582, -0.21513596, -0.08694767, 0.062347084, 0.02175225, 0.12940098, -0.041792966, -0.21540405, -0.1002449, -0.07551901, -0.032357585, 0.0068599093, -0.17865455, 0.096844174, -0.005916942, 0.036950383,
+    -0.094732575, -0.14937672, 0.1012571, -0.04080359, 0.020345006, 0.18339565, 0.116098754, -0.00854906, -0.1886395, 0.003729243, 0.09895275, 0.0119765345, -0.099957034, -0.04072337, 0.055993155, -0.09786595,
+    -0.11523006, -0.17493773, -0.060943745, -0.15551047, -0.0839155, 0.06853682, -0.034769382, -0.18042107, -0.11434338, 0.0709196, -0.10708133, 0.051671743, -0.0959351, 0.035418246, 0.03173027, -0.11353077,
+    -0.04208194, 0.017634768, -0.12715885, 0.05759307, 0.009603928, 0.21853536, 0.12574159, -0.10601748, 0.06279649, 0.015092316, 0.087983094, 0.15443133, -0.14008647, -0.2374896, 0.014282015, 0.009533304,
+    -0.06636211, 0.029297024, -0.1320227, 0.02517797, -0.0383119, 0.008226603, 0.062026903, 0.022802323, -0.08725033, -0.064882174, -0.14766735, -0.0711884, -0.12072474, 0.12828712, -0.06578139, -0.13843462,
+    -0.059253834, 0.08964266, 0.045696482, -0.120178, 0.099356845, -0.00448519, -0.13859183, -0.13080147, -0.10752183, -0.1516515, 0.019896716, -0.15117061, -0.09068303, 0.00043344498, 0.095733315, 0.018305644,
+    -0.1305038, 0.056997716, 0.0021169484, -0.0352815, 0.09825556, 0.074263826, 0.042590156, -0.08590708, -0.10564068, -0.02563785, 0.07294774, 0.091715366, -0.072926715, -0.06236928, 0.09473112, -0.13451941,
+    0.13189371, -0.111833714, 0.023033738, -0.06751245, 0.062214077, -0.07710399, 0.06728567, -0.051677734, 0.0136116, -0.12603486, 0.15220846, -0.046443857, -0.15154284, 0.015290499, 0.12851302, -0.14380515,
+    0.12461032, 0.09698446, -0.050273135, -0.093673095, -0.14879766, -0.011157364, 0.032561764, 0.09564231, 0.017133906, 0.14804934, -0.03481003, 0.101431355, 0.03317456, -0.07897149, 0.15298577, 0.14212407,
+    -0.1419605, -0.06608883, -0.14314385, 0.013654381, -0.13359888, -0.14674802, -0.020733342, -0.12865642, -0.091492385, -0.07837887, 0.09650868, 0.049597844, -0.006980121, 0.121886745, 0.13173647, -0.07844249,
+    -0.0031561702, 0.11332597, -0.060127728, -0.12990393, 0.13090746, 0.12998144, -0.13833143, -0.02710709, 0.0050011873, -0.11494157, 0.12434192, -0.061404794, -0.07322018, -0.0018495023, -0.02730769, -0.024548754,
+    -0.10759976, 0.12854151, -0.02000618, 0.012662739, -0.12154661, 0.057218686, 0.07055034, -0.058809705, 0.04456216, -0.10737806, -0.15139654, 0.123129204, -0.11190493, -0.07544366, 0.09796761, -0.14276062,
+    0.11709506, 0.067165874, -0.06341165, 0.09758422, -0.028478345, -0.062989324, -0.018507484, 0.12394241, 2.5835003e-5, 0.0058435765, -0.1562372, 0.1472278, -0.16783167, -0.09492592, -0.19921435, 0.030008346,
+    0.09448939, 0.11577869, 0.07372639, 0.023048053, -0.09848593, -0.045580287, 0.03675793, -0.086662054, -0.1661819, -0.054044757, -0.12534054, -0.03185248, 0.079098985, 0.05570652, 0.035437956, 0.10050376,
+    0.12352316, -0.0491852, 0.008818608, -0.012154802, 0.014105819, -0.015771223, -0.16090737, -0.13621226, 0.018743547, 0.15031235, 0.02648827, 0.16225228, -0.15738392, 0.12553263, -0.060519002, 0.044062607,
+    -0.027971808, 0.025390632, 0.042503856, 0.046326954, 0.004218029, -0.016550887, 0.114550084, 0.052777655, -0.16320437, 0.06761103, -0.16672973, 0.075655475, 0.09357199, 0.02752438, 0.13667391, -0.021274177,
+    -0.021562893, -0.067038946, -0.13846512, 0.020470103, -0.04421704, -0.031099135, -0.1435813, -0.00777578, -0.0076893806, 0.047250878, -0.08186797, 0.031879496, -0.16454343, -0.017232787, 0.04869436, -0.13804153,
+    0.05070332, 0.056257095, 0.14228736, -0.016705966, -0.06511839, -0.078448705, -0.13256806, -0.11014383, -0.13129155, -0.09398026, -0.072091885, -0.03716079, -0.1202606, 0.134008, 0.07302357, -0.15764286,
+    -0.026218895, -0.13103394, 0.11503836, 0.042739436, -0.00061344256, -0.09504701, -0.10405742, 0.008381444, 0.15177348, 0.03178383, 0.033439834, -0.09189268, 0.011597556, 0.11081044, 0.038432933, -0.07812827,
+    -0.019800726, -0.04897256, -0.06813425, 0.08771075, 0.09269965, -0.03492875, 0.008390523, -0.023153448, -0.091405414, 0.12908061, -0.14061372, -0.06998198, -0.13990714, 0.010314737, 0.033465125, 0.0855104,
+    -0.12773529, -0.09463501, -0.10664504, 0.091955446, 0.14684129, 0.13760294, -0.041956015, -0.061891057, -0.051932152, 0.036836322, -0.16362414, -0.11907373, -0.20278074, 0.12517622, -0.13675566, 0.06958006,
+    -0.023270266, 0.09988494, -0.0656965, -0.04421906, -0.027324999, -0.16030435, -0.09121339, -0.0068982826, 0.058874704, -0.038761657, -0.019700496, -0.094291866, 0.03180161, 0.011921572, -0.13216026, 0.027326021,
+    -0.08020668, 0.029119395, 0.024810117, -0.123642206, -0.07608654, 0.14776607, 0.04652743, -0.13789026, 0.11204988, -0.0917089, 0.0005251377, 0.12004424, -0.067454256, -0.07877841, -0.14717466, 0.13963258,
+    -0.001563074, 0.116647765, 0.0034588077, 0.14705499, -0.1255429, -0.0040605334, 0.12979464, 0.06286294, -0.06206345, 0.023114905, -0.08331372, 0.1160858, 0.090765275, -0.082532525, 0.107485406, -0.083549015,
+    0.05773845, -0.14550516, 0.03400391, 0.023657812, 0.075133525, -0.048229467, 0.028770471, -0.046386607, -0.039707962, -0.062073026, -0.15166041, 0.16055156, 0.035658758, -0.0010234643, 0.1131254, -0.05643535,
+    0.12158994, -0.116012454, 0.0942995, -0.08002913, 0.009344982, 0.03514115, -0.029105242, 0.11037714, -0.02058924, -0.009502214, 0.015016182, -0.028892208, 0.13450027, 0.036912896, -0.022495842, 0.07195746,
+    0.13740763, 0.075551875, -0.14640976, 0.037460707, 0.12713513, -0.020153103, -0.012423158, -0.14481924, -0.12227605, 0.06861119, 0.014165244, -0.068105854, 0.04085511, 0.02962668, 0.10019292, -0.014525002,
+    0.105714105, -0.026861133, -0.13922104, -0.0870724, -0.05114749, -0.011462139, 0.08795795, 0.05533105, -0.028837245, 0.14876373, 0.10904172, 0.075656146, 0.025557622, -0.14876798, 0.122962795, 0.038667087,
+    0.14523633, -0.11843559, 0.0062842797, -0.12528801, -0.06575417, -0.06029012, -0.0950369, -0.120468065, 0.055918425, 0.062086347, -0.0927638, -0.104061276, -0.12828413, -0.08088761, 0.067973986, 0.00033763645,
+    0.06049101, 0.02046685, 0.09592399, -0.06452544, 0.040445194, -0.034740884, -0.10080733, -0.0270495, -0.06283838, -0.11159718, -0.15958941, 0.1209664, -0.033636354, 0.009265482, 0.0895639, -0.056313448,
+    -0.06427593, -0.05252122, -0.14420798, -0.0949365, 0.057511892, 0.01589365, 0.086497426, 0.03041707, 0.12079544, 0.046808306, -0.13233739, 0.086086035, -0.016225412, -0.1389839, -0.10384218, -0.033250198,
+    0.112286165, -0.14838052, 0.0016512661, 0.10088717, 0.14643182, -0.10342956, 0.12684591, 0.0188643, -0.036465637, 0.00014002346, 0.0055585233, -0.13512152, -0.07298232, -0.11643585, -0.080239795, -0.060522288,
+    -0.089972496, 0.08784506, -0.046653073, -0.14813732, 0.06078743, 0.10114114, 0.07181114, 0.09322232, -0.10063346, 0.13729656, 0.018961623, -0.07202476, -0.06966706, 0.100331865, 0.13368781, 0.1464107,
+    -0.11095056, 0.07086876, 0.026048385, 0.037892666, 0.1449474, 0.092770346, -0.04257654, -0.0011350022, -0.0689417, -0.1193802, -0.09376967, 0.14385027, -0.12186443, 0.034556314, 0.11078813, -0.031154234,
+    0.025432404, -0.13505426, -0.089194864, -0.14264505, 0.008407912, -0.060446925, -0.087432005, -0.07570716, -0.09963718, -0.15717243, -0.09380402, -0.08673436, 0.09556523, -0.054351073, 0.13281469, 0.09074011,
+    0.10531433, -0.1065315, -0.07569785, 0.0814615, 0.11254551, 0.14383748, -0.025501486, -0.15004912, 0.039075553, -0.11710294, -0.13423882, -0.10824457, 0.043064035, -0.09228207, 0.12588538, 0.004132875,
+    -0.0254781, 0.11027866, 0.027686091, -0.08742498, 0.1444695, 0.13235654, 0.0054233647, 0.1625511, -0.100232035, -0.01102705, 0.15849203, -0.14595483, -0.04366008, 0.09316932, -0.17183115, -0.106538475,
+    0.020549512, -0.09869822, -0.12216496, -0.09133912, -0.07039411, -0.18223153, -0.24382329, 0.12774743, -0.024951026, -0.008383696, -0.05486282, -0.15000756, 0.10089214, 0.07021351, -0.15236774, -0.13995059,
+    0.073494956, 0.01259107, 0.122149915, -0.15133978, 0.12976423, 0.14445798, -0.11794072, -0.044413395, 0.021698266, -0.03172678, -0.014797396, 0.042341307, 0.039751563, -0.013494328, -0.16968547, 0.15146695,
+    -0.06305569, -0.0042442847, -0.11333215, 0.050775602, 0.004972352, -0.12570934, 0.0058927387, 0.10207704, -0.023289043, -0.028723205, -0.05015094, -0.09256947, -0.14440869, 0.010768687, -0.019936427, 0.032196205,
+    0.073695675, 0.060382724, -0.04074019, 0.12718667, -0.11490381, -0.050501645, -0.0021858183, -0.0649611, 0.09759472, 0.09175091, 0.23733918, 0.05477099, -0.00040429944, -0.0015727282, -0.068039194, -0.06560459,
+    -0.051293176, 0.08658133, -0.0777972, 0.06970784, 0.043755658, -0.21515016, -0.13358495, 0.053624954, -0.05343063, -0.110241346, -0.21276559, -0.07732759, 0.07056759, 0.13580854, 0.0984471, -0.20863883,
+    0.10507205, 0.12777305, -0.04050713, -0.037167944, -0.09036415, 0.0049307714, -0.08472977, -0.08401508, -0.042350248, 0.06899563, 0.15987864, 0.11547874, 0.15075713, -0.1317954, -0.19835265, -0.11221543,
+    0.034066867, 0.07169046, 0.033744793, 0.09274803, -0.06445953, -0.21963377, -0.25727364, 0.1363839, 0.16031556, -0.05054808, 0.03543207, 0.08794321, -0.06674274, 0.20757169, 0.004302606, -0.013560672,
+    -0.026880998, -0.075721376, -0.05587969, -0.15113473, 0.11954382, 0.08759475, 0.07222463, -0.0046260436, 0.14763822, -0.038153388, 0.13860975, 0.0006363392, 0.0012757237, 0.07013338, -0.09905731, -0.14694762,
+    -0.058179755, -0.1130345, -0.14115809, 0.11518061, 0.023010992, -0.15340067, -0.11878424, -0.09294035, -0.10546083, 0.09161297, -0.15219538, 0.053616066, -0.048969947, 0.12322851, 0.053785663, -0.066513315,
+    0.10232282, -0.084494025, 0.014886365, -0.010070129, 0.024422558, -0.11855771, 0.0632897, 0.015219803, -0.100835174, -0.039148934, -0.024891192, -0.14352407, 0.0026698466, -0.07251603, 0.098581225, -0.11222899,
+    0.088550836, 0.024087545, 0.043169055, -0.13545126, 0.050090045, 0.005565118, 0.11609153, -0.10975689, -0.16088152, 0.056275297, 0.124041416, 0.12765598, -0.035838246, 0.12984127, -0.12897001, -0.15774675,
+    -0.0112133315, 0.07909933, 0.1451281, -0.004758914, 0.11322648, -0.0752237, -0.050881874, -0.004476749, 0.10379873, -0.022298375, -0.048527233, -0.049101807, 0.13267903, -0.07384085, -0.042513542, 0.08486335,
+    0.019959338, -0.033045523, -0.13074596, -0.15569994, 0.070346095, 0.1264598, 0.051031996, -0.037922326, 0.0961379, -0.11062267, 0.07852783, -0.006805215, -0.101173535, 0.10471062, -0.0017654515, -0.03698097,
+    0.11355988, -0.014539534, -0.04295861, 0.102722816, -0.092904136, 0.049294554, 0.072219476, 0.062474184, -0.12586033, -0.11190351, 0.06086399, -0.11398427, -0.09992034, 0.01879592, -0.09651558, -0.15392292,
+    -0.026196234, 0.08713043, 0.070085794, -0.08069469, -0.01025561, 0.021041458, 0.084316514, -0.15272506, 0.042246036, -0.05741484, 0.108016916, 0.039869115, 0.012656595, -0.04463373, -0.06638096, -0.12134207,
+    0.12614214, -0.11568181, 0.060612146, 0.09195333, -0.045196053, 0.029711144, -0.025301559, 0.12975723, -0.10237891, 0.0020661987, 0.053996123, -0.10687039, 0.19101574, -0.108681165, 0.04553499, 0.050772805,
+    -0.020147417, 0.13917677, 0.085423715, -0.048214294, 0.10167441, -0.0051989597, 0.13829711, 0.097823076, 0.14076236, -0.14134364, -0.120949455, 0.11926223, 0.11494296, 0.08546068, -0.06041938, 0.05520632,
+    -0.08204009, -0.05814778, -0.11213845, -0.07008187, -0.09681266, -0.1406689, 0.11967593, -0.123791434, 0.0034772658, 0.09032147, 0.10558565, 0.13648134, 0.07524868, 0.12476806, 0.089566, -0.03625568,
+    -0.07834912, 0.023762723, -0.07000444, 0.10664376, -0.067102596, 0.07774486, -0.10621053, -0.15445486, 0.15494351, -0.03226335, 0.071691565, -0.14263584, -0.012884617, -0.055476137, -0.029938903, 0.050179683,
+    0.0012607999, 0.12654899, -0.10520378, -0.1407755, -0.011641116, 0.1415979, 0.0054885033, 0.101091556, 0.1319706, -0.005971764, -0.08993746, 0.056846295, -0.036650114, 0.07715949, -0.024080075, -0.12951653,
+    -0.043361213, 0.1410072, -0.046552595, 0.017353404, 0.01990501, 0.12203103, -0.077444986, 0.039268855, 0.0116437655, -0.032767568, -0.10348862, -0.13111988, 0.14877695, -0.121481694, -0.03614548, 0.116768315,
+    0.08416468, -0.023973942, 0.01391084, -0.019893495, 0.027261395, -0.13373129, 0.02084467, -0.087991424, -0.09076749, 0.040241785, -0.005418457, -0.13946712, 0.12638383, -0.12027266, -0.13683589, 0.014567597,
+    -0.016282853, 0.07607081, 0.1351681, 0.10408267, 0.02225172, -0.13896188, -0.07600786, 0.045128245, 0.18520315, 0.110119306, -0.07067412, -0.050924007, 0.088622354, 0.16928293, -0.14042515, 0.06581518,
+    0.12778018, -0.115570806, -0.039351225, 0.07502631, 0.04267499, -0.0037668943, -0.3559037, -0.13574013, 0.05200601, -0.0122451335, -0.05938232, -0.050290257, 0.08120738, -0.09309998, -0.4003336, 0.06876307,
+    -0.037389815, -0.07695685, -0.038428113, -0.056549653, -0.07023834, -0.061103348, -0.22812982, -0.13975498, -0.016644184, -0.1565337, -0.53503734, 0.056752745, 0.1310192, 0.1198293, 0.071756035, -0.015528995,
+    -0.14161521, -0.14341296, 0.07887638, -0.025431035, 0.10930063, 0.040201083, -0.40986127, -0.14518198, -0.069410965, 0.010351434, 0.12200143, 0.016601661, -0.13620776, -0.11732698, -0.49481755, -0.00082466425,
+    0.023968825, 0.13994636, -0.1349211, -0.022289773, -0.032363143, -0.10488198, -0.14726888, -0.022307888, -0.07751506, -0.042969517, -0.32362446, 0.13274208, -0.045613397, 0.02890142, 0.08052826, -0.04178598,
+    -0.09679602, -0.008055729, 0.10519577, -0.051116373, -0.12290584, 0.109346345, -0.39194214, 0.14561583, -0.021102306, -0.06327638, 0.013714952, 0.033928633, 0.04341183, -0.022052906, -0.44527143, 0.07412069,
+    -0.13336194, -0.13868958, 0.03821677, -0.123779446, -0.10300328, -0.05308522, 0.0333993, 0.062296897, 0.079231225, -0.075003155, -0.43837202, -0.0032803128, -0.007350976, 0.11827408, -0.09760082, 0.1003848,
+    0.059090823, 0.07170328, -0.08480031, 0.030027205, 0.059144735, -0.1505435, -0.1617316, 0.014424428, -0.11398499, 0.048344687, 0.0614497, 0.10485107, 0.036120795, 0.12298435, -0.22900675, 0.1282135,
+    0.085323736, 0.013031542, 0.010930583, -0.06375492, -0.041052464, -0.04176201, -0.08734595, -0.029127598, 0.07575352, -0.13271478, -0.5294777, -0.12044687, -0.0004500004, 0.07478702, 0.10239537, 0.0935345,
+    -0.04702323, 0.10377771, -0.025826188, 0.09120386, 0.1235881, -0.020187587, -0.0680259, -0.14120345, -0.1182076, 0.05911973, -0.1542311, 0.060786627, -0.15945622, -0.12136458, -0.011134505, 0.011137177,
+    0.026079256, -0.12774289, -0.08443521, 0.07859639, 0.08382983, -0.11190331, -0.004685504, -0.06270311, -0.120369144, 0.14983977, -0.08093789, 0.025889207, -0.06533814, -0.14949666, -0.0308715, 0.071599744,
+    -0.12144382, -0.05544269, -0.0954519, 0.02452855, -0.10332857, -0.032879964, 0.057570413, 0.08253719, 0.10785377, 0.037535533, 0.04374402, -0.003743509, -0.14281535, -0.019582665, -0.13693713, 0.018390585,
+    0.11824353, -0.09290582, 0.15141441, -0.0018570856, 0.13532737, 0.019146657, 0.08873892, -0.016654551, 0.0096685765, -0.09567699, -0.13103475, -0.08453762, 0.061557006, -0.0022303346, -0.09024119, 0.11134275,
+    -0.1316175, -0.065760925, 0.11025202, 0.14010498, -0.050364383, -0.07366457, 0.13071607, -0.14568904, -0.04358321, 0.06683071, -0.08496301, -0.08279984, -0.048309393, -0.14405914, -0.14375058, 0.016819421,
+    0.13285135, -0.02662675, -0.1430361, 0.06947749, 0.08944697, -0.007680079, -0.091208585, -0.00022436678, 0.049653538, 0.068541214, -0.09312624, -0.13891026, 0.06338633, -0.13857977, 0.05205729, -0.006967194,
+    0.064429104, 0.1295813, 0.028401645, -0.052158218, 0.025461689, 0.071850985, 0.0046880245, -0.07954757, 0.040052284, -0.03071189, -0.06048961, -0.11788931, -0.09994687, 0.02905665, 0.092774644, -0.040041268,
+    0.06754863, 0.07226676, -0.019889683, 0.12691337, -0.05887155, -0.14019477, -0.13705283, -0.08016187, 0.040325426, 0.13775702, 0.12561284, -0.024045836, -0.1052388, -0.11624153, -0.013845233, 0.08910676,
+    -0.20166591, -0.051193744, 0.059794065, -0.20973891, -0.017728185, -0.20529427, 0.010483348, 0.1544013, -0.055368435, -0.08545578, -0.01971999, -0.17555873, -0.17020041, -0.11171485, 0.07542451, -0.13355508,
+    0.12914056, 0.016897168, 0.24627651, -0.08266814, 0.045359347, 0.19386047, -0.016135352, 0.103784494, 0.016280856, 0.042512286, -0.028218752, 0.09804396, 0.11445316, 0.103525154, -0.15026826, 0.12318505,
+    -0.12928453, -0.03487777, 0.09103104, -0.0016537334, 0.084557176, -1.7076472e-5, 0.008157223, 0.13820255, 0.10471954, -0.11343179, -0.095199876, -0.20601259, -0.14279622, -0.09544041, 0.14228117, -0.12133869,
+    -0.118883185, 0.051567577, 0.07751065, -0.043863244, 0.12608549, 0.17872748, 0.071955934, -0.07279738, 0.0063361065, -0.03341194, 0.1744389, 0.07969834, -0.08042496, -0.09544251, -0.1371266, -0.111236185,
+    -0.13785297, -0.0029250532, 0.107280426, -0.15229234, -0.18583918, -0.19995685, 0.21539417, 0.024792442, -0.18743478, -0.06862723, 0.029061427, -0.21154188, -0.09236492, -0.12686351, 0.22757629, -0.17813125,
+    0.13475281, -0.07582404, 0.08081687, 0.09489921, -0.10910557, 0.04333358, -0.022520205, 0.08050532, -0.083084494, -0.01144329, 0.13245967, 0.08752225, -0.06410339, -0.07900777, 0.012975381, 0.16964105,
+    -0.23975134, 0.053285718, 0.00018145567, -0.01851123, -0.18480808, -0.19343539, 0.02294509, 0.03700525, -0.10868726, 0.055691082, -0.042740457, -0.20646603, -0.15719831, 0.08816979, 0.06655882, -0.049843714,
+    0.07452525, -0.10844152, 0.15582085, -0.006944042, 0.022167709, 0.21102402, -0.011881628, -0.04407688, 0.1196341, -0.022212842, 0.013376261, -0.17069106, 0.088705726, 0.1492586, -0.09116033, -0.010872445,
+    0.039075583, 0.108846694, 0.104901195, -0.11138783, 0.05134603, 0.019586304, -0.036481958, 0.019909116, -0.12548922, 0.084601596, 0.11132731, -0.10557707, -0.08683427, 0.0024621934, 0.095917135, -0.12208809,
+    0.19435203, 0.11533262, -0.014769389, -0.011121154, -0.075124644, -0.23068841, -0.014446546, -0.14357011, 0.100410126, 0.009179935, -0.0051828455, 0.14808817, 0.08187103, 0.13659693, 0.068950415, -0.15193744,
+    -0.14535327, 0.16594684, 0.015189229, -0.111094624, 0.12673661, -0.07334938, -0.054053303, 0.13803741, -0.12655628, 0.11915064, 0.18275395, 0.07648224, 0.1281683, 0.0056699812, -0.11273435, -0.08156625,
+    0.048580796, 0.005554258, -0.055573966, -0.11678249, 0.092240214, -0.113940395, -0.15278925, 0.04766623, -0.104045264, 0.050795685, -0.12792945, -0.027242765, 0.098815754, 0.0116707655, -0.001208663, -0.14877611,
+    0.06382403, 0.08920407, 0.054441057, 0.06995928, 0.0062074037, -0.111036025, -0.11120496, -0.069271125, -0.044704765, 0.094797164, 0.06570194, -0.04010643, 0.016595896, 0.019643813, -0.18875399, 0.095561326,
+    0.1724282, 0.094080314, 0.10054301, 0.060876712, -0.12010838, -0.12022462, -0.036598686, 0.13301489, 0.18001701, 0.07249228, -0.116387196, -0.00046479702, 0.055344522, -0.015388413, -0.11822483, -0.039071698,
+    0.024548784, 0.050072536, 0.09781045, -0.0806325, 0.1338411, -0.07848934, -0.13308832, 0.004911054, 0.099611506, -0.10027145, 0.15218726, -0.030176573, 0.077900454, -0.0058041215, -0.18796581, 0.008658737,
+    0.18327306, -0.09118724, -0.1185728, -0.0742193, -0.18536377, -0.15945072, -0.02181475, 0.08736502, 0.15724464, 0.0926154, 0.08162192, 0.0019243658, -0.07608607, 0.23139498, -0.1503218, -0.24083681,
+    -0.012611672, -0.047106475, -0.068816066, -0.014551569, -0.13669597, 0.050349817, -0.1135147, 0.047729537, 0.12198073, 0.11900751, -0.0037726252, 0.116276935, 0.10126777, 0.034804083, -0.31957442, -0.015295954,
+    -0.026872918, -0.11852888, -0.050609894, -0.09758719, -0.043177072, -0.09406097, -0.06182682, 0.01335603, -0.12790355, -0.17173108, -0.25874552, 0.12695342, 0.116579704, 0.08088577, 0.03986379, -0.14641672,
+    -0.11907343, -0.019635448, 0.029520495, -0.053498384, -0.06457561, -0.055442557, -0.20609368, -0.015405223, -0.04902007, -0.021036848, 0.059455335, -0.022427019, -0.02012375, -0.02975902, -0.10191702, -0.06821689,
+    -0.013255376, -0.11092398, -0.13862532, 0.14533721, -0.07838916, -0.11804354, -0.09279972, -0.0649604, -0.0902271, 0.029590366, -0.36966923, 0.04949372, -0.08411474, -0.11778947, 0.055739965, 0.004868462,
+    0.060634464, 0.0068103713, 0.056122888, 0.14251526, 0.11926265, 0.072340846, -0.33299345, 0.008205295, 0.027432123, 0.012422025, 0.07208795, 0.13488778, -0.02655038, 0.1424131, -0.22779515, 0.08798634,
+    -0.011165116, -0.035975695, 0.07922908, -0.08207721, 0.07700668, 0.008027464, -0.09964481, 0.015166953, -0.13486718, -0.04286117, -0.36783075, 0.019088209, 0.12502317, -0.12182001, -0.14745331, 0.13049528,
+    -0.042346522, 0.052351646, -0.121177636, -0.14741978, -0.12117055, 0.0750511, -0.052356962, -0.1527372, 0.10814071, -0.017808437, -0.11198379, -0.09990965, -0.03727934, 0.08306813, -0.15525512, -0.008531278,
+    0.099989966, -0.03238856, 0.14221422, -0.07884007, 0.008253389, 0.057069995, 0.0010755756, 0.12671854, 0.0087562455, 0.0007666949, -0.087462276, -0.04859185, 0.11838072, -0.07393483, -0.08707041, 0.00875405,
+    0.1421147, -0.19156751, 0.15249756, -0.1079832, -0.14638053, 0.13496125, 0.058136936, -0.0974163, -0.015218216, -0.014931937, 0.008430056, 0.032511048, -0.15306355, 0.061557166, 0.052914336, -0.14466472,
+    -0.12254086, -0.121756144, -0.1257327, -0.08203643, 0.025317887, 0.17531829, 0.17193928, 0.052737676, -0.17692065, 0.13702631, 0.12454147, -0.02882536, -0.07024153, 0.0005612408, 0.075787716, 0.015275707,
+    0.12309732, -0.1813227, -0.08327719, 0.024672829, -0.03607921, 0.09556985, 0.018899556, -0.096952885, 0.07176412, 0.1207752, 0.048134916, -0.18378083, -0.012684488, 0.021940837, -0.022591602, -0.13934095,
+    -0.17651428, 0.012589066, -0.14300025, -0.0136917215, 0.041546434, 0.1576051, 0.15767625, 0.09540103, -0.014787581, 0.06507917, -0.047705594, -0.08277737, -0.031798653, -0.17238213, 0.10457309, 0.1415228,
+    0.018470103, 0.0009535806, -0.11342084, 0.029390022, 0.09607617, 0.027945407, 0.030159026, -0.11607084, -0.16486442, 0.046941712, 0.004864969, -0.0012501094, -0.117869034, 0.10784502, -0.048979357, -0.06155613,
+    -0.19465868, -0.07462684, -0.105321504, -0.00874538, 0.1451879, -0.054986715, 0.14356135, 0.04014597, -0.16483223, 0.1315417, 0.08175776, -0.12818009, -0.1729536, -0.15359335, 0.049918506, 0.006304803,
+    0.023075236, -0.04696338, 0.13006803, 0.055833638, -0.05197959, 0.08700469, 0.09223458, -0.026604736, 0.0383333, 0.04570259, -0.057194494, -0.050839, 0.08828829, -0.047801394, 0.06475424, 0.016731804,
+    -0.067856, 0.042425588, 0.09604972, -0.081141405, 0.10927872, 0.08975377, 0.052237626, 0.10858316, 0.05207657, -0.13550678, 0.14890076, -0.09235773, -0.10405816, 0.02864715, 0.018532189, 0.11978934,
+    -0.036568385, -0.15067403, 0.06689122, -0.03156369, 0.1145558, -0.08093371, -0.06717664, 0.12776713, -0.04913798, -0.04803687, -0.10315027, -0.14683518, 0.13290997, -0.13335353, -0.043145493, 0.12791441,
+    0.09941886, 0.018582605, -0.14346886, 0.06605278, -0.07071061, -0.019257054, 0.053077564, -0.0884944, -0.023587221, 0.10070765, -0.035299797, 0.1093746, -0.1063443, 0.099457696, -0.0074845254, -0.14838096,
+    -0.022755781, -0.12256245, -0.062172286, -0.114204556, 0.0019344309, 0.11034356, -0.057518892, -0.059515372, 0.040434346, 0.024665313, 0.10316895, 0.09169449, -0.017725337, -0.12597293, 0.13005964, 0.1379957,
+    0.064525455, -0.13458756, -0.06717494, -0.12409188, 0.03442265, -0.019494012, -0.09452278, 0.092241414, 0.100614406, -0.06601061, -0.11449099, 0.10931827, 0.09343153, -0.06185817, 0.06985289, 0.1458533,
+    -0.16294476, 0.009036109, -0.082543075, 0.117151484, -0.0010756779, -0.06867813, 0.01404014, 0.06900902, -0.0662237, -0.064051785, 0.07823776, -0.091052845, -0.0055111847, 0.09022035, -0.10861146, -0.11754523,
+    0.06145981, -0.12577453, 0.0434708, -0.03890247, -0.09615591, -0.00080242753, 0.15274559, -0.12260552, -0.10527038, 0.08332455, -0.15679784, -0.07797875, -0.10219152, -0.055195667, 0.039481983, -0.106300354,
+    0.14062487, 0.072636425, -0.03218931, 0.14037405, 0.04483012, 0.05850006, -0.040480472, -0.05820352, 0.020115212, 0.035954926, -0.02318266, -0.11168936, -0.106867336, 0.013401642, -0.022417614, -0.123494044,
+    0.11193235, 0.044568054, 0.0099277375, -0.0009982139, -0.13833913, 0.09680179, -0.07096425, 0.10651136, 0.14888021, 0.045200862, 0.060277727, 0.03787306, -0.11366968, 0.14161624, -0.07621857, 0.041367874,
+    0.06038641, -0.09467697, -0.00891001, -0.044470843, -0.014493769, 0.0003434302, -0.02101422, -0.15398979, -0.11729543, 0.028345583, -0.20909794, -0.1606327, -0.18336871, 0.10617109, 0.16208932, 0.0150864655,
+    -0.17427775, -0.103974946, 0.13651656, -0.043900196, 0.16128948, 0.15807459, -0.027220484, 0.075362705, -0.062469218, -0.0882085, 0.10986522, -0.15473206, -0.11561877, -0.099120915, 0.11459026, 0.067121066,
+    -0.10258167, -0.09442286, -0.008000751, 0.029986337, 0.05243792, 0.09646291, 0.046243176, 0.020430038, -0.1280671, 0.09000676, -0.2505488, 0.0032390503, -0.08278157, -0.008015682, 0.088398956, 0.071115434,
+    0.017343832, 0.11113956, -0.03975632, 0.080155306, 0.057932954, 0.014468236, 0.22144856, 0.064690374, -0.20134884, -0.0030019672, 0.12454626, 0.016537042, -0.061557002, -0.23967642, 0.06699045, -0.09260564,
+    0.04586952, -0.016610045, -0.03735608, -0.13059679, 0.05881298, 0.08598844, -0.07749648, 0.07621019, 0.069193006, -0.021036696, 0.040460285, -0.14735326, 0.03328638, -0.06892705, -0.088841505, 0.07974866,
+    0.020599497, -0.14382425, -0.0006604112, -0.10540042, 0.029519975, -0.040775623, 0.24327633, -0.06357706, -0.18034622, 0.012970135, -0.06082946, -0.092975006, -0.046273448, 0.025338849, -0.1314056, -0.0534987,
+    0.08745656, 0.008684192, 0.07456133, -0.13102733, -0.06573206, 0.108419575, 0.14622828, 0.11337013, 0.08483738, -0.060194228, -0.063637115, -0.19838077, -0.16595246, -0.0061098924, 0.17359826, -0.19641846,
+    -0.0110927075, 0.06569546, -0.13116327, 0.06084716, 0.040311728, 0.115550764, 0.15812625, -0.035535056, -0.19337474, 0.13161555, 0.18205415, 0.1260746, 0.094324894, 0.03900143, 0.04455879, 0.16185023,
+    0.12186605, 0.06171878, 0.09607137, -0.08384465, -0.062534936, 0.047005802, 0.04400845, 0.062421154, -0.052169856, -0.036088143, 0.101987116, -0.13300909, -0.11905402, -0.042277157, -0.16683105, 0.065231785,
+    -0.024451042, -0.093816176, 0.11524607, 0.02265712, 0.047413263, 0.080576934, 0.025669117, 0.15592118, -0.028660433, -0.14081551, -0.1099926, -0.043945458, 0.020546665, 0.018020201, -0.13782662, -0.03449436,
+    0.03550938, 0.10303183, 0.0848218, -0.0035251256, -0.08061998, -0.12830932, -0.042811766, 0.0077064973, -0.06297586, -0.11729379, -0.13214937, 0.14958255, 0.027634569, -0.04864002, -0.053731147, -0.06821352,
+    -0.07547098, -0.10148138, -0.13624513, -0.0490004, -0.03557877, -0.013024179, 0.10404652, 0.05771177, 0.05662372, 0.062590435, 0.09590585, -0.07319067, 0.15245773, 0.070011616, -0.052515604, 0.052302886,
+    0.08314822, -0.16215049, 0.087136425, 0.027978199, 0.078310065, -0.07597186, -0.12053835, 0.0008971603, 0.08372029, 0.037800614, -0.13673592, -0.067666486, -0.09850131, 0.0050570513, -0.1228066, -0.15084793,
+    0.08164913, 0.1200013, 0.12039329, 0.021467293, 0.011169908, -0.019836811, 0.048527766, 0.091971114, -0.13818428, 0.08306214, -0.10760607, -0.10223274, 0.08466647, 0.0050255554, 0.053514056, -0.12132571,
+    0.13041705, 0.062405758, 0.09250752, 0.07425028, -0.118008174, 0.07007984, 0.12965773, 0.05742654, -0.010711408, 0.14782393, -0.01293444, 0.11130542, -0.16262779, -0.07439771, -0.11150905, -0.00611782,
+    -0.058299165, 0.020332342, -0.015952276, 0.02826319, 0.13944237, 0.11869893, -0.04684398, -0.009906465, -0.18058717, -0.026639307, -0.07836849, -0.0044737193, 0.14225255, 0.11207947, -0.14775813, 0.013458564,
+    -0.023042709, -0.045244753, 0.01721657, 0.09378279, -0.019536972, 0.027570233, -0.0025923997, -0.1391434, -0.11037885, -0.010272741, -0.09826782, -0.005798246, -0.085414685, 0.083909616, -0.10849036, -0.0038750353,
+    -2.1544603e-5, -0.14647523, -0.099859595, 0.08874715, -0.036551394, -0.13434479, -0.0580941, -0.08836836, -0.00055583526, 0.13814653, -0.059438802, 0.051312875, -0.031236598, 0.088882804, 0.118852854, -0.08771311,
+    0.097225115, -0.010353636, 0.1235188, -0.025565673, -0.09135887, -0.01836893, -0.13990612, -0.016877532, 0.008991902, 0.042291045, -0.10322839, -0.121208966, 0.091871426, -0.0042183967, 0.013758153, 0.056300018,
+    0.052208338, 0.122352436, -0.13410452, 0.081242256, 0.06307544, -0.043567184, -0.12828538, 0.029131398, -0.117140345, 0.06566569, 0.049992874, -0.040646274, 0.014196867, -0.118863955, 0.097361214, -0.16113485,
+    0.083752036, 0.12047748, 0.13390256, -0.053408742, 0.027973607, 0.15289219, 0.13212864, 0.04459691, -0.016776323, -0.0659854, 0.04710469, -0.10232414, -0.07137202, 0.0659769, -0.13108821, -0.0063528847,
+    -0.10507629, -0.027914882, 0.021446481, 0.110426046, 0.0796729, 0.02281148, 0.026660517, 0.0017278492, -0.009507847, 0.085697174, 0.12880854, -0.103744455, -0.034776933, -0.10189788, -0.03434293, 0.05288083,
+    0.109697625, -0.05951427, -0.0067706686, -0.15687104, -0.114696465, -0.09822803, -0.057080135, 0.052200854, 0.018058492, -0.12792054, -0.14158195, 0.04817299, 0.03858833, -0.01423119, -0.01830545, 0.099824764,
+    0.123522714, -0.10565634, 0.096055284, -0.06987382, 0.014598531, 0.019410782, -0.07669331, 0.051667303, 0.12919769, 0.06451969, 0.036499828, -0.10145641, -0.13346723, -0.0005102866, -0.043829307, -0.10716284,
+    0.06552297, 0.028237173, 0.13930987, 0.12155798, 0.058920115, -0.1491882, 0.095734045, 0.02732116, -0.08077365, -0.14064953, -0.03392617, 0.09593676, 0.08934765, 0.06898887, 0.033672467, 0.07182439,
+    -0.00028893512, -0.07893751, -0.04781656, -0.033257723, -0.017750286, -0.15346104, 0.065696746, -0.10779905, -0.077250674, -0.14850788, -0.006550097, -0.13788831, -0.1131563, -0.09241766, -0.04436455, -0.10446949,
+    0.1466422, 0.06646532, -0.107591756, 0.068760104, -0.1488899, -0.01619184, -0.06342067, -0.051618353, -0.12921563, -0.009973183, 0.053474132, -0.15892561, -0.018577881, -0.09534844, -0.031863146, 0.0928074,
+    0.051758163, -0.081617795, -0.057758383, -0.094787285, 0.12261821, -0.037955195, -0.072903074, -0.108422875, 0.049754534, 0.11980002, 0.12768269, 0.12704155, -0.008174079, 0.13886958, -0.09915959, 0.09945704,
+    0.012851328, 0.13836068, -0.046342548, 0.050459534, -0.050628938, 0.011969894, -0.109185696, -0.13973589, -0.08118038, 0.061968103, -0.04036941, -0.14457642, -0.14070855, 0.049383946, 0.14570908, 0.046654474,
+    -0.1392369, -0.10880562, 0.06008838, -0.040925812, -0.050989963, -0.0394544, 0.0004803936, 0.101123855, 0.037373785, 0.06633222, 0.14190942, -0.08762792, 0.1298985, 0.11464286, -0.11313765, 0.12667875,
+    -0.049865387, -0.04129821, -0.10715298, -0.13143027, 0.0707632, 0.023669675, -0.024380624, -0.024724603, -0.14139932, -0.11457923, 0.105539165, -0.11156316, -0.045178656, 0.08206614, -0.098272815, -0.062422413,
+    0.078519255, 0.031026408, -0.10871674, 0.12628609, 0.022337057, 0.07374976, 0.060902648, 0.037567437, -0.1002359, -0.07315692, 0.047845002, -0.056179274, -0.054791894, 0.09018892, -0.045592017, 0.1264347,
+    -0.11725202, -0.18655513, 0.097953096, -0.058798265, 0.05293206, 0.03709662, 0.008797563, 0.10375841, -0.106922336, -0.13872413, -0.084912315, -0.015259179, -0.16562152, -0.103177786, -0.09602869, -0.16632397,
+    -0.19119695, -0.096385784, -0.015987966, 0.111780606, 0.019783493, 0.18444377, 0.052308746, 0.0810028, -0.021080226, 0.114227526, -0.010279849, 0.028434485, 0.13830213, -0.12968218, -0.029288072, -0.008144961,
+    0.019937718, -0.13027687, 0.05242168, -0.13956475, -0.042026818, -0.010065426, -0.048428826, -0.08411531, -0.12890407, 0.12865432, -0.09212808, -0.054790936, 0.07697791, -0.12009379, 0.15612586, -0.10720915,
+    -0.18172324, 0.13704708, 0.028243886, 0.1719345, -0.00085288374, 0.08765218, 0.25010505, 0.14382592, 0.05270744, -0.0657994, -0.040514417, 0.03600623, 0.014401978, -0.082192525, 0.11759158, 0.063936986,
+    0.025399035, -0.091408305, 0.02386919, -0.07693809, -0.04542806, -0.09407077, 0.14496465, -0.06746836, -0.15589267, 0.0947756, -0.13462046, 0.009051744, -0.03037801, 0.08015607, -0.0712659, -0.17538813,
+    -0.056665923, 0.02466392, -0.023087205, 0.0071238824, -0.056824036, 0.030095654, 0.09995885, -0.11734787, -0.10378444, 0.041849177, 0.11029052, -0.082182355, 0.1369328, -0.21844655, 0.119123526, 0.14858104,
+    -0.073928505, -0.02111973, 0.13124238, -0.16281047, -0.121600844, 0.032181755, 0.080435514, 0.12152119, -0.15529883, 0.06771341, 0.027740477, -0.15676193, -0.11217615, -0.09666005, 0.12382092, -0.1219395,
+    -0.14552993, 0.1168255, 0.0010473273, -0.026299562, -0.012638742, 0.19995064, 0.14493462, 0.048385307, 0.09358193, -0.0718159, -0.04925719, -0.10604633, -0.052281186, -0.029747877, 0.0553027, -0.025202116,
+    -0.08866569, 0.04156713, -0.04936382, 0.09204139, -0.108259104, -0.0009007237, -0.17201439, -0.13911995, 0.15471412, 0.032682054, -0.043852758, -0.016491871, -0.14403707, -0.09598669, -0.04649371, -0.07259999,
+    -0.0018377044, 0.13323188, 0.031395398, 0.026953144, -0.0051862597, -0.104505114, -0.049052566, -0.055076547, -0.08284387, -0.061987534, -0.045287132, -0.14962682, -0.035877112, -0.16221747, 0.14207606, 0.07250013,
+    -0.041603487, 0.05381861, 0.083890796, 0.13339174, 0.0870319, -0.018461311, -0.11833135, 0.03973572, 0.05492408, -0.087206595, 0.10317903, -0.06375356, -0.024620652, -0.01864917, -0.023490941, 0.038069718,
+    0.10875149, -0.059317842, -0.028535256, -0.11471264, 0.1401026, 0.06457952, -0.11801269, 0.035184212, -0.07026549, -0.096657746, -0.16591762, 0.06916623, 0.09426734, 0.11221266, 0.0493388, 0.13297166,
+    -0.03334603, 0.08143045, 0.12399311, 0.113502815, 0.0083783, -0.09117035, -0.19000499, 0.028306808, 0.11262546, 0.12632683, 0.13227439, 0.056910988, 0.07784577, -0.08503903, -0.018201374, -0.108686686,
+    0.062436868, 0.035767555, -0.06001088, -0.12331343, 0.03459411, -0.120537005, 0.09700542, 0.08815947, -0.0703017, 0.13972835, -0.07307074, 0.05004861, 0.031522457, 0.08806744, -0.052784655, 0.006382083,
+    0.04356278, -0.12061759, -0.016031934, -0.09689678, 0.089350834, -0.077178895, 0.018915256, -0.04065074, -0.07799477, 0.09990072, -0.061832342, -0.0859429, -0.15886599, 0.1038087, -0.21516141, 0.04055722,
+    -0.08517305, -0.14961131, -0.13778429, 0.11098209, 0.12909341, -0.08292267, 0.07336994, -0.0317896, -0.029164419, 0.07077395, -0.122566156, -0.14844742, 0.01978704, -0.05678577, -0.14805737, 0.046997186,
+    -0.013924919, -0.06208056, 0.14141609, 0.090199895, 0.008302581, 0.112703286, 0.077424884, -0.0742629, 0.10462613, 0.13274385, -0.03896843, -0.11073187, -0.010649876, -0.08988227, 0.14583378, -0.16367093,
+    -0.12141497, -0.056842465, -0.05630135, 0.018051261, -0.066522695, 0.037664402, -0.005763746, -0.022307532, -0.014600387, -0.13778734, 0.04337364, -0.06752419, 0.023794483, -0.16357487, -0.061623316, -0.043938443,
+    -0.14806359, -0.04792541, -0.026948573, -0.08141767, 0.069409505, 0.025794333, 0.16202673, -0.12287411, -0.06748239, 0.096134104, -0.06951212, -0.060342614, -0.181302, 0.048266213, -0.06686444, -0.12067445,
+    -0.03325809, 0.08571902, -0.05784334, -0.03616627, -0.0011147304, 0.034491885, 0.0323894, 0.1461943, -0.17803761, -0.06386855, 0.1562101, -0.028710525, 0.10190751, -0.22792558, 0.07241186, 0.0062887133,
+    0.09660919, -0.046138283, -0.07662234, 0.01578432, 0.093382254, 0.1281522, 0.061951045, -0.067008846, 0.048167016, -0.04070673, 0.02108917, -0.07683014, -0.103530295, -0.12053014, 0.03476648, 0.0077537885,
+    0.0711514, -0.1386627, 0.008109972, -0.12752663, -0.12804155, 0.20903656, 0.25712174, 0.14353965, -0.062436007, -0.13999346, 0.014364906, -0.14136322, -0.06135694, -0.21112539, 0.10833021, -0.077719554,
+    0.07184161, 0.08057967, -0.042144436, -0.15048316, -0.021099837, -0.0014961592, 0.17430776, 0.021435581, -0.07948942, 0.07034894, 0.03537321, -0.14041243, -0.02948877, -0.10257925, 0.17661548, 0.031631988,
+    -0.11472305, -0.10650849, -0.01882231, 0.073656835, -0.020471476, 0.22139388, -0.009929345, 0.04643085, -0.0765169, -0.06582459, 0.084367596, -0.10574853, -0.105757475, -0.114968404, -0.06303754, -0.10739094,
+    -0.042978205, -0.08244185, 0.023450062, 0.09835795, 0.017579274, -0.11841029, 0.19983174, -0.06581428, 0.016197203, -0.12611438, 0.07731016, 0.06586963, -0.008699497, 0.051927783, 0.29290813, -0.14934452,
+    -0.077851266, -0.07958048, -0.07501212, 0.08894396, 0.01865881, -0.02637736, 0.10214781, 0.06664221, -0.16417308, -0.009214748, 0.081034884, -0.061502084, -0.053169545, -0.043410905, 0.074475355, 0.06284539,
+    -0.072935596, -0.12361259, -0.024166867, 0.0062523694, -0.0011395488, -0.10388607, 0.033668447, -0.091116816, -0.019431604, 0.0012804568, -0.03429837, -0.04069912, 0.030594358, -0.08104059, 0.17611371, 0.0423473,
+    0.110441655, 0.05259439, -0.18530884, -0.1367533, -0.02008558, 0.12890333, 0.15462221, -0.12734407, -0.078214474, -0.020734396, 0.10532874, -0.058040105, 0.1276203, -0.009156628, -0.13605176, -0.04352682,
+    0.046420597, 0.02645945, 0.1468757, -0.06027369, -0.008037146, -0.12004852, 0.20317711, -0.19477834, -0.015663043, -0.12137246, -0.09680621, -0.029496046, 0.000622932, 0.04257252, 0.13451369, 0.0027818298,
+    0.010571694, 0.15452819, -0.020800719, 0.13213308, 0.028691884, 0.064787075, -0.03943947, 0.023153186, 0.058300648, -0.08366537, 0.14885134, 0.042629354, -0.11918248, -0.013428632, -0.017099207, -0.08951306,
+    -0.06867342, 0.020431021, 0.07685349, -0.046514664, -0.028816605, -0.022999153, 0.26248768, -0.18744744, 0.08690868, -0.13021868, -0.10279524, -0.07020861, -0.01331201, -0.0018048118, 0.115881905, -0.0013000614,
+    -0.045766227, 0.12557194, -0.20135914, -0.017327257, 0.09796784, 0.05775559, -0.029766474, -0.04247892, 0.011087102, 0.119197294, 0.25616384, -0.0031240024, 0.06952417, 0.019705432, 0.14129749, 0.012756036,
+    0.061203066, 0.11728491, -0.043217544, 0.10156279, -0.076915264, 0.11419618, 0.005368657, -0.09053073, -0.023510626, -0.01481381, -0.18178187, 0.11657837, -0.10135082, -0.17392646, 0.1405308, -0.004154508,
+    -0.06628445, 0.06002534, 0.0668494, -0.08574925, -0.1149928, -0.100604266, 0.0067937677, -0.013194611, -0.054030143, -0.10193768, -0.0732555, -0.04905594, -0.06805205, -0.06333995, -0.06386642, 0.06994803,
+    0.12459956, 0.004290572, -0.009520744, -0.1221956, -0.14838414, 0.1190971, 0.07851094, -0.13901764, -0.04789178, 0.023787698, 0.08734168, -0.0040992657, -0.11186045, -0.12165071, 0.101794295, 0.11045956,
+    0.015882378, -0.13578436, 0.09025205, 0.022441994, 0.024096947, 0.0749561, -0.006890694, 0.044664014, 0.014046962, -0.109399095, 0.19852443, -0.12956564, 0.01990063, 0.09215084, -0.15480164, 0.078986675,
+    -0.049400505, -0.10674445, -0.04412607, 0.042140555, 0.061759837, -0.09735075, -0.10289444, -0.08927166, 0.09993421, 0.10727098, -0.18838474, -0.13914903, 0.045044042, 0.043395497, -0.048360135, 0.08634009,
+    -0.030730918, -0.045283213, 0.06387233, -0.08109065, -0.047084752, 0.01763584, 0.008237602, 0.002057598, -0.11150624, 0.10509917, 0.011255754, 0.0346855, -0.14999846, -0.013597185, 0.086664066, 0.09471606,
+    -0.022527575, -0.17615734, -0.16442363, 0.03307553, 0.09973569, 0.08698654, -0.06871317, -0.057020552, 0.07535016, -0.05871112, -0.16769323, -0.17606165, -0.17602886, -0.017637827, 0.08182164, 0.070003696,
+    0.097545356, -0.106143415, 0.12865691, -0.16254711, -0.12358989, 0.14094003, 0.08276954, -0.002169134, -0.15818729, 0.12536941, -0.0015060059, 0.11972979, 0.115750074, -0.038825423, 0.09187837, 0.03422064,
+    0.03153442, -0.11107224, -0.02356109, -0.016741678, -0.11176254, -0.084590554, 0.05292985, -0.068295784, -0.07837255, 0.13623308, -0.12549728, -0.010839805, 0.10094972, -0.014642224, 0.08908358, 0.11050995,
+    -0.115237616, -0.14311512, -0.115997404, 0.054053277, -0.06291481, 0.036815718, -0.0508283, 0.1147248, 0.039660648, -0.1526654, 0.10686071, -0.08756554, 0.13832684, 0.13696562, 0.12457, 0.03318481,
+    0.04129593, -0.014527574, -0.06801678, -0.03615608, -0.11053026, 0.13417001, -0.04887218, -0.042325027, 0.023612335, 0.15075521, 0.13080303, 0.14563377, 0.121826485, 0.07863152, 0.048404396, -0.10841364,
+    -0.07332877, -0.046024397, -0.04474459, 0.03142467, 0.101312384, 0.02204074, 0.00083494186, 0.062199995, -0.048228137, 0.038212135, 0.072208464, -0.07729554, -0.14316082, 0.09028572, -0.10283531, 0.120692775,
+    -0.00713259, 0.122218624, 0.09410672, 0.10987909, -0.015221626, -0.09777174, -0.093925595, -0.057580262, -0.05270876, -0.038191482, 0.087739974, 0.11699657, 0.058104634, 0.0074895173, -0.114682265, -0.055811502,
+    -0.10986124, 0.051680833, 0.06004615, 0.09139229, -0.043438315, -0.0091454685, 0.1408018, -0.0056006014, -0.13353527, -0.14369226, 0.038120344, -0.12871899, 0.12265526, -0.13170098, 0.016151607, -0.004126534,
+    0.064244404, 0.023868605, 0.076005146, -0.11360748, -0.1346737, -0.07096519, -0.10054098, -0.027409092, -0.09552643, -0.09969731, -0.093116134, -0.08246577, 0.09307796, -0.11194387, -0.01863794, 0.1503507,
+    -0.11473866, -0.0027999282, -0.07735022, -0.10752775, -0.009182215, -0.08567987, 0.016304836, -0.04555446, 0.1386178, -0.06856384, -0.14761119, -0.06611639, -0.0015192479, 0.06563178, -0.12123048, -0.1375259,
+    0.05659685, 0.11095905, -0.10670523, 0.1528265, 0.1234194, -0.08931322, -0.025210448, -0.1217763, -0.04819713, -0.13780414, -0.07445674, -0.027283112, -0.024390232, -0.045068547, 0.042787846, -0.06936051,
+    0.11025946, -0.017240746, -0.049706485, 0.04478162, 0.09524318, 0.06836296, 0.08288603, 0.07094758, -0.17572378, -0.0893887, -0.003808541, 0.12748815, 0.07137529, -0.12020103, -0.07277784, -0.002865429,
+    0.12292749, -0.06256006, -0.10740261, -0.08253999, -0.08416387, -0.036700148, 0.024883948, -0.07324582, 0.098439656, 0.08322688, 0.019340463, 0.07704577, -0.10301271, -0.00029643896, 0.041765656, 0.011325017,
+    0.041172884, 0.08219221, -0.046900313, 0.06780358, 0.11567404, -0.02785439, 0.042374905, 0.07639415, -0.14083694, -0.13112502, -0.005600331, 0.13105725, -0.11640861, 0.031832445, -0.110295705, 0.09471045,
+    0.07168698, -0.047438215, -0.091075756, 0.12941648, 0.11338105, -0.07624029, 0.032969277, 0.08511543, 0.050690923, 0.0153166, -0.159699, 0.089203745, -0.17206992, -0.061170742, -0.0077288873, 0.06814325,
+    0.10883422, 0.10841772, -0.14442888, -0.076823264, -0.048786733, 0.092070945, 0.0015116804, -0.0038829977, -0.08728158, 0.102212206, -0.0016453295, 7.2597606e-5, 0.016685816, 0.021127982, -0.06882501, -0.06560638,
+    -0.05208354, -0.002484369, 0.09097086, -0.12177038, 0.14850774, 0.011402173, -0.08141029, 0.10138516, 0.001104679, 0.08763885, -0.10643874, 0.11260587, -0.090770364, 0.017688554, 0.02190276, 0.028548228,
+    0.11039035, -0.02904415, 0.08912641, 0.07000761, 0.012126937, -0.11048772, 0.13289085, 0.13941035, 0.033793338, 0.0043569985, -0.00043326945, -0.015158432, 0.08671061, -0.12836967, -0.08874407, -0.12902337,
+    0.10303111, 0.041918848, 0.05370151, 0.10925787, 0.041157346, -0.009315237, 0.08745772, -0.039904926, -0.15193816, -0.017427957, 0.09472258, -0.032588355, -0.067659795, 0.019345392, 0.09360533, 0.06362484,
+    -0.05579671, -0.13905685, 0.06859232, 0.09491724, 0.1518664, 0.072793834, 0.0020016674, 0.08440403, 0.13526428, -0.12381646, -0.0018216163, -0.0582999, -0.13708797, -0.0012762826, 0.15469971, -0.14284122,
+    -0.03606362, -0.08094146, 0.15442449, 0.01205056, -0.10034951, -0.009034112, -0.025138728, -0.07484645, -0.021607924, 0.11108384, 0.14645112, 0.11836516, 0.107580006, -0.062046923, -0.034851186, -0.11176478,
+    0.15356949, -0.021351933, -0.15148605, 0.009929185, -0.06771155, -0.11587093, 0.15267713, 0.10774002, 0.0007115083, 0.009413279, 0.07673751, 0.06584715, 0.13634454, 0.10449561, -0.09934608, 0.046255127,
+    0.14285365, 0.05884153, 0.036531214, 0.0696727, -0.07741765, 0.109489396, 0.11198041, -0.05848879, -0.13209672, 0.028480047, -0.06382121, -0.07463571, 0.113711715, -0.034241565, 0.01218344, -0.15654269,
+    0.07547151, 0.13928679, 0.09416797, 0.11770137, -0.0074368776, 0.15987253, 0.15616624, -0.02822422, 0.03244771, 0.08388156, 0.06929526, 0.110259406, -0.00782249, 0.08687367, -0.019064844, 0.06974808,
+    0.07497508, 0.028959395, -0.028705996, -0.039798774, 0.010097714, 0.047526624, 0.13052373, 0.100688286, 0.04201606, 0.0799494, 0.16018984, -0.1285755, 0.111761004, 0.08653143, 0.12697214, 0.02105746,
+    -0.13360292, -0.058917537, 0.129057, -0.07673626, 0.11349019, -0.1056472, -0.07604072, -0.022379762, -0.10243987, -0.07337485, -0.11695902, 0.1315392, -0.028320113, 0.062025513, 0.008757194, -0.13083363,
+    -0.16684783, -0.03236828, 0.02694344, 0.098896496, 0.07908965, -0.119101316, 0.06686379, -0.2047971, -0.032007564, 0.026264297, -0.08726622, 0.018928962, -0.0015666484, -0.12303733, 0.02983982, -0.017838098,
+    -0.21597055, 0.031713564, -0.16231823, -0.060161594, -0.10400816, -0.03768143, 0.13048382, 0.020812402, -0.076624826, -0.13174473, 0.2956209, 0.09878387, -0.027328309, -0.22138157, -0.11036816, 0.070345424,
+    -0.001365295, 0.022845501, 0.12461291, 0.08637067, -0.08364671, -0.09155822, 0.08858585, 0.030330524, 0.031870417, -0.09776801, -0.014982912, -0.060393687, 0.0811977, 0.035309374, 0.28293544, -0.14465562,
+    -0.12919678, -0.0032384605, 0.018610857, 0.1464602, -0.06368728, 0.19336468, 0.06858884, -0.109828345, 0.0037595555, -0.028616443, 0.05487468, 0.07487219, -0.13378757, 0.06911908, 0.036972437, 0.059840303,
+    -0.12365302, -0.11682568, -0.043802757, -0.09313514, -0.034825213, -0.037704192, 0.24315095, -0.068695866, -0.14704248, 0.02715951, -0.20968865, 0.022456812, -0.08206367, -0.04756125, 0.26501843, -0.10060443,
+    -0.11382588, 0.06426154, -0.13893354, 0.08051773, 0.034645475, 0.23005673, 0.04792635, 0.09322014, 0.069777876, 0.080134384, 0.14830285, 0.1210823, -0.016986348, -0.081152566, -0.08382076, 0.06385278,
+    0.015745893, -0.035183467, 0.1303976, -0.0040070512, 0.08166797, -0.11325215, 0.09690207, -0.2315204, 0.0751601, -0.09792176, -0.21677761, -0.068387255, -0.02011405, -0.0003356893, 0.1631036, -0.17695923,
+    -0.21772894, 0.082828075, -0.045033295, 0.14195776, -0.13630621, 0.026805023, 0.21031117, 0.040103365, -0.19612347, -0.14806952, 0.3269892, 0.013751684, -0.048139904, -0.126696, -0.03115811, 0.11797831,
+    -0.054465957, -0.0136708, -0.03652422, 0.112527944, -0.08119103, -0.12815148, -0.29799482, -0.0739069, -0.09249729, 0.14138071, 0.040297803, 0.0038656585, 0.036398184, -0.025502417, -0.34016383, 0.13844141,
+    -0.15588836, -0.04258746, 0.016873702, 0.087040514, 0.08864448, -0.16721432, -0.13425826, -0.07766076, -0.083618395, 0.037072808, -0.32905498, 0.085313454, 0.11460723, -0.02730127, -0.13702686, -0.01768588,
+    0.08094816, 0.03456867, -0.034660153, 0.07684017, -0.050407857, -0.002468869, -0.14741954, 0.09055488, -0.025222562, 0.008210361, 0.10293505, -0.1229234, -0.072918594, -0.1426682, -0.14923674, 0.08625914,
+    0.12867247, -0.04651299, -0.13582781, -0.12331066, -0.046661336, 0.047534864, -0.097407244, -0.10765414, -0.0643244, 0.117659435, -0.33114588, -0.06717354, 0.077371255, 0.1155078, -0.07104095, -0.062059686,
+    0.067114085, 0.08180179, 0.03414853, 0.10658253, -0.0986415, -0.10919307, -0.09925798, -0.0810712, -0.079817325, -0.08351435, -0.09879373, 0.042254906, 0.10938877, -0.0051636226, -0.30509195, 0.11006658,
+    -0.09659554, 0.0071310103, -0.08689073, 0.060668502, -0.08637451, 0.025147136, -0.06621118, 0.0982144, -0.10915433, -0.122182705, -0.22521709, 0.110537365, 0.13868392, -0.09142106, 0.016426668, -0.044191137,
+    0.03420715, 0.0474633, -0.01477582, 0.049105916, -0.0034320503, -0.03139448, -0.111044936, -0.07864306, -0.051084287, 0.065711126, 0.12876658, -0.12202, -0.027842242, -0.105811596, -0.14328629, -0.114770725,
+    -0.064974144, 0.006587267, 0.12542559, -0.013338054, 0.026811115, -0.14724317, -0.1761424, -0.026464388, -0.0875452, 0.039997555, -0.30484286, 0.05047373, -0.017647844, 0.08294316, 0.1159344, 0.05923014,
+    -0.044898693, -0.010219205, 0.0043492434, -0.08162713, -0.12296695, -0.27184603, 0.15231627, 0.09516422, 0.10302012, 0.068740994, -0.0101895295, 0.0064713196, 0.07759202, -0.17159279, 0.14442664, -0.03993383,
+    0.0075007332, -0.020136774, -0.14565974, 0.13465007, -0.03696228, -0.028031258, -0.04509431, 0.04519377, 0.10248629, 0.1119868, -0.02679774, 0.09588653, 0.03929032, -0.008226489, 0.1070098, -0.06801385,
+    0.041179776, 0.063964605, 0.021889962, -0.06583948, -0.18215372, -0.20940937, -0.07055562, -0.037576765, -0.15361902, -0.057617325, 0.062489543, 0.03442661, 0.036984537, -0.0011846627, -0.041466556, 0.06709563,
+    -0.056563064, -0.085003994, -0.021713685, 0.030270956, 0.003906493, 0.036100082, 0.06701805, 0.060815424, -0.012245874, 0.013840564, 0.08744531, 0.08569108, -0.2190541, -0.03883491, -0.09030394, 0.000969277,
+    -0.11790242, -0.09802044, 0.010370646, -0.05969352, -0.15469265, -0.12966871, 0.10625502, -0.0656323, -0.10671932, -0.026060633, 0.061959557, -0.15990917, 0.009890024, -0.14218245, -0.12964901, -0.1412023,
+    -0.033513684, -0.07472866, 0.14172907, 0.12470127, 0.070015624, 0.119989604, 0.071799666, 0.052041486, 0.091975786, -0.01259324, -0.05758318, 0.0067276033, -0.20594534, 0.009656257, -0.07661511, -0.009804144,
+    -0.09450927, 0.060363684, -0.03438057, -0.030677414, -0.11463195, -0.055358615, -0.016514687, -0.039818063, 0.07654938, -0.08005685, 0.0054850136, -0.15367599, 0.0693384, -0.0132090375, 0.1502732, 0.04380682,
+    -0.06655732, 0.013639804, -0.0393437, -0.014384093, -0.033059612, -0.050824434, 0.13415143, 0.012953037, -0.010022861, -0.12735736, 0.014971293, -0.10062817, -0.08940165, 0.10145928, 0.046527818, -0.0892687,
+    0.095433675, -0.046683516, 0.093378715, -0.03506939, -0.10495072, 0.0052105067, 0.05520818, -0.16230772, 0.14571947, 0.06739147, 0.06793902, -0.06590424, 0.06992175, -0.008456321, -0.008375365, -0.033593606,
+    -0.07869269, -0.020467838, -0.04159706, -0.05569626, 0.0010864252, -0.011058612, -0.084468566, -0.012503031, -0.10112359, 0.12454813, -0.05951523, 0.07984368, 0.1470797, 0.05188063, -0.14882752, 0.028971592,
+    -0.074326195, -0.094619475, 0.11311271, -0.0007098187, 0.11739787, 0.06029494, -0.13124155, 0.043048356, -0.027071942, -0.058753185, 0.06286087, 0.13811222, -0.15225138, 0.0027225812, 0.06073976, 0.020346228,
+    -0.11827344, 0.011645755, 0.005351358, 0.05005906, -0.037755903, 0.055433623, -0.1307491, 0.053694997, 0.022194663, 0.08332119, 0.058791034, -0.09474192, -0.036031757, 0.01582796, -0.14953026, -0.055996094,
+    0.0019448106, -0.13527897, -0.05255287, 0.12766229, -0.023793316, 0.14648074, 0.106971756, 0.08956216, 0.08064602, -0.112526074, -0.14850597, -0.13449387, -0.090959564, 0.040190984, -0.004074204, 0.13974644,
+    -0.12515365, 0.015610593, -0.11526765, -0.04876406, 0.087597415, 0.1167201, 0.13633238, 0.14895724, -0.012191751, 0.15189803, 0.083772965, -0.11740561, -0.02779451, -0.14097005, -0.14150229, -0.11198643,
+    0.07286263, -0.009377764, -0.028909579, 0.066167966, 0.11891749, 0.10219985, -0.004475228, -0.0023022129, -0.10797335, 0.07934789, -0.13705434, 0.13973248, 0.025351213, -0.015264509, 0.06919311, 0.029711427,
+    0.0014732437, -0.1226702, -0.13239902, 0.06485897, 0.021866562, 0.12694806, -0.004243631, -0.106691, -0.14247166, -0.14884748, -0.07714992, 0.13198812, 0.051076394, 0.050317552, 0.0007303055, -0.0036052058,
+    0.05209577, -0.015157193, 0.13089003, 0.13522689, 0.08972427, 0.009192839, 0.07750104, -0.005979255, -0.04677663, 0.12646915, 0.05175194, -0.024594486, -0.0137869865, 0.13520376, 0.003513515, 0.03004849,
+    0.0057623237, -0.07716637, 0.09482412, -0.07160632, 0.019847706, 0.010722637, -0.05323115, 0.00021071732, -0.027275205, 0.073015854, -0.017595783, 0.059280917, -0.07879333, -0.09017064, -0.14267576, 0.019458503,
+    0.057155773, 0.13304229, -0.04681561, -0.07118168, -0.110400856, 0.089308605, -0.123741046, -0.10402307, 0.11250065, -0.051479287, -0.14398222, 0.048125133, 0.05518724, 0.041294366, -0.07515871, -0.089864254,
+    0.07638325, -0.0385686, 0.13329746, -0.011119038, -0.15031593, -0.048948027, -0.028226256, 0.059798196, 0.038537502, 0.14021794, 0.008570373, 0.11685173, -0.1090655, 0.012334898, -0.0025799125, 0.084326,
+    -0.03819813, -0.058632016, -0.05296003, -0.0887849, 0.07743026, 0.063719064, -0.06696167, 0.04911527, 0.0054375827, -0.14362963, -0.021745533, -0.07765171, -0.016997367, -0.03643378, -0.08602622, 0.030604973,
+    -0.09955405, -0.13414599, -0.08282132, -0.088116735, -0.053649113, -0.1410233, -0.04367298, -0.10944338, -0.021757245, -0.05375117, -0.061380707, 0.038979456, -0.1219479, 0.054969072, 0.060917914, -0.1190082,
+    0.09807058, -0.13970132, -0.13361411, 0.08590993, 0.14243723, -0.15201494, -0.14581507, 0.13755326, 0.045671225, -0.14307085, -0.070205264, -0.1529692, 0.0011402369, 0.02631858, 0.025838524, 0.13420297,
+    -0.128841, -0.10265785, -0.040535673, 0.11416589, 0.021914706, 0.053495407, -0.02124153, 0.09531848, 0.017693967, -0.11243798, -0.051566154, 0.024463847, 0.030365974, 0.067202926, -0.051039495, -0.0094483495,
+    0.12853797, 0.058043938, -0.0030981838, 0.058777113, -0.07371056, -0.13247836, -0.12523413, -0.13100292, -0.0584616, -0.086186305, -0.043224443, 0.043533638, -0.11905905, 0.07490071, 0.0013779551, 0.01279443,
+    0.02411722, -0.021107465, 0.15121798, -0.06996448, -0.0024789956, 0.0032703779, -0.046039347, 0.15090083, -0.05992875, 0.07074766, 0.053577144, -0.05631568, -0.122391716, -0.0049651004, -0.08101337, -0.062035684,
+    -0.091085, -0.056376655, -0.08750494, -0.065016694, -0.11620006, 0.14601769, -0.14458375, -0.04515069, -0.036312453, -0.12929696, -0.12712474, 0.073779844, 0.1378883, -0.14718401, -0.059023663, 0.04148909,
+    -0.050549634, -0.10079615, -0.14560705, -0.060770873, 0.1366615, -0.037673723, -0.09271928, 0.12703593, -0.13428685, 0.13624452, -0.067398235, 0.056528255, 0.13274625, -0.11353069, -0.0013733886, -0.003713985,
+    -0.147915, 0.0854412, -0.111150414, -0.08612167, -0.12989053, -0.11956735, 0.072045535, -0.09174946, -0.09145689, 0.01924549, -0.15947425, 0.05320306, 0.06081318, 0.027537182, 0.07292777, -0.04567415,
+    0.13744815, 0.13813649, 0.06453243, 0.059599955, -0.15406202, 0.09371934, 0.07333507, 0.039006934, 0.13148238, -0.00053808093, -0.12750615, 0.111417145, 0.10856368, -0.014682395, -0.07113122, -0.011931975,
+    -0.009723991, 0.094985425, 0.07195261, 0.07128983, -0.05212114, -0.08250931, -0.029102847, 0.14904304, 0.08643281, -0.011532903, -0.02202231, 0.04047598, 0.06377709, -0.11316081, 0.0105578005, 0.08255511,
+    0.12407111, 0.07903226, 0.068696484, -0.06574181, -0.010937966, 0.106510974, -0.058800343, 0.03458588, 0.08353374, 0.1259558, -0.14410482, -0.085245095, -0.025191346, 0.069695495, -0.11821069, 0.071469486,
+    -0.068993315, 0.08993368, 0.09111272, 0.13817005, -0.08734333, -0.20286894, 0.18077973, -0.18936667, 0.055787478, -0.13279237, 0.107383646, -0.13573426, 0.1105392, 0.018336283, 0.19935861, -0.10826373,
+    -0.061982695, 0.033181876, -0.2140302, -0.095566414, 0.107180044, -0.030938614, -0.064595655, -0.08655957, -0.0019144475, 0.029103788, 0.14475593, 0.10331933, 0.12524582, 0.1255778, 0.11208596, 0.13299428,
+    -0.0810854, -0.05337255, 0.11575355, 0.0077254726, -0.04172082, -0.09540058, 0.1364216, 0.035102256, 0.11456811, -0.05259071, -0.04244903, -0.029267859, 0.12917182, -0.14989617, 0.054402273, 0.040926766,
+    -0.09398346, -0.11625149, -0.03118854, -0.07230481, 0.07388066, -0.07822684, 0.09552872, -0.008713105, 0.093755305, -0.1604705, 0.103784576, 0.08183429, -0.058340758, 0.13413951, 0.0979185, -0.089356184,
+    -0.10264588, 0.16149244, -0.040172372, 0.13010587, -0.21342632, -0.061989643, 0.050541036, 0.06622445, 0.07268279, 0.013065818, 0.038754847, -0.020666473, 0.072261974, -0.052408222, 0.12741569, -0.011468253,
+    -0.12962504, -0.1241874, 0.025851065, -0.0857377, 0.016098598, 0.111652054, -0.004677123, -0.0738351, 0.13133374, -0.03202311, 0.14567176, 0.017125748, 0.14217992, -0.13068482, 0.13212076, -0.04956822,
+    -0.21932638, 0.070061445, -0.06797159, 0.09111029, -0.15962683, 0.04543961, 0.18575464, -0.12818894, -0.08965086, -0.16777492, -0.012965114, -0.16036545, 0.034537107, 0.13728079, 0.15808535, -0.117980674,
+    0.10789573, -0.014930164, -0.08840015, -0.09314328, 0.051842432, 0.068416186, 0.015091578, -0.18021727, -0.13878895, -0.07703423, 0.049907565, -0.0567932, 0.045160346, -0.13395181, -0.0812536, 0.09753028,
+    -0.10488916, -0.09176285, 0.102637134, -0.070281304, -0.10837896, -0.048646674, -0.08364348, -0.028517656, -0.083309755, 0.0020360532, 0.098699935, 0.077605404, 0.0011389886, -0.017839326, 0.03880421, 0.14606473,
+    0.03278774, -0.07725319, 0.067885734, 0.08836088, 0.03014647, 0.119004734, -0.05395319, -0.0047883587, 0.0586925, 0.08360935, -0.03563333, 0.11727967, -0.09468803, 0.12139569, -0.09732832, 0.03140316,
+    0.07185224, -0.1311852, 0.1159183, 0.044390112, -0.1565503, 0.13046347, -0.08722158, -0.1528096, 0.08085753, 0.10864986, 0.112084396, 0.06286671, 0.12572542, 0.09841793, -0.017386112, -0.04053125,
+    -0.07976464, 0.0042121126, -0.059566367, -0.14308259, 0.14663199, -0.09819471, 0.10333835, -0.09808463, 0.029330323, -0.16079938, 0.014060628, -0.059471376, -0.041725595, -0.13818723, 0.1498888, -0.1295973,
+    0.07427232, 0.11311165, -0.0068241213, 0.12175091, -0.12917899, -0.10885655, 0.05547257, 0.043130096, -0.075119644, 0.11947891, 0.098537005, 0.027924962, 0.0028421315, -0.13215242, 0.044372365, -0.061566226,
+    -0.052903704, 0.025370654, 0.044597585, -0.13132498, 0.14304793, -0.043384027, 0.04466973, -0.1300928, -0.167624, -0.13589735, -0.24766955, -0.007120514, -0.1318118, 0.06982559, 0.11673221, 0.0030891446,
+    -0.07479296, -0.07135286, -0.006850437, 0.11265706, 0.09396913, 0.064435534, 0.050038118, -0.08564646, 0.091293976, 0.0774095, 0.09435756, -0.052032083, -0.10156769, 0.06332842, -0.14958154, 0.11290545,
+    -0.09235828, 0.015684983, 0.019354062, 0.11684536, -0.12881859, 0.13184346, 0.07405331, -0.10476954, 0.0316367, -0.0988812, -0.18650112, 0.06761309, 0.12487087, 0.0081679085, 0.027019698, 0.095860206,
+    -0.02134437, -0.048534658, -0.095778696, 0.011786625, 0.04274332, 0.120892406, 0.091476, -0.039653875, 0.006561026, -0.09081651, 0.07833233, -0.04684875, 0.09941395, -0.09620679, -0.19757077, -0.04546795,
+    0.058694765, -0.02311454, -0.14010724, -0.04228717, 0.10210566, -0.15692016, -0.13771729, 0.12967266, 0.009059026, -0.067792386, -0.11253915, 0.11790483, -0.075746365, 0.17490666, -0.13805158, -0.22792983,
+    0.04069356, 0.00461839, -0.16960566, -0.14924245, -0.10267358, 0.13190448, -0.109053224, -0.008528785, 0.07630098, 0.060818315, 0.004907111, 0.102955386, 0.17919149, -0.0036094785, 0.073851906, 0.070874855,
+    0.029103734, 0.038164735, -0.11309523, -0.1487386, -0.029198205, -0.0660104, -0.16630419, -0.006072609, -0.09848175, -0.012342153, -0.14455526, -0.10210344, -0.10228018, 0.15203355, 0.06558597, -0.0126959,
+    0.12098558, -0.09846304, -0.1554233, -0.13930427, 0.152309, 0.07947698, -0.12978001, -0.09208115, -0.07716564, -0.13198368, 0.24268958, 0.040254146, 0.051157683, -0.07133721, 0.051913034, 0.05939123,
+    -0.06757044, -0.019402526, -0.14845726, 0.11099596, -0.08426494, -0.11997085, 0.01799735, 0.002122359, 0.015927328, -0.030001078, 0.023933673, 0.031352833, 0.10964553, 0.17158963, -0.13027127, -0.14161873,
+    0.13082507, 0.045800358, -0.13638772, -0.14294124, 0.07473401, 0.104159415, 0.0894827, 0.041378565, -0.04856237, -0.109763786, 0.077018045, 0.07309513, 0.08681745, -0.11123256, -0.067786664, -0.13074675,
+    0.0904968, -0.05119075, -0.14710766, -0.058137402, -0.026141098, -0.0176441, 0.005618716, -0.10002383, 0.05251563, -0.153658, -0.11028413, 0.05028288, 0.13870786, -0.0003035955, 0.13552631, -0.21504873,
+    0.07338295, 0.017336795, -0.120685376, 0.0016183925, -0.03893852, -0.08367332, -0.043072227, 0.06952634, -0.089914344, 0.046303228, -0.066420704, -0.07445389, -0.10619532, 0.10895963, 0.047605872, 0.13787834,
+    -0.014947271, 0.09785031, 0.11636682, -0.0434602, 0.043816306, -0.09341386, 0.06550418, 0.018551301, 0.016430091, 0.01560811, 0.23207246, 0.07922208, -0.049982693, 0.035786968, 0.04639939, -0.03510642,
+    -0.028855467, 0.113252535, 0.024335058, 0.11025896, -0.1114752, -0.14838184, -0.022173854, 0.00071694335, 0.084369965, -0.11580175, 0.01873186, -0.11725816, -0.08568535, 0.09401137, -0.039423533, -0.06744515,
+    0.107975975, 0.13060366, 0.12303457, -0.06997257, -0.07208125, 0.06647677, -0.123616055, 0.03730563, 0.0064417804, -0.00774642, 0.098233074, -0.05860394, 0.0512296, -0.1308242, 0.12992328, 0.10858783,
+    -0.0018748424, -0.07817192, 0.126447, -0.030005228, -0.16011852, 0.04648038, -0.09196768, -0.10263566, -0.11232061, -0.035186328, -0.016382968, -0.025003582, -0.0041136923, 0.0926979, 0.06096652, 0.02587054,
+    0.012027262, -0.0009043254, 0.009038284, -0.09415631, -0.07356703, -0.061490938, -0.04030626, -0.10112565, -0.12375212, 0.056550354, 0.29346636, 0.12045318, 0.029780764, 0.08005932, 0.063995734, -0.119664066,
+    0.123394415, 0.048428006, 0.10145117, -0.077990495, -0.14414218, -0.011888234, 0.12044187, -0.05068575, -0.005579181, 0.015569097, 0.044368234, -0.082234904, -0.14092387, 0.090119824, 0.21873066, -0.09817303,
+    -0.0035652863, -0.00485007, -0.07852186, -0.058041204, -0.08466311, 0.020243526, 0.12655823, -0.016998412, -0.12793656, -0.069848314, 0.033796582, 0.00064177846, -0.0017271877, -0.04686779, -0.04675569, 0.017933141,
+    0.10691537, -0.15702365, -0.034668207, 0.13031712, 0.056687757, -0.083723746, -0.007654324, 0.038422376, -0.00059052, 0.06983307, 0.002832622, -0.10032452, 0.08403061, -0.11315095, 0.05746664, 0.085387565,
+    0.13100353, 0.13863505, -0.029725946, 0.0632494, 0.08429215, 0.13914981, -0.086694464, 0.10071467, -0.03255269, 0.09046435, -0.14644165, -0.1462979, 0.049486566, 0.08323799, 0.054261077, -0.07694043,
+    -0.08967683, 0.088180594, -0.09802446, 0.124299146, -0.09351906, -0.1356422, -0.049509622, 0.083268076, -0.1461146, 0.056258157, 0.12617902, 0.084075734, 0.13883673, -0.0004978694, 0.058680788, -0.045080684,
+    -0.14376126, 0.14252628, 0.036331907, -0.09507328, -0.020118136, -0.05967893, -0.12196891, -0.06599926, -0.027169893, -0.021382868, -0.033400163, -0.065561935, -0.046931203, 0.018052837, 0.10736744, -0.13621591,
+    0.11077164, 0.031397432, 0.009267726, -0.1453763, -0.12011132, -0.08660431, 0.08676356, 0.09893836, -0.054034557, 0.08695705, -0.11871643, -0.13934988, 0.020873614, -0.11366334, 0.044199243, 0.11245545,
+    0.058685064, -0.057467148, 0.13016249, -0.0004947225, 0.029450502, -0.1338952, 0.09812564, -0.06322021, 0.126012, -0.027112022, -0.099872924, -0.0945134, 0.10425869, -0.0656914, -0.15003453, 0.016652288,
+    -0.12682258, -0.12744786, -0.031653833, -0.12009645, 0.051492065, -0.10971959, -0.028372481, -0.04760646, 0.06299227, -0.08238718, -0.03852472, 0.13194518, -0.02297055, -0.041989665, -0.10966611, -0.03694365,
+    0.12897435, -0.14854869, 0.06477596, 0.02868205, 0.13526864, -0.09044111, 0.13133593, -0.0759885, -0.0727396, 0.13399155, -0.11798544, -0.08445784, 0.06483175, -0.11419599, 0.07346542, 0.12149822,
+    0.00051373895, -0.1476179, -0.0015238828, 0.075173244, -0.1264874, -0.12256239, 0.21362242, -0.24748948, 0.051976934, -0.078099005, 0.034558304, -0.022693895, -0.11734342, -0.0886008, 0.3686338, -0.09260097,
+    -0.08773061, -0.18027376, -0.02376073, -0.09852722, 0.07942457, -0.023584327, 0.17280054, -0.055960976, -0.17908233, -0.07624086, 0.37590966, -0.14326175, 0.10281609, -0.110471256, 0.10116615, -0.002817479,
+    -0.19254686, 0.11880905, -0.072013274, -0.07879977, 0.072567254, 0.014200353, 0.05078532, -0.031387627, -0.02360198, -0.18345791, -0.1856654, -0.20601927, 0.025228847, -0.15002747, 0.1745336, -0.026040828,
+    -0.066731706, -0.15015268, -0.18861075, -0.09360826, -0.098040126, -0.06711992, 0.014533176, -0.014995039, -0.18681905, -0.17446585, 0.30331746, 0.0475756, -0.043571234, 0.111057214, 0.049188375, 0.092483446,
+    0.06529028, -0.040310185, 0.022155585, -0.07502782, -0.12855151, -0.014733146, 0.055790875, 0.009757485, 0.02050834, -0.10374715, 0.078382805, 0.09035901, -0.19892809, -0.016989443, 0.30886453, -0.117797926,
+    -0.015960649, 0.047428023, -0.20050235, 0.1286389, 0.08250252, -0.0014167811, 0.1991402, -0.22440903, -0.087424695, -0.05387437, 0.3450979, -0.075514935, -0.00013847607, 0.11551493, -0.12041942, -0.06137749,
+    -0.12101921, 0.04192472, -0.021032175, 0.031981576, 0.013805186, 0.011292397, 0.25051174, -0.22576939, -0.15422854, -0.14645231, -0.14091524, -0.13047253, 0.044486012, -0.00027070363, 0.1552136, -0.020937854,
+    -0.09701338, -0.07258561, 0.05499635, 0.1416018, 0.046523977, -0.09639212, 0.054411143, -0.13079126, 0.060336903, -0.17660925, 0.3534524, -0.008831072, 0.1153571, 0.010707921, 0.09609758, 0.08094667,
+    -0.005308505, 0.044033337, 0.118775584, -0.005334072, -0.13320096, -0.18771996, 0.030207621, -0.24242072, 0.044602994, -0.09572865, 0.10823112, -0.14523523, -0.08109065, 0.009171763, 0.22145683, 0.14477864,
+    -0.03981191, -0.026697967, -0.07219852, 0.04623072, -0.122546524, -0.008563801, 0.02006421, -0.2054266, 0.011433234, -0.12707242, 0.05845551, 0.07792053, 0.0060201446, 0.13005146, -0.120045766, 0.059213378,
+    -0.16473551, 0.13265242, -0.08215102, 0.08191774, 0.072817266, -0.11506214, 0.23017463, -0.28133845, -0.051793966, 0.033268876, 0.14769435, 0.051577933, -0.10344412, 0.066161186, 0.31333834, 0.06248788,
+    0.038483232, 0.042502157, -0.003183832, -0.037465245, -0.12695463, 0.089052744, -0.009315334, -0.1489668, -0.012233397, 0.05046381, 0.2719213, -0.044779897, 0.04061894, -0.13701987, -0.09455311, 0.02867727,
+    0.046619784, -0.06893819, 0.095684685, -0.0043682903, 0.08682832, -0.19753255, 0.07928858, -0.24315216, -0.018014979, 0.007631109, -0.021039922, -0.065842055, -0.10178094, 0.11597003, 0.31535622, -0.03203835,
+    0.10275512, 0.031298395, -0.09253936, -0.0061213155, -0.08065305, 0.0065609016, -0.11707369, -0.020205297, -0.041513763, -0.1534518, 0.11513916, 0.04590959, -0.1275295, 0.13942663, 0.009409563, 0.11950715,
+    -0.06399603, 0.13721015, -0.05241511, 0.0693625, -0.105204016, -0.015855946, -0.05650484, -0.19870864, -0.08111844, 0.02294324, -0.051833853, -0.000872293, -0.12267514, -0.111235246, 0.12032206, -0.047240905,
+    0.078974746, -0.061206277, 0.066697024, -0.011233214, -0.14394377, -0.021831756, 0.023404313, -0.14883372, 0.0389338, -0.19938305, 0.2200991, 0.1234553, 0.15202549, -0.02686922, 0.14898492, 0.123180375,
+    -0.06321902, -0.116954714, -0.04526674, -0.19697472, -0.10626346, 0.02564059, 0.03191072, 0.09804641, -0.097466476, 0.07472188, 0.0052570133, 0.0036301778, 0.067154415, -0.11835733, 0.17670237, -0.199505,
+    -0.18222491, 0.117861725, 0.04388882, -0.0060117245, 0.10830738, 0.06444719, 0.23968972, -0.0086336695, -0.1640344, 0.080974564, 0.045099646, -0.099623665, 0.13185313, -0.11458578, 0.12164189, -0.054948237,
+    0.06382002, 0.08406788, -0.067456245, -0.17410207, 0.15162542, -0.13598396, 0.123489074, -0.059448678, -0.08665157, 0.122171395, -0.006096855, -0.030762216, -0.06197909, -0.11338432, -0.0102464035, -0.11419224,
+    -0.123812996, 0.056150798, -0.09658938, 0.067916594, 0.06551249, 0.16654345, 0.17340241, 0.13079607, -0.031084182, -0.1425328, -0.037837125, -0.09888117, 0.08666684, -0.03434928, 0.11483419, 0.059106197,
+    0.10729663, -0.031251494, -0.022032566, -0.15178439, 0.108878046, -0.073054805, 0.09722798, -0.111784205, -0.13006856, 0.1200219, 0.047564767, -0.13417283, -0.17968392, 0.080454186, 0.07510789, -0.17347649,
+    -0.13500328, 0.06667585, 0.0043893126, -0.037838, -0.024236677, 0.1762794, 0.061037768, 0.037540838, -0.012171923, 0.07513307, 0.14851505, 0.0031915973, -0.12922247, -0.21301751, 0.061737686, 0.12610467,
+    -0.0933917, -0.031533673, -0.073181525, 0.06868483, -0.0026244705, 0.13306163, 0.054684434, -0.08659342, -0.1282945, -0.07542833, -0.058600426, 0.05893953, -0.17376894, 0.1459793, 0.1514613, -0.08403907,
+    -0.14330868, 0.045950297, 0.07787261, -0.07118123, -0.11426161, 0.16775146, 0.010927611, -0.10046412, -0.057695627, -0.110297695, 0.18802483, 0.06657952, 0.09207088, -0.08235926, 0.11446655, -0.10362876,
+    -0.13810308, 0.12880719, 0.1232151, -0.00678491, 0.05397988, 0.04457289, -0.015276864, -0.028878227, -0.10453178, -0.14236756, -0.064788446, 0.1217117, -0.15144403, 0.03431422, -0.20607139, -0.0026440662,
+    0.08708265, -0.14785197, 0.018833704, -0.042261563, 0.058836315, 0.09482596, 0.119622774, 0.15835367, 0.01309743, -0.017850934, -0.19373326, 0.02953853, -0.07413109, 0.13509637, -0.07852836, 0.11565226,
+    0.10857172, -0.01462204, 0.11567734, 0.12686051, 0.07948842, -0.04053516, -0.10825374, 0.012523338, 0.004122674, 0.11503657, 0.085600905, 0.013846159, 0.03200183, 0.01177637, -0.13456887, -0.038092207,
+    0.114434324, 0.0074634254, -0.06847392, -0.11356999, -0.1502868, 0.029132085, 0.09903867, -0.09397768, -0.09238439, 0.06070875, -0.14483534, -0.021625314, 0.09034626, -0.1456392, -0.014627406, -0.119624875,
+    -0.09277606, 0.06770695, -0.12406083, -0.07774661, -0.0548402, -0.07398169, -0.24722333, -0.0754137, 0.09697519, 0.11401691, 0.048039943, -0.030265877, -0.10749542, -0.02686923, -0.10827066, -0.13727593,
+    0.10846286, -0.12334994, 0.14841981, 0.09726432, 0.0923729, 0.10514147, -0.0113420775, 0.08834592, 0.10455041, -0.10775214, -0.19498874, 0.14022529, -0.04532389, 0.12453467, -0.023057347, 0.10329768,
+    -0.13393264, 0.033852983, 0.028033992, -0.038242836, -0.12085751, -0.04713787, -0.24639888, -0.08067492, -0.010069837, -0.07226879, 0.06636349, -0.0763083, 0.041141666, -0.053057797, -0.2889608, -0.077526465,
+    0.13939497, 0.10179986, -0.038146593, -0.023209857, -0.04150333, 0.08839864, -0.030761896, 0.08171802, 0.01820899, -0.062242195, -0.34693027, 0.09279635, 0.1337038, 0.11785068, -0.13260423, 0.11407545,
+    0.07532391, -0.06257193, 0.014661595, -0.05528239, 0.02422981, 0.04287082, -0.055401057, -0.016365692, -0.1198422, -0.09855748, 0.071977824, 0.09433703, 0.0008850992, 0.12575017, 0.011795312, 0.12953188,
+    -0.06586294, 0.086699605, -0.06670029, -0.046580806, -0.12908694, 0.023039684, 0.05169478, 0.12874617, 0.033300743, -0.010930359, 0.079765186, 0.08663224, -0.0383427, -0.069634944, -0.063842505, 0.08406904,
+    0.011127606, 0.13927908, -0.044723712, -0.011526883, -0.024152324, 0.14611982, 0.021040097, -0.12402804, -0.033965446, -0.08321946, 0.061458707, -0.035663508, -0.01009962, -0.13618428, 0.045391515, -0.041364595,
+    -0.12738508, -0.05679299, -0.053541474, -0.08003704, 0.03629613, 0.0438703, 0.075883344, -0.14764085, 0.067193985, -0.013645247, 0.13148446, -0.08670279, 0.12516363, 0.014768764, -0.0066414326, -0.09070343,
+    -0.052723795, 0.04039474, 0.03386368, 0.051231265, 0.028822705, -0.11914694, -0.1024618, 0.14929639, -0.087529406, -0.150953, 0.10766579, 0.09191768, -0.102141, -0.04656186, -0.12146656, -0.0071780235,
+    0.036739826, 0.13117434, 0.012147397, -0.049200542, -0.13603403, -0.13604324, -0.032902047, -0.08754937, -0.1015735, 0.097411886, -0.12900051, 0.14477246, -0.15265617, 0.05848095, 0.029829353, 0.08906759,
+    0.04684201, 0.07275644, 0.15217938, 0.0016925186, -0.009789035, -0.078738615, 0.11861937, 0.010503128, 0.13047369, -0.11886165, 0.15183218, 0.09692292, 0.13665543, 0.061070815, -0.13226743, -0.006870404,
+    0.06810114, 0.11470629, -0.063224696, 0.08818513, -0.12666395, -0.07198582, -0.14574678, -0.13811247, 0.10464643, 0.015062302, -0.04714846, -0.099753335, -0.15168376, 0.108229235, -0.09521921, 0.06687692,
+    -0.095303774, -0.058099594, 0.14430144, 0.034885336, -0.062190577, -0.02239491, -0.29447088, -0.049341246, 0.033242475, -0.032331772, 0.06343531, -0.029900508, 0.09441646, -0.0266062, -0.24240309, 0.08091098,
+    -0.14335695, 0.047736302, -0.15057047, 0.084693834, 0.11549398, 0.040087, -0.081160486, 0.13783742, -0.10011712, -0.1284251, -0.38012788, 0.09912043, 0.12084903, -0.08619675, -0.14097767, -0.0754152,
+    -0.12106018, -0.038699172, -0.06523991, -0.02464942, 0.10147576, 0.056747213, -0.22005208, 0.13744418, -0.07996314, 0.043008238, 0.023142537, -0.11728936, -0.045271605, -0.055316158, -0.27147686, -0.05773532,
+    0.06931733, 0.012828335, -0.00047647953, 0.081183344, -0.13684772, 0.04018639, -0.18337503, 0.052280903, 0.11025864, 0.09526744, -0.19959733, 0.08660752, 0.0062803356, 0.097754665, -0.025577882, -0.15226886,
+    -0.13757938, 0.11752965, -0.09537542, 0.075626925, -0.04495366, 0.15143926, -0.28019407, 0.063625365, -0.048887923, -0.08265152, 0.066892974, -0.10713451, 0.026847735, -0.070515454, -0.18089317, 0.06742833,
+    -0.13764305, -0.1506514, 0.1520607, 0.063384995, -0.08674096, -0.13023907, -0.086758584, -0.09319392, -0.053894553, -0.113654085, -0.30547038, 0.1543935, 0.13941641, -0.093264766, -0.11807555, -0.05388581,
+    -0.11828838, -0.14393915, 0.08085005, -0.077783406, 0.11203842, 0.12678118, -0.25223735, -0.10922187, 0.03486225, 0.15083902, 0.040342532, 0.15881771, -0.11742003, 0.13722563, -0.3041013, -0.038016442,
+    -0.09197414, -0.078544654, -0.021414176, 0.13098884, -0.052355856, -0.0040407367, 0.06838532, -0.12902194, -0.15711302, -0.124148235, -0.23287553, -0.080379255, -0.08862477, -0.03450278, 0.12841587, 0.11872712,
+    0.06994916, 0.005840816, -0.014749841, -0.043327484, -0.03500953, -0.1128652, 0.007267561, -0.2088814, 0.13459554, -0.048826933, -0.16549626, -0.17965923, 0.028561758, 0.123860925, 0.17791578, 0.07916509,
+    -0.107566625, -0.11219403, 0.06580675, -0.07536527, 0.06669397, -0.02778859, 0.026938822, -0.05712635, -0.08122144, -0.049936492, 0.31607604, -0.0009851496, 0.11130129, -0.06536832, -0.105942346, -0.005754759,
+    -0.19390318, -0.06209201, 0.12059674, -0.13077593, 0.09554765, -0.02477382, 0.28299284, -0.17605887, -0.117765486, -0.15598358, -0.1041086, -0.024151638, 0.09534171, 0.09626818, 0.19998658, 0.13851628,
+    -0.18115461, -0.119180426, -0.023202116, 0.15486278, 0.054316513, 0.122275874, -0.031734206, -0.06664664, -0.16310756, -0.18075648, 0.2077115, -0.044942908, -0.123269826, 0.09230331, 0.007295607, 0.15404281,
+    -0.0125884935, -0.16760282, 0.042622037, -0.025733965, -0.096953765, -0.08167324, 0.07705335, 0.008021408, -0.10171932, -0.03069971, 0.04069687, 0.03688611, -0.08184962, 0.14717305, 0.31832877, -0.07924794,
+    0.030255102, 0.08518876, -0.21701974, 0.070909776, -0.010125079, -0.009051535, 0.13885444, 0.005473204, -0.11950304, -0.060110535, 0.18694367, 0.14605178, -0.14088333, -0.14892295, 0.09644971, 0.1568718,
+    0.093231656, -0.0038146744, -0.09362674, -0.15717581, -0.13686472, 0.0052545764, 0.12653148, -0.015489125, 0.08953431, 0.008653778, -0.18140581, -0.053016707, 0.039359406, -0.1405741, 0.18084106, 0.0041464646,
+    -0.03483321, -0.15363982, -0.1710965, -0.038395688, -0.14885382, 0.034301884, 0.15459618, -0.19129692, -0.24075522, 0.07197767, 0.29620168, -0.12710996, 0.025594437, 0.064954646, 0.016363721, -0.07911723,
+    0.0185863, -0.07036297, -0.09541629, -0.00077628164, -0.18370219, -0.1863152, 0.17902206, -0.049678046, -0.13464987, 0.056875166, 0.01915136, -0.13646509, -0.100863025, -0.13251165, 0.26415324, -0.09574755,
+    -0.07883569, -0.027656784, -0.10840683, -0.15298809, -0.09070679, 0.06292651, 0.14834517, 0.09249633, -0.04932759, -0.16894746, 0.3611875, 0.04389087, 0.027816027, 0.08966297, -0.12205777, 0.10591873,
+    -0.018184755, 0.039331947, -0.15326756, 0.062199485, -0.12590656, -0.06621286, 0.058989353, -0.094865106, 0.01632655, 0.061482314, 0.1319474, -0.08512199, -0.058119614, -0.048785076, 0.19267459, -0.11565008,
+    -0.025187673, -0.019962331, -0.022058116, -0.027216895, -0.058058638, 0.039077424, -0.10647678, -0.08665872, -0.16536133, -0.020278292, 0.113794014, 0.067468375, -0.043145347, -0.021774758, 0.1269517, -0.09298233,
+    -0.04553972, 0.051863004, 0.032292705, 0.06894918, 0.061357416, -0.062768124, 0.0028623233, -0.20370217, -0.01274089, -0.10612276, 0.06869501, 0.10626618, 0.041538958, 0.07436739, 0.124268934, -0.07697419,
+    -0.12740809, 0.034548845, -0.09742257, 0.036350876, 0.115295716, 0.11190589, -0.115986116, -0.1264155, -0.1668117, -0.052153364, 0.2526457, 0.08240862, 0.07499911, 0.15756685, 0.10328569, 0.050602663,
+    -0.097358234, 0.042838205, 0.15017535, -0.12547013, -0.11160578, -0.15636538, 0.070811585, -0.07645787, 0.13281894, -0.14671916, 0.043845743, -0.11497755, -0.1570565, -0.041664794, 0.21713866, -0.048617665,
+    0.12928039, 0.06223483, 0.03132303, 0.066309325, -0.037618283, 0.027246036, 0.1256335, -0.031776115, 0.0024320087, -0.021464514, 0.3490015, 0.054060973, 0.04702409, -0.017375438, 0.07011873, -0.11221438,
+    0.059751313, 0.07446736, 0.030904263, -0.027083453, -0.07864518, 0.09557964, -0.113681175, -0.11721917, 0.019281574, 0.023298811, 0.10842095, 0.111898586, 0.018279286, 0.012736743, -0.07822333, 0.0644404,
+    0.04566456, -0.1278037, -0.0380819, 0.052402772, -0.10058655, 0.1327391, -0.037211295, 0.02780552, 0.095379524, 0.060233895, -0.061945423, 0.079246536, -0.10379995, 0.09221491, 0.10846054, 0.113360845,
+    -0.041966613, 0.05631191, -0.074201, 0.061560005, -0.08752894, 0.056025185, 0.15574121, -0.073456496, -0.12041504, 0.073546365, -0.115242705, 0.12325974, -0.096620664, 0.027394896, 0.06661908, -0.11407231,
+    0.07261929, 0.10836386, 0.14660937, 0.028545521, -0.068602555, -0.025767354, 0.10463054, -0.06706841, -0.15950213, -0.09835276, 0.124344304, 0.0073329727, 0.07629936, 0.04270732, -0.14591295, 0.05070764,
+    -0.15227517, -0.13118578, -0.09443933, 0.12296573, 0.10432524, 0.03274227, 0.08074569, 0.03876151, 0.10514926, 0.1042745, -0.034016605, -0.034144618, 0.08250853, 0.07055209, -0.07497594, -0.0046445457,
+    -0.057856087, -0.13959949, 0.13871107, -0.03132498, -0.119558685, -0.04355213, 0.015821094, 0.08205548, 0.061617374, 0.07630904, -0.15885885, -0.06529347, -0.055945065, -0.030825451, -0.08622844, -0.010219936,
+    -0.020180842, 0.11943551, -0.13312416, -0.12089844, 0.022466686, -0.087764464, -0.03946219, -0.055583917, -0.08383245, 0.12751082, -0.11531082, 0.05944857, -0.11074691, 0.13157949, -0.09829171, -0.13845624,
+    -0.07045527, -0.02558232, -0.063188426, 0.12358453, 0.00051094603, 0.014951751, -0.15907577, 0.029240338, -0.17803843, -0.08595765, 0.0014963971, 0.0805227, 0.11223403, -0.043676097, 0.065784745, 0.06131152,
+    -0.061573446, -0.1419195, -0.09860106, -0.050465785, -0.14437369, -0.12057385, -0.055867426, 0.0015392166, -0.15627569, 0.056378674, -0.1400895, -0.13462964, -0.030611852, -0.14955013, 0.056259435, -0.13028395,
+    -0.15732878, 0.13186328, 0.09720302, 0.044905808, 0.078030705, 0.16056642, 0.0012504902, -0.053726934, 0.03837241, -0.123921804, 0.2024118, -0.08716731, -0.044441696, 0.006569809, 0.105383456, -0.076386146,
+    0.07274259, -0.084667385, 0.15626061, -0.018248372, 0.0386451, -0.009432293, 0.06402682, 0.094729684, 0.09520956, 0.05226166, -0.07832939, -0.119468145, 0.02727626, -0.10553907, 0.1867136, -0.020128185,
+    -0.13038567, -0.14707172, -0.1283527, -0.15974098, 0.093353584, 0.17504391, 0.09306555, 0.07297153, -0.05436944, -0.10392613, 0.18427762, -0.12031917, -0.11014324, -0.026128722, -0.13954002, 0.036452223,
+    -0.010092461, 0.06599765, -0.10579921, 0.013899073, -0.07779967, 0.10092875, -0.06934116, -0.14244403, 0.046838798, -0.008718799, -0.23812158, -0.035876956, 0.06083108, -0.053318612, 0.13575312, -0.24850191,
+    -0.13813928, -0.058090605, 0.09174688, 0.06250288, -0.028466735, -0.013196789, 0.17621024, 0.13394819, 0.04889125, -0.062482998, -0.03722385, 0.08655423, 0.10543647, 0.032765277, -0.061625943, -0.09954352,
+    -0.11725081, 0.04825217, -0.064776674, -0.19607466, -0.06472453, -0.15641715, -0.11862896, 0.0033461906, 0.04708741, 0.095831685, -0.17427339, 0.05032656, -0.21338907, 0.12391681, 0.17169802, -0.16157548,
+    -0.13354164, 0.07298906, 0.006763949, 0.019383345, 0.046288826, -0.01748546, -0.00483932, 0.15001254, -0.008207942, 0.044603158, 0.12495295, 0.11205217, -0.12723245, -0.14606251, 0.0146101285, 0.018301897,
+    -0.10491739, -0.003058299, -0.024481218, -0.106826924, 0.038570996, -0.05183888, 0.121176794, 0.11359806, -0.0073826243, 0.14181031, -0.042171378, -0.024366375, 0.027347809, 0.086414285, -0.16233695, 0.096513145,
+    0.0074041523, -0.06636013, -0.10746967, 0.09489336, 0.08618423, -0.0343001, 0.079325765, -0.12302691, 0.026532296, -0.04332344, -0.21803714, -0.02003712, -0.0313884, 0.081508555, -0.13408068, -0.015549018,
+    -0.007816089, 0.09404696, 0.02992626, 0.09965616, -0.1175473, 0.00513432, 0.029072179, 0.055299625, 0.043541376, -0.08757162, 0.10036033, -0.018437952, -0.050713312, -0.09849959, -0.1868715, -0.06980937,
+    0.01648788, 0.023671294, 0.04404205, 0.11230438, -0.14530005, -0.10691904, -0.0857501, -0.020401023, -0.18050788, 0.13008277, -0.1916195, 0.13238637, -0.05791344, -0.065479875, 0.012751356, -0.039063096,
+    0.018976914, 0.09703622, -0.10321602, 0.12292581, -0.13801585, 0.08624114, 0.10192137, -0.060407132, 0.08162054, -0.010747212, 0.029254109, 0.14819774, 0.07460365, 0.0055728, -0.18193236, -0.0070040887,
+    -0.021384148, 0.14139448, 0.14220238, -0.10281753, 0.10133843, -0.14475247, -0.042844523, 0.13708524, 0.050312724, 0.14710213, -0.19888733, 0.1477334, 0.027712693, 0.0039545894, 0.06674043, 0.07595228,
+    -0.11006597, -0.06386136, 0.14538981, 0.14719704, 0.14371885, 0.114850685, -0.096441306, -0.06339877, 0.008677835, -0.13385609, -0.011291307, 0.07096837, -0.1458214, -0.023634747, -0.041042343, -0.059434935,
+    0.0907491, -0.021220213, -0.02740046, 0.13794494, -0.07073797, 0.083888374, 0.01626109, 0.0053499327, -0.0073227407, 0.041176412, 0.05995038, 0.09137955, -0.13261111, -0.08470393, 0.016572213, 0.09824282,
+    -0.10023019, 0.1761708, -0.13729005, 0.08473104, 0.09811202, -0.015217992, 0.006764615, 0.15308744, 0.11564262, 0.07500078, 0.05200397, -0.15294065, 0.15957615, 0.079046205, -0.023379005, -0.034718446,
+    0.10508827, 0.0071723354, 0.0037646347, 0.09798719, 0.08995949, -0.0129951695, -0.09345142, -0.03370032, -0.041021634, -0.03992216, 0.0108777, -0.15113539, 0.007728085, 0.10671675, -0.121883355, -0.1200396,
+    -0.085989684, 0.06578722, 0.0027584282, -0.11227226, 0.049819354, 0.1503718, -0.029169334, 0.026882736, -0.099432066, -0.09883469, 0.05060659, -0.009218171, -0.06331308, -0.1454616, 0.029825836, -0.11605216,
+    0.018128097, 0.0035085948, -0.034959972, 0.15282752, 0.03669214, -0.22825645, -0.061895747, -0.10464708, 0.19897644, 0.011826797, -0.037118435, -0.06729896, -0.033989064, -0.027337298, -0.09333611, 0.051684875,
+    -0.029385917, 0.003098463, 0.10477127, 0.07054259, -0.013268062, 0.058283195, 0.041040435, 0.102462426, -0.073718995, 0.10081324, 0.046095286, 0.14331047, 0.12993024, -0.039478227, -0.018192466, 0.07338305,
+    0.19581957, 0.12651159, -0.057450853, -0.060665883, -0.12846455, -0.24731399, -0.25180435, 0.03179821, 0.060859207, 0.038670864, -0.063943096, -0.045556717, -0.14290936, 0.14448544, -0.041644678, -0.036325756,
+    -0.021811336, 0.039126307, 0.1322161, 0.13591368, -0.055859398, 0.09500758, -0.17042185, -0.07488054, -0.06445282, 0.0658509, 0.17362377, 0.103971556, 0.102344766, -0.0901902, 0.03495595, 0.055195004,
+    -0.005617636, 0.13558455, 0.10875336, 0.06103216, 0.031455625, -0.011236598, -0.13208203, 0.025778165, 0.0952111, -0.1288694, 0.057044357, 0.03317365, -0.03898412, 0.043623928, -0.02637427, -0.039747205,
+    0.11767744, -0.025790505, 0.053025942, 0.011789938, 0.1138403, -0.10964732, 0.031404413, 0.02255574, -0.070345975, 0.024669373, -0.14903325, -0.12940727, -0.03229968, -0.019013675, 0.18090956, 0.07532656,
+    -0.01352273, -0.05123048, 0.026590608, -0.08383776, -0.15594164, 0.07019025, 0.14305641, 0.121233866, 0.053321194, -0.103623286, 0.1671457, -0.05138156, -0.083666675, -0.16712935, -0.0023665824, -0.01087715,
+    0.026071647, 0.062021594, -0.0068581128, 0.07031703, 0.030396853, -0.025165461, -0.07141772, 0.06498458, -0.08832015, 0.024001488, -0.11664251, 0.046961203, 0.075805746, 0.004770275, 0.02916158, 0.11420289,
+    -0.048704226, -0.056442093, -0.06823279, -0.011599382, 0.11757212, 0.13304727, 0.019638393, 0.0046194918, -0.009552253, -0.106282845, 0.15969038, 0.055459417, 0.044998724, -0.15924498, 0.059555415, -0.037833687,
+    -0.04437541, -0.11455883, -0.14310052, 0.12823924, 0.10691257, 0.11518425, 0.039746948, -0.098526604, 0.12792031, 0.10072458, -0.02056089, -0.073971346, 0.025732765, 0.016417203, 0.18104525, 0.06955154,
+    0.12381947, 0.09658158, 0.085853636, 0.085197985, 0.017421562, -0.03953104, -0.057494882, -0.05501295, -0.1216602, -0.14919223, 0.023321839, -0.095349915, -0.11650104, 0.07654618, -0.06206868, 0.13468558,
+    -0.14489919, 0.047734577, -0.11564646, -0.14719148, 0.12249935, -0.000780362, 0.152747, -0.06520829, 0.007999222, 0.125761, 0.026002806, -0.079561874, -0.16874318, -0.12956288, -0.09265046, -0.06881935,
+    -0.08189555, -0.12453535, 0.007073576, 0.103410274, -0.071554475, 0.030297987, -0.06507529, 0.07167591, -0.00695461, 0.06286159, 0.07755055, -0.02501081, -0.059940275, 0.0782979, -0.013659434, 0.078172356,
+    -0.08888276, 0.04727204, -0.10369387, -0.13766903, -0.09542858, 0.11432116, -0.051256776, -0.1019395, -0.03087245, -0.022168543, -0.14904958, -0.019853875, 0.0010580256, 0.111131236, -0.039807063, -0.11337881,
+    -0.012781143, -0.038426388, -0.044865735, 0.12432505, 0.008870542, -0.09387085, -0.144538, 0.091547064, -0.0066585364, 0.11750526, 0.06530476, -0.040788367, -0.015185446, -0.12119132, -0.029536255, 0.0821961,
+    0.015156503, 0.06266008, -0.030059226, -0.13193282, -0.07294684, 0.12967804, -0.14486706, 0.0013507754, 0.09337276, 0.054535985, -0.019905478, -0.11707935, -0.01677792, -0.066845745, -0.081577756, -0.012705922,
+    -0.11528522, 0.016838022, 0.082091264, -0.13632643, -0.1019523, -0.12807782, -0.0009276867, 0.08124203, -0.11499966, -0.03860091, 0.10616887, -0.13454358, 0.08794497, -0.10986055, 0.110202774, -0.037412897,
+    -0.049879834, 0.047755197, -0.07864923, -0.037306532, 0.1105794, -0.15375234, 0.008162275, 0.09325242, 0.04935381, -0.008623495, -0.009516522, 0.013651535, 0.11318241, 0.104963884, -0.14690872, 0.06855962,
+    -0.029333271, -0.08777486, -0.10252039, -0.049953103, -0.012628868, -0.06815537, -0.09838571, 0.07615682, 0.054376043, 0.020265577, -0.04149251, -0.12556335, -0.06507442, 0.041211665, -0.1241582, 0.015740916,
+    0.023234306, 0.084904596, 0.008874595, -0.053326346, -0.04878248, 0.025916286, -0.017741848, -0.124490954, -0.10193616, -0.07167666, 0.14374526, -0.036680005, 0.06250571, 0.032832146, 0.025073675, 0.09923901,
+    0.13590048, -0.1345473, 0.03210646, -0.123265594, -0.026887655, 0.07484086, 0.02115649, -0.12965363, 0.01786721, 0.05421748, -0.01651342, -0.053691745, 0.0007850826, -0.067650095, -0.08584795, 0.011714935,
+    -0.054621913, -0.08532909, 0.123578385, 0.0849132, -0.053905763, -0.009971404, 0.2352766, -0.07771515, -0.053442966, 0.09787309, -0.07627921, 0.022201298, 0.08308589, -0.050886147, 0.14624164, -0.08838766,
+    -0.04187311, -0.118541, -0.072038285, 0.03061499, 0.025061598, 0.04861086, 0.08628492, -0.07862013, -0.09277297, -0.053242024, 0.26121444, -0.04989971, -0.06902927, -0.14289516, -0.0018227716, 0.028331347,
+    -0.17239012, -0.13686454, -0.019236976, -0.1133076, -0.08331558, 0.08024984, 0.04864793, -0.11421142, 0.14036258, -0.060195807, -0.006219088, -0.065488644, -0.028690735, -0.113678254, 0.18183073, -0.024592325,
+    0.015932722, 0.054813806, -0.20839706, 0.09547454, 0.055190638, -0.13339508, 0.08251239, 0.057058703, 0.089939274, 0.05339623, 0.22287008, 0.12315885, 0.04070573, -0.048543002, -0.018627409, -0.0023676057,
+    0.04797454, 0.046003528, -0.03491473, -0.1113221, -0.06591196, -0.06506919, 0.08893124, -0.14724359, -0.004750494, -0.0416649, 0.14386593, 0.027496876, 0.11160266, -0.15262093, 0.24274898, 0.0883951,
+    0.08347505, -0.1908013, 0.078635685, 0.075480446, -0.11421359, 0.047101088, 0.08518484, -0.114652194, -0.16472478, 0.13259788, 0.2859782, 0.023269625, -0.14211458, 0.05341132, 0.13904014, 0.12223409,
+    -0.17778452, 0.008306079, -0.04670169, 0.07470406, -0.12689796, -0.18976405, -0.0012538502, -0.029054912, -0.06284081, -0.027509257, 0.13818268, 0.06668797, -0.15365599, 0.12749873, 0.12182543, 0.057938907,
+    -0.04027077, -0.14246455, 0.013098188, -0.09190278, 0.0038311838, 0.13941082, 0.08741531, -0.030676663, -0.101195656, 0.054020796, 0.30243745, 0.107959844, 0.14511803, -0.06509241, -0.0048267962, -0.056146607,
+    0.11434394, -0.16892134, -0.16680026, -0.110099055, 0.15369256, -0.10319311, -0.09982205, -0.053835057, 0.11862672, 0.048860952, -0.055484574, -0.025566963, -0.039616425, 0.026968975, 0.099349804, 0.090701185,
+    -0.18273462, 0.03557878, -0.03884151, -0.15759994, -0.018333277, -0.058184475, -0.022853246, -0.052942682, 0.01187226, -0.0148487, -0.09520227, 0.12189857, 0.0686757, -0.1505258, -0.031883128, -0.16335456,
+    -0.106578484, -0.119425826, 0.09670516, 0.091228284, -0.012162894, 0.13361695, -0.11143503, 0.12147041, -0.133594, -0.023918834, -0.04083642, -0.111675486, -0.052352514, 0.099513024, 0.13866322, 0.0751354,
+    0.031593315, -0.011361688, 0.02189104, 0.0377396, -0.09630914, 0.13855287, 0.15361011, 0.09358874, 0.043187853, -0.11833416, 0.15170103, -0.099222794, 0.04587471, -0.098916836, -0.10353998, 0.025220389,
+    -0.04697137, 0.10059985, -0.0028432144, 0.0112188775, 0.017969549, 0.09782276, 0.053913884, 0.08696777, -0.1161179, 0.15219638, 0.02546, -0.065675266, -0.11314738, -0.03135651, 0.07612685, 0.10836127,
+    -0.13157411, -0.05636161, -0.07766366, -0.15911572, -0.15119606, 0.03988683, 0.14976525, 0.02282593, -0.021048695, -0.025114523, -0.102829084, 0.055784937, -0.061755132, -0.13618441, -0.12528811, -0.13586026,
+    -0.13979165, 0.096275054, -0.12527506, -0.10074524, 0.13657449, 0.0853172, -0.013859127, -0.019406956, -0.16593161, -0.018001135, -0.13675374, -0.013513683, -0.060071576, -0.110036924, 0.14868769, 0.10062208,
+    -0.10056173, 0.12951101, -0.048691027, -0.15711975, -0.09529564, 0.0968172, 0.12709439, 0.13629246, -0.1594636, 0.054339226, -0.049740136, -0.036216207, 0.123710245, 0.051041953, -0.12171554, -0.14828397,
+    0.12002109, -0.09072862, 0.1051112, 0.08258793, 0.06638241, 0.07075572, 0.14842232, 0.035862625, -0.08970541, -0.06850694, 0.12689762, -0.024560243, -0.111864194, 0.12213047, 0.03951411, 0.033771917,
+    -0.094921656, 0.14335851, 0.00014008582, -0.14462578, 0.07295552, -0.07621755, -0.07715702, -0.0784887, 0.06623779, 0.045171276, -0.119565085, -0.025513336, -0.06085919, -0.13504812, 0.009797469, 0.07605983,
+    -0.0051459074, -0.0042657107, -0.11715479, 0.1460766, -0.14619204, 0.018055797, -0.097003266, -0.11638847, -0.049709313, -0.13204259, 0.08096589, 0.093137085, -0.00052052736, -0.056613266, -0.14594743, 0.011007965,
+    -0.13911049, -0.0025207102, -0.11453882, -0.050306134, 0.016612649, 0.09192225, 0.057113826, 0.04666823, -0.101503015, -0.018428504, -0.06516432, -0.031793132, -0.05512745, 0.0598097, 0.07417299, 0.012827858,
+    0.10526021, -0.14138661, 0.02059716, 0.010339245, 0.122966185, -0.06678136, -0.13074237, -0.09923971, 0.08538316, 0.11397751, -0.1009827, 0.11159994, 0.056240708, 0.07829784, 0.028155744, -0.1400111,
+    0.11177389, -0.15059566, -0.14182754, 0.038760886, 0.10014014, 0.06052412, 0.13923474, -0.12081781, 0.10503523, 0.12283887, -0.05890643, -0.038595356, 0.04593216, -0.04424899, -0.073131956, -0.043977283,
+    -0.012904033, 0.080888584, 0.13189848, -0.018217012, -0.0927123, 0.015796319, 0.09967555, -0.0033501089, -0.009441406, -0.01081188, -0.092194974, -0.103107564, -0.04955364, 0.03749597, -0.070728526, -0.035571456,
+    -0.116859, -0.020456165, 0.048890546, -0.11988577, -0.047879227, -0.1379664, 0.04845929, 0.056173697, -0.14289564, 0.15183492, 0.0147596, 0.11971252, -0.10670631, 0.029283956, -0.053851254, 0.046903864,
+    -0.13606757, 0.051228523, -0.030378833, -0.01394497, 0.068818256, -0.011677235, 0.054523963, 0.13136412, -0.14699656, 0.009244487, 0.04301952, -0.0973587, 0.14582632, -0.11411838, -0.044318732, 0.091899656,
+    -0.062065545, 0.12141861, -0.10562856, -0.051889814, -0.026527293, 0.034688085, -0.030909646, 0.13728277, -0.14410661, 0.087757245, 0.11522952, -0.00072736415, 0.14160024, -0.038050592, -0.15160054, -0.14927872,
+    -0.057056084, -0.10095197, -0.09522907, 0.04585448, -0.03172145, -0.013199076, -0.103538394, -0.11281371, -0.107129924, -0.10177757, -0.13237539, -0.014245391, 0.14798935, 0.05271051, -0.105488814, -0.010459211,
+    0.08457256, 0.088327706, -0.14270756, 0.030233596, -0.14498995, -0.07359056, -0.0887778, 0.1395262, -0.07493496, 0.067978606, 0.0014278388, 0.11028141, -0.053851478, 0.012487903, 0.090881445, 0.14351118,
+    0.0968626, -0.07758287, -0.080786176, 0.028585633, 0.12092714, -0.06449272, 0.020273054, 0.14476581, -0.10716271, -0.12146522, 0.091158226, -0.017147198, 0.02784954, 0.11400851, -0.091622405, -0.11346524,
+    0.12269092, -0.109799415, 0.0327831, -0.117029645, 0.11230179, -0.0736133, -0.023157436, 0.017537862, 0.13068111, -0.05459228, 0.047871005, 0.125326, 0.01518109, -0.08525285, 0.062308613, -0.08786184,
+    -0.08806859, 0.07299486, 0.13601635, -0.11750312, -0.08314423, 0.011998877, -0.13679615, -0.09817685, -0.09783868, 0.032622278, -0.0853809, -0.04339149, 0.15089528, 0.1207247, 0.1433596, -0.088766,
+    -0.039962094, -0.11121128, -0.060129583, -0.024851536, 0.056678273, 0.10494635, 0.06716645, 0.02651371, 0.027693972, -0.044805907, 0.030847775, 0.0023479227, -0.008811253, -0.08519372, -0.02008942, -0.13632996,
+    -0.10892119, 0.06315402, -0.04445159, -0.032299146, -0.083951496, 0.056679387, 0.16093212, 0.12113326, -0.15031913, 0.011822888, 0.048483923, 0.051521607, -0.082771175, 0.04819543, 0.16734451, -0.016403437,
+    0.08895608, 0.062437035, 0.0130135175, 0.120205835, 0.13578449, -0.011309024, -0.03367308, -0.07508481, -0.057170056, -0.06168632, -0.012310961, -0.121602334, -0.0869277, 0.0099293515, 0.118765436, -0.018542841,
+    0.10673282, 0.04808872, -0.15967199, -0.10095121, -0.056923985, 0.015039217, 0.15634805, 0.078096576, 0.08050254, 0.04900981, -0.18544798, -0.071339116, 0.03248733, 0.032912187, 0.08583, 0.09612672,
+    0.044924587, -0.070493504, 0.104820415, 0.124927364, -0.020562567, 0.1394196, -0.0595829, 0.13176608, 0.008778137, -0.1407091, 0.061496504, -0.102036454, -0.06260645, -0.068163045, 0.02161993, 0.060818847,
+    0.0035939587, -0.007932915, -0.06768899, 0.0877322, -0.006447725, 0.054538313, 0.16375914, -0.15180714, -0.09775333, 0.14591894, 0.11594592, -0.087268405, 0.075213246, -0.08469643, 0.10155593, 0.012367227,
+    -0.15848322, 0.13702032, 0.019486716, 0.0024247395, 0.12544642, -0.14079219, -0.12815496, 0.14611244, -0.07502318, 0.12348648, 0.13617977, 0.12393697, -0.045712937, -0.15080795, 0.018117074, -0.028790351,
+    -0.1362085, 0.034603987, 0.082008116, 0.07573483, 0.092126735, 0.057654824, 0.122445196, -0.13038208, 0.05656378, -0.07294191, -0.008392846, 0.07234023, -0.19602711, -0.12938553, -0.040934283, 0.00881415,
+    -0.122643985, -0.13732325, -0.014446434, 0.0040589287, -0.042890962, 0.12621915, -0.0033211743, -0.037088364, 0.084892, 0.04638919, -0.061133716, -0.07311081, 0.03891634, 0.0060106204, -0.0717331, -0.1131129,
+    -0.08572004, 0.13058105, 0.064863056, -0.083550714, 0.116145775, 0.109359585, 0.025381256, -0.13286021, -0.10566013, -0.12830426, 0.07996813, -0.10117934, 0.043850243, -0.086564146, 0.045016803, -0.03378649,
+    -0.1256891, 0.096320055, -0.12280761, 0.07856592, 0.11252215, -0.0981426, 0.13588893, -0.089542136, -0.03760724, 0.080621384, -0.027712652, -0.049985334, 0.13628647, -0.118807524, -0.12164189, -0.10861047,
+    0.1119575, 0.016273873, -0.09983372, -0.1347761, 0.048802957, 0.07336519, 0.102447644, 0.037578046, -0.057499353, 0.08785667, -0.06834965, -0.15236987, 0.060897723, 0.07309719, -0.08089631, -0.1258448,
+    -0.006157609, 0.08880184, -0.051842824, 0.11517859, 0.10467302, -0.08491459, 0.005094844, 0.12755388, -0.06551085, 0.029724136, -0.0097191725, 0.06453886, -0.104799636, -0.06030287, 0.0040678023, 0.020641569,
+    0.07687362, 0.14028877, -0.14021713, 0.032058775, 0.10129799, 0.04649168, -0.11954771, -0.11207093, -0.110397205, 0.13806425, 0.04246565, -0.078010805, 0.022490934, 0.0002808758, 0.08876499, 0.015141905,
+    0.051380403, 0.12181302, -0.0807533, 0.108927555, -0.009184298, -0.0115091335, 0.16192746, 0.084658384, 0.014532539, 0.057272695, 0.13714315, -0.06350933, 0.071050204, 0.07763262, 0.0050601214, 0.109148346,
+    0.08746665, -0.026215572, 0.12348713, -0.071455315, 0.1356341, 0.15051623, 0.1296163, 0.15859047, 0.12191404, 0.10044851, 0.1597244, -0.089712426, 0.12358856, 0.033178102, -0.027440159, 0.060837153,
+    0.16459692, -0.03441604, -0.075699106, 0.08641482, 0.14506216, -0.065388374, 0.15397272, -0.03723748, -0.026976805, -0.11857406, -0.029555699, -0.12973271, -0.002594182, 0.025621524, 0.08087784, -0.0149207115,
+    -0.16720107, -0.080129996, 0.08806853, 0.0062899925, -0.05403608, -0.0016007, -0.1839821, -0.028189918, 0.049173422, -0.06858316, 0.111349784, -0.095115826, -0.14054637, 0.05431293, 0.009440616, -0.0009126657,
+    0.118465655, 0.10851121, -0.037467968, 0.025095712, 0.12398303, 0.047082745, 0.12684739, 0.10776762, -0.017204344, 0.098072164, -0.02291979, -0.08686402, -0.013000455, 0.1413737, -0.104603484, 0.11359682,
+    -0.1547409, -0.043894622, -0.1225557, -0.12752871, -0.08924958, 0.06749366, 0.10561906, 0.025210112, 0.0054330826, 0.099278, -0.1217851, 0.14345337, -0.07492383, -0.10930895, 0.06782613, 0.102478676,
+    -0.07419249, 0.01335375, 0.1162319, 0.026183244, -0.09136558, -0.15447746, -0.07480742, 0.001135679, -0.09387313, 0.010588236, -0.14815788, 0.13790733, -0.030965865, 0.025129378, -0.021206286, 0.06911529,
+    0.09162144, -0.11624637, -0.10857493, -0.13597067, -0.018844837, 0.0023996916, 0.10174185, -0.016681198, -0.017386349, 0.047627557, 0.01097063, -0.03030051, -0.088278614, 0.13214009, -0.1672494, -0.004298066,
+    0.13915396, -0.1059763, -0.024758767, 0.10959288, 0.09954933, 0.0074098664, 0.11311722, -0.017525692, -0.019509725, -0.1315002, 0.054336503, -0.056779288, -0.054689772, -0.11252909, 0.03470438, 0.06872707,
+    0.083475366, -0.15199623, 0.15356389, 0.0019053872, -0.14910267, 0.07385935, 0.06509172, -0.13354348, -0.029478049, 0.09110591, 0.12238294, 0.087155215, -0.20868665, 0.15637563, 0.032008607, -0.07005935,
+    -0.094395734, 0.10234901, -0.037864473, -0.074609384, 0.1595684, 0.13029993, -0.0635679, -0.0052542957, 0.017223962, -0.07261294, 0.0150815165, 0.08616706, 0.00012070682, -0.07397784, 0.14143744, -0.11571183,
+    0.011520989, -0.04674277, -0.060808167, 0.123946324, -0.10558583, 0.12458043, 0.05493503, 0.1534382, 0.039543375, -0.1488962, 0.22000334, -0.095604435, 0.084773965, 0.060670048, -0.178568, 0.1388476,
+    -0.09222665, -0.09591188, -0.011788629, 0.0031866878, -0.14588833, -0.22856745, -0.0036279925, -0.08394886, 0.17712578, 0.05293343, 0.014651001, -0.13230272, -0.018360823, 0.027874712, -0.04756522, -0.018900663,
+    -0.07463518, 0.012555296, -0.07725331, 0.039348096, -0.07508634, 0.11390813, -0.13198896, 0.016906282, -0.13385135, -0.082312174, 0.066271886, -0.14499973, 0.026130382, -0.09561144, -0.022945518, 0.13883875,
+    -0.0142782945, 0.14492287, 0.070772626, -0.14991504, 0.04317507, -0.007943676, -0.1458932, -0.14127709, 0.079984516, 0.011477087, -0.022097785, -0.11640811, 0.15104158, 0.12191201, -0.012363732, 0.12600197,
+    -0.10617511, -0.076013446, 0.028452784, 0.018387914, -0.0023932164, -0.05860173, -0.12996684, -0.09916853, -0.067868, -0.054492407, 0.2253044, 0.124283, -0.022205144, -0.04863614, -0.019986885, -0.012706831,
+    0.16526058, -0.06778632, -0.12617125, 0.023494884, -0.1393541, -0.12305856, -0.011281015, 0.13222946, 0.15197596, 0.10403543, -0.21343161, -0.11577866, 0.15219857, 0.11391376, -0.0329426, 0.13566296,
+    -0.01349715, 0.19087508, -0.09531659, 0.13174559, 0.14379166, 0.074416235, -0.17678797, -0.11127024, 0.107006654, 0.09312808, 0.16785984, 0.04266444, -0.06422724, 0.0046989024, 0.031032493, -0.04353647,
+    -0.01830426, 0.12874384, 0.03155896, -0.10858391, -0.079199575, -0.17740735, -0.21979, 0.11111015, 0.16433346, -0.049983066, -0.08862601, -0.028496288, -0.13281986, -0.04944161, 0.02856578, -0.059329905,
+    0.038863514, 0.037375256, 0.079553224, -0.13979596, 0.02703553, 0.034102153, 0.056975305, 0.073971115, 0.10708977, 0.14990087, 0.014712096, 0.062434893, -0.17403524, -0.06213884, -0.081057616, -0.07679669,
+    0.08839796, -0.015758809, -0.12014837, 0.041064452, -0.026081251, 0.016266942, 0.08541616, 0.13798848, -0.034106877, 0.08267177, 0.08143167, -0.13364896, 0.07841873, 0.07663428, -0.049310192, -0.014287872,
+    -0.112336345, 0.062191635, 0.041949756, 0.08776922, -0.01846755, 0.14737526, -0.09733682, -0.08138866, 0.04488813, -0.09015542, -0.1401502, -0.05869039, -0.04991864, -0.094617866, -0.106556706, -0.12711994,
+    -0.06773015, 0.15147364, -0.032831367, -0.036569197, 0.10943472, -0.076401174, -0.04533578, 0.04388995, 0.094637394, -0.10456415, 0.09415798, 0.09949202, -0.14744364, -0.10187736, -0.12925336, -0.026882423,
+    0.14039256, -0.10751204, 0.077994, -0.09827394, -0.009846654, 0.14764658, 0.05665469, -0.047657527, 0.08997278, -0.12589431, -0.07692069, 0.025929028, -0.08415267, -0.0674229, 0.18702415, -0.09295706,
+    0.08999104, -0.057570245, -0.10070922, -0.13783437, 0.0739375, 0.058839884, -0.0077998373, 0.0926419, -0.18481946, 0.071288, 0.18969359, -0.13260761, 0.111844674, -0.02448931, 0.09505301, -0.032660253,
+    -0.05617925, -0.06162145, -0.07461643, 0.046653274, 0.08362323, -0.05353416, 0.15405793, 0.13329023, -0.033971295, -0.14162092, -0.01785238, 0.1090836, 0.023604024, 0.11719855, 0.06237348, -0.08907292,
+    -0.01777378, -0.06489789, 0.0046331026, -0.049110387, 0.12435611, 0.14444546, 0.16654892, -0.014185797, 0.061967157, 0.10784624, 0.030719249, 0.0029515494, 0.09356333, 0.07288971, -0.06728574, -0.090162754,
+    -0.081157826, -0.0028280455, 0.12574233, -0.1300746, -0.18571149, -0.039194815, 0.20572516, -0.16320531, 0.033440985, 0.007185726, 0.04600715, 0.0037499196, 0.006076008, 0.019591844, 0.055895526, 0.034859516,
+    0.043237478, 0.03786075, -0.08610727, 0.13507082, 0.056263976, -0.1335837, 0.11805065, -0.054360963, 0.07617156, 0.047468986, 0.32873353, -0.036902864, -0.07975763, 0.085187726, 0.045406785, 0.09491416,
+    -0.04694081, -0.13372482, -0.10240774, -0.11055068, -0.19076844, -0.108662225, 0.2796827, -0.07198732, 0.09012813, -0.06401366, 0.10959311, -0.050361574, -0.18309018, 0.1351236, 0.28035584, 0.048783816,
+    0.094941296, -0.21836124, -0.1438605, -0.012636567, -0.0018491946, 0.15702572, 0.0119592175, -0.0070731184, 0.10303271, 0.01725799, 0.31600603, 0.059127603, 0.12750848, 0.11878317, -0.11801283, 0.06928668,
+    -0.025147196, -0.07974591, -0.12820704, 0.081776105, -0.14792483, 0.028663723, 0.18426862, -0.1188413, 0.07879337, -0.16225992, 0.0057436037, 0.109795935, 0.05928434, 0.07261428, 0.046846677, -0.09100077,
+    -0.089151695, -0.21807756, -0.13776968, -0.06781201, 0.012287044, -0.061373465, -0.11908133, -0.055745944, -0.060170162, -0.03636859, 0.27707854, 0.10660346, 0.11447078, -0.102681436, 0.11298761, 0.07483267,
+    -0.03621095, 0.11546254, 0.13799824, 0.09398884, -0.17248677, -0.1126164, 0.17093569, -0.051306576, -0.052497603, -0.12905686, -0.027727995, -0.045207895, -0.12093516, 0.13677873, 0.2366509, -0.1418043,
+    -0.07756619, -0.09076865, 0.07220394, -0.04215203, 0.04071816, 0.050167926, 0.06162127, -0.2121826, 0.113106236, -0.10293555, 0.055242654, -0.065120816, -0.05886445, 0.13378932, 0.03818236, -0.13413489,
+    0.020674437, -0.098129585, 0.09094364, 0.09522039, 0.02801393, 0.15044387, -0.3331845, -0.068034485, 0.042183887, -0.0666981, -0.113108315, -0.113517754, 0.0069549275, -0.05115733, -0.11574538, 0.034776293,
+    -0.15575558, -0.028963968, -0.035046067, 0.07360743, -0.13108538, -0.16383362, -0.039794043, -0.09664023, 0.13516901, -0.107913606, -0.3128298, 0.12527233, -0.036454998, -0.027401995, 0.020711647, 0.076765165,
+    -0.10612829, -0.10655373, -0.081118174, 0.09704572, 0.149191, -0.038506843, -0.13036403, 0.0923059, 0.0742543, 0.08733931, 0.1408635, 0.14123791, 0.07520096, -0.028389314, -0.22368316, 0.067963876,
+    -0.10160542, -0.06435894, -0.07539488, -0.12503423, 0.058138076, 0.05961473, -0.037843086, -0.13609546, -0.08561726, -0.07850725, -0.20204411, 0.076507576, 0.058573857, -0.0053708903, -0.14976946, 0.09967217,
+    0.0056649446, 0.14256358, 0.11033446, -0.013048493, 0.06716958, 0.027864099, -0.083663054, 0.105352804, 0.12655972, -0.1310346, 0.113557436, 0.16212574, -0.068250276, 0.1216235, -0.28508103, 0.016410967,
+    -0.050793495, 0.07498978, 0.062058024, -0.00027839447, 0.033917908, -0.06368137, -0.06490611, -0.13915992, -0.05149315, -0.11492283, -0.32065484, 0.11493228, 0.07835872, 0.08034736, -0.14101137, -0.0244614,
+    -0.05795881, 0.052880358, 0.025407963, 0.056237295, 0.084999025, -0.07602292, -0.17806213, -0.05857858, -0.04427876, 0.06944223, -0.074500844, 0.06689418, 0.054249756, 0.045495715, -0.11885655, 0.101404935,
+    0.061497733, 0.0879907, 0.02369091, 0.11337805, 0.11728352, -0.026535127, 0.101290695, -0.12141477, -0.13927595, -0.17566192, -0.2107147, 0.07312768, -0.118334524, -0.141018, 0.08336988, -0.15265499,
+    -0.10968484, -0.122428365, -0.018356005, 0.050786152, 0.061666355, 0.040144578, -0.018122852, -0.04987908, -0.13201018, -0.056697726, 0.11779222, 0.045458566, 0.05854751, -0.14114004, -0.12968999, -0.14428961,
+    -0.05356923, 0.07404509, -0.11930743, -0.0040121805, 0.10018998, 0.043184046, -0.10546712, 0.023520976, -0.096567556, 0.1429701, -0.021575954, -0.034042917, 0.12833588, -0.03737344, -0.15159093, -0.14448452,
+    -0.03490872, 0.070248395, 0.07422582, 0.058720205, 0.03513904, -0.020244092, -0.09604111, 0.109216616, -0.057266723, 0.08679612, 0.11843443, 0.053969663, 0.09513246, 0.051642686, 0.056458354, 0.09976729,
+    0.046530936, -0.017558008, 0.081409454, -0.115472786, -0.14282298, 0.076480106, 0.08503311, -0.10905261, 0.06170445, -0.14191379, 0.04223845, 0.08894185, -0.059025507, 0.083824135, -0.13147761, -0.036840048,
+    0.040151685, -0.13838781, 0.109116755, 0.052195966, 0.13534065, -0.09789394, -0.043263555, 0.07783669, -0.15393747, 0.098960504, -0.12110012, -0.052444827, -0.1597077, -0.08091736, 0.124574795, 0.08195285,
+    0.061415683, -0.08501209, -0.079785004, -0.046335235, 0.12406302, 0.026683167, 0.12015507, -0.12899733, -0.0049578683, 0.15056975, -0.06226872, 0.07410359, -0.14700557, 0.06312778, -0.053481188, 0.042543925,
+    -0.06285473, 0.029189099, 0.12039946, 0.046456605, 0.061452612, -0.07238867, -0.03503432, -0.008377165, 0.11700417, 0.047876135, -0.12646897, -0.1264186, 0.011234102, -0.13985538, 0.10042094, 0.13200258,
+    0.0032594383, -0.14565155, 0.089898095, 0.06857801, -0.030891594, 0.04248314, -0.07314784, -0.15240851, 0.121656194, -0.04031025, 0.10163609, 0.10977972, -0.10262881, 0.02315996, 0.14053127, 0.04380866,
+    0.019248515, 0.03926667, -0.09622529, 0.03487794, 0.07449798, 0.043986768, -0.20672852, 0.008604094, 0.069954, -0.13676272, -0.060420007, -0.01567469, 0.13758492, -0.12014569, -0.30345237, 0.0452586,
+    0.013495023, 0.06984614, -0.054273594, 0.015905127, 0.116610594, -0.0672172, 0.06568499, -0.0905733, -0.12481952, -0.0934172, -0.3880812, 0.13311182, 0.11055479, 0.09552473, -0.13747436, 0.12126905,
+    -0.0036717802, -0.12518004, -0.029139537, 0.14224395, 0.10548513, -0.09364152, -0.1655487, -0.13402545, 0.12730418, 0.015756205, 0.062841125, -0.021859465, -0.119146466, -0.079297096, -0.32423502, -0.059720855,
+    0.12591903, -0.056245305, -0.105216466, 0.056033447, -0.10490992, -0.06536868, -0.00046821518, -0.047942743, 0.07397927, -0.10096991, -0.23109609, -0.03673374, 0.14806995, -0.15195706, 0.091009855, 0.035950694,
+    -0.023296177, -0.15084434, 0.14591521, 0.08626681, 0.08039576, 0.019386157, -0.07998531, -0.049822025, -0.042260673, 0.04730341, -0.07078211, 0.09037845, 0.06609526, 0.08018415, -0.20146681, -0.055508297,
+    0.11582681, 0.117644146, -0.11966402, -0.10684069, -0.15569408, -0.033992704, -0.038418118, -0.0764507, 0.11106546, -0.06961311, -0.14818233, 0.06645287, 0.12340177, -0.019285077, -0.07082543, 0.100597024,
+    0.12238316, -0.009777993, -0.0721094, 0.06880348, -0.13833082, -0.11717597, -0.28973076, 0.08887327, -0.12900452, -0.077777565, 0.11059374, -0.010522717, 0.0025243864, 0.061962128, -0.117823765, 0.09856256,
+    -0.13593681, 0.123894736, 0.12304934, -0.0875717, 0.12284813, 0.032411385, -0.058794055, -0.1421555, 0.11955238, 0.08181363, -0.1868397, 0.06851126, -0.13579376, 0.065778196, 0.052819654, 0.05501992,
+    -0.14537998, -0.07329274, 0.1641649, -0.17364803, 0.026638806, -0.02338047, 0.11128537, -0.10065728, -0.21995261, 0.117893554, -0.030460026, 0.10758891, -0.07177319, -0.17711999, 0.051308617, -0.048783947,
+    -0.12443758, -0.059987705, -0.05710784, 0.047798492, -0.15402697, -0.051895343, 0.013731533, 0.07491502, -0.05941064, -0.107157074, 0.027904062, 0.027405888, -0.18763357, 0.07230582, 0.03842841, -0.016921135,
+    0.1201297, -0.020789023, 0.011844688, -0.035610233, 0.05703397, 0.0034769585, 0.14489232, -0.088834114, -0.03137761, 0.013481611, -0.12897001, -0.14728022, -0.05482808, -0.12478033, 0.16156095, -0.08497243,
+    -0.079066195, 0.0639507, -0.04671545, -0.12475191, -0.0738388, 0.17311572, -0.08401152, 0.15870512, 0.123638354, 0.16476777, 0.19798201, -0.20540787, -0.1675297, -0.079255946, 0.030948982, 0.002921324,
+    0.0765337, -0.14005533, 0.12478253, -0.10947031, 0.10606308, -0.14618985, 0.06547451, -0.03614845, -0.23978037, -0.016527863, 0.049249902, -0.016697582, 0.12998223, -0.16587065, 0.09616242, -0.044380452,
+    0.04474753, -0.08597176, -0.07010937, 0.07184014, 0.021287538, 0.16832049, 0.15768956, 0.003850326, -0.05578558, 0.12914547, 0.17589438, -0.047675896, -0.03960477, 0.05650703, -0.105973415, 0.100076295,
+    -0.038138587, 0.0030226142, 0.10444839, -0.01780981, 0.04096687, -0.12167507, 0.052054495, 0.07407125, -0.23920977, -0.0738438, 0.09895001, -0.12230242, -0.08210343, -0.09767023, 0.00878104, 0.014627044,
+    0.085890956, -0.10232718, 0.1677457, 0.0767778, -0.03822801, 0.17854126, -0.08221059, -0.112525016, -0.11530482, 0.040819902, 0.1090219, -0.19802006, -0.16563746, 0.13685846, -0.05581745, 0.16081405,
+    -0.21595643, -0.03551394, -0.06430987, -0.07048824, -0.044652358, -0.22082454, 0.12930349, 0.13352586, 0.04178648, -0.109036595, 0.11951326, 0.062717214, 0.015576811, 0.107125446, 0.08622045, -0.10545433,
+    0.056202713, -0.16636133, 0.191146, 0.0114184385, 0.1379764, -0.06731037, -0.059975956, 0.12017248, 0.043371376, 0.023812821, -0.079530336, -0.020859841, 0.12308647, -0.13012163, 0.05482869, 0.011366277,
+    -0.13263842, -0.090304375, 0.031934384, 0.042450808, 0.07416682, 0.009866384, 0.06969922, 0.14019035, -0.11641986, -0.13633893, -0.10100773, -0.06828891, -0.26860866, -0.14700896, 0.2128934, 0.1195996,
+    0.09701323, -0.014878754, 0.049719505, -0.088161774, -0.073496625, 0.052079823, 0.044235658, 0.09400744, 0.11470907, 0.18502574, 0.046701845, -0.058786042, -0.08091463, -0.053501934, -0.04284571, 0.11119209,
+    -0.16773038, -0.090251304, -0.050913278, 0.11118646, -0.041089118, -0.19512716, 0.154652, 0.15704937, -0.14425139, -0.07794787, -0.041684207, 0.045746606, -0.07848649, -0.05970896, 0.23439264, -0.0906983,
+    -0.055454265, -0.12447374, -0.02775616, -0.054023378, 0.10271573, 0.0038725543, 0.10670313, -0.055864293, 0.05383162, 0.093352824, 0.137051, 0.06693756, 0.039618954, -0.045771435, -0.15039752, 0.062436003,
+    -0.02618298, -0.050230984, 0.11461187, -0.11870678, -0.05936733, -0.2278234, 0.1324406, -0.035663564, -0.04323211, -0.067614645, 0.015060421, -0.031328183, 0.010772695, -0.04255307, 0.10200349, -0.1469527,
+    0.14414042, -0.17344272, 0.2173712, 0.0067494665, 0.14701124, 0.099611975, 0.0984751, 0.057375174, -0.07422099, 0.10683194, 0.13158174, -0.10823693, 0.13750401, 0.1189706, -0.0738106, 0.044880453,
+    -0.10045378, -0.15262127, -0.023660727, -0.06623046, -0.029977031, -0.085333295, 0.037330657, -0.089777716, -0.13684791, 0.15238501, 0.04493457, -0.06812146, -0.0566006, 0.09139197, 0.071191885, -0.11021406,
+    -0.11548212, 0.005891174, 0.011279742, -0.084095754, -0.08054144, 0.08932092, 0.022827242, -0.12101168, 0.052635908, -0.08212956, -0.037862767, -0.11158557, -0.022036085, 0.0898356, -0.15341853, -0.0871388,
+    0.15191679, 0.064769134, -0.11150223, 0.1083117, -0.08651094, -0.08980071, 0.13366501, 0.10192351, -0.10764817, 0.122388355, -0.09315424, -0.0401576, -0.07929616, 0.04270296, 0.0018424954, -0.08697432,
+    0.004039813, -0.015429422, 0.11221128, -0.113659345, 3.2321786e-5, -0.0715981, 0.10887606, -0.028357279, 0.1068923, 0.100748375, -0.018349698, 0.070535675, 0.0590011, -0.110351294, -0.089928634, -0.06618492,
+    0.06706339, -0.04924514, 0.079456314, 0.0392908, 0.00556162, -0.08299692, -0.14401485, -0.12522857, -0.09816961, -0.11260217, 0.018086016, -0.10834338, -0.021222293, 0.10310986, -0.018677903, 0.13021792,
+    0.0043190755, 0.12638442, -0.01729653, -0.07667226, 0.076509796, -0.07360575, -0.0016639557, 0.0092217, -0.1380587, -0.15240476, -0.02643778, 0.084239036, 0.059768226, 0.040244803, 0.107017115, 0.13383493,
+    -0.12018479, -0.05955796, -0.0064051966, -0.12247905, 0.02420485, -0.15128168, 0.05797629, 0.132832, 0.0431872, 0.037911676, 0.040846467, -0.011907801, 0.05607398, 0.011765974, -0.1424973, 0.05042176,
+    -0.049438976, 0.080251664, -0.07666576, -0.0396375, 0.01118632, -0.029745448, -0.009347463, -0.07223076, -0.1266385, 0.14523135, -0.0093085915, 0.029332967, -0.019624004, 0.010358945, 0.013492326, 0.051637292,
+    -0.025332212, -0.11709719, 0.124177024, 0.05229635, 0.11831836, 0.011129561, 0.044402845, 0.08674076, -0.019063404, -0.08781942, -0.067394376, -0.09313613, -0.16377848, 0.025268348, -0.0025753344, 0.133732,
+    0.11747204, -0.1387619, 0.015867423, 0.15361206, 0.10396639, -0.09922504, 0.023355387, -0.08748857, -0.16553171, -0.009245568, 0.036864594, -0.10652338, 0.026504172, 0.10122086, -0.08503303, -0.07573366,
+    0.0012762889, 0.08092619, 0.12090572, -0.007987665, 0.05636885, 0.040338196, -0.0011966338, 0.11249389, 0.026553491, -0.0027123697, -0.07521814, -0.14821938, 0.01782993, -0.122241, -0.0035276266, -0.02299215,
+    0.020602578, 0.063722074, -0.070385866, 0.024558673, 0.10408337, 0.14376648, -0.05483532, -0.1380039, 0.019227158, 0.047505382, 0.008853073, -0.13980603, 0.05568905, -0.07148249, 0.048104666, 0.051633272,
+    0.023284186, -0.0225533, 0.07038186, -0.10198284, -0.10503987, -0.13247626, -0.09751552, -0.051909484, 0.10164581, -0.13779537, -0.04555422, -0.006658861, -0.2017829, -0.04304195, 0.19028792, -0.13163371,
+    0.120311424, -0.037628815, 0.071585, -0.06624463, 0.15521327, 0.017620955, 0.031104665, 0.048576847, -0.011901112, -0.018413842, 0.24227309, 0.014444593, 0.08779128, 0.06436783, -0.10901336, -0.14407602,
+    0.11748934, -0.055912487, 0.011154457, -0.1547911, -0.020961447, -0.036413267, -0.09279026, -0.14938775, -0.05908925, 0.041880615, -0.059615094, 0.037109647, -0.09441162, -0.03108794, 0.18152872, 0.021644844,
+    -0.12336214, -0.09974776, -0.11305439, -0.09173625, -0.028884022, 0.11702862, 0.08934088, 0.0556986, -0.030336063, 0.12120204, 0.30123156, 0.12223384, -0.048104823, 0.13218597, 0.12015571, 0.00419586,
+    0.0072301924, 0.05023772, 0.026647571, 0.11040283, -0.039338432, 0.1063375, -0.27834535, -0.13604148, 0.14698847, -0.034817256, -0.13267457, 0.030214684, 0.025352819, -0.08624746, -0.2896665, 0.018825646,
+    -0.038328245, -0.08903683, 0.035082534, -0.04621435, 0.11519308, -0.15580934, -0.02589962, -0.08878378, 0.11651604, 0.01634882, -0.23798563, -0.10999742, 0.08584494, 0.11915536, 0.048600253, 0.0682913,
+    0.13313271, 0.052085657, -0.04591118, 0.06533956, -0.06476366, 0.0728423, -0.17464837, -0.082122624, -0.102440506, -0.14099927, 0.10441352, -0.09451251, -0.08445657, 0.08671449, -0.26748863, -0.12944387,
+    -0.072712414, -0.105213374, -0.07570566, 0.13218749, 0.09132936, -0.13917865, -0.07175219, -0.12799463, 0.02041681, -0.10550231, -0.3288408, 0.08591892, 0.09052264, -0.06681328, 0.128392, 0.12538801,
+    0.14103682, 0.05582526, -0.0788191, -0.06289137, -0.07067513, -0.040256813, -0.17946506, -0.032496497, -0.06964318, -0.13828897, 0.0497219, -0.010199802, -0.04629527, -0.07111132, -0.35021678, 0.001226855,
+    -0.04236287, 0.12633793, -0.07916267, 0.0929238, 0.13301116, -0.118219465, 0.036853153, 0.06695901, 0.014722299, 0.0042466917, -0.08094904, -0.14482689, -0.01924625, 0.09222131, -0.11646037, -0.11382557,
+    -0.13938628, -0.037644427, -0.015512436, 0.09588159, 0.030519173, -0.14842188, -0.04203586, -0.111430496, 0.07549887, 0.042072773, 0.05242533, -0.008254968, 0.0707398, 0.012374798, -0.2074891, 0.10104975,
+    0.034048446, -0.13634214, -0.10502722, -0.07988595, -0.039883032, -0.014199852, -0.11265528, 0.074793085, -0.10951856, 0.06357043, -0.29927307, -0.018427, 0.07385432, -0.10415577, 0.12891333, 0.108871154,
+    -0.028747082, 0.004234879, -0.13449243, -0.1614408, -0.12012526, -0.13436142, 0.070533514, 0.004302904, 0.043674912, -0.055344075, -0.11622946, -0.11001869, -0.005902442, -0.00900485, 0.012245536, -0.04017858,
+    -0.14557683, 0.14749993, 0.11918603, 0.10958618, 0.0894917, -0.106154725, -0.12582462, -0.09788372, 0.05658774, -0.117514506, -0.13045238, 0.13361014, 0.07618491, 0.10324866, 0.13366257, 0.025957672,
+    0.048217326, 0.07894068, -0.04846527, 0.06002965, -0.1505092, 0.048013836, -0.12122315, 0.0027052462, -0.105272, -0.055950306, 0.043428186, 0.08782812, -0.010664831, 0.03865136, -0.00869666, -0.14176981,
+    0.0811671, 0.099831, 0.082229644, 0.09044722, -0.013254464, -0.12342237, 0.06302512, -0.1413117, 0.14508574, 0.10226543, -0.110151835, 0.11986018, -0.0030513457, 0.08163052, -0.1523191, 0.018365411,
+    0.025494903, -0.05665499, 0.11893498, -0.086487144, 0.08446251, 0.070041806, 0.0016855448, -0.11570497, -0.019096121, -0.11699255, 0.10604685, -0.0048611537, 0.12642187, 0.08678071, -0.018129274, 0.04572015,
+    0.10756372, 0.10017572, 0.08023298, 0.11210635, 0.011719732, -0.10587622, 0.048351925, 0.04749623, -0.103156276, 0.032475576, 0.0954463, -0.12922448, -0.1584732, -0.042055592, -0.095215686, -0.06660439,
+    -0.004430473, 0.0012369724, 0.050359573, 0.0010386882, 0.10750748, 0.12069191, 0.11879884, -0.017810732, 0.008178502, -0.11811238, -0.08921676, 0.124144316, 0.019368518, -0.025400724, -0.0009226948, 0.10494467,
+    -0.11735969, -0.06777829, -0.14646707, -0.034885168, -0.1306639, -0.15108868, 0.060227096, -0.14970618, -0.0012938626, 0.068927065, 0.11757113, -0.08668842, -0.023148347, -0.054571047, -0.013303244, -0.03304485,
+    0.024785118, -0.009425972, 0.04607842, 0.02689831, -0.12971489, -0.053154893, 0.09236157, 0.0077758185, -0.0069162846, -0.11237049, 0.16346271, 0.020659715, 0.021436736, -0.005419016, 0.0973808, 0.07413711,
+    0.039667707, 0.13435383, 0.037709832, 0.038131773, -0.1780865, -0.26174328, -0.2664583, 0.009484096, -0.020411685, 0.065433994, -0.07210253, 0.120606616, -0.14635517, 0.07425897, -0.05082185, 0.020928841,
+    -0.04943209, 0.17293717, 0.012183351, 0.10864337, 0.06732726, 0.039633982, 0.025354885, -0.07489267, -0.00062692165, -0.020196047, -0.024828147, 0.12144582, -0.072511196, 0.08032064, -0.08183545, -0.041435152,
+    0.12385023, -0.095393255, -0.0069176657, 0.10795711, -0.16335559, -0.031804442, -0.2414319, 0.10458975, -0.06121781, 0.13633817, -0.1035451, -0.011168629, -0.09458651, -0.016133053, -0.11768299, -0.1150854,
+    -0.14307009, -0.0077248802, 0.0026959304, -0.12167418, 0.039400555, 0.14081141, 0.11836049, -0.050797246, -0.09157261, -0.11524883, 0.0062954165, -0.015083283, 0.16728444, 0.10611178, -0.07097864, 0.10456808,
+    0.09196405, 0.10258117, 0.11396686, 0.131209, 0.06760916, -0.2965053, -0.26896945, 0.06958183, 0.16876094, 0.12108776, 0.08574098, -0.080101244, -0.030820876, 0.10040246, 0.017436344, -0.019392801,
+    -0.14614172, 0.18920933, -0.15330979, -0.12455617, 0.08174777, 0.12610754, 0.06933558, 0.07184396, 0.037237182, 0.11795473, 0.11253066, -0.057089522, -0.05497586, -0.12475995, 0.028053753, -0.052094974,
+    0.015663588, 0.119321436, -0.093824565, -0.0950516, 0.06710743, -0.21640742, -0.18768974, -0.09033511, 0.11747168, -0.048800714, -0.03482703, 0.04413806, -0.01126799, 0.028599372, 0.041530408, -0.05473246,
+    0.098840505, -0.14602815, 0.16126446, -0.12377804, -0.114286855, 0.04219815, 0.11010456, 0.12081, 0.017400937, 0.13672386, -0.1716968, 0.01600843, -0.11345126, -0.04160599, 0.038442217, -0.06751794,
+    -0.14052056, -0.07996221, -0.09042574, -0.08794579, -0.051917564, 0.09710367, 0.24454364, 0.069989294, -0.1721928, 0.031969253, 0.056660194, -0.089855395, 0.0033672957, 0.00067377626, 0.0763194, 0.02615284,
+    -0.13719097, -0.16438754, -0.070533514, -0.021724168, 0.004257839, -0.01009475, 0.00528073, 0.050924018, -0.05163456, -0.039015364, 0.0029265299, -0.019763952, -0.06483963, 0.10615277, -0.06630497, 0.02308945,
+    0.071036905, 0.13516374, 0.15622476, 0.1201732, 0.037450153, 0.04966032, 0.21797019, 0.015156734, -0.0463891, 0.1443023, 0.16259125, -0.08828322, -0.12579882, -0.087843426, 0.081314504, 0.01197073,
+    0.11594995, 0.024769196, 0.026972286, -0.1119182, -0.013804612, 0.001409647, 0.020552976, -0.06047389, -0.15468794, -0.15001449, 0.029397557, 0.053027246, 0.049365573, 0.050559577, -0.05102308, -0.10070667,
+    -0.06958662, -0.095639035, 0.013353541, 0.16207083, -0.000563702, 0.12589478, 0.16162142, 0.04581088, -0.10478188, -0.15043665, 0.18833199, 0.068230666, -0.1347654, 0.008130891, 0.099288136, 0.18352152,
+    -0.1302828, -0.019174183, 0.09585723, -0.15242925, 0.037280187, 0.14224847, 0.14689587, 0.01639684, 0.07518022, 0.109003596, -0.10788649, -0.16269025, 0.00092897617, -0.121920645, -0.0950516, -0.07079565,
+    0.002052827, 0.13646092, 0.08993747, -0.10919897, -0.10479157, -0.024527563, 0.015057305, -0.00018703629, -0.04021314, -0.015448014, 0.12074609, 0.12023095, 0.025872892, -0.22798502, 0.024717348, -0.05095595,
+    -0.10036732, -0.1059692, 0.118847094, -0.11478654, -0.091386884, -0.14219268, -0.021442948, -0.22224468, 0.027599467, -0.09419273, -0.06870451, -0.080745615, 0.07533233, 0.017563919, 0.12824014, -0.19462128,
+    -0.08622287, 0.11232865, -0.020282341, 0.11920012, 0.095516294, 0.09963691, 0.23631129, 0.12762243, -0.017729338, -0.13368881, 0.056925323, -0.056942236, -0.14489335, -0.057638995, 0.009741801, 0.10678309,
+    -0.0846297, -0.1800577, 0.06124185, 0.015887419, -0.12289762, -0.12769273, 0.005418886, -0.21399862, -0.112505265, 0.03841118, 0.040145505, -0.07876582, -0.071674705, 8.582877e-5, -0.021812044, -0.2224514,
+    -0.14430004, 0.13236134, 0.1757448, -0.08583804, 0.011372651, 0.0066109677, 0.06143155, 0.02884932, -0.20804732, 0.088709004, 0.082157604, 0.0091969315, 0.080607645, 0.055935755, -0.038078543, 0.08718161,
+    0.12522906, -0.14488907, 0.012156465, -0.056669053, 0.16408043, 0.042539388, 0.21447219, 0.06197639, -0.12751538, -0.04020824, -0.20933415, -0.13549887, -0.058337126, -0.16502495, 0.101356, -0.01773319,
+    -0.11409803, -0.13893825, -0.017428396, 0.07808853, 0.07730849, 0.09723659, 0.24194437, 0.13029028, -0.09433626, 0.12810776, 0.20216568, 0.018274587, -0.032718223, -0.03709276, -0.045553777, 0.100334436,
+    -0.072151035, -0.031237258, 0.046751805, -0.19610451, 0.10713604, -0.08731094, 0.051040564, -0.06485167, -0.10332311, 0.03745229, -0.117170855, 0.040315848, -0.09620941, -0.08701867, -0.008073046, -0.22121204,
+    -0.12973139, 0.0814212, 0.12014224, -0.050499856, -0.0881017, 0.17050496, 0.04683207, 0.15873353, -0.195031, 0.10886482, 0.17791526, 0.019085433, -0.0342778, -0.053350627, -0.018795852, 0.11354032,
+    -0.04330221, 0.06753897, -0.14481875, 0.014986254, 0.122080766, 0.030951083, 0.033220742, -0.13479842, -0.15883107, -0.14976545, 0.08173769, -0.0590529, -0.10118086, -0.14395876, 0.10892799, -0.14659254,
+    0.08810377, 0.1165453, 0.01149164, -0.13041954, -0.04265237, 0.09046026, -0.04060086, 0.09192326, 0.05791448, -0.063212655, 0.14100163, 0.14484313, 0.025679408, -0.14521463, 0.022083431, 0.11447291,
+    0.07744536, -0.00655369, -0.05088102, 0.009185244, -0.059301786, -0.15570761, -0.036584806, -0.086059526, 0.05688817, -0.08011925, 0.0042801737, 0.101840876, 0.029500406, -0.120491825, 0.13324356, -0.20517714,
+    0.04134982, -0.14959647, 0.08348579, -0.0956146, -0.12837313, -0.038038455, 0.0381455, -0.04142915, 0.1121121, -0.08187162, -0.13798884, -0.030846821, 0.09845988, 0.09667417, -0.156246, 0.07202457,
+    -0.0137424525, -0.09349496, -0.15375656, -0.11924251, -0.13871697, 0.08199937, 0.032071967, 0.08540051, -0.04342249, 0.028590435, 0.10686264, -0.04581072, -0.08994273, -0.048616167, -0.08891343, 0.03974481,
+    -0.104165375, -0.034731578, -0.077083774, 0.07126437, -0.09303109, 0.091278695, 0.13590577, -0.13038027, -0.15694125, -0.14581713, -0.0496224, 0.055070803, 0.0419547, -0.0093092155, 0.07216011, 0.09178959,
+    0.057134017, 0.10459017, 0.026606662, -0.17165983, -0.12769125, 0.122975424, -0.014959368, -0.0691334, -0.13632941, -0.09887826, 0.11222528, 0.06314329, -0.16621199, -0.04522923, -0.012946998, -0.06777251,
+    0.057663746, 0.08735704, 0.05744582, -0.055512372, 0.024743315, -0.09230574, 0.10411715, -0.041988593, -0.12536903, -0.041484162, -0.044497736, 0.09552611, -0.104931615, -0.0061560436, 0.038109016, -0.11852571,
+    -0.14941737, 0.10530909, -0.02707735, 0.034988876, -0.10963806, 0.14733708, -0.004819787, 0.05919429, 0.053222086, 0.09829148, -0.02958472, 0.03745336, -0.07395079, 0.07895, 0.10533549, -0.082044385,
+    0.033485524, 0.09251257, -0.05691022, -0.057889026, -0.104902364, 0.10314262, 0.041395947, 0.010254532, -0.042491004, 0.15046963, 0.0056049847, 0.11713833, -0.07041676, 0.02266251, -0.1139574, 0.13925575,
+    -0.0021258348, -0.064336084, 0.09728345, 0.09048908, 0.012452279, -0.14431137, -0.09165022, -0.14869131, -0.1540792, 0.028496109, 0.065757185, -0.15417473, 0.011312409, -0.12893602, 0.16854924, -0.14856012,
+    -0.1577406, 0.0014254552, -0.042815257, 0.12589107, 0.11455976, -0.07943021, -0.11385836, 0.113637745, -0.08971897, 0.069618925, 0.1626935, -0.13905416, 0.10127056, -0.108485766, -0.16604225, -0.09766571,
+    0.014711659, 0.016276529, -0.10720222, -0.007973092, 0.08868418, 0.08317879, -0.08371554, -0.103065364, -0.06641835, 0.022373412, -0.04273582, 0.0025574216, 0.076717846, 0.10584761, 0.10527705, -0.07762801,
+    -0.043587297, -0.019481288, 0.10831674, -0.05288397, -0.018553235, -0.008974829, 0.15307583, -0.13322, -0.10227521, -0.11716768, 0.16761154, 0.029804146, -0.09458314, -0.0528348, 0.035504214, -0.029872151,
+    0.10838344, 0.05445308, -0.053328317, 0.079079665, -0.021121984, 0.07825783, -0.011294345, -0.12263997, 0.060492836, -0.108138345, 0.03404255, -0.12885137, -0.14077988, 0.012419706, -0.07237221, 0.0150185665,
+    0.0149235055, 0.13686886, 0.1589891, 0.013077962, -0.15919292, -0.060842242, -0.13359238, 0.15692657, -0.07584117, -0.07170901, 0.17595053, -0.11286054, 0.12571323, -0.13924293, 0.007955992, -0.07170576,
+    -0.14533511, 0.11411148, -0.0038831795, -0.13235049, -0.11658865, 0.09121429, -0.082731366, -0.10806735, -0.019293735, 0.054341882, -0.11069675, 0.005901418, -0.1920311, 0.11133618, 0.09018136, -0.1283679,
+    -0.0133671295, -0.10923843, -0.0567253, -0.026859783, -0.0027415422, 0.043546375, 0.044367924, -0.05991028, -0.15499833, 0.0868865, 0.20959139, -0.013490603, 0.10839677, -0.0791509, -0.065038264, 0.025908342,
+    -0.07005516, 0.10018359, 0.10996196, 0.090550154, -0.10040484, -0.17795561, 0.11222111, -0.082013085, -0.014489119, -0.045048483, 0.05360886, -0.0010848399, -0.09526986, -0.020242529, -0.00047357226, -0.011237187,
+    -0.017176703, -0.03170358, 0.073151104, -0.11205278, -0.007927482, -0.051007863, 0.102869004, 0.022412324, -0.16220969, 0.085738875, 0.09784285, 0.09749274, -0.10908894, -0.008497552, 0.0038245963, -0.029663282,
+    0.08359005, 0.1385988, -0.024140399, 0.08158848, -0.048118442, 0.041978158, -0.00949709, 0.011126733, -0.043469474, -0.04724382, -0.00080667395, 0.037488136, -0.15716617, -0.04457487, 0.23937199, -0.084028386,
+    0.09970614, 0.07413998, 0.07799067, 0.03904504, -0.06500614, -0.07544121, 0.15090035, -0.06759922, 0.0896166, 0.1418704, 0.29282376, 0.13886538, -0.08780989, 0.11724672, -0.12440543, -0.1409496,
+    -0.12796563, -0.06887731, -0.031611126, 0.076112926, -0.009462082, -0.1210167, -0.0489947, -0.018868115, -0.14760368, 0.108373836, 0.13483618, -0.024484815, -0.11694759, 0.10108856, 0.10646614, 0.10697452,
+    0.05378075, 0.07769638, -0.083629124, -0.14648788, -0.13565083, -0.017007994, 0.07498194, -0.096797496, -0.09334153, 0.10430131, 0.23399231, 0.083234906, 0.10448262, -0.13002892, 0.10581291, -0.11146896,
+    -0.064031795, -0.06501262, 0.048162464, 0.11587674, -0.068146154, 0.12606992, 0.06314944, 0.034443606, 0.05681496, 0.0019755599, -0.07670378, 0.12860438, -0.20919718, -0.0015712236, -0.009560991, 0.13740045,
+    0.05666647, 0.02471418, 0.15454173, 0.04461449, -0.13449073, 0.06174816, 0.08267163, 0.14118378, 0.10047102, 0.10778174, 0.02773558, 0.049136173, 0.1490154, 0.112450555, -0.096820824, -0.086688854,
+    -0.083508015, -0.13514575, 0.0877187, -0.09203772, 0.064764775, -0.09338593, -0.14060622, -0.13217966, 0.015345345, 0.07644849, -0.05195553, 0.08907437, -0.18635792, -0.0455709, 0.05972289, -0.12989806,
+    0.107064016, 0.022822328, 0.03284841, 0.049624365, -0.08153992, 0.06976304, -0.102367, 0.1117467, 0.020145519, -0.12352, -0.07578218, -0.031110467, 0.08563674, -0.13544649, 0.12612641, -0.07443378,
+    0.023134587, 0.064713776, 0.073505096, 0.0008289559, 0.12214747, 0.012150942, -0.12524375, 0.014410871, 0.025871554, -0.015043158, -0.028441222, -0.14266199, 0.08447756, -0.04216904, 0.11402784, 0.051441688,
+    -0.14585407, 0.014307661, -0.07547303, 0.13374336, 0.08622969, 0.10983699, -0.046103436, 0.014426016, -0.16926444, 0.056904472, -0.12099238, -0.10141469, 0.08490943, 0.07674307, -0.104424916, 0.13967888,
+    -0.05339653, -0.058489386, -0.10188108, -0.12394098, 0.04372909, -0.019105807, 0.111430936, -0.15944728, 0.13281949, 0.12746643, -0.00901897, 0.035720803, 0.09482278, 0.053167704, -0.14606178, 0.076696955,
+    0.07915259, -0.1434738, -0.0764066, 0.11926358, -0.09861838, -0.025250524, 0.089857265, -0.08665778, 0.028734887, 0.08605982, 0.072257355, 0.078217424, -0.04820703, -0.15798089, 0.08377231, 0.1297716,
+    0.11782888, 0.12080686, 0.0049909228, -0.1347394, 0.038402557, -0.12012464, 0.046254195, 0.11210251, -0.09787503, 0.13607721, 0.10326547, -0.0025972365, 0.07350343, 0.06376235, 0.0033682012, 0.12469827,
+    0.014255006, -0.154293, -0.013152165, -0.06561614, 0.12058757, -0.08353471, -0.015106015, -0.04985523, 0.13276142, 0.08999442, -0.10168744, -0.035741925, 0.03236566, 0.050973717, -0.065570444, 0.029681401,
+    -0.09846664, -0.10842474, 0.04770167, -0.0012067244, 0.05625792, 0.13115275, 0.03095801, 0.103639096, -0.06039352, -0.13910174, -0.098507084, 0.13352913, 0.08222135, -0.04630531, 0.10561655, 0.08965115,
+    0.09838397, 0.13787323, 0.08992598, 0.13364482, -0.02060706, 0.003255459, -0.01887981, 0.08375309, 0.0466014, -0.057883587, -0.09981414, 0.045608424, -0.092111, -0.057213355, 0.07996524, 0.1578032,
+    -0.002170406, -0.050715607, -0.04924541, -0.11073005, -0.082369946, -0.035241, -0.043878227, 0.068437144, 0.03563355, 0.07421087, -0.046831902, 0.124379896, 0.1339277, -0.01340567, -0.05652624, 0.14157459,
+    -0.1222451, 0.056822732, -0.13503647, 0.14580867, 0.06159479, 0.15287578, 0.10231618, 0.1446166, 0.001236768, -0.09862734, -0.028455494, 0.05430261, -0.0761366, 0.18725407, -0.12333625, -0.04837367,
+    -0.020072369, -0.030663667, -0.047078796, 0.04403378, -0.08271224, 0.10991779, 0.12290798, -0.04034741, 0.0657757, 0.027062638, -0.086589955, -0.10322821, 0.07349343, 0.124450125, -0.119906954, 0.0116322255,
+    -0.09571815, 0.08794574, -0.092878096, 0.14835751, 0.023912394, 0.1353851, -0.11004589, -0.023813555, 0.14253432, 0.114833914, 0.12716405, 0.09135503, -0.073266365, -0.098158166, 0.07417112, -0.14134152,
+    -0.09816796, -0.12051717, 0.016167967, -0.08843179, -0.03538633, -0.0021205936, -0.0011072053, 0.115146235, 0.028317312, 0.11872927, -0.019280653, -0.008094299, 0.05068081, 0.115408204, -0.14409716, 0.14789742,
+    -0.05690886, -0.008021852, 0.014106557, 0.117137544, -0.10477235, 0.09295236, -0.1512181, -0.06844721, -0.109386444, -0.009496957, -0.15176158, 0.0019067576, 0.06251126, -0.0002265256, 0.023922002, 0.14720169,
+    -0.07510769, -0.13309371, 0.06377482, 0.01088691, 0.13731793, 0.044814326, 0.025889946, 0.03218568, -0.0058276127, -0.13045196, 0.011805504, -0.10542781, -0.08258462, -0.13654557, 0.043036375, 0.15040424,
+    -0.11481548, 0.074288845, 0.034460958, -0.10789813, -0.02255093, -0.14696383, -0.09434701, -0.14627205, -0.16722114, -0.09594541, 0.1069399, 0.11980517, -0.051066384, -0.17989743, -0.0048533725, -0.01591734,
+    0.024080236, -0.06359939, 0.11073299, -0.061859887, -0.01458262, 0.045271598, 0.0790382, 0.08669913, -0.14588958, 0.14372915, 0.0604643, -0.016111534, -0.20785213, -0.04425231, 0.0117112305, 0.07447139,
+    -0.0038002585, -0.077765405, 0.0037217133, -0.13978578, -0.08030551, 0.033098742, 0.11811987, 0.033555966, -0.17584352, -0.0052576247, -0.041595686, 0.13285935, -0.07292671, -0.17256063, -0.05463909, 0.084932245,
+    0.07790461, -0.15488875, -0.029225154, 0.032012217, 0.05490739, -0.12432187, -0.12253997, -0.015610052, -0.14532265, 0.08609065, 0.10864083, 0.044480756, -0.028971612, 0.04097181, -0.046216365, -0.01544107,
+    -0.11408679, 0.13109748, -0.058342982, 0.04499342, -0.10726949, 0.05283867, -0.09094869, 0.12933843, -0.14147887, -0.06630095, 0.10308471, 0.051455677, 0.10535046, -0.16066034, 0.13490084, 0.1022777,
+    -0.010498244, 0.021911237, -0.103419095, 0.03312691, -0.07006826, 0.042840634, -0.079556264, 0.10919769, 0.041151796, 0.14506963, -0.0048772087, -0.00036425932, -0.03224117, 0.036733158, -0.13432334, 0.05709577,
+    0.10177475, 0.009107172, 0.020260992, 0.11333032, 0.11879655, 0.012856718, 0.008246478, 0.09479294, 0.046246603, -0.017820315, 0.07937607, 0.054203264, 0.14579791, 0.076250866, 0.076278076, -0.1059955,
+    -0.13041145, 0.13514061, 0.10804218, 0.09516826, -0.10540163, -0.10340703, 0.073292516, 0.12695703, 0.008831789, -0.072954655, 0.14360437, -0.10128366, 0.02377957, 0.14100614, 0.029787952, -0.039031517,
+    -0.12683351, -0.00078996166, 0.08233198, 0.09279223, -0.066759035, 0.083415724, 0.15051897, 0.116557695, -0.059567146, -0.049700007, -0.10435726, 0.11391624, -0.011265494, 0.15071827, 0.03540361, 0.056011975,
+    -0.078139864, -0.017613951, -0.14601667, 0.040472765, 0.046881203, -0.10233126, -0.084556945, 0.15275642, -0.121409126, -0.14352691, -0.1016357, 0.112857215, -0.04443274, -0.11099066, -0.104446955, 0.015671587,
+    0.16003902, 0.08639837, 0.023488743, -0.07880509, 0.1195946, -0.005471891, -0.10087648, 0.011312463, 0.14176176, -0.083937176, 0.15995876, 0.0013332164, -0.04391759, -0.030071005, -0.011001007, -0.13520853,
+    0.005759745, -0.074507736, -0.05794701, -0.092586905, -0.06395232, 0.09550498, 0.04939306, 0.020433612, 0.08534355, 0.08077054, 0.065353125, -0.012333715, -0.07202139, -0.004902319, -0.021600185, -0.12198301,
+    -0.11746052, 0.072069876, 0.1385886, 0.022157423, 0.012167345, -0.109756164, -0.14216706, 0.09732015, 0.08831814, -0.014766736, 0.0616526, 0.027910592, 0.008267729, 0.102292314, -0.03249495, -0.12395796,
+    0.116700545, -0.022525674, 0.12802559, 0.06402886, -0.01810437, 0.08587007, 0.13127245, -0.12118217, 0.057851292, 0.050344866, -0.05730699, 0.12016001, 0.16458407, -0.00316083, -0.09604937, 0.10187122,
+    -0.11442031, 0.062661394, 0.07095949, 0.054638315, 0.15636879, -0.084729455, 0.06120094, 0.11622252, 0.060768604, -0.014186348, 0.019060982, -0.109642215, -0.10827935, 0.040759634, -0.003018039, -0.06377948,
+    0.09266026, -0.045231596, -0.12338875, 0.09907836, 0.0662326, 0.06797254, 0.048841726, -0.068022, 0.06897549, -0.05618879, 0.078955695, -0.079582945, 0.13721678, -0.13993104, -0.08078855, -0.10983515,
+    0.08455701, -0.007111983, 0.1273845, 0.13148984, 0.06917231, -0.07689483, 0.15156543, 0.07103961, 0.016618049, 0.13102609, -0.017704187, 0.08135943, 0.10072115, -0.052447204, 0.14919826, 0.14236833,
+    -0.006012806, -0.08448918, 0.004620308, -0.020631049, -0.05241919, -0.047062226, -0.080931254, 0.12784678, -0.027555685, 0.05886964, 0.07224992, 0.0036778515, -0.0012244458, -0.08202732, 0.06515044, 0.031417448,
+    -0.047605056, 0.1374196, 0.12496872, -0.013405651, 0.0011317064, 0.052142385, 0.019659271, 0.13765761, 0.021198753, 0.122551605, 0.16910715, 0.09833355, -0.052747715, 0.0059468145, -0.008646081, 0.04048679,
+    -0.106552616, 0.026857914, 0.08481334, 0.082470566, 0.10415632, 0.07656115, 0.14414756, -0.10125375, 0.08892937, -0.14867963, -0.028544549, 0.10591971, 0.20789006, -0.09675451, 0.07863812, -0.041313376,
+    0.10613212, -0.15154405, 0.10965814, 0.04838415, -0.1409219, 0.12881428, 0.064402804, 0.10933515, 0.1071184, 0.034074917, -0.11522767, -0.13136682, -0.08454601, 0.099420376, -0.13095199, 0.099495545,
+    -0.099387966, 0.027778659, 0.1330304, 0.1470589, 0.13708188, -0.026882324, 0.1531492, 0.054850478, 0.15419503, 0.09158459, -0.009687664, -0.10463537, -0.0201589, -0.096629344, 0.049493987, 0.036141947,
+    -0.12904209, 0.02108363, -0.054145787, -0.14715487, -0.04216935, -0.036246855, -0.10941828, 0.080574155, 0.064840905, 0.1236534, 0.12493338, -0.117729835, -0.0029324458, 0.115778245, 0.07239268, -0.104257256,
+    0.032017186, 0.08798286, -0.021643724, -0.104025215, 0.064610966, -0.044716567, -0.10190895, -0.09311624, 0.103892826, -0.07399821, 0.10884391, 0.03787182, -0.17762206, 0.14725617, -0.05452415, -0.04045056,
+    -0.16720572, 0.06941904, 0.012556615, -0.029350309, 0.0017788397, 0.05213122, 0.0042535434, 0.038284864, -0.06515732, 0.05770796, -0.11065838, 0.016009975, -0.136358, 0.04556303, -0.027937843, -0.12740779,
+    0.05721625, 0.053158008, 0.02574158, 0.1032048, 0.12502475, 0.09624477, 0.07038005, -0.036272477, 0.09076514, -0.07088138, -0.027088163, 0.069911994, -0.05031675, 0.054350525, -0.12957643, 0.030287389,
+    -0.0011853187, 0.12930381, -0.00046447894, -0.08434884, 0.11920466, 0.10916293, -0.079064526, 0.00032535254, -0.01747889, 0.05508026, 0.074153736, 0.05526944, 0.102222025, 0.04098503, -0.0007678026, 0.11402674,
+    0.14840126, -0.1676198, 0.10902024, 0.0776577, 0.045200475, 0.14986917, 0.10740776, -0.031863187, -0.012473225, -0.05819109, 0.060196333, 0.034986358, -0.13442104, -0.09879414, -0.15284534, 0.021518163,
+    -0.03696436, 0.14961717, 0.10053381, -0.08016536, -0.12223264, -0.10611677, 0.049206667, 0.014902449, -0.1061595, 0.102109194, -0.14205724, 0.086619146, 0.15280804, 0.032667484, 0.11494883, -0.14892906,
+    0.12423352, 0.12337649, 0.029033333, 0.07110858, -0.060408533, -0.06604884, 0.068844974, 0.05475284, -0.14041623, 0.0031035284, 0.064703405, -0.060401224, -0.011776785, 0.06454343, 0.13744198, 0.055364937,
+    -0.116103046, 0.13259654, -0.026359698, -0.047855422, 0.082143046, 0.14071678, -0.13932659, 0.118202545, 0.029120052, 0.043244895, -0.02697426, -0.095476285, -0.06397338, -0.08377093, -0.021915963, 0.104779325,
+    0.038425993, 0.013532277, -0.046188537, 0.08008224, 0.012240119, -0.0155394925, -0.112947285, -0.014502205, -0.012297154, 0.076763764, 0.11563316, -0.06930281, -0.17205313, -0.07151899, 0.10884276, -0.001780294,
+    -0.08984112, 0.07989503, 0.06917995, 0.04872667, 0.047004063, -0.12098787, -0.024239007, -0.026547331, 0.059832197, -0.058166716, -0.10131888, -0.0725991, 0.06722799, 0.032788485, 0.04511973, 0.017408278,
+    0.106147915, -0.12096449, -0.16160046, -0.11550148, -0.052533716, -0.048835773, 0.08929935, -0.16383746, 0.10123775, -0.086364366, 0.094556, -0.025734795, -0.0121556455, -0.04628539, 0.061030366, -0.022459693,
+    0.008237004, 0.0611718, 0.096225016, 0.056045122, 0.060617592, 0.13646401, -0.03274787, 0.08580942, 0.025032552, -0.06119087, -0.05443303, 0.04036283, -0.0746717, -0.110939644, -0.16244896, -0.086457215,
+    -0.11596452, 0.032300342, -0.031191017, -0.009553719, 0.10210725, -0.10461343, 0.020251906, 0.10723741, 0.11479812, -0.09595458, -0.102261014, -0.06652258, 0.019340549, -0.086103365, 0.1751826, -0.009587252,
+    -0.0698623, -0.110459186, 0.10915155, -0.10005467, -0.08833006, 0.13537253, 0.11575781, 0.14663243, 0.093159825, -0.016546777, 0.13539478, -0.079720445, 0.07005279, -0.15352261, 0.1367706, 0.08283106,
+    0.0234944, 0.15797277, 0.043540798, -0.15119357, -0.030607793, -0.10823221, 0.09477685, 0.050605807, 0.0048858523, -0.06673344, 0.23389862, 0.11389531, 0.16902363, 0.08066507, 0.06506251, 0.053611368,
+    0.09409368, 0.063689284, -0.053597532, 0.03167367, -0.13217534, -0.17934453, -0.16892658, -0.095663674, 0.078274764, 0.041753523, -0.2069065, 0.07266779, -0.12734774, 0.14987777, -0.1525697, -0.009422752,
+    0.07404106, 0.084086776, -0.02829974, -0.06334818, 0.10141504, 0.04316613, 0.045333188, -0.104305394, -0.124673225, 0.14256783, 0.063897334, 0.1272916, 0.04449721, -0.030774817, 0.0620285, -0.1452418,
+    0.1535075, -0.017973144, -0.15606225, -0.021547332, 0.08961757, -0.00040257565, -0.04341908, 0.13546537, 0.1220253, 0.07671435, -0.1799947, 0.12557356, 0.06256692, 0.15921338, 0.1227345, -0.2446226,
+    0.10588973, -0.048569657, 0.0146534005, 0.13684763, 0.0775003, -0.12795965, -0.06579068, 0.057827976, -0.14812693, 0.0048138797, 0.10895, 0.08978826, 0.21359459, -0.040782124, -0.0030209715, -0.005173981,
+    0.11287063, -0.12406259, 0.05667778, -0.13190067, 0.0952928, -0.21598274, -0.09567112, 0.080580406, 0.15154824, 0.06239948, -0.004250878, -0.029919505, 0.056771457, 0.10433117, -0.076438956, -0.009301514,
+    -0.02508239, 0.11972989, 0.08386035, -0.039033838, -0.044308566, -0.024133876, -0.0010326818, -0.0011984663, 0.07329668, -0.09837084, 0.21466513, -0.093842305, 0.108869486, 0.04123798, -0.15754959, -0.12595172,
+    -0.0320767, -0.013327541, 0.08337364, -0.068204425, -0.09021706, 0.044005986, -0.14022078, 0.12759247, -0.03291881, 0.12668504, -0.15487824, -0.062282115, -0.1433857, 0.07996523, -0.10894213, 0.0032849463,
+    0.031309027, 0.06218921, 0.026666671, -0.07426925, -0.054423343, -0.015203666, -0.11498763, 0.06529276, 0.043722104, 0.0019318057, 0.12831466, -0.07628954, 0.0026237092, -0.11875002, 0.15361775, 0.094939455,
+    -0.06675501, 0.04997148, -0.022428455, 0.13573657, 0.14747705, 0.07231656, 0.17212571, -0.138332, 0.063876145, 0.057026237, 0.014674579, 0.14357759, 0.057449345, -0.0021841861, -0.1077589, -0.008251037,
+    0.109292775, 0.011358089, 0.07987678, 0.11479877, 0.037885603, 0.03671469, -0.063115284, 0.07133502, -0.06938703, -0.14479965, -0.08770254, 0.060082383, -0.0956719, 0.06121823, -0.04889058, 0.06970048,
+    -0.072564155, -0.029434573, -0.07194051, 0.14678057, -0.10073183, 0.018424654, 0.12950538, -0.16507266, -0.012877451, -0.074820206, 0.19840509, 0.0386968, 0.07435641, 0.022993999, -0.04711279, -0.055485018,
+    -0.046726774, 0.14550367, 0.02146846, 0.10795951, 0.018960822, 0.121106714, 0.029560467, -0.14730117, -0.051077347, 0.045503236, -0.11290415, 0.057480156, -0.059142847, -0.020158889, 0.10308697, -0.031933155,
+    -0.10582127, 0.028005466, -0.09908366, -0.10125227, -0.044232078, 0.084420964, -0.0632858, 0.0955517, 0.10879587, 0.0588542, 0.13113241, -0.04901387, 0.1491082, -0.11214285, 0.11153699, -0.11981868,
+    -0.0090343375, 0.07040456, -0.11335857, -0.05034193, -0.04924282, -0.10711942, -0.019865518, 0.011338194, -0.024204634, -0.13154455, 0.06744447, -0.04274428, -0.016725212, -0.15171628, 0.1792558, 0.029256063,
+    -0.013648123, 0.023047365, -0.06888762, 0.021078708, -0.14580332, -0.011221571, -0.026352387, -0.14037894, -0.18785708, 0.117973, 0.06895072, -0.010298012, -0.10429965, -0.030066455, 0.14171083, 0.08298977,
+    0.032674763, -0.011491519, 0.10206499, -0.103644334, 0.06978397, -0.109836176, -0.15098375, -0.09159955, -0.007913956, 0.043892205, -0.033059217, -0.049000908, 0.004136777, 0.07905722, 0.05229171, -0.10226471,
+    0.010327699, 0.12533195, -0.064927585, 0.12901987, 0.040490612, -0.094344564, 0.100016125, 0.099877, -0.052671578, -0.15265147, 0.14453457, 0.01884419, 0.022481913, 0.08303104, -0.016781986, 0.0883017,
+    0.007463663, 0.036229897, 0.036103357, -0.13547951, -0.06266472, 0.1322889, 0.13106214, -0.07088078, -0.015078901, -0.031929567, 0.0051376373, 0.014935119, -0.08476665, 0.10639736, -0.13184099, -0.12412339,
+    -0.039802734, 0.07036071, 0.13695063, -0.0695032, -0.097587235, 0.12092682, -0.07991103, -0.029936314, -0.06855827, 0.0707172, -0.0961843, 0.05755531, 0.12784633, 0.06879107, 0.02083268, 0.064029224,
+    0.12153627, -0.094087, 0.106401466, -0.054052547, 0.13173458, -0.027351085, -0.06499321, 0.03604437, 0.04150728, -0.0027141206, 0.07603917, -0.018874489, 0.03154775, 0.12712568, -0.13298173, -0.12738347,
+    -0.11734392, 0.140599, -0.08184781, 0.0118567515, -0.08491848, -0.09661087, -0.046384975, -0.033068947, -0.0633306, 0.115749314, -0.0547131, 0.15020968, -0.0063688722, -0.11628696, -0.04154139, 0.022781445,
+    0.08419871, 0.11913621, 0.00419441, -0.09396803, 0.07100126, 0.12960705, -0.069006614, 0.09467802, 0.012479915, -0.08542113, -0.08049784, 0.09399905, 0.13563086, -0.14803353, -0.09626137, 0.06989191,
+    -0.12226012, 0.13951023, 0.05255586, -0.082942806, 0.01477215, -0.0016280711, 0.05314002, -0.09674029, -0.0606231, 0.02241166, -0.025371484, -0.037512034, 0.11460125, 0.05415476, -0.08046757, 0.13480502,
+    -0.080327846, 0.02594311, -0.05299328, 0.07681433, -0.09545388, 0.083922304, -0.1540411, 0.092618294, 0.042795245, -0.10652306, 0.15232013, -0.061146144, -0.023608508, 0.14277364, -0.057386424, 0.054942954,
+    0.10002699, 0.05501648, -0.043923263, -0.05469153, -0.122423805, -0.010147732, 0.062449925, 0.06166824, -0.049453575, -0.1199153, 0.08221344, -0.13993129, 0.11506893, 0.18511435, -0.11988375, 0.13056125,
+    0.135439, -0.05020453, -0.12619977, 0.035666607, -0.12276873, 0.14921874, -0.011778797, 0.04041126, -0.020819226, -0.0041538756, 0.1572473, 0.01494604, 0.022109615, -0.027120125, -0.12212178, -0.09247283,
+    0.08821223, 0.0660507, -0.14182395, -0.07677991, -0.08527839, -0.10360218, -0.13752976, 0.10551248, -0.020809254, -0.077875994, -0.078801125, 0.08518785, 0.0053546675, -0.025557201, -0.103114076, 0.059057865,
+    0.060304992, 0.1557221, -0.07232734, 0.05181116, 0.020475572, 0.14646402, -0.013078705, -0.12468756, -0.08696421, 0.012555366, 0.07414632, -0.10048668, 0.19074585, -0.009117374, 0.01723746, 0.12861337,
+    0.0845061, -0.01661821, -0.13410845, 0.022430407, -0.060557995, 0.09266535, 0.054631088, 0.119339585, 0.03341856, 0.05704841, 0.116516724, 0.013468021, 0.09561737, -0.0046687433, -0.12608261, -0.12180171,
+    0.14374636, 0.14261635, -0.038288284, 0.082995825, 0.1434347, 0.13602152, 0.071275994, 0.015062756, -0.07114, -0.010570491, 0.014112262, -0.14960618, 0.18970774, -0.119312674, 0.07458983, -0.027120516,
+    0.036249474, -0.05328325, 0.0058262628, 0.028128752, -0.09267327, 0.10574152, 0.08521358, -0.07862047, -0.024006682, 0.09488615, 0.12094868, -0.13002136, 0.04371536, 0.12749337, -0.04142847, 0.11549526,
+    -0.13901038, -0.049325485, 0.13516983, -0.04031436, -0.14493045, -0.07184572, 0.21376987, -0.18706076, 0.10730523, -0.119918816, 0.07260002, -0.053058557, 0.060528204, 0.0134734865, 0.17696874, -0.11975203,
+    -0.12617595, -0.10676816, 0.08363612, 0.10019032, 0.14508907, 0.08629736, 0.11839014, -0.14058591, 0.010872937, -0.07820792, 0.09051666, 0.10365227, -0.07974198, 0.10056415, 0.07876238, 0.04252815,
+    -0.010002564, -0.016788175, 0.06757354, 0.006228333, -0.09356639, -0.07180797, 0.116806455, -0.045009006, 0.051670976, -0.1533301, 0.13550147, -0.08645079, -0.014945069, -0.11626464, 0.09389489, 0.030860603,
+    0.09293745, -0.09347555, -0.045261063, 0.11374483, 0.015682776, 0.11339913, 0.12211251, -0.090862885, -0.15659364, -0.11161546, 0.23930807, 0.05094045, 0.1314612, 0.1591788, 0.11854699, 0.01280414,
+    -0.15879525, 0.097333305, -0.09221124, -0.04608074, 0.04388695, 0.08203844, 0.12698804, -0.25593975, 0.102069765, -0.08140748, 0.110449, -0.0697991, -0.1777292, -0.11955911, 0.21012588, -0.10963854,
+    -0.06647666, -0.16006878, 0.019817434, 0.09957496, 0.122589625, 0.14799532, 0.0025624156, 0.038756736, 0.06600874, 0.088545084, 0.23419563, -0.023874158, 0.13940263, 0.050159182, 0.10279107, 0.049831193,
+    0.021851739, -0.053429533, -0.1291228, 0.026722742, 0.0097472705, -0.08204577, 0.16287911, -0.22775461, -0.054573387, -0.15979992, -0.09090829, -0.028700909, 0.00548876, 0.07506639, 0.2683857, -0.065438345,
+    -0.036245286, -0.04608945, -0.06871864, 0.101962514, 0.006526832, -0.0021217016, 0.072364226, 0.043815874, 0.0673222, -0.10159661, 0.16474895, -0.10053411, -0.053939328, 0.11399682, 0.1532523, 0.017695952,
+    0.1527311, 0.12279509, -0.016621795, -0.049177237, 0.12524234, 0.0015153438, -0.12962648, -0.10394397, -0.13153458, 0.014963388, -0.1380983, 0.026213484, 0.073182225, 0.013803868, 0.08476232, -0.10214851,
+    0.093740255, -0.07134604, -0.068429306, -0.051805004, -0.048338555, -0.15091798, 0.081915334, -0.069287755, -0.13934045, 0.14302386, -0.11044203, -0.07747925, 0.011203491, -0.06526028, 0.113468185, -0.114356585,
+    0.008344322, -0.051936522, -0.13935393, 0.09300141, 0.12098302, -0.035238504, 0.08292852, -0.13695501, -0.05022194, 0.10721411, -0.06515607, 0.08338353, -0.0848016, 0.009286926, 0.09378795, 0.0389275,
+    -0.024545386, -0.088222764, -0.009736702, 0.05283714, -0.024817323, -0.15893777, 0.050381448, 0.020374253, -0.058701664, 0.09526834, -0.077317365, -0.13788122, 0.10481016, -0.062204227, -0.113602914, 0.09838942,
+    -0.1145082, -0.074489735, -0.004635199, 0.05855935, -0.14015907, -0.08651923, -0.11677291, -0.080028355, -0.07350961, 0.011706546, -0.061280407, -0.07073986, 0.021652788, -0.11287402, -0.09410307, -0.118111886,
+    0.02795586, -0.115399934, -0.07020106, -0.14763215, -0.14994203, 0.068538204, -0.059050526, -0.09099014, 0.09880535, -0.037175678, 0.13301194, 0.05058004, 0.09140549, -0.032749847, -0.1454806, 0.041319534,
+    0.085426524, 0.072081044, -0.02874952, -0.17675924, 0.15075703, -0.08177394, -0.041586116, -0.02495715, 0.0039697504, 0.029026851, 0.10169311, 0.009851906, -0.020163938, -0.034368496, 0.08693175, 0.033237696,
+    -0.103033505, -0.023922771, -0.112679824, -0.15574042, 0.036483586, 0.0033117966, 0.11955546, 0.0073085874, 0.108224764, -0.11589535, -0.03462399, -0.13124838, -0.14475088, -0.096374914, 0.011112368, 0.029202025,
+    -0.15429011, -0.061559476, 0.097576536, -0.11828768, -0.186764, -0.20843732, 0.04273723, -0.25882226, -0.0727102, 0.017011331, 0.008135593, 0.120915, -0.005570842, -0.14983419, 0.3376097, 0.13066411,
+    0.14466044, -0.1323339, -0.13278793, 0.12728772, -0.050606962, -0.125936, 0.021338, -0.17281048, -0.061865583, 0.10654466, 0.25282124, -0.13196966, -0.08448891, -0.09019316, 0.14034356, 0.10074574,
+    -0.20928271, 0.016454378, 0.054642443, 0.09991129, 0.048966374, -0.03308565, 0.1709476, -0.03540582, -0.06949981, 0.037112713, -0.06384474, 0.031712238, 0.012997184, -0.10745018, 0.29096562, 0.13585958,
+    0.14735612, -0.09957984, -0.14464064, -0.03837679, -0.12660135, -0.008141336, 0.040719096, -0.12173317, -0.090272136, -0.15487628, 0.22959971, 0.0056094294, -0.015012168, -0.035220265, -0.13716161, -0.002941555,
+    0.051794566, 0.14126799, -0.11483163, -0.124289885, 0.019485198, -0.08369548, 0.05525767, -0.10619949, 0.04226779, -0.18006137, 0.034544256, 0.049555305, -0.01674298, 0.056823064, 0.1294077, 0.021711303,
+    0.058506973, 0.031550672, -0.08372157, 0.14187336, 0.12771198, 0.161838, -0.112017296, -0.03527959, 0.12860009, -0.090573, 0.15495983, 0.115526974, 0.06971252, 0.14738674, 0.10837287, -0.06511019,
+    -0.03987722, -0.08102412, -0.0842676, 0.11981897, -0.042375248, -0.12921497, 0.18851446, -0.30060416, 0.002952617, 0.022425292, 0.08560792, 0.099361636, -0.16787326, 0.008697262, 0.35497785, -0.06491426,
+    0.09517791, -0.062283132, -0.026267469, -0.1409611, -0.008367554, -0.04217087, 0.17822936, -0.03178919, 0.064753406, -0.14848891, 0.18697625, -0.08776869, -0.09569876, 0.032527443, 0.09075579, 0.15533777,
+    -0.03346171, -0.0052402257, 0.0970328, -0.0048262915, 0.0817275, -0.066950314, 0.02216815, -0.14051077, 0.06444987, -0.08130427, -0.11012598, -0.08604178, 0.1529795, 0.017368615, -0.035101123, -0.07203475,
+    -0.05809437, 0.10347708, 0.005822029, 0.11607591, 0.035609353, -0.060427714, -0.06628748, 0.063284144, 0.098411754, -0.07213255, -0.015063005, -0.004217292, -0.045228384, 0.13803941, -0.13082303, 0.12109698,
+    0.092106685, 0.02519734, 0.054329608, 0.13967806, -0.003619182, -0.13536015, -0.1058841, 0.09160199, 0.09510855, 0.14341119, 0.12108461, -0.0064299353, -0.04346276, 0.09364409, 0.07300937, 0.05917374,
+    0.16111934, -0.087341495, 0.076506004, 0.102159746, -0.055358123, 0.08482103, 0.090688534, 0.05845654, 0.06580761, 0.14057034, 0.11852549, 0.02364741, -0.05478572, 0.078847155, 0.10124576, 0.011680401,
+    0.066666365, 0.020136764, -0.081839114, 0.11016659, -0.08625754, -0.039827224, 0.026153168, -0.12985885, -0.103297554, 0.1222405, 0.16117682, 0.13055725, 0.083996095, -0.08563236, 0.12372316, -0.1578799,
+    -0.09266418, 0.1487088, -0.08695556, 0.08964292, -0.07543371, -0.12491451, -0.089256786, 0.08808909, 0.09303774, 0.081836276, -0.03264823, -0.10825135, 0.12257658, -0.06266949, -0.013524229, 0.0528013,
+    0.021350287, -0.10932298, 0.12119439, 0.04791623, -0.14469887, -0.027266975, -0.13484134, 0.13111824, -0.019521004, 0.120525524, -0.056466367, 0.07645869, 0.19399229, -0.07920613, -0.13041875, 0.13775696,
+    0.06573282, 0.119858526, 0.04552868, 0.1010708, 0.035953913, -0.07824388, -0.0951676, -0.03844733, 0.114169955, -0.08465808, 0.15758534, 0.09292982, 0.054214418, -0.01900374, 0.01574839, 0.09017348,
+    -0.11300644, 0.068376675, -0.045129415, 0.03379034, -0.14924724, 0.10208811, 0.061950523, 0.102835655, 0.023465952, -0.029974781, -0.09957723, -0.11389935, -0.06763405, -0.10334998, 0.1595154, -0.047546342,
+    -0.078047566, -0.031668667, -0.034587767, -0.06565319, 0.14988507, -0.0645578, -0.014506772, -0.100219734, 0.036980275, 0.09928546, 0.09231039, 0.019720182, 0.10035317, -0.045459688, -0.11160234, 0.024437018,
+    -0.056049775, 0.07723802, -0.011332844, -0.055448744, 0.063971944, 0.075366795, 0.10577158, -0.094078034, 0.020625645, -0.072707675, -0.09038572, 0.0108182365, 0.02467224, 0.07824062, 0.011567767, 0.13028114,
+    0.043034274, 0.0074601355, 0.055717234, 0.024229055, -0.07316433, -0.13448174, 0.14628583, 0.116967514, 0.17226535, 0.097449936, -0.09099624, 0.10921249, -0.08855644, 0.018164923, 0.036281556, 0.029157862,
+    0.14083494, 0.03956398, -0.13506576, 0.07742617, -0.001603879, 0.048348106, -0.09254987, 0.13033053, 0.034394786, -0.041806426, -0.06350306, -0.03512574, -0.09473971, -0.040856857, -0.025474897, 0.103741944,
+    0.084926166, 0.01795303, 0.0016100954, 0.072049364, -0.13194393, 0.060874015, -0.013230726, -0.10706944, 0.17146087, 0.13314606, -0.00963461, 0.07671744, 0.022102889, -0.031990822, -0.07847475, 0.057657253,
+    -0.08934848, -0.09099376, 0.058527652, 0.108957596, 0.102559604, -0.039105557, 0.098767824, 0.058418833, 0.071063355, 0.014194993, 0.15561123, 0.07033821, 0.16965434, -0.06791037, 0.07926384, 0.04417081,
+    0.051182065, -0.057604242, 0.029413693, -0.0396179, 0.054224133, -0.107569315, 0.14378738, 0.024920125, 0.17748523, 0.044144575, -0.124184266, 0.08346136, 0.14551261, 0.15576975, -0.1172317, -0.039352726,
+    0.034092806, 0.022733046, -0.14767146, 0.12885557, -0.15607014, -0.029180974, -0.05643303, -0.13951169, -0.062099617, -0.13416685, 0.06267155, 0.053999916, 0.076419584, -0.105699964, -0.004955506, 0.03610225,
+    -0.105851345, -0.13825846, 0.13392925, -0.12446819, -0.10584713, -0.08001839, 0.0752598, 0.04848981, -0.14293164, -0.065007746, 0.0096511245, 0.022750217, 0.02167368, 0.06896426, -0.15929492, -0.16967343,
+    -0.020911137, 0.085945025, -0.04335922, -0.07168257, -0.09747302, 0.12343351, 0.08688237, 0.14053366, 0.09646487, -0.034643233, -0.13466537, -0.08572283, 0.054983363, -0.04598817, -0.018831907, -0.10218145,
+    -0.15144336, 0.009666716, 0.11160889, 0.13362023, -0.15686665, 0.06923451, -0.08145285, -0.07195811, -0.093923986, 0.092751615, -0.15927409, 0.14219795, -0.063866146, -0.080152996, -0.12671663, -0.17992394,
+    -0.077303626, 0.048179492, 0.013337333, -0.01900152, 0.10862509, 0.13599253, 0.08898249, 0.06568713, -0.10084277, -0.037674394, -0.044419345, -0.074395634, 0.004911989, 0.10565397, -0.026911452, 0.015832707,
+    -0.12554559, -0.0608288, 0.10926503, -0.06162957, 0.008456058, 0.06670574, -0.115995415, 0.12023925, 0.019887904, 0.01829687, 0.04078092, -0.017973617, -0.05835526, -0.15934537, -0.013704838, -0.15265587,
+    -0.051650017, -0.021277161, -0.13926706, 0.09255341, 0.036470648, -0.08039861, 0.11956491, -0.11268405, -0.033129163, -0.06869972, -0.15681046, -0.12041266, 0.09171047, -0.14573862, 0.054835897, 0.04593256,
+    0.060146328, -0.068827346, -0.026956484, -0.010446483, -0.14022885, -0.09149354, -0.052864965, 0.008832284, -0.031217953, -0.10827508, 0.00656758, 0.00049097044, 0.14842623, -0.06819949, -0.12480164, 0.07366595,
+    -0.116050646, 0.05866266, -0.067253634, -0.08166879, 0.118654616, -0.031879038, 0.09251855, -0.024329098, -0.14335175, -0.079350665, -0.024069319, 0.04860107, -0.0011560927, 0.05656434, -0.036555693, 0.15214856,
+    -0.102193676, 0.06559739, -0.07772901, -0.05134157, 0.049471904, -0.11067242, -0.14193927, -0.06878531, -0.011653443, -0.09396956, 0.012016086, -0.015124844, 0.032862037, 0.101211645, 0.06874453, -0.052418996,
+    0.0025686023, -0.121122606, 0.05733087, 0.091534354, 0.0036357846, -0.06973039, 0.0790185, -0.14231806, 0.14385106, -0.0258352, -0.14257343, 0.13340618, 0.07922368, 0.09825724, 0.055670213, 0.13140218,
+    0.07065563, 0.04669964, 0.00585335, 0.03991714, -0.15703064, 0.03218717, -0.12876801, -0.014383758, -0.03847397, 0.044360742, -0.042730156, 0.03167699, 0.072854884, 0.09917895, 0.12775436, -0.15040596,
+    -0.12343176, -0.12698373, 0.1425472, 0.14342938, 0.026265964, 0.15109089, 0.036390897, -0.091220915, -0.06445214, -0.043970894, -0.16237083, -0.006635912, 0.024281133, 0.020406961, -0.16136171, 0.054683656,
+    0.08024952, 0.123560674, 0.040472586, 0.004291108, -0.14912793, -0.01523911, 0.10711736, -0.009495758, 0.051053554, 0.055922523, -0.0027863698, 0.012459284, 0.0069920337, -0.08089045, -0.05094082, -0.06001929,
+    -0.03605233, -0.013023478, -0.08427742, -0.018065289, -0.058218617, 0.15240604, -0.14452747, 0.103298865, -0.071553744, -0.04357406, -0.1765994, 0.15027428, -0.036098145, 0.0015083497, -0.10496923, 0.10071942,
+    0.09169102, 0.099614955, -0.02801282, -0.10873492, -0.037315127, -0.025964199, -0.050795075, 0.03165927, 0.062075138, -0.027009824, -0.10883492, 0.11834969, -0.035257444, -0.17929816, -0.10686284, 0.09898745,
+    -0.06724374, -0.10434045, 0.13260952, 0.11132429, -0.034254603, 0.06232804, -0.15090154, -0.103695765, 0.1438829, -0.11606402, 0.013385726, -0.06881125, -0.024633933, -0.10013994, -0.20618217, -0.12443816,
+    -0.13775094, -0.07124818, -0.09601523, 0.06828688, 0.10697547, 0.088929825, -0.1658107, 0.11533807, -0.0677102, -0.016682472, -0.18464315, -0.14875968, 0.054408133, 0.04419596, -0.0031429003, 0.11782403,
+    0.13342299, -0.03919337, 0.12603128, -0.1378936, -0.12924272, -0.013717413, -0.2440228, -0.09438839, 0.010552486, -0.13307463, 0.14077096, 0.11585513, -0.03944915, -0.12862085, -0.1403307, 0.027820135,
+    -0.014431909, -0.038749278, 0.13307261, 0.07492216, 0.010255422, -0.11608819, 0.03724823, 0.12624653, -0.15252909, -0.13258624, -0.22245386, -0.020503422, 0.053552568, 0.07112515, 0.023661472, 0.13642387,
+    -0.055077665, -0.14754643, -0.13390864, 0.10064406, 0.1462598, 0.02481097, -0.20872666, 0.065787345, 0.009347279, -0.12734252, -0.10247987, -0.05485271, -0.06587589, 0.0036077525, -0.18667953, 0.098766424,
+    0.112273194, 0.016765773, -0.059937093, 0.11207166, -0.043554865, -0.020277271, -0.11066909, -0.046390347, 0.08682245, -0.038840555, -0.28056082, -0.14018632, -0.12551789, 0.06475976, -0.09460505, 0.03695004,
+    -0.061165646, 0.11688975, 0.14068553, 0.14805122, -0.15207158, -0.100355044, -0.2448407, -0.031864382, -0.044875216, -0.12669063, 0.059212092, 0.015611214, 0.034965944, -0.012803094, -0.23102455, 0.05313479,
+    0.08379536, -0.061771624, 0.097009845, -0.12670135, 0.047891684, 0.04989244, 0.045484677, -0.13472587, 0.10205287, 0.010802583, -0.09481815, 0.072486445, 0.08615166, 0.113949195, 0.11170896, 0.089054614,
+    -0.103561215, -0.03172442, 0.104239486, -0.08817386, 0.11382004, 0.0053096907, 0.047847927, 0.058359545, -0.07791349, 0.03809379, 0.17567669, -0.05948116, 0.13221791, -0.09817003, -0.19009952, -0.044289798,
+    0.17324464, -0.06232911, 0.10867755, -0.13322552, -0.0038786938, -0.17091995, -0.1326879, -0.117737204, 0.056888953, -0.13373803, -0.07935631, -0.0052768737, -0.1335852, -0.023154704, -0.11128351, -0.020194829,
+    -0.0009873478, 0.16789103, -0.022949236, 0.05533977, 0.074602656, 0.097279474, -0.058864996, 0.036936823, -0.10306041, 0.101692416, -0.055466447, 0.044892386, -0.057972223, -0.120581, -0.10630543, -0.086952485,
+    0.09332068, 0.048444234, -0.039780203, -0.14490122, 0.07730351, 0.030072523, -0.22961646, 0.06658581, 0.0004842445, 0.12182678, -0.08406821, -0.04099185, -0.013982654, -0.005724954, 0.039580643, -0.054237258,
+    0.13632607, 0.16998121, -0.1204377, 0.06773123, -0.14188118, 0.06918897, -0.16221163, 0.09932888, -0.025986314, 0.0110734, -0.021709409, -0.040490776, 0.08275153, 0.03016533, -0.2048298, 0.10958724,
+    0.10524108, 0.024588851, 0.038784374, 0.05874385, 0.08112622, -0.27408376, -0.060213488, -0.021731615, 0.077596396, 0.030382773, 0.07286617, -0.12555331, 0.11277403, 0.1694717, 0.015878081, -0.18265252,
+    -0.019472875, 0.17463598, -0.02574175, -0.112291865, -0.14473812, -0.03077568, 0.07246127, -0.114974804, -0.009077907, -0.02146488, 0.18540572, 0.10713561, 0.17981604, -0.068243556, -0.16509472, -0.08809644,
+    0.070216, 0.14232644, 0.016041761, -0.057273045, 0.0075164586, -0.27125043, -0.03494701, -0.08299765, 0.113265894, -0.027382141, -0.021650344, 0.03709659, 0.027422264, 0.113292985, 0.13450672, -0.01401968,
+    -0.07930782, -0.039819296, -0.0017323446, 0.08559342, -0.108507514, 0.0520707, 0.13007046, 0.08102231, 0.10842326, 0.07965746, 0.07505066, -0.06592013, -0.12210012, 0.0782993, 0.047768664, -0.082694545,
+    -0.12609145, -0.05678134, 0.06789888, 0.054861303, -0.091887645, -0.10801132, -0.0015068925, -0.084419355, -0.04660976, -0.12334961, -0.026361503, 0.040620845, -0.01406513, -0.13787012, 0.06501258, -0.13213317,
+    0.014488841, -0.062825516, 0.10109674, 0.11490728, -0.068052, -0.13686736, 0.02948262, -0.13278943, 0.11645201, -0.023096232, -0.16291347, -0.028370226, 0.087611966, 0.14447272, -0.004790538, 0.13593175,
+    0.034466434, -0.00951031, 0.026357275, -0.005832363, -0.011580605, 0.013658647, 0.057275917, 0.15242483, -0.13645881, -0.06442626, -0.07046776, 0.12042265, 0.005581587, 0.056045312, 0.04226114, -0.033407137,
+    0.06519278, -0.037853755, -0.04604133, -0.032606762, 0.096294284, -0.0068176677, 0.093160115, -0.04768169, -0.06431932, 0.06804947, -0.18217944, -0.13764004, -0.015966605, -0.14359121, 0.124804504, -0.09478614,
+    -0.15588753, -0.003586083, 0.13205129, -0.0016791455, -0.09411616, -0.05511477, 0.1283478, 0.023432024, -0.08273655, 0.14146632, -0.0878034, -0.13162994, 0.14958519, 0.046821695, -0.10231479, 0.035051078,
+    0.12171806, -0.020622317, -0.13988931, -0.018689316, -0.01735114, -0.045942158, 0.0020730759, 0.08471306, 0.13740122, 0.06966103, -0.06140572, 0.06336426, -0.08948412, -0.01974271, 0.1378449, -0.099410936,
+    0.008962025, 0.047430027, 0.14914268, 0.11888873, -0.1290393, -0.057007633, 0.08832222, 0.023767821, -0.05267548, 0.015046841, -0.009378843, 0.14940953, -0.0053642276, -0.12644318, 0.116544865, -0.0071838875,
+    -0.05566025, 0.01402992, 0.09750005, 0.12441256, 0.15304884, 0.029263915, -0.044307385, 0.08514097, -0.1341769, -0.0927835, -0.042123668, 0.14345902, 0.028457472, -0.019971529, 0.09540991, -0.11692504,
+    -0.060385432, -0.06463069, 0.07644948, -0.1368393, 0.05051959, -0.11749661, 0.019075412, 0.08370242, -0.11181007, 0.13611439, 0.13189435, -0.002580557, 0.049550604, -0.019105164, 0.08840031, -0.112074375,
+    -0.13605657, -0.04524526, -0.0990653, 0.12585345, -0.05937603, 0.08714461, -0.12727615, 0.0821051, 0.10359766, 0.028390381, -0.056749053, 0.14217097, 0.075483374, 0.12363971, -0.011507278, 0.050378155,
+    -0.14068322, 0.1076954, -0.0887227, 0.1466957, -0.0872124, -0.10942225, 0.087064, -0.06471891, -0.16984506, 0.0123751545, -0.023829294, -0.036932483, 0.111988574, 0.08017615, -0.031656526, -0.091576025,
+    -0.036795426, 0.07056306, 0.12390327, -0.09132452, 0.016849982, 0.13036685, -0.031144528, 0.04310095, 0.008106077, 0.12597166, -0.0548686, 0.10364844, -0.13835214, -0.03985468, -0.06578369, -0.09597849,
+    -0.027252706, -0.025479369, 0.13536999, 0.12803969, 0.02030236, -0.032555938, -0.13804303, -0.022589838, -0.07461429, 0.14588627, -0.013646888, 0.13155961, 0.1245279, -0.13162623, -0.07443924, 0.08562127,
+    0.012855247, 0.016069204, -0.0017297281, -0.021918252, -0.01248073, 0.11543098, -0.10133862, -0.026473286, -0.104580894, -0.059960138, 0.0020311454, -0.028514838, -0.07246398, 0.015132786, 0.02386193, -0.057901956,
+    -0.15702894, -0.02302184, 0.016658587, -0.01991869, -0.13417232, 0.1236823, 0.07255919, 0.14102186, 0.06491302, -0.10638639, -0.097033836, 0.096824944, 0.0077878176, 0.10996739, -0.14331527, -0.039696116,
+];
+
+static TENSOR_3: [f32; 1] = [
+    -0.013128229,
+];
+
+static TENSOR_7: [f32; 128] = [
+    0.06423331, 0.22075674, 0.1813878, -0.04340886, -0.14563036, 0.10264212, 0.030373726, -0.18753734, 0.22610046, 0.034953803, -0.14931028, -0.10612659, -0.069393545, 0.07149593, -0.23956622, 0.1825982,
+    -0.014902349, 0.13529874, -0.04412322, -0.18707494, -0.14678119, -0.24778458, -0.11477004, -0.29638073, 0.021014672, 0.117458664, 0.25152534, -0.13124992, 0.03318173, 0.3218417, -0.1364175, 0.22739063,
+    0.051629715, -0.03504138, -0.0557373, -0.057516757, -0.058930214, 0.06508623, 0.18497688, 0.2481888, -0.24852021, 0.13788809, 0.16020997, -0.02250567, 0.02822078, 0.02355278, 0.123041645, 0.14771599,
+    0.03900957, 0.16275777, 0.13626261, -0.096228, 0.09825198, -0.023632377, 0.03416687, -0.15781291, -0.30365106, 0.14151816, -0.11145818, 0.15705502, -0.1696859, 0.073880024, -0.056243762, 0.12605914,
+    -0.13326144, -0.18156907, 0.12749784, -0.048747186, -0.16730577, 0.21755897, 0.07410552, 0.022910923, 0.10068473, -0.10510766, -0.059017714, 0.11777858, 0.20373762, 0.04811257, -0.060573194, 0.20925874,
+    -0.13160503, -0.12606259, 0.13788025, -0.20931138, -0.20515469, 0.1584071, -0.11508585, -9.996254e-5, -0.05898506, 0.0445138, -0.19159213, 0.2005407, 0.06627284, 0.02713937, 0.116788864, 0.23490301,
+    -0.038470186, -0.12816226, 0.22059634, 0.19637641, -0.14167073, 0.097931035, 0.25537774, 0.03395084, 0.07119525, -0.027607644, 0.0632814, -0.15737268, 0.10165766, -0.0044049765, -0.1005705, 0.14505288,
+    0.13342802, -0.0889878, -0.07839378, -0.10742236, -0.1605999, -0.17225027, 0.0924846, -0.25447837, -0.017811013, -0.046512764, 0.16144913, 0.1096879, 0.17997427, -0.06366489, 0.055437673, 0.123914056,
+];
+
+pub fn invoke(arena: &mut Arena) -> Result<()> {
+    let arena = arena.0.as_mut_ptr();
+    // 0: CONV_2D
+    let (input, output, scratch) = unsafe { (slice::<f32>(arena, 2560, 36), slice_mut::<f32>(arena, 0, 512), cells::<f32>(arena, 2708, 144)) };
+    OpConv2D::gemm_kernel(
+        input,
+        &TENSOR_2,
+        &TENSOR_1,
+        output,
+        scratch,
+        6,
+        6,
+        1,
+        3,
+        3,
+        4,
+        4,
+        32,
+        1,
+        1,
+        1,
+        1,
+        0,
+        0,
+        1,
+        0.0,
+        3.4028235e38,
+    )?;
+    // 1: MAX_POOL_2D
+    let (input, output) = unsafe { (slice::<f32>(arena, 0, 512), slice_mut::<f32>(arena, 2048, 128)) };
+    OpMaxPool2D::kernel(
+        input,
+        output,
+        4,
+        4,
+        32,
+        2,
+        2,
+        32,
+        2,
+        2,
+        2,
+        2,
+        0,
+        0,
+        1,
+        -3.4028235e38,
+        3.4028235e38,
+    )?;
+    // 2: RESHAPE
+    // the output shares the buffer of the input
+    // 3: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<f32>(arena, 2048, 128), slice_mut::<f32>(arena, 0, 128)) };
+    OpFullyConnected::kernel(
+        input,
+        Some(&TENSOR_4),
+        &TENSOR_6,
+        output,
+        1,
+        128,
+        128,
+        0.0,
+        3.4028235e38,
+    )?;
+    // 4: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<f32>(arena, 0, 128), slice_mut::<f32>(arena, 2704, 1)) };
+    OpFullyConnected::kernel(
+        input,
+        Some(&TENSOR_3),
+        &TENSOR_7,
+        output,
+        1,
+        1,
+        128,
+        -3.4028235e38,
+        3.4028235e38,
+    )?;
+    Ok(())
+}
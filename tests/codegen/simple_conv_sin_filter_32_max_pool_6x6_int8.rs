@@ -0,0 +1,491 @@
+// @generated by berrylite-codegen from simple_conv_sin_filter_32_max_pool_6x6_int8.tflite, do not edit
+
+use berrylite::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8;
+use berrylite::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8;
+use berrylite::kernel::micro_operator::i8::max_pool2d_i8::OpMaxPool2DInt8;
+use berrylite::kernel::utils::int4::FilterData;
+use berrylite::micro_errors::Result;
+use core::cell::Cell;
+
+pub const ARENA_SIZE: usize = 821;
+pub const INPUT_OFFSET: usize = 640;
+pub const INPUT_LEN: usize = 36;
+pub const INPUT_SCALE: f32 = 0.005396079;
+pub const INPUT_ZERO_POINT: i32 = -53;
+pub const OUTPUT_OFFSET: usize = 676;
+pub const OUTPUT_LEN: usize = 1;
+pub const OUTPUT_SCALE: f32 = 0.0076607694;
+pub const OUTPUT_ZERO_POINT: i32 = -3;
+
+/// The memory of the activations, which is laid out by the greedy memory planner
+#[repr(C, align(16))]
+pub struct Arena(pub [u8; ARENA_SIZE]);
+
+impl Arena {
+    pub const fn new() -> Self {
+        Self([0; ARENA_SIZE])
+    }
+
+    pub fn input(&mut self) -> &mut [i8] {
+        unsafe { slice_mut(self.0.as_mut_ptr(), INPUT_OFFSET, INPUT_LEN) }
+    }
+
+    pub fn output(&self) -> &[i8] {
+        unsafe { slice(self.0.as_ptr() as *mut u8, OUTPUT_OFFSET, OUTPUT_LEN) }
+    }
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the `len` elements of `E` at the byte `offset` of the arena.
+///
+/// # Safety
+///
+/// The elements must be in the arena and aligned for `E`, as the offsets of the memory plan are.
+/// The slice must not overlap a slice returned by `slice_mut` while both of them are alive,
+/// which holds for the tensors of an operator, as the memory plan keeps its inputs and outputs apart.
+unsafe fn slice<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a [E] {
+    core::slice::from_raw_parts(arena.add(offset) as *const E, len)
+}
+
+/// Returns the `len` mutable elements of `E` at the byte `offset` of the arena.
+///
+/// # Safety
+///
+/// As `slice`, and the slice must not overlap any other slice of the arena while it is alive.
+unsafe fn slice_mut<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a mut [E] {
+    core::slice::from_raw_parts_mut(arena.add(offset) as *mut E, len)
+}
+
+/// Returns the `len` elements of `E` at the byte `offset` of the arena as the scratch of a kernel.
+///
+/// # Safety
+///
+/// As `slice`, where the scratch follows all the tensors in the arena and overlaps none of them.
+unsafe fn cells<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a [Cell<E>] {
+    core::slice::from_raw_parts(arena.add(offset) as *const Cell<E>, len)
+}
+
+static OP0_MULTIPLIER: [i32; 32] = [
+    1823616653, 2006404306, 1385321717, 1762356238, 1593184226, 1345514197, 2026194502, 1791769614, 1111084094, 1786643974, 1983650928, 1702357957, 1984627338, 2020538320, 1630146985, 1694507920,
+    1932176702, 1877755861, 1106728087, 1589497737, 2109565876, 1613204924, 1755462261, 1940364923, 1302621803, 1848238585, 1927474636, 1838876994, 1176169974, 1367232670, 1415755783, 1467102487,
+];
+
+static OP0_SHIFT: [i32; 32] = [
+    -9, -9, -9, -9, -9, -9, -9, -9, -8, -9, -9, -9, -9, -9, -9, -9,
+    -9, -9, -8, -8, -9, -9, -9, -9, -8, -9, -9, -9, -9, -9, -9, -9,
+];
+
+static TENSOR_7: [i8; 288] = [
+    23, 96, 76, 127, 127, -31, 46, -69, 7, -127, -62, -120, 96, 99, 65, 71,
+    116, 91, 18, -121, 127, -70, -28, 35, 84, -10, 28, -32, 57, -67, -127, 101,
+    125, -93, 12, -92, 126, -127, -38, -106, 121, -36, -70, -65, -14, 7, -67, -104,
+    127, -86, -70, -122, -113, -124, -127, -62, -40, -99, 11, -117, 57, -83, -23, 83,
+    -84, -7, 2, -127, -60, -22, 110, -107, 59, 4, -19, 12, -12, -78, 127, -7,
+    -28, -64, 93, 33, -28, 89, 35, 127, -124, 3, 108, -76, 67, -18, -100, 4,
+    60, 127, -16, -5, -123, -35, -127, 81, 98, 118, 5, 110, 68, -12, 99, 10,
+    -38, 56, -73, 127, -78, 90, 50, 45, 127, 112, -65, -26, -67, -13, -37, 70,
+    39, -73, -127, 45, -11, 1, -108, -127, 31, -77, -75, 86, -75, -102, -122, 95,
+    74, -10, 2, 47, 37, -114, -99, -1, -127, 46, 19, 31, 4, 127, 33, 52,
+    -126, -20, 122, 98, 96, 92, 104, 86, 125, -19, 127, -12, -127, -37, -105, -78,
+    -73, -28, 10, -5, -54, 63, -127, 10, 54, 35, -20, 47, -14, -2, 55, -46,
+    -127, -44, -3, -105, -11, 11, 43, 127, -14, -54, 118, 55, 12, -89, -10, -104,
+    62, -36, -22, -5, 127, 26, -26, 92, 13, 114, 94, 80, 15, 46, 43, 127,
+    -3, 76, 87, 64, 127, -92, 12, 70, -67, 115, 11, -110, -51, 88, -127, -43,
+    -53, 36, -112, 6, -88, 62, -95, 126, 21, 127, -79, -113, 48, -91, 69, 125,
+    -49, 28, 106, -127, -110, 127, -35, 71, 31, -10, 116, -46, 48, 108, 67, 88,
+    -114, 70, 1, -71, 38, 127, 105, -103, -11, 120, -121, -7, -69, 127, -91, 5,
+];
+
+static OP0_EFFECTIVE_BIAS: [i32; 32] = [
+    -6367, 33297, 35725, 16461, 5288, -22695, -12592, 8583, 16371, 28859, 37720, 28968, 38086, 8213, -16090, 2373,
+    -18368, 23145, 21240, -38484, -3152, -17863, 45541, 30210, 7675, 38714, -1394, 30232, -4112, 14217, 34382, 32369,
+];
+
+static TENSOR_5: [i8; 4096] = [
+    -63, 35, 50, -1, -59, 27, -13, -43, 44, 45, -3, 41, -10, -3, 1, -52,
+    -77, -29, -50, -75, -21, 35, -24, 38, 2, -46, -34, 79, -3, 9, -9, 51,
+    3, 31, 83, 33, -36, -18, -43, -22, 15, 41, 53, 6, -11, -35, -31, 7,
+    -90, 30, -23, -72, 42, -13, 59, 55, -60, -7, -61, -6, 21, -53, 19, 94,
+    -40, -13, 75, 65, -54, -24, -69, -34, -13, -6, 38, 26, -27, 1, 38, 5,
+    -97, 42, -5, -61, 3, 56, 53, 14, -38, 10, -64, 10, -11, 4, 1, 44,
+    -64, -2, 69, -6, -39, 4, -11, -52, 0, 5, 49, -24, 40, -4, 26, 15,
+    -84, 44, -45, -44, -19, 32, 32, -34, -22, -7, -63, 66, 43, -83, -37, 76,
+    14, 33, 14, 29, 33, 45, 47, -8, 28, -51, -24, -8, -2, -3, -27, -45,
+    -5, -9, 38, 33, 10, -17, 24, -6, -38, -3, -21, 42, 20, 34, 11, 40,
+    -12, 27, -38, -39, -41, -13, -35, 40, -43, 3, -19, 37, -8, 36, -39, 1,
+    34, -23, 7, -25, 22, -18, -2, -7, -4, 22, -11, 5, 26, -3, -5, 9,
+    -25, -52, 6, 22, -21, 1, 16, 5, 1, 13, -16, 41, 2, 0, 19, -41,
+    -32, -45, -30, -17, 18, -30, 1, -12, 29, 29, -6, 38, 43, -5, -12, -29,
+    7, -9, 0, 5, 43, -18, -14, -32, 47, -39, -48, 14, 2, -34, -19, 27,
+    -19, -50, -13, -5, 19, 20, -20, 23, -26, 19, 14, -7, -30, 29, 24, -45,
+    -14, -47, 40, -25, 2, 32, -24, -31, -10, -70, 31, 17, 89, 18, 13, 46,
+    4, -63, -19, -3, -21, 40, 36, 87, 3, -60, 36, 28, -38, 47, -73, 18,
+    -39, -9, 25, -2, 15, -2, -9, 52, 0, -54, 33, 37, 70, -27, -20, 44,
+    34, -38, -32, 6, -47, -28, 105, 105, -61, -86, 22, 1, 1, -25, -62, 12,
+    -17, -35, -4, 33, 11, 34, 20, 50, -3, -31, 59, 34, 112, -38, -9, 11,
+    -8, -11, -86, -52, -2, 24, 114, 67, -83, -38, 39, -7, 43, -31, -90, 9,
+    -56, -13, 39, 33, -13, 4, 21, 44, 45, -78, 79, 63, 40, -13, -17, -41,
+    -9, -14, -14, -77, -32, -8, 85, 122, -56, -57, 26, -13, -41, -17, -18, 45,
+    -35, -22, 35, 29, -28, -19, -53, -44, 29, 18, 54, 13, 19, -11, 5, -54,
+    26, 25, -48, -40, 7, 14, 59, 48, -64, 26, -5, 13, 11, 7, 44, 67,
+    -73, -32, 33, 29, -68, -6, 14, -60, 59, 44, -36, 47, -15, 27, -35, -17,
+    -26, 38, -54, 1, -30, 18, 54, 16, -33, -15, 20, 70, 15, -6, 0, 6,
+    -72, -6, 14, 67, -21, -54, 22, -56, 73, -4, 57, 34, 28, -20, -25, 1,
+    -5, 25, -43, 3, -14, 35, 30, 16, -34, -14, -47, 20, -7, -43, 31, 55,
+    -40, 31, 66, 79, 13, -41, -18, -48, -21, -34, 34, -28, 12, 7, -24, -3,
+    24, 10, -37, 20, 34, -10, 5, 17, -41, 44, -14, 72, -3, -35, 38, 100,
+    -62, -38, 32, 48, -52, -31, -30, 34, 1, 40, 15, 51, -36, -9, -37, 14,
+    -68, -43, -40, -21, -37, 27, 39, -18, -52, 46, 8, 65, -28, -28, -12, 25,
+    26, -26, 73, -10, -53, -16, -16, 31, 32, 20, 10, 15, 43, -30, 32, 33,
+    -65, -28, -12, 16, 33, 21, -34, -10, 18, -3, 27, 67, 39, -12, 33, 16,
+    -18, -15, 41, 17, -58, -24, -16, -53, 2, 47, 47, 30, -32, -52, -36, -33,
+    1, 41, 13, -41, 15, 2, -15, 7, -57, 0, 31, 67, -21, -6, 19, 12,
+    -44, 7, -5, 19, -63, -48, -14, -25, 51, 2, -10, 47, 4, 10, -22, -49,
+    -56, 40, -24, -48, 52, 18, -12, 45, -5, 34, -38, -1, -32, -79, -3, 86,
+    8, -2, 46, 43, 15, -27, -38, -5, 98, -6, 5, -2, 5, -38, -57, 39,
+    -37, -30, -68, -9, -24, -52, 79, 65, 0, -12, 25, 40, 14, -75, -17, 50,
+    -3, -19, 53, 46, -2, 9, 0, -17, 77, 3, 57, 70, 45, -57, -1, 21,
+    15, 0, -37, -1, -34, -50, 49, 36, -8, -62, 53, -8, -20, -52, -72, 38,
+    27, 3, -4, -24, 19, -53, -15, -6, 6, -19, 81, 70, 61, -9, -24, -12,
+    4, 6, -47, 4, -27, -45, 68, 37, -52, -20, -1, 3, 0, -19, -45, 55,
+    -36, -37, 38, -22, -19, 6, -22, 5, 98, -2, -4, 31, 3, -8, -42, 36,
+    -16, -9, -31, -70, 40, -42, 58, 68, -40, -5, -22, 73, -40, -52, -51, 40,
+    -30, -9, 16, 14, -9, 9, -29, -14, 39, 8, -33, 36, -20, -13, -21, -2,
+    -16, -16, 31, -44, 22, -25, 26, -19, 44, -49, -7, 17, 24, -15, -48, 6,
+    -20, 26, 45, -5, -32, 32, 38, 40, -17, 42, 18, 11, -41, -35, -17, -37,
+    4, -27, 12, -36, -50, 43, 5, 35, -28, -44, -27, 24, -20, 10, 20, -37,
+    -6, -38, 22, -46, 42, -33, -13, -2, -37, 36, 40, -41, -15, -18, 37, 34,
+    41, 29, 39, -5, 15, -42, -7, 3, -29, 10, 8, -22, 37, 15, 26, -23,
+    -26, 7, 24, 1, 40, 28, 32, -24, -13, -44, -26, 44, -34, -27, -16, -36,
+    -26, -20, -34, 45, 29, -43, -16, 38, -44, 18, -45, -29, 5, 2, -7, 17,
+    9, 49, -36, 41, 40, 12, 14, -52, -29, -35, -27, 12, -38, -23, -15, -51,
+    42, -21, 24, -12, 42, 19, 42, 28, -14, 11, 36, 32, 42, -4, 47, -19,
+    16, 44, 17, 40, 27, -22, -41, -15, -23, -4, 27, 35, 41, -21, -47, -36,
+    38, 18, -1, 27, 22, -19, -11, 5, -34, -19, -49, 13, -27, -18, 14, 52,
+    26, -13, -14, 22, -22, 43, -25, -20, -28, 31, 8, 9, -12, -1, -22, 1,
+    -17, 22, -36, -41, 41, -43, 48, -18, 2, 25, 33, -31, 45, 12, -5, -26,
+    21, 19, 39, 33, 3, 35, -29, -12, -10, 9, -6, -8, 40, -50, 3, 40,
+    32, 15, -22, 31, -42, 20, -38, 25, 4, 29, -53, 49, 33, -26, -4, -35,
+    -25, 40, 23, -31, -6, 31, -20, -36, 15, 3, 17, 25, 6, -18, 23, 6,
+    9, 26, -25, -43, 19, 9, 2, -44, -4, -26, 28, 35, -34, 25, 33, 33,
+    23, 2, -28, 39, 31, 35, 4, -3, 40, 41, -9, 14, 31, -23, -16, 38,
+    8, 3, -30, 4, -47, 40, 14, -33, 12, -13, 12, -18, 23, 35, -2, -44,
+    0, -29, -5, -28, 26, 15, -25, -11, -47, -44, -6, 22, -25, 21, -42, -23,
+    12, 5, -33, -29, -27, -12, 45, -1, 1, 45, -46, 16, -48, -25, -25, 37,
+    41, 35, 18, -40, 5, -42, -47, 17, -51, -44, -26, 36, 18, 25, -9, 29,
+    14, -39, 0, -26, 21, -33, -23, -31, -15, -26, -3, -33, -41, -30, -37, -21,
+    -8, -20, -35, 38, -16, -50, 14, 4, -41, 48, -27, 21, 41, 14, -26, -43,
+    -29, 38, 14, -22, -9, -45, 49, 21, -55, 24, -41, 41, 36, -3, 26, 47,
+    18, -38, -16, -16, -29, -2, 32, 33, -2, -21, -4, 45, 22, -13, 35, 4,
+    -49, -41, -22, 6, 22, 31, 52, 17, 6, 37, -8, 33, -24, -8, 27, -16,
+    -51, 39, 13, 36, -54, -19, -31, 10, -34, -11, 41, 11, -28, -40, -32, 6,
+    -32, 16, 2, -34, -31, 19, -33, 54, 16, 45, 9, -40, -49, -22, 13, 27,
+    -38, 15, -54, -48, -7, -6, -22, -9, 26, 51, -19, -2, 32, -35, 38, 26,
+    11, -14, -28, -33, 16, 31, 35, 24, 10, 19, 24, -13, 14, -31, -13, 17,
+    -54, 14, 53, -19, -20, 42, 37, 26, 50, -76, 58, 80, 63, -58, 11, 23,
+    -41, -55, -57, -75, 26, 33, 70, 98, -95, -21, 45, 35, -46, 14, -66, 8,
+    -45, -78, 51, 19, 11, -41, -15, -24, 27, -45, 54, 64, 47, -58, -50, -21,
+    -2, -1, -80, -12, -41, -14, 40, 80, -33, -43, -41, 36, -25, -52, -95, -1,
+    -65, -24, 52, 4, -39, -1, 38, -8, -17, -18, 45, 25, 61, -72, -52, -31,
+    -70, -27, -84, -61, -27, 3, 37, 65, -68, -58, 32, -3, -46, -12, -90, 56,
+    -31, -40, -17, 6, -39, -33, 35, 28, 26, -11, 45, 29, 84, -99, -37, 31,
+    -19, -54, -89, -58, -35, 21, 65, 116, -91, -11, 26, -5, 25, 3, -15, 66,
+    39, 31, -11, 30, -43, -45, 2, 2, 13, 40, -48, -14, -9, 23, 9, -42,
+    40, -15, 22, 35, 18, 14, -16, -25, 30, 13, 7, -25, 35, 18, -41, 27,
+    31, -9, -31, -39, 36, 14, -31, -42, 26, -25, -11, -39, -13, -37, 15, -28,
+    20, 15, -11, 39, -1, 5, 17, 12, -14, -36, -47, 29, 5, 30, -17, 23,
+    -18, -44, 24, 7, -39, 26, -37, -2, 47, 7, -15, -6, 23, 5, -9, -8,
+    17, 0, -35, -44, -39, 10, 16, 21, 10, 9, -27, -3, -45, 23, 33, 22,
+    35, 12, -12, -41, -25, 41, -8, -32, -11, 7, 33, 40, -38, 23, -15, -19,
+    18, -26, 5, 4, -37, -17, -35, -29, -47, -19, 38, 46, 21, 36, 2, -15,
+    32, -35, -1, 40, 47, -24, 45, 13, -34, 33, -20, 19, -38, 21, 21, 13,
+    -24, -23, -8, -24, -28, -33, 26, -20, -27, -8, 33, -23, -3, 9, 26, -28,
+    35, 24, 23, -23, 15, 25, 23, 7, 12, -11, -18, -39, -37, -40, -15, -3,
+    -24, -41, -31, -74, 3, -8, 39, 15, 22, 20, -5, -16, -17, 15, 40, 59,
+    26, 16, 13, 46, -19, -31, -34, -37, 47, -10, -27, -30, 32, 14, 16, 10,
+    6, 34, -23, -64, 29, -37, 62, 14, -25, -5, 10, 17, 19, -39, 24, 51,
+    0, -22, -34, 15, 22, 4, 19, -32, 16, 27, 32, 44, 27, -46, 11, 15,
+    23, 19, -52, -25, 40, 22, 52, 28, -34, -44, 47, 0, 31, -43, -12, -23,
+    43, -20, -19, 4, -32, -2, 44, 1, -38, 5, -103, -27, -51, 20, 1, 26,
+    -7, 43, -38, 12, -27, -30, -104, -40, 11, -43, 39, 27, -31, -17, -35, -15,
+    37, -44, -50, 24, 42, 41, 45, 6, -2, 21, -104, -57, -28, -15, -15, -4,
+    19, -14, 45, 35, 40, 27, -31, -123, 12, 38, 8, -25, 5, -21, 7, -14,
+    37, 39, 36, -34, -25, -13, -20, 0, -50, 39, -67, -58, -50, -35, 6, -20,
+    6, -20, -21, 13, -33, 44, -70, -76, -9, 13, 6, -29, 14, -14, 36, 35,
+    -40, -9, 6, 30, -17, 26, 6, 23, -11, 67, -111, -87, -108, 28, -41, 6,
+    -24, -12, -11, 57, -2, 24, -99, -86, -24, -38, 31, -49, 31, -41, 24, 10,
+    -6, -23, 16, 25, 11, -4, -31, -7, 0, -39, 40, 43, -41, 19, -26, 8,
+    38, 47, 6, -45, 13, 31, 44, 48, 15, 39, -19, 16, 9, 11, 36, 43,
+    34, 32, 43, -35, -4, -38, -2, -19, -39, 15, 15, -8, 5, -10, 23, 13,
+    16, 44, -5, -52, -30, -9, 21, 5, -9, 38, -50, 10, -23, -64, -3, 13,
+    -48, -5, 36, -11, -31, 33, -2, -42, 15, 41, -31, 5, -8, -36, 7, 14,
+    -49, -22, 42, -22, -16, -40, 32, 10, -8, 13, -20, -42, 42, 12, -23, 44,
+    -20, -5, -27, -38, -40, 11, 27, -1, 10, -18, -22, 16, 31, -1, -22, 10,
+    28, -41, 25, -41, -25, 21, 22, -12, -53, -23, 13, -34, 43, 17, -41, 14,
+    19, -65, 38, -30, -41, 23, -42, 44, -19, -1, 112, 90, 86, -33, 1, 1,
+    -30, -48, -81, -63, -5, 27, 41, 42, -22, -54, -18, 9, -37, -17, -40, 11,
+    -58, -42, 48, -11, 27, -37, 7, 49, -23, -71, 81, 85, 60, -13, -44, 17,
+    -44, -39, -15, -34, 5, -15, 75, 50, -64, -76, 51, 2, 16, -21, -73, 59,
+    -27, -56, -10, 40, -12, 34, 26, 16, -3, -61, 58, 22, 64, -7, -7, 40,
+    40, -48, -4, -58, -20, -33, 105, 44, -8, -74, 47, 26, -40, -25, -19, 42,
+    -31, -7, 18, -28, 51, 38, 46, 39, -14, -19, 103, 61, 89, -2, 4, -22,
+    -30, -47, -56, -51, 43, 46, 42, 47, -27, -3, 18, 47, 17, 4, -63, -4,
+    -40, -47, -7, 34, 25, -20, 21, 18, 5, 18, -26, 1, 15, -32, 21, 27,
+    -12, -15, -41, -32, -42, 12, -35, -14, -30, -47, 33, -41, -13, 12, 29, 21,
+    37, -15, 44, -36, 43, -18, -22, 11, 1, 7, 35, -25, 8, 39, 6, -44,
+    -2, 31, 40, -15, -47, 10, 20, 42, -31, 44, -26, 43, -20, -22, -5, -14,
+    -2, 10, -20, 22, 18, -7, -18, -50, 35, 37, 6, 12, -43, 0, 34, 2,
+    -12, -8, 3, 45, 11, 17, -44, -15, 21, -1, -25, 27, 40, -40, -35, -42,
+    -44, 38, -32, -9, 8, -35, -47, -13, 38, -22, -13, -19, 45, 26, 30, -32,
+    13, -33, 43, 20, -41, -15, 12, -44, 7, 3, 24, -28, 36, 43, -22, -14,
+    -12, 44, 50, -6, -50, 35, 5, 8, 45, -30, 51, -10, 50, 31, -21, -20,
+    -25, 10, -30, 19, 46, 38, 13, -14, -7, 18, -45, -14, 31, -16, -21, 17,
+    -75, 37, 30, 17, 18, -7, -46, 20, -6, 15, 63, 27, 11, -34, 21, -35,
+    21, 27, -41, -28, -37, -44, 14, 35, -60, 44, -42, -34, 30, 2, -21, -36,
+    -45, 51, 92, -24, 10, 11, 12, -45, 33, 48, -25, -15, 16, -27, 16, 6,
+    -11, -6, -59, -37, -14, 36, 20, 35, -22, -38, 5, 47, 38, 4, -42, 21,
+    -72, 17, 61, -40, 26, 36, -19, -4, 2, 49, -17, 43, 10, 19, -13, -11,
+    -2, -23, -12, 18, -26, 37, -4, -16, -59, 46, 18, 47, -6, 8, 44, 44,
+    -62, 37, 47, -46, -3, -50, -19, -47, 49, -7, 2, 8, 51, 14, 38, -37,
+    22, -23, -8, -4, -14, 8, 17, 35, -58, -20, -13, -24, 14, 8, -13, 31,
+    -35, -9, 45, 38, -26, -11, -49, 15, 32, -6, 58, 61, 54, -44, -37, -11,
+    38, 47, -62, -43, 6, -37, 71, 14, -47, 34, -58, 6, 5, 27, -14, -16,
+    -67, 15, 7, 8, -15, -10, -46, -32, -40, 60, 11, 67, 42, -63, 13, 3,
+    27, -1, -37, 2, -45, -46, 50, 53, -54, -21, -35, 25, 30, -5, -15, 13,
+    -55, 1, 21, -9, 37, 30, 27, -8, 38, 48, 19, 17, 58, -50, 38, 28,
+    -15, 48, -55, -52, 5, 43, 15, 64, -13, -26, 30, 54, -3, -14, -15, -3,
+    44, -40, -4, -117, -52, -20, -41, 1, -12, 41, -22, -35, 33, 74, -43, -18,
+    28, 27, 49, 2, 25, 31, -44, 6, 38, -34, -9, -26, 23, 69, 46, -8,
+    -6, 38, -47, -121, 1, -43, -36, 4, -29, 30, 26, 22, -9, -14, -12, 8,
+    -23, 10, 28, 26, -23, -34, -36, -14, -11, 44, 3, -8, -35, 75, -12, -73,
+    57, 6, -11, -97, -10, -14, -26, -14, -5, 28, 3, -49, 24, 37, 10, 46,
+    31, 11, 47, -40, -20, 5, -33, -28, 38, 6, -12, -57, -12, 93, 42, -87,
+    -16, 24, 28, -66, -14, -38, -21, 0, -48, -42, 32, 0, -33, 27, 5, -32,
+    -22, -18, -3, -11, 37, 35, -26, -45, 37, 35, -6, 5, 19, 90, -43, -21,
+    27, -35, 31, -27, -1, -50, 20, -27, 42, -39, 1, 48, -3, 9, 37, 38,
+    -18, 37, 4, -28, -18, 39, 25, -20, 5, -43, -1, -6, 34, -49, 39, 0,
+    -29, 27, -31, 5, 24, -9, -14, -26, -48, -11, -29, -7, -41, 24, 29, 19,
+    35, -3, 11, -2, -26, -47, 44, 13, -38, 34, 1, -33, 46, 19, 39, 6,
+    36, -21, -26, -17, -9, -34, -24, -1, -3, 17, 34, 24, 31, -30, -7, -14,
+    9, -6, -9, 21, 46, -50, -34, 44, -7, 25, -5, -9, -22, -59, -17, -35,
+    -49, -43, -45, -25, -17, 26, 17, 35, 1, 42, 25, 22, 22, -2, 28, -5,
+    5, -24, -19, -25, -19, 34, 41, 4, 1, 44, -2, -36, 2, -54, -6, -17,
+    18, 33, -34, -42, 26, 35, -29, 11, -34, 7, 34, -10, 26, 8, -27, -50,
+    -11, -12, -51, -3, 1, -32, -39, -24, -43, 39, 14, -32, 34, -55, -11, 17,
+    -4, 19, -8, -5, -31, -25, 32, 21, -6, 42, 38, -6, -37, -2, -20, 14,
+    -32, 24, -42, -21, 41, -25, 45, 44, -22, 36, 15, 13, -2, -7, 20, -43,
+    14, 35, -52, 4, 11, 7, 28, -49, 35, 38, 31, 36, 34, -1, 38, -45,
+    38, 32, 4, -18, 17, 27, 48, -11, -40, -28, 1, -46, 26, -30, -33, 38,
+    5, 39, -3, -47, 3, 7, 31, 23, 33, -17, 47, 36, 9, -26, -15, -20,
+    -48, 29, 8, 37, -4, -6, 11, 37, -24, -39, -28, -12, -43, -65, 28, -14,
+    15, -31, 23, -38, -3, -33, 11, 13, -16, -48, 63, 90, 74, 43, -10, -12,
+    22, -79, -40, -58, -40, -3, 53, 30, -52, -80, -22, -26, 19, -1, -59, -4,
+    -44, -49, 49, 41, -6, -19, 38, 32, 4, -67, 15, 50, 84, 38, -6, 49,
+    -39, -3, -54, -38, 6, -44, 81, 42, -56, -28, 16, -7, 11, 15, -55, 10,
+    -52, -56, 54, 29, 11, -32, -14, 4, 10, -14, 106, 34, 42, -22, -12, -14,
+    -48, -54, -47, -27, 36, 4, 94, 70, -47, -38, 15, 32, 42, 27, -24, 45,
+    -6, -78, 39, 32, 29, 46, -9, -2, -26, -21, 73, 92, 52, -34, 10, 29,
+    28, -6, -97, -84, 38, 46, 85, 63, -20, -65, 26, 58, -5, -44, -84, 57,
+    22, -30, 58, 56, 37, -20, -28, 36, 35, -20, 59, 71, 48, 38, -41, -36,
+    -28, 5, -81, -46, -17, 40, 70, 15, -78, -70, -28, 61, 15, -30, -30, 29,
+    12, -56, 15, 10, -39, 24, -32, 30, 40, -54, 83, 88, 43, 22, 4, 46,
+    -47, -49, -31, -8, 32, 20, 71, 68, -2, -49, 23, 42, -32, 36, -40, 51,
+    -40, -84, 65, 14, 32, 19, -39, 53, 15, -32, 33, 67, 21, -17, -35, -37,
+    -9, -72, -72, -90, -17, -17, 16, 67, -44, -60, 48, 65, 0, -30, -59, -30,
+    -68, -31, 25, 10, -26, 39, -10, 5, 49, -22, 57, 67, 90, -12, 2, -23,
+    14, 1, -44, 0, -43, 16, 80, 93, -16, -16, -35, 57, 3, 46, -24, 35,
+    32, -4, -3, -15, 20, 30, -27, 43, -25, 23, -7, -12, 37, -34, -26, -47,
+    -11, -18, -44, 6, -6, -14, 7, -42, -41, 46, 15, 37, 7, 2, -9, 42,
+    -2, 31, -8, 6, 19, -22, -28, -9, 39, -36, 16, -28, -42, 22, 47, -18,
+    20, -48, -36, -16, 40, 26, 27, 31, 42, -35, -40, 23, -41, -33, -13, -34,
+    -23, -6, 16, 8, -27, -15, -12, 22, -9, -9, -43, 37, -46, -45, 33, -39,
+    -19, -34, -31, -34, 12, 11, 20, -44, -37, -21, -6, -16, 16, -19, 13, 4,
+    -27, -30, -11, -21, 38, 37, 18, -4, -8, 5, -40, -34, 41, 27, -47, 9,
+    -19, -36, 44, 2, -25, 27, 22, -38, 20, 42, -32, 36, 44, -27, 30, -9,
+    -57, -15, 75, 29, -18, 33, -59, -3, 41, 37, 36, -2, 18, -52, -4, -11,
+    -32, -3, -5, -36, -10, 12, 54, 47, -71, -10, -65, 84, -34, -60, -29, 78,
+    -30, 38, 27, 32, -33, -32, -6, -6, 8, -4, 58, -19, 58, 3, -42, 4,
+    -51, 3, -25, 16, -15, 20, 36, 7, 2, -46, -56, 22, -28, -3, 3, 79,
+    -43, 45, 46, 41, -23, -54, -37, -10, -23, -39, -19, -25, -14, -46, -38, -34,
+    8, -10, -26, -28, 25, 38, 35, 25, -37, -19, -19, 37, 38, 7, -4, 48,
+    -45, -36, 10, 72, -21, -8, 11, -16, 63, 44, 21, 6, 28, -13, -14, -45,
+    23, -16, -6, -11, 28, -13, 56, 22, -60, 21, 3, 66, 2, 13, -23, 9,
+    27, -25, -27, 37, -18, -33, -36, -8, 13, -21, 24, -35, -24, 30, 39, 34,
+    14, -14, -47, 43, -2, 32, 15, -21, -2, -18, 35, -1, 43, 0, -16, -14,
+    12, -1, 27, 15, 35, -23, -3, 8, 1, 23, 1, -32, 24, 7, -20, 11,
+    20, 20, 21, -36, 14, -31, 2, -13, 36, 6, -17, 20, 16, -44, -47, -36,
+    33, -47, 21, -21, 46, 5, -46, -8, 22, -41, 19, -33, -27, -1, -41, -22,
+    -43, -12, -14, 29, -27, 39, 46, 8, -28, -30, 14, 12, 18, -39, -25, -25,
+    -15, 8, 4, 5, -29, 41, 3, -21, -36, 28, 14, 24, 6, 9, -20, -42,
+    16, -40, 1, -21, 12, -9, -22, -15, -17, -45, -35, -45, -41, -39, -41, -20,
+    -7, 11, 34, 16, -3, -38, 35, -32, 39, -21, 29, -17, 17, 24, 18, -44,
+    7, -35, -30, 9, -1, -1, 13, 41, -39, -52, 32, 21, 27, -3, -36, -44,
+    47, -3, 15, 5, -23, 21, 25, -15, -47, -22, -1, -29, 9, 26, 40, -27,
+    4, -37, -44, 31, -7, 2, -17, -16, -47, 32, -46, 4, -45, 46, -1, -37,
+    -12, 37, -47, 3, -38, 31, 23, -28, -24, 16, 7, -2, 37, 38, 4, 26,
+    -32, 6, 27, -33, -39, -7, 20, -45, 7, -48, 9, 6, -23, 39, 6, 44,
+    32, -15, 37, -38, -38, 3, -22, 16, 23, -9, 37, -48, -20, 28, -19, -13,
+    38, -22, 12, -6, -18, 5, 5, 41, 6, 30, -39, -42, 44, 44, 36, -13,
+    27, -36, 40, 1, 18, -31, -23, 18, -44, 13, -5, 8, -34, -41, -13, 11,
+    -32, 9, 11, -3, 42, -37, -51, -26, 35, -20, -39, 45, 31, -3, -34, 3,
+    22, -22, 4, 19, -19, -1, -3, -6, -18, -2, 41, 11, 6, -1, 17, -14,
+    -31, 15, 0, -15, -18, 23, 41, -29, -23, -1, 21, -4, 26, -27, 2, -5,
+    -50, -29, 33, 0, 28, 22, -12, 12, -7, -33, 35, 29, -44, -32, 4, -31,
+    -3, -10, -27, 34, -28, 47, -40, -29, 23, 12, -7, 23, -16, 13, -28, -11,
+    -22, -16, -25, -27, 15, 30, -38, -29, 4, 41, 23, 3, -8, -4, -4, 3,
+    18, -45, 36, -3, 10, -24, -37, -35, 7, 31, -45, 19, 27, 48, 8, 22,
+    -43, 33, -10, -1, 85, 34, -41, 27, -36, -44, -46, 14, 22, 60, -39, 21,
+    -27, 37, -20, -36, 11, -30, -44, 43, 37, 8, -51, -38, -10, 37, -7, -52,
+    10, -33, 46, -2, 12, 20, -4, 43, 41, -22, -22, -9, -13, 9, 39, 5,
+    39, -17, 12, 45, 26, -18, 46, -46, -22, 16, -127, 10, 2, 64, -22, -22,
+    21, 1, -17, -21, 17, -19, -24, 47, 17, 32, -39, -13, 24, 7, 17, 26,
+    -3, 12, 43, 0, 29, 28, -6, 25, -33, 43, -81, 8, -35, 64, -29, -11,
+    4, 3, 22, -26, 63, 0, -9, 85, -15, -41, 41, 44, 24, 56, 6, 4,
+    -33, 44, 14, -26, -31, 31, 38, 8, 23, -37, -105, -47, -42, 30, -45, 6,
+    -16, -5, 31, -29, 9, 2, -45, -27, 13, -7, 7, 30, -11, -29, 33, -30,
+    44, -38, -20, 30, -25, -40, 34, -38, 32, 31, -27, 41, 47, 36, -37, -14,
+    -10, -26, 21, 33, 41, 8, -13, -4, -40, -32, -48, 10, 12, 46, -28, -19,
+    11, -17, -42, -12, 19, 20, 31, -40, -24, 20, 8, -30, 47, 11, -40, 42,
+    8, -17, 17, -42, -38, 46, -5, -40, 20, 1, -12, 6, -7, 37, -41, 17,
+    19, 19, 5, 27, -17, -19, 0, -27, -33, 11, 25, -45, -27, -45, -3, -13,
+    26, 3, 24, -17, -12, 41, -16, -45, -4, -9, -37, 7, 19, -36, -42, 26,
+    -47, -34, 4, -24, 31, 0, -28, 35, 16, 41, 27, 30, 33, 37, 6, 34,
+    -34, -16, 35, -48, 10, -16, 4, 21, 4, 41, -8, -6, -5, 23, 11, 31,
+    6, -10, -34, 42, -36, -31, 4, -45, -37, 20, -32, -34, 18, -30, 10, -10,
+    9, 30, -6, -32, 45, 28, -37, -23, 47, 32, -32, 32, 22, -43, -42, -31,
+    18, 23, -42, 26, -42, -2, 43, -41, 41, 19, -36, 47, 41, -42, 42, 16,
+    -3, -38, -34, 34, -24, -36, -37, 35, 5, -36, 40, -3, -28, -31, 11, -43,
+    -8, -19, -20, -9, -36, 28, -12, 18, -21, -4, 23, -28, 6, 5, 3, -47,
+    -1, -5, 23, 30, 0, -1, -21, -24, 39, -45, 36, -7, -48, 24, -36, 3,
+    -23, -33, -3, -34, 42, 46, -39, -46, 18, 45, 13, -14, 6, 0, 30, 19,
+];
+
+static OP3_EFFECTIVE_BIAS: [i32; 32] = [
+    -39292, -15823, 42526, 54781, -17052, 21533, -41321, 49294, -48015, -3165, -94353, -17408, 29959, -165101, -1721, 37647,
+    -24419, 49682, 29837, -57113, -13914, 2987, 36627, 68197, -57216, 10011, -71680, -14703, -38169, 27087, -19650, -46097,
+];
+
+static OP3_MULTIPLIER: [i32; 32] = [
+    1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673,
+    1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673, 1811374673,
+];
+
+static OP3_SHIFT: [i32; 32] = [
+    -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+    -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+];
+
+static TENSOR_3: [i8; 32] = [
+    81, -84, -27, 106, 28, -88, -23, 30, 79, 81, -39, -64, -87, 93, 19, -79,
+    53, 119, 62, -16, 4, 23, -104, -49, -42, 127, -4, -41, -64, -44, -10, -44,
+];
+
+static OP4_EFFECTIVE_BIAS: [i32; 1] = [
+    -2835,
+];
+
+static OP4_MULTIPLIER: [i32; 1] = [
+    1324642537,
+];
+
+static OP4_SHIFT: [i32; 1] = [
+    -8,
+];
+
+pub fn invoke(arena: &mut Arena) -> Result<()> {
+    let arena = arena.0.as_mut_ptr();
+    // 0: CONV_2D
+    let (input, output, scratch) = unsafe { (slice::<i8>(arena, 640, 36), slice_mut::<i8>(arena, 0, 512), cells::<i8>(arena, 677, 144)) };
+    OpConv2DInt8::gemm_kernel(
+        input,
+        &TENSOR_7,
+        &OP0_EFFECTIVE_BIAS,
+        output,
+        scratch,
+        6,
+        6,
+        1,
+        3,
+        3,
+        4,
+        4,
+        32,
+        1,
+        1,
+        1,
+        1,
+        0,
+        0,
+        53,
+        0,
+        -128,
+        &OP0_MULTIPLIER,
+        &OP0_SHIFT,
+        1,
+        -128,
+        127,
+    )?;
+    // 1: MAX_POOL_2D
+    let (input, output) = unsafe { (slice::<i8>(arena, 0, 512), slice_mut::<i8>(arena, 512, 128)) };
+    OpMaxPool2DInt8::kernel(
+        input,
+        output,
+        4,
+        4,
+        32,
+        2,
+        2,
+        32,
+        2,
+        2,
+        2,
+        2,
+        0,
+        0,
+        1,
+        -128,
+        127,
+    )?;
+    // 2: RESHAPE
+    // the output shares the buffer of the input
+    // 3: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<i8>(arena, 512, 128), slice_mut::<i8>(arena, 0, 32)) };
+    OpFullyConnectedInt8::kernel(
+        input,
+        FilterData::Dense(&TENSOR_5),
+        &OP3_EFFECTIVE_BIAS,
+        output,
+        0,
+        -128,
+        32,
+        &OP3_MULTIPLIER,
+        &OP3_SHIFT,
+        128,
+        1,
+        -128,
+        127,
+    )?;
+    // 4: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<i8>(arena, 0, 32), slice_mut::<i8>(arena, 676, 1)) };
+    OpFullyConnectedInt8::kernel(
+        input,
+        FilterData::Dense(&TENSOR_3),
+        &OP4_EFFECTIVE_BIAS,
+        output,
+        0,
+        -3,
+        1,
+        &OP4_MULTIPLIER,
+        &OP4_SHIFT,
+        32,
+        1,
+        -128,
+        127,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,506 @@
+// @generated by berrylite-codegen from simple_depthwise_conv_avg_pool_sin_int8.tflite, do not edit
+
+use berrylite::kernel::micro_operator::i8::avg_pool2d_i8::OpAvgPool2DInt8;
+use berrylite::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8;
+use berrylite::kernel::micro_operator::i8::depthwise_conv2d_i8::OpDepthWiseConv2DInt8;
+use berrylite::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8;
+use berrylite::kernel::utils::int4::FilterData;
+use berrylite::micro_errors::Result;
+
+pub const ARENA_SIZE: usize = 677;
+pub const INPUT_OFFSET: usize = 640;
+pub const INPUT_LEN: usize = 36;
+pub const INPUT_SCALE: f32 = 0.0052235294;
+pub const INPUT_ZERO_POINT: i32 = 57;
+pub const OUTPUT_OFFSET: usize = 676;
+pub const OUTPUT_LEN: usize = 1;
+pub const OUTPUT_SCALE: f32 = 0.0072983084;
+pub const OUTPUT_ZERO_POINT: i32 = 3;
+
+/// The memory of the activations, which is laid out by the greedy memory planner
+#[repr(C, align(16))]
+pub struct Arena(pub [u8; ARENA_SIZE]);
+
+impl Arena {
+    pub const fn new() -> Self {
+        Self([0; ARENA_SIZE])
+    }
+
+    pub fn input(&mut self) -> &mut [i8] {
+        unsafe { slice_mut(self.0.as_mut_ptr(), INPUT_OFFSET, INPUT_LEN) }
+    }
+
+    pub fn output(&self) -> &[i8] {
+        unsafe { slice(self.0.as_ptr() as *mut u8, OUTPUT_OFFSET, OUTPUT_LEN) }
+    }
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the `len` elements of `E` at the byte `offset` of the arena.
+///
+/// # Safety
+///
+/// The elements must be in the arena and aligned for `E`, as the offsets of the memory plan are.
+/// The slice must not overlap a slice returned by `slice_mut` while both of them are alive,
+/// which holds for the tensors of an operator, as the memory plan keeps its inputs and outputs apart.
+unsafe fn slice<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a [E] {
+    core::slice::from_raw_parts(arena.add(offset) as *const E, len)
+}
+
+/// Returns the `len` mutable elements of `E` at the byte `offset` of the arena.
+///
+/// # Safety
+///
+/// As `slice`, and the slice must not overlap any other slice of the arena while it is alive.
+unsafe fn slice_mut<'a, E>(arena: *mut u8, offset: usize, len: usize) -> &'a mut [E] {
+    core::slice::from_raw_parts_mut(arena.add(offset) as *mut E, len)
+}
+
+static OP0_MULTIPLIER: [i32; 1] = [
+    1213422351,
+];
+
+static OP0_SHIFT: [i32; 1] = [
+    -8,
+];
+
+static OP0_EFFECTIVE_BIAS: [i32; 1] = [
+    -27230,
+];
+
+static TENSOR_9: [i8; 9] = [
+    55, 103, -26, 127, 96, 8, -97, 127, 60,
+];
+
+static OP1_MULTIPLIER: [i32; 32] = [
+    1341928408, 1454314130, 1615784698, 1502272221, 1878269695, 2119541744, 1269540912, 1128743418, 1200350615, 1102052221, 1875192813, 1347592103, 1797159327, 2044036984, 1230915665, 1619465960,
+    1674846942, 1859514754, 1158813947, 2104345574, 1116591825, 1548771288, 1986010274, 1831824056, 1233581895, 2069067871, 1325307694, 1154138465, 1403847298, 1362668888, 1499050172, 1498551302,
+];
+
+static OP1_SHIFT: [i32; 32] = [
+    -6, -6, -8, -6, -9, -8, -7, -6, -7, -7, -10, -8, -8, -7, -7, -6,
+    -7, -7, -6, -7, -6, -7, -8, -10, -7, -7, -7, -8, -6, -7, -7, -6,
+];
+
+static TENSOR_7: [i8; 32] = [
+    127, -127, -127, 127, 127, 127, 127, 127, 127, -127, -127, -127, 127, -127, -127, -127,
+    -127, -127, 127, -127, 127, -127, -127, -127, 127, 127, 127, 127, -127, -127, 127, 127,
+];
+
+static OP1_EFFECTIVE_BIAS: [i32; 32] = [
+    -8997, 4340, -6903, -9743, 31563, -19229, -3750, -10981, -2288, 18525, 100550, -8151, -302, 10007, 18433, 3331,
+    7571, -896, -3286, 12765, -4425, 10097, 15753, -14526, -21409, -14972, -10187, 24170, 8655, 13994, -1116, -11020,
+];
+
+static TENSOR_5: [i8; 4096] = [
+    -16, 4, 30, 30, 3, -30, -22, 19, -26, -7, -7, -29, -10, 9, 23, 25,
+    3, 10, 29, 8, 37, 30, 30, -11, -16, -33, -39, 22, -13, -2, 36, 1,
+    -10, -27, 19, 2, -9, -47, -20, 24, -19, 21, 49, -11, 14, 21, -24, 35,
+    -11, -26, 32, -37, -35, 3, 15, 28, -37, 6, -42, 18, -23, -43, -10, -35,
+    -42, -45, -5, -41, 28, -14, -16, -23, 2, -22, 55, 15, -17, 19, -41, -23,
+    -11, 24, -2, -8, 14, -2, 2, 28, -45, 23, 12, -10, -38, -25, 3, 16,
+    25, -14, 9, -16, 1, -47, -5, 13, -23, -1, 65, 6, 22, -12, 20, -16,
+    5, 0, 5, -8, 28, -12, 19, -17, 14, 32, 21, -17, 23, -1, -15, -24,
+    -28, -38, 11, -29, 51, 24, -33, 2, -47, 42, 62, 2, -17, 37, 6, -37,
+    -46, -17, -12, -23, 14, 2, -22, -56, -31, 88, -53, 102, 19, 36, -32, 5,
+    -16, -38, -43, -71, 47, 35, -14, 17, -47, 39, 65, -49, 22, 34, 9, -34,
+    -37, -29, -26, -25, 12, 20, -17, -81, -38, 74, -27, 111, -8, 1, 17, -33,
+    -48, -12, 12, -47, 33, 10, -40, -30, 15, 20, 3, -10, -50, 7, 20, -39,
+    16, -10, -51, 0, 17, 26, -1, -126, -9, 66, -65, 47, -18, 19, 22, -29,
+    -28, -24, -29, 6, 96, -23, -42, -15, -31, 20, 0, 3, 7, 36, 39, -18,
+    -12, -28, -38, 10, -35, -41, 39, -121, 3, 30, 0, 53, 4, 21, -37, -35,
+    -33, -38, -37, -38, 20, -10, -12, 24, 16, -16, 105, -26, 1, -38, 22, -12,
+    -39, 1, 17, -41, 24, 16, -20, 14, -32, -24, -43, 43, 26, 34, 7, 6,
+    12, -55, -3, -39, 0, -31, -19, 33, 25, -1, 30, -35, -2, -6, 12, -10,
+    -12, 47, 38, -34, 35, 26, 39, -8, -54, 6, -33, -16, 1, -12, 34, -45,
+    4, 17, 24, -11, -17, -4, 11, -16, -6, 13, 44, 35, 21, -22, 7, -48,
+    19, 18, 25, 22, -23, 43, -24, -13, -34, -36, 11, -31, -17, 29, -7, -10,
+    33, -21, 10, 1, 5, -37, 29, -40, 25, 14, 78, 8, 29, -3, 0, -7,
+    16, 15, 13, -30, 27, 23, 5, 6, -28, -19, -38, 33, -34, 22, -8, 6,
+    18, -55, 69, -12, 8, -83, -25, 2, -28, 9, 67, 71, 39, -36, -14, -28,
+    36, 52, -2, -25, 31, 32, -33, -22, -94, -38, 23, 44, 23, 14, 42, -23,
+    -26, -36, 18, -8, 68, -75, 22, 2, 0, 20, 94, 46, -15, 4, -10, -44,
+    5, -12, -8, -1, 1, 15, -14, 33, -14, -28, 8, 53, 25, -28, 44, -11,
+    19, -33, 21, -36, 23, -62, -26, -41, 25, 12, 94, 46, -26, -19, -26, -71,
+    -23, 11, 4, 11, 38, 9, 0, 26, -38, -51, -29, 41, -34, 10, -31, -51,
+    17, -42, 17, -21, 23, -31, 13, 12, -17, 24, 89, 35, 47, -31, 24, -59,
+    28, -19, -23, 20, 34, -32, 2, 26, -88, -3, -44, 42, -10, 8, -20, -57,
+    29, -19, -3, -4, -14, 9, 20, 23, 30, -1, 6, 37, -32, 0, -13, 12,
+    33, 36, -24, 22, 37, -14, -39, 9, 21, 4, 36, -11, -40, -30, 37, 7,
+    29, 20, -38, 8, 2, -5, -39, -27, -29, 12, 5, -29, 6, 32, -33, 23,
+    -15, 8, -31, -32, -7, -16, -29, 35, -21, -35, 33, 35, 30, 3, -38, -25,
+    1, -16, -30, 37, -26, -33, 25, -13, -33, 15, -23, 26, -38, 33, -3, 39,
+    -36, -16, 18, -33, -36, -5, -27, 38, -38, -2, 18, 26, -3, -21, -5, 7,
+    -34, -36, 6, 23, -21, -26, -35, 20, -20, 13, -37, -36, -15, -17, -27, 39,
+    -30, 1, 31, -34, 9, -21, 6, -39, -38, 13, -34, -10, 8, 12, -8, -41,
+    0, 27, -3, -39, 50, -18, 6, 24, -7, -36, -5, -21, 11, -1, 32, 2,
+    11, -6, 32, 16, 13, -29, 5, 25, -52, 17, -22, 34, 9, 20, 11, 17,
+    -11, 10, -33, -16, -12, 10, -3, 17, -23, 21, 6, 7, 24, 10, 23, -33,
+    34, -39, 2, -22, 5, -24, -34, -18, -37, 26, -34, 2, 29, -32, -37, 20,
+    6, -43, 20, -19, 4, -33, -29, 33, 35, 12, 27, -41, -26, 21, 31, -35,
+    18, 16, 31, -20, 24, 17, -44, -33, -54, 0, -26, 14, -1, -26, 15, -22,
+    20, 34, -40, -25, 31, -29, -14, -3, 0, -15, 20, 16, -35, 21, -2, -31,
+    -21, -37, -18, -13, -18, -45, -21, -14, -13, -4, 35, -12, -2, -30, 6, 19,
+    -15, 32, -24, 21, 1, 26, -27, 6, -35, -16, -31, 22, -1, 16, 29, -42,
+    36, -16, 21, -38, -19, -19, 29, -30, 21, -13, -6, -10, -4, 5, 23, -18,
+    7, -18, -29, -19, 11, -7, -38, 19, 8, -32, 24, 11, -25, 20, -28, -2,
+    9, -12, -31, 10, 6, -9, 26, 3, 16, 22, 30, -30, 0, 27, -16, 31,
+    -16, -27, -16, -6, 34, -10, 17, 4, 20, 31, -11, 21, -32, 30, 5, -4,
+    -34, 30, 21, -23, 32, -41, -5, -14, 17, 40, 9, -25, -27, -27, -33, -16,
+    -13, 0, 12, -11, 4, -8, 15, -34, 10, -36, 34, 36, 22, 31, -6, 30,
+    -31, -13, -34, -1, 12, 11, -15, 2, 37, 19, -33, 22, 1, 28, -29, 7,
+    6, -29, -4, -28, 21, -22, -8, -37, -28, 0, 12, -10, 6, -33, 21, 20,
+    17, -37, 0, -43, -27, 29, 22, -34, -2, -37, -15, -27, -8, -1, -42, -4,
+    21, 4, -18, -1, 2, -12, -32, 27, 9, 26, 4, -9, 7, -21, -25, 3,
+    2, 30, 14, 1, 4, -38, -10, 15, 9, -23, -12, -8, 37, 31, -17, -34,
+    -2, 30, 5, -32, -12, 23, 32, -15, -12, -34, 15, -10, -19, -12, -43, -13,
+    30, -40, 36, -23, 10, -19, -17, 1, 14, -35, 12, -37, 14, 20, -24, 38,
+    26, 34, 26, 21, 6, 13, 0, -28, 39, -39, 24, -16, 10, -33, -38, 6,
+    13, 24, 18, 10, 5, -28, 9, 17, 25, 10, 28, 39, 33, -19, 1, 6,
+    -15, 32, -17, -52, 44, -88, -40, -33, 10, -26, 18, -40, 18, -34, -20, -9,
+    16, 9, 28, -33, 15, 32, 36, -34, -72, 8, 4, 66, 22, 24, -48, 18,
+    14, 13, -2, -10, 33, -94, 18, 15, 14, 41, 40, 7, 21, -27, 20, 32,
+    24, 13, 19, -31, 21, -1, -13, -33, -66, 9, -28, 40, -37, 35, -37, -52,
+    -10, 33, -35, -43, 86, -92, -19, 16, -2, 19, 38, -2, 7, 26, 4, -15,
+    35, 9, -39, -26, 35, -28, -10, -74, -29, 12, -19, 59, -12, -11, -26, -34,
+    -40, -45, -24, -52, 27, -34, 3, 3, -46, 21, 9, -28, -45, -32, -8, 10,
+    -15, 1, -44, 28, 24, 9, 19, -54, -101, 28, 18, 100, 10, 36, -10, -42,
+    -37, 7, 40, -21, -19, 16, 33, 9, 22, -13, -20, 21, -2, -17, 28, -1,
+    -29, -11, -3, 20, 32, 12, 18, -15, 3, 11, -21, -30, 18, 11, -40, 4,
+    -7, -14, -24, -40, 2, 8, 17, -4, -21, -2, 4, 36, -14, 26, 24, 16,
+    31, -38, -11, -4, -3, -16, -26, 12, 1, -14, -14, -22, 0, 19, 1, 2,
+    39, 2, 2, -21, 6, 14, -12, 2, -10, -37, 26, 5, -36, -33, -21, -14,
+    -5, 33, -41, 15, 27, 30, 24, -30, -2, -10, 8, -27, -37, 18, 39, -34,
+    -23, -36, -1, 22, 23, -11, -37, -24, 31, 8, 12, -11, 12, -32, 4, -36,
+    -31, -21, -25, -14, -8, 33, 4, -36, -1, 4, -11, 40, 4, -21, 38, 0,
+    -8, -8, -7, 17, 29, 27, -4, -1, -32, 40, -28, -40, 6, 15, -32, -39,
+    24, -8, -43, 19, -17, 29, 0, 9, 1, 0, -23, 19, -14, -17, 9, 26,
+    -29, -1, 8, 12, -34, 10, 0, -18, 12, -14, 38, 32, 3, 22, -36, 32,
+    7, 27, -4, -21, 33, -8, -5, -31, -25, 27, -28, -32, -28, 8, 29, -5,
+    9, -23, -33, -35, 8, -35, -12, 35, 6, -29, -17, -20, -32, 26, 5, -23,
+    31, 4, 11, 37, -24, -2, 17, 37, 35, 3, -3, -22, -29, -26, -6, -11,
+    -4, -2, 26, 26, -35, -40, 12, -23, -11, -19, -10, 23, -23, 19, -4, 23,
+    -30, 8, 16, -21, 19, -15, -38, -4, 20, 30, -24, -2, -37, -27, 1, 4,
+    -2, 11, 28, 30, 31, -14, 34, -17, 27, 8, 70, 6, -2, -7, 0, 4,
+    -41, -6, -7, -22, 5, -11, -21, 16, 18, -28, -23, 39, 25, -22, -2, 30,
+    -44, -11, 24, 5, 8, 10, -35, -14, -15, 29, 86, 12, -22, -30, -25, -48,
+    22, 1, -15, -27, 6, 33, -29, -24, -33, 29, 32, 24, 0, -30, -4, 26,
+    -44, -46, 14, -35, 1, 7, -17, 8, -34, 23, 96, 34, 16, -7, 31, -5,
+    -20, 3, -32, -11, 28, 10, -2, 35, -38, 23, 35, 11, -25, -10, -20, 7,
+    29, -49, 25, 5, -25, -37, -9, 13, 2, 23, 94, 37, 31, -32, 25, -13,
+    15, 40, -15, -24, 36, 32, -9, 14, -18, 5, -46, -2, 29, 3, -39, 12,
+    -17, 27, 7, -40, 88, -46, -32, -57, -2, 41, -4, -42, -4, 29, -26, -28,
+    15, -21, -23, -6, 24, 5, -10, -88, -39, -5, -13, 47, 31, 21, -29, -20,
+    -12, -45, -33, 10, 79, -15, 38, -19, 3, 11, -4, -63, -15, -18, -9, 11,
+    -11, -38, -2, 9, -21, -24, 21, -64, -44, -46, -27, 116, 27, -1, -22, 5,
+    1, 4, -7, -25, 108, -60, -31, -7, -32, -4, 48, -32, 0, -17, -32, -2,
+    -30, 25, 24, -17, -22, 19, -34, -117, -57, 2, 23, 52, 13, 10, -9, -56,
+    -25, -22, 19, -38, 100, -30, -30, -30, -18, 28, 41, -56, 24, 17, 28, -48,
+    -45, -30, -38, 24, 25, 17, -3, -51, -45, -35, 14, 64, -27, -5, 12, -11,
+    -20, -19, -6, 35, 41, -37, -14, 17, -9, -33, 14, 25, 6, -34, -4, -37,
+    -17, -8, 22, -7, -35, -31, -28, 13, 29, -8, -32, -22, -26, -12, -24, -29,
+    -29, -39, -35, -11, -22, 7, -16, 30, -5, -27, -7, -15, 17, 16, 7, 31,
+    19, -4, -20, 40, 22, 20, -20, -30, -3, 11, -10, -6, -35, -41, -36, -34,
+    2, 17, 3, 11, 39, -2, -32, -30, -18, -15, -26, 30, -18, 34, -25, 15,
+    36, 32, 24, 16, 23, 17, 16, 20, -33, 13, -35, 4, -26, 10, 11, -23,
+    -37, 1, 30, 34, 21, 12, 8, 39, -33, 14, 32, -39, 4, 3, 2, -1,
+    -34, -26, -15, -4, -17, 15, -38, -5, -29, -5, 16, -12, 33, 15, -37, 25,
+    -34, -60, -35, -7, 64, -65, 19, -25, 2, 30, 75, -37, 25, 28, -12, -60,
+    -26, 25, 45, -42, 44, 26, 8, 2, -39, -27, 26, 51, 30, -32, 50, -50,
+    -24, -19, 36, -8, 75, -64, 24, -30, 11, 18, 79, -32, 44, -37, -12, -40,
+    3, -8, -23, 18, 9, 3, -30, -17, -63, -42, -33, -8, -36, 27, 20, 2,
+    -29, -21, -10, -37, 62, -51, 21, 21, -2, -5, 71, 19, -21, 12, 15, -36,
+    -10, 55, 9, -26, -27, -29, -44, -35, -51, -24, -48, 43, -26, -48, -10, -26,
+    9, -26, -23, -39, 34, -79, -13, -23, 17, -3, 74, 4, 53, 12, 31, -70,
+    2, -3, -23, 15, 8, -51, -11, 28, -65, -6, 5, -13, -49, -15, -13, 8,
+    22, 15, 3, -39, -30, 15, 23, 3, 0, 6, -36, 29, -34, 26, 9, -25,
+    1, -21, -7, -6, 16, 37, 11, -27, 40, 19, -30, 13, -36, -26, -38, -29,
+    -35, -5, -43, 37, -40, -10, -17, -10, 14, 1, -8, 0, 10, -16, 15, 17,
+    27, -37, -34, 25, -41, -6, -23, 25, -22, -13, 31, -10, 16, -44, -23, -28,
+    20, -6, -44, 17, 30, -10, 34, -34, 23, 21, 37, 5, -11, -36, 27, -2,
+    29, -31, -10, -9, 27, -16, -33, -9, 3, -40, 33, 4, -19, 30, 40, 0,
+    -17, 31, 15, -28, 40, 29, 3, 33, 21, -11, 9, -25, -24, 37, 15, -6,
+    32, 32, -38, -10, -9, -17, -11, 30, 8, 40, 28, 5, -21, -15, -5, -28,
+    0, 0, 32, 36, -7, -7, 20, 29, -26, -14, -12, 18, -12, 28, 24, -40,
+    -11, 16, -16, -12, -20, 11, 39, -15, -43, -16, -20, 13, -17, -27, -33, -22,
+    33, 13, -37, -17, 20, 21, 33, -41, 26, -25, 7, 23, -26, -4, 41, 7,
+    11, -4, 15, 6, 9, 16, -19, 27, 36, -31, -12, 16, -29, -23, -20, -14,
+    19, -8, 26, 3, 10, -32, -20, 10, 10, -36, -33, 38, -19, 19, -5, -32,
+    -36, 1, 16, -3, -28, -36, 27, 19, -38, -13, 10, -33, -40, 7, 16, 13,
+    31, -33, -7, -26, 30, 13, -24, 16, 24, 35, 35, -22, -33, 24, -16, -20,
+    13, 20, -14, -23, -21, 14, -16, 1, -32, 12, 3, 3, -21, -17, -23, 32,
+    -24, 15, -4, 14, 7, 27, 9, 44, -19, 8, 41, 2, -6, -33, 41, 22,
+    -44, -44, -34, 29, 42, -18, -17, -45, 52, -23, 45, 21, 31, -17, -10, 37,
+    33, 23, -8, 20, 29, -23, -1, -3, -17, 31, 33, 0, -6, 20, 34, 27,
+    -12, -51, -12, 36, 3, -16, 37, -95, 21, 10, 41, -14, -28, 24, 26, -7,
+    29, -45, -23, -15, -12, 39, 18, 31, -30, 11, -5, 5, 18, -40, -18, 13,
+    16, -2, -27, -18, 29, -14, 42, -39, 2, 0, 5, 22, -15, 6, 38, -14,
+    14, 12, 16, 22, -10, 0, -38, 20, -34, -11, 21, -46, 22, -38, -5, -14,
+    27, -12, 30, -7, -25, 25, 14, -45, -5, -6, 21, -33, 1, 39, -34, 31,
+    -19, -11, -3, 12, 32, -13, -34, -23, 25, -40, 69, 24, 11, 22, 12, -8,
+    -30, -16, 35, 32, -20, -11, -16, -40, -24, -41, 8, 23, 16, 33, 18, -45,
+    -25, 27, 1, 2, 1, -21, -18, -35, -14, 34, 30, 26, -36, -45, -1, -9,
+    -10, 11, 10, -22, 13, -31, -6, -40, -33, -5, -16, -6, -29, -20, 13, -1,
+    6, 27, 22, -47, 32, 16, -22, -35, 29, 9, 34, -21, 33, -26, 17, 34,
+    -35, 19, 20, -41, 32, 14, -1, 1, -9, -27, 15, 8, 30, -45, 22, 33,
+    -39, -36, 6, -36, 57, 17, 4, 7, -22, 4, 64, -8, 44, 21, -14, -9,
+    -6, -12, 38, -5, 6, -33, 16, 30, -57, 4, 23, 32, -44, 29, -26, -39,
+    -7, -21, 3, -26, 49, -37, 21, -15, 16, 19, 21, -16, 28, 1, 26, -2,
+    -40, 33, 13, 3, 2, 1, 18, -33, -12, -20, 13, -16, -39, -33, 9, -40,
+    -12, -19, 19, -37, 21, -33, -21, 33, -32, -44, 27, 9, 32, -11, -33, -18,
+    -15, 1, -14, 11, 27, -29, -6, 15, -20, 32, 12, 4, 24, -41, 29, 6,
+    -35, 20, 36, 18, 43, 6, 23, 24, 19, 28, 21, 30, 28, 20, -8, 35,
+    -14, 29, 40, 30, 33, -19, -32, 38, 21, -34, 2, -14, -17, 3, 7, 23,
+    -8, 15, 34, 6, 48, -25, 30, 27, 29, 10, 9, 23, -21, -37, -41, -5,
+    1, -40, -5, -33, 25, 29, 34, 15, -33, -21, 9, -7, 7, 31, -24, 3,
+    -18, -45, -51, 31, 31, 27, -38, 3, -18, -23, 14, 9, 4, 23, 34, 7,
+    17, -14, 31, 31, -14, 24, -7, -75, 35, 27, -21, 31, 22, -17, -31, 25,
+    -6, -33, -28, 5, 8, -22, 32, -42, -16, 21, 33, 12, 36, -31, 24, -31,
+    -22, -26, -17, -10, -28, -18, 29, -54, -24, -1, 23, 11, -19, -13, -20, -9,
+    5, -2, -48, 22, -13, 14, 18, 1, -28, -16, 1, -32, -10, -4, 41, 28,
+    -21, -14, 7, 22, -43, 14, -3, -103, 26, 20, -36, 2, 31, -35, -29, 26,
+    16, 21, -52, -40, 39, -23, -36, 21, -10, 32, 25, -2, -12, -5, 35, -33,
+    17, -29, 31, -15, -12, -21, 35, -110, -38, -13, 12, -1, -42, 10, 21, -32,
+    30, 15, -9, -3, -111, -7, -21, -11, -21, 15, -36, 46, -2, 26, 34, 22,
+    24, 20, -4, -11, -29, 30, 15, 126, -38, 24, 20, -70, 21, -28, -28, -34,
+    -19, 39, 62, -2, -92, -32, -40, 18, -27, 10, -20, 44, -26, 4, -14, 49,
+    -25, -21, 26, -25, 7, 6, -8, 92, -27, -39, 36, -109, 22, -15, -11, 11,
+    0, 21, 39, -37, -47, -11, 28, 14, 19, 3, -51, 67, 5, -17, 17, 20,
+    -12, 32, -8, -30, 37, 6, -36, 108, -34, 1, 13, -66, 30, 42, -10, 5,
+    -26, 38, 42, -29, -114, -5, 29, 6, -22, -13, 0, 38, 35, 20, 29, 31,
+    25, -21, 6, 36, 4, -26, 30, 100, -37, 40, -38, -98, -8, -18, 21, 19,
+    6, -34, -16, 23, 1, -14, 27, -2, 7, -1, 26, 1, -25, 31, 18, 21,
+    -26, -1, 39, 6, 0, -26, 2, -35, -19, 38, 35, -31, -1, 4, 23, 0,
+    5, 34, -46, -2, 35, 43, -31, -6, -29, -7, -15, -1, 12, 25, 3, -45,
+    -32, -43, 38, -39, 7, 2, 12, -15, 42, -23, 2, 26, -7, 1, -8, 23,
+    -4, 20, -13, -4, 21, -33, 25, 2, 13, -19, 42, 3, 34, -4, 33, -15,
+    24, -17, -30, 5, 38, -34, 0, -53, 37, -24, 40, -35, 17, -8, -19, 22,
+    20, -22, -31, -3, -24, 24, -17, -32, 16, 16, -11, -19, -36, 7, -9, 16,
+    27, 16, 37, -22, 1, 39, 2, -6, 26, -7, -12, 12, 8, 11, 19, -26,
+    -46, -31, -74, -38, 107, -9, -41, 17, -11, 31, 43, -79, -45, -6, 50, -23,
+    8, -48, -2, -10, -7, -37, 36, -74, 14, 14, -30, 47, -35, 2, -48, -48,
+    -52, -28, -67, 6, 65, 37, 2, -41, -36, 49, 4, -79, 25, -37, 45, -50,
+    21, -34, 5, 43, 14, 31, 0, -121, 13, -28, -29, 46, -15, -3, -3, -50,
+    -1, 12, -3, -25, 98, 11, 12, -29, 20, 12, 77, -86, -34, -21, 36, -12,
+    22, -44, 15, 11, -14, -16, -14, -79, 3, -33, -20, 104, 12, 17, -28, 22,
+    -19, -49, -74, -26, 62, 22, 6, 11, -11, 29, 47, -90, -14, 26, -22, -16,
+    -3, -8, -18, 31, -16, -9, -22, -127, 49, -3, 26, 78, -28, 20, 18, -14,
+    -33, 12, -34, 10, -20, 42, -23, 16, -2, 25, -40, 11, -20, -19, 8, -25,
+    -2, 13, 20, 23, -27, 22, 14, 19, 7, 24, 2, 17, -35, 13, 8, 45,
+    -15, -19, 11, -30, -47, 24, -23, 5, 12, -21, -96, -40, 40, 38, -33, 27,
+    -4, -13, 24, 6, 28, 8, -30, 11, 30, 38, 24, 25, 35, 0, -15, 32,
+    44, -34, 23, 46, 1, 20, -11, 32, -27, 20, -72, -10, -8, 14, 24, 35,
+    34, -30, 29, -4, -29, 26, -23, -19, 6, 16, -4, -36, -33, -36, -1, -14,
+    29, -19, -40, 20, -46, 16, -38, 26, 2, 12, -43, -20, -10, -36, 34, -5,
+    5, -28, -1, 1, 36, -11, -15, 13, 35, -16, -21, 18, -11, -34, 11, 30,
+    23, -69, -20, 6, 39, -46, -25, 22, -28, 10, 43, 15, 32, -8, 43, -44,
+    12, -54, 24, -35, 18, 1, -22, -7, -44, -34, 18, 3, -40, 4, 17, -30,
+    2, -51, 37, -17, -4, -10, 36, -33, -8, 19, 24, -34, 0, 20, 12, -67,
+    30, -85, -6, -9, 39, 33, 39, 38, -14, -41, -37, 44, -13, -45, -30, -21,
+    -23, -61, 8, 23, 48, 16, -26, -22, 25, -30, 37, 17, -19, -1, -16, -43,
+    26, -29, 10, 14, -16, 24, 14, -26, 8, -11, 34, 12, -3, 9, -15, 10,
+    -22, -1, -19, -3, 47, -17, 13, 16, 34, -13, 67, -2, 14, 19, 48, -19,
+    -6, -45, 6, -33, -28, -32, -34, 24, -8, 34, 10, -24, -2, 5, 18, 10,
+    -28, -9, -30, -33, -4, 8, -15, 27, -30, 6, 31, 4, 22, -28, -26, 8,
+    3, 32, -7, 38, -5, 24, 14, -14, -16, 23, -1, 24, 20, 31, -9, -28,
+    -11, 12, 31, 32, 0, -21, 2, -30, -6, -29, -37, -33, -2, 6, 26, 13,
+    -29, 26, 6, -25, 31, 17, -33, 11, 5, -27, 6, 5, -12, -23, -6, -11,
+    31, 3, 33, -36, -12, 19, -9, 36, 28, 27, 28, 36, -24, -15, -29, -35,
+    26, 8, -10, -18, 1, -28, -1, -13, 21, 26, -8, 24, -29, -35, -21, 3,
+    -40, -26, 10, -33, -31, 40, -11, 1, -40, -27, -27, 0, -4, 6, -38, 40,
+    19, 11, 3, 17, -21, 15, -24, -39, -18, 38, -30, -33, -28, 31, -1, 7,
+    11, 15, 11, 16, -27, -42, 6, 27, 5, -14, -16, 10, -12, 18, 16, 8,
+    -12, 14, -32, -4, 15, -16, 16, -14, -9, 37, 26, -30, 24, -24, -25, -16,
+    -8, -42, 24, 15, 4, 17, -11, -1, 33, 9, 14, 17, -37, -6, -19, -30,
+    8, -42, 12, 2, 12, 34, 32, 32, -18, 36, -41, -29, -30, -13, 10, -13,
+    28, 16, -12, -43, 45, -36, -24, 7, 26, -27, -3, -1, 3, 0, -22, -5,
+    -3, -38, 1, -4, -22, 20, 7, -23, -29, 2, 18, -22, -17, 22, -22, -20,
+    16, 11, 8, -39, 36, -49, -6, -43, 36, -3, 15, 6, 35, 0, 2, -18,
+    -18, -37, -12, 3, 19, -13, -22, 5, -37, 22, 13, 30, 8, 10, 0, 9,
+    -29, -13, 29, -23, 41, -36, 5, -7, 28, 24, 3, -25, -34, 7, -45, -22,
+    26, -14, -24, -43, 39, -15, 25, -30, -42, -3, 6, 41, -27, -33, 12, 19,
+    17, -23, 29, -29, 6, 4, 27, -42, 27, 27, 35, 33, 24, -21, 19, 10,
+    -17, 8, 4, 9, 32, 19, -28, 34, 16, -8, 33, 42, -13, 12, 12, -18,
+    -10, 15, -27, -28, 0, -32, 16, -22, 11, 29, 70, 38, -23, -41, -10, -12,
+    10, 12, 6, -43, 29, -31, -41, 18, -11, -12, 2, 27, 8, -9, 2, -42,
+    11, 34, 30, 22, 44, -49, 15, -30, -19, -4, 10, 37, 9, 15, -1, -29,
+    36, 36, -2, -15, 33, -42, 21, -24, 14, 14, -31, 12, 27, 36, 3, 5,
+    -42, -14, -39, -30, 14, -16, -10, -38, -21, 11, 40, 4, -1, -27, -25, 29,
+    -18, -17, -13, -20, 19, 31, -16, 9, 36, -8, -2, -9, -13, 41, 35, -18,
+    20, 13, -25, 6, 31, -32, -16, 36, -3, 31, 33, -4, -16, 29, -3, 10,
+    -39, 27, 13, -6, -43, -10, 6, -42, 35, 9, 8, 2, 7, 18, 13, 18,
+    26, -31, 8, 0, -10, 35, -34, -35, 2, -23, 26, -23, -3, -41, 34, 2,
+    15, 26, -2, 21, -15, 39, -6, 9, 20, 37, -9, 20, -27, 36, 29, 12,
+    -29, 3, -10, -23, 33, -10, 4, 2, -19, 33, 6, -43, 15, 32, -27, 6,
+    -41, 12, -26, 12, 35, 8, 13, -66, 2, 25, -31, -10, 32, -35, -23, 13,
+    -26, -24, -19, -16, 2, -19, -34, 22, 25, -8, 24, 23, -31, 26, 40, -4,
+    -3, -8, 29, 19, 15, -14, -40, 18, -6, -2, 25, -32, 12, -21, -3, 9,
+    -5, 35, 1, 8, -31, 35, -14, -34, 40, -3, 5, 11, -38, -3, 25, 5,
+    7, 16, 19, -38, -31, 35, 4, -13, -10, 33, 8, 6, 20, 6, -28, -7,
+    17, -40, 29, -36, -30, 35, 3, -1, 14, -32, -37, -13, 17, -10, -6, -41,
+    38, -31, -11, -16, -6, 39, 29, -17, -21, 15, 36, 16, -24, 12, -2, -7,
+    21, 1, -33, -12, -31, 36, -32, -36, 38, -18, -33, 5, -15, -21, -3, 8,
+    -29, 24, -34, -25, -29, -8, -31, -36, 30, 37, -39, -1, -6, -41, 40, -21,
+    -38, -8, -41, -38, 81, -25, -8, 0, 12, 11, 70, -76, -6, -44, 22, -12,
+    -3, 15, -35, -13, -19, 6, 24, -47, -4, -10, -8, 106, 8, 17, 25, -30,
+    -13, -11, -64, 17, 67, -19, -4, 8, -2, 11, 66, -73, -31, 19, 28, -29,
+    -26, -50, -5, -15, 1, 17, -35, -99, 3, -16, 12, 99, 13, 29, 9, 10,
+    22, -39, -71, -28, 73, -46, -40, -50, 28, 25, 2, -18, -25, 19, -13, -12,
+    -21, -4, -13, -1, 14, -36, -22, -71, 7, -38, -3, 65, 32, -22, -47, -48,
+    -3, -20, -39, -4, 70, -72, 15, -33, -2, 14, 5, -59, 6, -15, -14, 9,
+    -16, 12, 2, 31, 1, -8, 27, -64, -22, -45, -11, 67, -2, -16, 19, -40,
+];
+
+static OP4_EFFECTIVE_BIAS: [i32; 32] = [
+    -17489, -69467, 4796, 6516, -69612, -46112, -11011, -28163, -68605, -36145, -43470, 42388, -103524, -57984, -71935, -17999,
+    -33929, 34449, -10648, 49058, -64237, 9901, 15799, -85655, -7055, -30312, -35840, -22683, 36094, 9458, -42496, -86807,
+];
+
+static OP4_MULTIPLIER: [i32; 32] = [
+    2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160,
+    2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160, 2044229160,
+];
+
+static OP4_SHIFT: [i32; 32] = [
+    -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+    -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+];
+
+static TENSOR_3: [i8; 32] = [
+    30, -82, 95, 86, -79, 10, 38, 12, -7, 24, -34, 7, -77, 78, 78, 75,
+    -61, -66, 108, 30, -69, 37, -16, -127, -46, 53, -59, 37, 47, -4, 67, -23,
+];
+
+static OP5_EFFECTIVE_BIAS: [i32; 1] = [
+    20799,
+];
+
+static OP5_MULTIPLIER: [i32; 1] = [
+    1551275082,
+];
+
+static OP5_SHIFT: [i32; 1] = [
+    -8,
+];
+
+pub fn invoke(arena: &mut Arena) -> Result<()> {
+    let arena = arena.0.as_mut_ptr();
+    // 0: DEPTHWISE_CONV_2D
+    let (input, output) = unsafe { (slice::<i8>(arena, 640, 36), slice_mut::<i8>(arena, 512, 16)) };
+    OpDepthWiseConv2DInt8::kernel_3x3(
+        input,
+        &TENSOR_9,
+        &OP0_EFFECTIVE_BIAS,
+        output,
+        6,
+        6,
+        1,
+        4,
+        4,
+        1,
+        0,
+        0,
+        -57,
+        0,
+        60,
+        &OP0_MULTIPLIER,
+        &OP0_SHIFT,
+        1,
+        -128,
+        127,
+    )?;
+    // 1: CONV_2D
+    let (input, output) = unsafe { (slice::<i8>(arena, 512, 16), slice_mut::<i8>(arena, 0, 512)) };
+    OpConv2DInt8::gemm_kernel(
+        input,
+        &TENSOR_7,
+        &OP1_EFFECTIVE_BIAS,
+        output,
+        &[],
+        4,
+        4,
+        1,
+        1,
+        1,
+        4,
+        4,
+        32,
+        1,
+        1,
+        1,
+        1,
+        0,
+        0,
+        -60,
+        0,
+        -128,
+        &OP1_MULTIPLIER,
+        &OP1_SHIFT,
+        1,
+        -128,
+        127,
+    )?;
+    // 2: AVERAGE_POOL_2D
+    let (input, output) = unsafe { (slice::<i8>(arena, 0, 512), slice_mut::<i8>(arena, 512, 128)) };
+    OpAvgPool2DInt8::kernel(
+        input,
+        output,
+        4,
+        4,
+        32,
+        2,
+        2,
+        32,
+        2,
+        2,
+        2,
+        2,
+        0,
+        0,
+        1,
+        -128,
+        127,
+    )?;
+    // 3: RESHAPE
+    // the output shares the buffer of the input
+    // 4: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<i8>(arena, 512, 128), slice_mut::<i8>(arena, 0, 32)) };
+    OpFullyConnectedInt8::kernel(
+        input,
+        FilterData::Dense(&TENSOR_5),
+        &OP4_EFFECTIVE_BIAS,
+        output,
+        0,
+        -128,
+        32,
+        &OP4_MULTIPLIER,
+        &OP4_SHIFT,
+        128,
+        1,
+        -128,
+        127,
+    )?;
+    // 5: FULLY_CONNECTED
+    let (input, output) = unsafe { (slice::<i8>(arena, 0, 32), slice_mut::<i8>(arena, 676, 1)) };
+    OpFullyConnectedInt8::kernel(
+        input,
+        FilterData::Dense(&TENSOR_3),
+        &OP5_EFFECTIVE_BIAS,
+        output,
+        0,
+        3,
+        1,
+        &OP5_MULTIPLIER,
+        &OP5_SHIFT,
+        32,
+        1,
+        -128,
+        127,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use berrylite::kernel::micro_builtin_options::BLiteBuiltinOption::{self, *};
+use berrylite::kernel::micro_operator::BLiteOperator;
+use berrylite::kernel::utils::flexbuffer::FlexBufferRef;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_context::BLiteContext;
+use berrylite::micro_errors::BLiteError::{self, *};
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_node::BLiteNode;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::micro_tensor::BLiteTensor;
+use berrylite::tflite_schema_generated::tflite;
+use core::ptr::addr_of_mut;
+use flatbuffers::FlatBufferBuilder;
+
+const ARENA_SIZE: usize = 1024;
+static mut ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut UNREGISTERED_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut UNSUPPORTED_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+// The flexbuffer of {"factor": 2.0}
+const CUSTOM_OPTIONS: [u8; 32] = [
+    b'f', b'a', b'c', b't', b'o', b'r', 0, // key
+    1, 8, // keys vector: size and offset to "factor"
+    0, 0, 0, // padding
+    4, 0, 0, 0, // offset to the keys vector
+    1, 0, 0, 0, // byte width of the keys vector
+    1, 0, 0, 0, // size
+    0, 0, 0, 0x40, // 2.0
+    0x0e, // type: float
+    5, 0x26, 1, // root: offset to the values, type of the map and byte width
+];
+
+/// Builds a model of a single custom operator `custom_code` of `version`, whose input and output are [4] tensors
+fn build_model(custom_code: &str, version: i32) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let custom_code = fbb.create_string(custom_code);
+    let operator_code = tflite::OperatorCode::create(
+        &mut fbb,
+        &tflite::OperatorCodeArgs {
+            deprecated_builtin_code: tflite::BuiltinOperator::CUSTOM.0 as i8,
+            custom_code: Some(custom_code),
+            version,
+            builtin_code: tflite::BuiltinOperator::CUSTOM,
+        },
+    );
+    let operator_codes = fbb.create_vector(&[operator_code]);
+
+    let tensors = [0, 1].map(|buffer| {
+        let shape = fbb.create_vector(&[4]);
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                buffer,
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[1]);
+    let custom_options = fbb.create_vector(&CUSTOM_OPTIONS);
+    let operator = tflite::Operator::create(
+        &mut fbb,
+        &tflite::OperatorArgs {
+            opcode_index: 0,
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            custom_options: Some(custom_options),
+            ..Default::default()
+        },
+    );
+    let operators = fbb.create_vector(&[operator]);
+
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let buffers = [0, 1].map(|_| tflite::Buffer::create(&mut fbb, &Default::default()));
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+fn scale_eval<'a>(
+    _context: &BLiteContext,
+    tensors: &'a mut [BLiteTensor<'a, f32>],
+    node: &BLiteNode<'a>,
+    builtin_option: BLiteBuiltinOption<f32>,
+) -> Result<()> {
+    let CustomOptions { custom_options } = builtin_option else {
+        return Err(NotCompatibleOption);
+    };
+    let Some(factor) = FlexBufferRef::root(custom_options)?
+        .as_map()?
+        .get("factor")?
+    else {
+        return Err(NotFoundCustomOptions);
+    };
+    let factor = factor.as_f32()?;
+
+    let input = tensors[node.inputs[0] as usize]._t()?.borrow();
+    let mut output = tensors[node.outputs[0] as usize]._t()?.borrow_mut();
+    for (o, &i) in output.data.iter_mut().zip(input.data.iter()) {
+        *o = i * factor;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_custom_operator() -> Result<()> {
+    let buffer = build_model("Scale", 1);
+    let model = tflite::root_as_model(&buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut *addr_of_mut!(ARENA)) };
+    let mut op_resolver = BLiteOpResolver::<1, f32, _>::new();
+    op_resolver.add_op(BLiteOperator::custom("Scale", scale_eval))?;
+
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    interpreter.input.data.copy_from_slice(&[1., -2., 3., 0.5]);
+    interpreter.invoke()?;
+    assert_eq!(&[2., -4., 6., 1.], interpreter.output.data);
+    Ok(())
+}
+
+#[test]
+fn test_unregistered_custom_operator() {
+    let buffer = build_model("Unknown", 1);
+    let model = tflite::root_as_model(&buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut *addr_of_mut!(UNREGISTERED_ARENA)) };
+    let mut op_resolver = BLiteOpResolver::<1, f32, _>::new();
+    op_resolver
+        .add_op(BLiteOperator::custom("Scale", scale_eval))
+        .unwrap();
+
+    let result = BLiteInterpreter::new(&mut allocator, &op_resolver, &model);
+    assert!(matches!(result, Err(BLiteError::NotFoundCustomOperator)));
+}
+
+#[test]
+fn test_unsupported_custom_operator_version() {
+    let buffer = build_model("Scale", 3);
+    let model = tflite::root_as_model(&buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut *addr_of_mut!(UNSUPPORTED_ARENA)) };
+    let mut op_resolver = BLiteOpResolver::<1, f32, _>::new();
+    op_resolver
+        .add_op(BLiteOperator::custom("Scale", scale_eval).with_versions(1, 2))
+        .unwrap();
+
+    let result = BLiteInterpreter::new(&mut allocator, &op_resolver, &model);
+    assert!(matches!(
+        result,
+        Err(BLiteError::NotSupportedOperatorVersion(32, 3))
+    ));
+}
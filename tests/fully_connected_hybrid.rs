@@ -0,0 +1,163 @@
+use berrylite::kernel::micro_operator::f32::fully_connected_hybrid::OpFullyConnectedHybrid;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite;
+use core::mem::size_of;
+use flatbuffers::FlatBufferBuilder;
+
+const ARENA_SIZE: usize = 1024;
+static mut ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+const FILTER_SCALES: [f32; 2] = [1. / 64., 1. / 128.];
+const FILTER: [i8; 8] = [32, -64, 16, 127, -128, 0, 64, 8];
+const BIAS: [f32; 2] = [0.25, -0.5];
+
+/// Builds a model of a single FULLY_CONNECTED of the hybrid quantization,
+/// whose input is float [1, 4], filter is int8 [2, 4] quantized per channel,
+/// bias is float [2] and output is float [1, 2]
+fn build_model() -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let operator_code = tflite::OperatorCode::create(
+        &mut fbb,
+        &tflite::OperatorCodeArgs {
+            deprecated_builtin_code: tflite::BuiltinOperator::FULLY_CONNECTED.0 as i8,
+            custom_code: None,
+            version: 9,
+            builtin_code: tflite::BuiltinOperator::FULLY_CONNECTED,
+        },
+    );
+    let operator_codes = fbb.create_vector(&[operator_code]);
+
+    let tensor_args = [
+        (vec![1, 4], tflite::TensorType::FLOAT32, 0),
+        (vec![2, 4], tflite::TensorType::INT8, 1),
+        (vec![2], tflite::TensorType::FLOAT32, 2),
+        (vec![1, 2], tflite::TensorType::FLOAT32, 0),
+    ];
+    let tensors = tensor_args.map(|(shape, type_, buffer)| {
+        let shape = fbb.create_vector(&shape);
+        // only the filter is quantized
+        let quantization = (type_ == tflite::TensorType::INT8).then(|| {
+            let scale = fbb.create_vector(&FILTER_SCALES);
+            let zero_point = fbb.create_vector(&[0i64; 2]);
+            tflite::QuantizationParameters::create(
+                &mut fbb,
+                &tflite::QuantizationParametersArgs {
+                    scale: Some(scale),
+                    zero_point: Some(zero_point),
+                    quantized_dimension: 0,
+                    ..Default::default()
+                },
+            )
+        });
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                type_,
+                buffer,
+                quantization,
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[3]);
+    let op_inputs = fbb.create_vector(&[0, 1, 2]);
+    let builtin_options = tflite::FullyConnectedOptions::create(
+        &mut fbb,
+        &tflite::FullyConnectedOptionsArgs {
+            fused_activation_function: tflite::ActivationFunctionType::NONE,
+            ..Default::default()
+        },
+    );
+    let operator = tflite::Operator::create(
+        &mut fbb,
+        &tflite::OperatorArgs {
+            opcode_index: 0,
+            inputs: Some(op_inputs),
+            outputs: Some(outputs),
+            builtin_options_type: tflite::BuiltinOptions::FullyConnectedOptions,
+            builtin_options: Some(builtin_options.as_union_value()),
+            ..Default::default()
+        },
+    );
+    let operators = fbb.create_vector(&[operator]);
+
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let filter = fbb.create_vector(&FILTER.map(|v| v as u8));
+    // the bias is pushed as float values to be aligned, and then ended as a vector of bytes
+    fbb.start_vector::<f32>(BIAS.len());
+    for &v in BIAS.iter().rev() {
+        fbb.push(v);
+    }
+    let bias = fbb.end_vector::<u8>(BIAS.len() * size_of::<f32>());
+    let datas = [None, Some(filter), Some(bias)];
+    let buffers = datas.map(|data| {
+        tflite::Buffer::create(
+            &mut fbb,
+            &tflite::BufferArgs {
+                data,
+                ..Default::default()
+            },
+        )
+    });
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+#[test]
+fn test_fully_connected_hybrid() -> Result<()> {
+    let buffer = build_model();
+    let model = tflite::root_as_model(&buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
+    let mut op_resolver = BLiteOpResolver::<1, f32, _>::new();
+    op_resolver.add_op(OpFullyConnectedHybrid::fully_connected_hybrid())?;
+
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    let input = [0.5f32, -1., 2., 0.25];
+    interpreter.input.data.copy_from_slice(&input);
+    interpreter.invoke()?;
+
+    // the inputs are quantized with the scale of max(|input|) / 127
+    let tolerance = 2. / 127. * 4.;
+    for (out_d, &actual) in interpreter.output.data.iter().enumerate() {
+        let mut expected = BIAS[out_d];
+        for (d, &input_v) in input.iter().enumerate() {
+            expected += input_v * FILTER[out_d * 4 + d] as f32 * FILTER_SCALES[out_d];
+        }
+        assert!(
+            (expected - actual).abs() <= tolerance,
+            "{expected} != {actual}"
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,164 @@
+use berrylite::kernel::micro_operator::i16::fully_connected_i16::OpFullyConnectedInt16;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite;
+use core::mem::size_of;
+use flatbuffers::FlatBufferBuilder;
+
+const ARENA_SIZE: usize = 1024;
+static mut ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+const INPUT_SCALE: f32 = 1. / 512.;
+const FILTER_SCALE: f32 = 1. / 64.;
+const OUTPUT_SCALE: f32 = 1. / 256.;
+const FILTER: [i8; 8] = [32, -64, 16, 127, -128, 0, 64, 8];
+const BIAS: [i64; 2] = [16384, -32768];
+
+/// Builds a model of a single FULLY_CONNECTED of the int16x8 quantization,
+/// whose input is int16 [1, 4], filter is int8 [2, 4], bias is int64 [2] and output is int16 [1, 2]
+fn build_model() -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let operator_code = tflite::OperatorCode::create(
+        &mut fbb,
+        &tflite::OperatorCodeArgs {
+            deprecated_builtin_code: tflite::BuiltinOperator::FULLY_CONNECTED.0 as i8,
+            custom_code: None,
+            version: 7,
+            builtin_code: tflite::BuiltinOperator::FULLY_CONNECTED,
+        },
+    );
+    let operator_codes = fbb.create_vector(&[operator_code]);
+
+    let tensor_args = [
+        (vec![1, 4], tflite::TensorType::INT16, 0, INPUT_SCALE),
+        (vec![2, 4], tflite::TensorType::INT8, 1, FILTER_SCALE),
+        (
+            vec![2],
+            tflite::TensorType::INT64,
+            2,
+            INPUT_SCALE * FILTER_SCALE,
+        ),
+        (vec![1, 2], tflite::TensorType::INT16, 0, OUTPUT_SCALE),
+    ];
+    let tensors = tensor_args.map(|(shape, type_, buffer, scale)| {
+        let shape = fbb.create_vector(&shape);
+        let scale = fbb.create_vector(&[scale]);
+        let zero_point = fbb.create_vector(&[0i64]);
+        let quantization = tflite::QuantizationParameters::create(
+            &mut fbb,
+            &tflite::QuantizationParametersArgs {
+                scale: Some(scale),
+                zero_point: Some(zero_point),
+                ..Default::default()
+            },
+        );
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                type_,
+                buffer,
+                quantization: Some(quantization),
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[3]);
+    let op_inputs = fbb.create_vector(&[0, 1, 2]);
+    let builtin_options = tflite::FullyConnectedOptions::create(
+        &mut fbb,
+        &tflite::FullyConnectedOptionsArgs {
+            fused_activation_function: tflite::ActivationFunctionType::NONE,
+            ..Default::default()
+        },
+    );
+    let operator = tflite::Operator::create(
+        &mut fbb,
+        &tflite::OperatorArgs {
+            opcode_index: 0,
+            inputs: Some(op_inputs),
+            outputs: Some(outputs),
+            builtin_options_type: tflite::BuiltinOptions::FullyConnectedOptions,
+            builtin_options: Some(builtin_options.as_union_value()),
+            ..Default::default()
+        },
+    );
+    let operators = fbb.create_vector(&[operator]);
+
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let filter = fbb.create_vector(&FILTER.map(|v| v as u8));
+    // the bias is pushed as int64 values to be aligned, and then ended as a vector of bytes
+    fbb.start_vector::<i64>(BIAS.len());
+    for &v in BIAS.iter().rev() {
+        fbb.push(v);
+    }
+    let bias = fbb.end_vector::<u8>(BIAS.len() * size_of::<i64>());
+    let datas = [None, Some(filter), Some(bias)];
+    let buffers = datas.map(|data| {
+        tflite::Buffer::create(
+            &mut fbb,
+            &tflite::BufferArgs {
+                data,
+                ..Default::default()
+            },
+        )
+    });
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+#[test]
+fn test_fully_connected_int16x8() -> Result<()> {
+    let buffer = build_model();
+    let model = tflite::root_as_model(&buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
+    let mut op_resolver = BLiteOpResolver::<1, i16, _>::new();
+    op_resolver.add_op(OpFullyConnectedInt16::fully_connected_int16())?;
+
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    let input = [256i16, -512, 1024, 128];
+    interpreter.input.data.copy_from_slice(&input);
+    interpreter.invoke()?;
+
+    for (out_d, &output_v) in interpreter.output.data.iter().enumerate() {
+        let mut expected = BIAS[out_d] as f32 * INPUT_SCALE * FILTER_SCALE;
+        for (d, &input_v) in input.iter().enumerate() {
+            expected += input_v as f32 * INPUT_SCALE * FILTER[out_d * 4 + d] as f32 * FILTER_SCALE;
+        }
+        let actual = output_v as f32 * OUTPUT_SCALE;
+        assert!(
+            (expected - actual).abs() <= OUTPUT_SCALE,
+            "{expected} != {actual}"
+        );
+    }
+    Ok(())
+}
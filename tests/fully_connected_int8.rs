@@ -0,0 +1,237 @@
+use berrylite::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite;
+use core::mem::size_of;
+use core::ptr::addr_of_mut;
+use flatbuffers::FlatBufferBuilder;
+
+const ARENA_SIZE: usize = 1024;
+static mut PER_CHANNEL_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut DENSE_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut PACKED_INT4_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+const INPUT_SCALE: f32 = 1. / 32.;
+const INPUT_ZERO_POINT: i64 = 3;
+const OUTPUT_SCALE: f32 = 1. / 8.;
+const OUTPUT_ZERO_POINT: i64 = -5;
+const INPUT: [i8; 4] = [35, -29, 67, 127];
+const BIAS: [i32; 3] = [512, -8192, 4];
+
+/// Builds a model of a single FULLY_CONNECTED whose input is int8 [1, 4],
+/// filter is `filter_type` [3, 4] quantized per output channel by `filter_scales`,
+/// bias is int32 [3] and output is int8 [1, 3]
+fn build_model(
+    version: i32,
+    filter_type: tflite::TensorType,
+    filter: &[u8],
+    filter_scales: &[f32; 3],
+) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let operator_code = tflite::OperatorCode::create(
+        &mut fbb,
+        &tflite::OperatorCodeArgs {
+            deprecated_builtin_code: tflite::BuiltinOperator::FULLY_CONNECTED.0 as i8,
+            custom_code: None,
+            version,
+            builtin_code: tflite::BuiltinOperator::FULLY_CONNECTED,
+        },
+    );
+    let operator_codes = fbb.create_vector(&[operator_code]);
+
+    let bias_scales = filter_scales.map(|scale| INPUT_SCALE * scale);
+    let tensor_args = [
+        (
+            vec![1, 4],
+            tflite::TensorType::INT8,
+            0,
+            &[INPUT_SCALE][..],
+            &[INPUT_ZERO_POINT][..],
+        ),
+        (vec![3, 4], filter_type, 1, &filter_scales[..], &[0; 3][..]),
+        (
+            vec![3],
+            tflite::TensorType::INT32,
+            2,
+            &bias_scales[..],
+            &[0; 3][..],
+        ),
+        (
+            vec![1, 3],
+            tflite::TensorType::INT8,
+            0,
+            &[OUTPUT_SCALE][..],
+            &[OUTPUT_ZERO_POINT][..],
+        ),
+    ];
+    let tensors = tensor_args.map(|(shape, type_, buffer, scale, zero_point)| {
+        let shape = fbb.create_vector(&shape);
+        let scale = fbb.create_vector(scale);
+        let zero_point = fbb.create_vector(zero_point);
+        let quantization = tflite::QuantizationParameters::create(
+            &mut fbb,
+            &tflite::QuantizationParametersArgs {
+                scale: Some(scale),
+                zero_point: Some(zero_point),
+                quantized_dimension: 0,
+                ..Default::default()
+            },
+        );
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                type_,
+                buffer,
+                quantization: Some(quantization),
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[3]);
+    let op_inputs = fbb.create_vector(&[0, 1, 2]);
+    let builtin_options = tflite::FullyConnectedOptions::create(
+        &mut fbb,
+        &tflite::FullyConnectedOptionsArgs {
+            fused_activation_function: tflite::ActivationFunctionType::NONE,
+            ..Default::default()
+        },
+    );
+    let operator = tflite::Operator::create(
+        &mut fbb,
+        &tflite::OperatorArgs {
+            opcode_index: 0,
+            inputs: Some(op_inputs),
+            outputs: Some(outputs),
+            builtin_options_type: tflite::BuiltinOptions::FullyConnectedOptions,
+            builtin_options: Some(builtin_options.as_union_value()),
+            ..Default::default()
+        },
+    );
+    let operators = fbb.create_vector(&[operator]);
+
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let filter = fbb.create_vector(filter);
+    // the bias is pushed as int32 values to be aligned, and then ended as a vector of bytes
+    fbb.start_vector::<i32>(BIAS.len());
+    for &v in BIAS.iter().rev() {
+        fbb.push(v);
+    }
+    let bias = fbb.end_vector::<u8>(BIAS.len() * size_of::<i32>());
+    let datas = [None, Some(filter), Some(bias)];
+    let buffers = datas.map(|data| {
+        tflite::Buffer::create(
+            &mut fbb,
+            &tflite::BufferArgs {
+                data,
+                ..Default::default()
+            },
+        )
+    });
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+fn predict(arena: &'static mut [u8], buffer: &[u8]) -> Result<Vec<i8>> {
+    let model = tflite::root_as_model(buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(arena) };
+    let mut op_resolver = BLiteOpResolver::<1, i8, _>::new();
+    op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8())?;
+
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    interpreter.input.data.copy_from_slice(&INPUT);
+    interpreter.invoke()?;
+    Ok(interpreter.output.data.to_vec())
+}
+
+#[test]
+fn test_fully_connected_int8_per_channel() -> Result<()> {
+    // the converter marks the per-channel quantized FULLY_CONNECTED as version 12
+    let filter: [i8; 12] = [
+        32, -64, 16, 127, //
+        -128, 0, 64, 8, //
+        5, -7, 11, -13,
+    ];
+    let filter_scales = [1. / 64., 1. / 512., 1. / 16.];
+    let buffer = build_model(
+        12,
+        tflite::TensorType::INT8,
+        &filter.map(|v| v as u8),
+        &filter_scales,
+    );
+    let output = predict(unsafe { &mut *addr_of_mut!(PER_CHANNEL_ARENA) }, &buffer)?;
+
+    for (out_d, &output_v) in output.iter().enumerate() {
+        let mut expected = BIAS[out_d] as f32 * INPUT_SCALE * filter_scales[out_d];
+        for (d, &input_v) in INPUT.iter().enumerate() {
+            expected += (input_v as i64 - INPUT_ZERO_POINT) as f32
+                * INPUT_SCALE
+                * filter[out_d * 4 + d] as f32
+                * filter_scales[out_d];
+        }
+        let actual = (output_v as i64 - OUTPUT_ZERO_POINT) as f32 * OUTPUT_SCALE;
+        assert!(
+            (expected - actual).abs() <= OUTPUT_SCALE,
+            "{expected} != {actual}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_fully_connected_int8_packed_int4() -> Result<()> {
+    // the converter marks FULLY_CONNECTED with the int4 filter as version 10
+    let filter: [i8; 12] = [
+        1, -2, 7, -8, //
+        0, 3, -5, 4, //
+        -1, 6, 2, -7,
+    ];
+    // two values per byte with the low nibble first
+    let packed: [u8; 6] =
+        core::array::from_fn(|i| (filter[2 * i] as u8 & 0x0f) | ((filter[2 * i + 1] as u8) << 4));
+    let filter_scales = [1. / 4., 1. / 8., 1. / 2.];
+
+    let dense = build_model(
+        10,
+        tflite::TensorType::INT8,
+        &filter.map(|v| v as u8),
+        &filter_scales,
+    );
+    let packed_int4 = build_model(10, tflite::TensorType::INT4, &packed, &filter_scales);
+    let expected = predict(unsafe { &mut *addr_of_mut!(DENSE_ARENA) }, &dense)?;
+    let output = predict(
+        unsafe { &mut *addr_of_mut!(PACKED_INT4_ARENA) },
+        &packed_int4,
+    )?;
+    assert_eq!(expected, output);
+    Ok(())
+}
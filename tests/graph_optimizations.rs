@@ -0,0 +1,456 @@
+use berrylite::kernel::micro_builtin_options::BLiteBuiltinOption::{self, *};
+use berrylite::kernel::micro_operator::f32::{
+    conv2d::OpConv2D, depthwise_conv2d::OpDepthWiseConv2D, fully_connected::OpFullyConnected,
+    max_pool2d::OpMaxPool2D, relu::OpRelu, reshape::OpReshape, softmax::OpSoftMax,
+};
+use berrylite::kernel::micro_operator::generic::pad::OpPad;
+use berrylite::kernel::micro_operator::i8::{
+    avg_pool2d_i8::OpAvgPool2DInt8, conv2d_i8::OpConv2DInt8,
+    depthwise_conv2d_i8::OpDepthWiseConv2DInt8, reshape_i8::OpReshapeInt8,
+    softmax_i8::OpSoftMaxInt8,
+};
+use berrylite::kernel::micro_operator::BLiteOperator;
+use berrylite::micro_allocator::{ArenaAllocator, BumpArenaAllocator};
+use berrylite::micro_errors::Result;
+use berrylite::micro_graph::BLiteGraphOptions;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::micro_optimizer::BLiteOptimizations;
+use berrylite::micro_registration::BLiteRegistration;
+use berrylite::micro_tensor::BLiteTensor;
+use berrylite::tflite_schema_generated::tflite::{self, BuiltinOperator};
+use core::mem::size_of;
+use core::ptr::addr_of_mut;
+use flatbuffers::{FlatBufferBuilder, Push, WIPOffset};
+
+const PERSON_DETECT: &[u8; 300568] = include_bytes!("../resources/models/person_detect.tflite");
+const MNIST_DEPTHWISE_CNN: &[u8; 419572] =
+    include_bytes!("../resources/models/mnist_depthwise_cnn.tflite");
+
+const SMALL_ARENA_SIZE: usize = 16 * 1024;
+static mut ALL_ARENA: [u8; SMALL_ARENA_SIZE] = [0; SMALL_ARENA_SIZE];
+static mut EACH_ARENA: [u8; SMALL_ARENA_SIZE] = [0; SMALL_ARENA_SIZE];
+static mut PAD_ARENA: [u8; SMALL_ARENA_SIZE] = [0; SMALL_ARENA_SIZE];
+const INT8_ARENA_SIZE: usize = 136 * 1024;
+static mut INT8_ARENA: [u8; INT8_ARENA_SIZE] = [0; INT8_ARENA_SIZE];
+const FLOAT_ARENA_SIZE: usize = 256 * 1024;
+static mut FLOAT_ARENA: [u8; FLOAT_ARENA_SIZE] = [0; FLOAT_ARENA_SIZE];
+
+fn buffer<'a, V: Push + Copy>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    values: &[V],
+) -> WIPOffset<flatbuffers::Vector<'a, u8>> {
+    // the values are pushed as themselves to be aligned, and then ended as a vector of bytes
+    fbb.start_vector::<V>(values.len());
+    for &v in values.iter().rev() {
+        fbb.push(v);
+    }
+    fbb.end_vector::<u8>(values.len() * size_of::<V>())
+}
+
+/// Builds a model of
+/// PAD [1, 6, 6, 1] -> CONV_2D 3x3 VALID -> RELU6 -> RESHAPE [1, 72] -> FULLY_CONNECTED [1, 3],
+/// whose CONV_2D is also read by a RELU whose output is not used
+fn build_model() -> Vec<u8> {
+    build_model_with_pad(BuiltinOperator::PAD, None)
+}
+
+/// Builds the model of `build_model` whose `pad` takes `pad_value` as the third input
+fn build_model_with_pad(pad: BuiltinOperator, pad_value: Option<f32>) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let ops = [
+        pad,
+        BuiltinOperator::CONV_2D,
+        BuiltinOperator::RELU,
+        BuiltinOperator::RELU6,
+        BuiltinOperator::RESHAPE,
+        BuiltinOperator::FULLY_CONNECTED,
+    ];
+    let operator_codes = ops.map(|op| {
+        tflite::OperatorCode::create(
+            &mut fbb,
+            &tflite::OperatorCodeArgs {
+                deprecated_builtin_code: op.0 as i8,
+                custom_code: None,
+                version: 1,
+                builtin_code: op,
+            },
+        )
+    });
+    let operator_codes = fbb.create_vector(&operator_codes);
+
+    let tensor_args = [
+        (vec![1, 6, 6, 1], tflite::TensorType::FLOAT32, 0), // 0: input
+        (vec![4, 2], tflite::TensorType::INT32, 1),         // 1: paddings
+        (vec![1, 8, 8, 1], tflite::TensorType::FLOAT32, 0), // 2: padded
+        (vec![2, 3, 3, 1], tflite::TensorType::FLOAT32, 2), // 3: filter
+        (vec![2], tflite::TensorType::FLOAT32, 3),          // 4: bias
+        (vec![1, 6, 6, 2], tflite::TensorType::FLOAT32, 0), // 5: conv
+        (vec![1, 6, 6, 2], tflite::TensorType::FLOAT32, 0), // 6: relu6
+        (vec![2], tflite::TensorType::INT32, 4),            // 7: new shape
+        (vec![1, 72], tflite::TensorType::FLOAT32, 0),      // 8: reshape
+        (vec![3, 72], tflite::TensorType::FLOAT32, 5),      // 9: weights
+        (vec![3], tflite::TensorType::FLOAT32, 6),          // 10: bias
+        (vec![1, 3], tflite::TensorType::FLOAT32, 0),       // 11: output
+        (vec![1, 6, 6, 2], tflite::TensorType::FLOAT32, 0), // 12: unused relu
+        (vec![1], tflite::TensorType::FLOAT32, 7),          // 13: pad value
+    ];
+    let tensors = tensor_args.map(|(shape, type_, buffer)| {
+        let shape = fbb.create_vector(&shape);
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                type_,
+                buffer,
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let conv_options = tflite::Conv2DOptions::create(
+        &mut fbb,
+        &tflite::Conv2DOptionsArgs {
+            padding: tflite::Padding::VALID,
+            stride_w: 1,
+            stride_h: 1,
+            dilation_w_factor: 1,
+            dilation_h_factor: 1,
+            ..Default::default()
+        },
+    )
+    .as_union_value();
+    let fully_connected_options = tflite::FullyConnectedOptions::create(
+        &mut fbb,
+        &tflite::FullyConnectedOptionsArgs::default(),
+    )
+    .as_union_value();
+    let pad_inputs = match pad_value {
+        Some(_) => vec![0, 1, 13],
+        None => vec![0, 1],
+    };
+    let operator_args = [
+        (0, pad_inputs, 2, None),
+        (
+            1,
+            vec![2, 3, 4],
+            5,
+            Some((tflite::BuiltinOptions::Conv2DOptions, conv_options)),
+        ),
+        (2, vec![5], 12, None),
+        (3, vec![5], 6, None),
+        (4, vec![6, 7], 8, None),
+        (
+            5,
+            vec![8, 9, 10],
+            11,
+            Some((
+                tflite::BuiltinOptions::FullyConnectedOptions,
+                fully_connected_options,
+            )),
+        ),
+    ];
+    let operators = operator_args.map(|(opcode_index, inputs, output, options)| {
+        let inputs = fbb.create_vector(&inputs);
+        let outputs = fbb.create_vector(&[output]);
+        let (builtin_options_type, builtin_options) = match options {
+            Some((options_type, options)) => (options_type, Some(options)),
+            None => (tflite::BuiltinOptions::NONE, None),
+        };
+        tflite::Operator::create(
+            &mut fbb,
+            &tflite::OperatorArgs {
+                opcode_index,
+                inputs: Some(inputs),
+                outputs: Some(outputs),
+                builtin_options_type,
+                builtin_options,
+                ..Default::default()
+            },
+        )
+    });
+    let operators = fbb.create_vector(&operators);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[11]);
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let filter: Vec<f32> = (0..18).map(|i| i as f32 * 0.3 - 2.).collect();
+    let weights: Vec<f32> = (0..216)
+        .map(|i| ((i * 7) % 11) as f32 * 0.1 - 0.5)
+        .collect();
+    let datas = [
+        None,
+        Some(buffer(&mut fbb, &[0, 0, 1, 1, 1, 1, 0, 0i32])),
+        Some(buffer(&mut fbb, &filter)),
+        Some(buffer(&mut fbb, &[0.5, -1f32])),
+        Some(buffer(&mut fbb, &[1, 72i32])),
+        Some(buffer(&mut fbb, &weights)),
+        Some(buffer(&mut fbb, &[0.25, -0.5, 1f32])),
+        Some(buffer(&mut fbb, &[pad_value.unwrap_or_default()])),
+    ];
+    let buffers = datas.map(|data| {
+        tflite::Buffer::create(
+            &mut fbb,
+            &tflite::BufferArgs {
+                data,
+                ..Default::default()
+            },
+        )
+    });
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+/// Returns the output and the used size of the arena
+fn predict_small(
+    arena: &'static mut [u8],
+    buffer: &[u8],
+    optimizations: BLiteOptimizations,
+    input: &[f32],
+) -> Result<(Vec<f32>, usize)> {
+    let model = tflite::root_as_model(buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(arena) };
+
+    let mut op_resolver = BLiteOpResolver::<6, f32, _>::new();
+    op_resolver.add_op(OpPad::pad())?;
+    op_resolver.add_op(OpConv2D::conv2d())?;
+    op_resolver.add_op(OpRelu::relu())?;
+    op_resolver.add_op(OpRelu::relu6())?;
+    op_resolver.add_op(OpReshape::reshape())?;
+    op_resolver.add_op(OpFullyConnected::fully_connected())?;
+
+    let options = BLiteGraphOptions {
+        optimizations,
+        ..Default::default()
+    };
+    let interpreter =
+        BLiteInterpreter::new_with_options(&mut allocator, &op_resolver, &model, options)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    let (_, used) = allocator.description()?;
+    Ok((interpreter.output.data.to_vec(), used))
+}
+
+fn pad_value_parser<'a>(
+    _allocator: &mut BumpArenaAllocator,
+    op: tflite::Operator,
+    tensors: &mut [BLiteTensor<'a, f32>],
+) -> Result<BLiteBuiltinOption<'a, f32>> {
+    let idx = op.inputs().unwrap().get(2) as usize;
+    let constant_value = tensors[idx]._t()?.borrow().data[0];
+    Ok(PadOptions { constant_value })
+}
+
+/// Returns the output and the used size of the arena of the model of `build_model_with_pad`,
+/// whose PAD and PADV2 fill the borders with the pad value of the third input
+fn predict_padded(
+    buffer: &[u8],
+    optimizations: BLiteOptimizations,
+    input: &[f32],
+) -> Result<(Vec<f32>, usize)> {
+    let model = tflite::root_as_model(buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut *addr_of_mut!(PAD_ARENA)) };
+
+    let mut op_resolver = BLiteOpResolver::<7, f32, _>::new();
+    for pad in [BuiltinOperator::PAD, BuiltinOperator::PADV2] {
+        let registration = BLiteRegistration::new(pad.0, OpPad::eval::<f32>, NotInitialize);
+        op_resolver.add_op(BLiteOperator::new(registration, pad_value_parser, 1, 2))?;
+    }
+    op_resolver.add_op(OpConv2D::conv2d())?;
+    op_resolver.add_op(OpRelu::relu())?;
+    op_resolver.add_op(OpRelu::relu6())?;
+    op_resolver.add_op(OpReshape::reshape())?;
+    op_resolver.add_op(OpFullyConnected::fully_connected())?;
+
+    let options = BLiteGraphOptions {
+        optimizations,
+        ..Default::default()
+    };
+    let interpreter =
+        BLiteInterpreter::new_with_options(&mut allocator, &op_resolver, &model, options)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    let (_, used) = allocator.description()?;
+    Ok((interpreter.output.data.to_vec(), used))
+}
+
+fn predict_int8(optimizations: BLiteOptimizations, input: &[i8]) -> Result<Vec<i8>> {
+    let model = tflite::root_as_model(PERSON_DETECT).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut INT8_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<5, i8, _>::new();
+    op_resolver.add_op(OpReshapeInt8::reshape_int8())?;
+    op_resolver.add_op(OpConv2DInt8::conv2d_int8())?;
+    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8())?;
+    op_resolver.add_op(OpSoftMaxInt8::softmax_int8())?;
+    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8())?;
+
+    let options = BLiteGraphOptions {
+        optimizations,
+        ..Default::default()
+    };
+    let interpreter =
+        BLiteInterpreter::new_with_options(&mut allocator, &op_resolver, &model, options)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    Ok(interpreter.output.data.to_vec())
+}
+
+fn predict_float(optimizations: BLiteOptimizations, input: &[f32]) -> Result<Vec<f32>> {
+    let model = tflite::root_as_model(MNIST_DEPTHWISE_CNN).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut FLOAT_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<6, f32, _>::new();
+    op_resolver.add_op(OpFullyConnected::fully_connected())?;
+    op_resolver.add_op(OpReshape::reshape())?;
+    op_resolver.add_op(OpConv2D::conv2d())?;
+    op_resolver.add_op(OpMaxPool2D::max_pool2d())?;
+    op_resolver.add_op(OpSoftMax::softmax())?;
+    op_resolver.add_op(OpDepthWiseConv2D::depthwise_conv2d())?;
+
+    let options = BLiteGraphOptions {
+        optimizations,
+        ..Default::default()
+    };
+    let interpreter =
+        BLiteInterpreter::new_with_options(&mut allocator, &op_resolver, &model, options)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    Ok(interpreter.output.data.to_vec())
+}
+
+#[test]
+fn test_optimizations_match_unoptimized_graph() {
+    let buffer = build_model();
+    for x in [0., 0.5, 1., 2., 3.] {
+        let input: Vec<f32> = (0..36).map(|i| (x + i as f32 * 0.1).sin() * 4.).collect();
+        let (expected, unoptimized_used) = predict_small(
+            unsafe { &mut ALL_ARENA },
+            &buffer,
+            BLiteOptimizations::none(),
+            &input,
+        )
+        .unwrap();
+        let (output, optimized_used) = predict_small(
+            unsafe { &mut ALL_ARENA },
+            &buffer,
+            BLiteOptimizations::all(),
+            &input,
+        )
+        .unwrap();
+        assert_eq!(expected, output, "x: {x}");
+        // the padded, activated and reshaped tensors are not planned
+        assert!(
+            optimized_used < unoptimized_used,
+            "{optimized_used} >= {unoptimized_used}"
+        );
+    }
+}
+
+#[test]
+fn test_each_optimization_matches_unoptimized_graph() {
+    let buffer = build_model();
+    let input: Vec<f32> = (0..36).map(|i| (i as f32 * 0.3).cos() * 5.).collect();
+    let (expected, _) = predict_small(
+        unsafe { &mut EACH_ARENA },
+        &buffer,
+        BLiteOptimizations::none(),
+        &input,
+    )
+    .unwrap();
+    let none = BLiteOptimizations::none();
+    for optimizations in [
+        BLiteOptimizations {
+            elide_reshapes: true,
+            ..none
+        },
+        BLiteOptimizations {
+            fold_pads: true,
+            ..none
+        },
+        BLiteOptimizations {
+            fuse_activations: true,
+            ..none
+        },
+        BLiteOptimizations {
+            remove_unused_nodes: true,
+            ..none
+        },
+    ] {
+        let (output, _) =
+            predict_small(unsafe { &mut EACH_ARENA }, &buffer, optimizations, &input).unwrap();
+        assert_eq!(expected, output, "{optimizations:?}");
+    }
+}
+
+#[test]
+fn test_only_pads_of_zero_are_folded() {
+    let input: Vec<f32> = (0..36).map(|i| (i as f32 * 0.7).sin() * 3.).collect();
+    let fold_pads = BLiteOptimizations {
+        fold_pads: true,
+        ..BLiteOptimizations::none()
+    };
+    for (pad, pad_value) in [
+        (BuiltinOperator::PAD, 1.5),
+        (BuiltinOperator::PADV2, -2.),
+        (BuiltinOperator::PADV2, 0.),
+    ] {
+        let buffer = build_model_with_pad(pad, Some(pad_value));
+        let (expected, unoptimized_used) =
+            predict_padded(&buffer, BLiteOptimizations::none(), &input).unwrap();
+        let (output, optimized_used) = predict_padded(&buffer, fold_pads, &input).unwrap();
+        assert_eq!(expected, output, "{pad:?}: {pad_value}");
+        // the padded tensor is not planned only if the pad is folded
+        assert_eq!(
+            pad_value == 0.,
+            optimized_used < unoptimized_used,
+            "{pad:?}: {pad_value}"
+        );
+    }
+}
+
+#[test]
+fn test_optimizations_of_models_are_bit_exact() {
+    let image = image::open("./resources/dataset/person_detection/person0.jpg")
+        .unwrap()
+        .into_luma8();
+    let input: Vec<i8> = image.pixels().map(|p| p.0[0] as i8).collect();
+    let expected = predict_int8(BLiteOptimizations::none(), &input).unwrap();
+    let output = predict_int8(BLiteOptimizations::all(), &input).unwrap();
+    assert_eq!(expected, output);
+
+    for digit in [0, 3, 7] {
+        let image = image::open(format!("./resources/dataset/mnist/{digit}.jpg"))
+            .unwrap()
+            .into_luma8();
+        let input: Vec<f32> = image.pixels().map(|p| p.0[0] as f32 / 255.).collect();
+        let expected = predict_float(BLiteOptimizations::none(), &input).unwrap();
+        let output = predict_float(BLiteOptimizations::all(), &input).unwrap();
+        assert_eq!(expected, output, "digit: {digit}");
+    }
+}
@@ -0,0 +1,435 @@
+use berrylite::kernel::micro_operator::f32::{
+    add::OpAdd, fully_connected::OpFullyConnected, relu::OpRelu, reshape::OpReshape, tanh::OpTanh,
+};
+use berrylite::kernel::micro_operator::BLiteOperator;
+use berrylite::micro_allocator::{ArenaAllocator, BumpArenaAllocator};
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::micro_registration::BLiteInPlace;
+use berrylite::tflite_schema_generated::tflite::{self, BuiltinOperator};
+use core::mem::size_of_val;
+use core::ptr::addr_of_mut;
+use flatbuffers::{FlatBufferBuilder, Push, WIPOffset};
+
+const ARENA_SIZE: usize = 8 * 1024;
+static mut IN_PLACE_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut NOT_IN_PLACE_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut ALIASED_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut ADD_TO_ITSELF_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+fn buffer<'a, V: Push + Copy>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    values: &[V],
+) -> WIPOffset<flatbuffers::Vector<'a, u8>> {
+    // the values are pushed as themselves to be aligned, and then ended as a vector of bytes
+    fbb.start_vector::<V>(values.len());
+    for &v in values.iter().rev() {
+        fbb.push(v);
+    }
+    fbb.end_vector::<u8>(size_of_val(values))
+}
+
+fn weights() -> (Vec<f32>, Vec<f32>, Vec<f32>, Vec<f32>) {
+    let weights1 = (0..256)
+        .map(|i| ((i * 5) % 13) as f32 * 0.1 - 0.6)
+        .collect();
+    let bias1 = (0..16).map(|i| i as f32 * 0.05 - 0.4).collect();
+    let weights2 = (0..64).map(|i| ((i * 3) % 7) as f32 * 0.2 - 0.6).collect();
+    (weights1, bias1, weights2, vec![0.1, -0.2, 0.3, -0.4])
+}
+
+fn fully_connected(input: &[f32], weights: &[f32], bias: &[f32]) -> Vec<f32> {
+    bias.iter()
+        .zip(weights.chunks(input.len()))
+        .map(|(b, w)| b + w.iter().zip(input).map(|(w, x)| w * x).sum::<f32>())
+        .collect()
+}
+
+/// Computes the model of `build_model` without the interpreter
+fn reference(input: &[f32]) -> Vec<f32> {
+    let (weights1, bias1, weights2, bias2) = weights();
+    let hidden = fully_connected(input, &weights1, &bias1);
+    let added: Vec<f32> = hidden.iter().map(|&x| x.max(0.).tanh() + x).collect();
+    fully_connected(&added, &weights2, &bias2)
+}
+
+/// Builds a model of
+/// FULLY_CONNECTED [1, 16] -> RELU -> RESHAPE [4, 4] -> TANH -> RESHAPE [1, 16]
+/// -> ADD -> FULLY_CONNECTED [1, 4], whose ADD reads the first FULLY_CONNECTED again
+fn build_model() -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let ops = [
+        BuiltinOperator::FULLY_CONNECTED,
+        BuiltinOperator::RELU,
+        BuiltinOperator::RESHAPE,
+        BuiltinOperator::TANH,
+        BuiltinOperator::ADD,
+    ];
+    let operator_codes = ops.map(|op| {
+        tflite::OperatorCode::create(
+            &mut fbb,
+            &tflite::OperatorCodeArgs {
+                deprecated_builtin_code: op.0 as i8,
+                custom_code: None,
+                version: 1,
+                builtin_code: op,
+            },
+        )
+    });
+    let operator_codes = fbb.create_vector(&operator_codes);
+
+    let tensor_args = [
+        (vec![1, 16], tflite::TensorType::FLOAT32, 0), // 0: input
+        (vec![16, 16], tflite::TensorType::FLOAT32, 1), // 1: weights
+        (vec![16], tflite::TensorType::FLOAT32, 2),    // 2: bias
+        (vec![1, 16], tflite::TensorType::FLOAT32, 0), // 3: fully connected
+        (vec![1, 16], tflite::TensorType::FLOAT32, 0), // 4: relu
+        (vec![2], tflite::TensorType::INT32, 3),       // 5: new shape
+        (vec![4, 4], tflite::TensorType::FLOAT32, 0),  // 6: reshape
+        (vec![4, 4], tflite::TensorType::FLOAT32, 0),  // 7: tanh
+        (vec![2], tflite::TensorType::INT32, 4),       // 8: new shape
+        (vec![1, 16], tflite::TensorType::FLOAT32, 0), // 9: reshape
+        (vec![1, 16], tflite::TensorType::FLOAT32, 0), // 10: add
+        (vec![4, 16], tflite::TensorType::FLOAT32, 5), // 11: weights
+        (vec![4], tflite::TensorType::FLOAT32, 6),     // 12: bias
+        (vec![1, 4], tflite::TensorType::FLOAT32, 0),  // 13: output
+    ];
+    let tensors = tensor_args.map(|(shape, type_, buffer)| {
+        let shape = fbb.create_vector(&shape);
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                type_,
+                buffer,
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let fully_connected_options = tflite::FullyConnectedOptions::create(
+        &mut fbb,
+        &tflite::FullyConnectedOptionsArgs::default(),
+    )
+    .as_union_value();
+    let add_options =
+        tflite::AddOptions::create(&mut fbb, &tflite::AddOptionsArgs::default()).as_union_value();
+    let fully_connected = Some((
+        tflite::BuiltinOptions::FullyConnectedOptions,
+        fully_connected_options,
+    ));
+    let operator_args = [
+        (0, vec![0, 1, 2], 3, fully_connected),
+        (1, vec![3], 4, None),
+        (2, vec![4, 5], 6, None),
+        (3, vec![6], 7, None),
+        (2, vec![7, 8], 9, None),
+        (
+            4,
+            vec![9, 3],
+            10,
+            Some((tflite::BuiltinOptions::AddOptions, add_options)),
+        ),
+        (0, vec![10, 11, 12], 13, fully_connected),
+    ];
+    let operators = operator_args.map(|(opcode_index, inputs, output, options)| {
+        let inputs = fbb.create_vector(&inputs);
+        let outputs = fbb.create_vector(&[output]);
+        let (builtin_options_type, builtin_options) = match options {
+            Some((options_type, options)) => (options_type, Some(options)),
+            None => (tflite::BuiltinOptions::NONE, None),
+        };
+        tflite::Operator::create(
+            &mut fbb,
+            &tflite::OperatorArgs {
+                opcode_index,
+                inputs: Some(inputs),
+                outputs: Some(outputs),
+                builtin_options_type,
+                builtin_options,
+                ..Default::default()
+            },
+        )
+    });
+    let operators = fbb.create_vector(&operators);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[13]);
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let (weights1, bias1, weights2, bias2) = weights();
+    let datas = [
+        None,
+        Some(buffer(&mut fbb, &weights1)),
+        Some(buffer(&mut fbb, &bias1)),
+        Some(buffer(&mut fbb, &[4, 4i32])),
+        Some(buffer(&mut fbb, &[1, 16i32])),
+        Some(buffer(&mut fbb, &weights2)),
+        Some(buffer(&mut fbb, &bias2)),
+    ];
+    let buffers = datas.map(|data| {
+        tflite::Buffer::create(
+            &mut fbb,
+            &tflite::BufferArgs {
+                data,
+                ..Default::default()
+            },
+        )
+    });
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+/// Builds a model of RELU [1, 16] -> ADD -> RELU, whose ADD adds the output of RELU to itself
+fn build_add_to_itself_model() -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let ops = [BuiltinOperator::RELU, BuiltinOperator::ADD];
+    let operator_codes = ops.map(|op| {
+        tflite::OperatorCode::create(
+            &mut fbb,
+            &tflite::OperatorCodeArgs {
+                deprecated_builtin_code: op.0 as i8,
+                custom_code: None,
+                version: 1,
+                builtin_code: op,
+            },
+        )
+    });
+    let operator_codes = fbb.create_vector(&operator_codes);
+
+    let tensors = [0; 4].map(|_| {
+        let shape = fbb.create_vector(&[1, 16]);
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                type_: tflite::TensorType::FLOAT32,
+                buffer: 0,
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let add_options =
+        tflite::AddOptions::create(&mut fbb, &tflite::AddOptionsArgs::default()).as_union_value();
+    let operator_args = [
+        (0, vec![0], 1, None),
+        (
+            1,
+            vec![1, 1],
+            2,
+            Some((tflite::BuiltinOptions::AddOptions, add_options)),
+        ),
+        (0, vec![2], 3, None),
+    ];
+    let operators = operator_args.map(|(opcode_index, inputs, output, options)| {
+        let inputs = fbb.create_vector(&inputs);
+        let outputs = fbb.create_vector(&[output]);
+        let (builtin_options_type, builtin_options) = match options {
+            Some((options_type, options)) => (options_type, Some(options)),
+            None => (tflite::BuiltinOptions::NONE, None),
+        };
+        tflite::Operator::create(
+            &mut fbb,
+            &tflite::OperatorArgs {
+                opcode_index,
+                inputs: Some(inputs),
+                outputs: Some(outputs),
+                builtin_options_type,
+                builtin_options,
+                ..Default::default()
+            },
+        )
+    });
+    let operators = fbb.create_vector(&operators);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[3]);
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let buffers = [tflite::Buffer::create(
+        &mut fbb,
+        &tflite::BufferArgs::default(),
+    )];
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+fn in_place_or_not<'a>(
+    in_place: bool,
+    op: BLiteOperator<'a, f32, BumpArenaAllocator>,
+) -> BLiteOperator<'a, f32, BumpArenaAllocator> {
+    if in_place {
+        op
+    } else {
+        op.with_in_place(BLiteInPlace::NotInPlace)
+    }
+}
+
+fn input(x: f32) -> Vec<f32> {
+    (0..16).map(|i| (x + i as f32 * 0.4).sin() * 2.).collect()
+}
+
+/// Returns the output and the used size of the arena,
+/// whose operators run in place unless `in_place` is false
+fn predict(
+    arena: &'static mut [u8],
+    buffer: &[u8],
+    in_place: bool,
+    input: &[f32],
+) -> Result<(Vec<f32>, usize)> {
+    let model = tflite::root_as_model(buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(arena) };
+
+    let mut op_resolver = BLiteOpResolver::<5, f32, _>::new();
+    op_resolver.add_op(in_place_or_not(
+        in_place,
+        OpFullyConnected::fully_connected(),
+    ))?;
+    op_resolver.add_op(in_place_or_not(in_place, OpRelu::relu()))?;
+    op_resolver.add_op(in_place_or_not(in_place, OpReshape::reshape()))?;
+    op_resolver.add_op(in_place_or_not(in_place, OpTanh::tanh()))?;
+    op_resolver.add_op(in_place_or_not(in_place, OpAdd::add()))?;
+
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    let (_, used) = allocator.description()?;
+    Ok((interpreter.output.data.to_vec(), used))
+}
+
+#[test]
+fn test_in_place_matches_separate_buffers() {
+    let buffer = build_model();
+    for x in [0., 0.5, 1., 2., 3.] {
+        let input = input(x);
+        let (expected, not_in_place_used) = predict(
+            unsafe { &mut *addr_of_mut!(NOT_IN_PLACE_ARENA) },
+            &buffer,
+            false,
+            &input,
+        )
+        .unwrap();
+        let (output, in_place_used) = predict(
+            unsafe { &mut *addr_of_mut!(IN_PLACE_ARENA) },
+            &buffer,
+            true,
+            &input,
+        )
+        .unwrap();
+        assert_eq!(expected, output, "x: {x}");
+        // RELU, the RESHAPEs, TANH and ADD share the buffers of their inputs
+        assert!(
+            in_place_used < not_in_place_used,
+            "{in_place_used} >= {not_in_place_used}"
+        );
+    }
+}
+
+#[test]
+fn test_aliased_add_and_tanh() {
+    let buffer = build_model();
+    let model = tflite::root_as_model(&buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut *addr_of_mut!(ALIASED_ARENA)) };
+
+    let mut op_resolver = BLiteOpResolver::<5, f32, _>::new();
+    op_resolver
+        .add_op(OpFullyConnected::fully_connected())
+        .unwrap();
+    op_resolver.add_op(OpRelu::relu()).unwrap();
+    op_resolver.add_op(OpReshape::reshape()).unwrap();
+    op_resolver.add_op(OpTanh::tanh()).unwrap();
+    op_resolver.add_op(OpAdd::add()).unwrap();
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model).unwrap();
+
+    // TANH overwrites RELU through the RESHAPE, and ADD overwrites TANH through the RESHAPE,
+    // while RELU does not overwrite FULLY_CONNECTED read by ADD
+    let plan = interpreter.memory_plan();
+    for idx in [6, 7, 9, 10] {
+        assert_eq!(plan[idx], plan[4], "tensor {idx}");
+    }
+    assert_ne!(plan[3], plan[4]);
+
+    for x in [0., 1.5, 3.] {
+        let input = input(x);
+        interpreter.input.data.copy_from_slice(&input);
+        interpreter.invoke().unwrap();
+        for (output, expected) in interpreter.output.data.iter().zip(reference(&input)) {
+            assert!((output - expected).abs() < 1e-4, "{output} != {expected}");
+        }
+    }
+}
+
+#[test]
+fn test_add_to_itself_does_not_run_in_place() {
+    let buffer = build_add_to_itself_model();
+    let model = tflite::root_as_model(&buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut *addr_of_mut!(ADD_TO_ITSELF_ARENA)) };
+
+    let mut op_resolver = BLiteOpResolver::<2, f32, _>::new();
+    op_resolver.add_op(OpRelu::relu()).unwrap();
+    op_resolver.add_op(OpAdd::add()).unwrap();
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model).unwrap();
+
+    // both operands of ADD read RELU, so ADD must not overwrite it
+    let plan = interpreter.memory_plan();
+    assert_ne!(plan[2], plan[1]);
+
+    for x in [0., 1.5, 3.] {
+        let input = input(x);
+        interpreter.input.data.copy_from_slice(&input);
+        interpreter.invoke().unwrap();
+        for (output, x) in interpreter.output.data.iter().zip(&input) {
+            assert_eq!(*output, 2. * x.max(0.));
+        }
+    }
+}
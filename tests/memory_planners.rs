@@ -0,0 +1,159 @@
+use berrylite::kernel::micro_operator::f32::{
+    conv2d::OpConv2D, depthwise_conv2d::OpDepthWiseConv2D, fully_connected::OpFullyConnected,
+    max_pool2d::OpMaxPool2D, reshape::OpReshape, softmax::OpSoftMax,
+};
+use berrylite::kernel::micro_operator::i8::{
+    avg_pool2d_i8::OpAvgPool2DInt8, conv2d_i8::OpConv2DInt8,
+    depthwise_conv2d_i8::OpDepthWiseConv2DInt8, reshape_i8::OpReshapeInt8,
+    softmax_i8::OpSoftMaxInt8,
+};
+use berrylite::memory_planner::BLiteMemoryPlannerKind;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_graph::BLiteGraphOptions;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite;
+
+const HELLO_WORLD: &[u8; 3164] = include_bytes!("../resources/models/hello_world_float.tflite");
+const PERSON_DETECT: &[u8; 300568] = include_bytes!("../resources/models/person_detect.tflite");
+const MNIST_DEPTHWISE_CNN: &[u8; 419572] =
+    include_bytes!("../resources/models/mnist_depthwise_cnn.tflite");
+
+const MEMORY_PLANNERS: [BLiteMemoryPlannerKind; 3] = [
+    BLiteMemoryPlannerKind::Linear,
+    BLiteMemoryPlannerKind::Greedy,
+    BLiteMemoryPlannerKind::BestOrder,
+];
+
+const HELLO_WORLD_ARENA_SIZE: usize = 10 * 1024;
+static mut HELLO_WORLD_ARENA: [u8; HELLO_WORLD_ARENA_SIZE] = [0; HELLO_WORLD_ARENA_SIZE];
+const INT8_ARENA_SIZE: usize = 1024 * 1024;
+static mut INT8_ARENA: [u8; INT8_ARENA_SIZE] = [0; INT8_ARENA_SIZE];
+const FLOAT_ARENA_SIZE: usize = 1024 * 1024;
+static mut FLOAT_ARENA: [u8; FLOAT_ARENA_SIZE] = [0; FLOAT_ARENA_SIZE];
+
+/// Returns the output and the bytes of the planned tensors
+fn predict_hello_world(memory_planner: BLiteMemoryPlannerKind, input: f32) -> Result<(f32, usize)> {
+    let model = tflite::root_as_model(HELLO_WORLD).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut HELLO_WORLD_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<1, f32, _>::new();
+    op_resolver.add_op(OpFullyConnected::fully_connected())?;
+
+    let options = BLiteGraphOptions {
+        memory_planner,
+        ..Default::default()
+    };
+    let interpreter =
+        BLiteInterpreter::new_with_options(&mut allocator, &op_resolver, &model, options)?;
+    interpreter.input.data[0] = input;
+    interpreter.invoke()?;
+    Ok((interpreter.output.data[0], interpreter.planned_size()))
+}
+
+fn predict_int8(memory_planner: BLiteMemoryPlannerKind, input: &[i8]) -> Result<(Vec<i8>, usize)> {
+    let model = tflite::root_as_model(PERSON_DETECT).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut INT8_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<5, i8, _>::new();
+    op_resolver.add_op(OpReshapeInt8::reshape_int8())?;
+    op_resolver.add_op(OpConv2DInt8::conv2d_int8())?;
+    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8())?;
+    op_resolver.add_op(OpSoftMaxInt8::softmax_int8())?;
+    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8())?;
+
+    let options = BLiteGraphOptions {
+        memory_planner,
+        ..Default::default()
+    };
+    let interpreter =
+        BLiteInterpreter::new_with_options(&mut allocator, &op_resolver, &model, options)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    Ok((interpreter.output.data.to_vec(), interpreter.planned_size()))
+}
+
+fn predict_float(
+    memory_planner: BLiteMemoryPlannerKind,
+    input: &[f32],
+) -> Result<(Vec<f32>, usize)> {
+    let model = tflite::root_as_model(MNIST_DEPTHWISE_CNN).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut FLOAT_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<6, f32, _>::new();
+    op_resolver.add_op(OpFullyConnected::fully_connected())?;
+    op_resolver.add_op(OpReshape::reshape())?;
+    op_resolver.add_op(OpConv2D::conv2d())?;
+    op_resolver.add_op(OpMaxPool2D::max_pool2d())?;
+    op_resolver.add_op(OpSoftMax::softmax())?;
+    op_resolver.add_op(OpDepthWiseConv2D::depthwise_conv2d())?;
+
+    let options = BLiteGraphOptions {
+        memory_planner,
+        ..Default::default()
+    };
+    let interpreter =
+        BLiteInterpreter::new_with_options(&mut allocator, &op_resolver, &model, options)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    Ok((interpreter.output.data.to_vec(), interpreter.planned_size()))
+}
+
+/// Asserts that the memory planners need the arenas of linear >= greedy >= best order
+fn assert_planned_sizes(model_name: &str, sizes: &[usize]) {
+    println!(
+        "{model_name}: linear {} bytes, greedy {} bytes, best order {} bytes",
+        sizes[0], sizes[1], sizes[2]
+    );
+    assert!(sizes[0] >= sizes[1], "{model_name}: {sizes:?}");
+    assert!(sizes[1] >= sizes[2], "{model_name}: {sizes:?}");
+}
+
+#[test]
+fn test_memory_planners_hello_world() {
+    let mut sizes = [0; 3];
+    for x in [0., 1., 3.] {
+        let (expected, _) = predict_hello_world(BLiteMemoryPlannerKind::Greedy, x).unwrap();
+        for (size, memory_planner) in sizes.iter_mut().zip(MEMORY_PLANNERS) {
+            let (output, planned_size) = predict_hello_world(memory_planner, x).unwrap();
+            assert_eq!(expected, output, "{memory_planner:?}");
+            *size = planned_size;
+        }
+    }
+    assert_planned_sizes("hello_world_float", &sizes);
+}
+
+#[test]
+fn test_memory_planners_person_detect() {
+    let image = image::open("./resources/dataset/person_detection/person0.jpg")
+        .unwrap()
+        .into_luma8();
+    let input: Vec<i8> = image.pixels().map(|p| p.0[0] as i8).collect();
+
+    let mut sizes = [0; 3];
+    let (expected, _) = predict_int8(BLiteMemoryPlannerKind::Greedy, &input).unwrap();
+    for (size, memory_planner) in sizes.iter_mut().zip(MEMORY_PLANNERS) {
+        let (output, planned_size) = predict_int8(memory_planner, &input).unwrap();
+        assert_eq!(expected, output, "{memory_planner:?}");
+        *size = planned_size;
+    }
+    assert_planned_sizes("person_detect", &sizes);
+}
+
+#[test]
+fn test_memory_planners_mnist_depthwise_cnn() {
+    let image = image::open("./resources/dataset/mnist/3.jpg")
+        .unwrap()
+        .into_luma8();
+    let input: Vec<f32> = image.pixels().map(|p| p.0[0] as f32 / 255.).collect();
+
+    let mut sizes = [0; 3];
+    let (expected, _) = predict_float(BLiteMemoryPlannerKind::Greedy, &input).unwrap();
+    for (size, memory_planner) in sizes.iter_mut().zip(MEMORY_PLANNERS) {
+        let (output, planned_size) = predict_float(memory_planner, &input).unwrap();
+        assert_eq!(expected, output, "{memory_planner:?}");
+        *size = planned_size;
+    }
+    assert_planned_sizes("mnist_depthwise_cnn", &sizes);
+}
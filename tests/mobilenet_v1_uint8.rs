@@ -0,0 +1,67 @@
+use berrylite::kernel::micro_operator::u8::avg_pool2d_u8::OpAvgPool2DUInt8;
+use berrylite::kernel::micro_operator::u8::conv2d_u8::OpConv2DUInt8;
+use berrylite::kernel::micro_operator::u8::depthwise_conv2d_u8::OpDepthWiseConv2DUInt8;
+use berrylite::kernel::micro_operator::u8::reshape_u8::OpReshapeUInt8;
+use berrylite::kernel::micro_operator::u8::softmax_u8::OpSoftMaxUInt8;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite;
+use image::imageops::FilterType;
+
+const BUFFER: &[u8; 1364512] =
+    include_bytes!("../resources/models/mobilenet_v1_0.50_128_quantized_1_default_1.tflite");
+
+const ARENA_SIZE: usize = 4 * 1024 * 1024;
+static mut ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+const INPUT_SIZE: u32 = 128;
+
+fn predict(image: &[u8]) -> Result<(usize, f32)> {
+    let model = tflite::root_as_model(BUFFER).unwrap();
+
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<5, u8, _>::new();
+    op_resolver.add_op(OpConv2DUInt8::conv2d_uint8())?;
+    op_resolver.add_op(OpDepthWiseConv2DUInt8::depthwise_conv2d_uint8())?;
+    op_resolver.add_op(OpAvgPool2DUInt8::avg_pool2d_uint8())?;
+    op_resolver.add_op(OpReshapeUInt8::reshape_uint8())?;
+    op_resolver.add_op(OpSoftMaxUInt8::softmax_uint8())?;
+
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    let (output_scale, output_zero_point) = interpreter.get_output_quantization_params().unwrap();
+
+    interpreter.input.data.copy_from_slice(image);
+    interpreter.invoke()?;
+
+    let probs = interpreter
+        .output
+        .data
+        .iter()
+        .map(|&y| output_scale * (y as i32 - output_zero_point) as f32)
+        .collect::<Vec<_>>();
+    let (label, _) = probs
+        .iter()
+        .enumerate()
+        .fold((0, 0.), |acc, (i, &p)| if p > acc.1 { (i, p) } else { acc });
+    Ok((label, probs.iter().sum()))
+}
+
+#[test]
+fn test_mobilenet_v1_uint8() {
+    let img = image::open("./resources/dataset/person_detection/dog0.jpg").unwrap();
+    let img = img
+        .resize_exact(INPUT_SIZE, INPUT_SIZE, FilterType::Triangle)
+        .into_rgb8();
+    let (label, sum_of_probs) = predict(img.as_raw()).unwrap();
+
+    assert!(
+        (sum_of_probs - 1.).abs() < 0.05,
+        "the sum of probabilities is {}",
+        sum_of_probs
+    );
+    // the labels of dogs are from 152 to 269, where 0 is the background
+    assert!((152..=269).contains(&label), "the label is {}", label);
+}
@@ -0,0 +1,318 @@
+use berrylite::kernel::micro_operator::f32::{
+    add::OpAdd, fully_connected::OpFullyConnected, tanh::OpTanh,
+};
+use berrylite::memory_planner::offline_memory_plan::BLiteOfflineMemoryPlan;
+use berrylite::micro_allocator::{ArenaAllocator, BumpArenaAllocator};
+use berrylite::micro_errors::{BLiteError, Result};
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite::{self, BuiltinOperator};
+use core::mem::size_of;
+use flatbuffers::{FlatBufferBuilder, Push, WIPOffset};
+
+const ARENA_SIZE: usize = 8 * 1024;
+static mut ROUND_TRIP_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut PARTIAL_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut INVALID_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+fn buffer<'a, V: Push + Copy>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    values: &[V],
+) -> WIPOffset<flatbuffers::Vector<'a, u8>> {
+    // the values are pushed as themselves to be aligned, and then ended as a vector of bytes
+    fbb.start_vector::<V>(values.len());
+    for &v in values.iter().rev() {
+        fbb.push(v);
+    }
+    fbb.end_vector::<u8>(values.len() * size_of::<V>())
+}
+
+/// Builds a model of
+/// FULLY_CONNECTED [1, 16] -> TANH -> FULLY_CONNECTED [1, 16] -> ADD -> FULLY_CONNECTED [1, 4],
+/// whose ADD reads TANH again, with the `OfflineMemoryAllocation` metadata of `offsets`
+fn build_model(offsets: Option<&[i32]>) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let ops = [
+        BuiltinOperator::FULLY_CONNECTED,
+        BuiltinOperator::TANH,
+        BuiltinOperator::ADD,
+    ];
+    let operator_codes = ops.map(|op| {
+        tflite::OperatorCode::create(
+            &mut fbb,
+            &tflite::OperatorCodeArgs {
+                deprecated_builtin_code: op.0 as i8,
+                custom_code: None,
+                version: 1,
+                builtin_code: op,
+            },
+        )
+    });
+    let operator_codes = fbb.create_vector(&operator_codes);
+
+    let tensor_args = [
+        (vec![1, 8], 0),   // 0: input
+        (vec![16, 8], 1),  // 1: weights
+        (vec![16], 2),     // 2: bias
+        (vec![1, 16], 0),  // 3: fully connected
+        (vec![1, 16], 0),  // 4: tanh
+        (vec![16, 16], 3), // 5: weights
+        (vec![16], 2),     // 6: bias
+        (vec![1, 16], 0),  // 7: fully connected
+        (vec![1, 16], 0),  // 8: add
+        (vec![4, 16], 4),  // 9: weights
+        (vec![4], 5),      // 10: bias
+        (vec![1, 4], 0),   // 11: output
+    ];
+    let tensors = tensor_args.map(|(shape, buffer)| {
+        let shape = fbb.create_vector(&shape);
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                type_: tflite::TensorType::FLOAT32,
+                buffer,
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let fully_connected_options = tflite::FullyConnectedOptions::create(
+        &mut fbb,
+        &tflite::FullyConnectedOptionsArgs::default(),
+    )
+    .as_union_value();
+    let add_options =
+        tflite::AddOptions::create(&mut fbb, &tflite::AddOptionsArgs::default()).as_union_value();
+    let fully_connected = Some((
+        tflite::BuiltinOptions::FullyConnectedOptions,
+        fully_connected_options,
+    ));
+    let operator_args = [
+        (0, vec![0, 1, 2], 3, fully_connected),
+        (1, vec![3], 4, None),
+        (0, vec![4, 5, 6], 7, fully_connected),
+        (
+            2,
+            vec![7, 4],
+            8,
+            Some((tflite::BuiltinOptions::AddOptions, add_options)),
+        ),
+        (0, vec![8, 9, 10], 11, fully_connected),
+    ];
+    let operators = operator_args.map(|(opcode_index, inputs, output, options)| {
+        let inputs = fbb.create_vector(&inputs);
+        let outputs = fbb.create_vector(&[output]);
+        let (builtin_options_type, builtin_options) = match options {
+            Some((options_type, options)) => (options_type, Some(options)),
+            None => (tflite::BuiltinOptions::NONE, None),
+        };
+        tflite::Operator::create(
+            &mut fbb,
+            &tflite::OperatorArgs {
+                opcode_index,
+                inputs: Some(inputs),
+                outputs: Some(outputs),
+                builtin_options_type,
+                builtin_options,
+                ..Default::default()
+            },
+        )
+    });
+    let operators = fbb.create_vector(&operators);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[11]);
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let weights1: Vec<f32> = (0..128)
+        .map(|i| ((i * 5) % 13) as f32 * 0.1 - 0.6)
+        .collect();
+    let bias: Vec<f32> = (0..16).map(|i| i as f32 * 0.05 - 0.4).collect();
+    let weights2: Vec<f32> = (0..256)
+        .map(|i| ((i * 7) % 11) as f32 * 0.1 - 0.5)
+        .collect();
+    let weights3: Vec<f32> = (0..64).map(|i| ((i * 3) % 7) as f32 * 0.2 - 0.6).collect();
+    let mut datas = vec![
+        None,
+        Some(buffer(&mut fbb, &weights1)),
+        Some(buffer(&mut fbb, &bias)),
+        Some(buffer(&mut fbb, &weights2)),
+        Some(buffer(&mut fbb, &weights3)),
+        Some(buffer(&mut fbb, &[0.1, -0.2, 0.3, -0.4f32])),
+    ];
+    let metadata = offsets.map(|offsets| {
+        let mut bytes = vec![0u8; BLiteOfflineMemoryPlan::metadata_size(offsets.len())];
+        BLiteOfflineMemoryPlan::write_metadata(offsets, &mut bytes).unwrap();
+        datas.push(Some(buffer(&mut fbb, &bytes)));
+        let name = fbb.create_string(BLiteOfflineMemoryPlan::METADATA_NAME);
+        let metadata = tflite::Metadata::create(
+            &mut fbb,
+            &tflite::MetadataArgs {
+                name: Some(name),
+                buffer: datas.len() as u32 - 1,
+            },
+        );
+        fbb.create_vector(&[metadata])
+    });
+    let buffers: Vec<_> = datas
+        .into_iter()
+        .map(|data| {
+            tflite::Buffer::create(
+                &mut fbb,
+                &tflite::BufferArgs {
+                    data,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            metadata,
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+/// Returns the output, the memory plan and the used size of the arena
+fn predict(
+    arena: &'static mut [u8],
+    buffer: &[u8],
+    input: &[f32],
+) -> Result<(Vec<f32>, Vec<i32>, usize)> {
+    let model = tflite::root_as_model(buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(arena) };
+
+    let mut op_resolver = BLiteOpResolver::<3, f32, _>::new();
+    op_resolver.add_op(OpFullyConnected::fully_connected())?;
+    op_resolver.add_op(OpTanh::tanh())?;
+    op_resolver.add_op(OpAdd::add())?;
+
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    let (_, used) = allocator.description()?;
+    Ok((
+        interpreter.output.data.to_vec(),
+        interpreter.memory_plan().to_vec(),
+        used,
+    ))
+}
+
+fn input(x: f32) -> Vec<f32> {
+    (0..8).map(|i| (x + i as f32 * 0.4).sin() * 2.).collect()
+}
+
+#[test]
+fn test_offline_memory_plan_round_trip() {
+    let input = input(0.5);
+    let online = build_model(None);
+    let (expected, plan, used) =
+        predict(unsafe { &mut ROUND_TRIP_ARENA }, &online, &input).unwrap();
+    // TANH and ADD share the buffers of FULLY_CONNECTED, and the others are not planned
+    assert_eq!(plan[3], plan[4]);
+    assert_eq!(plan[7], plan[8]);
+    assert_ne!(plan[3], plan[7]);
+    for idx in [0, 1, 2, 5, 6, 9, 10, 11] {
+        assert_eq!(plan[idx], -1);
+    }
+
+    let offline = build_model(Some(&plan));
+    let (output, offline_plan, offline_used) =
+        predict(unsafe { &mut ROUND_TRIP_ARENA }, &offline, &input).unwrap();
+    assert_eq!(expected, output);
+    assert_eq!(plan, offline_plan);
+    assert_eq!(used, offline_used);
+}
+
+#[test]
+fn test_offline_memory_plan_with_online_tensors() {
+    let input = input(1.5);
+    let (expected, _, used) =
+        predict(unsafe { &mut PARTIAL_ARENA }, &build_model(None), &input).unwrap();
+
+    // the buffers are not reused, and ADD does not share the buffer of FULLY_CONNECTED
+    let linear = [-1, -1, -1, 0, 0, -1, -1, 64, 128, -1, -1, -1];
+    let (output, plan, linear_used) = predict(
+        unsafe { &mut PARTIAL_ARENA },
+        &build_model(Some(&linear)),
+        &input,
+    )
+    .unwrap();
+    assert_eq!(expected, output);
+    assert_eq!(plan, linear);
+    assert!(linear_used > used, "{linear_used} <= {used}");
+
+    // the tensors of -1 are planned around the others
+    let partial = [-1, -1, -1, 64, 64, -1, -1, -1, -1, -1, -1, -1];
+    let (output, plan, _) = predict(
+        unsafe { &mut PARTIAL_ARENA },
+        &build_model(Some(&partial)),
+        &input,
+    )
+    .unwrap();
+    assert_eq!(expected, output);
+    assert_eq!(plan[3], 64);
+    assert_eq!(plan[7], 0);
+}
+
+#[test]
+fn test_invalid_offline_memory_plan() {
+    let input = input(2.5);
+
+    // FULLY_CONNECTED overlaps TANH, which is read by ADD after it
+    let overlapped = [-1, -1, -1, 0, 0, -1, -1, 32, 32, -1, -1, -1];
+    let result = predict(
+        unsafe { &mut INVALID_ARENA },
+        &build_model(Some(&overlapped)),
+        &input,
+    );
+    assert!(
+        matches!(result, Err(BLiteError::OverlappedOfflineMemoryPlan(..))),
+        "{result:?}"
+    );
+
+    let misaligned = [-1, -1, -1, 0, 0, -1, -1, 66, 66, -1, -1, -1];
+    let result = predict(
+        unsafe { &mut INVALID_ARENA },
+        &build_model(Some(&misaligned)),
+        &input,
+    );
+    assert!(
+        matches!(result, Err(BLiteError::MisalignedOfflineMemoryPlan(7))),
+        "{result:?}"
+    );
+
+    // the plan must have the offsets of all tensors
+    let result = predict(
+        unsafe { &mut INVALID_ARENA },
+        &build_model(Some(&[0, 64])),
+        &input,
+    );
+    assert!(
+        matches!(result, Err(BLiteError::InvalidOfflineMemoryPlan)),
+        "{result:?}"
+    );
+}
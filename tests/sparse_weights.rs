@@ -0,0 +1,305 @@
+use berrylite::kernel::micro_operator::f32::add::OpAdd;
+use berrylite::kernel::micro_operator::f32::fully_connected::OpFullyConnected;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite;
+use core::mem::size_of;
+use flatbuffers::{FlatBufferBuilder, WIPOffset};
+
+const ARENA_SIZE: usize = 2048;
+static mut FULLY_CONNECTED_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+static mut ADD_ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+// the nonzero values of the dense weights
+// [[0, 0, 1.5, -2], [0, 0, 0, 0], [0.25, 3, -1, 0.5]]
+const VALUES: [f32; 6] = [1.5, -2., 0.25, 3., -1., 0.5];
+const DENSE: [f32; 12] = [0., 0., 1.5, -2., 0., 0., 0., 0., 0.25, 3., -1., 0.5];
+const BIAS: [f32; 3] = [0.25, -0.5, 1.];
+
+#[derive(Clone, Copy)]
+enum Format {
+    // the rows are dense and the columns are CSR of the blocks of 2 values,
+    // which the sparse kernels evaluate without densifying
+    BlockedRows,
+    // both the rows and the columns are CSR, which are densified at loading
+    Csr,
+}
+
+fn dim_metadata<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    dense_size: i32,
+    segments: &[i32],
+    indices: &[i32],
+    narrow: bool,
+) -> WIPOffset<tflite::DimensionMetadata<'a>> {
+    if segments.is_empty() {
+        return tflite::DimensionMetadata::create(
+            fbb,
+            &tflite::DimensionMetadataArgs {
+                format: tflite::DimensionType::DENSE,
+                dense_size,
+                ..Default::default()
+            },
+        );
+    }
+    // the narrow index vectors are uint8
+    let (index_type, segments, indices) = if narrow {
+        let segments = fbb.create_vector(&segments.iter().map(|&v| v as u8).collect::<Vec<_>>());
+        let indices = fbb.create_vector(&indices.iter().map(|&v| v as u8).collect::<Vec<_>>());
+        let segments = tflite::Uint8Vector::create(
+            fbb,
+            &tflite::Uint8VectorArgs {
+                values: Some(segments),
+            },
+        );
+        let indices = tflite::Uint8Vector::create(
+            fbb,
+            &tflite::Uint8VectorArgs {
+                values: Some(indices),
+            },
+        );
+        (
+            tflite::SparseIndexVector::Uint8Vector,
+            segments.as_union_value(),
+            indices.as_union_value(),
+        )
+    } else {
+        let segments = fbb.create_vector(segments);
+        let indices = fbb.create_vector(indices);
+        let segments = tflite::Int32Vector::create(
+            fbb,
+            &tflite::Int32VectorArgs {
+                values: Some(segments),
+            },
+        );
+        let indices = tflite::Int32Vector::create(
+            fbb,
+            &tflite::Int32VectorArgs {
+                values: Some(indices),
+            },
+        );
+        (
+            tflite::SparseIndexVector::Int32Vector,
+            segments.as_union_value(),
+            indices.as_union_value(),
+        )
+    };
+    tflite::DimensionMetadata::create(
+        fbb,
+        &tflite::DimensionMetadataArgs {
+            format: tflite::DimensionType::SPARSE_CSR,
+            dense_size,
+            array_segments_type: index_type,
+            array_segments: Some(segments),
+            array_indices_type: index_type,
+            array_indices: Some(indices),
+        },
+    )
+}
+
+fn float_buffer<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    values: &[f32],
+) -> WIPOffset<flatbuffers::Vector<'a, u8>> {
+    // the values are pushed as float values to be aligned, and then ended as a vector of bytes
+    fbb.start_vector::<f32>(values.len());
+    for &v in values.iter().rev() {
+        fbb.push(v);
+    }
+    fbb.end_vector::<u8>(values.len() * size_of::<f32>())
+}
+
+/// Builds a model of a single FULLY_CONNECTED or ADD whose second input is
+/// the sparse float weights [3, 4]
+fn build_model(op: tflite::BuiltinOperator, format: Format) -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    // the converter marks FULLY_CONNECTED with the sparse weights as version 8
+    let is_fully_connected = op == tflite::BuiltinOperator::FULLY_CONNECTED;
+    let operator_code = tflite::OperatorCode::create(
+        &mut fbb,
+        &tflite::OperatorCodeArgs {
+            deprecated_builtin_code: op.0 as i8,
+            custom_code: None,
+            version: if is_fully_connected { 8 } else { 1 },
+            builtin_code: op,
+        },
+    );
+    let operator_codes = fbb.create_vector(&[operator_code]);
+
+    let (input_shape, output_shape) = if is_fully_connected {
+        (vec![1, 4], vec![1, 3])
+    } else {
+        (vec![3, 4], vec![3, 4])
+    };
+
+    let sparsity = {
+        let (traversal_order, block_map, dims) = match format {
+            Format::BlockedRows => (
+                fbb.create_vector(&[0, 1, 2]),
+                Some(fbb.create_vector(&[1])),
+                [
+                    dim_metadata(&mut fbb, 3, &[], &[], false),
+                    dim_metadata(&mut fbb, 0, &[0, 1, 1, 3], &[1, 0, 1], true),
+                    dim_metadata(&mut fbb, 2, &[], &[], false),
+                ]
+                .to_vec(),
+            ),
+            Format::Csr => (
+                fbb.create_vector(&[0, 1]),
+                None,
+                [
+                    dim_metadata(&mut fbb, 0, &[0, 2], &[0, 2], false),
+                    dim_metadata(&mut fbb, 0, &[0, 2, 6], &[2, 3, 0, 1, 2, 3], false),
+                ]
+                .to_vec(),
+            ),
+        };
+        let dim_metadata = fbb.create_vector(&dims);
+        tflite::SparsityParameters::create(
+            &mut fbb,
+            &tflite::SparsityParametersArgs {
+                traversal_order: Some(traversal_order),
+                block_map,
+                dim_metadata: Some(dim_metadata),
+            },
+        )
+    };
+
+    let tensor_args = [
+        (input_shape, 0, None),
+        (vec![3, 4], 1, Some(sparsity)),
+        (vec![3], 2, None),
+        (output_shape, 0, None),
+    ];
+    let tensors = tensor_args.map(|(shape, buffer, sparsity)| {
+        let shape = fbb.create_vector(&shape);
+        tflite::Tensor::create(
+            &mut fbb,
+            &tflite::TensorArgs {
+                shape: Some(shape),
+                type_: tflite::TensorType::FLOAT32,
+                buffer,
+                sparsity,
+                ..Default::default()
+            },
+        )
+    });
+    let tensors = fbb.create_vector(&tensors);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[3]);
+    let (op_inputs, builtin_options_type, builtin_options) = if is_fully_connected {
+        let options = tflite::FullyConnectedOptions::create(
+            &mut fbb,
+            &tflite::FullyConnectedOptionsArgs::default(),
+        );
+        (
+            fbb.create_vector(&[0, 1, 2]),
+            tflite::BuiltinOptions::FullyConnectedOptions,
+            options.as_union_value(),
+        )
+    } else {
+        let options = tflite::AddOptions::create(&mut fbb, &tflite::AddOptionsArgs::default());
+        (
+            fbb.create_vector(&[0, 1]),
+            tflite::BuiltinOptions::AddOptions,
+            options.as_union_value(),
+        )
+    };
+    let operator = tflite::Operator::create(
+        &mut fbb,
+        &tflite::OperatorArgs {
+            opcode_index: 0,
+            inputs: Some(op_inputs),
+            outputs: Some(outputs),
+            builtin_options_type,
+            builtin_options: Some(builtin_options),
+            ..Default::default()
+        },
+    );
+    let operators = fbb.create_vector(&[operator]);
+
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let values = float_buffer(&mut fbb, &VALUES);
+    let bias = float_buffer(&mut fbb, &BIAS);
+    let datas = [None, Some(values), Some(bias)];
+    let buffers = datas.map(|data| {
+        tflite::Buffer::create(
+            &mut fbb,
+            &tflite::BufferArgs {
+                data,
+                ..Default::default()
+            },
+        )
+    });
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+#[test]
+fn test_sparse_fully_connected() -> Result<()> {
+    let input = [0.5f32, -1., 2., 0.25];
+    let expected: [f32; 3] = core::array::from_fn(|out_d| {
+        BIAS[out_d] + (0..4).map(|d| input[d] * DENSE[out_d * 4 + d]).sum::<f32>()
+    });
+
+    for format in [Format::BlockedRows, Format::Csr] {
+        let buffer = build_model(tflite::BuiltinOperator::FULLY_CONNECTED, format);
+        let model = tflite::root_as_model(&buffer).unwrap();
+        let mut allocator = unsafe { BumpArenaAllocator::new(&mut FULLY_CONNECTED_ARENA) };
+        let mut op_resolver = BLiteOpResolver::<1, f32, _>::new();
+        op_resolver.add_op(OpFullyConnected::fully_connected())?;
+
+        let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+        interpreter.input.data.copy_from_slice(&input);
+        interpreter.invoke()?;
+        assert_eq!(expected, interpreter.output.data);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_sparse_weights_densified_for_dense_kernels() -> Result<()> {
+    let input: [f32; 12] = core::array::from_fn(|i| i as f32 * 0.5 - 3.);
+    let expected: [f32; 12] = core::array::from_fn(|i| input[i] + DENSE[i]);
+
+    for format in [Format::BlockedRows, Format::Csr] {
+        let buffer = build_model(tflite::BuiltinOperator::ADD, format);
+        let model = tflite::root_as_model(&buffer).unwrap();
+        let mut allocator = unsafe { BumpArenaAllocator::new(&mut ADD_ARENA) };
+        let mut op_resolver = BLiteOpResolver::<1, f32, _>::new();
+        op_resolver.add_op(OpAdd::add())?;
+
+        let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+        interpreter.input.data.copy_from_slice(&input);
+        interpreter.invoke()?;
+        assert_eq!(expected, interpreter.output.data);
+    }
+    Ok(())
+}
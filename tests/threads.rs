@@ -0,0 +1,106 @@
+#![cfg(feature = "threads")]
+
+use berrylite::kernel::micro_operator::f32::{
+    conv2d::OpConv2D, depthwise_conv2d::OpDepthWiseConv2D, fully_connected::OpFullyConnected,
+    max_pool2d::OpMaxPool2D, reshape::OpReshape, softmax::OpSoftMax,
+};
+use berrylite::kernel::micro_operator::i8::avg_pool2d_i8::OpAvgPool2DInt8;
+use berrylite::kernel::micro_operator::i8::conv2d_i8::OpConv2DInt8;
+use berrylite::kernel::micro_operator::i8::depthwise_conv2d_i8::OpDepthWiseConv2DInt8;
+use berrylite::kernel::micro_operator::i8::fully_connected_i8::OpFullyConnectedInt8;
+use berrylite::kernel::micro_operator::i8::reshape_i8::OpReshapeInt8;
+use berrylite::kernel::micro_operator::i8::softmax_i8::OpSoftMaxInt8;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::micro_threads::BLiteThreadPool;
+use berrylite::tflite_schema_generated::tflite;
+use std::sync::Arc;
+
+const PERSON_DETECT: &[u8; 300568] = include_bytes!("../resources/models/person_detect.tflite");
+const HELLO_WORLD_INT8: &[u8; 2704] = include_bytes!("../resources/models/hello_world_int8.tflite");
+const MNIST_DEPTHWISE_CNN: &[u8; 419572] =
+    include_bytes!("../resources/models/mnist_depthwise_cnn.tflite");
+
+const INT8_ARENA_SIZE: usize = 136 * 1024;
+static mut INT8_ARENA: [u8; INT8_ARENA_SIZE] = [0; INT8_ARENA_SIZE];
+const FLOAT_ARENA_SIZE: usize = 256 * 1024;
+static mut FLOAT_ARENA: [u8; FLOAT_ARENA_SIZE] = [0; FLOAT_ARENA_SIZE];
+
+fn predict_int8(
+    buffer: &[u8],
+    thread_pool: Option<Arc<BLiteThreadPool>>,
+    input: &[i8],
+) -> Result<Vec<i8>> {
+    let model = tflite::root_as_model(buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut INT8_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<6, i8, _>::new();
+    op_resolver.add_op(OpFullyConnectedInt8::fully_connected_int8())?;
+    op_resolver.add_op(OpReshapeInt8::reshape_int8())?;
+    op_resolver.add_op(OpConv2DInt8::conv2d_int8())?;
+    op_resolver.add_op(OpAvgPool2DInt8::avg_pool2d_int8())?;
+    op_resolver.add_op(OpSoftMaxInt8::softmax_int8())?;
+    op_resolver.add_op(OpDepthWiseConv2DInt8::depthwise_conv2d_int8())?;
+
+    let mut interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    interpreter.set_thread_pool(thread_pool);
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    Ok(interpreter.output.data.to_vec())
+}
+
+fn predict_float(thread_pool: Option<Arc<BLiteThreadPool>>, input: &[f32]) -> Result<Vec<f32>> {
+    let model = tflite::root_as_model(MNIST_DEPTHWISE_CNN).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut FLOAT_ARENA) };
+
+    let mut op_resolver = BLiteOpResolver::<6, f32, _>::new();
+    op_resolver.add_op(OpFullyConnected::fully_connected())?;
+    op_resolver.add_op(OpReshape::reshape())?;
+    op_resolver.add_op(OpConv2D::conv2d())?;
+    op_resolver.add_op(OpMaxPool2D::max_pool2d())?;
+    op_resolver.add_op(OpSoftMax::softmax())?;
+    op_resolver.add_op(OpDepthWiseConv2D::depthwise_conv2d())?;
+
+    let mut interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    interpreter.set_thread_pool(thread_pool);
+    interpreter.input.data.copy_from_slice(input);
+    interpreter.invoke()?;
+    Ok(interpreter.output.data.to_vec())
+}
+
+#[test]
+fn test_threads_int8_is_bit_exact() {
+    let image = image::open("./resources/dataset/person_detection/person0.jpg")
+        .unwrap()
+        .into_luma8();
+    let input: Vec<i8> = image.pixels().map(|p| p.0[0] as i8).collect();
+    let expected = predict_int8(PERSON_DETECT, None, &input).unwrap();
+    for num_threads in [1, 2, 3, 4] {
+        let thread_pool = Arc::new(BLiteThreadPool::new(num_threads));
+        let output = predict_int8(PERSON_DETECT, Some(thread_pool), &input).unwrap();
+        assert_eq!(expected, output, "num_threads: {num_threads}");
+    }
+
+    let thread_pool = Arc::new(BLiteThreadPool::new(4));
+    for input in [-128i8, -50, 0, 77, 127] {
+        let expected = predict_int8(HELLO_WORLD_INT8, None, &[input]).unwrap();
+        let output = predict_int8(HELLO_WORLD_INT8, Some(thread_pool.clone()), &[input]).unwrap();
+        assert_eq!(expected, output, "input: {input}");
+    }
+}
+
+#[test]
+fn test_threads_float_is_bit_exact() {
+    let thread_pool = Arc::new(BLiteThreadPool::new(4));
+    for digit in [0, 3, 7] {
+        let image = image::open(format!("./resources/dataset/mnist/{digit}.jpg"))
+            .unwrap()
+            .into_luma8();
+        let input: Vec<f32> = image.pixels().map(|p| p.0[0] as f32 / 255.).collect();
+        let expected = predict_float(None, &input).unwrap();
+        let output = predict_float(Some(thread_pool.clone()), &input).unwrap();
+        assert_eq!(expected, output, "digit: {digit}");
+    }
+}
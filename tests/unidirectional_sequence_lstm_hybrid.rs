@@ -0,0 +1,249 @@
+use berrylite::kernel::micro_operator::f32::unidirectional_sequence_lstm_hybrid::OpUnidirectionalSequenceLSTMHybrid;
+use berrylite::micro_allocator::BumpArenaAllocator;
+use berrylite::micro_errors::Result;
+use berrylite::micro_interpreter::BLiteInterpreter;
+use berrylite::micro_op_resolver::BLiteOpResolver;
+use berrylite::tflite_schema_generated::tflite;
+use core::mem::size_of;
+use flatbuffers::{FlatBufferBuilder, Vector, WIPOffset};
+
+const ARENA_SIZE: usize = 4096;
+static mut ARENA: [u8; ARENA_SIZE] = [0; ARENA_SIZE];
+
+const MAX_TIME: usize = 2;
+const N_INPUT: usize = 2;
+const N_CELL: usize = 2;
+const WEIGHTS_SCALE: f32 = 1. / 32.;
+// the input to gate weights and then the recurrent to gate weights,
+// whose gates are in the order of the input, forget, cell and output gates
+const WEIGHTS: [[i8; 4]; 8] = [
+    [20, -32, 8, 40],
+    [-16, 24, 32, 4],
+    [48, -8, -40, 16],
+    [12, 36, -24, -20],
+    [-28, 10, 16, 30],
+    [6, -18, 22, -12],
+    [34, 14, -26, 8],
+    [-10, 26, 18, -34],
+];
+const BIASES: [[f32; N_CELL]; 4] = [[0.1, -0.1], [1., 0.5], [0., 0.2], [-0.2, 0.3]];
+
+fn float_vector<'a>(fbb: &mut FlatBufferBuilder<'a>, values: &[f32]) -> WIPOffset<Vector<'a, u8>> {
+    // the values are pushed as floats to be aligned, and then ended as a vector of bytes
+    fbb.start_vector::<f32>(values.len());
+    for &v in values.iter().rev() {
+        fbb.push(v);
+    }
+    fbb.end_vector::<u8>(values.len() * size_of::<f32>())
+}
+
+/// Builds a model of a single UNIDIRECTIONAL_SEQUENCE_LSTM of the hybrid quantization,
+/// whose input is float [1, 2, 2], weights are int8 [2, 2], biases are float [2],
+/// states are variable float [1, 2] and output is float [1, 2, 2]
+fn build_model() -> Vec<u8> {
+    let mut fbb = FlatBufferBuilder::new();
+
+    let operator_code = tflite::OperatorCode::create(
+        &mut fbb,
+        &tflite::OperatorCodeArgs {
+            deprecated_builtin_code: tflite::BuiltinOperator::UNIDIRECTIONAL_SEQUENCE_LSTM.0 as i8,
+            custom_code: None,
+            version: 3,
+            builtin_code: tflite::BuiltinOperator::UNIDIRECTIONAL_SEQUENCE_LSTM,
+        },
+    );
+    let operator_codes = fbb.create_vector(&[operator_code]);
+
+    let mut tensor_args = vec![(
+        vec![1, MAX_TIME as i32, N_INPUT as i32],
+        tflite::TensorType::FLOAT32,
+        0,
+        false,
+    )];
+    for buffer in 1..=8 {
+        tensor_args.push((
+            vec![N_CELL as i32, 2],
+            tflite::TensorType::INT8,
+            buffer,
+            false,
+        ));
+    }
+    for buffer in 9..=12 {
+        tensor_args.push((
+            vec![N_CELL as i32],
+            tflite::TensorType::FLOAT32,
+            buffer,
+            false,
+        ));
+    }
+    // the output state and the cell state
+    tensor_args.push((vec![1, N_CELL as i32], tflite::TensorType::FLOAT32, 0, true));
+    tensor_args.push((vec![1, N_CELL as i32], tflite::TensorType::FLOAT32, 0, true));
+    tensor_args.push((
+        vec![1, MAX_TIME as i32, N_CELL as i32],
+        tflite::TensorType::FLOAT32,
+        0,
+        false,
+    ));
+    let tensors: Vec<_> = tensor_args
+        .into_iter()
+        .map(|(shape, type_, buffer, is_variable)| {
+            let shape = fbb.create_vector(&shape);
+            // only the weights are quantized
+            let quantization = (type_ == tflite::TensorType::INT8).then(|| {
+                let scale = fbb.create_vector(&[WEIGHTS_SCALE]);
+                let zero_point = fbb.create_vector(&[0i64]);
+                tflite::QuantizationParameters::create(
+                    &mut fbb,
+                    &tflite::QuantizationParametersArgs {
+                        scale: Some(scale),
+                        zero_point: Some(zero_point),
+                        ..Default::default()
+                    },
+                )
+            });
+            tflite::Tensor::create(
+                &mut fbb,
+                &tflite::TensorArgs {
+                    shape: Some(shape),
+                    type_,
+                    buffer,
+                    quantization,
+                    is_variable,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+    let tensors = fbb.create_vector(&tensors);
+
+    let inputs = fbb.create_vector(&[0]);
+    let outputs = fbb.create_vector(&[15]);
+    // neither peepholes, projection nor layer normalization
+    let op_inputs = fbb.create_vector(&[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, -1, -1, -1, 9, 10, 11, 12, -1, -1, 13, 14, -1, -1, -1, -1,
+    ]);
+    let builtin_options = tflite::UnidirectionalSequenceLSTMOptions::create(
+        &mut fbb,
+        &tflite::UnidirectionalSequenceLSTMOptionsArgs {
+            fused_activation_function: tflite::ActivationFunctionType::TANH,
+            time_major: false,
+            ..Default::default()
+        },
+    );
+    let operator = tflite::Operator::create(
+        &mut fbb,
+        &tflite::OperatorArgs {
+            opcode_index: 0,
+            inputs: Some(op_inputs),
+            outputs: Some(outputs),
+            builtin_options_type: tflite::BuiltinOptions::UnidirectionalSequenceLSTMOptions,
+            builtin_options: Some(builtin_options.as_union_value()),
+            ..Default::default()
+        },
+    );
+    let operators = fbb.create_vector(&[operator]);
+
+    let subgraph = tflite::SubGraph::create(
+        &mut fbb,
+        &tflite::SubGraphArgs {
+            tensors: Some(tensors),
+            inputs: Some(inputs),
+            outputs: Some(outputs),
+            operators: Some(operators),
+            name: None,
+        },
+    );
+    let subgraphs = fbb.create_vector(&[subgraph]);
+
+    let mut datas = vec![None];
+    for weights in WEIGHTS.iter() {
+        datas.push(Some(fbb.create_vector(&weights.map(|v| v as u8))));
+    }
+    for bias in BIASES.iter() {
+        datas.push(Some(float_vector(&mut fbb, bias)));
+    }
+    let buffers: Vec<_> = datas
+        .into_iter()
+        .map(|data| {
+            tflite::Buffer::create(
+                &mut fbb,
+                &tflite::BufferArgs {
+                    data,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect();
+    let buffers = fbb.create_vector(&buffers);
+
+    let model = tflite::Model::create(
+        &mut fbb,
+        &tflite::ModelArgs {
+            version: 3,
+            operator_codes: Some(operator_codes),
+            subgraphs: Some(subgraphs),
+            buffers: Some(buffers),
+            ..Default::default()
+        },
+    );
+    fbb.finish(model, Some("TFL3"));
+    fbb.finished_data().to_vec()
+}
+
+fn logistic(x: f32) -> f32 {
+    1. / (1. + (-x).exp())
+}
+
+/// Runs the float LSTM with the dequantized weights from the states `h` and `c`
+fn float_lstm(input: &[f32], h: &mut [f32; N_CELL], c: &mut [f32; N_CELL]) -> Vec<f32> {
+    let dot = |weights: &[i8; 4], values: &[f32], cell: usize| -> f32 {
+        values
+            .iter()
+            .enumerate()
+            .map(|(d, &v)| weights[cell * 2 + d] as f32 * WEIGHTS_SCALE * v)
+            .sum()
+    };
+    let mut outputs = vec![];
+    for x in input.chunks(N_INPUT) {
+        let gate = |gate: usize, cell: usize| {
+            BIASES[gate][cell]
+                + dot(&WEIGHTS[gate], x, cell)
+                + dot(&WEIGHTS[gate + 4], &h[..], cell)
+        };
+        let gates: Vec<[f32; 4]> = (0..N_CELL)
+            .map(|cell| core::array::from_fn(|g| gate(g, cell)))
+            .collect();
+        for cell in 0..N_CELL {
+            let [i, f, g, o] = gates[cell];
+            c[cell] = logistic(f) * c[cell] + logistic(i) * g.tanh();
+            h[cell] = logistic(o) * c[cell].tanh();
+        }
+        outputs.extend_from_slice(&h[..]);
+    }
+    outputs
+}
+
+#[test]
+fn test_unidirectional_sequence_lstm_hybrid() -> Result<()> {
+    let buffer = build_model();
+    let model = tflite::root_as_model(&buffer).unwrap();
+    let mut allocator = unsafe { BumpArenaAllocator::new(&mut ARENA) };
+    let mut op_resolver = BLiteOpResolver::<1, f32, _>::new();
+    op_resolver
+        .add_op(OpUnidirectionalSequenceLSTMHybrid::unidirectional_sequence_lstm_hybrid())?;
+
+    let interpreter = BLiteInterpreter::new(&mut allocator, &op_resolver, &model)?;
+    let (mut h, mut c) = ([0f32; N_CELL], [0f32; N_CELL]);
+    // the states are carried over to the second invocation
+    for input in [[0.5f32, -1., 2., 0.25], [-0.75, 1.5, 0.1, -0.4]] {
+        interpreter.input.data.copy_from_slice(&input);
+        interpreter.invoke()?;
+
+        let expected = float_lstm(&input, &mut h, &mut c);
+        for (&expected, &actual) in expected.iter().zip(interpreter.output.data.iter()) {
+            assert!((expected - actual).abs() <= 0.02, "{expected} != {actual}");
+        }
+    }
+    Ok(())
+}